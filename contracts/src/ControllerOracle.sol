@@ -7,6 +7,7 @@ import {UUPSUpgradeable} from "openzeppelin-contracts-upgradeable/contracts/prox
 import {IControllerOracle} from "./interfaces/IControllerOracle.sol";
 import {IOPCrossDomainMessenger} from "./interfaces/IOPCrossDomainMessenger.sol";
 import {IAdapter} from "./interfaces/IAdapter.sol";
+import {BLS} from "./libraries/BLS.sol";
 // solhint-disable-next-line no-global-import
 import "./utils/Utils.sol" as Utils;
 
@@ -41,14 +42,32 @@ contract ControllerOracle is UUPSUpgradeable, IControllerOracle, OwnableUpgradea
     // *Task Variables*
     uint256 private _lastOutput;
 
+    // *Group Relay Variables*
+    GroupRelayCache[] internal _groupRelayCaches;
+    // group_index => group_epoch => group relay cache index + 1
+    mapping(uint256 => mapping(uint256 => uint256)) private _groupRelayCacheIndices;
+    // group_index => controller global epoch of the last group relay
+    mapping(uint256 => uint256) private _groupRelayControllerGlobalEpochs;
+
     // *Events*
     event NodeRewarded(address indexed nodeAddress, uint256 ethAmount, uint256 arpaAmount);
     event GroupUpdated(
         uint256 epoch, uint256 indexed groupIndex, uint256 indexed groupEpoch, address indexed committer
     );
 
+    event GroupRelayConfirmationTask(
+        uint256 indexed groupRelayCacheIndex,
+        uint256 indexed relayedGroupIndex,
+        uint256 relayedGroupEpoch,
+        uint256 relayerGroupIndex
+    );
+
     // *Errors*
     error GroupObsolete(uint256 groupIndex, uint256 relayedGroupEpoch, uint256 currentGroupEpoch);
+    error GroupNotAvailable(uint256 groupIndex);
+    error GroupRelayAlreadyRequested(uint256 groupIndex, uint256 groupEpoch);
+    error GroupRelayAlreadyConfirmed(uint256 groupRelayCacheIndex);
+    error InvalidControllerGlobalEpoch(uint256 groupIndex, uint256 groupEpoch, uint256 controllerGlobalEpoch);
     error SenderNotAdapter();
     error SenderNotChainMessenger();
     error InvalidZeroAddress();
@@ -97,6 +116,92 @@ contract ControllerOracle is UUPSUpgradeable, IControllerOracle, OwnableUpgradea
         emit GroupUpdated(_groupData.epoch, groupIndex, group.epoch, committer);
     }
 
+    function relayGroup(
+        uint256 relayerGroupIndex,
+        uint256 controllerGlobalEpoch,
+        Group memory group,
+        uint256 signature
+    ) external override(IControllerOracle) {
+        uint256 groupIndex = group.index;
+
+        if (group.epoch <= _groupData.groups[groupIndex].epoch) {
+            revert GroupObsolete(groupIndex, group.epoch, _groupData.groups[groupIndex].epoch);
+        }
+
+        if (_groupRelayCacheIndices[groupIndex][group.epoch] != 0) {
+            revert GroupRelayAlreadyRequested(groupIndex, group.epoch);
+        }
+
+        // the group is signed as the controller had it at its global epoch of the relay task. The
+        // global epoch goes up with the epoch of every group, so the relayed epoch can never run
+        // ahead of it, and the relays of a group must follow the controller forward
+        if (
+            group.epoch > controllerGlobalEpoch
+                || controllerGlobalEpoch <= _groupRelayControllerGlobalEpochs[groupIndex]
+        ) {
+            revert InvalidControllerGlobalEpoch(groupIndex, group.epoch, controllerGlobalEpoch);
+        }
+
+        // the group to relay is vouched by another group which is already known on this chain.
+        // a group can't vouch for its own next epoch on purpose, so that a threshold of a single
+        // group can never replace its own key on this chain without another group agreeing
+        Group storage relayerGroup = _groupData.groups[relayerGroupIndex];
+        if (relayerGroupIndex == groupIndex || !relayerGroup.isStrictlyMajorityConsensusReached) {
+            revert GroupNotAvailable(relayerGroupIndex);
+        }
+
+        _verifyGroupRelaySignature(
+            relayerGroup.publicKey,
+            _buildGroupRelayMessage(abi.encode(controllerGlobalEpoch, abi.encode(group))),
+            signature
+        );
+
+        _groupRelayControllerGlobalEpochs[groupIndex] = controllerGlobalEpoch;
+
+        GroupRelayCache storage cache = _groupRelayCaches.push();
+        cache.relayerGroupIndex = relayerGroupIndex;
+        _copyGroupTo(cache.group, group);
+
+        uint256 groupRelayCacheIndex = _groupRelayCaches.length - 1;
+        _groupRelayCacheIndices[groupIndex][group.epoch] = groupRelayCacheIndex + 1;
+
+        emit GroupRelayConfirmationTask(groupRelayCacheIndex, groupIndex, group.epoch, relayerGroupIndex);
+    }
+
+    function confirmGroupRelay(uint256 groupRelayCacheIndex, uint256 signature) external override(IControllerOracle) {
+        GroupRelayCache storage cache = _groupRelayCaches[groupRelayCacheIndex];
+
+        if (cache.isConfirmed) {
+            revert GroupRelayAlreadyConfirmed(groupRelayCacheIndex);
+        }
+
+        Group memory group = cache.group;
+        uint256 groupIndex = group.index;
+
+        if (group.epoch <= _groupData.groups[groupIndex].epoch) {
+            revert GroupObsolete(groupIndex, group.epoch, _groupData.groups[groupIndex].epoch);
+        }
+
+        // the relayed group proves that it holds the relayed public key
+        _verifyGroupRelaySignature(
+            group.publicKey,
+            _buildGroupRelayMessage(abi.encode(groupRelayCacheIndex, groupIndex, group.epoch)),
+            signature
+        );
+
+        cache.isConfirmed = true;
+
+        _groupData.epoch++;
+
+        if (_groupData.groups[groupIndex].epoch == 0) {
+            _groupData.groupCount++;
+        }
+
+        _copyGroup(group);
+
+        emit GroupUpdated(_groupData.epoch, groupIndex, group.epoch, msg.sender);
+    }
+
     function setChainMessenger(address chainMessenger) external onlyOwner {
         if (chainMessenger == address(0)) {
             revert InvalidZeroAddress();
@@ -225,8 +330,58 @@ contract ControllerOracle is UUPSUpgradeable, IControllerOracle, OwnableUpgradea
         return _lastOutput;
     }
 
+    function getGroupRelayCache(uint256 groupRelayCacheIndex)
+        external
+        view
+        override(IControllerOracle)
+        returns (GroupRelayCache memory)
+    {
+        return _groupRelayCaches[groupRelayCacheIndex];
+    }
+
+    function isGroupRelayRequested(uint256 groupIndex, uint256 groupEpoch)
+        external
+        view
+        override(IControllerOracle)
+        returns (bool)
+    {
+        return _groupRelayCacheIndices[groupIndex][groupEpoch] != 0;
+    }
+
+    /**
+     * @notice Binds the signed payload of a group relay to this chain and this contract,
+     * so that the signature can't be replayed on another chain or another deployment.
+     */
+    function _buildGroupRelayMessage(bytes memory payload) internal view returns (bytes memory) {
+        return abi.encode(block.chainid, address(this), payload);
+    }
+
+    function _verifyGroupRelaySignature(uint256[4] memory publicKey, bytes memory message, uint256 signature)
+        internal
+        view
+    {
+        if (!BLS.isValid(signature)) {
+            revert BLS.InvalidSignatureFormat();
+        }
+
+        if (!BLS.verifySingle(BLS.decompress(signature), publicKey, BLS.hashToPoint(message))) {
+            revert BLS.InvalidSignature();
+        }
+    }
+
     function _copyGroup(Group memory group) internal {
-        Group storage g = _groupData.groups[group.index];
+        _copyGroupTo(_groupData.groups[group.index], group);
+        for (uint256 i = 0; i < group.size; i++) {
+            address memberAddress = group.members[i].nodeIdAddress;
+            // Initialize withdrawable eths and arpa rewards to save gas for adapter call
+            if (_withdrawableEths[memberAddress] == 0 && _arpaRewards[memberAddress] == 0) {
+                _withdrawableEths[memberAddress] = _BALANCE_BASE;
+                _arpaRewards[memberAddress] = _BALANCE_BASE;
+            }
+        }
+    }
+
+    function _copyGroupTo(Group storage g, Group memory group) internal {
         g.index = group.index;
         g.epoch = group.epoch;
         g.threshold = group.threshold;
@@ -234,13 +389,7 @@ contract ControllerOracle is UUPSUpgradeable, IControllerOracle, OwnableUpgradea
         g.isStrictlyMajorityConsensusReached = group.isStrictlyMajorityConsensusReached;
         delete g.members;
         for (uint256 i = 0; i < group.size; i++) {
-            address memberAddress = group.members[i].nodeIdAddress;
             g.members.push(group.members[i]);
-            // Initialize withdrawable eths and arpa rewards to save gas for adapter call
-            if (_withdrawableEths[memberAddress] == 0 && _arpaRewards[memberAddress] == 0) {
-                _withdrawableEths[memberAddress] = _BALANCE_BASE;
-                _arpaRewards[memberAddress] = _BALANCE_BASE;
-            }
         }
         g.committers = group.committers;
         g.publicKey = group.publicKey;
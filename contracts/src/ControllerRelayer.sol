@@ -10,10 +10,14 @@ contract ControllerRelayer is UUPSUpgradeable, OwnableUpgradeable {
     mapping(uint256 => mapping(uint256 => uint256)) private _chainRelayRecord;
     mapping(uint256 => address) private _chainMessengers;
     IController private _controller;
+    mapping(uint256 => uint256) private _groupRelayTaskRecord;
 
     event GroupRelayed(
         uint256 epoch, uint256 indexed groupIndex, uint256 indexed groupEpoch, address indexed committer
     );
+    event GroupRelayTask(
+        uint256 controllerGlobalEpoch, uint256 indexed relayedGroupIndex, uint256 indexed relayedGroupEpoch
+    );
     event ChainMessengerSet(uint256 indexed chainId, address indexed chainMessenger);
     event ChainRelayRecordReset(uint256 indexed chainId, uint256 indexed groupIndex, uint256 groupEpoch);
 
@@ -59,6 +63,25 @@ contract ControllerRelayer is UUPSUpgradeable, OwnableUpgradeable {
         emit GroupRelayed(_controller.getGroupEpoch(), groupIndex, groupToRelay.epoch, msg.sender);
     }
 
+    /// @notice Requests the nodes to relay the group by a threshold signature of another group,
+    /// instead of sending the group through the chain messengers.
+    function requestGroupRelay(uint256 groupIndex) external {
+        IController.Group memory groupToRelay = _controller.getGroup(groupIndex);
+
+        // need the group is not in a DKG process so that group info on current epoch is finalized
+        if (_controller.getCoordinator(groupIndex) != address(0)) {
+            revert GroupNotFinalized(groupIndex, groupToRelay.epoch);
+        }
+
+        if (_groupRelayTaskRecord[groupIndex] >= groupToRelay.epoch) {
+            revert GroupObsolete(groupIndex, groupToRelay.epoch, _groupRelayTaskRecord[groupIndex]);
+        }
+
+        _groupRelayTaskRecord[groupIndex] = groupToRelay.epoch;
+
+        emit GroupRelayTask(_controller.getGroupEpoch(), groupIndex, groupToRelay.epoch);
+    }
+
     function setChainMessenger(uint256 chainId, address chainMessenger) external onlyOwner {
         _chainMessengers[chainId] = chainMessenger;
 
@@ -30,6 +30,12 @@ interface IControllerOracle {
         CommitResult commitResult;
     }
 
+    struct GroupRelayCache {
+        uint256 relayerGroupIndex;
+        bool isConfirmed;
+        Group group;
+    }
+
     struct CommitDkgParams {
         uint256 groupIndex;
         uint256 groupEpoch;
@@ -44,6 +50,15 @@ interface IControllerOracle {
     // node transaction
     function nodeWithdraw(address recipient) external;
 
+    function relayGroup(
+        uint256 relayerGroupIndex,
+        uint256 controllerGlobalEpoch,
+        Group memory group,
+        uint256 signature
+    ) external;
+
+    function confirmGroupRelay(uint256 groupRelayCacheIndex, uint256 signature) external;
+
     // adapter transaction
     function addReward(address[] memory nodes, uint256 ethAmount, uint256 arpaAmount) external;
 
@@ -70,4 +85,8 @@ interface IControllerOracle {
     function getNodeWithdrawableTokens(address nodeAddress) external view returns (uint256, uint256);
 
     function getLastOutput() external view returns (uint256);
+
+    function getGroupRelayCache(uint256 groupRelayCacheIndex) external view returns (GroupRelayCache memory);
+
+    function isGroupRelayRequested(uint256 groupIndex, uint256 groupEpoch) external view returns (bool);
 }
@@ -0,0 +1,343 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.18;
+
+pragma experimental ABIEncoderV2;
+
+import {Test} from "forge-std/Test.sol";
+import {ERC1967Proxy} from "openzeppelin-contracts/contracts/proxy/ERC1967/ERC1967Proxy.sol";
+import {ControllerOracle, IControllerOracle} from "../src/ControllerOracle.sol";
+import {BLS} from "../src/libraries/BLS.sol";
+
+contract ControllerOracleGroupRelayTest is Test {
+    event GroupRelayConfirmationTask(
+        uint256 indexed groupRelayCacheIndex,
+        uint256 indexed relayedGroupIndex,
+        uint256 relayedGroupEpoch,
+        uint256 relayerGroupIndex
+    );
+
+    uint256 internal constant _RELAYER_GROUP_INDEX = 0;
+    uint256 internal constant _RELAYED_GROUP_INDEX = 1;
+    uint256 internal constant _UNAVAILABLE_GROUP_INDEX = 2;
+
+    uint256 internal constant _CONTROLLER_GLOBAL_EPOCH = 10;
+
+    // the groups sign with a single key, whose public key is secretKey * G2
+    uint256 internal constant _RELAYER_SECRET_KEY = 7;
+    uint256 internal constant _RELAYED_SECRET_KEY = 11;
+
+    address internal _admin = address(0xABCD);
+
+    ControllerOracle internal _controllerOracle;
+
+    function setUp() public {
+        vm.prank(_admin);
+        ControllerOracle controllerOracleImpl = new ControllerOracle();
+
+        vm.prank(_admin);
+        ERC1967Proxy controllerOracle = new ERC1967Proxy(
+            address(controllerOracleImpl),
+            abi.encodeWithSignature("initialize(address,address,uint256)", address(0x90003), address(0x90002), 42)
+        );
+        _controllerOracle = ControllerOracle(address(controllerOracle));
+
+        vm.prank(_admin);
+        _controllerOracle.updateGroup(_admin, _buildGroup(_RELAYER_GROUP_INDEX, 1, true, _relayerPublicKey()));
+
+        vm.prank(_admin);
+        _controllerOracle.updateGroup(_admin, _buildGroup(_UNAVAILABLE_GROUP_INDEX, 1, false, _relayerPublicKey()));
+    }
+
+    function testRelayAndConfirmGroup() public {
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey());
+
+        vm.expectEmit(true, true, true, true);
+        emit GroupRelayConfirmationTask(0, _RELAYED_GROUP_INDEX, 1, _RELAYER_GROUP_INDEX);
+
+        _relayGroup(group);
+
+        assertTrue(_controllerOracle.isGroupRelayRequested(_RELAYED_GROUP_INDEX, 1));
+        // the group is not used until it proves that it holds the relayed key
+        assertEq(_controllerOracle.getGroup(_RELAYED_GROUP_INDEX).epoch, 0);
+
+        _confirmGroupRelay(0, _RELAYED_GROUP_INDEX, 1);
+
+        IControllerOracle.Group memory relayedGroup = _controllerOracle.getGroup(_RELAYED_GROUP_INDEX);
+        assertEq(relayedGroup.epoch, 1);
+        assertEq(relayedGroup.publicKey[0], group.publicKey[0]);
+        assertEq(relayedGroup.publicKey[3], group.publicKey[3]);
+    }
+
+    function testRelayGroupWithBadSignature() public {
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey());
+
+        // signed by the relayed group instead of the relayer group
+        uint256 signature = _signGroupRelay(_RELAYED_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH, group);
+
+        vm.expectRevert(BLS.InvalidSignature.selector);
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function testRelayGroupWithSignatureForAnotherChain() public {
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey());
+
+        uint256 signature = _sign(
+            _RELAYER_SECRET_KEY,
+            abi.encode(
+                block.chainid + 1, address(_controllerOracle), _buildGroupRelayPayload(_CONTROLLER_GLOBAL_EPOCH, group)
+            )
+        );
+
+        vm.expectRevert(BLS.InvalidSignature.selector);
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function testRelayGroupWithSignatureForAnotherControllerOracle() public {
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey());
+
+        uint256 signature = _sign(
+            _RELAYER_SECRET_KEY,
+            abi.encode(block.chainid, address(0x90103), _buildGroupRelayPayload(_CONTROLLER_GLOBAL_EPOCH, group))
+        );
+
+        vm.expectRevert(BLS.InvalidSignature.selector);
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function testRelayGroupByRelayerGroupWithoutConsensus() public {
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey());
+
+        uint256 signature = _signGroupRelay(_RELAYER_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH, group);
+
+        vm.expectRevert(
+            abi.encodeWithSelector(ControllerOracle.GroupNotAvailable.selector, _UNAVAILABLE_GROUP_INDEX)
+        );
+        _controllerOracle.relayGroup(_UNAVAILABLE_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function testRelayGroupBySelf() public {
+        // a group can't vouch for its own next epoch
+        IControllerOracle.Group memory group = _buildGroup(_RELAYER_GROUP_INDEX, 2, true, _relayedPublicKey());
+
+        uint256 signature = _signGroupRelay(_RELAYER_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH, group);
+
+        vm.expectRevert(abi.encodeWithSelector(ControllerOracle.GroupNotAvailable.selector, _RELAYER_GROUP_INDEX));
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function testRelayGroupOfStaleEpoch() public {
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey());
+
+        _relayGroup(group);
+        _confirmGroupRelay(0, _RELAYED_GROUP_INDEX, 1);
+
+        uint256 signature = _signGroupRelay(_RELAYER_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH, group);
+
+        vm.expectRevert(abi.encodeWithSelector(ControllerOracle.GroupObsolete.selector, _RELAYED_GROUP_INDEX, 1, 1));
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function testRelayGroupWithSignatureForAnotherControllerGlobalEpoch() public {
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey());
+
+        uint256 signature = _signGroupRelay(_RELAYER_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH, group);
+
+        vm.expectRevert(BLS.InvalidSignature.selector);
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH + 1, group, signature);
+    }
+
+    function testRelayGroupAheadOfControllerGlobalEpoch() public {
+        // the epoch of a group never runs ahead of the global epoch of the controller
+        IControllerOracle.Group memory group =
+            _buildGroup(_RELAYED_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH + 1, true, _relayedPublicKey());
+
+        uint256 signature = _signGroupRelay(_RELAYER_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH, group);
+
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ControllerOracle.InvalidControllerGlobalEpoch.selector,
+                _RELAYED_GROUP_INDEX,
+                _CONTROLLER_GLOBAL_EPOCH + 1,
+                _CONTROLLER_GLOBAL_EPOCH
+            )
+        );
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function testRelayGroupAtStaleControllerGlobalEpoch() public {
+        _relayGroup(_buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey()));
+
+        // a later epoch of the group is relayed at an earlier global epoch of the controller
+        IControllerOracle.Group memory group = _buildGroup(_RELAYED_GROUP_INDEX, 2, true, _relayedPublicKey());
+
+        uint256 signature = _signGroupRelay(_RELAYER_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH - 1, group);
+
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                ControllerOracle.InvalidControllerGlobalEpoch.selector,
+                _RELAYED_GROUP_INDEX,
+                2,
+                _CONTROLLER_GLOBAL_EPOCH - 1
+            )
+        );
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH - 1, group, signature);
+    }
+
+    function testConfirmGroupRelayOfStaleEpoch() public {
+        _relayGroup(_buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey()));
+
+        // a newer epoch of the group arrives before the relay is confirmed
+        vm.prank(_admin);
+        _controllerOracle.updateGroup(_admin, _buildGroup(_RELAYED_GROUP_INDEX, 2, true, _relayedPublicKey()));
+
+        uint256 signature = _sign(
+            _RELAYED_SECRET_KEY, _buildGroupRelayMessage(abi.encode(uint256(0), _RELAYED_GROUP_INDEX, uint256(1)))
+        );
+
+        vm.expectRevert(abi.encodeWithSelector(ControllerOracle.GroupObsolete.selector, _RELAYED_GROUP_INDEX, 1, 2));
+        _controllerOracle.confirmGroupRelay(0, signature);
+    }
+
+    function testReplayGroupRelayConfirmation() public {
+        _relayGroup(_buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey()));
+
+        uint256 signature = _sign(
+            _RELAYED_SECRET_KEY, _buildGroupRelayMessage(abi.encode(uint256(0), _RELAYED_GROUP_INDEX, uint256(1)))
+        );
+
+        _controllerOracle.confirmGroupRelay(0, signature);
+
+        vm.expectRevert(abi.encodeWithSelector(ControllerOracle.GroupRelayAlreadyConfirmed.selector, 0));
+        _controllerOracle.confirmGroupRelay(0, signature);
+    }
+
+    function testConfirmGroupRelayWithBadSignature() public {
+        _relayGroup(_buildGroup(_RELAYED_GROUP_INDEX, 1, true, _relayedPublicKey()));
+
+        // signed by the relayer group instead of the relayed group
+        uint256 signature = _sign(
+            _RELAYER_SECRET_KEY, _buildGroupRelayMessage(abi.encode(uint256(0), _RELAYED_GROUP_INDEX, uint256(1)))
+        );
+
+        vm.expectRevert(BLS.InvalidSignature.selector);
+        _controllerOracle.confirmGroupRelay(0, signature);
+    }
+
+    function testGroupEncoding() public {
+        IControllerOracle.Member[] memory members = new IControllerOracle.Member[](1);
+        members[0] = IControllerOracle.Member(address(1), [uint256(1), 2, 3, 4]);
+        address[] memory committers = new address[](1);
+        committers[0] = address(1);
+
+        IControllerOracle.Group memory group = IControllerOracle.Group(
+            1, 3, 1, 1, members, committers, new IControllerOracle.CommitCache[](0), true, [uint256(5), 6, 7, 8]
+        );
+
+        // shared with the group encoding test of the node, which signs the group in this form
+        bytes memory encodedGroup = abi.encodePacked(
+            hex"00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000001"
+            hex"00000000000000000000000000000000000000000000000000000000000000030000000000000000000000000000000000000000000000000000000000000001"
+            hex"00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000180"
+            hex"00000000000000000000000000000000000000000000000000000000000002400000000000000000000000000000000000000000000000000000000000000280"
+            hex"00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000005"
+            hex"00000000000000000000000000000000000000000000000000000000000000060000000000000000000000000000000000000000000000000000000000000007"
+            hex"00000000000000000000000000000000000000000000000000000000000000080000000000000000000000000000000000000000000000000000000000000001"
+            hex"00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000001"
+            hex"00000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000003"
+            hex"00000000000000000000000000000000000000000000000000000000000000040000000000000000000000000000000000000000000000000000000000000001"
+            hex"00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        assertEq(abi.encode(group), encodedGroup);
+    }
+
+    function _relayGroup(IControllerOracle.Group memory group) internal {
+        uint256 signature = _signGroupRelay(_RELAYER_SECRET_KEY, _CONTROLLER_GLOBAL_EPOCH, group);
+        _controllerOracle.relayGroup(_RELAYER_GROUP_INDEX, _CONTROLLER_GLOBAL_EPOCH, group, signature);
+    }
+
+    function _confirmGroupRelay(uint256 groupRelayCacheIndex, uint256 groupIndex, uint256 groupEpoch) internal {
+        uint256 signature = _sign(
+            _RELAYED_SECRET_KEY, _buildGroupRelayMessage(abi.encode(groupRelayCacheIndex, groupIndex, groupEpoch))
+        );
+        _controllerOracle.confirmGroupRelay(groupRelayCacheIndex, signature);
+    }
+
+    function _signGroupRelay(uint256 secretKey, uint256 controllerGlobalEpoch, IControllerOracle.Group memory group)
+        internal
+        view
+        returns (uint256)
+    {
+        return _sign(secretKey, _buildGroupRelayMessage(_buildGroupRelayPayload(controllerGlobalEpoch, group)));
+    }
+
+    function _buildGroupRelayPayload(uint256 controllerGlobalEpoch, IControllerOracle.Group memory group)
+        internal
+        pure
+        returns (bytes memory)
+    {
+        return abi.encode(controllerGlobalEpoch, abi.encode(group));
+    }
+
+    function _buildGroupRelayMessage(bytes memory payload) internal view returns (bytes memory) {
+        return abi.encode(block.chainid, address(_controllerOracle), payload);
+    }
+
+    function _buildGroup(uint256 groupIndex, uint256 groupEpoch, bool consensus, uint256[4] memory publicKey)
+        internal
+        pure
+        returns (IControllerOracle.Group memory group)
+    {
+        IControllerOracle.Member[] memory members = new IControllerOracle.Member[](1);
+        members[0] = IControllerOracle.Member(address(uint160(0x1000 + groupIndex)), publicKey);
+        address[] memory committers = new address[](1);
+        committers[0] = members[0].nodeIdAddress;
+
+        group = IControllerOracle.Group(
+            groupIndex,
+            groupEpoch,
+            1,
+            1,
+            members,
+            committers,
+            new IControllerOracle.CommitCache[](0),
+            consensus,
+            publicKey
+        );
+    }
+
+    /// @dev signs the message on G1 as the nodes do, compressed as BLS.decompress expects
+    function _sign(uint256 secretKey, bytes memory message) internal view returns (uint256) {
+        uint256[2] memory point = BLS.hashToPoint(message);
+        uint256[3] memory input = [point[0], point[1], secretKey];
+        uint256[2] memory signature;
+        bool success;
+        // solhint-disable-next-line no-inline-assembly
+        assembly {
+            success := staticcall(gas(), 0x07, input, 0x60, signature, 0x40)
+        }
+        require(success, "ecMul failed");
+
+        return signature[0] | (signature[1] > BLS.N / 2 ? 1 << 255 : 0);
+    }
+
+    /// @dev 7 * G2 in the form of BLS.fromBytesPublicKey
+    function _relayerPublicKey() internal pure returns (uint256[4] memory) {
+        return [
+            15512671280233143720612069991584289591749188907863576513414377951116606878472,
+            18551411094430470096460536606940536822990217226529861227533666875800903099477,
+            13376798835316611669264291046140500151806347092962367781523498857425536295743,
+            1711576522631428957817575436337311654689480489843856945284031697403898093784
+        ];
+    }
+
+    /// @dev 11 * G2 in the form of BLS.fromBytesPublicKey
+    function _relayedPublicKey() internal pure returns (uint256[4] memory) {
+        return [
+            8472151341754925747860535367990505955708751825377817860727104273184244800723,
+            15624790064206502667756020446826209080711344272800176518784649088946231692936,
+            1196137947243150610106053819405501111182787323156221967342356892090037828244,
+            19488077321171448217727198730828487286865984357780136663388739985720647978898
+        ];
+    }
+}
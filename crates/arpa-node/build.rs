@@ -9,7 +9,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     fs::create_dir_all("./src/rpc_stub")?;
 
-    let protos = &["proto/committer.proto", "proto/management.proto"];
+    let protos = &[
+        "proto/board.proto",
+        "proto/committer.proto",
+        "proto/management.proto",
+    ];
 
     tonic_build::configure()
         .out_dir("./src/rpc_stub")
@@ -2,7 +2,7 @@ use anyhow::Result;
 use std::marker::PhantomData;
 use threshold_bls::{
     group::Curve,
-    poly::Eval,
+    poly::{Eval, Poly},
     sig::{Share, SignatureScheme, ThresholdScheme},
 };
 
@@ -26,6 +26,16 @@ pub(crate) trait BLSCore<C: Curve> {
     /// aggregates them.
     fn aggregate(threshold: usize, partials: &[Vec<u8>]) -> Result<Vec<u8>>;
 
+    /// Aggregates the partial signatures which are valid against the public polynomial and
+    /// returns the positions in `partials` of the invalid or malformed ones along with the
+    /// signature.
+    fn aggregate_verified(
+        threshold: usize,
+        public: &Poly<C::Point>,
+        msg: &[u8],
+        partials: &[Vec<u8>],
+    ) -> Result<(Vec<u8>, Vec<usize>)>;
+
     /// Verifies that the signature on the provided message was produced by the public key
     fn verify(public: &C::Point, msg: &[u8], sig: &[u8]) -> Result<()>;
 
@@ -56,6 +66,17 @@ where
         Ok(signature)
     }
 
+    fn aggregate_verified(
+        threshold: usize,
+        public: &Poly<C::Point>,
+        msg: &[u8],
+        partials: &[Vec<u8>],
+    ) -> Result<(Vec<u8>, Vec<usize>)> {
+        let (signature, discarded_positions) =
+            S::aggregate_verified(threshold, public, msg, partials, &mut rand::thread_rng())?;
+        Ok((signature, discarded_positions))
+    }
+
     fn verify(public: &C::Point, msg: &[u8], sig: &[u8]) -> Result<()> {
         S::verify(public, msg, sig)?;
         Ok(())
@@ -1,16 +1,91 @@
 use crate::error::{NodeError, NodeResult};
 use arpa_contract_client::coordinator::{CoordinatorTransactions, CoordinatorViews};
+use arpa_core::{metrics::DKG_PHASE_DURATION, Group as ArpaGroup};
+use arpa_dal::GroupInfoHandler;
 use async_trait::async_trait;
 use core::fmt::Debug;
 use dkg_core::{
-    primitives::{joint_feldman::*, *},
+    primitives::{
+        joint_feldman::*,
+        resharing::{RDKGWaitingJustification, RDKGWaitingResponse, RDKGWaitingShare, RDKG},
+        *,
+    },
     BoardPublisher, DKGNodeError, DKGPhase, Phase2Result,
 };
+use ethers::types::Address;
 use log::info;
 use rand::RngCore;
 use rustc_hex::ToHex;
-use std::marker::PhantomData;
-use threshold_bls::{group::Curve, poly::Idx};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{marker::PhantomData, sync::Arc, time::Instant};
+use threshold_bls::{
+    group::{Curve, Element},
+    poly::{Eval, Idx, Poly, PublicPoly},
+    sig::Share,
+};
+use tokio::sync::RwLock;
+
+/// The share and the ready group state a member held before the group was assigned
+/// a new DKG task, which is all it needs to deal its share in a resharing.
+#[derive(Clone)]
+pub struct PreviousGroupShare<C: Curve> {
+    pub share: Share<C::Scalar>,
+    pub group: ArpaGroup<C>,
+}
+
+impl<C: Curve> std::fmt::Debug for PreviousGroupShare<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PreviousGroupShare")
+            .field("share", &"ignored")
+            .field("group", &self.group)
+            .finish()
+    }
+}
+
+/// The part a node plays in a resharing of an existing group key.
+pub(crate) enum ReshareRole<C: Curve> {
+    /// The node holds a share of the group key and deals it to the new group.
+    Dealer(PreviousGroupShare<C>),
+    /// The node only receives refreshed shares. The previous group is the one on the controller
+    /// before the DKG task was assigned, which the dealers' commitments are checked against.
+    NewMember(ArpaGroup<C>),
+}
+
+/// The state a node keeps between the phases of a DKG. It is persisted every time the
+/// node has published its part of a phase, so that the DKG can be resumed after a restart.
+///
+/// It holds the secret polynomial and the shares dealt to the node, so it is only stored
+/// encrypted: the SQLite file is encrypted as a whole, and on PostgreSQL the column is sealed
+/// with a key derived from the node account, without which the database can't be opened.
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "C::Scalar: DeserializeOwned")]
+pub enum DKGCheckpoint<C: Curve> {
+    /// The shares are published, waiting for the shares of the others.
+    WaitingShare(DKGWaitingShare<C>),
+    /// The responses are published, waiting for the responses of the others.
+    WaitingResponse(DKGWaitingResponse<C>),
+    /// The justifications are published, waiting for the justifications of the others.
+    WaitingJustification(DKGWaitingJustification<C>),
+    /// The resharing counterparts of the states above, along with the group public key
+    /// the refreshed shares must keep. Only the dealers publish shares, so a new member
+    /// has no state to keep before its responses are published.
+    ResharingWaitingShare(C::Point, RDKGWaitingShare<C>),
+    ResharingWaitingResponse(C::Point, RDKGWaitingResponse<C>),
+    ResharingWaitingJustification(C::Point, RDKGWaitingJustification<C>),
+}
+
+impl<C: Curve> DKGCheckpoint<C> {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DKGCheckpoint::WaitingShare(_) => "WaitingShare",
+            DKGCheckpoint::WaitingResponse(_) => "WaitingResponse",
+            DKGCheckpoint::WaitingJustification(_) => "WaitingJustification",
+            DKGCheckpoint::ResharingWaitingShare(..) => "ResharingWaitingShare",
+            DKGCheckpoint::ResharingWaitingResponse(..) => "ResharingWaitingResponse",
+            DKGCheckpoint::ResharingWaitingJustification(..) => "ResharingWaitingJustification",
+        }
+    }
+}
 
 #[async_trait]
 pub(crate) trait DKGCore<F, R, C> {
@@ -24,6 +99,23 @@ pub(crate) trait DKGCore<F, R, C> {
         R: RngCore,
         F: Fn() -> R + Send + Debug + 'async_trait,
         C: Curve;
+
+    /// Continues a DKG from the checkpoint saved before the node restarted.
+    async fn resume_dkg(&mut self, checkpoint: DKGCheckpoint<C>) -> NodeResult<DKGOutput<C>>
+    where
+        C: Curve;
+
+    async fn run_resharing(
+        &mut self,
+        dkg_private_key: C::Scalar,
+        node_rpc_endpoint: String,
+        role: ReshareRole<C>,
+        rng: F,
+    ) -> NodeResult<DKGOutput<C>>
+    where
+        R: RngCore,
+        F: Fn() -> R + Send + Debug + 'async_trait,
+        C: Curve;
 }
 
 pub(crate) struct AllPhasesDKGCore<
@@ -33,18 +125,247 @@ pub(crate) struct AllPhasesDKGCore<
     coordinator_client: P,
     c: PhantomData<C>,
     dkg_wait_for_phase_interval_millis: u64,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<C>>>>,
+    group_index: usize,
+    group_epoch: usize,
 }
 
 impl<P: CoordinatorTransactions + CoordinatorViews + BoardPublisher<C>, C: Curve>
     AllPhasesDKGCore<P, C>
 {
-    pub fn new(coordinator_client: P, dkg_wait_for_phase_interval_millis: u64) -> Self {
+    pub fn new(
+        coordinator_client: P,
+        dkg_wait_for_phase_interval_millis: u64,
+        group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<C>>>>,
+        group_index: usize,
+        group_epoch: usize,
+    ) -> Self {
         AllPhasesDKGCore {
             coordinator_client,
             c: PhantomData,
             dkg_wait_for_phase_interval_millis,
+            group_cache,
+            group_index,
+            group_epoch,
+        }
+    }
+}
+
+impl<P, C> AllPhasesDKGCore<P, C>
+where
+    P: CoordinatorTransactions + CoordinatorViews + BoardPublisher<C> + Sync + Send,
+    C: Curve,
+{
+    async fn save_checkpoint(&self, checkpoint: DKGCheckpoint<C>) -> NodeResult<()> {
+        self.group_cache
+            .write()
+            .await
+            .save_dkg_checkpoint(
+                self.group_index,
+                self.group_epoch,
+                bincode::serialize(&checkpoint)?,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn run_from_waiting_share(
+        &mut self,
+        phase1: DKGWaitingShare<C>,
+        phase_timer: &mut PhaseTimer,
+    ) -> NodeResult<DKGOutput<C>> {
+        // Wait for Phase 1
+        let phase = wait_for_phase(
+            &self.coordinator_client,
+            1,
+            self.dkg_wait_for_phase_interval_millis,
+            phase_timer,
+        )
+        .await?;
+
+        // Get the shares
+        let shares = self.coordinator_client.get_shares().await?;
+        info!("Got {} shares...", shares.len());
+        let shares = parse_bundle(&shares)?;
+        info!("Parsed {} shares. Running Phase 1.", shares.len());
+
+        // Run Phase 1, the responses can only be published within the window of Phase 1
+        let phase2 = if is_publishing_window(phase, 1) {
+            phase1.run(&mut self.coordinator_client, &shares).await
+        } else {
+            phase1.run(&mut std::io::sink(), &shares).await
+        };
+
+        match phase2 {
+            Ok(phase2) => {
+                self.save_checkpoint(DKGCheckpoint::WaitingResponse(phase2.clone()))
+                    .await?;
+
+                self.run_from_waiting_response(phase2, phase_timer).await
+            }
+            Err(err) => match err {
+                DKGNodeError::DKGError(e) => match e {
+                    DKGError::NotEnoughValidShares(_, _, disqualified_node_indices) => {
+                        Ok(DKGOutput::<C>::fail(disqualified_node_indices))
+                    }
+                    DKGError::NotEnoughJustifications(_, _, disqualified_node_indices) => {
+                        Ok(DKGOutput::<C>::fail(disqualified_node_indices))
+                    }
+                    _ => Err(e.into()),
+                },
+                _ => Err(err.into()),
+            },
         }
     }
+
+    async fn run_from_waiting_response(
+        &mut self,
+        phase2: DKGWaitingResponse<C>,
+        phase_timer: &mut PhaseTimer,
+    ) -> NodeResult<DKGOutput<C>> {
+        // Wait for Phase 2
+        let phase = wait_for_phase(
+            &self.coordinator_client,
+            2,
+            self.dkg_wait_for_phase_interval_millis,
+            phase_timer,
+        )
+        .await?;
+
+        // Get the responses
+        let responses = self.coordinator_client.get_responses().await?;
+        info!("Got {} responses...", responses.len());
+        let responses = parse_bundle(&responses)?;
+        info!("Parsed {} responses. Running Phase 2.", responses.len());
+
+        // Run Phase 2, the justifications can only be published within the window of Phase 2
+        let result = if is_publishing_window(phase, 2) {
+            phase2.run(&mut self.coordinator_client, &responses).await?
+        } else {
+            phase2.run(&mut std::io::sink(), &responses).await?
+        };
+
+        let output = match result {
+            Phase2Result::Output(out) => out,
+            // Run Phase 3 if Phase 2 errored
+            Phase2Result::GoToPhase3(phase3) => {
+                info!("There were complaints. Running Phase 3.");
+
+                self.save_checkpoint(DKGCheckpoint::WaitingJustification(phase3.clone()))
+                    .await?;
+
+                self.run_from_waiting_justification(phase3, phase_timer)
+                    .await?
+            }
+        };
+
+        info!("Success. Your share and threshold pubkey are ready.");
+
+        phase_timer.finish();
+
+        write_output(&output)?;
+
+        Ok(output)
+    }
+
+    async fn run_from_waiting_justification<P3: Phase3<C> + Send>(
+        &mut self,
+        phase3: P3,
+        phase_timer: &mut PhaseTimer,
+    ) -> NodeResult<DKGOutput<C>> {
+        // Wait for Phase 3
+        wait_for_phase(
+            &self.coordinator_client,
+            3,
+            self.dkg_wait_for_phase_interval_millis,
+            phase_timer,
+        )
+        .await?;
+
+        let justifications = self.coordinator_client.get_justifications().await?;
+        let justifications = parse_bundle(&justifications)?;
+
+        // Run Phase 3
+        Ok(phase3
+            .run(&mut self.coordinator_client, &justifications)
+            .await?)
+    }
+
+    async fn run_from_resharing_waiting_share(
+        &mut self,
+        public_key: C::Point,
+        phase1: RDKGWaitingShare<C>,
+        shares: &[BundledShares<C>],
+        phase: i8,
+        phase_timer: &mut PhaseTimer,
+    ) -> NodeResult<DKGOutput<C>> {
+        info!("Parsed {} shares. Running Phase 1.", shares.len());
+
+        // Run Phase 1, the responses can only be published within the window of Phase 1
+        let phase2 = if is_publishing_window(phase, 1) {
+            phase1.run(&mut self.coordinator_client, shares).await?
+        } else {
+            phase1.run(&mut std::io::sink(), shares).await?
+        };
+
+        self.save_checkpoint(DKGCheckpoint::ResharingWaitingResponse(
+            public_key.clone(),
+            phase2.clone(),
+        ))
+        .await?;
+
+        self.run_from_resharing_waiting_response(public_key, phase2, phase_timer)
+            .await
+    }
+
+    async fn run_from_resharing_waiting_response(
+        &mut self,
+        public_key: C::Point,
+        phase2: RDKGWaitingResponse<C>,
+        phase_timer: &mut PhaseTimer,
+    ) -> NodeResult<DKGOutput<C>> {
+        // Wait for Phase 2
+        let phase = wait_for_phase(
+            &self.coordinator_client,
+            2,
+            self.dkg_wait_for_phase_interval_millis,
+            phase_timer,
+        )
+        .await?;
+
+        // Get the responses
+        let responses = self.coordinator_client.get_responses().await?;
+        info!("Got {} responses...", responses.len());
+        let responses = parse_bundle(&responses)?;
+        info!("Parsed {} responses. Running Phase 2.", responses.len());
+
+        // Run Phase 2, the justifications can only be published within the window of Phase 2
+        let result = if is_publishing_window(phase, 2) {
+            phase2.run(&mut self.coordinator_client, &responses).await?
+        } else {
+            phase2.run(&mut std::io::sink(), &responses).await?
+        };
+
+        let output = match result {
+            Phase2Result::Output(out) => out,
+            // Run Phase 3 if Phase 2 errored
+            Phase2Result::GoToPhase3(phase3) => {
+                info!("There were complaints. Running Phase 3.");
+
+                self.save_checkpoint(DKGCheckpoint::ResharingWaitingJustification(
+                    public_key.clone(),
+                    phase3.clone(),
+                ))
+                .await?;
+
+                self.run_from_waiting_justification(phase3, phase_timer)
+                    .await?
+            }
+        };
+
+        finish_resharing(&public_key, output, phase_timer)
+    }
 }
 
 #[async_trait]
@@ -66,43 +387,19 @@ where
     {
         // TODO error handling and retry
 
+        let mut phase_timer = PhaseTimer::default();
+
         // Wait for Phase 0
         wait_for_phase(
             &self.coordinator_client,
             0,
             self.dkg_wait_for_phase_interval_millis,
+            &mut phase_timer,
         )
         .await?;
 
         // Get the group info
-        let group = self.coordinator_client.get_dkg_keys().await?;
-        let participants = self.coordinator_client.get_participants().await?;
-
-        // print some debug info
-        info!(
-            "Will run DKG with the group listed below and threshold {}",
-            group.0
-        );
-        for (bls_pubkey, address) in group.1.iter().zip(participants) {
-            let key = bls_pubkey.to_hex::<String>();
-            info!("{:?} -> {}", address, key)
-        }
-
-        let nodes = group
-            .1
-            .into_iter()
-            .filter(|pubkey| !pubkey.is_empty()) // skip users that did not register
-            .enumerate()
-            .map(|(i, pubkey)| {
-                let pubkey = bincode::deserialize(&pubkey)?;
-                Ok(Node::<C>::new(i as Idx, pubkey))
-            })
-            .collect::<NodeResult<_>>()?;
-
-        let group = Group {
-            threshold: group.0,
-            nodes,
-        };
+        let (group, _) = get_dkg_group(&self.coordinator_client).await?;
 
         // Instantiate the DKG with the group info
         info!("Calculating and broadcasting our shares... Running Phase 0.");
@@ -111,88 +408,399 @@ where
         // Run Phase 0 and publish to the chain
         let phase1 = phase0.run(&mut self.coordinator_client, rng).await?;
 
-        // Wait for Phase 1
+        self.save_checkpoint(DKGCheckpoint::WaitingShare(phase1.clone()))
+            .await?;
+
+        self.run_from_waiting_share(phase1, &mut phase_timer).await
+    }
+
+    async fn resume_dkg(&mut self, checkpoint: DKGCheckpoint<C>) -> NodeResult<DKGOutput<C>> {
+        let mut phase_timer = PhaseTimer::default();
+
+        match checkpoint {
+            DKGCheckpoint::WaitingShare(phase1) => {
+                info!("Resuming the DKG before Phase 1.");
+                self.run_from_waiting_share(phase1, &mut phase_timer).await
+            }
+            DKGCheckpoint::WaitingResponse(phase2) => {
+                info!("Resuming the DKG before Phase 2.");
+                self.run_from_waiting_response(phase2, &mut phase_timer)
+                    .await
+            }
+            DKGCheckpoint::WaitingJustification(phase3) => {
+                info!("Resuming the DKG before Phase 3.");
+                let output = self
+                    .run_from_waiting_justification(phase3, &mut phase_timer)
+                    .await?;
+
+                info!("Success. Your share and threshold pubkey are ready.");
+
+                write_output(&output)?;
+
+                Ok(output)
+            }
+            DKGCheckpoint::ResharingWaitingShare(public_key, phase1) => {
+                info!("Resuming the resharing before Phase 1.");
+                // Wait for Phase 1
+                let phase = wait_for_phase(
+                    &self.coordinator_client,
+                    1,
+                    self.dkg_wait_for_phase_interval_millis,
+                    &mut phase_timer,
+                )
+                .await?;
+
+                // Get the shares
+                let shares = self.coordinator_client.get_shares().await?;
+                info!("Got {} shares...", shares.len());
+                let shares = parse_bundle(&shares)?;
+
+                self.run_from_resharing_waiting_share(
+                    public_key,
+                    phase1,
+                    &shares,
+                    phase,
+                    &mut phase_timer,
+                )
+                .await
+            }
+            DKGCheckpoint::ResharingWaitingResponse(public_key, phase2) => {
+                info!("Resuming the resharing before Phase 2.");
+                self.run_from_resharing_waiting_response(public_key, phase2, &mut phase_timer)
+                    .await
+            }
+            DKGCheckpoint::ResharingWaitingJustification(public_key, phase3) => {
+                info!("Resuming the resharing before Phase 3.");
+                let output = self
+                    .run_from_waiting_justification(phase3, &mut phase_timer)
+                    .await?;
+
+                finish_resharing(&public_key, output, &mut phase_timer)
+            }
+        }
+    }
+
+    async fn run_resharing(
+        &mut self,
+        dkg_private_key: C::Scalar,
+        node_rpc_endpoint: String,
+        role: ReshareRole<C>,
+        rng: F,
+    ) -> NodeResult<DKGOutput<C>>
+    where
+        F: Send + Debug + 'async_trait,
+    {
+        let mut phase_timer = PhaseTimer::default();
+
+        // Wait for Phase 0
         wait_for_phase(
+            &self.coordinator_client,
+            0,
+            self.dkg_wait_for_phase_interval_millis,
+            &mut phase_timer,
+        )
+        .await?;
+
+        // Get the new group info
+        let (new_group, participants) = get_dkg_group(&self.coordinator_client).await?;
+
+        let mut rng = Some(rng);
+
+        // Only the dealers have something to publish in Phase 0
+        let (public_key, phase1, previous_group) = match role {
+            ReshareRole::Dealer(previous) => {
+                let public_key = previous
+                    .group
+                    .public_key
+                    .clone()
+                    .ok_or(NodeError::GroupNotReady)?;
+
+                let previous_output = build_previous_output(previous, &participants)?;
+
+                info!("Resharing our share to the new group... Running Phase 0.");
+                let phase0 = RDKG::new_from_share(
+                    dkg_private_key.clone(),
+                    previous_output,
+                    new_group.clone(),
+                )?
+                .with_rpc_endpoint(node_rpc_endpoint.clone());
+
+                let phase1 = phase0
+                    .run(&mut self.coordinator_client, rng.take().unwrap())
+                    .await?;
+
+                self.save_checkpoint(DKGCheckpoint::ResharingWaitingShare(
+                    public_key.clone(),
+                    phase1.clone(),
+                ))
+                .await?;
+
+                (public_key, Some(phase1), None)
+            }
+            ReshareRole::NewMember(previous_group) => {
+                let public_key = previous_group
+                    .public_key
+                    .clone()
+                    .ok_or(NodeError::GroupNotReady)?;
+
+                (public_key, None, Some(previous_group))
+            }
+        };
+
+        // Wait for Phase 1
+        let phase = wait_for_phase(
             &self.coordinator_client,
             1,
             self.dkg_wait_for_phase_interval_millis,
+            &mut phase_timer,
         )
         .await?;
 
         // Get the shares
         let shares = self.coordinator_client.get_shares().await?;
         info!("Got {} shares...", shares.len());
-        let shares = parse_bundle(&shares)?;
-        info!("Parsed {} shares. Running Phase 1.", shares.len());
+        // Pair each bundle with the DKG public key of the participant who published it
+        let publishers = self.coordinator_client.get_participants().await?;
+        let shares = shares
+            .iter()
+            .zip(publishers)
+            .filter(|(item, _)| !item.is_empty()) // filter out empty items
+            .map(|(item, publisher)| {
+                let key = participants
+                    .iter()
+                    .find(|(address, _)| *address == publisher)
+                    .map(|(_, node)| node.key().clone())
+                    .ok_or(NodeError::MemberNotExisted)?;
+                Ok((bincode::deserialize::<BundledShares<C>>(item)?, key))
+            })
+            .collect::<NodeResult<Vec<_>>>()?;
 
-        // Run Phase 1
-        match phase1.run(&mut self.coordinator_client, &shares).await {
-            Ok(phase2) => {
-                // Wait for Phase 2
-                wait_for_phase(
-                    &self.coordinator_client,
-                    2,
-                    self.dkg_wait_for_phase_interval_millis,
-                )
-                .await?;
+        let phase1 = match phase1 {
+            Some(phase1) => phase1,
+            None => {
+                let previous_group = previous_group.ok_or(NodeError::GroupNotReady)?;
 
-                // Get the responses
-                let responses = self.coordinator_client.get_responses().await?;
-                info!("Got {} responses...", responses.len());
-                let responses = parse_bundle(&responses)?;
-                info!("Parsed {} responses. Running Phase 2.", responses.len());
-
-                // Run Phase 2
-                let result = match phase2.run(&mut self.coordinator_client, &responses).await? {
-                    Phase2Result::Output(out) => Ok(out),
-                    // Run Phase 3 if Phase 2 errored
-                    Phase2Result::GoToPhase3(phase3) => {
-                        info!("There were complaints. Running Phase 3.");
-                        // Wait for Phase 3
-                        wait_for_phase(
-                            &self.coordinator_client,
-                            3,
-                            self.dkg_wait_for_phase_interval_millis,
-                        )
-                        .await?;
-
-                        let justifications = self.coordinator_client.get_justifications().await?;
-                        let justifications = parse_bundle(&justifications)?;
-
-                        // Run Phase 3
-                        phase3
-                            .run(&mut self.coordinator_client, &justifications)
-                            .await
-                    }
-                };
+                // the dealers publish from their new index but deal under their previous one
+                let (previous_group, previous_public) =
+                    recover_previous_group::<C>(&shares, &previous_group)?;
+
+                info!(
+                    "Recovered the previous group with threshold {} from the dealers.",
+                    previous_group.threshold
+                );
+
+                let phase0 =
+                    RDKG::new_member(dkg_private_key, previous_group, previous_public, new_group)?
+                        .with_rpc_endpoint(node_rpc_endpoint);
+
+                phase0
+                    .run(&mut self.coordinator_client, rng.take().unwrap())
+                    .await?
+            }
+        };
 
-                match result {
-                    Ok(output) => {
-                        info!("Success. Your share and threshold pubkey are ready.");
+        let shares = shares
+            .into_iter()
+            .map(|(bundle, _)| bundle)
+            .collect::<Vec<_>>();
 
-                        write_output(&output)?;
+        self.run_from_resharing_waiting_share(public_key, phase1, &shares, phase, &mut phase_timer)
+            .await
+    }
+}
 
-                        // info!("{:#?}", output.qual.nodes);
+/// Checks that the refreshed shares keep the group public key.
+fn finish_resharing<C: Curve>(
+    public_key: &C::Point,
+    output: DKGOutput<C>,
+    phase_timer: &mut PhaseTimer,
+) -> NodeResult<DKGOutput<C>> {
+    if output.public.public_key() != public_key {
+        return Err(NodeError::ResharingPublicKeyMismatch);
+    }
 
-                        // info!("public key: {}", output.public.public_key());
+    info!("Success. Your refreshed share of the existing group key is ready.");
 
-                        Ok(output)
-                    }
-                    Err(err) => Err(err.into()),
-                }
-            }
-            Err(err) => match err {
-                DKGNodeError::DKGError(e) => match e {
-                    DKGError::NotEnoughValidShares(_, _, disqualified_node_indices) => {
-                        Ok(DKGOutput::<C>::fail(disqualified_node_indices))
-                    }
-                    DKGError::NotEnoughJustifications(_, _, disqualified_node_indices) => {
-                        Ok(DKGOutput::<C>::fail(disqualified_node_indices))
-                    }
-                    _ => Err(e.into()),
-                },
-                _ => Err(err.into()),
-            },
+    phase_timer.finish();
+
+    write_output(&output)?;
+
+    Ok(output)
+}
+
+/// Fetches the participants of the DKG from the coordinator and builds the group with
+/// their DKG public keys, along with each participant's id address.
+async fn get_dkg_group<C: Curve>(
+    coordinator: &impl CoordinatorViews,
+) -> NodeResult<(Group<C>, Vec<(Address, Node<C>)>)> {
+    let group = coordinator.get_dkg_keys().await?;
+    let participants = coordinator.get_participants().await?;
+
+    // print some debug info
+    info!(
+        "Will run DKG with the group listed below and threshold {}",
+        group.0
+    );
+    for (bls_pubkey, address) in group.1.iter().zip(participants.iter()) {
+        let key = bls_pubkey.to_hex::<String>();
+        info!("{:?} -> {}", address, key)
+    }
+
+    let participants = group
+        .1
+        .into_iter()
+        .zip(participants)
+        .filter(|(pubkey, _)| !pubkey.is_empty()) // skip users that did not register
+        .enumerate()
+        .map(|(i, (pubkey, address))| {
+            let pubkey = bincode::deserialize(&pubkey)?;
+            Ok((address, Node::<C>::new(i as Idx, pubkey)))
+        })
+        .collect::<NodeResult<Vec<_>>>()?;
+
+    let group = Group {
+        threshold: group.0,
+        nodes: participants.iter().map(|(_, node)| node.clone()).collect(),
+    };
+
+    Ok((group, participants))
+}
+
+/// Rebuilds the output of the DKG that created the current group key from the cached group.
+///
+/// Previous members are keyed by their DKG public key when they take part in this DKG as well,
+/// so that share holders can tell which dealer they are.
+fn build_previous_output<C: Curve>(
+    previous: PreviousGroupShare<C>,
+    participants: &[(Address, Node<C>)],
+) -> NodeResult<DKGOutput<C>> {
+    let PreviousGroupShare { share, group } = previous;
+
+    let size = group
+        .members
+        .values()
+        .map(|member| member.index + 1)
+        .max()
+        .unwrap_or(0);
+
+    let nodes = (0..size)
+        .map(|index| {
+            let key = group
+                .members
+                .values()
+                .find(|member| member.index == index)
+                .and_then(|member| {
+                    participants
+                        .iter()
+                        .find(|(address, _)| *address == member.id_address)
+                })
+                .map(|(_, node)| node.key().clone())
+                .unwrap_or_else(C::Point::new);
+            Node::<C>::new(index as Idx, key)
+        })
+        .collect::<Vec<_>>();
+
+    let public = previous_public_poly(&group)?;
+
+    Ok(DKGOutput {
+        qual: Group {
+            nodes,
+            threshold: group.threshold,
+        },
+        public,
+        share,
+        disqualified_node_indices: vec![],
+    })
+}
+
+/// Interpolates the public polynomial of the group from the partial public keys of its members,
+/// which must match the group public key.
+fn previous_public_poly<C: Curve>(group: &ArpaGroup<C>) -> NodeResult<PublicPoly<C>> {
+    let partial_public_keys = group
+        .members
+        .values()
+        .filter_map(|member| {
+            member.partial_public_key.clone().map(|value| Eval {
+                value,
+                index: member.index as Idx,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let public = Poly::<C::Point>::full_recover(group.threshold, partial_public_keys)
+        .map_err(DKGError::from)?;
+
+    if Some(public.public_key()) != group.public_key.as_ref() {
+        return Err(NodeError::ResharingPublicKeyMismatch);
+    }
+
+    Ok(public)
+}
+
+/// Builds the previous group of a resharing from the shares the dealers published.
+///
+/// The free coefficient of each dealer's polynomial is the commitment to its current share, i.e.
+/// the previous public polynomial evaluated at the dealer's index. The polynomial is interpolated
+/// from the partial public keys of the previous group, so a dealer only qualifies if its
+/// commitment is on it, and at least the previous threshold of dealers must qualify.
+fn recover_previous_group<C: Curve>(
+    shares: &[(BundledShares<C>, C::Point)],
+    previous_group: &ArpaGroup<C>,
+) -> NodeResult<(Group<C>, PublicPoly<C>)> {
+    let public = previous_public_poly(previous_group)?;
+
+    let nodes = (0..previous_group.size as Idx)
+        .map(|index| {
+            let key = shares
+                .iter()
+                .find(|(bundle, _)| {
+                    bundle.dealer_idx == index
+                        && bundle.public.public_key() == &public.eval(index).value
+                })
+                .map(|(_, key)| key.clone())
+                .unwrap_or_else(C::Point::new);
+            Node::<C>::new(index, key)
+        })
+        .collect::<Vec<_>>();
+
+    let qualified = nodes
+        .iter()
+        .filter(|node| node.key() != &C::Point::new())
+        .count();
+
+    if qualified < previous_group.threshold {
+        return Err(NodeError::NotEnoughResharingDealers(
+            qualified,
+            previous_group.threshold,
+        ));
+    }
+
+    let group = Group {
+        nodes,
+        threshold: previous_group.threshold,
+    };
+
+    Ok((group, public))
+}
+
+/// Records how long the node stays in every DKG phase, from the moment the phase
+/// starts until the next one starts or the DKG finishes.
+#[derive(Default)]
+struct PhaseTimer {
+    current: Option<(usize, Instant)>,
+}
+
+impl PhaseTimer {
+    fn enter(&mut self, phase: usize) {
+        self.finish();
+        self.current = Some((phase, Instant::now()));
+    }
+
+    fn finish(&mut self) {
+        if let Some((phase, started_at)) = self.current.take() {
+            DKG_PHASE_DURATION
+                .with_label_values(&[&phase.to_string()])
+                .observe(started_at.elapsed().as_secs_f64());
         }
     }
 }
@@ -201,10 +809,11 @@ async fn wait_for_phase(
     dkg: &impl CoordinatorViews,
     num: usize,
     dkg_wait_for_phase_interval_millis: u64,
-) -> NodeResult<()> {
+    phase_timer: &mut PhaseTimer,
+) -> NodeResult<i8> {
     info!("Waiting for Phase {} to start", num);
 
-    loop {
+    let phase = loop {
         let phase = dkg.in_phase().await?;
 
         if phase == 0 {
@@ -215,18 +824,35 @@ async fn wait_for_phase(
             return Err(NodeError::DKGEnded);
         }
         if phase > num as i8 {
-            break;
+            break phase;
         }
 
         tokio::time::sleep(std::time::Duration::from_millis(
             dkg_wait_for_phase_interval_millis,
         ))
         .await;
-    }
+    };
 
     info!("In Phase {}. Moving to the next step.", num);
 
-    Ok(())
+    phase_timer.enter(num);
+
+    Ok(phase)
+}
+
+/// Whether the coordinator still accepts the publications of the given phase. A node which
+/// resumes a DKG late still processes the phase to get its share, but skips the publication.
+fn is_publishing_window(coordinator_phase: i8, num: usize) -> bool {
+    let is_open = coordinator_phase == num as i8 + 1;
+
+    if !is_open {
+        info!(
+            "The window to publish in Phase {} has passed, catching up without publishing.",
+            num
+        );
+    }
+
+    is_open
 }
 
 fn parse_bundle<D: serde::de::DeserializeOwned>(bundle: &[Vec<u8>]) -> NodeResult<Vec<D>> {
@@ -261,3 +887,193 @@ struct OutputJson {
     #[serde(rename = "share")]
     share: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{build_previous_output, recover_previous_group, PreviousGroupShare};
+    use crate::error::NodeError;
+    use arpa_core::{Group as ArpaGroup, Member};
+    use dkg_core::primitives::{group::Node, BundledShares};
+    use ethers::types::Address;
+    use std::{collections::BTreeMap, marker::PhantomData};
+    use threshold_bls::{
+        curve::bn254::{G2Curve, Scalar, G2},
+        group::Element,
+        poly::{Idx, Poly, PublicPoly},
+        sig::Share,
+    };
+
+    const PREVIOUS_SIZE: usize = 5;
+    const PREVIOUS_THRESHOLD: usize = 3;
+
+    fn previous_group(public: &PublicPoly<G2Curve>) -> ArpaGroup<G2Curve> {
+        let members = (0..PREVIOUS_SIZE)
+            .map(|index| {
+                let id_address = Address::from_low_u64_be(index as u64 + 1);
+                let member = Member {
+                    index,
+                    dkg_index: Some(index),
+                    id_address,
+                    rpc_endpoint: None,
+                    partial_public_key: Some(public.eval(index as Idx).value),
+                };
+                (id_address, member)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        ArpaGroup {
+            index: 0,
+            epoch: 1,
+            size: PREVIOUS_SIZE,
+            threshold: PREVIOUS_THRESHOLD,
+            state: true,
+            public_key: Some(*public.public_key()),
+            members,
+            committers: vec![],
+            c: PhantomData,
+        }
+    }
+
+    fn random_point() -> G2 {
+        let mut point = G2::one();
+        point.mul(&Scalar::rand(&mut rand::thread_rng()));
+        point
+    }
+
+    /// Each dealer commits to its share of the previous group key as the free coefficient.
+    fn dealer_bundle(public: &PublicPoly<G2Curve>, dealer_idx: Idx) -> BundledShares<G2Curve> {
+        BundledShares {
+            dealer_idx,
+            shares: vec![],
+            public: Poly::from(vec![public.eval(dealer_idx).value, random_point()]),
+        }
+    }
+
+    #[test]
+    fn test_build_previous_output() {
+        let private = Poly::<Scalar>::new_from(PREVIOUS_THRESHOLD - 1, &mut rand::thread_rng());
+        let public = private.commit::<G2>();
+        let group = previous_group(&public);
+
+        // the members of index 1 and 3 don't take part in the new DKG
+        let participants = [0usize, 2, 4]
+            .iter()
+            .enumerate()
+            .map(|(new_index, &index)| {
+                (
+                    Address::from_low_u64_be(index as u64 + 1),
+                    Node::<G2Curve>::new(new_index as Idx, random_point()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let previous = PreviousGroupShare {
+            share: Share {
+                index: 2,
+                private: private.eval(2).value,
+            },
+            group: group.clone(),
+        };
+
+        let output = build_previous_output(previous, &participants).unwrap();
+
+        assert_eq!(public, output.public);
+        assert_eq!(2, output.share.index);
+        assert_eq!(PREVIOUS_THRESHOLD, output.qual.threshold);
+        assert_eq!(PREVIOUS_SIZE, output.qual.nodes.len());
+        for (index, node) in output.qual.nodes.iter().enumerate() {
+            assert_eq!(index as Idx, node.id());
+        }
+        // the previous members are keyed by their DKG public keys in the new DKG
+        assert_eq!(participants[1].1.key(), output.qual.nodes[2].key());
+        assert_eq!(participants[2].1.key(), output.qual.nodes[4].key());
+        assert_eq!(&G2::new(), output.qual.nodes[1].key());
+        assert_eq!(&G2::new(), output.qual.nodes[3].key());
+
+        let mismatched = PreviousGroupShare {
+            share: Share {
+                index: 2,
+                private: private.eval(2).value,
+            },
+            group: ArpaGroup {
+                public_key: Some(random_point()),
+                ..group
+            },
+        };
+
+        assert!(matches!(
+            build_previous_output(mismatched, &participants),
+            Err(NodeError::ResharingPublicKeyMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_recover_previous_group() {
+        let private = Poly::<Scalar>::new_from(PREVIOUS_THRESHOLD - 1, &mut rand::thread_rng());
+        let public = private.commit::<G2>();
+        let previous = previous_group(&public);
+
+        // exactly the threshold of the dealers, with a gap in their indices
+        let shares = [0, 2, 4]
+            .into_iter()
+            .map(|dealer_idx| (dealer_bundle(&public, dealer_idx), random_point()))
+            .collect::<Vec<_>>();
+
+        let (group, recovered) = recover_previous_group(&shares, &previous).unwrap();
+
+        assert_eq!(public, recovered);
+        assert_eq!(PREVIOUS_THRESHOLD, group.threshold);
+        assert_eq!(PREVIOUS_SIZE, group.nodes.len());
+        assert_eq!(shares[1].1, *group.nodes[2].key());
+        assert_eq!(G2::new(), *group.nodes[1].key());
+
+        // less dealers than the threshold
+        assert!(matches!(
+            recover_previous_group(&shares[..2], &previous),
+            Err(NodeError::NotEnoughResharingDealers(2, PREVIOUS_THRESHOLD))
+        ));
+
+        let mismatched = ArpaGroup {
+            public_key: Some(random_point()),
+            ..previous
+        };
+
+        assert!(matches!(
+            recover_previous_group(&shares, &mismatched),
+            Err(NodeError::ResharingPublicKeyMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_recover_previous_group_with_bad_dealer() {
+        let private = Poly::<Scalar>::new_from(PREVIOUS_THRESHOLD - 1, &mut rand::thread_rng());
+        let public = private.commit::<G2>();
+        let previous = previous_group(&public);
+
+        // the dealer of index 0 commits to the group public key instead of its share, which
+        // alone would interpolate to the group public key with threshold 1
+        let shares = (0..PREVIOUS_SIZE as Idx)
+            .map(|dealer_idx| {
+                let mut bundle = dealer_bundle(&public, dealer_idx);
+                if dealer_idx == 0 {
+                    bundle.public = Poly::from(vec![*public.public_key(), random_point()]);
+                }
+                (bundle, random_point())
+            })
+            .collect::<Vec<_>>();
+
+        let (group, recovered) = recover_previous_group(&shares, &previous).unwrap();
+
+        assert_eq!(public, recovered);
+        assert_eq!(PREVIOUS_THRESHOLD, group.threshold);
+        assert_eq!(PREVIOUS_SIZE, group.nodes.len());
+        assert_eq!(G2::new(), *group.nodes[0].key());
+        assert_eq!(shares[1].1, *group.nodes[1].key());
+
+        // the bad dealer doesn't count towards the threshold
+        assert!(matches!(
+            recover_previous_group(&shares[..3], &previous),
+            Err(NodeError::NotEnoughResharingDealers(2, PREVIOUS_THRESHOLD))
+        ));
+    }
+}
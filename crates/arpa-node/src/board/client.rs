@@ -0,0 +1,65 @@
+use crate::committer::tls::{connect_committer_service, CommitterTls};
+use crate::error::{NodeError, NodeResult};
+use crate::rpc_stub::board::dkg_board_service_client::DkgBoardServiceClient;
+use crate::rpc_stub::board::{GetBundleRequest, SignedBundle};
+use std::time::Duration;
+use tonic::transport::Channel;
+use tonic::Request;
+
+/// A member that doesn't answer in time is skipped, the bundle is asked from the others.
+const BOARD_CONNECT_TIMEOUT_MILLIS: u64 = 3000;
+
+const BOARD_REQUEST_TIMEOUT_MILLIS: u64 = 10000;
+
+#[derive(Clone, Debug)]
+pub struct GeneralBoardClient {
+    board_endpoint: String,
+    tls: Option<CommitterTls>,
+}
+
+impl GeneralBoardClient {
+    /// The board is served by the committer rpc server, so it is connected to over mutual TLS
+    /// if the committer service is.
+    pub fn new(board_endpoint: String, tls: Option<CommitterTls>) -> Self {
+        GeneralBoardClient {
+            board_endpoint,
+            tls,
+        }
+    }
+
+    async fn prepare_service_client(&self) -> NodeResult<DkgBoardServiceClient<Channel>> {
+        let endpoint = tonic::transport::Endpoint::new(format!(
+            "{}{}",
+            "http://",
+            self.board_endpoint.clone()
+        ))?
+        .connect_timeout(Duration::from_millis(BOARD_CONNECT_TIMEOUT_MILLIS))
+        .timeout(Duration::from_millis(BOARD_REQUEST_TIMEOUT_MILLIS));
+
+        let channel = connect_committer_service(endpoint, self.tls.as_ref())
+            .await
+            .map_err(NodeError::RpcNotAvailableError)?;
+
+        Ok(DkgBoardServiceClient::new(channel))
+    }
+
+    pub async fn post_bundle(&self, signed_bundle: SignedBundle) -> NodeResult<bool> {
+        let mut board_client = self.prepare_service_client().await?;
+
+        board_client
+            .post_bundle(Request::new(signed_bundle))
+            .await
+            .map(|r| r.into_inner().result)
+            .map_err(|status| status.into())
+    }
+
+    pub async fn get_bundle(&self, request: GetBundleRequest) -> NodeResult<SignedBundle> {
+        let mut board_client = self.prepare_service_client().await?;
+
+        board_client
+            .get_bundle(Request::new(request))
+            .await
+            .map(|r| r.into_inner())
+            .map_err(|status| status.into())
+    }
+}
@@ -0,0 +1,352 @@
+//! # Off-chain DKG Board
+//!
+//! Publishing the encrypted share bundles to the coordinator is the largest gas cost of a
+//! DKG. With the off-chain board the members exchange their bundles over gRPC instead,
+//! each bundle signed by the id key of its publisher, and only put on-chain what the
+//! coordinator needs to keep the DKG accountable:
+//!
+//! - shares and justifications: a [`BundleCommitment`] to the bundle, so that the bundle
+//!   every member received from the board is the one its publisher committed to
+//! - responses: only the complaints, since a member that published nothing is taken to be
+//!   satisfied with the shares it received
+//!
+//! A member that can't get a committed bundle from any other member treats it as not
+//! published, which makes it complain about the dealer as it would on-chain. Unlike an
+//! on-chain bundle, only the publisher is then sure to have it, so a dealer whose bundle
+//! reached nobody ends up with a different group key than the others, which is caught
+//! when the group commits the DKG result like any other member with a wrong output.
+pub mod client;
+pub mod publisher;
+pub mod server;
+
+use crate::{
+    error::{NodeError, NodeResult},
+    rpc_stub::board::{BundlePhase, SignedBundle},
+};
+use arpa_core::address_to_string;
+use ethers::{
+    signers::{LocalWallet, Signer},
+    types::{Address, Signature},
+    utils::keccak256,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::RwLock;
+
+pub use publisher::OffChainBoard;
+
+pub type DKGBoardStoreHandle = Arc<RwLock<DKGBoardStore>>;
+
+/// Prefix of a commitment published in place of a bundle. Read as the index of the
+/// publisher, which is where a bincode bundle starts, it is far beyond any group size.
+const COMMITMENT_PREFIX: &[u8; 4] = b"ARPB";
+
+/// What a member publishes to the coordinator in place of a bundle it has posted to the
+/// off-chain board.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleCommitment {
+    /// The keccak256 hash of the bundle.
+    pub bundle_hash: [u8; 32],
+    /// The endpoint of the board where the publisher serves the bundle.
+    pub board_endpoint: String,
+}
+
+impl BundleCommitment {
+    pub fn new(bundle: &[u8], board_endpoint: String) -> Self {
+        BundleCommitment {
+            bundle_hash: keccak256(bundle),
+            board_endpoint,
+        }
+    }
+
+    pub fn encode(&self) -> NodeResult<Vec<u8>> {
+        Ok([&COMMITMENT_PREFIX[..], &bincode::serialize(self)?].concat())
+    }
+
+    /// Returns `None` if the value published to the coordinator is not a commitment,
+    /// i.e. it is a bundle published in full.
+    pub fn decode(value: &[u8]) -> Option<Self> {
+        value
+            .strip_prefix(&COMMITMENT_PREFIX[..])
+            .and_then(|bytes| bincode::deserialize(bytes).ok())
+    }
+
+    pub fn matches(&self, bundle: &[u8]) -> bool {
+        keccak256(bundle) == self.bundle_hash
+    }
+}
+
+/// The digest a member signs for a bundle, binding it to the DKG, the phase and the member.
+fn bundle_digest(
+    chain_id: u32,
+    coordinator_address: Address,
+    phase: i32,
+    id_address: Address,
+    bundle: &[u8],
+) -> [u8; 32] {
+    keccak256(
+        [
+            &chain_id.to_be_bytes()[..],
+            coordinator_address.as_bytes(),
+            &phase.to_be_bytes(),
+            id_address.as_bytes(),
+            &keccak256(bundle),
+        ]
+        .concat(),
+    )
+}
+
+/// Signs the bundle with the id key of the node as an EIP-191 message.
+pub async fn sign_bundle(
+    wallet: &LocalWallet,
+    chain_id: usize,
+    coordinator_address: Address,
+    phase: BundlePhase,
+    bundle: Vec<u8>,
+) -> NodeResult<SignedBundle> {
+    let digest = bundle_digest(
+        chain_id as u32,
+        coordinator_address,
+        phase as i32,
+        wallet.address(),
+        &bundle,
+    );
+
+    let signature = wallet.sign_message(digest).await?;
+
+    Ok(SignedBundle {
+        chain_id: chain_id as u32,
+        coordinator_address: address_to_string(coordinator_address),
+        phase: phase as i32,
+        id_address: address_to_string(wallet.address()),
+        bundle,
+        signature: signature.to_vec(),
+    })
+}
+
+/// Checks that the bundle is signed by the member it claims to be published by,
+/// and returns the coordinator address and the member address.
+pub fn verify_bundle(signed_bundle: &SignedBundle) -> NodeResult<(Address, Address)> {
+    let coordinator_address: Address = signed_bundle
+        .coordinator_address
+        .parse()
+        .map_err(|_| NodeError::AddressFormatError)?;
+
+    let id_address: Address = signed_bundle
+        .id_address
+        .parse()
+        .map_err(|_| NodeError::AddressFormatError)?;
+
+    let digest = bundle_digest(
+        signed_bundle.chain_id,
+        coordinator_address,
+        signed_bundle.phase,
+        id_address,
+        &signed_bundle.bundle,
+    );
+
+    Signature::try_from(&signed_bundle.signature[..])
+        .and_then(|signature| signature.verify(&digest[..], id_address))
+        .map_err(|_| NodeError::InvalidBundleSignature)?;
+
+    Ok((coordinator_address, id_address))
+}
+
+/// The DKG a node is taking part in, whose bundles its board accepts.
+#[derive(Debug)]
+struct BoardSession {
+    chain_id: usize,
+    coordinator_address: Address,
+    participants: Vec<Address>,
+    bundles: HashMap<(BundlePhase, Address), SignedBundle>,
+}
+
+/// The bundles of the current DKG of the node, the ones it published as well as the ones
+/// it received from the other members, which its board serves to the group.
+#[derive(Debug, Default)]
+pub struct DKGBoardStore {
+    session: Option<BoardSession>,
+}
+
+impl DKGBoardStore {
+    pub fn new() -> Self {
+        DKGBoardStore::default()
+    }
+
+    /// Starts accepting the bundles of the DKG run by the given coordinator. The bundles of
+    /// a previous DKG are dropped, unless it is the same DKG.
+    pub fn open_session(
+        &mut self,
+        chain_id: usize,
+        coordinator_address: Address,
+        participants: Vec<Address>,
+    ) {
+        if let Some(session) = &self.session {
+            if session.chain_id == chain_id && session.coordinator_address == coordinator_address {
+                return;
+            }
+        }
+
+        self.session = Some(BoardSession {
+            chain_id,
+            coordinator_address,
+            participants,
+            bundles: HashMap::new(),
+        });
+    }
+
+    /// Verifies and stores the bundle. Returns false if the same bundle is already stored.
+    /// The first bundle of a member for a phase is kept if it posts a different one.
+    pub fn post(&mut self, signed_bundle: SignedBundle) -> NodeResult<bool> {
+        let phase = BundlePhase::try_from(signed_bundle.phase)
+            .map_err(|_| NodeError::InvalidBundlePhase(signed_bundle.phase))?;
+
+        let (coordinator_address, id_address) = verify_bundle(&signed_bundle)?;
+
+        let session = self
+            .session
+            .as_mut()
+            .filter(|session| {
+                session.chain_id == signed_bundle.chain_id as usize
+                    && session.coordinator_address == coordinator_address
+            })
+            .ok_or(NodeError::DKGBoardSessionNotFound)?;
+
+        if !session.participants.contains(&id_address) {
+            return Err(NodeError::MemberNotExisted);
+        }
+
+        match session.bundles.get(&(phase, id_address)) {
+            Some(existing) if existing.bundle == signed_bundle.bundle => Ok(false),
+            Some(_) => Err(NodeError::ConflictingBundle),
+            None => {
+                session.bundles.insert((phase, id_address), signed_bundle);
+                Ok(true)
+            }
+        }
+    }
+
+    pub fn get(
+        &self,
+        chain_id: usize,
+        coordinator_address: Address,
+        phase: BundlePhase,
+        id_address: Address,
+    ) -> Option<&SignedBundle> {
+        self.session
+            .as_ref()
+            .filter(|session| {
+                session.chain_id == chain_id && session.coordinator_address == coordinator_address
+            })
+            .and_then(|session| session.bundles.get(&(phase, id_address)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_bundle, BundleCommitment, DKGBoardStore};
+    use crate::{
+        error::NodeError,
+        rpc_stub::board::{BundlePhase, SignedBundle},
+    };
+    use ethers::{
+        signers::{LocalWallet, Signer},
+        types::Address,
+    };
+
+    async fn signed(
+        wallet: &LocalWallet,
+        coordinator_address: Address,
+        bundle: &[u8],
+    ) -> SignedBundle {
+        sign_bundle(
+            wallet,
+            1,
+            coordinator_address,
+            BundlePhase::Shares,
+            bundle.to_vec(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[test]
+    fn test_bundle_commitment() {
+        let commitment = BundleCommitment::new(b"bundle", "127.0.0.1:50061".to_string());
+        let value = commitment.encode().unwrap();
+
+        assert_eq!(BundleCommitment::decode(&value), Some(commitment.clone()));
+        assert!(commitment.matches(b"bundle"));
+        assert!(!commitment.matches(b"another bundle"));
+
+        // a bundle published in full starts with the index of its publisher
+        let bundle = bincode::serialize(&(3u32, vec![1u8, 2, 3])).unwrap();
+        assert_eq!(BundleCommitment::decode(&bundle), None);
+    }
+
+    #[tokio::test]
+    async fn test_store_accepts_signed_bundles_of_participants() {
+        let member = LocalWallet::new(&mut rand::thread_rng());
+        let outsider = LocalWallet::new(&mut rand::thread_rng());
+        let coordinator_address = Address::random();
+
+        let mut store = DKGBoardStore::new();
+
+        // no DKG is running
+        assert!(matches!(
+            store.post(signed(&member, coordinator_address, b"shares").await),
+            Err(NodeError::DKGBoardSessionNotFound)
+        ));
+
+        store.open_session(1, coordinator_address, vec![member.address()]);
+
+        assert!(store
+            .post(signed(&member, coordinator_address, b"shares").await)
+            .unwrap());
+        // the same bundle again
+        assert!(!store
+            .post(signed(&member, coordinator_address, b"shares").await)
+            .unwrap());
+        // the member changes its bundle
+        assert!(matches!(
+            store.post(signed(&member, coordinator_address, b"other shares").await),
+            Err(NodeError::ConflictingBundle)
+        ));
+        assert_eq!(
+            store
+                .get(
+                    1,
+                    coordinator_address,
+                    BundlePhase::Shares,
+                    member.address()
+                )
+                .unwrap()
+                .bundle,
+            b"shares"
+        );
+
+        assert!(matches!(
+            store.post(signed(&outsider, coordinator_address, b"shares").await),
+            Err(NodeError::MemberNotExisted)
+        ));
+
+        // a bundle of a member forged by someone else
+        let mut forged = signed(&outsider, coordinator_address, b"shares").await;
+        forged.id_address = format!("{:?}", member.address());
+        assert!(matches!(
+            store.post(forged),
+            Err(NodeError::InvalidBundleSignature)
+        ));
+
+        // a new DKG drops the bundles of the previous one
+        store.open_session(1, Address::random(), vec![member.address()]);
+        assert!(store
+            .get(
+                1,
+                coordinator_address,
+                BundlePhase::Shares,
+                member.address()
+            )
+            .is_none());
+    }
+}
@@ -0,0 +1,800 @@
+use super::{
+    client::GeneralBoardClient, sign_bundle, verify_bundle, BundleCommitment, DKGBoardStoreHandle,
+};
+use crate::{
+    committer::CommitterCredentials,
+    error::{NodeError, NodeResult},
+    rpc_stub::board::{BundlePhase, GetBundleRequest, SignedBundle},
+};
+use arpa_contract_client::{
+    coordinator::{CoordinatorTransactions, CoordinatorViews},
+    error::ContractClientResult,
+};
+use arpa_core::address_to_string;
+use async_trait::async_trait;
+use dkg_core::{
+    primitives::{BundledJustification, BundledResponses, BundledShares, Status},
+    BoardPublisher,
+};
+use ethers::types::{Address, TransactionReceipt};
+use futures::future::join_all;
+use log::{debug, info, warn};
+use std::{iter::once, marker::PhantomData};
+use threshold_bls::group::Curve;
+
+/// A board which exchanges the bundles with the other members off-chain and leaves
+/// the coordinator, which it wraps, only the commitments and the complaints.
+///
+/// Bundles published in full to the coordinator are passed through as they are, so members
+/// which publish in full and members which publish off-chain can run the same DKG. A member
+/// which doesn't publish off-chain still reads the committed bundles of the others.
+pub struct OffChainBoard<P, C> {
+    coordinator_client: P,
+    is_publishing_off_chain: bool,
+    chain_id: usize,
+    coordinator_address: Address,
+    committer_credentials: CommitterCredentials,
+    board_endpoint: String,
+    board_store: DKGBoardStoreHandle,
+    c: PhantomData<C>,
+}
+
+impl<P: CoordinatorTransactions + CoordinatorViews + Sync + Send, C: Curve> OffChainBoard<P, C> {
+    /// Opens the session of the DKG in the board store, from when on the board of the node
+    /// accepts the bundles of the other participants.
+    pub async fn new(
+        coordinator_client: P,
+        chain_id: usize,
+        coordinator_address: Address,
+        committer_credentials: CommitterCredentials,
+        board_endpoint: String,
+        board_store: DKGBoardStoreHandle,
+        is_publishing_off_chain: bool,
+    ) -> NodeResult<Self> {
+        let participants = coordinator_client.get_participants().await?;
+
+        board_store
+            .write()
+            .await
+            .open_session(chain_id, coordinator_address, participants);
+
+        Ok(OffChainBoard {
+            coordinator_client,
+            is_publishing_off_chain,
+            chain_id,
+            coordinator_address,
+            committer_credentials,
+            board_endpoint,
+            board_store,
+            c: PhantomData,
+        })
+    }
+
+    /// Signs the bundle and keeps it on the board of the node.
+    async fn post_bundle(&self, phase: BundlePhase, bundle: Vec<u8>) -> NodeResult<SignedBundle> {
+        let signed_bundle = sign_bundle(
+            self.committer_credentials.get_wallet(),
+            self.chain_id,
+            self.coordinator_address,
+            phase,
+            bundle,
+        )
+        .await?;
+
+        self.board_store.write().await.post(signed_bundle.clone())?;
+
+        Ok(signed_bundle)
+    }
+
+    /// Pushes the bundle to the boards of the other participants.
+    async fn push_bundle(&self, signed_bundle: SignedBundle) -> NodeResult<()> {
+        let board_endpoints = self.get_board_endpoints().await?;
+
+        join_all(board_endpoints.into_iter().map(|board_endpoint| {
+            let signed_bundle = signed_bundle.clone();
+            async move {
+                if let Err(e) = GeneralBoardClient::new(
+                    board_endpoint.clone(),
+                    self.committer_credentials.get_tls().cloned(),
+                )
+                .post_bundle(signed_bundle)
+                .await
+                {
+                    // the member can still pull the bundle from our board
+                    debug!("Failed to push a bundle to {}: {:?}", board_endpoint, e);
+                }
+            }
+        }))
+        .await;
+
+        Ok(())
+    }
+
+    /// The boards of the other participants, as announced in their share commitments.
+    async fn get_board_endpoints(&self) -> ContractClientResult<Vec<String>> {
+        let mut board_endpoints = self
+            .coordinator_client
+            .get_shares()
+            .await?
+            .iter()
+            .filter_map(|value| BundleCommitment::decode(value))
+            .map(|commitment| commitment.board_endpoint)
+            .filter(|board_endpoint| *board_endpoint != self.board_endpoint)
+            .collect::<Vec<_>>();
+
+        board_endpoints.sort();
+        board_endpoints.dedup();
+
+        Ok(board_endpoints)
+    }
+
+    /// Looks the committed bundle up on the board of the node first, then asks the board
+    /// of the publisher and at last the boards of the other participants, which keep the
+    /// bundles pushed to them.
+    async fn fetch_bundle(
+        &self,
+        phase: BundlePhase,
+        publisher: Address,
+        commitment: &BundleCommitment,
+        board_endpoints: &[String],
+    ) -> Option<Vec<u8>> {
+        if let Some(signed_bundle) = self.board_store.read().await.get(
+            self.chain_id,
+            self.coordinator_address,
+            phase,
+            publisher,
+        ) {
+            if commitment.matches(&signed_bundle.bundle) {
+                return Some(signed_bundle.bundle.clone());
+            }
+        }
+
+        let request = GetBundleRequest {
+            chain_id: self.chain_id as u32,
+            coordinator_address: address_to_string(self.coordinator_address),
+            phase: phase as i32,
+            id_address: address_to_string(publisher),
+        };
+
+        let others = board_endpoints
+            .iter()
+            .filter(|board_endpoint| **board_endpoint != commitment.board_endpoint);
+
+        for board_endpoint in once(&commitment.board_endpoint).chain(others) {
+            match GeneralBoardClient::new(
+                board_endpoint.clone(),
+                self.committer_credentials.get_tls().cloned(),
+            )
+            .get_bundle(request.clone())
+            .await
+            {
+                Ok(signed_bundle)
+                    if commitment.matches(&signed_bundle.bundle)
+                        && matches!(verify_bundle(&signed_bundle), Ok((_, id_address)) if id_address == publisher) =>
+                {
+                    let bundle = signed_bundle.bundle.clone();
+
+                    if let Err(e) = self.board_store.write().await.post(signed_bundle) {
+                        warn!(
+                            "The {:?} bundle of {:?} differs from the one on our board: {:?}",
+                            phase, publisher, e
+                        );
+                    }
+
+                    return Some(bundle);
+                }
+                Ok(_) => {
+                    warn!(
+                        "{} served a {:?} bundle of {:?} which doesn't match the commitment.",
+                        board_endpoint, phase, publisher
+                    );
+                }
+                Err(e) => {
+                    debug!(
+                        "Failed to get the {:?} bundle of {:?} from {}: {:?}",
+                        phase, publisher, board_endpoint, e
+                    );
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Replaces the commitments among the values published to the coordinator with the
+    /// bundles they commit to. A bundle which is nowhere to be found counts as not published.
+    async fn resolve_bundles(
+        &self,
+        phase: BundlePhase,
+        values: Vec<Vec<u8>>,
+    ) -> ContractClientResult<Vec<Vec<u8>>> {
+        let participants = self.coordinator_client.get_participants().await?;
+
+        let board_endpoints = self.get_board_endpoints().await?;
+
+        let mut bundles = Vec::with_capacity(values.len());
+
+        for (publisher, value) in participants.into_iter().zip(values) {
+            let bundle = match BundleCommitment::decode(&value) {
+                Some(commitment) => self
+                    .fetch_bundle(phase, publisher, &commitment, &board_endpoints)
+                    .await
+                    .unwrap_or_else(|| {
+                        warn!(
+                            "The {:?} bundle of {:?} is not available on any board.",
+                            phase, publisher
+                        );
+                        vec![]
+                    }),
+                None => value,
+            };
+
+            bundles.push(bundle);
+        }
+
+        Ok(bundles)
+    }
+
+    async fn publish_commitment(&self, phase: BundlePhase, bundle: Vec<u8>) -> NodeResult<()> {
+        let commitment = BundleCommitment::new(&bundle, self.board_endpoint.clone()).encode()?;
+
+        // the bundle is handed out before anyone sees the commitment, so that the members
+        // don't depend on our board being reachable when they read it
+        let signed_bundle = self.post_bundle(phase, bundle).await?;
+
+        self.push_bundle(signed_bundle).await?;
+
+        self.coordinator_client.publish(commitment).await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P: CoordinatorTransactions + Sync + Send, C: Curve> CoordinatorTransactions
+    for OffChainBoard<P, C>
+{
+    async fn publish(&self, value: Vec<u8>) -> ContractClientResult<TransactionReceipt> {
+        self.coordinator_client.publish(value).await
+    }
+}
+
+#[async_trait]
+impl<P: CoordinatorTransactions + CoordinatorViews + Sync + Send, C: Curve> CoordinatorViews
+    for OffChainBoard<P, C>
+{
+    async fn get_shares(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+        let shares = self.coordinator_client.get_shares().await?;
+
+        self.resolve_bundles(BundlePhase::Shares, shares).await
+    }
+
+    /// Only the complaints are published to the coordinator, a member that has none is
+    /// represented by the responses it posted to the board, if we have received them.
+    async fn get_responses(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+        let responses = self.coordinator_client.get_responses().await?;
+
+        let participants = self.coordinator_client.get_participants().await?;
+
+        let board_store = self.board_store.read().await;
+
+        Ok(participants
+            .into_iter()
+            .zip(responses)
+            .map(|(publisher, value)| {
+                if !value.is_empty() {
+                    return value;
+                }
+                board_store
+                    .get(
+                        self.chain_id,
+                        self.coordinator_address,
+                        BundlePhase::Responses,
+                        publisher,
+                    )
+                    .map(|signed_bundle| signed_bundle.bundle.clone())
+                    .unwrap_or_default()
+            })
+            .collect())
+    }
+
+    async fn get_justifications(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+        let justifications = self.coordinator_client.get_justifications().await?;
+
+        self.resolve_bundles(BundlePhase::Justifications, justifications)
+            .await
+    }
+
+    async fn get_participants(&self) -> ContractClientResult<Vec<Address>> {
+        self.coordinator_client.get_participants().await
+    }
+
+    async fn get_dkg_keys(&self) -> ContractClientResult<(usize, Vec<Vec<u8>>)> {
+        self.coordinator_client.get_dkg_keys().await
+    }
+
+    async fn in_phase(&self) -> ContractClientResult<i8> {
+        self.coordinator_client.in_phase().await
+    }
+}
+
+#[async_trait]
+impl<P: CoordinatorTransactions + CoordinatorViews + Sync + Send, C: Curve + 'static>
+    BoardPublisher<C> for OffChainBoard<P, C>
+{
+    type Error = NodeError;
+
+    async fn publish_shares(&mut self, shares: BundledShares<C>) -> Result<(), Self::Error> {
+        let serialized = bincode::serialize(&shares)?;
+        if !self.is_publishing_off_chain {
+            self.coordinator_client.publish(serialized).await?;
+            return Ok(());
+        }
+
+        info!("called publish_shares on the off-chain board");
+        self.publish_commitment(BundlePhase::Shares, serialized)
+            .await
+    }
+
+    async fn publish_responses(&mut self, responses: BundledResponses) -> Result<(), Self::Error> {
+        if !self.is_publishing_off_chain {
+            self.coordinator_client
+                .publish(bincode::serialize(&responses)?)
+                .await?;
+            return Ok(());
+        }
+
+        info!("called publish_responses on the off-chain board");
+        let signed_bundle = self
+            .post_bundle(BundlePhase::Responses, bincode::serialize(&responses)?)
+            .await?;
+
+        self.push_bundle(signed_bundle).await?;
+
+        let complaints = BundledResponses {
+            share_idx: responses.share_idx,
+            responses: responses
+                .responses
+                .into_iter()
+                .filter(|response| response.status == Status::Complaint)
+                .collect(),
+        };
+
+        if !complaints.responses.is_empty() {
+            self.coordinator_client
+                .publish(bincode::serialize(&complaints)?)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn publish_justifications(
+        &mut self,
+        justifications: BundledJustification<C>,
+    ) -> Result<(), Self::Error> {
+        let serialized = bincode::serialize(&justifications)?;
+        if !self.is_publishing_off_chain {
+            self.coordinator_client.publish(serialized).await?;
+            return Ok(());
+        }
+
+        self.publish_commitment(BundlePhase::Justifications, serialized)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OffChainBoard;
+    use crate::{
+        algorithm::dkg::{AllPhasesDKGCore, DKGCore},
+        board::{server::DKGBoardServiceServer, BundleCommitment, DKGBoardStore},
+        committer::CommitterCredentials,
+        rpc_stub::board::dkg_board_service_server::DkgBoardServiceServer,
+    };
+    use arpa_contract_client::{
+        coordinator::{CoordinatorTransactions, CoordinatorViews},
+        error::ContractClientResult,
+    };
+    use arpa_core::DKGTask;
+    use arpa_dal::{cache::InMemoryGroupInfoCache, GroupInfoHandler, GroupInfoUpdater};
+    use async_trait::async_trait;
+    use dkg_core::{generate_keypair, primitives::DKGOutput};
+    use ethers::{
+        signers::{LocalWallet, Signer},
+        types::{Address, TransactionReceipt},
+    };
+    use rand::prelude::ThreadRng;
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use threshold_bls::{
+        group::Curve,
+        schemes::bn254::{G2Curve, G2Scheme},
+    };
+    use tokio::{net::TcpListener, sync::RwLock, task::JoinHandle};
+    use tonic::transport::{server::TcpIncoming, Server};
+
+    static RNG_FN: fn() -> ThreadRng = rand::thread_rng;
+
+    const WAIT_FOR_PHASE_INTERVAL_MILLIS: u64 = 50;
+
+    /// The state of a coordinator whose phase is driven by the test.
+    #[derive(Default)]
+    struct CoordinatorState {
+        phase: i8,
+        threshold: usize,
+        participants: Vec<Address>,
+        dkg_keys: Vec<Vec<u8>>,
+        shares: Vec<Vec<u8>>,
+        responses: Vec<Vec<u8>>,
+        justifications: Vec<Vec<u8>>,
+    }
+
+    /// The view of the coordinator of one participant, whose transactions it signs.
+    #[derive(Clone)]
+    struct MockCoordinator {
+        sender: Address,
+        state: Arc<Mutex<CoordinatorState>>,
+    }
+
+    #[async_trait]
+    impl CoordinatorTransactions for MockCoordinator {
+        async fn publish(&self, value: Vec<u8>) -> ContractClientResult<TransactionReceipt> {
+            let mut state = self.state.lock().unwrap();
+            let index = state
+                .participants
+                .iter()
+                .position(|p| *p == self.sender)
+                .unwrap();
+            match state.phase {
+                1 => state.shares[index] = value,
+                2 => state.responses[index] = value,
+                3 => state.justifications[index] = value,
+                phase => panic!("publishing in phase {}", phase),
+            }
+            Ok(TransactionReceipt::default())
+        }
+    }
+
+    #[async_trait]
+    impl CoordinatorViews for MockCoordinator {
+        async fn get_shares(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+            Ok(self.state.lock().unwrap().shares.clone())
+        }
+
+        async fn get_responses(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+            Ok(self.state.lock().unwrap().responses.clone())
+        }
+
+        async fn get_justifications(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+            Ok(self.state.lock().unwrap().justifications.clone())
+        }
+
+        async fn get_participants(&self) -> ContractClientResult<Vec<Address>> {
+            Ok(self.state.lock().unwrap().participants.clone())
+        }
+
+        async fn get_dkg_keys(&self) -> ContractClientResult<(usize, Vec<Vec<u8>>)> {
+            let state = self.state.lock().unwrap();
+            Ok((state.threshold, state.dkg_keys.clone()))
+        }
+
+        async fn in_phase(&self) -> ContractClientResult<i8> {
+            Ok(self.state.lock().unwrap().phase)
+        }
+    }
+
+    struct TestNode {
+        wallet: LocalWallet,
+        dkg_private_key: <G2Curve as Curve>::Scalar,
+        dkg_public_key: <G2Curve as Curve>::Point,
+        board_endpoint: String,
+        board_store: Arc<RwLock<DKGBoardStore>>,
+    }
+
+    /// Runs the board of the node on a free local port, unless it is meant to be unreachable.
+    async fn start_node(serving: bool) -> TestNode {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let board_endpoint = listener.local_addr().unwrap().to_string();
+        let board_store = Arc::new(RwLock::new(DKGBoardStore::new()));
+
+        if serving {
+            let incoming = TcpIncoming::from_listener(listener, true, None).unwrap();
+            let service =
+                DkgBoardServiceServer::new(DKGBoardServiceServer::new(board_store.clone()));
+            tokio::spawn(
+                Server::builder()
+                    .add_service(service)
+                    .serve_with_incoming(incoming),
+            );
+        }
+
+        let (dkg_private_key, dkg_public_key) = generate_keypair::<G2Scheme>();
+
+        TestNode {
+            wallet: LocalWallet::new(&mut rand::thread_rng()),
+            dkg_private_key,
+            dkg_public_key,
+            board_endpoint,
+            board_store,
+        }
+    }
+
+    fn setup_coordinator(nodes: &[TestNode], threshold: usize) -> Arc<Mutex<CoordinatorState>> {
+        let n = nodes.len();
+        Arc::new(Mutex::new(CoordinatorState {
+            phase: 0,
+            threshold,
+            participants: nodes.iter().map(|node| node.wallet.address()).collect(),
+            dkg_keys: nodes
+                .iter()
+                .map(|node| bincode::serialize(&node.dkg_public_key).unwrap())
+                .collect(),
+            shares: vec![vec![]; n],
+            responses: vec![vec![]; n],
+            justifications: vec![vec![]; n],
+        }))
+    }
+
+    fn spawn_dkg(
+        node: &TestNode,
+        coordinator: &Arc<Mutex<CoordinatorState>>,
+        is_publishing_off_chain: bool,
+    ) -> JoinHandle<DKGOutput<G2Curve>> {
+        let coordinator_client = MockCoordinator {
+            sender: node.wallet.address(),
+            state: coordinator.clone(),
+        };
+        let wallet = node.wallet.clone();
+        let dkg_private_key = node.dkg_private_key;
+        let board_endpoint = node.board_endpoint.clone();
+        let board_store = node.board_store.clone();
+        let participants = coordinator.lock().unwrap().participants.clone();
+
+        tokio::spawn(async move {
+            let mut group_cache = InMemoryGroupInfoCache::<G2Curve>::new(wallet.address());
+            let self_index = participants
+                .iter()
+                .position(|p| *p == wallet.address())
+                .unwrap();
+            group_cache
+                .save_task_info(
+                    self_index,
+                    DKGTask {
+                        group_index: 0,
+                        epoch: 1,
+                        size: participants.len(),
+                        threshold: 3,
+                        members: participants,
+                        assignment_block_height: 100,
+                        coordinator_address: Address::zero(),
+                    },
+                )
+                .await
+                .unwrap();
+            let group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<G2Curve>>>> =
+                Arc::new(RwLock::new(Box::new(group_cache)));
+
+            let board = OffChainBoard::new(
+                coordinator_client,
+                1,
+                Address::zero(),
+                CommitterCredentials::new(wallet, None),
+                board_endpoint.clone(),
+                board_store,
+                is_publishing_off_chain,
+            )
+            .await
+            .unwrap();
+
+            let mut dkg_core =
+                AllPhasesDKGCore::new(board, WAIT_FOR_PHASE_INTERVAL_MILLIS, group_cache, 0, 1);
+
+            DKGCore::<_, ThreadRng, G2Curve>::run_dkg(
+                &mut dkg_core,
+                dkg_private_key,
+                board_endpoint,
+                RNG_FN,
+            )
+            .await
+            .unwrap()
+        })
+    }
+
+    async fn wait_until(
+        coordinator: &Arc<Mutex<CoordinatorState>>,
+        condition: impl Fn(&CoordinatorState) -> bool,
+    ) {
+        tokio::time::timeout(Duration::from_secs(30), async {
+            while !condition(&coordinator.lock().unwrap()) {
+                tokio::time::sleep(Duration::from_millis(WAIT_FOR_PHASE_INTERVAL_MILLIS)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    fn set_phase(coordinator: &Arc<Mutex<CoordinatorState>>, phase: i8) {
+        coordinator.lock().unwrap().phase = phase;
+    }
+
+    async fn assert_same_group_key(handles: Vec<JoinHandle<DKGOutput<G2Curve>>>, qualified: usize) {
+        let mut outputs = vec![];
+        for handle in handles {
+            outputs.push(handle.await.unwrap());
+        }
+
+        for output in outputs.iter() {
+            assert!(output.disqualified_node_indices.is_empty());
+            assert_eq!(output.qual.nodes.len(), qualified);
+            assert_eq!(output.public, outputs[0].public);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dkg_over_off_chain_board() {
+        let n = 5;
+        let mut nodes = vec![];
+        for _ in 0..n {
+            nodes.push(start_node(true).await);
+        }
+        let coordinator = setup_coordinator(&nodes, 3);
+
+        let handles = nodes
+            .iter()
+            .map(|node| spawn_dkg(node, &coordinator, true))
+            .collect::<Vec<_>>();
+
+        set_phase(&coordinator, 1);
+        wait_until(&coordinator, |state| {
+            state.shares.iter().all(|value| !value.is_empty())
+        })
+        .await;
+
+        // only the commitments are published to the coordinator
+        assert!(coordinator
+            .lock()
+            .unwrap()
+            .shares
+            .iter()
+            .all(|value| BundleCommitment::decode(value).is_some()));
+
+        set_phase(&coordinator, 2);
+        // every member has got all the shares and pushed its responses to the others
+        wait_until(&coordinator, |_| {
+            nodes.iter().all(|node| {
+                nodes.iter().all(|publisher| {
+                    node.board_store
+                        .try_read()
+                        .map(|store| {
+                            store
+                                .get(
+                                    1,
+                                    Address::zero(),
+                                    crate::rpc_stub::board::BundlePhase::Responses,
+                                    publisher.wallet.address(),
+                                )
+                                .is_some()
+                        })
+                        .unwrap_or(false)
+                })
+            })
+        })
+        .await;
+
+        // nobody complained, so nothing is published in Phase 2
+        assert!(coordinator
+            .lock()
+            .unwrap()
+            .responses
+            .iter()
+            .all(|value| value.is_empty()));
+
+        set_phase(&coordinator, 3);
+
+        assert_same_group_key(handles, n).await;
+    }
+
+    #[tokio::test]
+    async fn test_dkg_over_off_chain_board_without_shares_of_unreachable_dealer() {
+        let n = 5;
+        // the first member publishes its commitment before it knows anyone to push its
+        // shares to, and nobody can pull them from its board
+        let mut nodes = vec![start_node(false).await];
+        for _ in 1..n {
+            nodes.push(start_node(true).await);
+        }
+        let coordinator = setup_coordinator(&nodes, 3);
+
+        set_phase(&coordinator, 1);
+        let mut handles = vec![spawn_dkg(&nodes[0], &coordinator, true)];
+        wait_until(&coordinator, |state| !state.shares[0].is_empty()).await;
+
+        handles.extend(
+            nodes[1..]
+                .iter()
+                .map(|node| spawn_dkg(node, &coordinator, true)),
+        );
+        wait_until(&coordinator, |state| {
+            state.shares.iter().all(|value| !value.is_empty())
+        })
+        .await;
+
+        // the others complain about the shares they couldn't get on-chain
+        set_phase(&coordinator, 2);
+        wait_until(&coordinator, |state| {
+            state.responses[1..].iter().all(|value| !value.is_empty())
+        })
+        .await;
+        assert!(coordinator.lock().unwrap().responses[0].is_empty());
+
+        // the dealer answers with a justification, which it can push to everyone by now
+        set_phase(&coordinator, 3);
+        wait_until(&coordinator, |state| !state.justifications[0].is_empty()).await;
+        assert!(BundleCommitment::decode(&coordinator.lock().unwrap().justifications[0]).is_some());
+
+        set_phase(&coordinator, 4);
+
+        // but without its public polynomial nobody can check it, so the others agree on a
+        // group key without the dealer, as if it had not published its shares on-chain
+        let dealer = handles.remove(0);
+        assert_same_group_key(handles, n - 1).await;
+        dealer.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_dkg_of_members_publishing_in_full_and_off_chain() {
+        let n = 5;
+        let mut nodes = vec![];
+        for _ in 0..n {
+            nodes.push(start_node(true).await);
+        }
+        let coordinator = setup_coordinator(&nodes, 3);
+
+        // the first two members publish their bundles in full to the coordinator
+        let handles = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| spawn_dkg(node, &coordinator, i >= 2))
+            .collect::<Vec<_>>();
+
+        set_phase(&coordinator, 1);
+        wait_until(&coordinator, |state| {
+            state.shares.iter().all(|value| !value.is_empty())
+        })
+        .await;
+
+        {
+            let state = coordinator.lock().unwrap();
+            assert!(state.shares[..2]
+                .iter()
+                .all(|value| BundleCommitment::decode(value).is_none()));
+            assert!(state.shares[2..]
+                .iter()
+                .all(|value| BundleCommitment::decode(value).is_some()));
+        }
+
+        set_phase(&coordinator, 2);
+        // the members publishing in full publish their responses, without any complaint
+        wait_until(&coordinator, |state| {
+            state.responses[..2].iter().all(|value| !value.is_empty())
+        })
+        .await;
+        assert!(coordinator.lock().unwrap().responses[..2]
+            .iter()
+            .all(
+                |value| bincode::deserialize::<dkg_core::primitives::BundledResponses>(value)
+                    .unwrap()
+                    .responses
+                    .iter()
+                    .all(|response| response.status == dkg_core::primitives::Status::Success)
+            ));
+
+        set_phase(&coordinator, 3);
+
+        assert_same_group_key(handles, n).await;
+    }
+}
@@ -0,0 +1,77 @@
+use super::DKGBoardStoreHandle;
+use crate::{
+    error::NodeError,
+    rpc_stub::board::{
+        dkg_board_service_server::DkgBoardService, BundlePhase, GetBundleRequest, PostBundleReply,
+        SignedBundle,
+    },
+};
+use ethers::types::Address;
+use tonic::{Request, Response, Status};
+
+pub(crate) struct DKGBoardServiceServer {
+    board_store: DKGBoardStoreHandle,
+}
+
+impl DKGBoardServiceServer {
+    pub fn new(board_store: DKGBoardStoreHandle) -> Self {
+        DKGBoardServiceServer { board_store }
+    }
+}
+
+#[tonic::async_trait]
+impl DkgBoardService for DKGBoardServiceServer {
+    async fn post_bundle(
+        &self,
+        request: Request<SignedBundle>,
+    ) -> Result<Response<PostBundleReply>, Status> {
+        let result = self
+            .board_store
+            .write()
+            .await
+            .post(request.into_inner())
+            .map_err(|e| match e {
+                NodeError::DKGBoardSessionNotFound | NodeError::MemberNotExisted => {
+                    Status::not_found(e.to_string())
+                }
+                NodeError::ConflictingBundle => Status::already_exists(e.to_string()),
+                _ => Status::invalid_argument(e.to_string()),
+            })?;
+
+        Ok(Response::new(PostBundleReply { result }))
+    }
+
+    async fn get_bundle(
+        &self,
+        request: Request<GetBundleRequest>,
+    ) -> Result<Response<SignedBundle>, Status> {
+        let req = request.into_inner();
+
+        let phase = BundlePhase::try_from(req.phase).map_err(|_| {
+            Status::invalid_argument(NodeError::InvalidBundlePhase(req.phase).to_string())
+        })?;
+
+        let coordinator_address: Address = req
+            .coordinator_address
+            .parse()
+            .map_err(|_| Status::invalid_argument(NodeError::AddressFormatError.to_string()))?;
+
+        let id_address: Address = req
+            .id_address
+            .parse()
+            .map_err(|_| Status::invalid_argument(NodeError::AddressFormatError.to_string()))?;
+
+        self.board_store
+            .read()
+            .await
+            .get(
+                req.chain_id as usize,
+                coordinator_address,
+                phase,
+                id_address,
+            )
+            .cloned()
+            .map(Response::new)
+            .ok_or_else(|| Status::not_found("the DKG bundle is not on the board"))
+    }
+}
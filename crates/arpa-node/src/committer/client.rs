@@ -1,12 +1,20 @@
-use super::{CommitterClient, CommitterService, ServiceClient};
+use super::{
+    inject_trace_context, sign_commit_request, tls::connect_committer_service, CommitterClient,
+    CommitterCredentials, CommitterService, ServiceClient,
+};
 use crate::error::{NodeError, NodeResult};
 use crate::rpc_stub::committer::committer_service_client::CommitterServiceClient;
 use crate::rpc_stub::committer::CommitPartialSignatureRequest;
-use arpa_core::{address_to_string, jitter, BLSTaskType, ExponentialBackoffRetryDescriptor};
+use arpa_core::{
+    address_to_string, jitter, metrics::PARTIAL_SIGNATURES, BLSTaskType,
+    ExponentialBackoffRetryDescriptor,
+};
+use chrono::Utc;
 use ethers::types::Address;
 use log::error;
 use tokio_retry::{strategy::ExponentialBackoff, RetryIf};
 use tonic::Request;
+use tracing::{info_span, Instrument};
 
 #[derive(Clone, Debug)]
 pub(crate) struct GeneralCommitterClient {
@@ -14,6 +22,7 @@ pub(crate) struct GeneralCommitterClient {
     committer_id_address: Address,
     committer_endpoint: String,
     commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+    committer_credentials: CommitterCredentials,
 }
 
 impl GeneralCommitterClient {
@@ -22,12 +31,14 @@ impl GeneralCommitterClient {
         committer_id_address: Address,
         committer_endpoint: String,
         commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+        committer_credentials: CommitterCredentials,
     ) -> Self {
         GeneralCommitterClient {
             id_address,
             committer_id_address,
             committer_endpoint,
             commit_partial_signature_retry_descriptor,
+            committer_credentials,
         }
     }
 }
@@ -50,12 +61,14 @@ impl CommitterClient for GeneralCommitterClient {
         committer_id_address: Address,
         committer_endpoint: String,
         commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+        committer_credentials: CommitterCredentials,
     ) -> Self {
         Self::new(
             id_address,
             committer_id_address,
             committer_endpoint,
             commit_partial_signature_retry_descriptor,
+            committer_credentials,
         )
     }
 }
@@ -64,9 +77,16 @@ impl ServiceClient<CommitterServiceClient<tonic::transport::Channel>> for Genera
     async fn prepare_service_client(
         &self,
     ) -> NodeResult<CommitterServiceClient<tonic::transport::Channel>> {
-        CommitterServiceClient::connect(format!("{}{}", "http://", self.committer_endpoint.clone()))
-            .await
-            .map_err(|err| err.into())
+        let endpoint = tonic::transport::Endpoint::new(format!(
+            "{}{}",
+            "http://",
+            self.committer_endpoint.clone()
+        ))?;
+
+        let channel =
+            connect_committer_service(endpoint, self.committer_credentials.get_tls()).await?;
+
+        Ok(CommitterServiceClient::new(channel))
     }
 }
 
@@ -93,21 +113,34 @@ impl CommitterService for GeneralCommitterClient {
                 })
                 .take(commit_partial_signature_retry_descriptor.max_attempts);
 
-        RetryIf::spawn(
+        let result = RetryIf::spawn(
             retry_strategy,
             || async {
                 let request_id = request_id.clone();
                 let message = message.clone();
                 let partial_signature = partial_signature.clone();
 
-                let request = Request::new(CommitPartialSignatureRequest {
+                let mut request = CommitPartialSignatureRequest {
                     id_address: address_to_string(self.id_address),
                     chain_id: chain_id as u32,
                     task_type: task_type.to_i32(),
                     request_id,
                     message,
                     partial_signature,
-                });
+                    signature: vec![],
+                    signed_at: 0,
+                };
+
+                sign_commit_request(
+                    self.committer_credentials.get_wallet(),
+                    self.committer_id_address,
+                    Utc::now().timestamp() as u64,
+                    &mut request,
+                )
+                .await?;
+
+                let mut request = Request::new(request);
+                inject_trace_context(request.metadata_mut());
 
                 let mut committer_client = self.prepare_service_client().await?;
 
@@ -126,6 +159,25 @@ impl CommitterService for GeneralCommitterClient {
                 true
             },
         )
-        .await
+        .instrument(info_span!(
+            "commit_partial_signature",
+            committer = %address_to_string(self.committer_id_address)
+        ))
+        .await;
+
+        PARTIAL_SIGNATURES
+            .with_label_values(&[
+                &chain_id.to_string(),
+                &format!("{:?}", task_type),
+                &address_to_string(self.committer_id_address),
+                match result {
+                    Ok(true) => "accepted",
+                    Ok(false) => "rejected",
+                    Err(_) => "failed",
+                },
+            ])
+            .inc();
+
+        result
     }
 }
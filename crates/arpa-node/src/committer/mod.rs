@@ -1,13 +1,166 @@
 pub mod client;
+pub(crate) mod rate_limit;
 pub mod server;
+pub mod tls;
 
-use crate::error::NodeResult;
+use crate::{
+    error::{NodeError, NodeResult},
+    rpc_stub::committer::CommitPartialSignatureRequest,
+};
 use arpa_core::{BLSTaskType, ExponentialBackoffRetryDescriptor};
 use arpa_dal::GroupInfoHandler;
-use ethers::types::Address;
+use ethers::{
+    signers::{LocalWallet, Signer},
+    types::{Address, Signature},
+    utils::keccak256,
+};
+use hyper::http::HeaderMap;
+use opentelemetry::{
+    global,
+    propagation::{Extractor, Injector},
+    Context,
+};
 use std::sync::Arc;
 use threshold_bls::group::Curve;
 use tokio::sync::RwLock;
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// How long after it is signed a request is accepted by a committer which requires signed
+/// requests, also allowing for the clock skew between the nodes.
+pub const COMMIT_REQUEST_MAX_AGE_SECS: u64 = 300;
+
+/// What a node proves its identity to the committers of its group with: the id key signs
+/// every partial signature it sends, and the certificate authenticates the connection
+/// when mutual TLS is configured.
+#[derive(Clone, Debug)]
+pub struct CommitterCredentials {
+    wallet: LocalWallet,
+    tls: Option<tls::CommitterTls>,
+}
+
+impl CommitterCredentials {
+    pub fn new(wallet: LocalWallet, tls: Option<tls::CommitterTls>) -> Self {
+        CommitterCredentials { wallet, tls }
+    }
+
+    pub fn get_wallet(&self) -> &LocalWallet {
+        &self.wallet
+    }
+
+    pub fn get_tls(&self) -> Option<&tls::CommitterTls> {
+        self.tls.as_ref()
+    }
+}
+
+/// The digest a member signs for a partial signature, binding it to the task and to the
+/// committer it is sent to, so that it can't be replayed to another committer.
+fn commit_request_digest(
+    request: &CommitPartialSignatureRequest,
+    id_address: Address,
+    committer_id_address: Address,
+) -> [u8; 32] {
+    keccak256(
+        [
+            &request.chain_id.to_be_bytes()[..],
+            &request.task_type.to_be_bytes(),
+            &keccak256(&request.request_id),
+            &keccak256(&request.message),
+            &keccak256(&request.partial_signature),
+            id_address.as_bytes(),
+            committer_id_address.as_bytes(),
+            &request.signed_at.to_be_bytes(),
+        ]
+        .concat(),
+    )
+}
+
+/// Signs the request with the id key of the node as an EIP-191 message.
+pub async fn sign_commit_request(
+    wallet: &LocalWallet,
+    committer_id_address: Address,
+    signed_at: u64,
+    request: &mut CommitPartialSignatureRequest,
+) -> NodeResult<()> {
+    request.signed_at = signed_at;
+
+    let digest = commit_request_digest(request, wallet.address(), committer_id_address);
+
+    request.signature = wallet.sign_message(digest).await?.to_vec();
+
+    Ok(())
+}
+
+/// Checks that the request is signed by the member it claims to be sent by, for this
+/// committer and within [`COMMIT_REQUEST_MAX_AGE_SECS`] of `now`, and returns the address
+/// of the member.
+pub fn verify_commit_request(
+    request: &CommitPartialSignatureRequest,
+    committer_id_address: Address,
+    now: u64,
+) -> NodeResult<Address> {
+    let id_address: Address = request
+        .id_address
+        .parse()
+        .map_err(|_| NodeError::AddressFormatError)?;
+
+    if request.signature.is_empty() {
+        return Err(NodeError::RequestSignatureRequired);
+    }
+
+    let digest = commit_request_digest(request, id_address, committer_id_address);
+
+    Signature::try_from(&request.signature[..])
+        .and_then(|signature| signature.verify(&digest[..], id_address))
+        .map_err(|_| NodeError::InvalidRequestSignature)?;
+
+    if now.abs_diff(request.signed_at) > COMMIT_REQUEST_MAX_AGE_SECS {
+        return Err(NodeError::ExpiredRequest);
+    }
+
+    Ok(id_address)
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl Injector for MetadataInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(key), Ok(value)) = (
+            MetadataKey::from_bytes(key.as_bytes()),
+            MetadataValue::try_from(value),
+        ) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+/// Carries the trace context of the current span to the committer in the metadata of a request,
+/// so that the span of the request on the committer is a child of it.
+pub(crate) fn inject_trace_context(metadata: &mut MetadataMap) {
+    let context = Span::current().context();
+
+    global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut MetadataInjector(metadata))
+    });
+}
+
+/// Reads the trace context carried by a request from a member.
+pub(crate) fn extract_trace_context(headers: &HeaderMap) -> Context {
+    global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(headers)))
+}
 
 pub trait ServiceClient<C> {
     async fn prepare_service_client(&self) -> NodeResult<C>;
@@ -36,6 +189,7 @@ pub trait CommitterClient {
         committer_id_address: Address,
         committer_endpoint: String,
         commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+        committer_credentials: CommitterCredentials,
     ) -> Self;
 }
 
@@ -46,6 +200,8 @@ pub(crate) trait CommitterClientHandler<C: CommitterClient + Sync + Send, PC: Cu
 
     fn get_commit_partial_signature_retry_descriptor(&self) -> ExponentialBackoffRetryDescriptor;
 
+    fn get_committer_credentials(&self) -> CommitterCredentials;
+
     async fn prepare_committer_clients(&self) -> NodeResult<Vec<C>> {
         let mut committers = self.get_group_cache().read().await.get_committers()?;
 
@@ -71,6 +227,7 @@ pub(crate) trait CommitterClientHandler<C: CommitterClient + Sync + Send, PC: Cu
                 committer,
                 endpoint.clone(),
                 self.get_commit_partial_signature_retry_descriptor(),
+                self.get_committer_credentials(),
             );
 
             committer_clients.push(committer_client);
@@ -79,3 +236,98 @@ pub(crate) trait CommitterClientHandler<C: CommitterClient + Sync + Send, PC: Cu
         Ok(committer_clients)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{sign_commit_request, verify_commit_request, COMMIT_REQUEST_MAX_AGE_SECS};
+    use crate::{error::NodeError, rpc_stub::committer::CommitPartialSignatureRequest};
+    use arpa_core::address_to_string;
+    use ethers::{
+        signers::{LocalWallet, Signer},
+        types::Address,
+    };
+
+    const SIGNED_AT: u64 = 1_700_000_000;
+
+    async fn signed_request(
+        wallet: &LocalWallet,
+        committer_id_address: Address,
+    ) -> CommitPartialSignatureRequest {
+        let mut request = CommitPartialSignatureRequest {
+            id_address: address_to_string(wallet.address()),
+            chain_id: 1,
+            task_type: 0,
+            request_id: vec![1; 32],
+            message: b"message".to_vec(),
+            partial_signature: b"partial signature".to_vec(),
+            signature: vec![],
+            signed_at: 0,
+        };
+
+        sign_commit_request(wallet, committer_id_address, SIGNED_AT, &mut request)
+            .await
+            .unwrap();
+
+        request
+    }
+
+    #[tokio::test]
+    async fn test_commit_request_signature() {
+        let member = LocalWallet::new(&mut rand::thread_rng());
+        let committer_id_address = Address::random();
+
+        let request = signed_request(&member, committer_id_address).await;
+        assert_eq!(
+            verify_commit_request(&request, committer_id_address, SIGNED_AT).unwrap(),
+            member.address()
+        );
+
+        // the request is replayed to another committer
+        assert!(matches!(
+            verify_commit_request(&request, Address::random(), SIGNED_AT),
+            Err(NodeError::InvalidRequestSignature)
+        ));
+
+        // the partial signature is replaced
+        let mut tampered = request.clone();
+        tampered.partial_signature = b"another partial signature".to_vec();
+        assert!(matches!(
+            verify_commit_request(&tampered, committer_id_address, SIGNED_AT),
+            Err(NodeError::InvalidRequestSignature)
+        ));
+
+        // someone else claims to be the member
+        let mut forged = signed_request(
+            &LocalWallet::new(&mut rand::thread_rng()),
+            committer_id_address,
+        )
+        .await;
+        forged.id_address = address_to_string(member.address());
+        assert!(matches!(
+            verify_commit_request(&forged, committer_id_address, SIGNED_AT),
+            Err(NodeError::InvalidRequestSignature)
+        ));
+
+        let mut unsigned = request.clone();
+        unsigned.signature = vec![];
+        assert!(matches!(
+            verify_commit_request(&unsigned, committer_id_address, SIGNED_AT),
+            Err(NodeError::RequestSignatureRequired)
+        ));
+
+        assert!(verify_commit_request(
+            &request,
+            committer_id_address,
+            SIGNED_AT + COMMIT_REQUEST_MAX_AGE_SECS
+        )
+        .is_ok());
+        assert!(matches!(
+            verify_commit_request(
+                &request,
+                committer_id_address,
+                SIGNED_AT + COMMIT_REQUEST_MAX_AGE_SECS + 1
+            ),
+            Err(NodeError::ExpiredRequest)
+        ));
+    }
+}
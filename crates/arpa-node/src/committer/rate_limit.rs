@@ -0,0 +1,158 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Beyond this many keys the buckets which are full again are dropped, as they are the same
+/// as a new bucket, and then the ones seen longest ago.
+const MAX_RATE_LIMITER_BUCKETS: usize = 10000;
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// A token bucket per key, e.g. per remote address or per member, refilled at a fixed rate
+/// up to the burst. A rate of 0 disables the limiter.
+#[derive(Debug)]
+pub(crate) struct RateLimiter<K> {
+    requests_per_second: f64,
+    burst: f64,
+    max_buckets: usize,
+    buckets: Mutex<HashMap<K, Bucket>>,
+}
+
+impl<K: Hash + Eq> RateLimiter<K> {
+    pub fn new(requests_per_second: u32, burst: u32) -> Self {
+        RateLimiter {
+            requests_per_second: requests_per_second as f64,
+            // a burst below one request would block the key for good
+            burst: burst.max(1) as f64,
+            max_buckets: MAX_RATE_LIMITER_BUCKETS,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Takes a token from the bucket of the key, returns false if there is none left.
+    pub fn check(&self, key: K) -> bool {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&self, key: K, now: Instant) -> bool {
+        if self.requests_per_second == 0.0 {
+            return true;
+        }
+
+        let mut buckets = self.buckets.lock().unwrap();
+
+        if buckets.len() >= self.max_buckets && !buckets.contains_key(&key) {
+            buckets.retain(|_, bucket| self.refill(bucket, now) < self.burst);
+
+            // the keys which keep coming back are kept, a tenth of the buckets are evicted at
+            // a time so that a flood of new keys doesn't cost a scan per request
+            if buckets.len() >= self.max_buckets {
+                let mut last_seen = buckets
+                    .values()
+                    .map(|bucket| bucket.updated_at)
+                    .collect::<Vec<_>>();
+                let evicted = (self.max_buckets / 10).max(1);
+                let (_, cutoff, _) = last_seen.select_nth_unstable(evicted - 1);
+                let cutoff = *cutoff;
+
+                buckets.retain(|_, bucket| bucket.updated_at > cutoff);
+            }
+        }
+
+        let bucket = buckets.entry(key).or_insert(Bucket {
+            tokens: self.burst,
+            updated_at: now,
+        });
+
+        bucket.tokens = self.refill(bucket, now);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn refill(&self, bucket: &Bucket, now: Instant) -> f64 {
+        let elapsed = now
+            .checked_duration_since(bucket.updated_at)
+            .unwrap_or(Duration::ZERO);
+
+        (bucket.tokens + elapsed.as_secs_f64() * self.requests_per_second).min(self.burst)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RateLimiter;
+    use std::time::{Duration, Instant};
+
+    #[test]
+    fn test_rate_limiter() {
+        let limiter = RateLimiter::new(2, 3);
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(limiter.check_at("a", now));
+        }
+        assert!(!limiter.check_at("a", now));
+
+        // the other keys have their own buckets
+        assert!(limiter.check_at("b", now));
+
+        // 2 requests per second
+        assert!(limiter.check_at("a", now + Duration::from_millis(500)));
+        assert!(!limiter.check_at("a", now + Duration::from_millis(500)));
+
+        // up to the burst
+        let later = now + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert!(limiter.check_at("a", later));
+        }
+        assert!(!limiter.check_at("a", later));
+    }
+
+    #[test]
+    fn test_rate_limiter_evicts_least_recently_seen_buckets() {
+        let mut limiter = RateLimiter::new(1, 3);
+        limiter.max_buckets = 10;
+        let now = Instant::now();
+
+        // every bucket is partly drained, so none of them is full again
+        for key in 0..10u32 {
+            assert!(limiter.check_at(key, now + Duration::from_millis(key as u64)));
+        }
+        for _ in 0..2 {
+            assert!(limiter.check_at(9, now + Duration::from_millis(10)));
+        }
+
+        for key in 10..100u32 {
+            let later = now + Duration::from_millis(key as u64 + 10);
+            assert!(limiter.check_at(key, later));
+            // the drained key which keeps coming back keeps its bucket
+            assert!(!limiter.check_at(9, later));
+            assert!(limiter.buckets.lock().unwrap().len() <= 10);
+        }
+
+        assert!(!limiter.buckets.lock().unwrap().contains_key(&0));
+    }
+
+    #[test]
+    fn test_rate_limiter_disabled() {
+        let limiter = RateLimiter::new(0, 0);
+        let now = Instant::now();
+
+        for _ in 0..1000 {
+            assert!(limiter.check_at("a", now));
+        }
+    }
+}
@@ -1,26 +1,49 @@
+use super::{extract_trace_context, rate_limit::RateLimiter, verify_commit_request};
 use crate::{
     algorithm::bls::{BLSCore, SimpleBLSCore},
-    context::{types::GeneralContext, Context},
+    board::server::DKGBoardServiceServer,
+    context::{types::GeneralContext, Context, ContextFetcher},
     error::NodeError,
 };
 use crate::{
-    context::chain::Chain,
+    context::chain::{Chain, MainChain},
+    rpc_stub::board::dkg_board_service_server::DkgBoardServiceServer,
     rpc_stub::committer::{
         committer_service_server::{CommitterService, CommitterServiceServer},
         CommitPartialSignatureReply, CommitPartialSignatureRequest,
     },
 };
-use arpa_core::{BLSTaskError, BLSTaskType, SchedulerError};
-use arpa_dal::GroupInfoHandler;
+use arpa_core::{
+    metrics::COMMITTER_REJECTED_REQUESTS, BLSTaskError, BLSTaskType, CommitterRateLimitDescriptor,
+    PartialSignature, SchedulerError,
+};
+use arpa_dal::{
+    cache::{GroupRelayConfirmationResultCache, GroupRelayResultCache, RandomnessResultCache},
+    GroupInfoHandler, ResultCache, SignatureResultCacheHandler,
+};
+use chrono::Utc;
 use ethers::types::Address;
 use futures::Future;
-use std::{marker::PhantomData, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    marker::PhantomData,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
 use threshold_bls::{
     group::Curve,
     sig::{SignatureScheme, ThresholdScheme},
 };
-use tokio::sync::RwLock;
+use tokio::{
+    net::TcpListener,
+    sync::{RwLock, Semaphore, TryAcquireError},
+};
 use tonic::{transport::Server, Request, Response, Status};
+use tracing::{
+    field::{debug, display, Empty},
+    info_span, Span,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
 type NodeContext<PC, S> = Arc<RwLock<GeneralContext<PC, S>>>;
 
@@ -31,6 +54,10 @@ pub(crate) struct BLSCommitterServiceServer<
     id_address: Address,
     group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
     context: NodeContext<PC, S>,
+    is_request_signature_required: bool,
+    remote_address_limiter: RateLimiter<IpAddr>,
+    member_limiter: RateLimiter<Address>,
+    verification_permits: Semaphore,
     c: PhantomData<PC>,
     s: PhantomData<S>,
 }
@@ -42,17 +69,193 @@ impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private
         id_address: Address,
         group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
         context: NodeContext<PC, S>,
+        is_request_signature_required: bool,
+        rate_limits: CommitterRateLimitDescriptor,
     ) -> Self {
         BLSCommitterServiceServer {
             id_address,
             group_cache,
             context,
+            is_request_signature_required,
+            remote_address_limiter: RateLimiter::new(
+                rate_limits.remote_address_requests_per_second,
+                rate_limits.remote_address_burst,
+            ),
+            member_limiter: RateLimiter::new(
+                rate_limits.member_requests_per_second,
+                rate_limits.member_burst,
+            ),
+            verification_permits: Semaphore::new(rate_limits.max_concurrent_verifications),
             c: PhantomData,
             s: PhantomData,
         }
     }
 }
 
+/// The part of a committer cache that a partial signature is checked against.
+trait CommitterCache {
+    fn message(&self) -> &[u8];
+
+    fn partial_signatures(&self) -> &BTreeMap<Address, PartialSignature>;
+}
+
+impl CommitterCache for RandomnessResultCache {
+    fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    fn partial_signatures(&self) -> &BTreeMap<Address, PartialSignature> {
+        &self.partial_signatures
+    }
+}
+
+impl CommitterCache for GroupRelayResultCache {
+    fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    fn partial_signatures(&self) -> &BTreeMap<Address, PartialSignature> {
+        &self.partial_signatures
+    }
+}
+
+impl CommitterCache for GroupRelayConfirmationResultCache {
+    fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    fn partial_signatures(&self) -> &BTreeMap<Address, PartialSignature> {
+        &self.partial_signatures
+    }
+}
+
+/// Checks the partial signature of the member against the committer cache of the task and adds
+/// it to the cache, from which the signature is aggregated once the threshold is reached.
+///
+/// The member limiter is given when the request is not signed, and is only charged once the
+/// partial signature proves the request comes from the member.
+#[allow(clippy::too_many_arguments)]
+async fn commit_to_committer_cache<PC, S, C>(
+    result_cache: &RwLock<Box<dyn SignatureResultCacheHandler<C>>>,
+    verification_permits: &Semaphore,
+    member_limiter: Option<&RateLimiter<Address>>,
+    partial_public_key: &PC::Point,
+    member_index: usize,
+    member: Address,
+    req: CommitPartialSignatureRequest,
+) -> Result<(), Status>
+where
+    PC: Curve + 'static,
+    S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar> + 'static,
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+    C: ResultCache + CommitterCache,
+{
+    // because we can't assure reliability of requested partial signature to original message,
+    // we refuse to accept other node's request if the committer has not build this committer cache first.
+    if !result_cache
+        .read()
+        .await
+        .contains(&req.request_id)
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?
+    {
+        return Err(rejected(
+            "committer_cache_not_existed",
+            Status::invalid_argument(BLSTaskError::CommitterCacheNotExisted.to_string()),
+        ));
+    }
+
+    let committer_cache = result_cache
+        .read()
+        .await
+        .get(&req.request_id)
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?
+        .result_cache;
+
+    if req.message != committer_cache.message() {
+        return Err(rejected(
+            "invalid_task_message",
+            Status::invalid_argument(NodeError::InvalidTaskMessage.to_string()),
+        ));
+    }
+
+    if committer_cache.partial_signatures().contains_key(&member) {
+        return Err(rejected(
+            "already_committed",
+            Status::invalid_argument(BLSTaskError::AlreadyCommittedPartialSignature.to_string()),
+        ));
+    }
+
+    verify_partial_signature::<PC, S>(verification_permits, partial_public_key, &req).await?;
+
+    if let Some(member_limiter) = member_limiter {
+        charge_member(member_limiter, member)?;
+    }
+
+    if !result_cache
+        .write()
+        .await
+        .add_partial_signature(req.request_id, member, member_index, req.partial_signature)
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?
+    {
+        return Err(Status::invalid_argument(
+            BLSTaskError::AlreadyCommittedPartialSignature.to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Verifies the partial signature against the key of the member, which is the only
+/// expensive check, so it is done last and by a bounded number of requests at a time.
+async fn verify_partial_signature<PC, S>(
+    verification_permits: &Semaphore,
+    partial_public_key: &PC::Point,
+    req: &CommitPartialSignatureRequest,
+) -> Result<(), Status>
+where
+    PC: Curve + 'static,
+    S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar> + 'static,
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    // a request that would queue behind the verifications in flight is turned away instead
+    let _permit = verification_permits.try_acquire().map_err(|e| match e {
+        TryAcquireError::NoPermits => rejected(
+            "verification_rate_limited",
+            Status::resource_exhausted(NodeError::RateLimited.to_string()),
+        ),
+        TryAcquireError::Closed => Status::internal(e.to_string()),
+    })?;
+
+    SimpleBLSCore::<PC, S>::partial_verify(partial_public_key, &req.message, &req.partial_signature)
+        .map_err(|e| rejected("invalid_partial_signature", Status::internal(e.to_string())))
+}
+
+/// Takes a token from the bucket of the member, which must be authenticated by then so that
+/// nobody else can use up its quota.
+fn charge_member(member_limiter: &RateLimiter<Address>, member: Address) -> Result<(), Status> {
+    if !member_limiter.check(member) {
+        return Err(rejected(
+            "member_rate_limited",
+            Status::resource_exhausted(NodeError::RateLimited.to_string()),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Counts the rejected request by the reason.
+fn rejected(reason: &str, status: Status) -> Status {
+    COMMITTER_REJECTED_REQUESTS
+        .with_label_values(&[reason])
+        .inc();
+    status
+}
+
 #[tonic::async_trait]
 impl<
         PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
@@ -72,8 +275,51 @@ where
         &self,
         request: Request<CommitPartialSignatureRequest>,
     ) -> Result<Response<CommitPartialSignatureReply>, Status> {
+        if let Some(remote_addr) = request.remote_addr() {
+            if !self.remote_address_limiter.check(remote_addr.ip()) {
+                return Err(rejected(
+                    "remote_address_rate_limited",
+                    Status::resource_exhausted(NodeError::RateLimited.to_string()),
+                ));
+            }
+        }
+
         let req = request.into_inner();
 
+        Span::current()
+            .record("chain_id", req.chain_id)
+            .record("task_type", debug(BLSTaskType::from(req.task_type)))
+            .record(
+                "request_id",
+                display(format!("0x{}", hex::encode(&req.request_id))),
+            )
+            .record("member", req.id_address.as_str());
+
+        let is_signed = self.is_request_signature_required || !req.signature.is_empty();
+
+        // the sender is authenticated before anything about the task is revealed to it
+        let req_id_address: Address = if is_signed {
+            verify_commit_request(&req, self.id_address, Utc::now().timestamp() as u64).map_err(
+                |e| match e {
+                    NodeError::AddressFormatError => Status::invalid_argument(e.to_string()),
+                    _ => rejected("unauthenticated", Status::unauthenticated(e.to_string())),
+                },
+            )?
+        } else {
+            req.id_address
+                .parse()
+                .map_err(|_| Status::invalid_argument(NodeError::AddressFormatError.to_string()))?
+        };
+
+        // an unsigned request may claim any member, so it only counts against the member once
+        // its partial signature is verified, until then the remote address limiter applies
+        let member_limiter = if is_signed {
+            charge_member(&self.member_limiter, req_id_address)?;
+            None
+        } else {
+            Some(&self.member_limiter)
+        };
+
         if let Err(_) | Ok(false) = self.group_cache.read().await.get_state() {
             return Err(Status::not_found(NodeError::GroupNotReady.to_string()));
         }
@@ -84,23 +330,11 @@ where
 
         let chain_id = req.chain_id as usize;
 
-        let req_id_address: Address = req
-            .id_address
-            .parse()
-            .map_err(|_| Status::invalid_argument(NodeError::AddressFormatError.to_string()))?;
-
         if let Ok(member) = self.group_cache.read().await.get_member(req_id_address) {
             let partial_public_key = member.partial_public_key.clone().unwrap();
 
             let member_index = member.index;
 
-            SimpleBLSCore::<PC, S>::partial_verify(
-                &partial_public_key,
-                &req.message,
-                &req.partial_signature,
-            )
-            .map_err(|e| Status::internal(e.to_string()))?;
-
             match BLSTaskType::from(req.task_type) {
                 BLSTaskType::Randomness => {
                     let main_chain_id = self
@@ -133,65 +367,90 @@ where
                             .get_randomness_result_cache()
                     };
 
-                    if !randomness_result_cache
+                    commit_to_committer_cache::<PC, S, _>(
+                        &randomness_result_cache,
+                        &self.verification_permits,
+                        member_limiter,
+                        &partial_public_key,
+                        member_index,
+                        req_id_address,
+                        req,
+                    )
+                    .await?;
+                }
+
+                BLSTaskType::GroupRelay => {
+                    let main_chain_id = self
+                        .context
                         .read()
                         .await
-                        .contains(&req.request_id)
+                        .get_main_chain()
+                        .get_chain_identity()
+                        .read()
                         .await
-                        .map_err(|e| Status::internal(e.to_string()))?
-                    {
+                        .get_chain_id();
+
+                    // groups are always relayed from the main chain
+                    if chain_id != main_chain_id {
                         return Err(Status::invalid_argument(
-                            BLSTaskError::CommitterCacheNotExisted.to_string(),
+                            SchedulerError::InvalidChainId(chain_id).to_string(),
                         ));
-                        // because we can't assure reliability of requested partial signature to original message,
-                        // we refuse to accept other node's request if the committer has not build this committer cache first.
                     }
 
-                    let committer_cache_message = randomness_result_cache
+                    let group_relay_result_cache = self
+                        .context
                         .read()
                         .await
-                        .get(&req.request_id)
-                        .await
-                        .unwrap()
-                        .result_cache
-                        .message
-                        .clone();
+                        .get_main_chain()
+                        .get_group_relay_result_cache();
+
+                    commit_to_committer_cache::<PC, S, _>(
+                        &group_relay_result_cache,
+                        &self.verification_permits,
+                        member_limiter,
+                        &partial_public_key,
+                        member_index,
+                        req_id_address,
+                        req,
+                    )
+                    .await?;
+                }
 
-                    if req.message != committer_cache_message {
+                BLSTaskType::GroupRelayConfirmation => {
+                    if !self.context.read().await.contains_relayed_chain(chain_id) {
                         return Err(Status::invalid_argument(
-                            NodeError::InvalidTaskMessage.to_string(),
+                            SchedulerError::InvalidChainId(chain_id).to_string(),
                         ));
                     }
 
-                    if !randomness_result_cache
-                        .write()
-                        .await
-                        .add_partial_signature(
-                            req.request_id,
-                            req_id_address,
-                            member_index,
-                            req.partial_signature,
-                        )
+                    let group_relay_confirmation_result_cache = self
+                        .context
+                        .read()
                         .await
-                        .map_err(|e| Status::internal(e.to_string()))?
-                    {
-                        return Err(Status::invalid_argument(
-                            BLSTaskError::AlreadyCommittedPartialSignature.to_string(),
-                        ));
-                    }
-                }
-
-                _ => {
-                    return Err(Status::invalid_argument(
-                        NodeError::InvalidTaskType.to_string(),
-                    ));
+                        .get_relayed_chain(chain_id)
+                        .unwrap()
+                        .get_group_relay_confirmation_result_cache();
+
+                    commit_to_committer_cache::<PC, S, _>(
+                        &group_relay_confirmation_result_cache,
+                        &self.verification_permits,
+                        member_limiter,
+                        &partial_public_key,
+                        member_index,
+                        req_id_address,
+                        req,
+                    )
+                    .await?;
                 }
             }
 
             return Ok(Response::new(CommitPartialSignatureReply { result: true }));
         }
 
-        Err(Status::not_found(NodeError::MemberNotExisted.to_string()))
+        Err(rejected(
+            "member_not_existed",
+            Status::not_found(NodeError::MemberNotExisted.to_string()),
+        ))
     }
 }
 
@@ -213,7 +472,7 @@ where
     <S as ThresholdScheme>::Error: Sync + Send,
     <S as SignatureScheme>::Error: Sync + Send,
 {
-    let addr = endpoint.parse()?;
+    let addr: SocketAddr = endpoint.parse()?;
 
     let id_address = context
         .read()
@@ -226,19 +485,59 @@ where
 
     let group_cache = context.read().await.get_main_chain().get_group_cache();
 
+    let dkg_board_store = context.read().await.get_main_chain().get_dkg_board_store();
+
+    let committer_tls = context
+        .read()
+        .await
+        .get_committer_credentials()
+        .get_tls()
+        .cloned();
+
+    let is_request_signature_required = context
+        .read()
+        .await
+        .get_config()
+        .is_committer_request_signature_required();
+
+    let rate_limits = *context
+        .read()
+        .await
+        .get_config()
+        .get_committer_rate_limits();
+
     let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
     health_reporter
         .set_serving::<CommitterServiceServer<BLSCommitterServiceServer<PC, S>>>()
         .await;
 
-    Server::builder()
+    let router = Server::builder()
+        .trace_fn(committer_request_span)
         .add_service(CommitterServiceServer::with_interceptor(
-            BLSCommitterServiceServer::new(id_address, group_cache, context),
+            BLSCommitterServiceServer::new(
+                id_address,
+                group_cache,
+                context,
+                is_request_signature_required,
+                rate_limits,
+            ),
             intercept,
         ))
-        .add_service(health_service)
-        .serve_with_shutdown(addr, shutdown_signal)
-        .await?;
+        .add_service(DkgBoardServiceServer::new(DKGBoardServiceServer::new(
+            dkg_board_store,
+        )))
+        .add_service(health_service);
+
+    match committer_tls {
+        Some(tls) => {
+            let listener = TcpListener::bind(addr).await?;
+            router
+                .serve_with_incoming_shutdown(tls.incoming(listener), shutdown_signal)
+                .await?;
+        }
+        None => router.serve_with_shutdown(addr, shutdown_signal).await?,
+    }
+
     Ok(())
 }
 
@@ -259,38 +558,399 @@ where
     <S as ThresholdScheme>::Error: Sync + Send,
     <S as SignatureScheme>::Error: Sync + Send,
 {
-    let addr = endpoint.parse()?;
+    start_committer_server_with_shutdown(endpoint, context, std::future::pending()).await
+}
 
-    let id_address = context
-        .read()
+/// Opens the span of a request to the committer as a child of the span of the sender, whose
+/// fields of the task are recorded once the request is decoded.
+fn committer_request_span(req: &hyper::http::Request<()>) -> Span {
+    let span = info_span!(
+        "committer_request",
+        path = %req.uri().path(),
+        chain_id = Empty,
+        task_type = Empty,
+        request_id = Empty,
+        member = Empty
+    );
+    span.set_parent(extract_trace_context(req.headers()));
+    span
+}
+
+fn intercept(req: Request<()>) -> Result<Request<()>, Status> {
+    // println!("Intercepting request: {:?}", req);
+
+    Ok(req)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::commit_to_committer_cache;
+    use crate::{
+        algorithm::bls::{BLSCore, SimpleBLSCore},
+        committer::rate_limit::RateLimiter,
+        error::NodeError,
+        rpc_stub::committer::CommitPartialSignatureRequest,
+    };
+    use arpa_core::{BLSTaskError, BLSTaskType, GroupRelayConfirmationTask, GroupRelayTask};
+    use arpa_dal::{
+        cache::{
+            GroupRelayConfirmationResultCache, GroupRelayResultCache, InMemorySignatureResultCache,
+        },
+        SignatureResultCacheHandler, SignatureResultCacheUpdater,
+    };
+    use ethers::types::Address;
+    use threshold_bls::{
+        curve::bn254::{G2Curve, Scalar, G2},
+        poly::{Idx, Poly, PrivatePoly, PublicPoly},
+        schemes::bn254::G2Scheme,
+        sig::Share,
+    };
+    use tokio::sync::{RwLock, Semaphore};
+    use tonic::Code;
+
+    const GROUP_INDEX: usize = 0;
+    const THRESHOLD: usize = 3;
+
+    type GroupRelayResultCacheType =
+        RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>;
+
+    async fn build_group_relay_result_cache(
+        task: &GroupRelayTask,
+        message: &[u8],
+    ) -> GroupRelayResultCacheType {
+        let mut cache = InMemorySignatureResultCache::<GroupRelayResultCache>::new();
+
+        cache
+            .add(GROUP_INDEX, task.clone(), message.to_vec(), THRESHOLD)
+            .await
+            .unwrap();
+
+        RwLock::new(Box::new(cache))
+    }
+
+    fn build_keys() -> (PrivatePoly<G2Curve>, PublicPoly<G2Curve>) {
+        let private = Poly::<Scalar>::new_from(THRESHOLD - 1, &mut rand::thread_rng());
+        let public = private.commit::<G2>();
+        (private, public)
+    }
+
+    fn build_request(
+        private: &PrivatePoly<G2Curve>,
+        index: Idx,
+        task_type: BLSTaskType,
+        request_id: &[u8],
+        message: &[u8],
+    ) -> CommitPartialSignatureRequest {
+        let share = Share {
+            index,
+            private: private.eval(index).value,
+        };
+
+        CommitPartialSignatureRequest {
+            id_address: String::new(),
+            chain_id: 1,
+            task_type: task_type.to_i32(),
+            request_id: request_id.to_vec(),
+            message: message.to_vec(),
+            partial_signature: SimpleBLSCore::<G2Curve, G2Scheme>::partial_sign(&share, message)
+                .unwrap(),
+            signature: vec![],
+            signed_at: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reject_group_relay_partial_signature_of_mismatched_message() {
+        let task = GroupRelayTask::new(10, 5, 1, 3, 100);
+        let cache = build_group_relay_result_cache(&task, b"message").await;
+        let (private, public) = build_keys();
+
+        let req = build_request(
+            &private,
+            0,
+            BLSTaskType::GroupRelay,
+            &task.request_id,
+            b"another message",
+        );
+
+        let status = commit_to_committer_cache::<G2Curve, G2Scheme, _>(
+            &cache,
+            &Semaphore::new(1),
+            None,
+            &public.eval(0).value,
+            0,
+            Address::from_low_u64_be(1),
+            req,
+        )
         .await
-        .get_main_chain()
-        .get_chain_identity()
-        .read()
+        .unwrap_err();
+
+        assert_eq!(Code::InvalidArgument, status.code());
+        assert_eq!(NodeError::InvalidTaskMessage.to_string(), status.message());
+        assert!(cache
+            .read()
+            .await
+            .get(&task.request_id)
+            .await
+            .unwrap()
+            .result_cache
+            .partial_signatures
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reject_duplicate_group_relay_partial_signature() {
+        let task = GroupRelayTask::new(10, 5, 1, 3, 100);
+        let cache = build_group_relay_result_cache(&task, b"message").await;
+        let (private, public) = build_keys();
+
+        for expected in [
+            None,
+            Some(BLSTaskError::AlreadyCommittedPartialSignature.to_string()),
+        ] {
+            let req = build_request(
+                &private,
+                0,
+                BLSTaskType::GroupRelay,
+                &task.request_id,
+                b"message",
+            );
+
+            let res = commit_to_committer_cache::<G2Curve, G2Scheme, _>(
+                &cache,
+                &Semaphore::new(1),
+                None,
+                &public.eval(0).value,
+                0,
+                Address::from_low_u64_be(1),
+                req,
+            )
+            .await;
+
+            assert_eq!(expected, res.err().map(|e| e.message().to_string()));
+        }
+
+        assert_eq!(
+            1,
+            cache
+                .read()
+                .await
+                .get(&task.request_id)
+                .await
+                .unwrap()
+                .result_cache
+                .partial_signatures
+                .len()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reject_group_relay_partial_signature_without_verification_permit() {
+        let task = GroupRelayTask::new(10, 5, 1, 3, 100);
+        let cache = build_group_relay_result_cache(&task, b"message").await;
+        let (private, public) = build_keys();
+
+        let req = build_request(
+            &private,
+            0,
+            BLSTaskType::GroupRelay,
+            &task.request_id,
+            b"message",
+        );
+
+        // every permit is taken by the verifications in flight
+        let status = commit_to_committer_cache::<G2Curve, G2Scheme, _>(
+            &cache,
+            &Semaphore::new(0),
+            None,
+            &public.eval(0).value,
+            0,
+            Address::from_low_u64_be(1),
+            req,
+        )
         .await
-        .get_id_address();
+        .unwrap_err();
+
+        assert_eq!(Code::ResourceExhausted, status.code());
+        assert!(cache
+            .read()
+            .await
+            .get(&task.request_id)
+            .await
+            .unwrap()
+            .result_cache
+            .partial_signatures
+            .is_empty());
+    }
 
-    let group_cache = context.read().await.get_main_chain().get_group_cache();
+    #[tokio::test]
+    async fn test_reject_group_relay_partial_signature_of_another_member() {
+        let task = GroupRelayTask::new(10, 5, 1, 3, 100);
+        let cache = build_group_relay_result_cache(&task, b"message").await;
+        let (private, public) = build_keys();
+
+        // signed by the member of index 1 but sent as the member of index 0
+        let req = build_request(
+            &private,
+            1,
+            BLSTaskType::GroupRelay,
+            &task.request_id,
+            b"message",
+        );
+        let member_limiter = RateLimiter::new(1, 1);
+
+        let status = commit_to_committer_cache::<G2Curve, G2Scheme, _>(
+            &cache,
+            &Semaphore::new(1),
+            Some(&member_limiter),
+            &public.eval(0).value,
+            0,
+            Address::from_low_u64_be(1),
+            req,
+        )
+        .await
+        .unwrap_err();
 
-    let (mut health_reporter, health_service) = tonic_health::server::health_reporter();
-    health_reporter
-        .set_serving::<CommitterServiceServer<BLSCommitterServiceServer<PC, S>>>()
-        .await;
+        assert_eq!(Code::Internal, status.code());
+        // nor does it use up the quota of the member
+        assert!(member_limiter.check(Address::from_low_u64_be(1)));
+    }
 
-    Server::builder()
-        .add_service(CommitterServiceServer::with_interceptor(
-            BLSCommitterServiceServer::new(id_address, group_cache, context),
-            intercept,
-        ))
-        .add_service(health_service)
-        .serve(addr)
-        .await?;
+    #[tokio::test]
+    async fn test_aggregate_group_relay_signature_at_threshold() {
+        let task = GroupRelayTask::new(10, 5, 1, 3, 100);
+        let message = b"message";
+        let cache = build_group_relay_result_cache(&task, message).await;
+        let (private, public) = build_keys();
+
+        for index in 0..THRESHOLD as Idx {
+            assert!(cache
+                .write()
+                .await
+                .get_ready_to_commit_signatures(0)
+                .await
+                .unwrap()
+                .is_empty());
+
+            let req = build_request(
+                &private,
+                index,
+                BLSTaskType::GroupRelay,
+                &task.request_id,
+                message,
+            );
+
+            commit_to_committer_cache::<G2Curve, G2Scheme, _>(
+                &cache,
+                &Semaphore::new(1),
+                None,
+                &public.eval(index).value,
+                index as usize,
+                Address::from_low_u64_be(index as u64 + 1),
+                req,
+            )
+            .await
+            .unwrap();
+        }
 
-    Ok(())
-}
+        let ready = cache
+            .write()
+            .await
+            .get_ready_to_commit_signatures(0)
+            .await
+            .unwrap();
 
-fn intercept(req: Request<()>) -> Result<Request<()>, Status> {
-    // println!("Intercepting request: {:?}", req);
+        assert_eq!(1, ready.len());
+        assert_eq!(task, ready[0].group_relay_task);
 
-    Ok(req)
+        let partials = ready[0]
+            .partial_signatures
+            .values()
+            .map(|partial| partial.signed_partial_signature.clone())
+            .collect::<Vec<_>>();
+
+        let signature =
+            SimpleBLSCore::<G2Curve, G2Scheme>::aggregate(ready[0].threshold, &partials).unwrap();
+
+        SimpleBLSCore::<G2Curve, G2Scheme>::verify(public.public_key(), message, &signature)
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_aggregate_group_relay_confirmation_signature_at_threshold() {
+        let task = GroupRelayConfirmationTask::new(2, 1, 3, 0, 100);
+        let message = b"message";
+        let (private, public) = build_keys();
+
+        let mut cache = InMemorySignatureResultCache::<GroupRelayConfirmationResultCache>::new();
+        cache
+            .add(GROUP_INDEX, task.clone(), message.to_vec(), THRESHOLD)
+            .await
+            .unwrap();
+        let cache: RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>> =
+            RwLock::new(Box::new(cache));
+
+        let mismatched = build_request(
+            &private,
+            0,
+            BLSTaskType::GroupRelayConfirmation,
+            &task.request_id,
+            b"another message",
+        );
+
+        assert!(commit_to_committer_cache::<G2Curve, G2Scheme, _>(
+            &cache,
+            &Semaphore::new(1),
+            None,
+            &public.eval(0).value,
+            0,
+            Address::from_low_u64_be(1),
+            mismatched,
+        )
+        .await
+        .is_err());
+
+        for index in 0..THRESHOLD as Idx {
+            let req = build_request(
+                &private,
+                index,
+                BLSTaskType::GroupRelayConfirmation,
+                &task.request_id,
+                message,
+            );
+
+            commit_to_committer_cache::<G2Curve, G2Scheme, _>(
+                &cache,
+                &Semaphore::new(1),
+                None,
+                &public.eval(index).value,
+                index as usize,
+                Address::from_low_u64_be(index as u64 + 1),
+                req,
+            )
+            .await
+            .unwrap();
+        }
+
+        let ready = cache
+            .write()
+            .await
+            .get_ready_to_commit_signatures(0)
+            .await
+            .unwrap();
+
+        assert_eq!(1, ready.len());
+
+        let partials = ready[0]
+            .partial_signatures
+            .values()
+            .map(|partial| partial.signed_partial_signature.clone())
+            .collect::<Vec<_>>();
+
+        let signature =
+            SimpleBLSCore::<G2Curve, G2Scheme>::aggregate(ready[0].threshold, &partials).unwrap();
+
+        SimpleBLSCore::<G2Curve, G2Scheme>::verify(public.public_key(), message, &signature)
+            .unwrap();
+    }
 }
@@ -0,0 +1,428 @@
+use crate::error::{NodeError, NodeResult};
+use arpa_core::CommitterTlsDescriptor;
+use futures::Stream;
+use hyper::Uri;
+use log::{debug, error};
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufReader},
+    net::IpAddr,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+use tokio_rustls::{
+    rustls::{
+        server::AllowAnyAuthenticatedClient, Certificate, ClientConfig, PrivateKey, RootCertStore,
+        ServerConfig, ServerName,
+    },
+    server::TlsStream,
+    TlsAcceptor, TlsConnector,
+};
+use tonic::transport::{
+    server::{Connected, TcpConnectInfo},
+    Channel, Endpoint,
+};
+
+/// A client that doesn't finish the handshake in time is dropped.
+const TLS_HANDSHAKE_TIMEOUT_MILLIS: u64 = 3000;
+
+const TLS_ACCEPT_RETRY_INTERVAL_MILLIS: u64 = 100;
+
+const TLS_ACCEPT_BACKLOG: usize = 128;
+
+/// The connections from an address accepted while this many handshakes from the same address are
+/// pending are dropped, so that a peer which never finishes the handshake can't exhaust the tasks
+/// and the sockets of the node, nor lock the other members out.
+const TLS_MAX_PENDING_HANDSHAKES_PER_IP: usize = 8;
+
+const ALPN_H2: &[u8] = b"h2";
+
+/// Mutual TLS of the committer service, where the servers and the clients of all the nodes
+/// present a certificate issued by the same CA.
+#[derive(Clone)]
+pub struct CommitterTls {
+    client_config: Arc<ClientConfig>,
+    server_config: Arc<ServerConfig>,
+}
+
+impl std::fmt::Debug for CommitterTls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CommitterTls").finish_non_exhaustive()
+    }
+}
+
+impl CommitterTls {
+    pub fn load(descriptor: &CommitterTlsDescriptor) -> NodeResult<Self> {
+        let mut roots = RootCertStore::empty();
+        for ca_cert in load_certs(&descriptor.ca_cert_path)? {
+            roots.add(&ca_cert).map_err(|e| {
+                NodeError::TlsConfigError(format!("{}: {}", descriptor.ca_cert_path, e))
+            })?;
+        }
+
+        let certs = load_certs(&descriptor.cert_path)?;
+
+        let key = load_private_key(&descriptor.key_path)?;
+
+        let mut server_config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_client_cert_verifier(AllowAnyAuthenticatedClient::new(roots.clone()).boxed())
+            .with_single_cert(certs.clone(), key.clone())
+            .map_err(|e| NodeError::TlsConfigError(e.to_string()))?;
+        server_config.alpn_protocols = vec![ALPN_H2.to_vec()];
+
+        let mut client_config = ClientConfig::builder()
+            .with_safe_defaults()
+            .with_root_certificates(roots)
+            .with_client_auth_cert(certs, key)
+            .map_err(|e| NodeError::TlsConfigError(e.to_string()))?;
+        client_config.alpn_protocols = vec![ALPN_H2.to_vec()];
+
+        Ok(CommitterTls {
+            client_config: Arc::new(client_config),
+            server_config: Arc::new(server_config),
+        })
+    }
+
+    /// Connects to the committer service at the endpoint, whose certificate must name the
+    /// host of the endpoint.
+    pub(crate) async fn connect(
+        &self,
+        endpoint: Endpoint,
+    ) -> Result<Channel, tonic::transport::Error> {
+        let connector = TlsConnector::from(self.client_config.clone());
+
+        endpoint
+            .connect_with_connector(tower::service_fn(move |uri: Uri| {
+                let connector = connector.clone();
+                async move {
+                    let host = uri
+                        .host()
+                        .map(|host| host.trim_start_matches('[').trim_end_matches(']'))
+                        .ok_or_else(|| invalid_endpoint("the endpoint has no host"))?;
+
+                    let port = uri
+                        .port_u16()
+                        .ok_or_else(|| invalid_endpoint("the endpoint has no port"))?;
+
+                    let server_name = ServerName::try_from(host)
+                        .map_err(|_| invalid_endpoint("the host of the endpoint is invalid"))?;
+
+                    let stream = TcpStream::connect((host, port)).await?;
+
+                    connector.connect(server_name, stream).await
+                }
+            }))
+            .await
+    }
+
+    /// Accepts the connections to the committer service on the listener. A client that fails
+    /// the handshake, e.g. as its certificate is not issued by the CA, is dropped without
+    /// affecting the others.
+    pub(crate) fn incoming(
+        &self,
+        listener: TcpListener,
+    ) -> impl Stream<Item = Result<TlsConnection, io::Error>> {
+        self.incoming_with_max_pending_handshakes(listener, TLS_MAX_PENDING_HANDSHAKES_PER_IP)
+    }
+
+    fn incoming_with_max_pending_handshakes(
+        &self,
+        listener: TcpListener,
+        max_pending_handshakes_per_ip: usize,
+    ) -> impl Stream<Item = Result<TlsConnection, io::Error>> {
+        let acceptor = TlsAcceptor::from(self.server_config.clone());
+
+        let pending_handshakes = PendingHandshakes::default();
+
+        let (sender, receiver) = mpsc::channel(TLS_ACCEPT_BACKLOG);
+
+        tokio::spawn(async move {
+            loop {
+                let (stream, remote_addr) = tokio::select! {
+                    _ = sender.closed() => break,
+                    accepted = listener.accept() => match accepted {
+                        Ok(accepted) => accepted,
+                        Err(e) => {
+                            error!("Failed to accept a committer connection: {:?}", e);
+                            tokio::time::sleep(Duration::from_millis(
+                                TLS_ACCEPT_RETRY_INTERVAL_MILLIS,
+                            ))
+                            .await;
+                            continue;
+                        }
+                    },
+                };
+
+                let permit = match pending_handshakes
+                    .try_acquire(remote_addr.ip(), max_pending_handshakes_per_ip)
+                {
+                    Some(permit) => permit,
+                    None => {
+                        debug!(
+                            "Too many pending TLS handshakes from {}, dropped the connection",
+                            remote_addr
+                        );
+                        continue;
+                    }
+                };
+
+                let acceptor = acceptor.clone();
+                let sender = sender.clone();
+
+                tokio::spawn(async move {
+                    match tokio::time::timeout(
+                        Duration::from_millis(TLS_HANDSHAKE_TIMEOUT_MILLIS),
+                        acceptor.accept(stream),
+                    )
+                    .await
+                    {
+                        Ok(Ok(stream)) => {
+                            drop(permit);
+                            let _ = sender.send(Ok(TlsConnection(stream))).await;
+                        }
+                        Ok(Err(e)) => {
+                            debug!("TLS handshake with {} failed: {:?}", remote_addr, e)
+                        }
+                        Err(_) => debug!("TLS handshake with {} timed out", remote_addr),
+                    }
+                });
+            }
+        });
+
+        futures::stream::unfold(receiver, |mut receiver| async move {
+            receiver
+                .recv()
+                .await
+                .map(|connection| (connection, receiver))
+        })
+    }
+}
+
+/// The number of pending handshakes of every remote address.
+#[derive(Clone, Default)]
+struct PendingHandshakes(Arc<Mutex<HashMap<IpAddr, usize>>>);
+
+impl PendingHandshakes {
+    fn try_acquire(&self, ip: IpAddr, max_per_ip: usize) -> Option<PendingHandshake> {
+        let mut pending = self.0.lock().unwrap();
+        let count = pending.entry(ip).or_default();
+        if *count >= max_per_ip {
+            return None;
+        }
+        *count += 1;
+
+        Some(PendingHandshake {
+            pending_handshakes: self.clone(),
+            ip,
+        })
+    }
+}
+
+/// A pending handshake, which is no longer counted when dropped.
+struct PendingHandshake {
+    pending_handshakes: PendingHandshakes,
+    ip: IpAddr,
+}
+
+impl Drop for PendingHandshake {
+    fn drop(&mut self) {
+        let mut pending = self.pending_handshakes.0.lock().unwrap();
+        if let Some(count) = pending.get_mut(&self.ip) {
+            *count -= 1;
+            if *count == 0 {
+                pending.remove(&self.ip);
+            }
+        }
+    }
+}
+
+/// Connects to the committer service, over mutual TLS if it is configured.
+pub(crate) async fn connect_committer_service(
+    endpoint: Endpoint,
+    tls: Option<&CommitterTls>,
+) -> Result<Channel, tonic::transport::Error> {
+    match tls {
+        Some(tls) => tls.connect(endpoint).await,
+        None => endpoint.connect().await,
+    }
+}
+
+pub(crate) struct TlsConnection(TlsStream<TcpStream>);
+
+impl Connected for TlsConnection {
+    type ConnectInfo = TcpConnectInfo;
+
+    fn connect_info(&self) -> Self::ConnectInfo {
+        self.0.get_ref().0.connect_info()
+    }
+}
+
+impl AsyncRead for TlsConnection {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for TlsConnection {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.0).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.0).poll_shutdown(cx)
+    }
+}
+
+fn invalid_endpoint(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, reason)
+}
+
+fn load_certs(path: &str) -> NodeResult<Vec<Certificate>> {
+    let certs = File::open(path)
+        .and_then(|file| rustls_pemfile::certs(&mut BufReader::new(file)))
+        .map_err(|e| NodeError::TlsConfigError(format!("{}: {}", path, e)))?;
+
+    if certs.is_empty() {
+        return Err(NodeError::TlsConfigError(format!(
+            "{}: no certificate found",
+            path
+        )));
+    }
+
+    Ok(certs.into_iter().map(Certificate).collect())
+}
+
+fn load_private_key(path: &str) -> NodeResult<PrivateKey> {
+    let mut reader = BufReader::new(
+        File::open(path).map_err(|e| NodeError::TlsConfigError(format!("{}: {}", path, e)))?,
+    );
+
+    loop {
+        match rustls_pemfile::read_one(&mut reader)
+            .map_err(|e| NodeError::TlsConfigError(format!("{}: {}", path, e)))?
+        {
+            Some(rustls_pemfile::Item::RSAKey(key))
+            | Some(rustls_pemfile::Item::PKCS8Key(key))
+            | Some(rustls_pemfile::Item::ECKey(key)) => return Ok(PrivateKey(key)),
+            Some(_) => continue,
+            None => {
+                return Err(NodeError::TlsConfigError(format!(
+                    "{}: no private key found",
+                    path
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{connect_committer_service, CommitterTls};
+    use arpa_core::CommitterTlsDescriptor;
+    use std::time::Duration;
+    use tokio::net::{TcpListener, TcpSocket, TcpStream};
+    use tonic::transport::{Endpoint, Server};
+    use tonic_health::pb::{health_client::HealthClient, HealthCheckRequest};
+
+    fn descriptor(name: &str) -> CommitterTlsDescriptor {
+        let path = |file: &str| format!("{}/test/tls/{}", env!("CARGO_MANIFEST_DIR"), file);
+        CommitterTlsDescriptor {
+            ca_cert_path: path("ca.pem"),
+            cert_path: path(&format!("{}.pem", name)),
+            key_path: path(&format!("{}.key", name)),
+        }
+    }
+
+    async fn check_health(endpoint: &str, tls: Option<&CommitterTls>) -> bool {
+        let endpoint = Endpoint::new(format!("http://{}", endpoint)).unwrap();
+        match connect_committer_service(endpoint, tls).await {
+            Ok(channel) => HealthClient::new(channel)
+                .check(HealthCheckRequest::default())
+                .await
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mutual_tls_rejects_clients_without_a_certificate_of_the_ca() {
+        let tls = CommitterTls::load(&descriptor("node")).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = listener.local_addr().unwrap().to_string();
+        let (_, health_service) = tonic_health::server::health_reporter();
+        tokio::spawn(
+            Server::builder()
+                .add_service(health_service)
+                .serve_with_incoming(tls.incoming(listener)),
+        );
+
+        assert!(check_health(&endpoint, Some(&tls)).await);
+
+        // a client whose certificate is issued by another CA
+        let untrusted_tls = CommitterTls::load(&descriptor("untrusted_node")).unwrap();
+        assert!(!check_health(&endpoint, Some(&untrusted_tls)).await);
+
+        // a client without TLS
+        assert!(!check_health(&endpoint, None).await);
+
+        // the server is still serving the others
+        assert!(check_health(&endpoint, Some(&tls)).await);
+    }
+
+    #[tokio::test]
+    async fn test_drop_connections_beyond_max_pending_handshakes_per_ip() {
+        let tls = CommitterTls::load(&descriptor("node")).unwrap();
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let endpoint = addr.to_string();
+        let (_, health_service) = tonic_health::server::health_reporter();
+        tokio::spawn(
+            Server::builder()
+                .add_service(health_service)
+                .serve_with_incoming(tls.incoming_with_max_pending_handshakes(listener, 1)),
+        );
+
+        // a client of another address that never starts the handshake doesn't affect the others
+        let socket = TcpSocket::new_v4().unwrap();
+        socket.bind("127.0.0.2:0".parse().unwrap()).unwrap();
+        let _stalled_elsewhere = socket.connect(addr).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(check_health(&endpoint, Some(&tls)).await);
+
+        // a client of the same address that never starts the handshake holds its only slot
+        let stalled = TcpStream::connect(&endpoint).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(!check_health(&endpoint, Some(&tls)).await);
+
+        // the slot is released once the stalled client goes away
+        drop(stalled);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(check_health(&endpoint, Some(&tls)).await);
+    }
+}
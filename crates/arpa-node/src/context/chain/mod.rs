@@ -1,7 +1,13 @@
 pub mod types;
-use crate::{queue::event_queue::EventQueue, scheduler::fixed::SimpleFixedTaskScheduler};
+use crate::{
+    board::DKGBoardStoreHandle,
+    queue::event_queue::EventQueue,
+    scheduler::{fixed::SimpleFixedTaskScheduler, FixedTaskScheduler},
+};
 
-use arpa_core::{ListenerDescriptor, SchedulerResult};
+use arpa_core::{
+    ComponentTaskType, ListenerDescriptor, SchedulerError, SchedulerResult, TimeLimitDescriptor,
+};
 use async_trait::async_trait;
 use std::sync::Arc;
 use threshold_bls::{
@@ -59,6 +65,93 @@ pub trait Chain<
     ) -> SchedulerResult<()>;
 
     async fn init_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
+
+    /// Applies the time limits and the listeners of the reloaded config to the running chain,
+    /// returning the changes applied.
+    async fn reload_components(
+        &mut self,
+        eq: Arc<RwLock<EventQueue>>,
+        fs: Arc<RwLock<SimpleFixedTaskScheduler>>,
+        time_limits: TimeLimitDescriptor,
+        listener_descriptors: Vec<ListenerDescriptor>,
+    ) -> SchedulerResult<Vec<String>>;
+
+    /// Restarts the listeners whose descriptors have changed, starts the added ones and shuts down
+    /// the removed ones. The listeners shut down by the management service are left as they are.
+    async fn reload_listeners(
+        &self,
+        eq: Arc<RwLock<EventQueue>>,
+        fs: Arc<RwLock<SimpleFixedTaskScheduler>>,
+        running: &[ListenerDescriptor],
+        reloaded: &[ListenerDescriptor],
+    ) -> SchedulerResult<Vec<String>>
+    where
+        Self: Sync,
+    {
+        let mut changes = vec![];
+
+        for listener in running {
+            if reloaded.iter().any(|l| l.l_type == listener.l_type) {
+                continue;
+            }
+
+            let aborted = fs
+                .write()
+                .await
+                .abort(&ComponentTaskType::Listener(self.id(), listener.l_type))
+                .await;
+
+            match aborted {
+                Ok(()) => changes.push(format!(
+                    "shut down the listener {} of chain {}",
+                    listener.l_type,
+                    self.id()
+                )),
+                Err(SchedulerError::TaskNotFound) => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        for listener in reloaded {
+            match running.iter().find(|l| l.l_type == listener.l_type) {
+                Some(running_listener) if running_listener == listener => {}
+                Some(_) => {
+                    let aborted = fs
+                        .write()
+                        .await
+                        .abort(&ComponentTaskType::Listener(self.id(), listener.l_type))
+                        .await;
+
+                    match aborted {
+                        Ok(()) => {
+                            self.init_listener(eq.clone(), fs.clone(), *listener)
+                                .await?;
+
+                            changes.push(format!(
+                                "restarted the listener {} of chain {}",
+                                listener.l_type,
+                                self.id()
+                            ));
+                        }
+                        Err(SchedulerError::TaskNotFound) => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                None => {
+                    self.init_listener(eq.clone(), fs.clone(), *listener)
+                        .await?;
+
+                    changes.push(format!(
+                        "started the listener {} of chain {}",
+                        listener.l_type,
+                        self.id()
+                    ));
+                }
+            }
+        }
+
+        Ok(changes)
+    }
 }
 
 #[async_trait]
@@ -67,6 +160,17 @@ pub trait MainChain<
     S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>,
 >: Chain<PC, S>
 {
+    type GroupRelayTasksQueue;
+    type GroupRelayResultCaches;
+
+    fn get_group_relay_tasks_cache(&self) -> Arc<RwLock<Self::GroupRelayTasksQueue>>;
+
+    fn get_group_relay_result_cache(&self) -> Arc<RwLock<Self::GroupRelayResultCaches>>;
+
+    /// The store of the off-chain DKG board, which the committer server serves whether or not
+    /// the node publishes its own bundles there.
+    fn get_dkg_board_store(&self) -> DKGBoardStoreHandle;
+
     async fn init_schedule_listeners(
         &self,
         context: &(dyn ContextFetcher + Sync + Send),
@@ -87,6 +191,11 @@ pub trait MainChain<
         context: &(dyn ContextFetcher + Sync + Send),
     ) -> SchedulerResult<()>;
 
+    async fn init_group_relay_listeners(
+        &self,
+        context: &(dyn ContextFetcher + Sync + Send),
+    ) -> SchedulerResult<()>;
+
     async fn init_schedule_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
 
     async fn init_block_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
@@ -94,6 +203,8 @@ pub trait MainChain<
     async fn init_dkg_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
 
     async fn init_randomness_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
+
+    async fn init_group_relay_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
 }
 
 #[async_trait]
@@ -102,6 +213,17 @@ pub trait RelayedChain<
     S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>,
 >: Chain<PC, S>
 {
+    type GroupRelayConfirmationTasksQueue;
+    type GroupRelayConfirmationResultCaches;
+
+    fn get_group_relay_confirmation_tasks_cache(
+        &self,
+    ) -> Arc<RwLock<Self::GroupRelayConfirmationTasksQueue>>;
+
+    fn get_group_relay_confirmation_result_cache(
+        &self,
+    ) -> Arc<RwLock<Self::GroupRelayConfirmationResultCaches>>;
+
     async fn init_block_listeners(
         &self,
         context: &(dyn ContextFetcher + Sync + Send),
@@ -112,7 +234,14 @@ pub trait RelayedChain<
         context: &(dyn ContextFetcher + Sync + Send),
     ) -> SchedulerResult<()>;
 
+    async fn init_group_relay_listeners(
+        &self,
+        context: &(dyn ContextFetcher + Sync + Send),
+    ) -> SchedulerResult<()>;
+
     async fn init_block_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
 
     async fn init_randomness_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
+
+    async fn init_group_relay_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send));
 }
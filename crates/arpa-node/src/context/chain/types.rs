@@ -1,38 +1,54 @@
 use super::{Chain, MainChain, RelayedChain};
 use crate::{
+    board::{DKGBoardStore, DKGBoardStoreHandle},
     context::{ChainIdentityHandlerType, ContextFetcher},
     listener::{
-        block::BlockListener, new_randomness_task::NewRandomnessTaskListener,
+        block::BlockListener,
+        group_relay_confirmation_signature_aggregation::GroupRelayConfirmationSignatureAggregationListener,
+        group_relay_signature_aggregation::GroupRelaySignatureAggregationListener,
+        new_group_relay_confirmation_task::NewGroupRelayConfirmationTaskListener,
+        new_group_relay_task::NewGroupRelayTaskListener,
+        new_randomness_task::NewRandomnessTaskListener,
         post_commit_grouping::PostCommitGroupingListener, post_grouping::PostGroupingListener,
         pre_grouping::PreGroupingListener,
         randomness_signature_aggregation::RandomnessSignatureAggregationListener,
+        ready_to_handle_group_relay_confirmation_task::ReadyToHandleGroupRelayConfirmationTaskListener,
+        ready_to_handle_group_relay_task::ReadyToHandleGroupRelayTaskListener,
         ready_to_handle_randomness_task::ReadyToHandleRandomnessTaskListener,
         schedule_node_activation::NodeActivationListener, Listener,
     },
     queue::event_queue::EventQueue,
     scheduler::{fixed::SimpleFixedTaskScheduler, TaskScheduler},
     subscriber::{
-        block::BlockSubscriber, in_grouping::InGroupingSubscriber,
-        post_grouping::PostGroupingSubscriber,
+        block::BlockSubscriber,
+        group_relay_confirmation_signature_aggregation::GroupRelayConfirmationSignatureAggregationSubscriber,
+        group_relay_signature_aggregation::GroupRelaySignatureAggregationSubscriber,
+        in_grouping::InGroupingSubscriber, post_grouping::PostGroupingSubscriber,
         post_success_grouping::PostSuccessGroupingSubscriber, pre_grouping::PreGroupingSubscriber,
         randomness_signature_aggregation::RandomnessSignatureAggregationSubscriber,
+        ready_to_handle_group_relay_confirmation_task::ReadyToHandleGroupRelayConfirmationTaskSubscriber,
+        ready_to_handle_group_relay_task::ReadyToHandleGroupRelayTaskSubscriber,
         ready_to_handle_randomness_task::ReadyToHandleRandomnessTaskSubscriber,
         schedule_node_activation::NodeActivationSubscriber, Subscriber,
     },
 };
 use arpa_core::{
-    ChainIdentity, ComponentTaskType, GeneralMainChainIdentity, GeneralRelayedChainIdentity,
-    ListenerDescriptor, ListenerType, RandomnessTask, SchedulerError, SchedulerResult,
-    TimeLimitDescriptor, DEFAULT_NODE_ACTIVATION_INTERVAL_MILLIS,
+    ChainIdentity, ComponentTaskType, ExponentialBackoffRetryDescriptor, GeneralMainChainIdentity,
+    GeneralRelayedChainIdentity, GroupRelayConfirmationTask, GroupRelayTask, ListenerDescriptor,
+    ListenerType, RandomnessTask, SchedulerError, SchedulerResult, TimeLimitDescriptor,
+    DEFAULT_NODE_ACTIVATION_INTERVAL_MILLIS,
+};
+use arpa_dal::cache::{
+    GroupRelayConfirmationResultCache, GroupRelayResultCache, InMemoryBlockInfoCache,
+    RandomnessResultCache,
 };
-use arpa_dal::cache::{InMemoryBlockInfoCache, RandomnessResultCache};
 use arpa_dal::{
-    BLSTasksHandler, BlockInfoHandler, GroupInfoHandler, NodeInfoHandler,
+    BLSTasksHandler, BlockCheckpointHandler, BlockInfoHandler, GroupInfoHandler, NodeInfoHandler,
     SignatureResultCacheHandler,
 };
 use async_trait::async_trait;
 use log::error;
-use std::{fmt::Display, marker::PhantomData, sync::Arc};
+use std::{collections::HashMap, fmt::Display, marker::PhantomData, sync::Arc};
 use threshold_bls::{
     group::Curve,
     sig::{SignatureScheme, ThresholdScheme},
@@ -65,6 +81,34 @@ async fn add_listener_task(
     )
 }
 
+/// Replaces the retry descriptors shared with the chain identity and the subscribers, returning
+/// whether any of them has changed.
+async fn reload_retry_descriptors<PC: Curve>(
+    chain_identity: &Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    commit_partial_signature_retry_descriptor: &Arc<RwLock<ExponentialBackoffRetryDescriptor>>,
+    running: &TimeLimitDescriptor,
+    reloaded: &TimeLimitDescriptor,
+) -> bool {
+    if running.contract_transaction_retry_descriptor
+        == reloaded.contract_transaction_retry_descriptor
+        && running.contract_view_retry_descriptor == reloaded.contract_view_retry_descriptor
+        && running.commit_partial_signature_retry_descriptor
+            == reloaded.commit_partial_signature_retry_descriptor
+    {
+        return false;
+    }
+
+    chain_identity.write().await.set_retry_descriptors(
+        reloaded.contract_transaction_retry_descriptor,
+        reloaded.contract_view_retry_descriptor,
+    );
+
+    *commit_partial_signature_retry_descriptor.write().await =
+        reloaded.commit_partial_signature_retry_descriptor;
+
+    true
+}
+
 #[derive(Debug)]
 pub struct GeneralMainChain<
     PC: Curve,
@@ -73,16 +117,27 @@ pub struct GeneralMainChain<
     id: usize,
     description: String,
     is_eigenlayer: bool,
+    is_dkg_resharing_enabled: bool,
+    is_group_relay_by_signature_enabled: bool,
+    is_dkg_off_chain_board_enabled: bool,
+    dkg_board_store: DKGBoardStoreHandle,
     chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    relayed_chain_identities: HashMap<usize, Arc<RwLock<ChainIdentityHandlerType<PC>>>>,
     node_cache: Arc<RwLock<Box<dyn NodeInfoHandler<PC>>>>,
     group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
     block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
     randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>>,
     committer_randomness_result_cache:
         Arc<RwLock<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>>>,
+    group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>>,
+    committer_group_relay_result_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>>,
+    block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>>,
+    adapter_deployed_block_height: usize,
     c: PhantomData<PC>,
     s: PhantomData<S>,
     time_limits: TimeLimitDescriptor,
+    commit_partial_signature_retry_descriptor: Arc<RwLock<ExponentialBackoffRetryDescriptor>>,
     listener_descriptors: Vec<ListenerDescriptor>,
 }
 
@@ -100,6 +155,9 @@ impl<
     pub fn new(
         description: String,
         is_eigenlayer: bool,
+        is_dkg_resharing_enabled: bool,
+        is_group_relay_by_signature_enabled: bool,
+        is_dkg_off_chain_board_enabled: bool,
         chain_identity: GeneralMainChainIdentity,
         node_cache: Arc<RwLock<Box<dyn NodeInfoHandler<PC>>>>,
         group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
@@ -107,6 +165,12 @@ impl<
         committer_randomness_result_cache: Arc<
             RwLock<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>>,
         >,
+        group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>>,
+        committer_group_relay_result_cache: Arc<
+            RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>,
+        >,
+        block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>>,
+        adapter_deployed_block_height: usize,
         time_limits: TimeLimitDescriptor,
         listener_descriptors: Vec<ListenerDescriptor>,
     ) -> Self {
@@ -115,21 +179,52 @@ impl<
             id: chain_id,
             description,
             is_eigenlayer,
+            is_dkg_resharing_enabled,
+            is_group_relay_by_signature_enabled,
+            is_dkg_off_chain_board_enabled,
+            dkg_board_store: Arc::new(RwLock::new(DKGBoardStore::new())),
             chain_identity: Arc::new(RwLock::new(Box::new(chain_identity))),
+            relayed_chain_identities: HashMap::new(),
             block_cache: Arc::new(RwLock::new(Box::new(InMemoryBlockInfoCache::new(
                 chain_id,
                 time_limits.block_time,
             )))),
             randomness_tasks_cache,
             committer_randomness_result_cache,
+            group_relay_tasks_cache,
+            committer_group_relay_result_cache,
+            block_checkpoint_cache,
+            adapter_deployed_block_height,
             node_cache,
             group_cache,
             c: PhantomData,
             s: PhantomData,
             time_limits,
+            commit_partial_signature_retry_descriptor: Arc::new(RwLock::new(
+                time_limits.commit_partial_signature_retry_descriptor,
+            )),
             listener_descriptors,
         }
     }
+
+    fn node_activation_listener_descriptor(&self) -> ListenerDescriptor {
+        ListenerDescriptor {
+            l_type: ListenerType::ScheduleNodeActivation,
+            interval_millis: DEFAULT_NODE_ACTIVATION_INTERVAL_MILLIS,
+            use_jitter: false,
+            reset_descriptor: self.time_limits.provider_reset_descriptor,
+        }
+    }
+
+    /// Registers the identity of a relayed chain, which the group relay is sent to.
+    pub fn add_relayed_chain_identity(
+        &mut self,
+        chain_id: usize,
+        chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    ) {
+        self.relayed_chain_identities
+            .insert(chain_id, chain_identity);
+    }
 }
 
 #[async_trait]
@@ -247,6 +342,8 @@ where
                     id_address,
                     self.get_chain_identity(),
                     self.get_randomness_tasks_cache(),
+                    self.block_checkpoint_cache.clone(),
+                    self.adapter_deployed_block_height,
                     eq,
                 );
 
@@ -283,6 +380,49 @@ where
 
                 add_listener_task(self.id(), p_randomness_signature_aggregation, listener, fs).await
             }
+            ListenerType::NewGroupRelayTask => {
+                let p_new_group_relay_task = NewGroupRelayTaskListener::new(
+                    self.id(),
+                    self.get_chain_identity(),
+                    self.relayed_chain_identities.keys().copied().collect(),
+                    self.get_group_relay_tasks_cache(),
+                    eq,
+                );
+
+                add_listener_task(self.id(), p_new_group_relay_task, listener, fs).await
+            }
+            ListenerType::ReadyToHandleGroupRelayTask => {
+                let p_ready_to_handle_group_relay_task = ReadyToHandleGroupRelayTaskListener::new(
+                    self.id(),
+                    self.get_chain_identity(),
+                    self.get_block_cache(),
+                    self.get_group_cache(),
+                    self.get_group_relay_tasks_cache(),
+                    eq,
+                );
+
+                add_listener_task(self.id(), p_ready_to_handle_group_relay_task, listener, fs).await
+            }
+            ListenerType::GroupRelaySignatureAggregation => {
+                let id_address = self.get_node_cache().read().await.get_id_address().unwrap();
+
+                let p_group_relay_signature_aggregation =
+                    GroupRelaySignatureAggregationListener::new(
+                        self.id(),
+                        id_address,
+                        self.get_block_cache(),
+                        self.get_group_cache(),
+                        self.get_group_relay_result_cache(),
+                        eq,
+                    );
+
+                add_listener_task(self.id(), p_group_relay_signature_aggregation, listener, fs)
+                    .await
+            }
+            _ => Err(SchedulerError::UnsupportedListenerType(
+                self.id(),
+                listener.l_type.to_string(),
+            )),
         }
     }
 
@@ -298,6 +438,8 @@ where
 
         self.init_randomness_listeners(context).await?;
 
+        self.init_group_relay_listeners(context).await?;
+
         Ok(())
     }
 
@@ -309,6 +451,8 @@ where
         self.init_dkg_subscribers(context).await;
 
         self.init_randomness_subscribers(context).await;
+
+        self.init_group_relay_subscribers(context).await;
     }
 
     async fn init_components(
@@ -321,6 +465,47 @@ where
 
         Ok(())
     }
+
+    async fn reload_components(
+        &mut self,
+        eq: Arc<RwLock<EventQueue>>,
+        fs: Arc<RwLock<SimpleFixedTaskScheduler>>,
+        time_limits: TimeLimitDescriptor,
+        listener_descriptors: Vec<ListenerDescriptor>,
+    ) -> SchedulerResult<Vec<String>> {
+        let mut changes = vec![];
+
+        if reload_retry_descriptors(
+            &self.chain_identity,
+            &self.commit_partial_signature_retry_descriptor,
+            &self.time_limits,
+            &time_limits,
+        )
+        .await
+        {
+            changes.push(format!(
+                "updated the retry descriptors of chain {}",
+                self.id()
+            ));
+        }
+
+        // the node activation listener is not configured but resets the provider by the time limits
+        let mut running = self.listener_descriptors.clone();
+        let mut reloaded = listener_descriptors.clone();
+        if !self.is_eigenlayer {
+            running.push(self.node_activation_listener_descriptor());
+        }
+
+        self.time_limits = time_limits;
+        self.listener_descriptors = listener_descriptors;
+        if !self.is_eigenlayer {
+            reloaded.push(self.node_activation_listener_descriptor());
+        }
+
+        changes.extend(self.reload_listeners(eq, fs, &running, &reloaded).await?);
+
+        Ok(changes)
+    }
 }
 
 #[async_trait]
@@ -337,6 +522,24 @@ where
     <S as ThresholdScheme>::Error: Sync + Send,
     <S as SignatureScheme>::Error: Sync + Send,
 {
+    type GroupRelayTasksQueue = Box<dyn BLSTasksHandler<GroupRelayTask>>;
+
+    type GroupRelayResultCaches = Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>;
+
+    fn get_group_relay_tasks_cache(&self) -> Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>> {
+        self.group_relay_tasks_cache.clone()
+    }
+
+    fn get_group_relay_result_cache(
+        &self,
+    ) -> Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>> {
+        self.committer_group_relay_result_cache.clone()
+    }
+
+    fn get_dkg_board_store(&self) -> DKGBoardStoreHandle {
+        self.dkg_board_store.clone()
+    }
+
     async fn init_schedule_listeners(
         &self,
         context: &(dyn ContextFetcher + Sync + Send),
@@ -345,12 +548,7 @@ where
             self.init_listener(
                 context.get_event_queue(),
                 context.get_fixed_task_handler(),
-                ListenerDescriptor {
-                    l_type: ListenerType::ScheduleNodeActivation,
-                    interval_millis: DEFAULT_NODE_ACTIVATION_INTERVAL_MILLIS,
-                    use_jitter: false,
-                    reset_descriptor: self.time_limits.provider_reset_descriptor,
-                },
+                self.node_activation_listener_descriptor(),
             )
             .await?;
         }
@@ -418,6 +616,27 @@ where
         Ok(())
     }
 
+    async fn init_group_relay_listeners(
+        &self,
+        context: &(dyn ContextFetcher + Sync + Send),
+    ) -> SchedulerResult<()> {
+        for listener in self.listener_descriptors.iter() {
+            if listener.l_type == ListenerType::NewGroupRelayTask
+                || listener.l_type == ListenerType::ReadyToHandleGroupRelayTask
+                || listener.l_type == ListenerType::GroupRelaySignatureAggregation
+            {
+                self.init_listener(
+                    context.get_event_queue(),
+                    context.get_fixed_task_handler(),
+                    *listener,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn init_schedule_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send)) {
         let s_node_activation = NodeActivationSubscriber::<PC>::new(
             self.get_chain_identity(),
@@ -428,8 +647,16 @@ where
     }
 
     async fn init_block_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send)) {
-        let s_block =
-            BlockSubscriber::new(self.id(), self.get_block_cache(), context.get_event_queue());
+        let id_address = self.get_node_cache().read().await.get_id_address().unwrap();
+
+        let s_block = BlockSubscriber::<PC>::new(
+            self.id(),
+            id_address,
+            self.get_chain_identity(),
+            self.get_block_cache(),
+            self.get_randomness_tasks_cache(),
+            context.get_event_queue(),
+        );
 
         s_block.subscribe().await;
     }
@@ -447,6 +674,10 @@ where
             context.get_event_queue(),
             context.get_dynamic_task_handler(),
             self.time_limits.dkg_wait_for_phase_interval_millis,
+            self.is_dkg_resharing_enabled,
+            self.is_dkg_off_chain_board_enabled,
+            self.get_dkg_board_store(),
+            context.get_committer_credentials().clone(),
         );
 
         s_in_grouping.subscribe().await;
@@ -462,6 +693,7 @@ where
             self.get_group_cache(),
             context.get_event_queue(),
             context.get_dynamic_task_handler(),
+            self.is_group_relay_by_signature_enabled,
         );
 
         s_post_grouping.subscribe().await;
@@ -478,7 +710,8 @@ where
             self.get_randomness_result_cache(),
             context.get_event_queue(),
             context.get_dynamic_task_handler(),
-            self.time_limits.commit_partial_signature_retry_descriptor,
+            self.commit_partial_signature_retry_descriptor.clone(),
+            context.get_committer_credentials().clone(),
         );
 
         s_ready_to_handle_randomness_task.subscribe().await;
@@ -488,6 +721,7 @@ where
                 self.id(),
                 id_address,
                 self.get_chain_identity(),
+                self.get_group_cache(),
                 self.get_block_cache(),
                 self.get_randomness_result_cache(),
                 context.get_event_queue(),
@@ -496,6 +730,38 @@ where
 
         s_randomness_signature_aggregation.subscribe().await;
     }
+
+    async fn init_group_relay_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send)) {
+        let id_address = self.get_node_cache().read().await.get_id_address().unwrap();
+
+        let s_ready_to_handle_group_relay_task =
+            ReadyToHandleGroupRelayTaskSubscriber::<PC, S>::new(
+                self.id(),
+                id_address,
+                self.get_chain_identity(),
+                self.relayed_chain_identities.clone(),
+                self.get_group_cache(),
+                self.get_group_relay_tasks_cache(),
+                self.get_group_relay_result_cache(),
+                context.get_event_queue(),
+                context.get_dynamic_task_handler(),
+                self.commit_partial_signature_retry_descriptor.clone(),
+                context.get_committer_credentials().clone(),
+            );
+
+        s_ready_to_handle_group_relay_task.subscribe().await;
+
+        let s_group_relay_signature_aggregation =
+            GroupRelaySignatureAggregationSubscriber::<PC, S>::new(
+                self.id(),
+                self.relayed_chain_identities.clone(),
+                self.get_group_relay_result_cache(),
+                context.get_event_queue(),
+                context.get_dynamic_task_handler(),
+            );
+
+        s_group_relay_signature_aggregation.subscribe().await;
+    }
 }
 
 #[derive(Debug)]
@@ -512,9 +778,16 @@ pub struct GeneralRelayedChain<
     randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>>,
     committer_randomness_result_cache:
         Arc<RwLock<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>>>,
+    group_relay_confirmation_tasks_cache:
+        Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>>>,
+    committer_group_relay_confirmation_result_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>>,
+    block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>>,
+    adapter_deployed_block_height: usize,
     c: PhantomData<PC>,
     s: PhantomData<S>,
     time_limits: TimeLimitDescriptor,
+    commit_partial_signature_retry_descriptor: Arc<RwLock<ExponentialBackoffRetryDescriptor>>,
     listener_descriptors: Vec<ListenerDescriptor>,
 }
 
@@ -538,6 +811,14 @@ impl<
         committer_randomness_result_cache: Arc<
             RwLock<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>>,
         >,
+        group_relay_confirmation_tasks_cache: Arc<
+            RwLock<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>>,
+        >,
+        committer_group_relay_confirmation_result_cache: Arc<
+            RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>,
+        >,
+        block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>>,
+        adapter_deployed_block_height: usize,
         time_limits: TimeLimitDescriptor,
         listener_descriptors: Vec<ListenerDescriptor>,
     ) -> Self {
@@ -553,11 +834,18 @@ impl<
             )))),
             randomness_tasks_cache,
             committer_randomness_result_cache,
+            group_relay_confirmation_tasks_cache,
+            committer_group_relay_confirmation_result_cache,
+            block_checkpoint_cache,
+            adapter_deployed_block_height,
             node_cache,
             group_cache,
             c: PhantomData,
             s: PhantomData,
             time_limits,
+            commit_partial_signature_retry_descriptor: Arc::new(RwLock::new(
+                time_limits.commit_partial_signature_retry_descriptor,
+            )),
             listener_descriptors,
         }
     }
@@ -642,6 +930,8 @@ where
                     id_address,
                     self.get_chain_identity(),
                     self.get_randomness_tasks_cache(),
+                    self.block_checkpoint_cache.clone(),
+                    self.adapter_deployed_block_height,
                     eq,
                 );
 
@@ -678,6 +968,57 @@ where
 
                 add_listener_task(self.id, p_randomness_signature_aggregation, listener, fs).await
             }
+            ListenerType::NewGroupRelayConfirmationTask => {
+                let p_new_group_relay_confirmation_task =
+                    NewGroupRelayConfirmationTaskListener::new(
+                        self.id(),
+                        self.get_chain_identity(),
+                        self.get_group_relay_confirmation_tasks_cache(),
+                        eq,
+                    );
+
+                add_listener_task(self.id, p_new_group_relay_confirmation_task, listener, fs).await
+            }
+            ListenerType::ReadyToHandleGroupRelayConfirmationTask => {
+                let p_ready_to_handle_group_relay_confirmation_task =
+                    ReadyToHandleGroupRelayConfirmationTaskListener::new(
+                        self.id(),
+                        self.get_chain_identity(),
+                        self.get_block_cache(),
+                        self.get_group_cache(),
+                        self.get_group_relay_confirmation_tasks_cache(),
+                        eq,
+                    );
+
+                add_listener_task(
+                    self.id,
+                    p_ready_to_handle_group_relay_confirmation_task,
+                    listener,
+                    fs,
+                )
+                .await
+            }
+            ListenerType::GroupRelayConfirmationSignatureAggregation => {
+                let id_address = self.get_node_cache().read().await.get_id_address().unwrap();
+
+                let p_group_relay_confirmation_signature_aggregation =
+                    GroupRelayConfirmationSignatureAggregationListener::new(
+                        self.id(),
+                        id_address,
+                        self.get_block_cache(),
+                        self.get_group_cache(),
+                        self.get_group_relay_confirmation_result_cache(),
+                        eq,
+                    );
+
+                add_listener_task(
+                    self.id,
+                    p_group_relay_confirmation_signature_aggregation,
+                    listener,
+                    fs,
+                )
+                .await
+            }
             _ => {
                 return Err(SchedulerError::UnsupportedListenerType(
                     self.id(),
@@ -695,6 +1036,8 @@ where
 
         self.init_randomness_listeners(context).await?;
 
+        self.init_group_relay_listeners(context).await?;
+
         Ok(())
     }
 
@@ -702,6 +1045,8 @@ where
         self.init_block_subscribers(context).await;
 
         self.init_randomness_subscribers(context).await;
+
+        self.init_group_relay_subscribers(context).await;
     }
 
     async fn init_components(
@@ -714,6 +1059,40 @@ where
 
         Ok(())
     }
+
+    async fn reload_components(
+        &mut self,
+        eq: Arc<RwLock<EventQueue>>,
+        fs: Arc<RwLock<SimpleFixedTaskScheduler>>,
+        time_limits: TimeLimitDescriptor,
+        listener_descriptors: Vec<ListenerDescriptor>,
+    ) -> SchedulerResult<Vec<String>> {
+        let mut changes = vec![];
+
+        if reload_retry_descriptors(
+            &self.chain_identity,
+            &self.commit_partial_signature_retry_descriptor,
+            &self.time_limits,
+            &time_limits,
+        )
+        .await
+        {
+            changes.push(format!(
+                "updated the retry descriptors of chain {}",
+                self.id()
+            ));
+        }
+
+        let running = std::mem::replace(&mut self.listener_descriptors, listener_descriptors);
+        self.time_limits = time_limits;
+
+        changes.extend(
+            self.reload_listeners(eq, fs, &running, &self.listener_descriptors)
+                .await?,
+        );
+
+        Ok(changes)
+    }
 }
 
 #[async_trait]
@@ -730,6 +1109,23 @@ where
     <S as ThresholdScheme>::Error: Sync + Send,
     <S as SignatureScheme>::Error: Sync + Send,
 {
+    type GroupRelayConfirmationTasksQueue = Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>;
+
+    type GroupRelayConfirmationResultCaches =
+        Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>;
+
+    fn get_group_relay_confirmation_tasks_cache(
+        &self,
+    ) -> Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>>> {
+        self.group_relay_confirmation_tasks_cache.clone()
+    }
+
+    fn get_group_relay_confirmation_result_cache(
+        &self,
+    ) -> Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>> {
+        self.committer_group_relay_confirmation_result_cache.clone()
+    }
+
     async fn init_block_listeners(
         &self,
         context: &(dyn ContextFetcher + Sync + Send),
@@ -769,9 +1165,38 @@ where
         Ok(())
     }
 
+    async fn init_group_relay_listeners(
+        &self,
+        context: &(dyn ContextFetcher + Sync + Send),
+    ) -> SchedulerResult<()> {
+        for listener in self.listener_descriptors.iter() {
+            if listener.l_type == ListenerType::NewGroupRelayConfirmationTask
+                || listener.l_type == ListenerType::ReadyToHandleGroupRelayConfirmationTask
+                || listener.l_type == ListenerType::GroupRelayConfirmationSignatureAggregation
+            {
+                self.init_listener(
+                    context.get_event_queue(),
+                    context.get_fixed_task_handler(),
+                    *listener,
+                )
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     async fn init_block_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send)) {
-        let s_block =
-            BlockSubscriber::new(self.id(), self.get_block_cache(), context.get_event_queue());
+        let id_address = self.get_node_cache().read().await.get_id_address().unwrap();
+
+        let s_block = BlockSubscriber::<PC>::new(
+            self.id(),
+            id_address,
+            self.get_chain_identity(),
+            self.get_block_cache(),
+            self.get_randomness_tasks_cache(),
+            context.get_event_queue(),
+        );
 
         s_block.subscribe().await;
     }
@@ -787,7 +1212,8 @@ where
             self.get_randomness_result_cache(),
             context.get_event_queue(),
             context.get_dynamic_task_handler(),
-            self.time_limits.commit_partial_signature_retry_descriptor,
+            self.commit_partial_signature_retry_descriptor.clone(),
+            context.get_committer_credentials().clone(),
         );
 
         s_ready_to_handle_randomness_task.subscribe().await;
@@ -797,6 +1223,7 @@ where
                 self.id(),
                 id_address,
                 self.get_chain_identity(),
+                self.get_group_cache(),
                 self.get_block_cache(),
                 self.get_randomness_result_cache(),
                 context.get_event_queue(),
@@ -805,4 +1232,38 @@ where
 
         s_randomness_signature_aggregation.subscribe().await;
     }
+    async fn init_group_relay_subscribers(&self, context: &(dyn ContextFetcher + Sync + Send)) {
+        let id_address = self.get_node_cache().read().await.get_id_address().unwrap();
+
+        let s_ready_to_handle_group_relay_confirmation_task =
+            ReadyToHandleGroupRelayConfirmationTaskSubscriber::<PC, S>::new(
+                self.id(),
+                id_address,
+                self.get_chain_identity(),
+                self.get_group_cache(),
+                self.get_group_relay_confirmation_tasks_cache(),
+                self.get_group_relay_confirmation_result_cache(),
+                context.get_event_queue(),
+                context.get_dynamic_task_handler(),
+                self.commit_partial_signature_retry_descriptor.clone(),
+                context.get_committer_credentials().clone(),
+            );
+
+        s_ready_to_handle_group_relay_confirmation_task
+            .subscribe()
+            .await;
+
+        let s_group_relay_confirmation_signature_aggregation =
+            GroupRelayConfirmationSignatureAggregationSubscriber::<PC, S>::new(
+                self.id(),
+                self.get_chain_identity(),
+                self.get_group_relay_confirmation_result_cache(),
+                context.get_event_queue(),
+                context.get_dynamic_task_handler(),
+            );
+
+        s_group_relay_confirmation_signature_aggregation
+            .subscribe()
+            .await;
+    }
 }
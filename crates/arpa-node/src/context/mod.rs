@@ -4,6 +4,7 @@ pub mod types;
 use self::{chain::RelayedChain, types::ContextHandle};
 
 use crate::{
+    committer::CommitterCredentials,
     error::NodeResult,
     queue::event_queue::EventQueue,
     scheduler::{dynamic::SimpleDynamicTaskScheduler, fixed::SimpleFixedTaskScheduler},
@@ -21,8 +22,11 @@ use arpa_contract_client::{
     },
     node_registry::NodeRegistryClientBuilder,
 };
-use arpa_core::{ChainIdentity, ChainProviderManager, Config, RandomnessTask, SchedulerResult};
-use arpa_dal::cache::RandomnessResultCache;
+use arpa_core::{
+    ChainIdentity, ChainProviderManager, Config, GroupRelayConfirmationTask, RandomnessTask,
+    SchedulerResult,
+};
+use arpa_dal::cache::{GroupRelayConfirmationResultCache, RandomnessResultCache};
 use arpa_dal::{
     BLSTasksHandler, BlockInfoHandler, GroupInfoHandler, NodeInfoHandler,
     SignatureResultCacheHandler,
@@ -72,6 +76,10 @@ pub type RelayedChainType<PC, S> = Box<
             RandomnessTasksQueue = Box<dyn BLSTasksHandler<RandomnessTask>>,
             RandomnessResultCaches = Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>,
             ChainIdentity = ChainIdentityHandlerType<PC>,
+            GroupRelayConfirmationTasksQueue = Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>,
+            GroupRelayConfirmationResultCaches = Box<
+                dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>,
+            >,
         > + Sync
         + Send,
 >;
@@ -85,10 +93,16 @@ pub trait Context<
 
     fn get_main_chain(&self) -> &Self::MainChain;
 
+    fn get_main_chain_mut(&mut self) -> &mut Self::MainChain;
+
     fn contains_relayed_chain(&self, index: usize) -> bool;
 
     fn get_relayed_chain(&self, index: usize) -> Option<&RelayedChainType<PC, S>>;
 
+    fn get_relayed_chain_mut(&mut self, index: usize) -> Option<&mut RelayedChainType<PC, S>>;
+
+    fn set_config(&mut self, config: Config);
+
     fn add_relayed_chain(&mut self, relayed_chain: RelayedChainType<PC, S>) -> NodeResult<()>;
 
     async fn deploy(self) -> SchedulerResult<ContextHandle>;
@@ -109,6 +123,8 @@ pub trait ContextFetcher {
     fn get_event_queue(&self) -> Arc<RwLock<EventQueue>>;
 
     fn get_config(&self) -> &Config;
+
+    fn get_committer_credentials(&self) -> &CommitterCredentials;
 }
 
 pub(crate) trait CommitterServerStarter<
@@ -137,6 +153,15 @@ pub(crate) trait ManagementServerStarter<
     ) -> SchedulerResult<()>;
 }
 
+pub(crate) trait ConfigReloaderStarter<
+    C: Context<PC, S>,
+    PC: Curve,
+    S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>,
+>
+{
+    fn start_config_reloader(&mut self, context: Arc<RwLock<C>>) -> SchedulerResult<()>;
+}
+
 pub(crate) trait StatisticsServerStarter<
     C: Context<PC, S>,
     PC: Curve,
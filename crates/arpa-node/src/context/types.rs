@@ -1,14 +1,14 @@
 use super::{
     chain::{types::GeneralMainChain, Chain, RelayedChain},
     BLSTasksHandler, BlockInfoHandler, ChainIdentityHandler, ChainIdentityHandlerType,
-    CommitterServerStarter, Context, ContextFetcher, GroupInfoHandler, ManagementServerStarter,
-    NodeInfoHandler, RelayedChainType, SignatureResultCacheHandler, StatisticsServerStarter,
-    TaskWaiter,
+    CommitterServerStarter, ConfigReloaderStarter, Context, ContextFetcher, GroupInfoHandler,
+    ManagementServerStarter, NodeInfoHandler, RelayedChainType, SignatureResultCacheHandler,
+    StatisticsServerStarter, TaskWaiter,
 };
 use crate::{
-    committer::server as committer_server,
+    committer::{server as committer_server, CommitterCredentials},
     error::{NodeError, NodeResult},
-    management::server as management_server,
+    management::{reload as config_reloader, server as management_server},
     queue::event_queue::EventQueue,
     scheduler::{
         dynamic::SimpleDynamicTaskScheduler, fixed::SimpleFixedTaskScheduler, TaskScheduler,
@@ -17,10 +17,10 @@ use crate::{
 };
 use arpa_core::{
     ComponentTaskType, Config, GeneralMainChainIdentity, GeneralRelayedChainIdentity,
-    HttpServerType, RandomnessTask, RpcServerType, SchedulerResult,
+    GroupRelayConfirmationTask, HttpServerType, RandomnessTask, RpcServerType, SchedulerResult,
     DEFAULT_DYNAMIC_TASK_CLEANER_INTERVAL_MILLIS,
 };
-use arpa_dal::cache::RandomnessResultCache;
+use arpa_dal::cache::{GroupRelayConfirmationResultCache, RandomnessResultCache};
 use async_trait::async_trait;
 use std::{collections::HashMap, fmt::Debug, sync::Arc};
 use threshold_bls::{
@@ -40,6 +40,7 @@ pub struct GeneralContext<
     ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
     f_ts: Arc<RwLock<SimpleFixedTaskScheduler>>,
     config: Config,
+    committer_credentials: CommitterCredentials,
 }
 
 impl<
@@ -52,7 +53,11 @@ impl<
             + 'static,
     > GeneralContext<PC, S>
 {
-    pub fn new(main_chain: GeneralMainChain<PC, S>, config: Config) -> Self {
+    pub fn new(
+        main_chain: GeneralMainChain<PC, S>,
+        config: Config,
+        committer_credentials: CommitterCredentials,
+    ) -> Self {
         GeneralContext {
             main_chain,
             relayed_chains: HashMap::new(),
@@ -60,6 +65,7 @@ impl<
             ts: Arc::new(RwLock::new(SimpleDynamicTaskScheduler::new())),
             f_ts: Arc::new(RwLock::new(SimpleFixedTaskScheduler::new())),
             config,
+            committer_credentials,
         }
     }
 }
@@ -83,6 +89,10 @@ where
         &self.main_chain
     }
 
+    fn get_main_chain_mut(&mut self) -> &mut <GeneralContext<PC, S> as Context<PC, S>>::MainChain {
+        &mut self.main_chain
+    }
+
     fn contains_relayed_chain(&self, index: usize) -> bool {
         self.relayed_chains.contains_key(&index)
     }
@@ -103,6 +113,12 @@ where
                         dyn SignatureResultCacheHandler<RandomnessResultCache>,
                     >,
                     ChainIdentity = ChainIdentityHandlerType<PC>,
+                    GroupRelayConfirmationTasksQueue = Box<
+                        dyn BLSTasksHandler<GroupRelayConfirmationTask>,
+                    >,
+                    GroupRelayConfirmationResultCaches = Box<
+                        dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>,
+                    >,
                 > + Sync
                 + Send,
         >,
@@ -110,6 +126,14 @@ where
         self.relayed_chains.get(&index)
     }
 
+    fn get_relayed_chain_mut(&mut self, index: usize) -> Option<&mut RelayedChainType<PC, S>> {
+        self.relayed_chains.get_mut(&index)
+    }
+
+    fn set_config(&mut self, config: Config) {
+        self.config = config;
+    }
+
     fn add_relayed_chain(
         &mut self,
         relayed_chain: Box<
@@ -124,6 +148,12 @@ where
                         dyn SignatureResultCacheHandler<RandomnessResultCache>,
                     >,
                     ChainIdentity = ChainIdentityHandlerType<PC>,
+                    GroupRelayConfirmationTasksQueue = Box<
+                        dyn BLSTasksHandler<GroupRelayConfirmationTask>,
+                    >,
+                    GroupRelayConfirmationResultCaches = Box<
+                        dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>,
+                    >,
                 > + Sync
                 + Send,
         >,
@@ -134,6 +164,10 @@ where
             return Err(NodeError::RepeatedChainId);
         }
 
+        // the main chain relays groups to every relayed chain by threshold signatures
+        self.main_chain
+            .add_relayed_chain_identity(index, relayed_chain.get_chain_identity());
+
         self.relayed_chains.insert(index, relayed_chain);
 
         Ok(())
@@ -169,6 +203,8 @@ where
             .await
             .start_statistics_server(node_statistics_http_endpoint, context.clone())?;
 
+        f_ts.write().await.start_config_reloader(context.clone())?;
+
         let ts = context.read().await.get_dynamic_task_handler();
 
         Ok(ContextHandle { ts })
@@ -204,6 +240,10 @@ impl<
     fn get_config(&self) -> &Config {
         &self.config
     }
+
+    fn get_committer_credentials(&self) -> &CommitterCredentials {
+        &self.committer_credentials
+    }
 }
 pub struct ContextHandle {
     ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
@@ -213,15 +253,7 @@ pub struct ContextHandle {
 impl TaskWaiter for ContextHandle {
     async fn wait_task(&self) {
         loop {
-            while !self.ts.read().await.dynamic_tasks.is_empty() {
-                let (task_recv, task_monitor) = self.ts.write().await.dynamic_tasks.pop().unwrap();
-
-                let _ = task_recv.await;
-
-                if let Some(monitor) = task_monitor {
-                    monitor.abort();
-                }
-            }
+            self.ts.write().await.clean_finished_tasks();
 
             tokio::time::sleep(std::time::Duration::from_millis(
                 DEFAULT_DYNAMIC_TASK_CLEANER_INTERVAL_MILLIS,
@@ -306,5 +338,29 @@ where
     }
 }
 
+impl<
+        PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+    > ConfigReloaderStarter<GeneralContext<PC, S>, PC, S> for SimpleFixedTaskScheduler
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    fn start_config_reloader(
+        &mut self,
+        context: Arc<RwLock<GeneralContext<PC, S>>>,
+    ) -> SchedulerResult<()> {
+        self.add_task(
+            ComponentTaskType::ConfigReloader,
+            config_reloader::reload_config_on_hangup(context),
+        )
+    }
+}
+
 impl<PC: Curve + 'static> ChainIdentityHandler<PC> for GeneralMainChainIdentity {}
 impl<PC: Curve + 'static> ChainIdentityHandler<PC> for GeneralRelayedChainIdentity {}
@@ -1,9 +1,9 @@
 use arpa_contract_client::error::ContractClientError;
-use arpa_core::SchedulerError;
+use arpa_core::{ConfigError, SchedulerError};
 use arpa_dal::error::DataAccessError;
-use arpa_sqlite_db::DBError;
+use arpa_sea_orm_db::DBError;
 use dkg_core::{primitives::DKGError, DKGNodeError};
-use ethers::providers::ProviderError;
+use ethers::{providers::ProviderError, signers::WalletError};
 use rustc_hex::FromHexError;
 use thiserror::Error;
 use threshold_bls::sig::BLSError;
@@ -51,6 +51,9 @@ pub enum NodeError {
     #[error(transparent)]
     SchedulerError(#[from] SchedulerError),
 
+    #[error(transparent)]
+    ConfigError(#[from] ConfigError),
+
     #[error(transparent)]
     ContractClientError(#[from] ContractClientError),
 
@@ -89,4 +92,40 @@ pub enum NodeError {
 
     #[error("the group is not available since unbearable mismatch happened")]
     DKGGroupingTwisted,
+
+    #[error("the resharing does not match the existing group public key")]
+    ResharingPublicKeyMismatch,
+
+    #[error("only {0} dealers of the resharing are qualified, less than the threshold {1}")]
+    NotEnoughResharingDealers(usize, usize),
+
+    #[error(transparent)]
+    WalletError(#[from] WalletError),
+
+    #[error("the signature of the DKG bundle is invalid")]
+    InvalidBundleSignature,
+
+    #[error("the DKG bundle phase: {0} is invalid")]
+    InvalidBundlePhase(i32),
+
+    #[error("the DKG bundle doesn't belong to the DKG the node is running")]
+    DKGBoardSessionNotFound,
+
+    #[error("the member has already posted a different DKG bundle for this phase")]
+    ConflictingBundle,
+
+    #[error("failed to load the committer TLS config: {0}")]
+    TlsConfigError(String),
+
+    #[error("the request is not signed by the member")]
+    RequestSignatureRequired,
+
+    #[error("the signature of the request is invalid")]
+    InvalidRequestSignature,
+
+    #[error("the request was signed too long ago or in the future")]
+    ExpiredRequest,
+
+    #[error("too many requests, please retry later")]
+    RateLimited,
 }
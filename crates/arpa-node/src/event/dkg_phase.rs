@@ -1,5 +1,8 @@
 use super::{types::Topic, Event};
-use crate::subscriber::DebuggableEvent;
+use crate::{
+    rpc_stub::management::{node_event, DkgPhaseEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
 
 #[derive(Clone, Debug)]
 pub struct DKGPhase {
@@ -20,5 +23,15 @@ impl Event for DKGPhase {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            None,
+            node_event::Event::DkgPhase(DkgPhaseEvent {
+                phase: self.phase as u32,
+            }),
+        )
+    }
 }
 impl DebuggableEvent for DKGPhase {}
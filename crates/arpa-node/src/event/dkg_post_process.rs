@@ -1,5 +1,8 @@
 use super::{types::Topic, Event};
-use crate::subscriber::DebuggableEvent;
+use crate::{
+    rpc_stub::management::{node_event, DkgPostProcessEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
 
 #[derive(Clone, Debug)]
 pub struct DKGPostProcess {
@@ -24,5 +27,16 @@ impl Event for DKGPostProcess {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            None,
+            node_event::Event::DkgPostProcess(DkgPostProcessEvent {
+                group_index: self.group_index as u32,
+                group_epoch: self.group_epoch as u32,
+            }),
+        )
+    }
 }
 impl DebuggableEvent for DKGPostProcess {}
@@ -1,5 +1,8 @@
-use crate::subscriber::DebuggableEvent;
-use arpa_core::Group;
+use crate::{
+    rpc_stub::management::{node_event, DkgSuccessEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
+use arpa_core::{address_to_string, Group};
 use ethers::types::Address;
 use threshold_bls::group::Curve;
 
@@ -30,5 +33,16 @@ impl<C: Curve + 'static> Event for DKGSuccess<C> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::DkgSuccess(DkgSuccessEvent {
+                id_address: address_to_string(self.id_address),
+                group: Some(self.group.clone().into()),
+            }),
+        )
+    }
 }
 impl<C: Curve + Send + Sync + 'static> DebuggableEvent for DKGSuccess<C> {}
@@ -3,18 +3,29 @@ pub mod dkg_post_process;
 pub mod dkg_success;
 pub mod new_block;
 pub mod new_dkg_task;
+pub mod new_group_relay_confirmation_task;
+pub mod new_group_relay_task;
 pub mod new_randomness_task;
+pub mod node_activation;
+pub mod ready_to_fulfill_group_relay_confirmation_task;
+pub mod ready_to_fulfill_group_relay_task;
 pub mod ready_to_fulfill_randomness_task;
+pub mod ready_to_handle_group_relay_confirmation_task;
+pub mod ready_to_handle_group_relay_task;
 pub mod ready_to_handle_randomness_task;
 pub mod run_dkg;
-pub mod node_activation;
 pub mod types;
 
 use std::any::Any;
 
 use self::types::Topic;
+use crate::rpc_stub::management::NodeEvent;
 pub trait Event {
     fn topic(&self) -> Topic;
 
     fn as_any(&self) -> &dyn Any;
+
+    /// Converts the event to the message streamed to the event subscribers of the management
+    /// service.
+    fn to_node_event(&self) -> NodeEvent;
 }
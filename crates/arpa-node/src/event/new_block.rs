@@ -1,17 +1,25 @@
 use super::{types::Topic, Event};
-use crate::subscriber::DebuggableEvent;
+use crate::{
+    rpc_stub::management::{node_event, NewBlockEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
+use ethers::types::H256;
 
 #[derive(Clone, Debug)]
 pub struct NewBlock {
     pub chain_id: usize,
     pub block_height: usize,
+    pub block_hash: H256,
+    pub parent_hash: H256,
 }
 
 impl NewBlock {
-    pub fn new(chain_id: usize, block_height: usize) -> Self {
+    pub fn new(chain_id: usize, block_height: usize, block_hash: H256, parent_hash: H256) -> Self {
         NewBlock {
             chain_id,
             block_height,
+            block_hash,
+            parent_hash,
         }
     }
 }
@@ -24,5 +32,17 @@ impl Event for NewBlock {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::NewBlock(NewBlockEvent {
+                block_height: self.block_height as u32,
+                block_hash: format!("{:?}", self.block_hash),
+                parent_hash: format!("{:?}", self.parent_hash),
+            }),
+        )
+    }
 }
 impl DebuggableEvent for NewBlock {}
@@ -1,5 +1,8 @@
 use super::{types::Topic, Event};
-use crate::subscriber::DebuggableEvent;
+use crate::{
+    rpc_stub::management::{node_event, DkgTaskEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
 use arpa_core::DKGTask;
 
 #[derive(Clone, Debug)]
@@ -27,5 +30,13 @@ impl Event for NewDKGTask {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::DkgTask(DkgTaskEvent::new(&self.dkg_task, self.self_index)),
+        )
+    }
 }
 impl DebuggableEvent for NewDKGTask {}
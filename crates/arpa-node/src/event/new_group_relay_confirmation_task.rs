@@ -0,0 +1,42 @@
+use super::{types::Topic, Event};
+use crate::{
+    rpc_stub::management::{node_event, GroupRelayConfirmationTasksEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
+use arpa_core::GroupRelayConfirmationTask;
+
+#[derive(Clone, Debug)]
+pub struct NewGroupRelayConfirmationTask {
+    pub chain_id: usize,
+    pub group_relay_confirmation_task: GroupRelayConfirmationTask,
+}
+
+impl NewGroupRelayConfirmationTask {
+    pub fn new(chain_id: usize, group_relay_confirmation_task: GroupRelayConfirmationTask) -> Self {
+        NewGroupRelayConfirmationTask {
+            chain_id,
+            group_relay_confirmation_task,
+        }
+    }
+}
+
+impl Event for NewGroupRelayConfirmationTask {
+    fn topic(&self) -> Topic {
+        Topic::NewGroupRelayConfirmationTask(self.chain_id)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::GroupRelayConfirmationTasks(GroupRelayConfirmationTasksEvent {
+                tasks: vec![(&self.group_relay_confirmation_task).into()],
+            }),
+        )
+    }
+}
+impl DebuggableEvent for NewGroupRelayConfirmationTask {}
@@ -0,0 +1,38 @@
+use super::{types::Topic, Event};
+use crate::{
+    rpc_stub::management::{node_event, GroupRelayTasksEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
+use arpa_core::GroupRelayTask;
+
+#[derive(Clone, Debug)]
+pub struct NewGroupRelayTask {
+    pub group_relay_task: GroupRelayTask,
+}
+
+impl NewGroupRelayTask {
+    pub fn new(group_relay_task: GroupRelayTask) -> Self {
+        NewGroupRelayTask { group_relay_task }
+    }
+}
+
+impl Event for NewGroupRelayTask {
+    fn topic(&self) -> Topic {
+        Topic::NewGroupRelayTask
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            None,
+            node_event::Event::GroupRelayTasks(GroupRelayTasksEvent {
+                tasks: vec![(&self.group_relay_task).into()],
+            }),
+        )
+    }
+}
+impl DebuggableEvent for NewGroupRelayTask {}
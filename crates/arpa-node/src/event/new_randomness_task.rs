@@ -1,5 +1,8 @@
 use super::{types::Topic, Event};
-use crate::subscriber::DebuggableEvent;
+use crate::{
+    rpc_stub::management::{node_event, NodeEvent, RandomnessTasksEvent},
+    subscriber::DebuggableEvent,
+};
 use arpa_core::RandomnessTask;
 
 #[derive(Clone, Debug)]
@@ -25,5 +28,15 @@ impl Event for NewRandomnessTask {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::RandomnessTasks(RandomnessTasksEvent {
+                tasks: vec![self.randomness_task.clone().into()],
+            }),
+        )
+    }
 }
 impl DebuggableEvent for NewRandomnessTask {}
@@ -1,6 +1,10 @@
+use arpa_core::address_to_string;
 use ethers::types::Address;
 
-use crate::subscriber::DebuggableEvent;
+use crate::{
+    rpc_stub::management::{node_event, NodeActivationEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
 
 use super::{types::Topic, Event};
 
@@ -29,5 +33,16 @@ impl Event for NodeActivation {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::NodeActivation(NodeActivationEvent {
+                is_eigenlayer: self.is_eigenlayer,
+                node_registry_address: address_to_string(self.node_registry_address),
+            }),
+        )
+    }
 }
 impl DebuggableEvent for NodeActivation {}
@@ -0,0 +1,39 @@
+use super::{types::Topic, Event};
+use crate::{
+    rpc_stub::management::{node_event, NodeEvent, SignatureResultsEvent},
+    subscriber::DebuggableEvent,
+};
+use arpa_dal::cache::GroupRelayConfirmationResultCache;
+
+#[derive(Clone, Debug)]
+pub struct ReadyToFulfillGroupRelayConfirmationTask {
+    pub chain_id: usize,
+    pub tasks: Vec<GroupRelayConfirmationResultCache>,
+}
+
+impl ReadyToFulfillGroupRelayConfirmationTask {
+    pub fn new(chain_id: usize, tasks: Vec<GroupRelayConfirmationResultCache>) -> Self {
+        ReadyToFulfillGroupRelayConfirmationTask { chain_id, tasks }
+    }
+}
+
+impl Event for ReadyToFulfillGroupRelayConfirmationTask {
+    fn topic(&self) -> Topic {
+        Topic::ReadyToFulfillGroupRelayConfirmationTask(self.chain_id)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::SignatureResults(SignatureResultsEvent {
+                results: self.tasks.iter().map(Into::into).collect(),
+            }),
+        )
+    }
+}
+impl DebuggableEvent for ReadyToFulfillGroupRelayConfirmationTask {}
@@ -0,0 +1,38 @@
+use super::{types::Topic, Event};
+use crate::{
+    rpc_stub::management::{node_event, NodeEvent, SignatureResultsEvent},
+    subscriber::DebuggableEvent,
+};
+use arpa_dal::cache::GroupRelayResultCache;
+
+#[derive(Clone, Debug)]
+pub struct ReadyToFulfillGroupRelayTask {
+    pub tasks: Vec<GroupRelayResultCache>,
+}
+
+impl ReadyToFulfillGroupRelayTask {
+    pub fn new(tasks: Vec<GroupRelayResultCache>) -> Self {
+        ReadyToFulfillGroupRelayTask { tasks }
+    }
+}
+
+impl Event for ReadyToFulfillGroupRelayTask {
+    fn topic(&self) -> Topic {
+        Topic::ReadyToFulfillGroupRelayTask
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            None,
+            node_event::Event::SignatureResults(SignatureResultsEvent {
+                results: self.tasks.iter().map(Into::into).collect(),
+            }),
+        )
+    }
+}
+impl DebuggableEvent for ReadyToFulfillGroupRelayTask {}
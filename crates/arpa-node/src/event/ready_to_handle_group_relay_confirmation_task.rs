@@ -0,0 +1,39 @@
+use super::{types::Topic, Event};
+use crate::{
+    rpc_stub::management::{node_event, GroupRelayConfirmationTasksEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
+use arpa_core::GroupRelayConfirmationTask;
+
+#[derive(Clone, Debug)]
+pub struct ReadyToHandleGroupRelayConfirmationTask {
+    pub chain_id: usize,
+    pub tasks: Vec<GroupRelayConfirmationTask>,
+}
+
+impl ReadyToHandleGroupRelayConfirmationTask {
+    pub fn new(chain_id: usize, tasks: Vec<GroupRelayConfirmationTask>) -> Self {
+        ReadyToHandleGroupRelayConfirmationTask { chain_id, tasks }
+    }
+}
+
+impl Event for ReadyToHandleGroupRelayConfirmationTask {
+    fn topic(&self) -> Topic {
+        Topic::ReadyToHandleGroupRelayConfirmationTask(self.chain_id)
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::GroupRelayConfirmationTasks(GroupRelayConfirmationTasksEvent {
+                tasks: self.tasks.iter().map(Into::into).collect(),
+            }),
+        )
+    }
+}
+impl DebuggableEvent for ReadyToHandleGroupRelayConfirmationTask {}
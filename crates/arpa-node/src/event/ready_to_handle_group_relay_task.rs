@@ -0,0 +1,38 @@
+use super::{types::Topic, Event};
+use crate::{
+    rpc_stub::management::{node_event, GroupRelayTasksEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
+use arpa_core::GroupRelayTask;
+
+#[derive(Clone, Debug)]
+pub struct ReadyToHandleGroupRelayTask {
+    pub tasks: Vec<GroupRelayTask>,
+}
+
+impl ReadyToHandleGroupRelayTask {
+    pub fn new(tasks: Vec<GroupRelayTask>) -> Self {
+        ReadyToHandleGroupRelayTask { tasks }
+    }
+}
+
+impl Event for ReadyToHandleGroupRelayTask {
+    fn topic(&self) -> Topic {
+        Topic::ReadyToHandleGroupRelayTask
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            None,
+            node_event::Event::GroupRelayTasks(GroupRelayTasksEvent {
+                tasks: self.tasks.iter().map(Into::into).collect(),
+            }),
+        )
+    }
+}
+impl DebuggableEvent for ReadyToHandleGroupRelayTask {}
@@ -1,5 +1,8 @@
 use super::{types::Topic, Event};
-use crate::subscriber::DebuggableEvent;
+use crate::{
+    rpc_stub::management::{node_event, NodeEvent, RandomnessTasksEvent},
+    subscriber::DebuggableEvent,
+};
 use arpa_core::RandomnessTask;
 
 #[derive(Clone, Debug)]
@@ -22,5 +25,15 @@ impl Event for ReadyToHandleRandomnessTask {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            Some(self.chain_id),
+            node_event::Event::RandomnessTasks(RandomnessTasksEvent {
+                tasks: self.tasks.iter().cloned().map(Into::into).collect(),
+            }),
+        )
+    }
 }
 impl DebuggableEvent for ReadyToHandleRandomnessTask {}
@@ -1,21 +1,35 @@
+use crate::{
+    algorithm::dkg::PreviousGroupShare,
+    rpc_stub::management::{node_event, DkgTaskEvent, NodeEvent},
+    subscriber::DebuggableEvent,
+};
 use arpa_core::DKGTask;
-
-use crate::subscriber::DebuggableEvent;
+use threshold_bls::group::Curve;
 
 use super::{types::Topic, Event};
 
 #[derive(Clone, Debug)]
-pub struct RunDKG {
+pub struct RunDKG<C: Curve> {
     pub dkg_task: DKGTask,
+    pub self_index: usize,
+    pub previous_group_share: Option<PreviousGroupShare<C>>,
 }
 
-impl RunDKG {
-    pub fn new(dkg_task: DKGTask) -> Self {
-        RunDKG { dkg_task }
+impl<C: Curve> RunDKG<C> {
+    pub fn new(
+        dkg_task: DKGTask,
+        self_index: usize,
+        previous_group_share: Option<PreviousGroupShare<C>>,
+    ) -> Self {
+        RunDKG {
+            dkg_task,
+            self_index,
+            previous_group_share,
+        }
     }
 }
 
-impl Event for RunDKG {
+impl<C: Curve + 'static> Event for RunDKG<C> {
     fn topic(&self) -> Topic {
         Topic::RunDKG
     }
@@ -23,6 +37,14 @@ impl Event for RunDKG {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
+
+    fn to_node_event(&self) -> NodeEvent {
+        NodeEvent::new(
+            self.topic(),
+            None,
+            node_event::Event::DkgTask(DkgTaskEvent::new(&self.dkg_task, self.self_index)),
+        )
+    }
 }
 
-impl DebuggableEvent for RunDKG {}
+impl<C: Curve + Send + Sync + 'static> DebuggableEvent for RunDKG<C> {}
@@ -1,3 +1,4 @@
+use crate::rpc_stub::management::{node_event, node_event::Topic as EventTopic, NodeEvent};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Hash, Eq)]
@@ -19,3 +20,40 @@ pub enum Topic {
     ReadyToFulfillGroupRelayConfirmationTask(usize),
     NodeActivation,
 }
+
+impl From<Topic> for EventTopic {
+    fn from(topic: Topic) -> Self {
+        match topic {
+            Topic::NewBlock(_) => EventTopic::NewBlock,
+            Topic::NewDKGTask => EventTopic::NewDkgTask,
+            Topic::RunDKG => EventTopic::RunDkg,
+            Topic::DKGPhase => EventTopic::DkgPhase,
+            Topic::DKGSuccess => EventTopic::DkgSuccess,
+            Topic::DKGPostProcess => EventTopic::DkgPostProcess,
+            Topic::NewRandomnessTask(_) => EventTopic::NewRandomnessTask,
+            Topic::NewGroupRelayTask => EventTopic::NewGroupRelayTask,
+            Topic::NewGroupRelayConfirmationTask(_) => EventTopic::NewGroupRelayConfirmationTask,
+            Topic::ReadyToHandleRandomnessTask(_) => EventTopic::ReadyToHandleRandomnessTask,
+            Topic::ReadyToHandleGroupRelayTask => EventTopic::ReadyToHandleGroupRelayTask,
+            Topic::ReadyToHandleGroupRelayConfirmationTask(_) => {
+                EventTopic::ReadyToHandleGroupRelayConfirmationTask
+            }
+            Topic::ReadyToFulfillRandomnessTask(_) => EventTopic::ReadyToFulfillRandomnessTask,
+            Topic::ReadyToFulfillGroupRelayTask => EventTopic::ReadyToFulfillGroupRelayTask,
+            Topic::ReadyToFulfillGroupRelayConfirmationTask(_) => {
+                EventTopic::ReadyToFulfillGroupRelayConfirmationTask
+            }
+            Topic::NodeActivation => EventTopic::NodeActivation,
+        }
+    }
+}
+
+impl NodeEvent {
+    pub fn new(topic: Topic, chain_id: Option<usize>, event: node_event::Event) -> Self {
+        NodeEvent {
+            topic: EventTopic::from(topic) as i32,
+            chain_id: chain_id.unwrap_or_default() as u32,
+            event: Some(event),
+        }
+    }
+}
@@ -1,14 +1,17 @@
 #![allow(incomplete_features)]
 #![allow(async_fn_in_trait)]
 pub mod algorithm;
+pub mod board;
 pub mod committer;
 pub mod context;
 pub mod error;
 pub mod event;
 pub mod listener;
 pub mod management;
+pub mod pruner;
 pub mod queue;
 pub mod rpc_stub;
 pub mod scheduler;
 pub mod stats;
+pub mod storage;
 pub mod subscriber;
@@ -5,7 +5,7 @@ use crate::{
     event::new_block::NewBlock,
     queue::{event_queue::EventQueue, EventPublisher},
 };
-use arpa_contract_client::provider::BlockFetcher;
+use arpa_contract_client::provider::{BlockFetcher, BlockHeader};
 use async_trait::async_trait;
 use std::{marker::PhantomData, sync::Arc};
 use threshold_bls::group::Curve;
@@ -56,15 +56,17 @@ impl<PC: Curve + Sync + Send> Listener for BlockListener<PC> {
             .read()
             .await
             .get_provider()
-            .subscribe_new_block_height(move |block_height: usize| {
+            .subscribe_new_block(move |block: BlockHeader| {
                 let eq = eq.clone();
                 async move {
                     eq.read()
                         .await
-                        .publish(NewBlock {
+                        .publish(NewBlock::new(
                             chain_id,
-                            block_height,
-                        })
+                            block.block_height,
+                            block.block_hash,
+                            block.parent_hash,
+                        ))
                         .await;
 
                     Ok(())
@@ -0,0 +1,98 @@
+use super::Listener;
+use crate::{
+    error::NodeResult,
+    event::ready_to_fulfill_group_relay_confirmation_task::ReadyToFulfillGroupRelayConfirmationTask,
+    queue::{event_queue::EventQueue, EventPublisher},
+};
+use arpa_dal::cache::GroupRelayConfirmationResultCache;
+use arpa_dal::{BlockInfoHandler, GroupInfoHandler, SignatureResultCacheHandler};
+use async_trait::async_trait;
+use ethers::types::Address;
+use std::{marker::PhantomData, sync::Arc};
+use threshold_bls::group::Curve;
+use tokio::sync::RwLock;
+
+pub struct GroupRelayConfirmationSignatureAggregationListener<PC: Curve> {
+    chain_id: usize,
+    id_address: Address,
+    block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+    group_relay_confirmation_signature_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>>,
+    eq: Arc<RwLock<EventQueue>>,
+    pc: PhantomData<PC>,
+}
+
+impl<PC: Curve> std::fmt::Display for GroupRelayConfirmationSignatureAggregationListener<PC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GroupRelayConfirmationSignatureAggregationListener")
+    }
+}
+
+impl<PC: Curve> GroupRelayConfirmationSignatureAggregationListener<PC> {
+    pub fn new(
+        chain_id: usize,
+        id_address: Address,
+        block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+        group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+        group_relay_confirmation_signature_cache: Arc<
+            RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>,
+        >,
+        eq: Arc<RwLock<EventQueue>>,
+    ) -> Self {
+        GroupRelayConfirmationSignatureAggregationListener {
+            chain_id,
+            id_address,
+            block_cache,
+            group_cache,
+            group_relay_confirmation_signature_cache,
+            eq,
+            pc: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> EventPublisher<ReadyToFulfillGroupRelayConfirmationTask>
+    for GroupRelayConfirmationSignatureAggregationListener<PC>
+{
+    async fn publish(&self, event: ReadyToFulfillGroupRelayConfirmationTask) {
+        self.eq.read().await.publish(event).await;
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> Listener for GroupRelayConfirmationSignatureAggregationListener<PC> {
+    async fn listen(&self) -> NodeResult<()> {
+        let is_committer = self.group_cache.read().await.is_committer(self.id_address);
+
+        if let Ok(true) = is_committer {
+            let current_block_height = self.block_cache.read().await.get_block_height();
+
+            let ready_signatures = self
+                .group_relay_confirmation_signature_cache
+                .write()
+                .await
+                .get_ready_to_commit_signatures(current_block_height)
+                .await?;
+
+            if !ready_signatures.is_empty() {
+                self.publish(ReadyToFulfillGroupRelayConfirmationTask {
+                    chain_id: self.chain_id,
+                    tasks: ready_signatures,
+                })
+                .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_interruption(&self) -> NodeResult<()> {
+        Ok(())
+    }
+
+    async fn chain_id(&self) -> usize {
+        self.chain_id
+    }
+}
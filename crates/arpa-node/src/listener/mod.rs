@@ -1,9 +1,15 @@
 pub mod block;
+pub mod group_relay_confirmation_signature_aggregation;
+pub mod group_relay_signature_aggregation;
+pub mod new_group_relay_confirmation_task;
+pub mod new_group_relay_task;
 pub mod new_randomness_task;
 pub mod post_commit_grouping;
 pub mod post_grouping;
 pub mod pre_grouping;
 pub mod randomness_signature_aggregation;
+pub mod ready_to_handle_group_relay_confirmation_task;
+pub mod ready_to_handle_group_relay_task;
 pub mod ready_to_handle_randomness_task;
 pub mod schedule_node_activation;
 
@@ -13,12 +19,14 @@ use crate::error::NodeResult;
 use arpa_core::{
     jitter,
     log::{build_general_payload, LogType},
+    metrics::{LISTENER_ERRORS, LISTENER_ITERATIONS},
     FixedIntervalRetryDescriptor,
 };
 use async_trait::async_trait;
 use log::error;
 use tokio::time::sleep;
 use tokio_retry::{strategy::FixedInterval, Retry};
+use tracing::{debug_span, Instrument};
 
 #[async_trait]
 pub trait Listener {
@@ -35,8 +43,18 @@ pub trait Listener {
             FixedInterval::from_millis(interval_millis)
                 .map(|e| if use_jitter { jitter(e) } else { e });
 
+        let chain_id = self.chain_id().await.to_string();
+        let listener = self.to_string();
+
         loop {
-            if let Err(err) = self.listen().await {
+            LISTENER_ITERATIONS
+                .with_label_values(&[&chain_id, &listener])
+                .inc();
+            let span = debug_span!("listener", chain_id = %chain_id, listener = %listener);
+            if let Err(err) = self.listen().instrument(span).await {
+                LISTENER_ERRORS
+                    .with_label_values(&[&chain_id, &listener])
+                    .inc();
                 error!(
                     "{}",
                     build_general_payload(
@@ -0,0 +1,139 @@
+use super::Listener;
+use crate::{
+    context::ChainIdentityHandlerType,
+    error::NodeResult,
+    event::new_group_relay_confirmation_task::NewGroupRelayConfirmationTask,
+    queue::{event_queue::EventQueue, EventPublisher},
+};
+use arpa_contract_client::controller_oracle::ControllerOracleLogs;
+use arpa_core::{
+    log::{build_task_related_payload, LogType},
+    BLSTaskType, GroupRelayConfirmationTask, TaskType,
+};
+use arpa_dal::BLSTasksHandler;
+use async_trait::async_trait;
+use ethers::providers::Middleware;
+use log::info;
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+use threshold_bls::group::Curve;
+use tokio::sync::RwLock;
+
+pub struct NewGroupRelayConfirmationTaskListener<PC: Curve> {
+    chain_id: usize,
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    group_relay_confirmation_tasks_cache:
+        Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>>>,
+    eq: Arc<RwLock<EventQueue>>,
+    pc: PhantomData<PC>,
+}
+
+impl<PC: Curve> std::fmt::Display for NewGroupRelayConfirmationTaskListener<PC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NewGroupRelayConfirmationTaskListener")
+    }
+}
+
+impl<PC: Curve> NewGroupRelayConfirmationTaskListener<PC> {
+    pub fn new(
+        chain_id: usize,
+        chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+        group_relay_confirmation_tasks_cache: Arc<
+            RwLock<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>>,
+        >,
+        eq: Arc<RwLock<EventQueue>>,
+    ) -> Self {
+        NewGroupRelayConfirmationTaskListener {
+            chain_id,
+            chain_identity,
+            group_relay_confirmation_tasks_cache,
+            eq,
+            pc: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> EventPublisher<NewGroupRelayConfirmationTask>
+    for NewGroupRelayConfirmationTaskListener<PC>
+{
+    async fn publish(&self, event: NewGroupRelayConfirmationTask) {
+        self.eq.read().await.publish(event).await;
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> Listener for NewGroupRelayConfirmationTaskListener<PC> {
+    async fn listen(&self) -> NodeResult<()> {
+        let client = self
+            .chain_identity
+            .read()
+            .await
+            .build_controller_oracle_client();
+        let chain_id = self.chain_id;
+
+        client
+            .subscribe_group_relay_confirmation_task(move |group_relay_confirmation_task| {
+                let group_relay_confirmation_tasks_cache =
+                    self.group_relay_confirmation_tasks_cache.clone();
+                let eq = self.eq.clone();
+
+                async move {
+                    if let Ok(false) = group_relay_confirmation_tasks_cache
+                        .read()
+                        .await
+                        .contains(&group_relay_confirmation_task.request_id)
+                        .await
+                    {
+                        info!(
+                            "{}",
+                            build_task_related_payload(
+                                LogType::TaskReceived,
+                                "New group relay confirmation task received.",
+                                self.chain_id,
+                                &group_relay_confirmation_task.request_id,
+                                TaskType::BLS(BLSTaskType::GroupRelayConfirmation),
+                                json!(group_relay_confirmation_task),
+                                None
+                            )
+                        );
+
+                        group_relay_confirmation_tasks_cache
+                            .write()
+                            .await
+                            .add(group_relay_confirmation_task.clone())
+                            .await
+                            .map_err(anyhow::Error::from)?;
+
+                        eq.read()
+                            .await
+                            .publish(NewGroupRelayConfirmationTask::new(
+                                chain_id,
+                                group_relay_confirmation_task,
+                            ))
+                            .await;
+                    }
+
+                    Ok(())
+                }
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn handle_interruption(&self) -> NodeResult<()> {
+        self.chain_identity
+            .read()
+            .await
+            .get_provider()
+            .get_net_version()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn chain_id(&self) -> usize {
+        self.chain_id
+    }
+}
@@ -0,0 +1,150 @@
+use super::Listener;
+use crate::{
+    context::ChainIdentityHandlerType,
+    error::NodeResult,
+    event::new_group_relay_task::NewGroupRelayTask,
+    queue::{event_queue::EventQueue, EventPublisher},
+};
+use arpa_contract_client::controller_relayer::ControllerRelayerLogs;
+use arpa_core::{
+    log::{build_task_related_payload, LogType},
+    BLSTaskType, GroupRelayTask, TaskType,
+};
+use arpa_dal::BLSTasksHandler;
+use async_trait::async_trait;
+use ethers::providers::Middleware;
+use log::info;
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+use threshold_bls::group::Curve;
+use tokio::sync::RwLock;
+
+pub struct NewGroupRelayTaskListener<PC: Curve> {
+    chain_id: usize,
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    relayed_chain_ids: Vec<usize>,
+    group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>>,
+    eq: Arc<RwLock<EventQueue>>,
+    pc: PhantomData<PC>,
+}
+
+impl<PC: Curve> std::fmt::Display for NewGroupRelayTaskListener<PC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NewGroupRelayTaskListener")
+    }
+}
+
+impl<PC: Curve> NewGroupRelayTaskListener<PC> {
+    pub fn new(
+        chain_id: usize,
+        chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+        relayed_chain_ids: Vec<usize>,
+        group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>>,
+        eq: Arc<RwLock<EventQueue>>,
+    ) -> Self {
+        NewGroupRelayTaskListener {
+            chain_id,
+            chain_identity,
+            relayed_chain_ids,
+            group_relay_tasks_cache,
+            eq,
+            pc: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> EventPublisher<NewGroupRelayTask> for NewGroupRelayTaskListener<PC> {
+    async fn publish(&self, event: NewGroupRelayTask) {
+        self.eq.read().await.publish(event).await;
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> Listener for NewGroupRelayTaskListener<PC> {
+    async fn listen(&self) -> NodeResult<()> {
+        let client = self
+            .chain_identity
+            .read()
+            .await
+            .build_controller_relayer_client();
+
+        client
+            .subscribe_group_relay_task(move |group_relay_task_log| {
+                let group_relay_tasks_cache = self.group_relay_tasks_cache.clone();
+                let eq = self.eq.clone();
+
+                // the group is signed for and relayed to every relayed chain separately,
+                // since the signed message is bound to the controller oracle of the chain
+                let group_relay_tasks = self
+                    .relayed_chain_ids
+                    .iter()
+                    .map(|relayed_chain_id| {
+                        GroupRelayTask::new(
+                            *relayed_chain_id,
+                            group_relay_task_log.controller_global_epoch,
+                            group_relay_task_log.relayed_group_index,
+                            group_relay_task_log.relayed_group_epoch,
+                            group_relay_task_log.assignment_block_height,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+
+                async move {
+                    for group_relay_task in group_relay_tasks {
+                        if let Ok(false) = group_relay_tasks_cache
+                            .read()
+                            .await
+                            .contains(&group_relay_task.request_id)
+                            .await
+                        {
+                            info!(
+                                "{}",
+                                build_task_related_payload(
+                                    LogType::TaskReceived,
+                                    "New group relay task received.",
+                                    self.chain_id,
+                                    &group_relay_task.request_id,
+                                    TaskType::BLS(BLSTaskType::GroupRelay),
+                                    json!(group_relay_task),
+                                    None
+                                )
+                            );
+
+                            group_relay_tasks_cache
+                                .write()
+                                .await
+                                .add(group_relay_task.clone())
+                                .await
+                                .map_err(anyhow::Error::from)?;
+
+                            eq.read()
+                                .await
+                                .publish(NewGroupRelayTask::new(group_relay_task))
+                                .await;
+                        }
+                    }
+
+                    Ok(())
+                }
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    async fn handle_interruption(&self) -> NodeResult<()> {
+        self.chain_identity
+            .read()
+            .await
+            .get_provider()
+            .get_net_version()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn chain_id(&self) -> usize {
+        self.chain_id
+    }
+}
@@ -5,25 +5,29 @@ use crate::{
     event::new_randomness_task::NewRandomnessTask,
     queue::{event_queue::EventQueue, EventPublisher},
 };
-use arpa_contract_client::adapter::AdapterLogs;
+use arpa_contract_client::adapter::{AdapterLogs, RandomnessTaskLog};
 use arpa_core::{
-    log::{build_task_related_payload, LogType},
-    BLSTaskType, RandomnessTask, TaskType,
+    log::{build_task_related_payload, trace::set_task_parent, LogType},
+    metrics::RANDOMNESS_TASKS,
+    BLSTaskType, RandomnessTask, TaskType, BLOCK_CHECKPOINT_INTERVAL, BLOCK_HASH_HISTORY_LENGTH,
 };
-use arpa_dal::BLSTasksHandler;
+use arpa_dal::{BLSTasksHandler, BlockCheckpointHandler};
 use async_trait::async_trait;
 use ethers::{providers::Middleware, types::Address};
 use log::info;
 use serde_json::json;
-use std::{marker::PhantomData, sync::Arc};
+use std::{future::Future, marker::PhantomData, sync::Arc};
 use threshold_bls::group::Curve;
 use tokio::sync::RwLock;
+use tracing::{info_span, Instrument};
 
 pub struct NewRandomnessTaskListener<PC: Curve> {
     chain_id: usize,
     id_address: Address,
     chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
     randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>>,
+    block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>>,
+    adapter_deployed_block_height: usize,
     eq: Arc<RwLock<EventQueue>>,
     pc: PhantomData<PC>,
 }
@@ -40,6 +44,8 @@ impl<PC: Curve> NewRandomnessTaskListener<PC> {
         id_address: Address,
         chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
         randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>>,
+        block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>>,
+        adapter_deployed_block_height: usize,
         eq: Arc<RwLock<EventQueue>>,
     ) -> Self {
         NewRandomnessTaskListener {
@@ -47,10 +53,206 @@ impl<PC: Curve> NewRandomnessTaskListener<PC> {
             id_address,
             chain_identity,
             randomness_tasks_cache,
+            block_checkpoint_cache,
+            adapter_deployed_block_height,
             eq,
             pc: PhantomData,
         }
     }
+
+    async fn withdraw_task(&self, randomness_task: &RandomnessTask) -> NodeResult<()> {
+        info!(
+            "{}",
+            build_task_related_payload(
+                LogType::TaskWithdrawn,
+                "Randomness task withdrawn after a chain reorg.",
+                self.chain_id,
+                &randomness_task.request_id,
+                TaskType::BLS(BLSTaskType::Randomness),
+                json!(randomness_task),
+                None
+            )
+        );
+
+        self.randomness_tasks_cache
+            .write()
+            .await
+            .remove(&randomness_task.request_id)
+            .await?;
+
+        RANDOMNESS_TASKS
+            .with_label_values(&[&self.chain_id.to_string(), "withdrawn"])
+            .inc();
+
+        Ok(())
+    }
+
+    /// Returns the block to replay the requests from, i.e. the last processed block clamped to
+    /// the block the adapter was deployed in. Without a checkpoint the subscription starts from
+    /// the latest block, which is saved as the first checkpoint.
+    async fn resolve_from_block(
+        &self,
+        current_block_height: impl Future<Output = NodeResult<usize>>,
+    ) -> NodeResult<Option<usize>> {
+        let last_processed_block_height = self
+            .block_checkpoint_cache
+            .read()
+            .await
+            .get_last_processed_block_height()
+            .await?;
+
+        match last_processed_block_height {
+            Some(block_height) => Ok(Some(block_height.max(self.adapter_deployed_block_height))),
+            None => {
+                let current_block_height = current_block_height.await?;
+
+                self.block_checkpoint_cache
+                    .write()
+                    .await
+                    .set_last_processed_block_height(current_block_height)
+                    .await?;
+
+                Ok(None)
+            }
+        }
+    }
+
+    /// Caches and publishes a new randomness task, or reconciles the cached one after a reorg.
+    /// The tasks replayed from the checkpoint block are already cached and are skipped.
+    async fn handle_randomness_task_log(&self, log: RandomnessTaskLog) -> NodeResult<()> {
+        let RandomnessTaskLog {
+            task: randomness_task,
+            removed,
+            ..
+        } = log;
+
+        let cached_task = if self
+            .randomness_tasks_cache
+            .read()
+            .await
+            .contains(&randomness_task.request_id)
+            .await?
+        {
+            Some(
+                self.randomness_tasks_cache
+                    .read()
+                    .await
+                    .get(&randomness_task.request_id)
+                    .await?,
+            )
+        } else {
+            None
+        };
+
+        // the block of the request has been orphaned by a reorg, withdraw the task
+        // unless it has been rescheduled to the block which replaced it
+        if removed {
+            if let Some(cached_task) = cached_task {
+                if cached_task.assignment_block_height == randomness_task.assignment_block_height {
+                    self.withdraw_task(&randomness_task).await?;
+                }
+            }
+
+            return Ok(());
+        }
+
+        let is_new_task = match cached_task {
+            None => true,
+            // the request has been included again in another block by a reorg,
+            // reschedule the task to that block
+            Some(cached_task)
+                if cached_task.assignment_block_height
+                    != randomness_task.assignment_block_height =>
+            {
+                info!(
+                    "{}",
+                    build_task_related_payload(
+                        LogType::TaskRescheduled,
+                        "Randomness task rescheduled after a chain reorg.",
+                        self.chain_id,
+                        &randomness_task.request_id,
+                        TaskType::BLS(BLSTaskType::Randomness),
+                        json!(randomness_task),
+                        None
+                    )
+                );
+
+                self.randomness_tasks_cache
+                    .write()
+                    .await
+                    .remove(&randomness_task.request_id)
+                    .await?;
+
+                true
+            }
+            Some(_) => false,
+        };
+
+        if is_new_task {
+            info!(
+                "{}",
+                build_task_related_payload(
+                    LogType::TaskReceived,
+                    "New randomness task received.",
+                    self.chain_id,
+                    &randomness_task.request_id,
+                    TaskType::BLS(BLSTaskType::Randomness),
+                    json!(randomness_task),
+                    None
+                )
+            );
+
+            self.randomness_tasks_cache
+                .write()
+                .await
+                .add(randomness_task.clone())
+                .await?;
+
+            RANDOMNESS_TASKS
+                .with_label_values(&[&self.chain_id.to_string(), "received"])
+                .inc();
+
+            self.publish(NewRandomnessTask::new(
+                self.chain_id,
+                randomness_task.clone(),
+            ))
+            .await;
+        }
+
+        // logs replayed or delivered out of order must not move the checkpoint backwards
+        self.block_checkpoint_cache
+            .write()
+            .await
+            .advance_last_processed_block_height(randomness_task.assignment_block_height)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Moves the checkpoint forward once the requests up to the block have been handled, so
+    /// that a restart doesn't replay from a stale block while no request comes in. It trails
+    /// the block by the reorg depth we follow, and is only saved every
+    /// `BLOCK_CHECKPOINT_INTERVAL` blocks.
+    async fn handle_block_range(&self, block_height: usize) -> NodeResult<()> {
+        let block_height = block_height.saturating_sub(BLOCK_HASH_HISTORY_LENGTH);
+
+        let mut block_checkpoint_cache = self.block_checkpoint_cache.write().await;
+
+        match block_checkpoint_cache
+            .get_last_processed_block_height()
+            .await?
+        {
+            Some(last_processed_block_height)
+                if last_processed_block_height + BLOCK_CHECKPOINT_INTERVAL > block_height => {}
+            _ => {
+                block_checkpoint_cache
+                    .set_last_processed_block_height(block_height)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -70,46 +272,49 @@ impl<PC: Curve + Sync + Send> Listener for NewRandomnessTaskListener<PC> {
             .build_adapter_client(self.id_address);
         let chain_id = self.chain_id;
 
+        let from_block = self
+            .resolve_from_block(async {
+                Ok(self
+                    .chain_identity
+                    .read()
+                    .await
+                    .get_provider()
+                    .get_block_number()
+                    .await?
+                    .as_usize())
+            })
+            .await?;
+
         client
-            .subscribe_randomness_task(move |randomness_task| {
-                let randomness_tasks_cache = self.randomness_tasks_cache.clone();
-                let eq = self.eq.clone();
+            .subscribe_randomness_task(
+                from_block,
+                move |log: RandomnessTaskLog| {
+                    let span = info_span!(
+                        "new_randomness_task",
+                        chain_id,
+                        group_index = log.task.group_index,
+                        request_id = %format!("0x{}", hex::encode(&log.task.request_id)),
+                        assignment_block_height = log.task.assignment_block_height
+                    );
+                    set_task_parent(&span, &log.task.request_id);
 
-                async move {
-                    let contained_res = randomness_tasks_cache
-                        .read()
-                        .await
-                        .contains(&randomness_task.request_id)
-                        .await;
-                    if let Ok(false) = contained_res {
-                        info!(
-                            "{}",
-                            build_task_related_payload(
-                                LogType::TaskReceived,
-                                "New randomness task received.",
-                                self.chain_id,
-                                &randomness_task.request_id,
-                                TaskType::BLS(BLSTaskType::Randomness),
-                                json!(randomness_task),
-                                None
-                            )
-                        );
-
-                        randomness_tasks_cache
-                            .write()
-                            .await
-                            .add(randomness_task.clone())
+                    async move {
+                        self.handle_randomness_task_log(log)
                             .await
                             .map_err(anyhow::Error::from)?;
 
-                        eq.read()
-                            .await
-                            .publish(NewRandomnessTask::new(chain_id, randomness_task))
-                            .await;
+                        Ok(())
                     }
+                    .instrument(span)
+                },
+                move |block_height: usize| async move {
+                    self.handle_block_range(block_height)
+                        .await
+                        .map_err(anyhow::Error::from)?;
+
                     Ok(())
-                }
-            })
+                },
+            )
             .await?;
 
         Ok(())
@@ -130,3 +335,284 @@ impl<PC: Curve + Sync + Send> Listener for NewRandomnessTaskListener<PC> {
         self.chain_id
     }
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::NewRandomnessTaskListener;
+    use crate::{
+        context::ChainIdentityHandlerType,
+        error::NodeResult,
+        event::types::Topic,
+        queue::{event_queue::EventQueue, EventSubscriber},
+        subscriber::{DebuggableEvent, DebuggableSubscriber, Subscriber},
+    };
+    use arpa_contract_client::adapter::RandomnessTaskLog;
+    use arpa_core::{
+        ChainTransport, Config, GeneralMainChainIdentity, ProviderEndpoints, RandomnessRequestType,
+        RandomnessTask, BLOCK_CHECKPOINT_INTERVAL, BLOCK_HASH_HISTORY_LENGTH,
+    };
+    use arpa_dal::{
+        cache::{InMemoryBLSTasksQueue, InMemoryBlockCheckpointCache},
+        BLSTasksHandler, BlockCheckpointHandler, BlockCheckpointUpdater,
+    };
+    use async_trait::async_trait;
+    use ethers::{
+        providers::{Http, Provider},
+        types::{Address, H256, U256},
+    };
+    use std::{
+        str::FromStr,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    use threshold_bls::schemes::bn254::G2Curve;
+    use tokio::sync::RwLock;
+
+    const ADAPTER_DEPLOYED_BLOCK_HEIGHT: usize = 100;
+
+    #[derive(Debug, Default, Clone)]
+    struct NewRandomnessTaskCounter {
+        count: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl Subscriber for NewRandomnessTaskCounter {
+        async fn notify(&self, _topic: Topic, _payload: &dyn DebuggableEvent) -> NodeResult<()> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+
+            Ok(())
+        }
+
+        async fn subscribe(self) {}
+    }
+
+    impl DebuggableSubscriber for NewRandomnessTaskCounter {}
+
+    async fn build_listener(
+        last_processed_block_height: Option<usize>,
+    ) -> (NewRandomnessTaskListener<G2Curve>, NewRandomnessTaskCounter) {
+        let config = Config::default();
+
+        let fake_wallet = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+            .parse()
+            .unwrap();
+
+        // the provider is never requested in these tests
+        let endpoint = "http://127.0.0.1:8545";
+        let provider = Arc::new(Provider::new(ChainTransport::from(
+            Http::from_str(endpoint).unwrap(),
+        )));
+
+        let chain_identity = GeneralMainChainIdentity::new(
+            0,
+            fake_wallet,
+            provider,
+            ProviderEndpoints::new(vec![endpoint.to_string()], 0),
+            Address::random(),
+            Address::random(),
+            Address::random(),
+            config
+                .get_time_limits()
+                .contract_transaction_retry_descriptor,
+            config.get_time_limits().contract_view_retry_descriptor,
+        );
+
+        let chain_identity: Arc<RwLock<ChainIdentityHandlerType<G2Curve>>> =
+            Arc::new(RwLock::new(Box::new(chain_identity)));
+
+        let mut block_checkpoint_cache = InMemoryBlockCheckpointCache::new();
+        if let Some(block_height) = last_processed_block_height {
+            block_checkpoint_cache
+                .set_last_processed_block_height(block_height)
+                .await
+                .unwrap();
+        }
+
+        let block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>> =
+            Arc::new(RwLock::new(Box::new(block_checkpoint_cache)));
+
+        let randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>> =
+            Arc::new(RwLock::new(Box::new(InMemoryBLSTasksQueue::new())));
+
+        let counter = NewRandomnessTaskCounter::default();
+
+        let mut eq = EventQueue::new();
+        eq.subscribe(Topic::NewRandomnessTask(0), Box::new(counter.clone()));
+
+        let listener = NewRandomnessTaskListener::new(
+            0,
+            Address::random(),
+            chain_identity,
+            randomness_tasks_cache,
+            block_checkpoint_cache,
+            ADAPTER_DEPLOYED_BLOCK_HEIGHT,
+            Arc::new(RwLock::new(eq)),
+        );
+
+        (listener, counter)
+    }
+
+    fn randomness_task_log(request_id: u8, assignment_block_height: usize) -> RandomnessTaskLog {
+        RandomnessTaskLog {
+            task: RandomnessTask {
+                request_id: vec![request_id; 32],
+                subscription_id: 1,
+                group_index: 0,
+                request_type: RandomnessRequestType::Randomness,
+                params: vec![],
+                requester: Address::zero(),
+                seed: U256::from(request_id),
+                request_confirmations: 0,
+                callback_gas_limit: 0,
+                callback_max_gas_price: U256::zero(),
+                assignment_block_height,
+            },
+            block_hash: H256::zero(),
+            removed: false,
+        }
+    }
+
+    async fn last_processed_block_height(
+        listener: &NewRandomnessTaskListener<G2Curve>,
+    ) -> Option<usize> {
+        listener
+            .block_checkpoint_cache
+            .read()
+            .await
+            .get_last_processed_block_height()
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_replay_from_block_checkpoint() {
+        let (listener, counter) = build_listener(Some(150)).await;
+
+        let from_block = listener
+            .resolve_from_block(async { panic!("the checkpoint should be used") })
+            .await
+            .unwrap();
+
+        assert_eq!(Some(150), from_block);
+
+        // the task in the checkpoint block has been handled before the restart
+        listener
+            .randomness_tasks_cache
+            .write()
+            .await
+            .add(randomness_task_log(1, 150).task)
+            .await
+            .unwrap();
+
+        // the tasks are replayed from the checkpoint block
+        listener
+            .handle_randomness_task_log(randomness_task_log(1, 150))
+            .await
+            .unwrap();
+
+        assert_eq!(0, counter.count.load(Ordering::SeqCst));
+
+        listener
+            .handle_randomness_task_log(randomness_task_log(2, 160))
+            .await
+            .unwrap();
+
+        assert_eq!(1, counter.count.load(Ordering::SeqCst));
+        assert!(listener
+            .randomness_tasks_cache
+            .read()
+            .await
+            .contains(&[2; 32])
+            .await
+            .unwrap());
+        assert_eq!(Some(160), last_processed_block_height(&listener).await);
+    }
+
+    #[tokio::test]
+    async fn test_clamp_block_checkpoint_to_adapter_deployed_block_height() {
+        let (listener, _) = build_listener(Some(ADAPTER_DEPLOYED_BLOCK_HEIGHT - 50)).await;
+
+        let from_block = listener
+            .resolve_from_block(async { panic!("the checkpoint should be used") })
+            .await
+            .unwrap();
+
+        assert_eq!(Some(ADAPTER_DEPLOYED_BLOCK_HEIGHT), from_block);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_block_checkpoint_without_replay() {
+        let (listener, _) = build_listener(None).await;
+
+        let from_block = listener
+            .resolve_from_block(async { Ok(200) })
+            .await
+            .unwrap();
+
+        // nothing is replayed on the first start, the current block becomes the checkpoint
+        assert_eq!(None, from_block);
+        assert_eq!(Some(200), last_processed_block_height(&listener).await);
+
+        let from_block = listener
+            .resolve_from_block(async { panic!("the checkpoint should be used") })
+            .await
+            .unwrap();
+
+        assert_eq!(Some(200), from_block);
+    }
+
+    #[tokio::test]
+    async fn test_block_checkpoint_never_moves_backwards() {
+        let (listener, counter) = build_listener(Some(150)).await;
+
+        listener
+            .handle_randomness_task_log(randomness_task_log(1, 170))
+            .await
+            .unwrap();
+
+        assert_eq!(Some(170), last_processed_block_height(&listener).await);
+
+        // a request of an earlier block delivered late is still handled
+        listener
+            .handle_randomness_task_log(randomness_task_log(2, 160))
+            .await
+            .unwrap();
+
+        assert_eq!(2, counter.count.load(Ordering::SeqCst));
+        assert_eq!(Some(170), last_processed_block_height(&listener).await);
+    }
+
+    #[tokio::test]
+    async fn test_advance_block_checkpoint_by_handled_block_ranges() {
+        let (listener, _) = build_listener(Some(150)).await;
+
+        // the checkpoint trails the handled blocks by the reorg depth
+        listener
+            .handle_block_range(BLOCK_HASH_HISTORY_LENGTH + 150 + BLOCK_CHECKPOINT_INTERVAL)
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(150 + BLOCK_CHECKPOINT_INTERVAL),
+            last_processed_block_height(&listener).await
+        );
+
+        // and is only saved every BLOCK_CHECKPOINT_INTERVAL blocks
+        listener
+            .handle_block_range(BLOCK_HASH_HISTORY_LENGTH + 150 + 2 * BLOCK_CHECKPOINT_INTERVAL - 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            Some(150 + BLOCK_CHECKPOINT_INTERVAL),
+            last_processed_block_height(&listener).await
+        );
+
+        // an earlier block leaves it untouched
+        listener.handle_block_range(100).await.unwrap();
+        assert_eq!(
+            Some(150 + BLOCK_CHECKPOINT_INTERVAL),
+            last_processed_block_height(&listener).await
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use super::Listener;
+use crate::{
+    context::ChainIdentityHandlerType,
+    error::NodeResult,
+    event::ready_to_handle_group_relay_confirmation_task::ReadyToHandleGroupRelayConfirmationTask,
+    queue::{event_queue::EventQueue, EventPublisher},
+};
+use arpa_contract_client::controller_oracle::ControllerOracleViews;
+use arpa_core::GroupRelayConfirmationTask;
+use arpa_dal::{BLSTasksHandler, BlockInfoHandler, GroupInfoHandler};
+use async_trait::async_trait;
+use ethers::providers::Middleware;
+use std::{marker::PhantomData, sync::Arc};
+use threshold_bls::group::Curve;
+use tokio::sync::RwLock;
+
+pub struct ReadyToHandleGroupRelayConfirmationTaskListener<PC: Curve> {
+    chain_id: usize,
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+    group_relay_confirmation_tasks_cache:
+        Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>>>,
+    eq: Arc<RwLock<EventQueue>>,
+    pc: PhantomData<PC>,
+}
+
+impl<PC: Curve> std::fmt::Display for ReadyToHandleGroupRelayConfirmationTaskListener<PC> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ReadyToHandleGroupRelayConfirmationTaskListener")
+    }
+}
+
+impl<PC: Curve> ReadyToHandleGroupRelayConfirmationTaskListener<PC> {
+    pub fn new(
+        chain_id: usize,
+        chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+        block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+        group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+        group_relay_confirmation_tasks_cache: Arc<
+            RwLock<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>>,
+        >,
+        eq: Arc<RwLock<EventQueue>>,
+    ) -> Self {
+        ReadyToHandleGroupRelayConfirmationTaskListener {
+            chain_id,
+            chain_identity,
+            block_cache,
+            group_cache,
+            group_relay_confirmation_tasks_cache,
+            eq,
+            pc: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> EventPublisher<ReadyToHandleGroupRelayConfirmationTask>
+    for ReadyToHandleGroupRelayConfirmationTaskListener<PC>
+{
+    async fn publish(&self, event: ReadyToHandleGroupRelayConfirmationTask) {
+        self.eq.read().await.publish(event).await;
+    }
+}
+
+#[async_trait]
+impl<PC: Curve + Sync + Send> Listener for ReadyToHandleGroupRelayConfirmationTaskListener<PC> {
+    async fn listen(&self) -> NodeResult<()> {
+        let is_bls_ready = self.group_cache.read().await.get_state();
+
+        if let Ok(true) = is_bls_ready {
+            let current_group_index = self.group_cache.read().await.get_index()?;
+
+            let current_group_epoch = self.group_cache.read().await.get_epoch()?;
+
+            let current_block_height = self.block_cache.read().await.get_block_height();
+
+            let available_tasks = self
+                .group_relay_confirmation_tasks_cache
+                .write()
+                .await
+                .check_and_get_available_tasks(current_block_height, current_group_index, 0)
+                .await?;
+
+            if available_tasks.is_empty() {
+                return Ok(());
+            }
+
+            let mut tasks_to_process: Vec<GroupRelayConfirmationTask> = vec![];
+
+            let client = self
+                .chain_identity
+                .read()
+                .await
+                .build_controller_oracle_client();
+
+            // only confirm the epoch we are actually in, and only if nobody has confirmed it yet
+            for task in available_tasks {
+                if task.relayed_group_epoch != current_group_epoch {
+                    continue;
+                }
+
+                if let Ok(group) =
+                    ControllerOracleViews::<PC>::get_group(&client, task.relayed_group_index).await
+                {
+                    if group.epoch < task.relayed_group_epoch {
+                        tasks_to_process.push(task);
+                    }
+                }
+            }
+
+            if !tasks_to_process.is_empty() {
+                self.publish(ReadyToHandleGroupRelayConfirmationTask {
+                    chain_id: self.chain_id,
+                    tasks: tasks_to_process,
+                })
+                .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn handle_interruption(&self) -> NodeResult<()> {
+        self.chain_identity
+            .read()
+            .await
+            .get_provider()
+            .get_net_version()
+            .await?;
+
+        Ok(())
+    }
+
+    async fn chain_id(&self) -> usize {
+        self.chain_id
+    }
+}
@@ -5,11 +5,17 @@ use crate::{
     event::ready_to_handle_randomness_task::ReadyToHandleRandomnessTask,
     queue::{event_queue::EventQueue, EventPublisher},
 };
-use arpa_contract_client::adapter::AdapterViews;
-use arpa_core::RandomnessTask;
+use arpa_contract_client::adapter::{AdapterLogs, AdapterViews};
+use arpa_core::{
+    log::{build_task_related_payload, LogType},
+    metrics::RANDOMNESS_TASKS,
+    BLSTaskType, RandomnessTask, TaskType,
+};
 use arpa_dal::{BLSTasksHandler, BlockInfoHandler, GroupInfoHandler};
 use async_trait::async_trait;
 use ethers::{providers::Middleware, types::Address};
+use log::{info, warn};
+use serde_json::json;
 use std::{marker::PhantomData, sync::Arc};
 use threshold_bls::group::Curve;
 use tokio::sync::RwLock;
@@ -101,6 +107,77 @@ impl<PC: Curve + Sync + Send> Listener for ReadyToHandleRandomnessTaskListener<P
                 .build_adapter_client(self.id_address);
 
             for task in available_tasks {
+                // the request block may have been orphaned by a reorg while the task was
+                // waiting for its confirmations, withdraw the task if so
+                let block_hash = self
+                    .block_cache
+                    .read()
+                    .await
+                    .get_block_hash(task.assignment_block_height);
+
+                match client
+                    .is_randomness_task_requested(
+                        &task.request_id,
+                        task.assignment_block_height,
+                        block_hash,
+                    )
+                    .await
+                {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        info!(
+                            "{}",
+                            build_task_related_payload(
+                                LogType::TaskWithdrawn,
+                                "Randomness task withdrawn as its request block is orphaned.",
+                                self.chain_id,
+                                &task.request_id,
+                                TaskType::BLS(BLSTaskType::Randomness),
+                                json!(task),
+                                None
+                            )
+                        );
+
+                        self.randomness_tasks_cache
+                            .write()
+                            .await
+                            .remove(&task.request_id)
+                            .await?;
+
+                        RANDOMNESS_TASKS
+                            .with_label_values(&[&self.chain_id.to_string(), "withdrawn"])
+                            .inc();
+
+                        continue;
+                    }
+                    // the request is not confirmed yet, the task is handed out again on the next tick
+                    Err(e) => {
+                        warn!(
+                            "{}",
+                            build_task_related_payload(
+                                LogType::TaskRescheduled,
+                                &format!(
+                                    "Failed to confirm the request of the randomness task, retry later: {:?}",
+                                    e
+                                ),
+                                self.chain_id,
+                                &task.request_id,
+                                TaskType::BLS(BLSTaskType::Randomness),
+                                json!(task),
+                                None
+                            )
+                        );
+
+                        self.randomness_tasks_cache
+                            .write()
+                            .await
+                            .release(&task.request_id)
+                            .await?;
+
+                        continue;
+                    }
+                }
+
                 if let Ok(true) = client.is_task_pending(&task.request_id).await {
                     tasks_to_process.push(task);
                 }
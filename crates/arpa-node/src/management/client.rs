@@ -1,10 +1,15 @@
 use crate::error::{NodeError, NodeResult};
 use crate::rpc_stub::management::management_service_client::ManagementServiceClient;
-use crate::rpc_stub::management::ListFixedTasksRequest;
+use crate::rpc_stub::management::{
+    GetDkgProgressReply, GetDkgProgressRequest, GetRandomnessResultReply,
+    GetRandomnessResultRequest, ListDynamicTasksRequest, ListFixedTasksRequest,
+    ListPendingRandomnessTasksRequest, ListRelayedChainsRequest, NodeEvent, RandomnessTask,
+    RelayedChain, ReloadConfigRequest, SubscribeEventsRequest,
+};
 use tonic::codegen::InterceptedService;
 use tonic::service::Interceptor;
 use tonic::transport::Channel;
-use tonic::Request;
+use tonic::{Request, Streaming};
 
 #[derive(Clone, Debug)]
 pub struct GeneralManagementClient {
@@ -55,4 +60,93 @@ impl GeneralManagementClient {
             .map(|r| r.into_inner().fixed_tasks)
             .map_err(|status| status.into())
     }
+
+    pub async fn list_pending_randomness_tasks(
+        &self,
+        chain_id: usize,
+    ) -> NodeResult<Vec<RandomnessTask>> {
+        let mut management_client = self.prepare_service_client().await?;
+
+        let request = Request::new(ListPendingRandomnessTasksRequest {
+            chain_id: chain_id as u32,
+        });
+        management_client
+            .list_pending_randomness_tasks(request)
+            .await
+            .map(|r| r.into_inner().tasks)
+            .map_err(|status| status.into())
+    }
+
+    pub async fn get_randomness_result(
+        &self,
+        request_id: Vec<u8>,
+    ) -> NodeResult<GetRandomnessResultReply> {
+        let mut management_client = self.prepare_service_client().await?;
+
+        let request = Request::new(GetRandomnessResultRequest { request_id });
+        management_client
+            .get_randomness_result(request)
+            .await
+            .map(|r| r.into_inner())
+            .map_err(|status| status.into())
+    }
+
+    pub async fn get_dkg_progress(&self) -> NodeResult<GetDkgProgressReply> {
+        let mut management_client = self.prepare_service_client().await?;
+
+        let request = Request::new(GetDkgProgressRequest {});
+        management_client
+            .get_dkg_progress(request)
+            .await
+            .map(|r| r.into_inner())
+            .map_err(|status| status.into())
+    }
+
+    pub async fn list_relayed_chains(&self) -> NodeResult<Vec<RelayedChain>> {
+        let mut management_client = self.prepare_service_client().await?;
+
+        let request = Request::new(ListRelayedChainsRequest {});
+        management_client
+            .list_relayed_chains(request)
+            .await
+            .map(|r| r.into_inner().relayed_chains)
+            .map_err(|status| status.into())
+    }
+
+    pub async fn list_dynamic_tasks(&self) -> NodeResult<Vec<String>> {
+        let mut management_client = self.prepare_service_client().await?;
+
+        let request = Request::new(ListDynamicTasksRequest {});
+        management_client
+            .list_dynamic_tasks(request)
+            .await
+            .map(|r| r.into_inner().dynamic_tasks)
+            .map_err(|status| status.into())
+    }
+
+    pub async fn subscribe_events(
+        &self,
+        topics: Vec<i32>,
+        chain_ids: Vec<u32>,
+    ) -> NodeResult<Streaming<NodeEvent>> {
+        let mut management_client = self.prepare_service_client().await?;
+
+        let request = Request::new(SubscribeEventsRequest { topics, chain_ids });
+        management_client
+            .subscribe_events(request)
+            .await
+            .map(|r| r.into_inner())
+            .map_err(|status| status.into())
+    }
+
+    pub async fn reload_config(&self) -> NodeResult<Vec<String>> {
+        let mut management_client = self.prepare_service_client().await?;
+
+        let request = Request::new(ReloadConfigRequest {});
+        management_client
+            .reload_config(request)
+            .await
+            .map(|r| r.into_inner().changes)
+            .map_err(|status| status.into())
+    }
 }
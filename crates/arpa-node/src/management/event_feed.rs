@@ -0,0 +1,318 @@
+//! Streams the events of the node to the subscribers of the management service.
+//!
+//! The events are the ones published to the [`EventQueue`](crate::queue::event_queue::EventQueue)
+//! along with the transaction receipts logged by the node, each converted to a typed
+//! [`NodeEvent`] and filtered by the topics and the chains of the subscription.
+
+use crate::rpc_stub::management::{
+    node_event, node_event::Topic as EventTopic, DkgTaskEvent, EventsSkippedEvent,
+    GroupRelayConfirmationTask, GroupRelayTask, NodeEvent, PartialSignature, SignatureResult,
+    SubscribeEventsRequest, TransactionReceiptEvent,
+};
+use arpa_core::{
+    address_to_string, log::TransactionReceiptEvent as ModelTransactionReceiptEvent, DKGTask,
+    GroupRelayConfirmationTask as ModelGroupRelayConfirmationTask,
+    GroupRelayTask as ModelGroupRelayTask, PartialSignature as ModelPartialSignature,
+};
+use arpa_dal::cache::{
+    GroupRelayConfirmationResultCache, GroupRelayResultCache, RandomnessResultCache,
+};
+use ethers::types::Address;
+use log::warn;
+use std::collections::{BTreeMap, HashSet};
+use tokio::sync::{broadcast, broadcast::error::RecvError, mpsc};
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::Status;
+
+/// The events which are not sent to a slow subscriber yet are buffered up to this size, after
+/// which the subscriber lags behind the feed.
+const EVENT_STREAM_BUFFER_SIZE: usize = 128;
+
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    topics: HashSet<i32>,
+    chain_ids: HashSet<u32>,
+}
+
+impl From<SubscribeEventsRequest> for EventFilter {
+    fn from(req: SubscribeEventsRequest) -> Self {
+        EventFilter {
+            topics: req.topics.into_iter().collect(),
+            chain_ids: req.chain_ids.into_iter().collect(),
+        }
+    }
+}
+
+impl EventFilter {
+    /// An empty filter matches everything, and the events which are not bound to a chain
+    /// match any chain.
+    pub fn matches(&self, event: &NodeEvent) -> bool {
+        (self.topics.is_empty() || self.topics.contains(&event.topic))
+            && (self.chain_ids.is_empty()
+                || event.chain_id == 0
+                || self.chain_ids.contains(&event.chain_id))
+    }
+}
+
+/// Forwards the events and the transaction receipts which match the filter to the returned
+/// stream until the subscriber goes away. The events a slow subscriber lagged behind on are
+/// replaced with an `EventsSkipped` event carrying how many of them are skipped.
+pub fn subscribe_events(
+    mut events: broadcast::Receiver<NodeEvent>,
+    mut receipts: broadcast::Receiver<ModelTransactionReceiptEvent>,
+    filter: EventFilter,
+) -> ReceiverStream<Result<NodeEvent, Status>> {
+    let (sender, receiver) = mpsc::channel(EVENT_STREAM_BUFFER_SIZE);
+
+    tokio::spawn(async move {
+        loop {
+            let event = tokio::select! {
+                event = events.recv() => event,
+                receipt = receipts.recv() => receipt.map(NodeEvent::from),
+                _ = sender.closed() => return,
+            };
+
+            match event {
+                Ok(event) => {
+                    if filter.matches(&event) && sender.send(Ok(event)).await.is_err() {
+                        return;
+                    }
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!(
+                        "The event subscriber lagged behind, {} events are skipped.",
+                        skipped
+                    );
+
+                    if sender
+                        .send(Ok(NodeEvent::events_skipped(skipped)))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                Err(RecvError::Closed) => return,
+            }
+        }
+    });
+
+    ReceiverStream::new(receiver)
+}
+
+impl NodeEvent {
+    fn events_skipped(skipped: u64) -> Self {
+        NodeEvent {
+            topic: EventTopic::EventsSkipped as i32,
+            chain_id: 0,
+            event: Some(node_event::Event::EventsSkipped(EventsSkippedEvent {
+                skipped,
+            })),
+        }
+    }
+}
+
+impl From<ModelTransactionReceiptEvent> for NodeEvent {
+    fn from(r: ModelTransactionReceiptEvent) -> Self {
+        NodeEvent {
+            topic: EventTopic::TransactionReceipt as i32,
+            chain_id: r.chain_id.unwrap_or_default() as u32,
+            event: Some(node_event::Event::TransactionReceipt(
+                TransactionReceiptEvent {
+                    log_type: format!("{:?}", r.log_type),
+                    message: r.message,
+                    group_index: r.group_index.map(|index| index as u32),
+                    group_epoch: r.group_epoch.map(|epoch| epoch as u32),
+                    request_id: r.request_id.unwrap_or_default(),
+                    transaction_hash: format!("{:?}", r.transaction_hash),
+                    gas_used: r.gas_used.to_string(),
+                    effective_gas_price: r.effective_gas_price.to_string(),
+                },
+            )),
+        }
+    }
+}
+
+impl DkgTaskEvent {
+    pub fn new(t: &DKGTask, self_index: usize) -> Self {
+        DkgTaskEvent {
+            group_index: t.group_index as u32,
+            epoch: t.epoch as u32,
+            size: t.size as u32,
+            threshold: t.threshold as u32,
+            members: t.members.iter().copied().map(address_to_string).collect(),
+            assignment_block_height: t.assignment_block_height as u32,
+            coordinator_address: address_to_string(t.coordinator_address),
+            self_index: self_index as u32,
+        }
+    }
+}
+
+impl From<&ModelGroupRelayTask> for GroupRelayTask {
+    fn from(t: &ModelGroupRelayTask) -> Self {
+        GroupRelayTask {
+            request_id: t.request_id.clone(),
+            controller_global_epoch: t.controller_global_epoch as u32,
+            relayed_group_index: t.relayed_group_index as u32,
+            relayed_group_epoch: t.relayed_group_epoch as u32,
+            assignment_block_height: t.assignment_block_height as u32,
+            relayed_chain_id: t.relayed_chain_id as u32,
+        }
+    }
+}
+
+impl From<&ModelGroupRelayConfirmationTask> for GroupRelayConfirmationTask {
+    fn from(t: &ModelGroupRelayConfirmationTask) -> Self {
+        GroupRelayConfirmationTask {
+            request_id: t.request_id.clone(),
+            group_relay_cache_index: t.group_relay_cache_index as u32,
+            relayed_group_index: t.relayed_group_index as u32,
+            relayed_group_epoch: t.relayed_group_epoch as u32,
+            relayer_group_index: t.relayer_group_index as u32,
+            assignment_block_height: t.assignment_block_height as u32,
+        }
+    }
+}
+
+impl From<&RandomnessResultCache> for SignatureResult {
+    fn from(r: &RandomnessResultCache) -> Self {
+        build_signature_result(
+            &r.randomness_task.request_id,
+            r.group_index,
+            &r.message,
+            r.threshold,
+            &r.partial_signatures,
+            r.committed_times,
+        )
+    }
+}
+
+impl From<&GroupRelayResultCache> for SignatureResult {
+    fn from(r: &GroupRelayResultCache) -> Self {
+        build_signature_result(
+            &r.group_relay_task.request_id,
+            r.group_index,
+            &r.message,
+            r.threshold,
+            &r.partial_signatures,
+            r.committed_times,
+        )
+    }
+}
+
+impl From<&GroupRelayConfirmationResultCache> for SignatureResult {
+    fn from(r: &GroupRelayConfirmationResultCache) -> Self {
+        build_signature_result(
+            &r.group_relay_confirmation_task.request_id,
+            r.group_index,
+            &r.message,
+            r.threshold,
+            &r.partial_signatures,
+            r.committed_times,
+        )
+    }
+}
+
+fn build_signature_result(
+    request_id: &[u8],
+    group_index: usize,
+    message: &[u8],
+    threshold: usize,
+    partial_signatures: &BTreeMap<Address, ModelPartialSignature>,
+    committed_times: usize,
+) -> SignatureResult {
+    SignatureResult {
+        request_id: request_id.to_vec(),
+        group_index: group_index as u32,
+        message: message.to_vec(),
+        threshold: threshold as u32,
+        partial_signatures: partial_signatures
+            .iter()
+            .map(|(id_address, s)| PartialSignature {
+                id_address: address_to_string(*id_address),
+                index: s.index as u32,
+                signed_partial_signature: s.signed_partial_signature.clone(),
+            })
+            .collect(),
+        committed_times: committed_times as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{subscribe_events, EventFilter};
+    use crate::{
+        event::{new_block::NewBlock, types::Topic, Event},
+        rpc_stub::management::{
+            node_event, node_event::Topic as EventTopic, DkgPhaseEvent, EventsSkippedEvent,
+            NodeEvent,
+        },
+    };
+    use ethers::types::H256;
+    use tokio::sync::broadcast;
+    use tokio_stream::StreamExt;
+
+    #[test]
+    fn test_filter_events_by_topic_and_chain() {
+        let new_block = NewBlock::new(31337, 100, H256::zero(), H256::zero()).to_node_event();
+        let dkg_phase = NodeEvent::new(
+            Topic::DKGPhase,
+            None,
+            node_event::Event::DkgPhase(DkgPhaseEvent { phase: 1 }),
+        );
+
+        assert_eq!(EventTopic::NewBlock as i32, new_block.topic);
+        assert_eq!(31337, new_block.chain_id);
+
+        let filter = EventFilter::default();
+        assert!(filter.matches(&new_block));
+        assert!(filter.matches(&dkg_phase));
+
+        let filter = EventFilter {
+            topics: [EventTopic::DkgPhase as i32].into(),
+            chain_ids: [].into(),
+        };
+        assert!(!filter.matches(&new_block));
+        assert!(filter.matches(&dkg_phase));
+
+        let filter = EventFilter {
+            topics: [].into(),
+            chain_ids: [1].into(),
+        };
+        assert!(!filter.matches(&new_block));
+        // the events of the DKG are not bound to a chain
+        assert!(filter.matches(&dkg_phase));
+    }
+    #[tokio::test]
+    async fn test_report_skipped_events_to_lagging_subscriber() {
+        let (events_sender, events) = broadcast::channel(1);
+        let (_receipts_sender, receipts) = broadcast::channel(1);
+
+        for block_height in 100..103 {
+            events_sender
+                .send(
+                    NewBlock::new(31337, block_height, H256::zero(), H256::zero()).to_node_event(),
+                )
+                .unwrap();
+        }
+
+        // the gap is reported even if the subscriber is only interested in other topics
+        let filter = EventFilter {
+            topics: [EventTopic::NewBlock as i32].into(),
+            chain_ids: [].into(),
+        };
+        let mut stream = subscribe_events(events, receipts, filter);
+
+        let skipped = stream.next().await.unwrap().unwrap();
+        assert_eq!(EventTopic::EventsSkipped as i32, skipped.topic);
+        assert_eq!(
+            Some(node_event::Event::EventsSkipped(EventsSkippedEvent {
+                skipped: 2
+            })),
+            skipped.event
+        );
+
+        let new_block = stream.next().await.unwrap().unwrap();
+        assert_eq!(EventTopic::NewBlock as i32, new_block.topic);
+    }
+}
@@ -1,30 +1,47 @@
 use super::{
-    algorithm::bls::{BLSCore, SimpleBLSCore},
+    algorithm::{
+        bls::{BLSCore, SimpleBLSCore},
+        dkg::DKGCheckpoint,
+    },
     committer::{client::GeneralCommitterClient, CommitterClient, CommitterService},
     context::{chain::Chain, types::GeneralContext, Context, ContextFetcher},
     error::NodeResult,
     scheduler::FixedTaskScheduler,
 };
 use anyhow::Result;
-use arpa_contract_client::controller::ControllerTransactions;
+use arpa_contract_client::{
+    controller::{ControllerTransactions, ControllerViews},
+    coordinator::CoordinatorViews,
+};
 use arpa_core::{
-    BLSTaskType, ComponentTaskType, DKGStatus, ExponentialBackoffRetryDescriptor, Group,
-    ListenerDescriptor, ListenerType, SchedulerError, SchedulerResult,
+    BLSTaskType, ComponentTaskType, Config, DKGStatus, ExponentialBackoffRetryDescriptor, Group,
+    ListenerDescriptor, ListenerType, RandomnessTask, SchedulerError, SchedulerResult,
     DEFAULT_COMMIT_PARTIAL_SIGNATURE_RETRY_BASE, DEFAULT_COMMIT_PARTIAL_SIGNATURE_RETRY_FACTOR,
     DEFAULT_COMMIT_PARTIAL_SIGNATURE_RETRY_MAX_ATTEMPTS,
     DEFAULT_COMMIT_PARTIAL_SIGNATURE_RETRY_USE_JITTER,
 };
-use arpa_dal::error::DataAccessResult;
+use arpa_dal::{
+    cache::RandomnessResultCache,
+    error::{DataAccessError, DataAccessResult, RandomnessTaskError},
+    BLSResultCacheState, GroupInfoHandler, SignatureResultCacheHandler,
+};
 use ethers::types::Address;
+use log::error;
+use std::sync::Arc;
 use threshold_bls::{
     group::Curve,
     sig::{Share, SignatureScheme, ThresholdScheme},
 };
+use tokio::sync::RwLock;
 
 pub mod server;
 
 pub mod client;
 
+pub mod event_feed;
+
+pub mod reload;
+
 pub struct NodeInfo<PC: Curve> {
     pub id_address: Address,
     pub node_rpc_endpoint: String,
@@ -40,6 +57,38 @@ pub struct GroupInfo<PC: Curve> {
     pub dkg_start_block_height: usize,
 }
 
+pub struct RandomnessResult {
+    pub chain_id: usize,
+    pub result_cache: RandomnessResultCache,
+    pub state: BLSResultCacheState,
+    /// The committers of the group if the result is signed by the current epoch of the group.
+    pub committers: Vec<Address>,
+}
+
+pub struct DKGProgress {
+    pub group_index: usize,
+    pub epoch: usize,
+    pub dkg_status: DKGStatus,
+    /// The last phase this node has published its part of.
+    pub checkpoint: Option<&'static str>,
+    /// What the coordinator has seen, if the DKG is still running.
+    pub coordinator_progress: Option<CoordinatorProgress>,
+}
+
+pub struct CoordinatorProgress {
+    pub coordinator_address: Address,
+    pub phase: i8,
+    pub shares: usize,
+    pub responses: usize,
+    pub justifications: usize,
+}
+
+pub struct RelayedChainInfo {
+    pub chain_id: usize,
+    pub description: String,
+    pub block_height: usize,
+}
+
 pub trait ServiceClient<C> {
     async fn prepare_service_client(&self) -> NodeResult<C>;
 }
@@ -65,15 +114,37 @@ pub trait ComponentService {
         chain_id: usize,
         task_type: ListenerType,
     ) -> SchedulerResult<()>;
+
+    async fn list_dynamic_tasks(&self) -> SchedulerResult<Vec<ComponentTaskType>>;
 }
+
+pub trait ConfigService {
+    /// Loads the config file again and applies the listeners, the retry descriptors of the time
+    /// limits and the log level to the running node, returning the changes applied. The config
+    /// is rejected as a whole if anything else has changed, and what has been applied is rolled
+    /// back if a change fails to apply.
+    async fn reload_config(&mut self) -> NodeResult<Vec<String>>;
+}
+
 pub trait DBService<PC: Curve> {
     async fn get_node_info(&self) -> DataAccessResult<NodeInfo<PC>>;
 
     async fn get_group_info(&self) -> DataAccessResult<GroupInfo<PC>>;
+
+    async fn list_pending_randomness_tasks(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Vec<RandomnessTask>>;
+
+    async fn get_randomness_result(&self, request_id: &[u8]) -> DataAccessResult<RandomnessResult>;
+
+    async fn list_relayed_chains(&self) -> DataAccessResult<Vec<RelayedChainInfo>>;
 }
 
 pub trait DKGService {
     async fn post_process_dkg(&self) -> NodeResult<()>;
+
+    async fn get_dkg_progress(&self) -> NodeResult<DKGProgress>;
 }
 
 pub trait BLSRandomnessService<PC: Curve> {
@@ -227,6 +298,121 @@ where
             .abort(&ComponentTaskType::Listener(chain_id, task_type))
             .await
     }
+
+    async fn list_dynamic_tasks(&self) -> SchedulerResult<Vec<ComponentTaskType>> {
+        Ok(self
+            .get_dynamic_task_handler()
+            .read()
+            .await
+            .get_tasks()
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+}
+
+impl<
+        PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+    > ConfigService for GeneralContext<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    async fn reload_config(&mut self) -> NodeResult<Vec<String>> {
+        let config = self.get_config().reload()?;
+
+        self.get_config().check_reloadable(&config)?;
+
+        let running = self.get_config().clone();
+
+        match self.apply_config(&running, &config).await {
+            Ok(changes) => {
+                self.set_config(config);
+
+                Ok(changes)
+            }
+            Err(e) => {
+                // puts back what has been applied before the error, so that the running node
+                // still matches the running config
+                if let Err(rollback_err) = self.apply_config(&config, &running).await {
+                    error!(
+                        "Failed to roll back the partially applied config: {:?}",
+                        rollback_err
+                    );
+                }
+
+                Err(e)
+            }
+        }
+    }
+}
+
+impl<
+        PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Send
+            + Sync
+            + 'static,
+    > GeneralContext<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    /// Applies the log level, the listeners and the retry descriptors of the time limits of the
+    /// `to` config to the node currently running with the `from` config.
+    async fn apply_config(&mut self, from: &Config, to: &Config) -> NodeResult<Vec<String>> {
+        let mut changes = vec![];
+
+        let log_level = to.get_logger_descriptor().get_log_level();
+
+        if log_level != from.get_logger_descriptor().get_log_level() {
+            log::set_max_level(log_level);
+
+            changes.push(format!("set the log level to {}", log_level));
+        }
+
+        let eq = self.get_event_queue();
+
+        let f_ts = self.get_fixed_task_handler();
+
+        changes.extend(
+            self.get_main_chain_mut()
+                .reload_components(
+                    eq.clone(),
+                    f_ts.clone(),
+                    *to.get_time_limits(),
+                    to.get_listeners().clone(),
+                )
+                .await?,
+        );
+
+        for relayed_chain_config in to.get_relayed_chains() {
+            if let Some(relayed_chain) =
+                self.get_relayed_chain_mut(relayed_chain_config.get_chain_id())
+            {
+                changes.extend(
+                    relayed_chain
+                        .reload_components(
+                            eq.clone(),
+                            f_ts.clone(),
+                            *relayed_chain_config.get_time_limits(),
+                            relayed_chain_config.get_listeners().clone(),
+                        )
+                        .await?,
+                );
+            }
+        }
+
+        Ok(changes)
+    }
 }
 
 impl<
@@ -323,6 +509,121 @@ where
             dkg_start_block_height,
         })
     }
+
+    async fn list_pending_randomness_tasks(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Vec<RandomnessTask>> {
+        let main_chain_id = self
+            .get_main_chain()
+            .get_chain_identity()
+            .read()
+            .await
+            .get_chain_id();
+
+        let randomness_tasks_cache = if chain_id == main_chain_id {
+            self.get_main_chain().get_randomness_tasks_cache()
+        } else {
+            self.get_relayed_chain(chain_id)
+                .ok_or(DataAccessError::InvalidChainId(chain_id))?
+                .get_randomness_tasks_cache()
+        };
+
+        let tasks = randomness_tasks_cache.read().await.get_unhandled().await?;
+
+        Ok(tasks)
+    }
+
+    async fn get_randomness_result(&self, request_id: &[u8]) -> DataAccessResult<RandomnessResult> {
+        let main_chain = self.get_main_chain();
+
+        let main_chain_id = main_chain.get_chain_identity().read().await.get_chain_id();
+
+        if let Some(result) = find_randomness_result(
+            main_chain_id,
+            main_chain.get_randomness_result_cache(),
+            main_chain.get_group_cache(),
+            request_id,
+        )
+        .await?
+        {
+            return Ok(result);
+        }
+
+        for chain_id in self.get_supported_relayed_chains() {
+            let relayed_chain = self.get_relayed_chain(chain_id).unwrap();
+
+            if let Some(result) = find_randomness_result(
+                chain_id,
+                relayed_chain.get_randomness_result_cache(),
+                relayed_chain.get_group_cache(),
+                request_id,
+            )
+            .await?
+            {
+                return Ok(result);
+            }
+        }
+
+        Err(RandomnessTaskError::NoRandomnessTask(format!("{:?}", request_id)).into())
+    }
+
+    async fn list_relayed_chains(&self) -> DataAccessResult<Vec<RelayedChainInfo>> {
+        let mut relayed_chains = vec![];
+
+        for chain_id in self.get_supported_relayed_chains() {
+            let relayed_chain = self.get_relayed_chain(chain_id).unwrap();
+
+            let block_height = relayed_chain
+                .get_block_cache()
+                .read()
+                .await
+                .get_block_height();
+
+            relayed_chains.push(RelayedChainInfo {
+                chain_id,
+                description: relayed_chain.description().to_string(),
+                block_height,
+            });
+        }
+
+        relayed_chains.sort_by_key(|relayed_chain| relayed_chain.chain_id);
+
+        Ok(relayed_chains)
+    }
+}
+
+async fn find_randomness_result<PC: Curve>(
+    chain_id: usize,
+    randomness_result_cache: Arc<
+        RwLock<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>>,
+    >,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+    request_id: &[u8],
+) -> DataAccessResult<Option<RandomnessResult>> {
+    let randomness_result_cache = randomness_result_cache.read().await;
+
+    if !randomness_result_cache.contains(request_id).await? {
+        return Ok(None);
+    }
+
+    let result = randomness_result_cache.get(request_id).await?;
+
+    let group_cache = group_cache.read().await;
+
+    let committers = match group_cache.get_index() {
+        Ok(group_index) if group_index == result.result_cache.group_index => {
+            group_cache.get_committers()?
+        }
+        _ => vec![],
+    };
+
+    Ok(Some(RandomnessResult {
+        chain_id,
+        result_cache: result.result_cache,
+        state: result.state,
+        committers,
+    }))
 }
 
 impl<
@@ -364,6 +665,68 @@ where
 
         Ok(())
     }
+
+    async fn get_dkg_progress(&self) -> NodeResult<DKGProgress> {
+        let (group_index, epoch, dkg_status, checkpoint) = {
+            let group_cache = self.get_main_chain().get_group_cache();
+            let group_cache = group_cache.read().await;
+
+            let checkpoint = match group_cache.get_dkg_checkpoint()? {
+                Some(checkpoint) => {
+                    Some(bincode::deserialize::<DKGCheckpoint<PC>>(checkpoint)?.name())
+                }
+                None => None,
+            };
+
+            (
+                group_cache.get_index()?,
+                group_cache.get_epoch()?,
+                group_cache.get_dkg_status()?,
+                checkpoint,
+            )
+        };
+
+        let coordinator_progress = if dkg_status == DKGStatus::InPhase {
+            let chain_identity = self.get_main_chain().get_chain_identity();
+            let chain_identity = chain_identity.read().await;
+
+            let coordinator_address = ControllerViews::<PC>::get_coordinator(
+                &chain_identity.build_controller_client(),
+                group_index,
+            )
+            .await?;
+
+            if coordinator_address == Address::zero() {
+                None
+            } else {
+                let coordinator_client =
+                    chain_identity.build_coordinator_client(coordinator_address);
+
+                // a member that has not published a bundle for a phase is left empty
+                let count_published = |bundles: Vec<Vec<u8>>| {
+                    bundles.iter().filter(|bundle| !bundle.is_empty()).count()
+                };
+
+                Some(CoordinatorProgress {
+                    coordinator_address,
+                    phase: coordinator_client.in_phase().await?,
+                    shares: count_published(coordinator_client.get_shares().await?),
+                    responses: count_published(coordinator_client.get_responses().await?),
+                    justifications: count_published(coordinator_client.get_justifications().await?),
+                })
+            }
+        } else {
+            None
+        };
+
+        Ok(DKGProgress {
+            group_index,
+            epoch,
+            dkg_status,
+            checkpoint,
+            coordinator_progress,
+        })
+    }
 }
 
 impl<
@@ -530,6 +893,7 @@ where
             committer_id_address,
             endpoint,
             commit_partial_signature_retry_descriptor,
+            self.get_committer_credentials().clone(),
         );
 
         committer_client
@@ -0,0 +1,62 @@
+use super::ConfigService;
+use crate::context::types::GeneralContext;
+use arpa_core::log::{build_general_payload, LogType};
+use log::{error, info};
+use std::sync::Arc;
+use threshold_bls::{
+    group::Curve,
+    sig::{SignatureScheme, ThresholdScheme},
+};
+use tokio::{
+    signal::unix::{signal, SignalKind},
+    sync::RwLock,
+};
+
+/// Reloads the config on every SIGHUP the node receives, the same as the `ReloadConfig` rpc.
+pub async fn reload_config_on_hangup<
+    PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+    S: SignatureScheme
+        + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    context: Arc<RwLock<GeneralContext<PC, S>>>,
+) where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    let mut hangup = match signal(SignalKind::hangup()) {
+        Ok(hangup) => hangup,
+        Err(e) => {
+            error!("Failed to listen to SIGHUP: {:?}", e);
+            return;
+        }
+    };
+
+    while hangup.recv().await.is_some() {
+        match context.write().await.reload_config().await {
+            Ok(changes) => {
+                info!(
+                    "{}",
+                    build_general_payload(
+                        LogType::ConfigReloaded,
+                        &format!("Config reloaded with changes: {:?}", changes),
+                        None
+                    )
+                );
+            }
+            Err(e) => {
+                error!(
+                    "{}",
+                    build_general_payload(
+                        LogType::ConfigReloadFailed,
+                        &format!("Config reload failed: {}", e),
+                        None
+                    )
+                );
+            }
+        }
+    }
+}
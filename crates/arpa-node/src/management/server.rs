@@ -1,24 +1,30 @@
 use crate::context::types::GeneralContext;
 use crate::context::ContextFetcher;
 use crate::error::NodeError;
-use crate::management::ComponentService;
+use crate::management::{ComponentService, ConfigService};
 use crate::rpc_stub::management::management_service_server::{
     ManagementService, ManagementServiceServer,
 };
 use crate::rpc_stub::management::{
-    AggregatePartialSigsReply, AggregatePartialSigsRequest, GetGroupInfoReply, GetGroupInfoRequest,
-    GetNodeInfoReply, GetNodeInfoRequest, Group, ListFixedTasksReply, ListFixedTasksRequest,
-    Member, NodeActivateReply, NodeActivateRequest, NodeQuitReply, NodeQuitRequest,
-    NodeRegisterReply, NodeRegisterRequest, PartialSignReply, PartialSignRequest,
-    PostProcessDkgReply, PostProcessDkgRequest, SendPartialSigReply, SendPartialSigRequest,
-    ShutdownListenerReply, ShutdownListenerRequest, ShutdownNodeReply, ShutdownNodeRequest,
-    StartListenerReply, StartListenerRequest, VerifyPartialSigsReply, VerifyPartialSigsRequest,
-    VerifySigReply, VerifySigRequest,
+    AggregatePartialSigsReply, AggregatePartialSigsRequest, GetDkgProgressReply,
+    GetDkgProgressRequest, GetGroupInfoReply, GetGroupInfoRequest, GetNodeInfoReply,
+    GetNodeInfoRequest, GetRandomnessResultReply, GetRandomnessResultRequest, Group,
+    ListDynamicTasksReply, ListDynamicTasksRequest, ListFixedTasksReply, ListFixedTasksRequest,
+    ListPendingRandomnessTasksReply, ListPendingRandomnessTasksRequest, ListRelayedChainsReply,
+    ListRelayedChainsRequest, Member, NodeActivateReply, NodeActivateRequest, NodeEvent,
+    NodeQuitReply, NodeQuitRequest, NodeRegisterReply, NodeRegisterRequest, PartialSignReply,
+    PartialSignRequest, PartialSignature, PostProcessDkgReply, PostProcessDkgRequest,
+    RandomnessResultState, RandomnessTask, RelayedChain, ReloadConfigReply, ReloadConfigRequest,
+    SendPartialSigReply, SendPartialSigRequest, ShutdownListenerReply, ShutdownListenerRequest,
+    ShutdownNodeReply, ShutdownNodeRequest, StartListenerReply, StartListenerRequest,
+    SubscribeEventsRequest, VerifyPartialSigsReply, VerifyPartialSigsRequest, VerifySigReply,
+    VerifySigRequest,
 };
 use arpa_core::{
-    address_to_string, Group as ModelGroup, ListenerType, Member as ModelMember, SchedulerError,
+    address_to_string, ConfigError, Group as ModelGroup, ListenerType, Member as ModelMember,
+    RandomnessTask as ModelRandomnessTask, SchedulerError,
 };
-use arpa_dal::error::DataAccessError;
+use arpa_dal::{error::DataAccessError, BLSResultCacheState};
 use arpa_log::debug;
 use hyper::http::HeaderValue;
 use rustc_hex::FromHexError;
@@ -30,12 +36,18 @@ use std::{
 use threshold_bls::group::Curve;
 use threshold_bls::sig::{SignatureScheme, ThresholdScheme};
 use tokio::sync::RwLock;
+use tokio_stream::wrappers::ReceiverStream;
 use tonic::transport::Body;
 use tonic::{body::BoxBody, transport::Server, Request, Response, Status};
 use tower::{Layer, Service};
+use tracing::{info_span, Instrument};
 use uuid::Uuid;
 
-use super::{BLSRandomnessService, DBService, DKGService, GroupInfo, NodeInfo, NodeService};
+use super::{
+    event_feed::{subscribe_events, EventFilter},
+    BLSRandomnessService, DBService, DKGProgress, DKGService, GroupInfo, NodeInfo, NodeService,
+    RandomnessResult, RelayedChainInfo,
+};
 
 type NodeContext<PC, S> = Arc<RwLock<GeneralContext<PC, S>>>;
 
@@ -320,6 +332,145 @@ where
             .map_err(|e: anyhow::Error| Status::unavailable(e.to_string()))?;
         return Ok(Response::new(SendPartialSigReply { res: true }));
     }
+
+    async fn list_pending_randomness_tasks(
+        &self,
+        request: Request<ListPendingRandomnessTasksRequest>,
+    ) -> Result<Response<ListPendingRandomnessTasksReply>, Status> {
+        let req = request.into_inner();
+        let tasks = self
+            .context
+            .read()
+            .await
+            .list_pending_randomness_tasks(req.chain_id as usize)
+            .await
+            .map_err(|e: DataAccessError| match e {
+                DataAccessError::InvalidChainId(_) => Status::invalid_argument(e.to_string()),
+                _ => Status::unavailable(e.to_string()),
+            })?;
+        return Ok(Response::new(ListPendingRandomnessTasksReply {
+            tasks: tasks.into_iter().map(|t| t.into()).collect(),
+        }));
+    }
+
+    async fn get_randomness_result(
+        &self,
+        request: Request<GetRandomnessResultRequest>,
+    ) -> Result<Response<GetRandomnessResultReply>, Status> {
+        let req = request.into_inner();
+        let randomness_result = self
+            .context
+            .read()
+            .await
+            .get_randomness_result(&req.request_id)
+            .await
+            .map_err(|e: DataAccessError| match e {
+                DataAccessError::RandomnessTaskError(_) => Status::not_found(e.to_string()),
+                _ => Status::unavailable(e.to_string()),
+            })?;
+        return Ok(Response::new(randomness_result.into()));
+    }
+
+    async fn get_dkg_progress(
+        &self,
+        request: Request<GetDkgProgressRequest>,
+    ) -> Result<Response<GetDkgProgressReply>, Status> {
+        let _req = request.into_inner();
+        let dkg_progress = self
+            .context
+            .read()
+            .await
+            .get_dkg_progress()
+            .await
+            .map_err(|e: NodeError| Status::unavailable(e.to_string()))?;
+        return Ok(Response::new(dkg_progress.into()));
+    }
+
+    async fn list_relayed_chains(
+        &self,
+        request: Request<ListRelayedChainsRequest>,
+    ) -> Result<Response<ListRelayedChainsReply>, Status> {
+        let _req = request.into_inner();
+        let relayed_chains = self
+            .context
+            .read()
+            .await
+            .list_relayed_chains()
+            .await
+            .map_err(|e: DataAccessError| Status::unavailable(e.to_string()))?;
+        return Ok(Response::new(ListRelayedChainsReply {
+            relayed_chains: relayed_chains.into_iter().map(|c| c.into()).collect(),
+        }));
+    }
+
+    async fn list_dynamic_tasks(
+        &self,
+        request: Request<ListDynamicTasksRequest>,
+    ) -> Result<Response<ListDynamicTasksReply>, Status> {
+        let _req = request.into_inner();
+
+        let dynamic_tasks = self
+            .context
+            .read()
+            .await
+            .list_dynamic_tasks()
+            .await
+            .map(|ts| ts.iter().map(|t| t.to_string()).collect())
+            .map_err(|e: SchedulerError| Status::internal(e.to_string()))?;
+
+        return Ok(Response::new(ListDynamicTasksReply { dynamic_tasks }));
+    }
+
+    type SubscribeEventsStream = ReceiverStream<Result<NodeEvent, Status>>;
+
+    async fn subscribe_events(
+        &self,
+        request: Request<SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        let req = request.into_inner();
+
+        let events = self
+            .context
+            .read()
+            .await
+            .get_event_queue()
+            .read()
+            .await
+            .subscribe_feed();
+
+        let receipts = arpa_core::log::subscribe_transaction_receipts();
+
+        return Ok(Response::new(subscribe_events(
+            events,
+            receipts,
+            EventFilter::from(req),
+        )));
+    }
+
+    async fn reload_config(
+        &self,
+        request: Request<ReloadConfigRequest>,
+    ) -> Result<Response<ReloadConfigReply>, Status> {
+        let _req = request.into_inner();
+
+        let changes =
+            self.context
+                .write()
+                .await
+                .reload_config()
+                .await
+                .map_err(|e: NodeError| match e {
+                    NodeError::ConfigError(ConfigError::UnreloadableChanges(_)) => {
+                        Status::failed_precondition(e.to_string())
+                    }
+                    NodeError::ConfigError(ConfigError::LoadingError(_)) => {
+                        Status::invalid_argument(e.to_string())
+                    }
+                    _ => Status::internal(e.to_string()),
+                })?;
+
+        return Ok(Response::new(ReloadConfigReply { changes }));
+    }
 }
 
 impl<PC: Curve> From<NodeInfo<PC>> for GetNodeInfoReply {
@@ -396,6 +547,99 @@ impl<PC: Curve> From<ModelMember<PC>> for Member {
     }
 }
 
+impl From<ModelRandomnessTask> for RandomnessTask {
+    fn from(t: ModelRandomnessTask) -> Self {
+        RandomnessTask {
+            request_id: t.request_id,
+            subscription_id: t.subscription_id,
+            group_index: t.group_index,
+            request_type: t.request_type as u32,
+            params: t.params,
+            requester: address_to_string(t.requester),
+            seed: t.seed.to_string(),
+            request_confirmations: t.request_confirmations as u32,
+            callback_gas_limit: t.callback_gas_limit,
+            callback_max_gas_price: t.callback_max_gas_price.to_string(),
+            assignment_block_height: t.assignment_block_height as u32,
+        }
+    }
+}
+
+impl From<BLSResultCacheState> for RandomnessResultState {
+    fn from(state: BLSResultCacheState) -> Self {
+        match state {
+            BLSResultCacheState::NotCommitted => RandomnessResultState::NotCommitted,
+            BLSResultCacheState::Committing => RandomnessResultState::Committing,
+            BLSResultCacheState::Committed => RandomnessResultState::Committed,
+            BLSResultCacheState::CommittedByOthers => RandomnessResultState::CommittedByOthers,
+            BLSResultCacheState::Expired => RandomnessResultState::Expired,
+            BLSResultCacheState::FAULTY => RandomnessResultState::Faulty,
+        }
+    }
+}
+
+impl From<RandomnessResult> for GetRandomnessResultReply {
+    fn from(r: RandomnessResult) -> Self {
+        let partial_signatures = r
+            .result_cache
+            .partial_signatures
+            .into_iter()
+            .map(|(id_address, s)| PartialSignature {
+                id_address: address_to_string(id_address),
+                index: s.index as u32,
+                signed_partial_signature: s.signed_partial_signature,
+            })
+            .collect();
+
+        GetRandomnessResultReply {
+            chain_id: r.chain_id as u32,
+            group_index: r.result_cache.group_index as u32,
+            message: r.result_cache.message,
+            threshold: r.result_cache.threshold as u32,
+            state: RandomnessResultState::from(r.state) as i32,
+            committed_times: r.result_cache.committed_times as u32,
+            partial_signatures,
+            committers: r.committers.into_iter().map(address_to_string).collect(),
+        }
+    }
+}
+
+impl From<DKGProgress> for GetDkgProgressReply {
+    fn from(p: DKGProgress) -> Self {
+        let mut reply = GetDkgProgressReply {
+            group_index: p.group_index as u32,
+            epoch: p.epoch as u32,
+            dkg_status: p.dkg_status.to_usize() as i32,
+            checkpoint: p.checkpoint.unwrap_or_default().to_string(),
+            coordinator_address: String::new(),
+            phase: -1,
+            shares: 0,
+            responses: 0,
+            justifications: 0,
+        };
+
+        if let Some(c) = p.coordinator_progress {
+            reply.coordinator_address = address_to_string(c.coordinator_address);
+            reply.phase = c.phase as i32;
+            reply.shares = c.shares as u32;
+            reply.responses = c.responses as u32;
+            reply.justifications = c.justifications as u32;
+        }
+
+        reply
+    }
+}
+
+impl From<RelayedChainInfo> for RelayedChain {
+    fn from(c: RelayedChainInfo) -> Self {
+        RelayedChain {
+            chain_id: c.chain_id as u32,
+            description: c.description,
+            block_height: c.block_height as u32,
+        }
+    }
+}
+
 pub async fn start_management_server<
     PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
     SS: SignatureScheme
@@ -507,30 +751,35 @@ where
 
         let context = self.context.clone();
 
-        Box::pin(async move {
-            log_mdc::insert("management_request_id", Uuid::new_v4().to_string());
-
-            debug!("Intercepting management request: {:?}", req);
-
-            let token = HeaderValue::from_str(
-                context
-                    .read()
-                    .await
-                    .get_config()
-                    .get_node_management_rpc_token(),
-            )
-            .unwrap();
-
-            match req.headers().get("authorization") {
-                Some(t) if token == t => {}
-                _ => return Ok(Status::unauthenticated("No valid auth token").to_http()),
-            };
-
-            let response = inner.call(req).await?;
-
-            log_mdc::remove("management_request_id");
-
-            Ok(response)
-        })
+        let span = info_span!(
+            "management_request",
+            management_request_id = %Uuid::new_v4(),
+            path = %req.uri().path()
+        );
+
+        Box::pin(
+            async move {
+                debug!("Intercepting management request: {:?}", req);
+
+                let token = HeaderValue::from_str(
+                    context
+                        .read()
+                        .await
+                        .get_config()
+                        .get_node_management_rpc_token(),
+                )
+                .unwrap();
+
+                match req.headers().get("authorization") {
+                    Some(t) if token == t => {}
+                    _ => return Ok(Status::unauthenticated("No valid auth token").to_http()),
+                };
+
+                let response = inner.call(req).await?;
+
+                Ok(response)
+            }
+            .instrument(span),
+        )
     }
 }
@@ -8,23 +8,27 @@ use arpa_core::build_wallet_from_config;
 use arpa_core::log::build_general_payload;
 use arpa_core::log::build_transaction_receipt_payload;
 use arpa_core::log::encoder::JsonEncoder;
+use arpa_core::log::trace::{init_tracing, shutdown_tracing};
 use arpa_core::log::LogType;
+use arpa_core::ChainTransport;
 use arpa_core::Config;
+use arpa_core::DataSource;
 use arpa_core::GeneralMainChainIdentity;
 use arpa_core::GeneralRelayedChainIdentity;
 use arpa_core::DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES;
 use arpa_dal::GroupInfoHandler;
 use arpa_dal::NodeInfoHandler;
-use arpa_dal::{NodeInfoFetcher, NodeInfoUpdater};
+use arpa_node::committer::{tls::CommitterTls, CommitterCredentials};
 use arpa_node::context::chain::types::GeneralMainChain;
 use arpa_node::context::chain::types::GeneralRelayedChain;
+use arpa_node::context::chain::Chain;
 use arpa_node::context::types::GeneralContext;
-use arpa_node::context::{Context, TaskWaiter};
-use arpa_sqlite_db::SqliteDB;
+use arpa_node::context::{Context, ContextFetcher, TaskWaiter};
+use arpa_node::pruner::DataPruner;
+use arpa_node::storage::NodeDB;
 use check_latest::check_max_async;
 use ethers::core::k256::ecdsa::SigningKey;
 use ethers::providers::Provider;
-use ethers::providers::Ws;
 use ethers::signers::Signer;
 use ethers::signers::Wallet;
 use ethers::types::U256;
@@ -37,7 +41,6 @@ use log4rs::append::rolling_file::RollingFileAppender;
 use log4rs::config::{Appender, Root};
 use log4rs::filter::threshold::ThresholdFilter;
 use log4rs::Config as LogConfig;
-use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
@@ -69,6 +72,7 @@ fn init_logger(
     context_logging: bool,
     log_file_path: &str,
     rolling_file_size: u64,
+    log_level: LevelFilter,
 ) {
     let stdout = ConsoleAppender::builder()
         .encoder(Box::new(
@@ -120,14 +124,16 @@ fn init_logger(
                 .filter(Box::new(ThresholdFilter::new(LevelFilter::Error)))
                 .build("err_file", Box::new(rolling_err_file)),
         )
-        .logger(log4rs::config::Logger::builder().build("node_client", LevelFilter::Info))
-        .logger(log4rs::config::Logger::builder().build("arpa_node", LevelFilter::Info))
-        .logger(log4rs::config::Logger::builder().build("arpa_core", LevelFilter::Info))
-        .logger(log4rs::config::Logger::builder().build("arpa_contract_client", LevelFilter::Info))
-        .logger(log4rs::config::Logger::builder().build("arpa_sqlite_db", LevelFilter::Info))
-        .logger(log4rs::config::Logger::builder().build("arpa_dal", LevelFilter::Info))
-        .logger(log4rs::config::Logger::builder().build("dkg_core", LevelFilter::Info))
-        .logger(log4rs::config::Logger::builder().build("threshold_bls", LevelFilter::Info))
+        .logger(log4rs::config::Logger::builder().build("node_client", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("arpa_node", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("arpa_core", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("arpa_contract_client", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("arpa_sea_orm_db", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("arpa_sqlite_db", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("arpa_postgres_db", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("arpa_dal", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("dkg_core", LevelFilter::Trace))
+        .logger(log4rs::config::Logger::builder().build("threshold_bls", LevelFilter::Trace))
         .build(
             Root::builder()
                 .appender("err_file")
@@ -138,6 +144,10 @@ fn init_logger(
         .unwrap();
 
     log4rs::init_config(log_config).unwrap();
+
+    // the loggers above let everything through so that the level can be changed
+    // by reloading the config without rebuilding them
+    log::set_max_level(log_level);
 }
 
 #[tokio::main]
@@ -161,8 +171,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         logger_descriptor.get_context_logging(),
         logger_descriptor.get_log_file_path(),
         logger_descriptor.get_rolling_file_size(),
+        logger_descriptor.get_log_level(),
     );
 
+    init_tracing(
+        config.get_tracing_descriptor(),
+        &address_to_string(id_address),
+    )?;
+
     info!("{:?}", config);
 
     if let Ok(Some(version)) = check_max_async!().await {
@@ -177,6 +193,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         error!("{:?}", e);
     };
 
+    shutdown_tracing();
+
     Ok(())
 }
 
@@ -188,31 +206,40 @@ async fn start(
 
     let l1_chain_id = config.get_main_chain_id();
 
-    let data_path = PathBuf::from(config.get_data_path());
+    let data_source = config.get_data_source();
 
-    let is_new_run = !data_path.exists();
+    // the SQLite file is created on the first run, while the PostgreSQL database is created
+    // beforehand and stays new until the node info is saved
+    let is_new_sqlite_file =
+        matches!(&data_source, DataSource::Sqlite(db_path) if !db_path.exists());
 
     let is_eigenlayer = config.is_eigenlayer();
 
     let is_consistent_asset_and_node_account = config.is_consistent_asset_and_node_account();
 
-    if let Some(parent) = data_path.parent() {
-        fs::create_dir_all(parent)?;
-    }
+    let relayed_chain_ids = config
+        .get_relayed_chains()
+        .iter()
+        .map(|relayed_chain| relayed_chain.get_chain_id())
+        .collect::<Vec<_>>();
 
-    let db = SqliteDB::build(
-        data_path.as_os_str().to_str().unwrap(),
+    let db = NodeDB::build(
+        &data_source,
         &wallet.signer().to_bytes(),
+        &relayed_chain_ids,
     )
     .await?;
 
-    let mut node_cache = db.get_node_info_client();
+    let saved_node_cache = db.load_node_info_cache::<G2Curve>().await?;
 
-    let mut group_cache = db.get_group_info_client(id_address);
+    let is_new_run = match data_source {
+        DataSource::Sqlite(_) => is_new_sqlite_file,
+        DataSource::Postgres(_) => saved_node_cache.is_none(),
+    };
 
     let mut dkg_public_key_to_register: Option<Vec<u8>> = None;
 
-    if is_new_run {
+    let node_cache = if is_new_run {
         let rng = &mut rand::thread_rng();
 
         let (dkg_private_key, dkg_public_key) = G2Scheme::keypair(rng);
@@ -226,8 +253,8 @@ async fn start(
             )
         );
 
-        node_cache
-            .save_node_info(
+        let node_cache = db
+            .save_node_info_cache::<G2Curve>(
                 id_address,
                 config
                     .get_node_advertised_committer_rpc_endpoint()
@@ -238,10 +265,12 @@ async fn start(
             .await?;
 
         dkg_public_key_to_register = Some(bincode::serialize(&dkg_public_key)?);
+
+        node_cache
     } else {
-        if let Ok(false) = node_cache.refresh_current_node_info().await {
+        let Some(mut node_cache) = saved_node_cache else {
             return Err("It seems there is no existing node record. Please check the database or remove it.".into());
-        }
+        };
 
         if node_cache.get_id_address()? != id_address {
             return Err("Node identity is different from the database, please check config or remove the existed database.".into());
@@ -256,25 +285,40 @@ async fn start(
             )
             .await?;
 
-        group_cache.refresh_current_group_info().await?;
-    }
+        node_cache
+    };
+
+    let group_cache = db.load_group_info_cache::<G2Curve>(id_address).await?;
 
     let node_cache: Arc<RwLock<Box<dyn NodeInfoHandler<G2Curve>>>> =
-        Arc::new(RwLock::new(Box::new(node_cache)));
+        Arc::new(RwLock::new(node_cache));
 
     let group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<G2Curve>>>> =
-        Arc::new(RwLock::new(Box::new(group_cache)));
+        Arc::new(RwLock::new(group_cache));
 
     let randomness_tasks_cache = Arc::new(RwLock::new(db.build_randomness_tasks_cache(0)?));
 
     let randomness_result_cache = Arc::new(RwLock::new(db.build_randomness_result_cache(0).await?));
 
+    let group_relay_tasks_cache = Arc::new(RwLock::new(db.build_group_relay_tasks_cache()?));
+
+    let group_relay_result_cache =
+        Arc::new(RwLock::new(db.build_group_relay_result_cache().await?));
+
+    let block_checkpoint_cache = Arc::new(RwLock::new(
+        db.build_block_checkpoint_cache(config.get_main_chain_id())?,
+    ));
+
+    let provider_endpoints = config.build_provider_endpoints();
+
     let provider = Arc::new(
-        Provider::<Ws>::connect_with_reconnects(
-            config.get_provider_endpoint(),
-            DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+        Provider::new(
+            ChainTransport::connect_endpoints(
+                &provider_endpoints,
+                DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+            )
+            .await?,
         )
-        .await?
         .interval(Duration::from_millis(
             config.get_time_limits().provider_polling_interval_millis,
         )),
@@ -284,7 +328,7 @@ async fn start(
         config.get_main_chain_id(),
         wallet.clone(),
         provider,
-        config.get_provider_endpoint().to_string(),
+        provider_endpoints,
         config
             .get_controller_address()
             .parse()
@@ -306,26 +350,46 @@ async fn start(
     let main_chain = GeneralMainChain::<G2Curve, G2Scheme>::new(
         "main chain".to_string(),
         is_eigenlayer,
+        config.is_dkg_resharing_enabled(),
+        config.is_group_relay_by_signature_enabled(),
+        config.is_dkg_off_chain_board_enabled(),
         main_chain_identity.clone(),
         node_cache.clone(),
         group_cache.clone(),
         randomness_tasks_cache,
         randomness_result_cache,
+        group_relay_tasks_cache,
+        group_relay_result_cache,
+        block_checkpoint_cache,
+        config.get_adapter_deployed_block_height() as usize,
         *config.get_time_limits(),
         config.get_listeners().clone(),
     );
 
     let relayed_chains_config = config.get_relayed_chains().clone();
 
-    let mut context = GeneralContext::new(main_chain, config);
+    let committer_tls = config
+        .get_committer_tls()
+        .map(CommitterTls::load)
+        .transpose()?;
+
+    let committer_credentials = CommitterCredentials::new(wallet.clone(), committer_tls);
+
+    let data_retention = *config.get_data_retention();
+
+    let mut context = GeneralContext::new(main_chain, config, committer_credentials);
 
     for relayed_chain_config in relayed_chains_config {
+        let provider_endpoints = relayed_chain_config.build_provider_endpoints();
+
         let provider = Arc::new(
-            Provider::<Ws>::connect_with_reconnects(
-                relayed_chain_config.get_provider_endpoint(),
-                DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+            Provider::new(
+                ChainTransport::connect_endpoints(
+                    &provider_endpoints,
+                    DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+                )
+                .await?,
             )
-            .await?
             .interval(Duration::from_millis(
                 relayed_chain_config
                     .get_time_limits()
@@ -339,7 +403,7 @@ async fn start(
             relayed_chain_id,
             wallet.clone(),
             provider,
-            relayed_chain_config.get_provider_endpoint().to_string(),
+            provider_endpoints,
             relayed_chain_config
                 .get_controller_oracle_address()
                 .parse()
@@ -364,6 +428,19 @@ async fn start(
             db.build_randomness_result_cache(relayed_chain_id).await?,
         ));
 
+        let group_relay_confirmation_tasks_cache = Arc::new(RwLock::new(
+            db.build_group_relay_confirmation_tasks_cache(relayed_chain_id)?,
+        ));
+
+        let group_relay_confirmation_result_cache = Arc::new(RwLock::new(
+            db.build_group_relay_confirmation_result_cache(relayed_chain_id)
+                .await?,
+        ));
+
+        let block_checkpoint_cache = Arc::new(RwLock::new(
+            db.build_block_checkpoint_cache(relayed_chain_id)?,
+        ));
+
         let relayed_chain = GeneralRelayedChain::<G2Curve, G2Scheme>::new(
             relayed_chain_config.get_description().to_string(),
             relayed_chain_identity,
@@ -371,6 +448,10 @@ async fn start(
             group_cache.clone(),
             randomness_tasks_cache,
             randomness_result_cache,
+            group_relay_confirmation_tasks_cache,
+            group_relay_confirmation_result_cache,
+            block_checkpoint_cache,
+            relayed_chain_config.get_adapter_deployed_block_height() as usize,
             *relayed_chain_config.get_time_limits(),
             relayed_chain_config.get_listeners().to_vec(),
         );
@@ -397,6 +478,31 @@ async fn start(
         return Err("Node is registered with different dkg public key".into());
     }
 
+    // prune the task and result tables of every chain by the retention
+    let f_ts = context.get_fixed_task_handler();
+
+    DataPruner::new(
+        l1_chain_id,
+        true,
+        context.get_main_chain().get_block_cache(),
+        db.clone(),
+        data_retention,
+    )
+    .start(&mut *f_ts.write().await)?;
+
+    for relayed_chain_id in context.get_supported_relayed_chains() {
+        let relayed_chain = context.get_relayed_chain(relayed_chain_id).unwrap();
+
+        DataPruner::new(
+            relayed_chain_id,
+            false,
+            relayed_chain.get_block_cache(),
+            db.clone(),
+            data_retention,
+        )
+        .start(&mut *f_ts.write().await)?;
+    }
+
     // deploy the node context and start the node
     let handle = context.deploy().await?;
 
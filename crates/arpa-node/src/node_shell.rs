@@ -9,15 +9,16 @@ use arpa_contract_client::ethers::node_registry::NodeRegistryClient;
 use arpa_contract_client::node_registry::{NodeRegistryTransactions, NodeRegistryViews};
 use arpa_contract_client::{ServiceClient, TransactionCaller, ViewCaller};
 use arpa_core::{
-    address_to_string, build_wallet_from_config, pad_to_bytes32, Account, Config, ConfigError,
-    GeneralMainChainIdentity, GeneralRelayedChainIdentity, Keystore, WsWalletSigner,
+    address_to_string, build_wallet_from_config, pad_to_bytes32, Account, ChainTransport, Config,
+    ConfigError, GeneralMainChainIdentity, GeneralRelayedChainIdentity, Keystore, WalletSigner,
 };
-use arpa_dal::NodeInfoFetcher;
+use arpa_dal::error::NodeInfoError;
 use arpa_node::context::ChainIdentityHandlerType;
 use arpa_node::management::client::GeneralManagementClient;
-use arpa_sqlite_db::SqliteDB;
+use arpa_node::rpc_stub::management::node_event::Topic;
+use arpa_node::storage::NodeDB;
 use ethers::prelude::k256::ecdsa::SigningKey;
-use ethers::providers::{Middleware, Provider, Ws};
+use ethers::providers::{Middleware, Provider};
 use ethers::signers::coins_bip39::English;
 use ethers::signers::Signer;
 use ethers::signers::{LocalWallet, MnemonicBuilder};
@@ -65,7 +66,7 @@ struct Context<PC: Curve> {
     config: Config,
     wallet: LocalWallet,
     chain_identities: BTreeMap<usize, ChainIdentityHandlerType<PC>>,
-    db: SqliteDB,
+    db: NodeDB,
     staking_contract_address: Option<Address>,
     node_registry_address: Option<Address>,
     show_address: bool,
@@ -429,9 +430,11 @@ async fn send<PC: Curve>(
                 return Ok(Some("Node already registered".to_string()));
             }
 
-            let mut node_cache = context.db.get_node_info_client::<G2Curve>();
-
-            node_cache.refresh_current_node_info().await?;
+            let node_cache = context
+                .db
+                .load_node_info_cache::<G2Curve>()
+                .await?
+                .ok_or(NodeInfoError::NoDKGKeyPair)?;
 
             let dkg_public_key = node_cache.get_dkg_public_key()?;
 
@@ -545,9 +548,11 @@ async fn send<PC: Curve>(
                 return Ok(Some("Node already activated".to_string()));
             }
 
-            let mut node_cache = context.db.get_node_info_client::<G2Curve>();
-
-            node_cache.refresh_current_node_info().await?;
+            let node_cache = context
+                .db
+                .load_node_info_cache::<G2Curve>()
+                .await?
+                .ok_or(NodeInfoError::NoDKGKeyPair)?;
 
             let dkg_public_key = node_cache.get_dkg_public_key()?;
 
@@ -1004,7 +1009,7 @@ async fn call<PC: Curve>(
                 .build_adapter_client(context.wallet.address());
 
             let adapter_contract =
-                ServiceClient::<AdapterContract<WsWalletSigner>>::prepare_service_client(&client)
+                ServiceClient::<AdapterContract<WalletSigner>>::prepare_service_client(&client)
                     .await?;
 
             let filter = adapter_contract
@@ -1047,7 +1052,7 @@ async fn call<PC: Curve>(
                 .build_adapter_client(context.chain_identity(*chain_id)?.get_id_address());
 
             let adapter_contract =
-                ServiceClient::<AdapterContract<WsWalletSigner>>::prepare_service_client(&client)
+                ServiceClient::<AdapterContract<WalletSigner>>::prepare_service_client(&client)
                     .await?;
 
             let filter = adapter_contract
@@ -1202,7 +1207,7 @@ async fn call<PC: Curve>(
                 .build_adapter_client(context.wallet.address());
 
             let adapter_contract =
-                ServiceClient::<AdapterContract<WsWalletSigner>>::prepare_service_client(&client)
+                ServiceClient::<AdapterContract<WalletSigner>>::prepare_service_client(&client)
                     .await?;
 
             let (
@@ -1242,7 +1247,7 @@ async fn call<PC: Curve>(
                 .build_adapter_client(context.wallet.address());
 
             let adapter_contract =
-                ServiceClient::<AdapterContract<WsWalletSigner>>::prepare_service_client(&client)
+                ServiceClient::<AdapterContract<WalletSigner>>::prepare_service_client(&client)
                     .await?;
 
             let last_assigned_group_index = AdapterClient::call_contract_view(
@@ -1266,7 +1271,7 @@ async fn call<PC: Curve>(
                 .build_adapter_client(context.wallet.address());
 
             let adapter_contract =
-                ServiceClient::<AdapterContract<WsWalletSigner>>::prepare_service_client(&client)
+                ServiceClient::<AdapterContract<WalletSigner>>::prepare_service_client(&client)
                     .await?;
 
             let randomness_count = AdapterClient::call_contract_view(
@@ -1365,7 +1370,7 @@ async fn call<PC: Curve>(
                 .build_adapter_client(context.wallet.address());
 
             let adapter_contract =
-                ServiceClient::<AdapterContract<WsWalletSigner>>::prepare_service_client(&client)
+                ServiceClient::<AdapterContract<WalletSigner>>::prepare_service_client(&client)
                     .await?;
 
             let (
@@ -1400,7 +1405,7 @@ async fn call<PC: Curve>(
                 .build_adapter_client(context.wallet.address());
 
             let adapter_contract =
-                ServiceClient::<AdapterContract<WsWalletSigner>>::prepare_service_client(&client)
+                ServiceClient::<AdapterContract<WalletSigner>>::prepare_service_client(&client)
                     .await?;
 
             let r_id = sub_matches.get_one::<String>("request-id").unwrap();
@@ -1476,9 +1481,11 @@ async fn show<PC: Curve>(
         Some(("node", sub_matches)) => {
             let display_sensitive = sub_matches.get_flag("display-sensitive");
 
-            let mut node_cache = context.db.get_node_info_client::<G2Curve>();
-
-            node_cache.refresh_current_node_info().await?;
+            let node_cache = context
+                .db
+                .load_node_info_cache::<G2Curve>()
+                .await?
+                .ok_or(NodeInfoError::NoDKGKeyPair)?;
 
             Ok(Some(if display_sensitive {
                 format!(
@@ -1501,19 +1508,110 @@ async fn inspect<PC: Curve>(
 ) -> anyhow::Result<Option<String>> {
     match args.subcommand() {
         Some(("list-fixed-tasks", _sub_matches)) => {
-            let management_client = GeneralManagementClient::new(
-                context.config.get_node_management_rpc_endpoint().to_owned(),
-                context.config.get_node_management_rpc_token().to_owned(),
-            );
+            let management_client = build_management_client(context);
             Ok(Some(format!(
                 "fixed-tasks: {:#?}",
                 management_client.list_fixed_tasks().await?
             )))
         }
+        Some(("list-dynamic-tasks", _sub_matches)) => {
+            let management_client = build_management_client(context);
+            Ok(Some(format!(
+                "dynamic-tasks: {:#?}",
+                management_client.list_dynamic_tasks().await?
+            )))
+        }
+        Some(("list-pending-randomness-tasks", sub_matches)) => {
+            let chain_id = sub_matches.get_one::<usize>("chain-id").unwrap();
+            let management_client = build_management_client(context);
+            Ok(Some(format!(
+                "pending-randomness-tasks: {:#?}",
+                management_client
+                    .list_pending_randomness_tasks(*chain_id)
+                    .await?
+            )))
+        }
+        Some(("randomness-result", sub_matches)) => {
+            let r_id = sub_matches.get_one::<String>("request-id").unwrap();
+            let request_id = pad_to_bytes32(&hex::decode(r_id.trim_start_matches("0x"))?)
+                .unwrap()
+                .to_vec();
+            let management_client = build_management_client(context);
+            Ok(Some(format!(
+                "{:#?}",
+                management_client.get_randomness_result(request_id).await?
+            )))
+        }
+        Some(("dkg-progress", _sub_matches)) => {
+            let management_client = build_management_client(context);
+            Ok(Some(format!(
+                "{:#?}",
+                management_client.get_dkg_progress().await?
+            )))
+        }
+        Some(("list-relayed-chains", _sub_matches)) => {
+            let management_client = build_management_client(context);
+            Ok(Some(format!(
+                "relayed-chains: {:#?}",
+                management_client.list_relayed_chains().await?
+            )))
+        }
+        Some(("events", sub_matches)) => {
+            let count = *sub_matches.get_one::<usize>("count").unwrap();
+            let topics = sub_matches
+                .get_many::<String>("topic")
+                .unwrap_or_default()
+                .map(|t| {
+                    Topic::from_str_name(t)
+                        .map(|t| t as i32)
+                        .ok_or_else(|| anyhow::anyhow!("unknown event topic {}", t))
+                })
+                .collect::<anyhow::Result<Vec<_>>>()?;
+            let chain_ids = sub_matches
+                .get_many::<u32>("chain-id")
+                .unwrap_or_default()
+                .copied()
+                .collect();
+            let management_client = build_management_client(context);
+            let mut events = management_client
+                .subscribe_events(topics, chain_ids)
+                .await?;
+            let mut res = String::new();
+            for _ in 0..count {
+                match events.message().await? {
+                    Some(event) => res += &format!("{:#?}\n", event),
+                    None => break,
+                }
+            }
+            Ok(Some(res))
+        }
+        Some(("reload-config", _sub_matches)) => {
+            let management_client = build_management_client(context);
+            Ok(Some(format!(
+                "changes: {:#?}",
+                management_client.reload_config().await?
+            )))
+        }
+        Some(("table-sizes", _sub_matches)) => {
+            let mut res = String::new();
+            for (table, rows) in context.db.get_table_sizes().await? {
+                res += &format!("{:<36}{:>12} rows\n", table, rows);
+            }
+            let database_size = context.db.get_database_size().await?;
+            res += &format!("{:<36}{:>12} bytes", "database", database_size);
+            Ok(Some(res))
+        }
         _ => panic!("Unknown subcommand {:?}", args.subcommand_name()),
     }
 }
 
+fn build_management_client<PC: Curve>(context: &Context<PC>) -> GeneralManagementClient {
+    GeneralManagementClient::new(
+        context.config.get_node_management_rpc_endpoint().to_owned(),
+        context.config.get_node_management_rpc_token().to_owned(),
+    )
+}
+
 fn history<PC: Curve>(
     _args: ArgMatches,
     context: &mut Context<PC>,
@@ -1554,19 +1652,19 @@ async fn main() -> anyhow::Result<()> {
 
     let mut chain_identities = BTreeMap::new();
 
+    let provider_endpoints = config.build_provider_endpoints();
+
     let provider = Arc::new(
-        Provider::<Ws>::connect_with_reconnects(config.get_provider_endpoint(), 0)
-            .await?
-            .interval(Duration::from_millis(
-                config.get_time_limits().provider_polling_interval_millis,
-            )),
+        Provider::new(ChainTransport::connect_endpoints(&provider_endpoints, 0).await?).interval(
+            Duration::from_millis(config.get_time_limits().provider_polling_interval_millis),
+        ),
     );
 
     let main_chain_identity = GeneralMainChainIdentity::new(
         config.get_main_chain_id(),
         wallet.clone(),
         provider,
-        config.get_provider_endpoint().to_owned(),
+        provider_endpoints,
         config
             .get_controller_address()
             .parse()
@@ -1590,9 +1688,10 @@ async fn main() -> anyhow::Result<()> {
     chain_identities.insert(config.get_main_chain_id(), boxed_main_chain_identity);
 
     for relayed_chain in config.get_relayed_chains().iter() {
+        let provider_endpoints = relayed_chain.build_provider_endpoints();
+
         let provider = Arc::new(
-            Provider::<Ws>::connect_with_reconnects(relayed_chain.get_provider_endpoint(), 0)
-                .await?
+            Provider::new(ChainTransport::connect_endpoints(&provider_endpoints, 0).await?)
                 .interval(Duration::from_millis(
                     relayed_chain
                         .get_time_limits()
@@ -1604,7 +1703,7 @@ async fn main() -> anyhow::Result<()> {
             relayed_chain.get_chain_id(),
             wallet.clone(),
             provider,
-            relayed_chain.get_provider_endpoint().to_string(),
+            provider_endpoints,
             relayed_chain
                 .get_controller_oracle_address()
                 .parse()
@@ -1626,12 +1725,16 @@ async fn main() -> anyhow::Result<()> {
         chain_identities.insert(relayed_chain.get_chain_id(), boxed_relayed_chain_identity);
     }
 
-    let db = SqliteDB::build(
-        PathBuf::from(config.get_data_path())
-            .as_os_str()
-            .to_str()
-            .unwrap(),
+    let relayed_chain_ids = config
+        .get_relayed_chains()
+        .iter()
+        .map(|relayed_chain| relayed_chain.get_chain_id())
+        .collect::<Vec<_>>();
+
+    let db = NodeDB::build(
+        &config.get_data_source(),
         &wallet.signer().to_bytes(),
+        &relayed_chain_ids,
     )
     .await
     .unwrap();
@@ -1846,6 +1949,35 @@ async fn main() -> anyhow::Result<()> {
                 .subcommand(
                     Command::new("list-fixed-tasks").visible_alias("lft")
                     .about("List fixed tasks of the node")
+                ).subcommand(
+                    Command::new("list-dynamic-tasks").visible_alias("ldt")
+                    .about("List running dynamic tasks of the node")
+                ).subcommand(
+                    Command::new("list-pending-randomness-tasks").visible_alias("lprt")
+                    .about("List randomness tasks of the chain which are not handled yet")
+                    .arg(Arg::new("chain-id").required(true).value_parser(value_parser!(usize)).help("chain id in decimal format"))
+                ).subcommand(
+                    Command::new("randomness-result").visible_alias("rr")
+                    .about("Show the partial signatures collected, the committers and the state of a randomness result")
+                    .arg(Arg::new("request-id").required(true).help("request id in hex format"))
+                ).subcommand(
+                    Command::new("dkg-progress").visible_alias("dp")
+                    .about("Show the current phase of the DKG and the shares and responses seen by the coordinator")
+                ).subcommand(
+                    Command::new("list-relayed-chains").visible_alias("lrc")
+                    .about("List relayed chains with their block heights")
+                ).subcommand(
+                    Command::new("events").visible_alias("e")
+                    .about("Wait for and print the next events of the node, e.g. NewBlock, DkgPhase or TransactionReceipt")
+                    .arg(Arg::new("count").short('n').long("count").value_parser(value_parser!(usize)).default_value("10").help("number of events to wait for"))
+                    .arg(Arg::new("topic").short('t').long("topic").action(ArgAction::Append).help("event topic to subscribe to, all topics if omitted"))
+                    .arg(Arg::new("chain-id").short('c').long("chain-id").value_parser(value_parser!(u32)).action(ArgAction::Append).help("chain id in decimal format, all chains if omitted"))
+                ).subcommand(
+                    Command::new("reload-config").visible_alias("rlc")
+                    .about("Reload the config file of the node and apply the listeners, retry descriptors and log level live")
+                ).subcommand(
+                    Command::new("table-sizes").visible_alias("ts")
+                    .about("Show the row counts of the task and result tables and the size of the node database")
                 ).about("Connect to the node client and inspect the node status"),
                 |args, context| Box::pin(inspect(args, context)),
         ).with_on_after_command_async(|context| Box::pin(update_prompt(context)));
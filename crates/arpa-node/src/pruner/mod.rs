@@ -0,0 +1,78 @@
+use crate::scheduler::{fixed::SimpleFixedTaskScheduler, TaskScheduler};
+use crate::storage::NodeDB;
+use arpa_core::{
+    metrics::DATA_PRUNED_ROWS, ComponentTaskType, DataRetentionDescriptor, SchedulerResult,
+};
+use arpa_dal::BlockInfoHandler;
+use log::{error, info};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+
+/// Deletes the rows of the task and result tables of a chain beyond the retention periodically.
+pub struct DataPruner {
+    chain_id: usize,
+    /// The main chain keeps its randomness tasks and results in the tables of chain id 0.
+    db_chain_id: usize,
+    block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+    db: NodeDB,
+    retention: DataRetentionDescriptor,
+}
+
+impl DataPruner {
+    pub fn new(
+        chain_id: usize,
+        is_main_chain: bool,
+        block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+        db: NodeDB,
+        retention: DataRetentionDescriptor,
+    ) -> Self {
+        DataPruner {
+            chain_id,
+            db_chain_id: if is_main_chain { 0 } else { chain_id },
+            block_cache,
+            db,
+            retention,
+        }
+    }
+
+    pub async fn prune(&self) {
+        let current_block_height = self.block_cache.read().await.get_block_height();
+
+        match self
+            .db
+            .prune(self.db_chain_id, current_block_height, &self.retention)
+            .await
+        {
+            Ok(pruned_rows) => {
+                DATA_PRUNED_ROWS
+                    .with_label_values(&[&self.chain_id.to_string()])
+                    .inc_by(pruned_rows);
+                info!(
+                    "Pruned {} rows of chain {} at block {}",
+                    pruned_rows, self.chain_id, current_block_height
+                );
+            }
+            Err(e) => error!("Failed to prune rows of chain {}: {:?}", self.chain_id, e),
+        }
+    }
+
+    /// Registers the pruner with the fixed task scheduler, unless the pruning is disabled.
+    pub fn start(self, fs: &mut SimpleFixedTaskScheduler) -> SchedulerResult<()> {
+        if self.retention.pruning_interval_millis == 0 {
+            return Ok(());
+        }
+
+        fs.add_task(ComponentTaskType::DataPruner(self.chain_id), async move {
+            let mut interval = tokio::time::interval(Duration::from_millis(
+                self.retention.pruning_interval_millis,
+            ));
+            // the first tick completes immediately, while the block height is not known yet
+            interval.tick().await;
+
+            loop {
+                interval.tick().await;
+                self.prune().await;
+            }
+        })
+    }
+}
@@ -1,23 +1,41 @@
 use super::{EventPublisher, EventSubscriber};
 use crate::{
     event::types::Topic,
+    rpc_stub::management::NodeEvent,
     subscriber::{DebuggableEvent, DebuggableSubscriber},
 };
 use async_trait::async_trait;
 use log::error;
 use std::collections::HashMap;
+use tokio::sync::broadcast;
 
-#[derive(Debug, Default)]
+/// The events which are not received by a lagging feed subscriber in time are dropped.
+const EVENT_FEED_CAPACITY: usize = 1024;
+
+#[derive(Debug)]
 pub struct EventQueue {
     subscribers: HashMap<Topic, Vec<Box<dyn DebuggableSubscriber>>>,
+    event_feed: broadcast::Sender<NodeEvent>,
+}
+
+impl Default for EventQueue {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EventQueue {
     pub fn new() -> Self {
         EventQueue {
             subscribers: HashMap::new(),
+            event_feed: broadcast::channel(EVENT_FEED_CAPACITY).0,
         }
     }
+
+    /// Subscribes to all the events published to the queue, e.g. to stream them out of the node.
+    pub fn subscribe_feed(&self) -> broadcast::Receiver<NodeEvent> {
+        self.event_feed.subscribe()
+    }
 }
 
 impl EventSubscriber for EventQueue {
@@ -33,6 +51,11 @@ impl<E: DebuggableEvent + Clone + Send + Sync + 'static> EventPublisher<E> for E
     async fn publish(&self, event: E) {
         let topic = event.topic();
 
+        if self.event_feed.receiver_count() > 0 {
+            // there is nobody to notify if all the feed subscribers have just gone
+            let _ = self.event_feed.send(event.to_node_event());
+        }
+
         if let Some(subscribers) = self.subscribers.get(&topic) {
             for subscriber in subscribers {
                 if let Err(e) = subscriber.notify(topic, &event).await {
@@ -53,11 +76,16 @@ pub mod tests {
         queue::event_queue::EventQueue,
         subscriber::{block::BlockSubscriber, Subscriber},
     };
-    use arpa_core::{Config, GeneralMainChainIdentity};
-    use arpa_dal::{cache::InMemoryBlockInfoCache, BlockInfoHandler};
+    use arpa_core::{
+        ChainTransport, Config, GeneralMainChainIdentity, ProviderEndpoints, RandomnessTask,
+    };
+    use arpa_dal::{
+        cache::{InMemoryBLSTasksQueue, InMemoryBlockInfoCache},
+        BLSTasksHandler, BlockInfoHandler,
+    };
     use ethers::{
         providers::{Provider, Ws},
-        types::Address,
+        types::{Address, H256},
         utils::Anvil,
     };
     use std::{sync::Arc, time::Duration};
@@ -78,10 +106,6 @@ pub mod tests {
 
         assert_eq!(0, block_cache.clone().read().await.get_block_height());
 
-        let s = BlockSubscriber::new(chain_id, block_cache.clone(), eq.clone());
-
-        s.subscribe().await;
-
         let fake_wallet = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
             .parse()
             .unwrap();
@@ -96,17 +120,17 @@ pub mod tests {
         let avnil = Anvil::new().spawn();
 
         let provider = Arc::new(
-            Provider::<Ws>::connect(avnil.ws_endpoint())
-                .await
-                .unwrap()
-                .interval(Duration::from_millis(3000)),
+            Provider::new(ChainTransport::from(
+                Ws::connect(avnil.ws_endpoint()).await.unwrap(),
+            ))
+            .interval(Duration::from_millis(3000)),
         );
 
         let chain_identity = GeneralMainChainIdentity::new(
             0,
             fake_wallet,
             provider,
-            avnil.ws_endpoint(),
+            ProviderEndpoints::new(vec![avnil.ws_endpoint()], 0),
             Address::random(),
             Address::random(),
             Address::random(),
@@ -117,29 +141,34 @@ pub mod tests {
         let chain_identity: Arc<RwLock<ChainIdentityHandlerType<G2Curve>>> =
             Arc::new(RwLock::new(Box::new(chain_identity)));
 
-        let p = BlockListener::new(chain_id, chain_identity, eq);
+        let randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>> =
+            Arc::new(RwLock::new(Box::new(InMemoryBLSTasksQueue::new())));
 
-        p.publish(NewBlock {
+        let s = BlockSubscriber::new(
             chain_id,
-            block_height: 1,
-        })
-        .await;
+            Address::random(),
+            chain_identity.clone(),
+            block_cache.clone(),
+            randomness_tasks_cache,
+            eq.clone(),
+        );
+
+        s.subscribe().await;
+
+        let p = BlockListener::new(chain_id, chain_identity, eq);
+
+        p.publish(NewBlock::new(chain_id, 1, H256::zero(), H256::zero()))
+            .await;
 
         assert_eq!(1, block_cache.clone().read().await.get_block_height());
 
-        p.publish(NewBlock {
-            chain_id,
-            block_height: 10,
-        })
-        .await;
+        p.publish(NewBlock::new(chain_id, 10, H256::zero(), H256::zero()))
+            .await;
 
         assert_eq!(10, block_cache.clone().read().await.get_block_height());
 
-        p.publish(NewBlock {
-            chain_id: 999,
-            block_height: 10,
-        })
-        .await;
+        p.publish(NewBlock::new(999, 10, H256::zero(), H256::zero()))
+            .await;
 
         assert_eq!(10, block_cache.clone().read().await.get_block_height());
     }
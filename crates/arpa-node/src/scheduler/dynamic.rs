@@ -1,16 +1,21 @@
 use arpa_core::SchedulerResult;
 use futures::Future;
-use tokio::{
-    sync::{oneshot::channel, oneshot::Receiver},
-    task::JoinHandle,
-};
+use tokio::task::JoinHandle;
+use tracing::Instrument;
 
 use super::{ComponentTaskType, DynamicTaskScheduler, TaskScheduler};
 
+#[derive(Debug)]
+struct DynamicTask {
+    task_type: ComponentTaskType,
+    task: JoinHandle<()>,
+    task_monitor: Option<JoinHandle<()>>,
+}
+
 #[derive(Debug, Default)]
 pub struct SimpleDynamicTaskScheduler {
     // TODO access control
-    pub dynamic_tasks: Vec<(Receiver<()>, Option<JoinHandle<()>>)>,
+    dynamic_tasks: Vec<DynamicTask>,
 }
 
 impl SimpleDynamicTaskScheduler {
@@ -19,27 +24,51 @@ impl SimpleDynamicTaskScheduler {
             dynamic_tasks: vec![],
         }
     }
+
+    /// Returns the types of the tasks which are still running.
+    pub fn get_tasks(&self) -> Vec<&ComponentTaskType> {
+        self.dynamic_tasks
+            .iter()
+            .filter(|dynamic_task| !dynamic_task.task.is_finished())
+            .map(|dynamic_task| &dynamic_task.task_type)
+            .collect()
+    }
+
+    /// Drops the finished tasks along with their shutdown monitors.
+    pub fn clean_finished_tasks(&mut self) {
+        self.dynamic_tasks.retain(|dynamic_task| {
+            if !dynamic_task.task.is_finished() {
+                return true;
+            }
+
+            if let Some(monitor) = &dynamic_task.task_monitor {
+                monitor.abort();
+            }
+
+            false
+        });
+    }
 }
 
 impl TaskScheduler for SimpleDynamicTaskScheduler {
     fn add_task(
         &mut self,
-        _: ComponentTaskType,
+        task_type: ComponentTaskType,
         future: impl Future + Send + 'static,
     ) -> SchedulerResult<()> {
-        let (send, recv) = channel::<()>();
-
-        let mut mdc = vec![];
-        log_mdc::iter(|k, v| mdc.push((k.to_owned(), v.to_owned())));
+        let task = tokio::spawn(
+            async move {
+                future.await;
+            }
+            .in_current_span(),
+        );
 
-        tokio::spawn(async move {
-            log_mdc::extend(mdc);
-            future.await;
-            drop(send);
+        self.dynamic_tasks.push(DynamicTask {
+            task_type,
+            task,
+            task_monitor: None,
         });
 
-        self.dynamic_tasks.push((recv, None));
-
         Ok(())
     }
 }
@@ -47,6 +76,7 @@ impl TaskScheduler for SimpleDynamicTaskScheduler {
 impl DynamicTaskScheduler for SimpleDynamicTaskScheduler {
     fn add_task_with_shutdown_signal<T, P, F>(
         &mut self,
+        task_type: ComponentTaskType,
         future: T,
         shutdown_predicate: P,
         shutdown_check_frequency: u64,
@@ -56,17 +86,14 @@ impl DynamicTaskScheduler for SimpleDynamicTaskScheduler {
         P: Fn() -> F + Sync + Send + 'static,
         F: Future<Output = bool> + Send + 'static,
     {
-        let (send, recv) = channel::<()>();
+        let task = tokio::spawn(future.in_current_span());
 
-        let task = tokio::spawn(async move {
-            future.await;
-            drop(send);
-        });
+        let task_abort_handle = task.abort_handle();
 
         let task_monitor = tokio::spawn(async move {
             loop {
                 if shutdown_predicate().await {
-                    task.abort();
+                    task_abort_handle.abort();
                     return;
                 }
 
@@ -75,17 +102,50 @@ impl DynamicTaskScheduler for SimpleDynamicTaskScheduler {
             }
         });
 
-        self.dynamic_tasks.push((recv, Some(task_monitor)));
+        self.dynamic_tasks.push(DynamicTask {
+            task_type,
+            task,
+            task_monitor: Some(task_monitor),
+        });
     }
 }
 
 #[cfg(test)]
 pub mod tests {
 
+    use super::SimpleDynamicTaskScheduler;
+    use crate::scheduler::{DynamicTaskScheduler, TaskScheduler};
+    use arpa_core::{ComponentTaskType, SubscriberType};
     use std::time::Duration;
     use tokio::task;
     use tokio::time;
 
+    #[tokio::test]
+    async fn test_list_and_clean_tasks() {
+        let mut ts = SimpleDynamicTaskScheduler::new();
+
+        let finishing_task =
+            ComponentTaskType::Subscriber(0, SubscriberType::SendingPartialSignature);
+        let running_task = ComponentTaskType::Subscriber(0, SubscriberType::InGrouping);
+
+        ts.add_task(finishing_task, async {}).unwrap();
+        ts.add_task_with_shutdown_signal(
+            running_task,
+            async {
+                time::sleep(Duration::from_secs(10)).await;
+            },
+            || async { false },
+            10,
+        );
+
+        time::sleep(Duration::from_millis(100)).await;
+
+        assert_eq!(vec![&running_task], ts.get_tasks());
+
+        ts.clean_finished_tasks();
+        assert_eq!(1, ts.dynamic_tasks.len());
+    }
+
     #[tokio::test]
     async fn test() {
         let original_task = task::spawn(async {
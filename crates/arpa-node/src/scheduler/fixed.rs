@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use futures::Future;
 use std::collections::HashMap;
 use tokio::task::JoinHandle;
+use tracing::Instrument;
 
 #[derive(Debug, Default)]
 pub struct SimpleFixedTaskScheduler {
@@ -28,13 +29,12 @@ impl TaskScheduler for SimpleFixedTaskScheduler {
             return Err(SchedulerError::TaskAlreadyExisted);
         }
 
-        let mut mdc = vec![];
-        log_mdc::iter(|k, v| mdc.push((k.to_owned(), v.to_owned())));
-
-        let handle = tokio::spawn(async move {
-            log_mdc::extend(mdc);
-            future.await;
-        });
+        let handle = tokio::spawn(
+            async move {
+                future.await;
+            }
+            .in_current_span(),
+        );
         self.fixed_tasks.insert(task_type, handle);
         Ok(())
     }
@@ -60,6 +60,14 @@ impl FixedTaskScheduler for SimpleFixedTaskScheduler {
     fn get_tasks(&self) -> Vec<&ComponentTaskType> {
         self.fixed_tasks.keys().collect::<Vec<&ComponentTaskType>>()
     }
+
+    fn get_finished_tasks(&self) -> Vec<&ComponentTaskType> {
+        self.fixed_tasks
+            .iter()
+            .filter(|(_, handle)| handle.is_finished())
+            .map(|(task_type, _)| task_type)
+            .collect::<Vec<&ComponentTaskType>>()
+    }
 }
 
 #[cfg(test)]
@@ -19,11 +19,15 @@ pub trait FixedTaskScheduler: TaskScheduler {
     async fn abort(&mut self, task_type: &ComponentTaskType) -> SchedulerResult<()>;
 
     fn get_tasks(&self) -> Vec<&ComponentTaskType>;
+
+    /// The tasks which have exited, all of them are expected to run as long as the node.
+    fn get_finished_tasks(&self) -> Vec<&ComponentTaskType>;
 }
 
 pub trait DynamicTaskScheduler: TaskScheduler {
     fn add_task_with_shutdown_signal<T, P, F>(
         &mut self,
+        task_type: ComponentTaskType,
         future: T,
         shutdown_predicate: P,
         shutdown_check_frequency: u64,
@@ -1,8 +1,19 @@
+use crate::context::chain::{Chain, MainChain};
 use crate::context::types::GeneralContext;
-use crate::context::ContextFetcher;
+use crate::context::{ChainIdentityHandlerType, Context, ContextFetcher};
+use crate::error::NodeResult;
 use crate::scheduler::FixedTaskScheduler;
 use actix_web::{get, web, App, HttpResponse, HttpServer, Responder};
+use arpa_contract_client::controller::ControllerViews;
+use arpa_contract_client::node_registry::NodeRegistryViews;
+use arpa_core::metrics::{encode_metrics, BLOCK_HEIGHT_LAG};
+use arpa_core::{address_to_string, DKGStatus, DEFAULT_MAX_BLOCK_STALENESS_BLOCKS};
+use arpa_dal::{BlockInfoHandler, GroupInfoHandler};
+use ethers::providers::Middleware;
+use ethers::types::Address;
+use serde::Serialize;
 use std::sync::Arc;
+use std::time::Duration;
 use threshold_bls::{
     group::Curve,
     sig::{SignatureScheme, ThresholdScheme},
@@ -45,6 +56,358 @@ async fn health<
     }
 }
 
+#[derive(Debug, Serialize)]
+struct NodeStatus {
+    id_address: String,
+    live: bool,
+    ready: bool,
+    running_components: usize,
+    finished_components: usize,
+    /// none if the chains are not in sync to look it up
+    registered: Option<bool>,
+    chains: Vec<ChainStatus>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChainStatus {
+    chain_id: usize,
+    description: String,
+    block_height: usize,
+    block_time: usize,
+    /// none if no block has been received since the node started
+    seconds_since_last_block: Option<u64>,
+    block_fresh: bool,
+    group: Option<GroupStatus>,
+    pending_results: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct GroupStatus {
+    index: usize,
+    epoch: usize,
+    state: bool,
+    dkg_status: DKGStatus,
+    is_committer: bool,
+    committers: Vec<String>,
+}
+
+/// Counts the running and the finished task loops of the node, all of which are expected to run
+/// as long as the node.
+async fn count_components<
+    PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+    SS: SignatureScheme
+        + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    context: &NodeContext<PC, SS>,
+) -> (usize, usize)
+where
+    <SS as ThresholdScheme>::Error: Sync + Send,
+    <SS as SignatureScheme>::Error: Sync + Send,
+{
+    let context = context.read().await;
+    let fixed_task_handler = context.get_fixed_task_handler();
+    let fixed_task_handler = fixed_task_handler.read().await;
+
+    let finished_components = fixed_task_handler.get_finished_tasks().len();
+    let running_components = fixed_task_handler.get_tasks().len() - finished_components;
+
+    (running_components, finished_components)
+}
+
+/// The node is live as long as it has task loops and none of them has exited.
+fn is_live(running_components: usize, finished_components: usize) -> bool {
+    running_components > 0 && finished_components == 0
+}
+
+/// Whether the node is registered and activated in the NodeRegistry.
+async fn is_registered<PC: Curve>(
+    chain_identity: &ChainIdentityHandlerType<PC>,
+    id_address: Address,
+) -> NodeResult<bool> {
+    let controller_client = chain_identity.build_controller_client();
+
+    let node_registry_address =
+        ControllerViews::<PC>::get_node_registry_address(&controller_client).await?;
+
+    let node = chain_identity
+        .build_node_registry_client(node_registry_address)
+        .get_node(id_address)
+        .await?;
+
+    Ok(node.id_address == id_address && node.state)
+}
+
+async fn build_chain_status<PC: Curve>(
+    description: &str,
+    block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+    pending_results: usize,
+) -> ChainStatus {
+    let block_cache = block_cache.read().await;
+    let block_time = block_cache.get_block_time();
+    let seconds_since_last_block = block_cache
+        .get_block_updated_at()
+        .map(|updated_at| updated_at.elapsed().as_secs());
+    let staleness_tolerance =
+        Duration::from_secs((block_time * DEFAULT_MAX_BLOCK_STALENESS_BLOCKS) as u64);
+
+    let group_cache = group_cache.read().await;
+    // the group cache is empty until the node is assigned to a group
+    let group = group_cache.get_group().ok().map(|group| GroupStatus {
+        index: group.index,
+        epoch: group.epoch,
+        state: group.state,
+        dkg_status: group_cache.get_dkg_status().unwrap_or(DKGStatus::None),
+        is_committer: group_cache
+            .get_self_id_address()
+            .and_then(|id_address| group_cache.is_committer(id_address))
+            .unwrap_or(false),
+        committers: group
+            .committers
+            .iter()
+            .cloned()
+            .map(address_to_string)
+            .collect(),
+    });
+
+    ChainStatus {
+        chain_id: block_cache.get_chain_id(),
+        description: description.to_string(),
+        block_height: block_cache.get_block_height(),
+        block_time,
+        seconds_since_last_block,
+        block_fresh: seconds_since_last_block
+            .map(|seconds| seconds <= staleness_tolerance.as_secs())
+            .unwrap_or(false),
+        group,
+        pending_results,
+    }
+}
+
+async fn build_node_status<
+    PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+    SS: SignatureScheme
+        + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    context: &NodeContext<PC, SS>,
+) -> NodeResult<NodeStatus>
+where
+    <SS as ThresholdScheme>::Error: Sync + Send,
+    <SS as SignatureScheme>::Error: Sync + Send,
+{
+    let (running_components, finished_components) = count_components(context).await;
+
+    let context = context.read().await;
+
+    let main_chain = context.get_main_chain();
+
+    let id_address = main_chain.get_node_cache().read().await.get_id_address()?;
+
+    let pending_results = main_chain
+        .get_randomness_result_cache()
+        .read()
+        .await
+        .count_pending()
+        .await?
+        + main_chain
+            .get_group_relay_result_cache()
+            .read()
+            .await
+            .count_pending()
+            .await?;
+
+    let mut chains = vec![
+        build_chain_status(
+            main_chain.description(),
+            main_chain.get_block_cache(),
+            main_chain.get_group_cache(),
+            pending_results,
+        )
+        .await,
+    ];
+
+    for index in context.get_supported_relayed_chains() {
+        if let Some(relayed_chain) = context.get_relayed_chain(index) {
+            let pending_results = relayed_chain
+                .get_randomness_result_cache()
+                .read()
+                .await
+                .count_pending()
+                .await?
+                + relayed_chain
+                    .get_group_relay_confirmation_result_cache()
+                    .read()
+                    .await
+                    .count_pending()
+                    .await?;
+
+            chains.push(
+                build_chain_status(
+                    relayed_chain.description(),
+                    relayed_chain.get_block_cache(),
+                    relayed_chain.get_group_cache(),
+                    pending_results,
+                )
+                .await,
+            );
+        }
+    }
+
+    let live = is_live(running_components, finished_components);
+
+    let in_sync = chains.iter().all(|chain| chain.block_fresh);
+
+    // the registry is only looked up while the chains are in sync,
+    // so that a probe doesn't wait on the retries against a dead provider
+    let registered = if in_sync {
+        Some(
+            is_registered(&*main_chain.get_chain_identity().read().await, id_address)
+                .await
+                .unwrap_or(false),
+        )
+    } else {
+        None
+    };
+
+    // the node takes tasks once every chain is in sync and it is registered,
+    // whether or not it has been assigned to a group yet
+    let ready = live && in_sync && registered == Some(true);
+
+    Ok(NodeStatus {
+        id_address: address_to_string(id_address),
+        live,
+        ready,
+        running_components,
+        finished_components,
+        registered,
+        chains,
+    })
+}
+
+async fn livez<
+    PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+    SS: SignatureScheme
+        + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    context: web::Data<NodeContext<PC, SS>>,
+) -> impl Responder
+where
+    <SS as ThresholdScheme>::Error: Sync + Send,
+    <SS as SignatureScheme>::Error: Sync + Send,
+{
+    let (running_components, finished_components) = count_components(&context).await;
+
+    if is_live(running_components, finished_components) {
+        HttpResponse::Ok()
+    } else {
+        HttpResponse::ServiceUnavailable()
+    }
+}
+
+async fn readyz<
+    PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+    SS: SignatureScheme
+        + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    context: web::Data<NodeContext<PC, SS>>,
+) -> impl Responder
+where
+    <SS as ThresholdScheme>::Error: Sync + Send,
+    <SS as SignatureScheme>::Error: Sync + Send,
+{
+    match build_node_status(&context).await {
+        Ok(status) if status.ready => HttpResponse::Ok(),
+        _ => HttpResponse::ServiceUnavailable(),
+    }
+}
+
+async fn status<
+    PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+    SS: SignatureScheme
+        + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    context: web::Data<NodeContext<PC, SS>>,
+) -> impl Responder
+where
+    <SS as ThresholdScheme>::Error: Sync + Send,
+    <SS as SignatureScheme>::Error: Sync + Send,
+{
+    match build_node_status(&context).await {
+        Ok(status) => HttpResponse::Ok().json(status),
+        Err(e) => HttpResponse::InternalServerError().body(format!("{:?}", e)),
+    }
+}
+
+async fn metrics<
+    PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
+    SS: SignatureScheme
+        + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    context: web::Data<NodeContext<PC, SS>>,
+) -> impl Responder
+where
+    <SS as ThresholdScheme>::Error: Sync + Send,
+    <SS as SignatureScheme>::Error: Sync + Send,
+{
+    let chains = {
+        let context = context.read().await;
+        let main_chain = context.get_main_chain();
+        let mut chains = vec![(
+            main_chain.get_chain_identity(),
+            main_chain.get_block_cache(),
+        )];
+        for index in context.get_supported_relayed_chains() {
+            if let Some(relayed_chain) = context.get_relayed_chain(index) {
+                chains.push((
+                    relayed_chain.get_chain_identity(),
+                    relayed_chain.get_block_cache(),
+                ));
+            }
+        }
+        chains
+    };
+
+    // the lag is measured against the provider head at scrape time,
+    // an unreachable provider keeps the lag it had at the last scrape
+    for (chain_identity, block_cache) in chains {
+        let chain_identity = chain_identity.read().await;
+        if let Ok(latest_block_height) = chain_identity.get_provider().get_block_number().await {
+            let block_height = block_cache.read().await.get_block_height();
+            BLOCK_HEIGHT_LAG
+                .with_label_values(&[&chain_identity.get_chain_id().to_string()])
+                .set(latest_block_height.as_usize().saturating_sub(block_height) as i64);
+        }
+    }
+
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4")
+        .body(encode_metrics())
+}
+
 pub async fn start_statistics_server<
     PC: Curve + std::fmt::Debug + Clone + Sync + Send + 'static,
     SS: SignatureScheme
@@ -65,6 +428,10 @@ where
         App::new()
             .app_data(web::Data::new(context.clone()))
             .route("/health", web::get().to(health::<PC, SS>))
+            .route("/livez", web::get().to(livez::<PC, SS>))
+            .route("/readyz", web::get().to(readyz::<PC, SS>))
+            .route("/status", web::get().to(status::<PC, SS>))
+            .route("/metrics", web::get().to(metrics::<PC, SS>))
             .service(greet)
     })
     .bind(endpoint)
@@ -81,6 +448,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::committer::CommitterCredentials;
     use crate::context::chain::types::GeneralMainChain;
     use crate::scheduler::TaskScheduler;
     use actix_web::{
@@ -88,18 +456,21 @@ mod tests {
         test,
     };
     use arpa_core::{
-        ComponentTaskType, Config, GeneralMainChainIdentity, ListenerType, RandomnessTask,
-        PLACEHOLDER_ADDRESS,
+        ChainTransport, ComponentTaskType, Config, GeneralMainChainIdentity, GroupRelayTask,
+        ListenerType, ProviderEndpoints, RandomnessTask, PLACEHOLDER_ADDRESS,
     };
     use arpa_dal::{
         cache::{
-            InMemoryBLSTasksQueue, InMemoryGroupInfoCache, InMemoryNodeInfoCache,
-            InMemorySignatureResultCache, RandomnessResultCache,
+            GroupRelayResultCache, InMemoryBLSTasksQueue, InMemoryBlockCheckpointCache,
+            InMemoryGroupInfoCache, InMemoryNodeInfoCache, InMemorySignatureResultCache,
+            RandomnessResultCache,
         },
-        BLSTasksHandler, GroupInfoHandler, NodeInfoHandler, SignatureResultCacheHandler,
+        BLSTasksHandler, BlockCheckpointHandler, GroupInfoHandler, NodeInfoHandler,
+        SignatureResultCacheHandler,
     };
     use ethers::{
         providers::{Provider, Ws},
+        signers::LocalWallet,
         types::Address,
         utils::Anvil,
     };
@@ -108,9 +479,10 @@ mod tests {
     async fn build_context() -> NodeContext<G2Curve, G2Scheme> {
         let config = Config::default();
 
-        let fake_wallet = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
-            .parse()
-            .unwrap();
+        let fake_wallet: LocalWallet =
+            "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+                .parse()
+                .unwrap();
 
         let node_cache: Arc<RwLock<Box<dyn NodeInfoHandler<G2Curve>>>> = Arc::new(RwLock::new(
             Box::new(InMemoryNodeInfoCache::<G2Curve>::new(Address::random())),
@@ -129,9 +501,23 @@ mod tests {
             RandomnessResultCache,
         >::new())));
 
+        let group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>> =
+            Arc::new(RwLock::new(Box::new(InMemoryBLSTasksQueue::new())));
+
+        let group_relay_result_cache: Arc<
+            RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>,
+        > = Arc::new(RwLock::new(Box::new(InMemorySignatureResultCache::<
+            GroupRelayResultCache,
+        >::new())));
+
+        let block_checkpoint_cache: Arc<RwLock<Box<dyn BlockCheckpointHandler>>> =
+            Arc::new(RwLock::new(Box::new(InMemoryBlockCheckpointCache::new())));
+
         let avnil = Anvil::new().spawn();
 
-        let provider = Arc::new(Provider::<Ws>::connect(avnil.ws_endpoint()).await.unwrap());
+        let provider = Arc::new(Provider::new(ChainTransport::from(
+            Ws::connect(avnil.ws_endpoint()).await.unwrap(),
+        )));
 
         let contract_transaction_retry_descriptor = config
             .get_time_limits()
@@ -142,9 +528,9 @@ mod tests {
 
         let main_chain_identity = GeneralMainChainIdentity::new(
             config.get_main_chain_id(),
-            fake_wallet,
+            fake_wallet.clone(),
             provider,
-            avnil.ws_endpoint(),
+            ProviderEndpoints::new(vec![avnil.ws_endpoint()], 0),
             Address::random(),
             Address::random(),
             Address::random(),
@@ -155,23 +541,36 @@ mod tests {
         let main_chain = GeneralMainChain::<G2Curve, G2Scheme>::new(
             "main chain".to_string(),
             false,
+            false,
+            false,
+            false,
             main_chain_identity.clone(),
             node_cache.clone(),
             group_cache.clone(),
             randomness_tasks_cache,
             randomness_result_cache,
+            group_relay_tasks_cache,
+            group_relay_result_cache,
+            block_checkpoint_cache,
+            0,
             *config.get_time_limits(),
             config.get_listeners().clone(),
         );
 
-        let context = GeneralContext::new(main_chain, config);
+        let context = GeneralContext::new(
+            main_chain,
+            config,
+            CommitterCredentials::new(fake_wallet, None),
+        );
 
         context
             .get_fixed_task_handler()
             .write()
             .await
-            .add_task(ComponentTaskType::Listener(0, ListenerType::Block), async {
-            })
+            .add_task(
+                ComponentTaskType::Listener(0, ListenerType::Block),
+                std::future::pending::<()>(),
+            )
             .unwrap();
 
         Arc::new(RwLock::new(context))
@@ -203,6 +602,90 @@ mod tests {
         assert!(resp.status().is_client_error());
     }
 
+    #[actix_web::test]
+    async fn test_livez_and_readyz_get() {
+        let context = build_context().await;
+        let app = App::new()
+            .app_data(web::Data::new(context))
+            .route("/livez", web::get().to(livez::<G2Curve, G2Scheme>))
+            .route("/readyz", web::get().to(readyz::<G2Curve, G2Scheme>));
+        let app = test::init_service(app).await;
+
+        // the node is running but has neither received a block nor joined a group
+        let req = test::TestRequest::get().uri("/livez").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+
+        let req = test::TestRequest::get().uri("/readyz").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[actix_web::test]
+    async fn test_livez_with_finished_component() {
+        let context = build_context().await;
+
+        context
+            .read()
+            .await
+            .get_fixed_task_handler()
+            .write()
+            .await
+            .add_task(
+                ComponentTaskType::Listener(0, ListenerType::PreGrouping),
+                async {},
+            )
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let app = App::new()
+            .app_data(web::Data::new(context))
+            .route("/livez", web::get().to(livez::<G2Curve, G2Scheme>));
+        let app = test::init_service(app).await;
+
+        // a task loop of the node has exited
+        let req = test::TestRequest::get().uri("/livez").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[actix_web::test]
+    async fn test_status_get() {
+        let context = build_context().await;
+        let app = App::new()
+            .app_data(web::Data::new(context))
+            .route("/status", web::get().to(status::<G2Curve, G2Scheme>));
+        let app = test::init_service(app).await;
+
+        let req = test::TestRequest::get().uri("/status").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let body: serde_json::Value = test::read_body_json(resp).await;
+        assert_eq!(body["live"], true);
+        assert_eq!(body["ready"], false);
+        assert_eq!(body["finished_components"], 0);
+        assert_eq!(body["registered"], serde_json::Value::Null);
+        assert_eq!(body["chains"][0]["group"], serde_json::Value::Null);
+        assert_eq!(body["chains"][0]["pending_results"], 0);
+    }
+
+    #[actix_web::test]
+    async fn test_metrics_get() {
+        let context = build_context().await;
+        let app = App::new()
+            .app_data(web::Data::new(context))
+            .route("/metrics", web::get().to(metrics::<G2Curve, G2Scheme>));
+        let app = test::init_service(app).await;
+
+        let req = test::TestRequest::get().uri("/metrics").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), http::StatusCode::OK);
+        let body = test::read_body(resp).await;
+        assert!(String::from_utf8(body.to_vec())
+            .unwrap()
+            .contains("arpa_node_block_height_lag"));
+    }
+
     #[actix_web::test]
     async fn test_greet() {
         let app = App::new().service(greet);
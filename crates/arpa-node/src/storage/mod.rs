@@ -0,0 +1,213 @@
+use arpa_core::{
+    DataRetentionDescriptor, DataSource, GroupRelayConfirmationTask, GroupRelayTask, RandomnessTask,
+};
+use arpa_dal::cache::{
+    GroupRelayConfirmationResultCache, GroupRelayResultCache, RandomnessResultCache,
+};
+use arpa_dal::error::{DataAccessError, DataAccessResult};
+use arpa_dal::{
+    BLSTasksHandler, BlockCheckpointHandler, GroupInfoHandler, NodeInfoHandler,
+    SignatureResultCacheHandler,
+};
+use arpa_sea_orm_db::SeaOrmDB;
+use ethers::types::Address;
+use std::fs;
+use threshold_bls::group::Curve;
+
+/// The database the node keeps its state in, selected by the `data_path` in the config.
+#[derive(Debug, Clone)]
+pub struct NodeDB {
+    db: SeaOrmDB,
+}
+
+impl NodeDB {
+    /// The SQLite file is created along with its directory if absent, and is encrypted by the
+    /// signing key of the node. On PostgreSQL the columns holding secrets are encrypted by a key
+    /// derived from the signing key instead.
+    ///
+    /// The relayed chains of the config tell which chain the randomness tasks and results kept by
+    /// an older release in the tables of an L2 chain belong to.
+    pub async fn build(
+        data_source: &DataSource,
+        signing_key: &[u8],
+        relayed_chain_ids: &[usize],
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let db = match data_source {
+            DataSource::Sqlite(db_path) => {
+                if let Some(parent) = db_path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+
+                arpa_sqlite_db::build(
+                    db_path.as_os_str().to_str().unwrap(),
+                    signing_key,
+                    relayed_chain_ids,
+                )
+                .await?
+            }
+            DataSource::Postgres(database_url) => {
+                arpa_postgres_db::build(database_url, signing_key).await?
+            }
+        };
+
+        Ok(NodeDB { db })
+    }
+
+    /// Loads the node info saved by the last run, none if the database is new.
+    pub async fn load_node_info_cache<C: Curve + 'static>(
+        &self,
+    ) -> DataAccessResult<Option<Box<dyn NodeInfoHandler<C>>>> {
+        let mut node_cache = self.db.get_node_info_client::<C>();
+        let is_saved = node_cache
+            .refresh_current_node_info()
+            .await
+            .map_err(DataAccessError::from)?;
+        Ok(is_saved.then(|| Box::new(node_cache) as Box<dyn NodeInfoHandler<C>>))
+    }
+
+    pub async fn save_node_info_cache<C: Curve + 'static>(
+        &self,
+        id_address: Address,
+        node_rpc_endpoint: String,
+        dkg_private_key: C::Scalar,
+        dkg_public_key: C::Point,
+    ) -> DataAccessResult<Box<dyn NodeInfoHandler<C>>> {
+        let mut node_cache = self.db.get_node_info_client::<C>();
+        node_cache
+            .save_node_info(
+                id_address,
+                node_rpc_endpoint,
+                dkg_private_key,
+                dkg_public_key,
+            )
+            .await
+            .map_err(DataAccessError::from)?;
+        Ok(Box::new(node_cache))
+    }
+
+    /// Loads the group info of the latest DKG task, if any.
+    pub async fn load_group_info_cache<C: Curve + 'static>(
+        &self,
+        id_address: Address,
+    ) -> DataAccessResult<Box<dyn GroupInfoHandler<C>>> {
+        let mut group_cache = self.db.get_group_info_client::<C>(id_address);
+        group_cache
+            .refresh_current_group_info()
+            .await
+            .map_err(DataAccessError::from)?;
+        Ok(Box::new(group_cache))
+    }
+
+    pub fn build_randomness_tasks_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn BLSTasksHandler<RandomnessTask>>> {
+        self.db.build_randomness_tasks_cache(chain_id)
+    }
+
+    pub async fn build_randomness_result_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>> {
+        self.db.build_randomness_result_cache(chain_id).await
+    }
+
+    pub fn build_group_relay_tasks_cache(
+        &self,
+    ) -> DataAccessResult<Box<dyn BLSTasksHandler<GroupRelayTask>>> {
+        self.db.build_group_relay_tasks_cache()
+    }
+
+    pub async fn build_group_relay_result_cache(
+        &self,
+    ) -> DataAccessResult<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>> {
+        self.db.build_group_relay_result_cache().await
+    }
+
+    pub fn build_group_relay_confirmation_tasks_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>> {
+        self.db.build_group_relay_confirmation_tasks_cache(chain_id)
+    }
+
+    pub async fn build_group_relay_confirmation_result_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>
+    {
+        self.db
+            .build_group_relay_confirmation_result_cache(chain_id)
+            .await
+    }
+
+    pub fn build_block_checkpoint_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn BlockCheckpointHandler>> {
+        self.db.build_block_checkpoint_cache(chain_id)
+    }
+
+    pub async fn prune(
+        &self,
+        chain_id: usize,
+        current_block_height: usize,
+        retention: &DataRetentionDescriptor,
+    ) -> DataAccessResult<u64> {
+        self.db
+            .prune(chain_id, current_block_height, retention)
+            .await
+    }
+
+    pub async fn get_table_sizes(&self) -> DataAccessResult<Vec<(&'static str, u64)>> {
+        self.db.get_table_sizes().await
+    }
+
+    /// Counts the bytes the database takes, on the server or in the file.
+    pub async fn get_database_size(&self) -> DataAccessResult<u64> {
+        self.db.get_database_size().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NodeDB;
+    use arpa_core::DataSource;
+    use std::{env, fs};
+    use threshold_bls::schemes::bn254::{G2Curve, G2Scheme};
+    use threshold_bls::sig::Scheme;
+
+    #[tokio::test]
+    async fn test_load_node_info_saved_in_sqlite_file() {
+        let db_path = env::temp_dir().join(format!("arpa_node_db_{}", rand::random::<u64>()));
+        let data_source = DataSource::from(db_path.join("data.sqlite").to_str().unwrap());
+
+        let db = NodeDB::build(&data_source, b"passphrase", &[])
+            .await
+            .unwrap();
+        assert!(db
+            .load_node_info_cache::<G2Curve>()
+            .await
+            .unwrap()
+            .is_none());
+
+        let (dkg_private_key, dkg_public_key) = G2Scheme::keypair(&mut rand::thread_rng());
+        let id_address = "0x0000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        db.save_node_info_cache::<G2Curve>(
+            id_address,
+            "127.0.0.1:50061".to_string(),
+            dkg_private_key,
+            dkg_public_key,
+        )
+        .await
+        .unwrap();
+
+        let node_cache = db.load_node_info_cache::<G2Curve>().await.unwrap().unwrap();
+        assert_eq!(id_address, node_cache.get_id_address().unwrap());
+        assert_eq!(&dkg_public_key, node_cache.get_dkg_public_key().unwrap());
+
+        fs::remove_dir_all(db_path).unwrap();
+    }
+}
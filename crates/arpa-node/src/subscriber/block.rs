@@ -1,47 +1,254 @@
 use super::{DebuggableEvent, DebuggableSubscriber, Subscriber};
 use crate::{
+    context::ChainIdentityHandlerType,
     error::NodeResult,
     event::{new_block::NewBlock, types::Topic},
     queue::{event_queue::EventQueue, EventSubscriber},
 };
-use arpa_dal::BlockInfoHandler;
+use arpa_contract_client::adapter::AdapterLogs;
+use arpa_core::{
+    log::{build_task_related_payload, LogType},
+    metrics::{BLOCK_HEIGHT, CHAIN_REORGS, RANDOMNESS_TASKS},
+    BLSTaskType, RandomnessTask, TaskType,
+};
+use arpa_dal::{BLSTasksHandler, BlockInfoHandler};
 use async_trait::async_trait;
-use log::debug;
+use ethers::{
+    providers::Middleware,
+    types::{Address, H256},
+};
+use log::{debug, info, warn};
+use serde_json::json;
 use std::sync::Arc;
+use threshold_bls::group::Curve;
 use tokio::sync::RwLock;
 
 #[derive(Debug)]
-pub struct BlockSubscriber {
+pub struct BlockSubscriber<PC: Curve> {
     chain_id: usize,
+    id_address: Address,
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
     block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+    randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>>,
     eq: Arc<RwLock<EventQueue>>,
 }
 
-impl BlockSubscriber {
+impl<PC: Curve> BlockSubscriber<PC> {
     pub fn new(
         chain_id: usize,
+        id_address: Address,
+        chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
         block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
+        randomness_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<RandomnessTask>>>>,
         eq: Arc<RwLock<EventQueue>>,
     ) -> Self {
         BlockSubscriber {
             chain_id,
+            id_address,
+            chain_identity,
             block_cache,
+            randomness_tasks_cache,
             eq,
         }
     }
 }
 
+impl<PC: Curve + Sync + Send> BlockSubscriber<PC> {
+    /// Walks back from the parent of the new block as long as the blocks followed now differ from
+    /// the recorded ones, and returns their hashes from the highest to the lowest.
+    async fn find_replaced_block_hashes(
+        &self,
+        block_height: usize,
+        parent_hash: H256,
+    ) -> Vec<(usize, H256)> {
+        let mut replaced_block_hashes = vec![];
+
+        let mut block_height = block_height;
+        let mut block_hash = parent_hash;
+
+        while block_height > 0 {
+            block_height -= 1;
+
+            let recorded_block_hash = self.block_cache.read().await.get_block_hash(block_height);
+
+            // the blocks below the recorded history are not checked
+            if recorded_block_hash.map_or(true, |hash| hash == block_hash) {
+                break;
+            }
+
+            replaced_block_hashes.push((block_height, block_hash));
+
+            let block = self
+                .chain_identity
+                .read()
+                .await
+                .get_provider()
+                .get_block(block_height as u64)
+                .await;
+
+            match block {
+                Ok(Some(block)) => block_hash = block.parent_hash,
+                res => {
+                    warn!(
+                        "chain {} failed to fetch block {} to find the reorganized blocks: {:?}",
+                        self.chain_id, block_height, res
+                    );
+                    break;
+                }
+            }
+        }
+
+        replaced_block_hashes
+    }
+
+    /// Checks again the randomness tasks assigned at or after the reorganized block,
+    /// and withdraws the ones whose request is not in the blocks followed now.
+    async fn recheck_randomness_tasks(&self, reorg_block_height: usize) -> NodeResult<()> {
+        let tasks = self
+            .randomness_tasks_cache
+            .read()
+            .await
+            .get_assigned_from(reorg_block_height)
+            .await?;
+
+        if tasks.is_empty() {
+            return Ok(());
+        }
+
+        let client = self
+            .chain_identity
+            .read()
+            .await
+            .build_adapter_client(self.id_address);
+
+        for task in tasks {
+            let block_hash = self
+                .block_cache
+                .read()
+                .await
+                .get_block_hash(task.assignment_block_height);
+
+            match client
+                .is_randomness_task_requested(
+                    &task.request_id,
+                    task.assignment_block_height,
+                    block_hash,
+                )
+                .await
+            {
+                Ok(true) => {}
+                Ok(false) => {
+                    info!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::TaskWithdrawn,
+                            "Randomness task withdrawn as its request block is replaced by a reorg.",
+                            self.chain_id,
+                            &task.request_id,
+                            TaskType::BLS(BLSTaskType::Randomness),
+                            json!(task),
+                            None
+                        )
+                    );
+
+                    self.randomness_tasks_cache
+                        .write()
+                        .await
+                        .remove(&task.request_id)
+                        .await?;
+
+                    RANDOMNESS_TASKS
+                        .with_label_values(&[&self.chain_id.to_string(), "withdrawn"])
+                        .inc();
+                }
+                // the tasks not handed out yet are confirmed again before being handled
+                Err(e) => {
+                    warn!(
+                        "chain {} failed to check the randomness task {} after a reorg: {:?}",
+                        self.chain_id,
+                        hex::encode(&task.request_id),
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[async_trait]
-impl Subscriber for BlockSubscriber {
+impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber for BlockSubscriber<PC> {
     async fn notify(&self, topic: Topic, payload: &(dyn DebuggableEvent)) -> NodeResult<()> {
         debug!("{:?}", topic);
 
-        let &NewBlock { block_height, .. } = payload.as_any().downcast_ref::<NewBlock>().unwrap();
+        let &NewBlock {
+            block_height,
+            block_hash,
+            parent_hash,
+            ..
+        } = payload.as_any().downcast_ref::<NewBlock>().unwrap();
 
-        self.block_cache
-            .write()
-            .await
-            .set_block_height(block_height);
+        // a block at a known height with another hash, or whose parent is not the block
+        // we followed, means the chain has been reorganized
+        let (replaced, forked) = {
+            let block_cache = self.block_cache.read().await;
+
+            let replaced = block_cache
+                .get_block_hash(block_height)
+                .map_or(false, |hash| hash != block_hash);
+            let forked = block_height > 0
+                && block_cache
+                    .get_block_hash(block_height - 1)
+                    .map_or(false, |hash| hash != parent_hash);
+
+            (replaced, forked)
+        };
+
+        let mut reorg_block_height = None;
+
+        if replaced || forked {
+            warn!(
+                "chain {} reorganized at block {}, new block hash: {:?}, parent hash: {:?}",
+                self.chain_id, block_height, block_hash, parent_hash
+            );
+
+            CHAIN_REORGS
+                .with_label_values(&[&self.chain_id.to_string()])
+                .inc();
+
+            let replaced_block_hashes = if forked {
+                self.find_replaced_block_hashes(block_height, parent_hash)
+                    .await
+            } else {
+                vec![]
+            };
+
+            reorg_block_height = Some(
+                replaced_block_hashes
+                    .last()
+                    .map_or(block_height, |(height, _)| *height),
+            );
+
+            let mut block_cache = self.block_cache.write().await;
+            for (height, hash) in replaced_block_hashes.into_iter().rev() {
+                block_cache.set_block_hash(height, hash);
+            }
+        }
+
+        {
+            let mut block_cache = self.block_cache.write().await;
+            block_cache.set_block_hash(block_height, block_hash);
+            block_cache.set_block_height(block_height);
+        }
+
+        BLOCK_HEIGHT
+            .with_label_values(&[&self.chain_id.to_string()])
+            .set(block_height as i64);
+
+        if let Some(reorg_block_height) = reorg_block_height {
+            self.recheck_randomness_tasks(reorg_block_height).await?;
+        }
 
         Ok(())
     }
@@ -59,4 +266,7 @@ impl Subscriber for BlockSubscriber {
     }
 }
 
-impl DebuggableSubscriber for BlockSubscriber {}
+impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> DebuggableSubscriber
+    for BlockSubscriber<PC>
+{
+}
@@ -0,0 +1,353 @@
+use super::{DebuggableEvent, DebuggableSubscriber, Subscriber};
+use crate::{
+    algorithm::bls::{BLSCore, SimpleBLSCore},
+    context::ChainIdentityHandlerType,
+    error::NodeResult,
+    event::{
+        ready_to_fulfill_group_relay_confirmation_task::ReadyToFulfillGroupRelayConfirmationTask,
+        types::Topic,
+    },
+    queue::{event_queue::EventQueue, EventSubscriber},
+    scheduler::{dynamic::SimpleDynamicTaskScheduler, TaskScheduler},
+};
+use arpa_contract_client::{
+    controller_oracle::{ControllerOracleTransactions, ControllerOracleViews},
+    error::ContractClientError,
+};
+use arpa_core::{
+    log::{build_task_related_payload, build_task_related_transaction_receipt_payload, LogType},
+    BLSTaskType, ComponentTaskType, GroupRelayConfirmationTask, SubscriberType, TaskType,
+    DEFAULT_MAX_GROUP_RELAY_ATTEMPTS,
+};
+use arpa_dal::{
+    cache::GroupRelayConfirmationResultCache, BLSResultCacheState, SignatureResultCacheHandler,
+};
+use async_trait::async_trait;
+use ethers::types::U256;
+use log::{debug, error, info};
+use serde_json::json;
+use std::{marker::PhantomData, sync::Arc};
+use threshold_bls::{
+    group::Curve,
+    sig::{SignatureScheme, ThresholdScheme},
+};
+use tokio::sync::RwLock;
+
+#[derive(Debug)]
+pub struct GroupRelayConfirmationSignatureAggregationSubscriber<
+    PC: Curve,
+    S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>,
+> {
+    chain_id: usize,
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    group_relay_confirmation_signature_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>>,
+    eq: Arc<RwLock<EventQueue>>,
+    ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    c: PhantomData<PC>,
+    s: PhantomData<S>,
+}
+
+impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>>
+    GroupRelayConfirmationSignatureAggregationSubscriber<PC, S>
+{
+    pub fn new(
+        chain_id: usize,
+        chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+        group_relay_confirmation_signature_cache: Arc<
+            RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>,
+        >,
+        eq: Arc<RwLock<EventQueue>>,
+        ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    ) -> Self {
+        GroupRelayConfirmationSignatureAggregationSubscriber {
+            chain_id,
+            chain_identity,
+            group_relay_confirmation_signature_cache,
+            eq,
+            ts,
+            c: PhantomData,
+            s: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+pub trait FulfillGroupRelayConfirmationHandler {
+    async fn handle(
+        &self,
+        group_relay_confirmation_task: GroupRelayConfirmationTask,
+        signature: Vec<u8>,
+    ) -> NodeResult<()>;
+}
+
+pub struct GeneralFulfillGroupRelayConfirmationHandler<PC: Curve> {
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    group_relay_confirmation_signature_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>>,
+    pc: PhantomData<PC>,
+}
+
+#[async_trait]
+impl<PC: Curve> FulfillGroupRelayConfirmationHandler
+    for GeneralFulfillGroupRelayConfirmationHandler<PC>
+{
+    async fn handle(
+        &self,
+        group_relay_confirmation_task: GroupRelayConfirmationTask,
+        signature: Vec<u8>,
+    ) -> NodeResult<()> {
+        let client = self
+            .chain_identity
+            .read()
+            .await
+            .build_controller_oracle_client();
+
+        let chain_id = self.chain_identity.read().await.get_chain_id();
+
+        let request_id = group_relay_confirmation_task.request_id.clone();
+
+        let group_relay_confirmation_task_json = json!(group_relay_confirmation_task);
+
+        let group = ControllerOracleViews::<PC>::get_group(
+            &client,
+            group_relay_confirmation_task.relayed_group_index,
+        )
+        .await?;
+
+        if group.epoch < group_relay_confirmation_task.relayed_group_epoch {
+            match client
+                .confirm_group_relay(
+                    group_relay_confirmation_task.group_relay_cache_index,
+                    signature,
+                )
+                .await
+            {
+                Ok(receipt) => {
+                    self.group_relay_confirmation_signature_cache
+                        .write()
+                        .await
+                        .update_commit_result(&request_id, BLSResultCacheState::Committed)
+                        .await?;
+
+                    info!(
+                        "{}",
+                        build_task_related_transaction_receipt_payload(
+                            LogType::FulfillmentFinished,
+                            "Group relay confirmed successfully.",
+                            chain_id,
+                            &request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelayConfirmation),
+                            group_relay_confirmation_task_json,
+                            receipt.transaction_hash,
+                            receipt.gas_used.unwrap_or(U256::zero()),
+                            receipt.effective_gas_price.unwrap_or(U256::zero()),
+                        )
+                    );
+                }
+                Err(e) => {
+                    self.group_relay_confirmation_signature_cache
+                        .write()
+                        .await
+                        .update_commit_result(&request_id, BLSResultCacheState::NotCommitted)
+                        .await?;
+
+                    match e {
+                        ContractClientError::TransactionFailed(receipt) => {
+                            error!(
+                                "{}",
+                                build_task_related_transaction_receipt_payload(
+                                    LogType::FulfillmentFailed,
+                                    "Group relay confirmation reverted.",
+                                    chain_id,
+                                    &request_id,
+                                    TaskType::BLS(BLSTaskType::GroupRelayConfirmation),
+                                    group_relay_confirmation_task_json,
+                                    receipt.transaction_hash,
+                                    receipt.gas_used.unwrap_or(U256::zero()),
+                                    receipt.effective_gas_price.unwrap_or(U256::zero()),
+                                )
+                            );
+                        }
+                        _ => {
+                            error!(
+                                "{}",
+                                build_task_related_payload(
+                                    LogType::FulfillmentFailed,
+                                    &format!("Group relay confirmation failed with error: {:?}", e),
+                                    chain_id,
+                                    &request_id,
+                                    TaskType::BLS(BLSTaskType::GroupRelayConfirmation),
+                                    group_relay_confirmation_task_json,
+                                    None,
+                                )
+                            );
+                        }
+                    }
+                }
+            }
+
+            self.group_relay_confirmation_signature_cache
+                .write()
+                .await
+                .incr_committed_times(&request_id)
+                .await?;
+        } else {
+            self.group_relay_confirmation_signature_cache
+                .write()
+                .await
+                .update_commit_result(&request_id, BLSResultCacheState::CommittedByOthers)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<
+        PC: Curve + std::fmt::Debug + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Sync
+            + Send
+            + 'static,
+    > Subscriber for GroupRelayConfirmationSignatureAggregationSubscriber<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    async fn notify(&self, topic: Topic, payload: &(dyn DebuggableEvent)) -> NodeResult<()> {
+        debug!("{:?}", topic);
+
+        let ReadyToFulfillGroupRelayConfirmationTask {
+            tasks: ready_signature_caches,
+            ..
+        } = payload
+            .as_any()
+            .downcast_ref::<ReadyToFulfillGroupRelayConfirmationTask>()
+            .unwrap();
+
+        for ready_signature_cache in ready_signature_caches {
+            let GroupRelayConfirmationResultCache {
+                group_relay_confirmation_task,
+                threshold,
+                partial_signatures,
+                committed_times,
+                ..
+            } = ready_signature_cache.clone();
+
+            if committed_times >= DEFAULT_MAX_GROUP_RELAY_ATTEMPTS {
+                self.group_relay_confirmation_signature_cache
+                    .write()
+                    .await
+                    .update_commit_result(
+                        &group_relay_confirmation_task.request_id,
+                        BLSResultCacheState::FAULTY,
+                    )
+                    .await?;
+
+                error!("mark group relay confirmation task as faulty for too many failed confirmation attempts. task request id: {}",
+                format!("0x{}", hex::encode(&group_relay_confirmation_task.request_id)));
+
+                continue;
+            }
+
+            let partials = partial_signatures
+                .values()
+                .map(|partial| partial.signed_partial_signature.clone())
+                .collect::<Vec<Vec<u8>>>();
+
+            match SimpleBLSCore::<PC, S>::aggregate(threshold, &partials) {
+                Ok(signature) => {
+                    info!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::AggregatedSignatureFinished,
+                            "Group relay confirmation signature aggregated successfully.",
+                            self.chain_id,
+                            &group_relay_confirmation_task.request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelayConfirmation),
+                            json!(group_relay_confirmation_task),
+                            None
+                        )
+                    );
+
+                    let chain_identity = self.chain_identity.clone();
+
+                    let group_relay_confirmation_signature_cache =
+                        self.group_relay_confirmation_signature_cache.clone();
+
+                    self.ts.write().await.add_task(
+                        ComponentTaskType::Subscriber(
+                            self.chain_id,
+                            SubscriberType::GroupRelayConfirmationSignatureAggregation,
+                        ),
+                        async move {
+                            let handler = GeneralFulfillGroupRelayConfirmationHandler {
+                                chain_identity,
+                                group_relay_confirmation_signature_cache,
+                                pc: PhantomData,
+                            };
+
+                            if let Err(e) = handler
+                                .handle(group_relay_confirmation_task, signature)
+                                .await
+                            {
+                                error!("{:?}", e);
+                            }
+                        },
+                    )?;
+                }
+                Err(e) => {
+                    error!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::AggregatedSignatureFailed,
+                            &format!(
+                                "Group relay confirmation signature aggregation failed with error: {:?}",
+                                e
+                            ),
+                            self.chain_id,
+                            &group_relay_confirmation_task.request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelayConfirmation),
+                            json!(group_relay_confirmation_task),
+                            None
+                        )
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe(self) {
+        let eq = self.eq.clone();
+
+        let chain_id = self.chain_id;
+
+        let subscriber = Box::new(self);
+
+        eq.write().await.subscribe(
+            Topic::ReadyToFulfillGroupRelayConfirmationTask(chain_id),
+            subscriber,
+        );
+    }
+}
+
+impl<
+        PC: Curve + std::fmt::Debug + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Sync
+            + Send
+            + 'static,
+    > DebuggableSubscriber for GroupRelayConfirmationSignatureAggregationSubscriber<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+}
@@ -0,0 +1,372 @@
+use super::{DebuggableEvent, DebuggableSubscriber, Subscriber};
+use crate::{
+    algorithm::bls::{BLSCore, SimpleBLSCore},
+    context::ChainIdentityHandlerType,
+    error::{NodeError, NodeResult},
+    event::{ready_to_fulfill_group_relay_task::ReadyToFulfillGroupRelayTask, types::Topic},
+    queue::{event_queue::EventQueue, EventSubscriber},
+    scheduler::{dynamic::SimpleDynamicTaskScheduler, TaskScheduler},
+};
+use arpa_contract_client::{
+    controller_oracle::{ControllerOracleTransactions, ControllerOracleViews},
+    error::ContractClientError,
+};
+use arpa_core::{
+    log::{build_task_related_payload, build_task_related_transaction_receipt_payload, LogType},
+    BLSTaskType, ComponentTaskType, GroupRelayTask, SchedulerError, SubscriberType, TaskType,
+    DEFAULT_MAX_GROUP_RELAY_ATTEMPTS,
+};
+use arpa_dal::{cache::GroupRelayResultCache, BLSResultCacheState, SignatureResultCacheHandler};
+use async_trait::async_trait;
+use ethers::types::U256;
+use log::{debug, error, info};
+use serde_json::json;
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+use threshold_bls::{
+    group::Curve,
+    sig::{SignatureScheme, ThresholdScheme},
+};
+use tokio::sync::RwLock;
+
+#[derive(Debug)]
+pub struct GroupRelaySignatureAggregationSubscriber<
+    PC: Curve,
+    S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>,
+> {
+    chain_id: usize,
+    relayed_chain_identities: HashMap<usize, Arc<RwLock<ChainIdentityHandlerType<PC>>>>,
+    group_relay_signature_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>>,
+    eq: Arc<RwLock<EventQueue>>,
+    ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    c: PhantomData<PC>,
+    s: PhantomData<S>,
+}
+
+impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>>
+    GroupRelaySignatureAggregationSubscriber<PC, S>
+{
+    pub fn new(
+        chain_id: usize,
+        relayed_chain_identities: HashMap<usize, Arc<RwLock<ChainIdentityHandlerType<PC>>>>,
+        group_relay_signature_cache: Arc<
+            RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>,
+        >,
+        eq: Arc<RwLock<EventQueue>>,
+        ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    ) -> Self {
+        GroupRelaySignatureAggregationSubscriber {
+            chain_id,
+            relayed_chain_identities,
+            group_relay_signature_cache,
+            eq,
+            ts,
+            c: PhantomData,
+            s: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+pub trait FulfillGroupRelayHandler {
+    async fn handle(
+        &self,
+        group_index: usize,
+        group_relay_task: GroupRelayTask,
+        message: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> NodeResult<()>;
+}
+
+pub struct GeneralFulfillGroupRelayHandler<PC: Curve> {
+    relayed_chain_identities: HashMap<usize, Arc<RwLock<ChainIdentityHandlerType<PC>>>>,
+    group_relay_signature_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>>,
+    pc: PhantomData<PC>,
+}
+
+#[async_trait]
+impl<PC: Curve> FulfillGroupRelayHandler for GeneralFulfillGroupRelayHandler<PC> {
+    async fn handle(
+        &self,
+        group_index: usize,
+        group_relay_task: GroupRelayTask,
+        message: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> NodeResult<()> {
+        let request_id = group_relay_task.request_id.clone();
+
+        let chain_id = group_relay_task.relayed_chain_id;
+
+        let group_relay_task_json = json!(group_relay_task);
+
+        let chain_identity = self
+            .relayed_chain_identities
+            .get(&chain_id)
+            .ok_or(SchedulerError::InvalidChainId(chain_id))?;
+
+        let encoded_group =
+            GroupRelayTask::parse_encoded_group(&message).ok_or(NodeError::InvalidTaskMessage)?;
+
+        let client = chain_identity.read().await.build_controller_oracle_client();
+
+        // the group may have been relayed by others, or the relay is still pending confirmation
+        let is_relayed = match ControllerOracleViews::<PC>::get_group(
+            &client,
+            group_relay_task.relayed_group_index,
+        )
+        .await
+        {
+            Ok(group) if group.epoch >= group_relay_task.relayed_group_epoch => Ok(true),
+            Ok(_) => {
+                ControllerOracleViews::<PC>::is_group_relay_requested(
+                    &client,
+                    group_relay_task.relayed_group_index,
+                    group_relay_task.relayed_group_epoch,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        let state = match is_relayed {
+            Ok(true) => BLSResultCacheState::CommittedByOthers,
+            Ok(false) => match client
+                .relay_group(
+                    group_index,
+                    group_relay_task.controller_global_epoch,
+                    encoded_group,
+                    signature,
+                )
+                .await
+            {
+                Ok(receipt) => {
+                    info!(
+                        "{}",
+                        build_task_related_transaction_receipt_payload(
+                            LogType::FulfillmentFinished,
+                            "Group relayed successfully.",
+                            chain_id,
+                            &request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            group_relay_task_json,
+                            receipt.transaction_hash,
+                            receipt.gas_used.unwrap_or(U256::zero()),
+                            receipt.effective_gas_price.unwrap_or(U256::zero()),
+                        )
+                    );
+
+                    BLSResultCacheState::Committed
+                }
+                Err(ContractClientError::TransactionFailed(receipt)) => {
+                    error!(
+                        "{}",
+                        build_task_related_transaction_receipt_payload(
+                            LogType::FulfillmentFailed,
+                            "Group relay reverted.",
+                            chain_id,
+                            &request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            group_relay_task_json,
+                            receipt.transaction_hash,
+                            receipt.gas_used.unwrap_or(U256::zero()),
+                            receipt.effective_gas_price.unwrap_or(U256::zero()),
+                        )
+                    );
+
+                    BLSResultCacheState::NotCommitted
+                }
+                Err(e) => {
+                    error!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::FulfillmentFailed,
+                            &format!("Group relay failed with error: {:?}", e),
+                            chain_id,
+                            &request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            group_relay_task_json,
+                            None,
+                        )
+                    );
+
+                    BLSResultCacheState::NotCommitted
+                }
+            },
+            Err(e) => {
+                error!(
+                    "{}",
+                    build_task_related_payload(
+                        LogType::FulfillmentFailed,
+                        &format!("Group relay state query failed with error: {:?}", e),
+                        chain_id,
+                        &request_id,
+                        TaskType::BLS(BLSTaskType::GroupRelay),
+                        group_relay_task_json,
+                        None,
+                    )
+                );
+
+                BLSResultCacheState::NotCommitted
+            }
+        };
+
+        self.group_relay_signature_cache
+            .write()
+            .await
+            .update_commit_result(&request_id, state)
+            .await?;
+
+        // retried until the relay is confirmed by us or by others
+        if state == BLSResultCacheState::NotCommitted {
+            self.group_relay_signature_cache
+                .write()
+                .await
+                .incr_committed_times(&request_id)
+                .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<
+        PC: Curve + std::fmt::Debug + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Sync
+            + Send
+            + 'static,
+    > Subscriber for GroupRelaySignatureAggregationSubscriber<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    async fn notify(&self, topic: Topic, payload: &(dyn DebuggableEvent)) -> NodeResult<()> {
+        debug!("{:?}", topic);
+
+        let ReadyToFulfillGroupRelayTask {
+            tasks: ready_signature_caches,
+        } = payload
+            .as_any()
+            .downcast_ref::<ReadyToFulfillGroupRelayTask>()
+            .unwrap();
+
+        for ready_signature_cache in ready_signature_caches {
+            let GroupRelayResultCache {
+                group_index,
+                group_relay_task,
+                message,
+                threshold,
+                partial_signatures,
+                committed_times,
+            } = ready_signature_cache.clone();
+
+            if committed_times >= DEFAULT_MAX_GROUP_RELAY_ATTEMPTS {
+                self.group_relay_signature_cache
+                    .write()
+                    .await
+                    .update_commit_result(&group_relay_task.request_id, BLSResultCacheState::FAULTY)
+                    .await?;
+
+                error!("mark group relay task as faulty for too many failed relay attempts. task request id: {}",
+                format!("0x{}", hex::encode(&group_relay_task.request_id)));
+
+                continue;
+            }
+
+            let partials = partial_signatures
+                .values()
+                .map(|partial| partial.signed_partial_signature.clone())
+                .collect::<Vec<Vec<u8>>>();
+
+            match SimpleBLSCore::<PC, S>::aggregate(threshold, &partials) {
+                Ok(signature) => {
+                    info!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::AggregatedSignatureFinished,
+                            "Group relay signature aggregated successfully.",
+                            self.chain_id,
+                            &group_relay_task.request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            json!(group_relay_task),
+                            None
+                        )
+                    );
+
+                    let relayed_chain_identities = self.relayed_chain_identities.clone();
+
+                    let group_relay_signature_cache = self.group_relay_signature_cache.clone();
+
+                    self.ts.write().await.add_task(
+                        ComponentTaskType::Subscriber(
+                            self.chain_id,
+                            SubscriberType::GroupRelaySignatureAggregation,
+                        ),
+                        async move {
+                            let handler = GeneralFulfillGroupRelayHandler {
+                                relayed_chain_identities,
+                                group_relay_signature_cache,
+                                pc: PhantomData,
+                            };
+
+                            if let Err(e) = handler
+                                .handle(group_index, group_relay_task, message, signature)
+                                .await
+                            {
+                                error!("{:?}", e);
+                            }
+                        },
+                    )?;
+                }
+                Err(e) => {
+                    error!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::AggregatedSignatureFailed,
+                            &format!(
+                                "Group relay signature aggregation failed with error: {:?}",
+                                e
+                            ),
+                            self.chain_id,
+                            &group_relay_task.request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            json!(group_relay_task),
+                            None
+                        )
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe(self) {
+        let eq = self.eq.clone();
+
+        let subscriber = Box::new(self);
+
+        eq.write()
+            .await
+            .subscribe(Topic::ReadyToFulfillGroupRelayTask, subscriber);
+    }
+}
+
+impl<
+        PC: Curve + std::fmt::Debug + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Sync
+            + Send
+            + 'static,
+    > DebuggableSubscriber for GroupRelaySignatureAggregationSubscriber<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+}
@@ -1,21 +1,29 @@
 use super::{DebuggableEvent, DebuggableSubscriber, Subscriber};
 use crate::{
-    algorithm::dkg::{AllPhasesDKGCore, DKGCore},
+    algorithm::dkg::{AllPhasesDKGCore, DKGCheckpoint, DKGCore, PreviousGroupShare, ReshareRole},
+    board::{DKGBoardStoreHandle, OffChainBoard},
+    committer::CommitterCredentials,
     context::ChainIdentityHandlerType,
     error::NodeResult,
     event::{run_dkg::RunDKG, types::Topic},
     queue::{event_queue::EventQueue, EventSubscriber},
     scheduler::{dynamic::SimpleDynamicTaskScheduler, DynamicTaskScheduler},
 };
-use arpa_contract_client::{controller::ControllerTransactions, error::ContractClientError};
+use arpa_contract_client::{
+    controller::{ControllerTransactions, ControllerViews},
+    coordinator::{CoordinatorTransactions, CoordinatorViews},
+    error::ContractClientError,
+};
 use arpa_core::{
     log::{build_group_related_payload, build_group_related_transaction_receipt_payload, LogType},
-    DKGStatus, DKGTask,
+    metrics::DKG_OUTCOMES,
+    ComponentTaskType, DKGStatus, DKGTask, SubscriberType,
 };
 use arpa_dal::{GroupInfoHandler, NodeInfoHandler};
 use async_trait::async_trait;
 use core::fmt::Debug;
-use ethers::types::U256;
+use dkg_core::{primitives::DKGOutput, BoardPublisher};
+use ethers::types::{Address, U256};
 use log::{debug, error, info};
 use rand::{prelude::ThreadRng, RngCore};
 use std::{marker::PhantomData, sync::Arc};
@@ -31,9 +39,14 @@ pub struct InGroupingSubscriber<PC: Curve> {
     ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
     c: PhantomData<PC>,
     dkg_wait_for_phase_interval_millis: u64,
+    is_dkg_resharing_enabled: bool,
+    is_dkg_off_chain_board_enabled: bool,
+    dkg_board_store: DKGBoardStoreHandle,
+    committer_credentials: CommitterCredentials,
 }
 
 impl<PC: Curve> InGroupingSubscriber<PC> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
         node_cache: Arc<RwLock<Box<dyn NodeInfoHandler<PC>>>>,
@@ -41,6 +54,10 @@ impl<PC: Curve> InGroupingSubscriber<PC> {
         eq: Arc<RwLock<EventQueue>>,
         ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
         dkg_wait_for_phase_interval_millis: u64,
+        is_dkg_resharing_enabled: bool,
+        is_dkg_off_chain_board_enabled: bool,
+        dkg_board_store: DKGBoardStoreHandle,
+        committer_credentials: CommitterCredentials,
     ) -> Self {
         InGroupingSubscriber {
             chain_identity,
@@ -50,6 +67,10 @@ impl<PC: Curve> InGroupingSubscriber<PC> {
             ts,
             c: PhantomData,
             dkg_wait_for_phase_interval_millis,
+            is_dkg_resharing_enabled,
+            is_dkg_off_chain_board_enabled,
+            dkg_board_store,
+            committer_credentials,
         }
     }
 }
@@ -61,15 +82,24 @@ pub struct AllInOneDKGHandler<F: Fn() -> R, R: RngCore, PC: Curve> {
     group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
     c: PhantomData<PC>,
     dkg_wait_for_phase_interval_millis: u64,
+    is_dkg_resharing_enabled: bool,
+    is_dkg_off_chain_board_enabled: bool,
+    dkg_board_store: DKGBoardStoreHandle,
+    committer_credentials: CommitterCredentials,
 }
 
 impl<F: Fn() -> R, R: RngCore, PC: Curve> AllInOneDKGHandler<F, R, PC> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         rng: F,
         chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
         node_cache: Arc<RwLock<Box<dyn NodeInfoHandler<PC>>>>,
         group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
         dkg_wait_for_phase_interval_millis: u64,
+        is_dkg_resharing_enabled: bool,
+        is_dkg_off_chain_board_enabled: bool,
+        dkg_board_store: DKGBoardStoreHandle,
+        committer_credentials: CommitterCredentials,
     ) -> Self {
         AllInOneDKGHandler {
             rng,
@@ -78,13 +108,22 @@ impl<F: Fn() -> R, R: RngCore, PC: Curve> AllInOneDKGHandler<F, R, PC> {
             group_cache,
             c: PhantomData,
             dkg_wait_for_phase_interval_millis,
+            is_dkg_resharing_enabled,
+            is_dkg_off_chain_board_enabled,
+            dkg_board_store,
+            committer_credentials,
         }
     }
 }
 
 #[async_trait]
-pub trait DKGHandler<F, R> {
-    async fn handle(&mut self, task: DKGTask) -> NodeResult<()>
+pub trait DKGHandler<F, R, PC: Curve> {
+    async fn handle(
+        &mut self,
+        task: DKGTask,
+        previous_group_share: Option<PreviousGroupShare<PC>>,
+        checkpoint: Option<DKGCheckpoint<PC>>,
+    ) -> NodeResult<()>
     where
         R: RngCore,
         F: Fn() -> R + 'static;
@@ -95,9 +134,14 @@ impl<
         F: Fn() -> R + Debug + Send + Sync + Copy + 'static,
         R: RngCore + 'static,
         PC: Curve + Sync + Send + 'static,
-    > DKGHandler<F, R> for AllInOneDKGHandler<F, R, PC>
+    > DKGHandler<F, R, PC> for AllInOneDKGHandler<F, R, PC>
 {
-    async fn handle(&mut self, task: DKGTask) -> NodeResult<()>
+    async fn handle(
+        &mut self,
+        task: DKGTask,
+        previous_group_share: Option<PreviousGroupShare<PC>>,
+        checkpoint: Option<DKGCheckpoint<PC>>,
+    ) -> NodeResult<()>
     where
         R: RngCore,
         F: Fn() -> R + Send + Debug + 'async_trait,
@@ -125,13 +169,70 @@ impl<
             .await
             .build_coordinator_client(task.coordinator_address);
 
-        let mut dkg_core =
-            AllPhasesDKGCore::new(coordinator_client, self.dkg_wait_for_phase_interval_millis);
+        // a group that already has a public key keeps it by resharing instead of running a fresh DKG
+        let public_key = if self.is_dkg_resharing_enabled {
+            ControllerViews::<PC>::get_group(&controller_client, task_group_index)
+                .await?
+                .public_key
+        } else {
+            None
+        };
 
-        match dkg_core
-            .run_dkg(dkg_private_key, node_rpc_endpoint, self.rng)
-            .await
+        let reshare_role = match (public_key, previous_group_share) {
+            (None, _) => None,
+            (Some(public_key), Some(previous))
+                if previous.group.public_key.as_ref() == Some(&public_key) =>
+            {
+                Some(ReshareRole::Dealer(previous))
+            }
+            // the group as it was before the task, with the previous threshold
+            (Some(_), _) => Some(ReshareRole::NewMember(
+                ControllerViews::<PC>::get_group_at(
+                    &controller_client,
+                    task_group_index,
+                    task.assignment_block_height.saturating_sub(1) as u64,
+                )
+                .await?,
+            )),
+        };
+
+        // every member reads the bundles committed to the coordinator from the off-chain boards,
+        // whether or not it publishes its own bundles there
+        let dkg_result = match OffChainBoard::new(
+            coordinator_client,
+            chain_id,
+            task.coordinator_address,
+            self.committer_credentials.clone(),
+            node_rpc_endpoint.clone(),
+            self.dkg_board_store.clone(),
+            self.is_dkg_off_chain_board_enabled,
+        )
+        .await
         {
+            Ok(board) => {
+                let mut dkg_core = AllPhasesDKGCore::new(
+                    board,
+                    self.dkg_wait_for_phase_interval_millis,
+                    self.group_cache.clone(),
+                    task_group_index,
+                    task_epoch,
+                );
+
+                run_dkg_core(
+                    &mut dkg_core,
+                    dkg_private_key,
+                    node_rpc_endpoint,
+                    checkpoint,
+                    reshare_role,
+                    task_group_index,
+                    self.rng,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        match dkg_result {
             Ok(output) => match output.disqualified_node_indices.len() {
                 0 => {
                     let (public_key, partial_public_key, disqualified_nodes) = self
@@ -141,6 +242,8 @@ impl<
                         .save_successful_output(task_group_index, task_epoch, output)
                         .await?;
 
+                    DKG_OUTCOMES.with_label_values(&["finished"]).inc();
+
                     info!(
                         "{}",
                         build_group_related_payload(
@@ -225,6 +328,8 @@ impl<
 
                     info!("Disqualified node addresses: {:?}", disqualified_nodes);
 
+                    DKG_OUTCOMES.with_label_values(&["aborted"]).inc();
+
                     info!(
                         "{}",
                         build_group_related_payload(
@@ -295,6 +400,8 @@ impl<
                 }
             },
             Err(e) => {
+                DKG_OUTCOMES.with_label_values(&["failed"]).inc();
+
                 error!(
                     "{}",
                     build_group_related_payload(
@@ -311,18 +418,59 @@ impl<
     }
 }
 
-#[async_trait]
-impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber for InGroupingSubscriber<PC> {
-    async fn notify(&self, topic: Topic, payload: &(dyn DebuggableEvent)) -> NodeResult<()> {
-        debug!("{:?}", topic);
-
-        let RunDKG { dkg_task: task, .. } =
-            payload.as_any().downcast_ref::<RunDKG>().unwrap().clone();
+/// Runs the DKG, or continues it from the checkpoint, on the given board.
+async fn run_dkg_core<F, R, P, PC>(
+    dkg_core: &mut AllPhasesDKGCore<P, PC>,
+    dkg_private_key: PC::Scalar,
+    node_rpc_endpoint: String,
+    checkpoint: Option<DKGCheckpoint<PC>>,
+    reshare_role: Option<ReshareRole<PC>>,
+    task_group_index: usize,
+    rng: F,
+) -> NodeResult<DKGOutput<PC>>
+where
+    R: RngCore,
+    F: Fn() -> R + Send + Debug + 'static,
+    P: CoordinatorTransactions + CoordinatorViews + BoardPublisher<PC> + Sync + Send,
+    PC: Curve,
+{
+    match (checkpoint, reshare_role) {
+        // a DKG interrupted by a restart continues from the last persisted phase
+        (Some(checkpoint), _) => DKGCore::<F, R, PC>::resume_dkg(dkg_core, checkpoint).await,
+        (None, Some(role)) => {
+            info!(
+                "Resharing the existing key of group {} as a {}.",
+                task_group_index,
+                match role {
+                    ReshareRole::Dealer(_) => "dealer",
+                    ReshareRole::NewMember(_) => "new member",
+                }
+            );
+            dkg_core
+                .run_resharing(dkg_private_key, node_rpc_endpoint, role, rng)
+                .await
+        }
+        (None, None) => {
+            dkg_core
+                .run_dkg(dkg_private_key, node_rpc_endpoint, rng)
+                .await
+        }
+    }
+}
 
+impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> InGroupingSubscriber<PC> {
+    async fn spawn_dkg_handler(
+        &self,
+        task: DKGTask,
+        previous_group_share: Option<PreviousGroupShare<PC>>,
+        checkpoint: Option<DKGCheckpoint<PC>>,
+    ) {
         static RNG_FN: fn() -> ThreadRng = rand::thread_rng;
 
         let chain_identity = self.chain_identity.clone();
 
+        let chain_id = self.chain_identity.read().await.get_chain_id();
+
         let group_cache_for_handler = self.group_cache.clone();
 
         let group_cache_for_handler_shutdown_signal = self.group_cache.clone();
@@ -337,11 +485,16 @@ impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber for InGroup
             self.node_cache.clone(),
             self.group_cache.clone(),
             self.dkg_wait_for_phase_interval_millis,
+            self.is_dkg_resharing_enabled,
+            self.is_dkg_off_chain_board_enabled,
+            self.dkg_board_store.clone(),
+            self.committer_credentials.clone(),
         );
 
         self.ts.write().await.add_task_with_shutdown_signal(
+            ComponentTaskType::Subscriber(chain_id, SubscriberType::InGrouping),
             async move {
-                if let Err(e) = handler.handle(task).await {
+                if let Err(e) = handler.handle(task, previous_group_share, checkpoint).await {
                     error!("{:?}", e);
                 } else if let Err(e) = group_cache_for_handler
                     .write()
@@ -366,11 +519,104 @@ impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber for InGroup
             },
             2000,
         );
+    }
+
+    /// Picks up the DKG this node was running before it restarted, if its last
+    /// checkpoint is still in the group cache.
+    async fn resume_in_progress_dkg(&self) -> NodeResult<()> {
+        let (task, checkpoint) = match load_in_progress_dkg(&**self.group_cache.read().await)? {
+            Some(in_progress_dkg) => in_progress_dkg,
+            None => return Ok(()),
+        };
+
+        let controller_client = self.chain_identity.read().await.build_controller_client();
+
+        let coordinator_address =
+            ControllerViews::<PC>::get_coordinator(&controller_client, task.group_index).await?;
+
+        if coordinator_address == Address::zero() {
+            info!(
+                "The coordinator of group {} epoch {} is gone, dropping the DKG checkpoint.",
+                task.group_index, task.epoch
+            );
+            return Ok(());
+        }
+
+        info!(
+            "Resuming the DKG of group {} epoch {} from the checkpoint.",
+            task.group_index, task.epoch
+        );
+
+        self.spawn_dkg_handler(
+            DKGTask {
+                coordinator_address,
+                ..task
+            },
+            None,
+            Some(checkpoint),
+        )
+        .await;
+
+        Ok(())
+    }
+}
+
+/// Reads the task and the last checkpoint of the DKG the group cache says is still in phase.
+/// The coordinator address is not cached and is left zero.
+pub(crate) fn load_in_progress_dkg<C: Curve>(
+    group_cache: &dyn GroupInfoHandler<C>,
+) -> NodeResult<Option<(DKGTask, DKGCheckpoint<C>)>> {
+    if !matches!(group_cache.get_dkg_status(), Ok(DKGStatus::InPhase)) {
+        return Ok(None);
+    }
+
+    let checkpoint = match group_cache.get_dkg_checkpoint()? {
+        Some(checkpoint) => bincode::deserialize::<DKGCheckpoint<C>>(checkpoint)?,
+        None => return Ok(None),
+    };
+
+    let mut members = group_cache.get_members()?.values().collect::<Vec<_>>();
+    members.sort_by_key(|member| member.index);
+
+    let task = DKGTask {
+        group_index: group_cache.get_index()?,
+        epoch: group_cache.get_epoch()?,
+        size: group_cache.get_size()?,
+        threshold: group_cache.get_threshold()?,
+        members: members.iter().map(|member| member.id_address).collect(),
+        assignment_block_height: group_cache.get_dkg_start_block_height()?,
+        coordinator_address: Address::zero(),
+    };
+
+    Ok(Some((task, checkpoint)))
+}
+
+#[async_trait]
+impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber for InGroupingSubscriber<PC> {
+    async fn notify(&self, topic: Topic, payload: &(dyn DebuggableEvent)) -> NodeResult<()> {
+        debug!("{:?}", topic);
+
+        let RunDKG {
+            dkg_task: task,
+            previous_group_share,
+            ..
+        } = payload
+            .as_any()
+            .downcast_ref::<RunDKG<PC>>()
+            .unwrap()
+            .clone();
+
+        self.spawn_dkg_handler(task, previous_group_share, None)
+            .await;
 
         Ok(())
     }
 
     async fn subscribe(self) {
+        if let Err(e) = self.resume_in_progress_dkg().await {
+            error!("Failed to resume the in-progress DKG: {:?}", e);
+        }
+
         let eq = self.eq.clone();
 
         let subscriber = Box::new(self);
@@ -383,3 +629,434 @@ impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> DebuggableSubscriber
     for InGroupingSubscriber<PC>
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::load_in_progress_dkg;
+    use crate::algorithm::dkg::{AllPhasesDKGCore, DKGCore, PreviousGroupShare, ReshareRole};
+    use arpa_contract_client::{
+        coordinator::{CoordinatorTransactions, CoordinatorViews},
+        error::{ContractClientError, ContractClientResult},
+    };
+    use arpa_core::{DKGStatus, DKGTask, Group as ArpaGroup, Member};
+    use arpa_dal::{cache::InMemoryGroupInfoCache, GroupInfoHandler, GroupInfoUpdater};
+    use async_trait::async_trait;
+    use dkg_core::{
+        generate_keypair,
+        primitives::{BundledJustification, BundledResponses, BundledShares, DKGOutput},
+        BoardPublisher,
+    };
+    use ethers::types::{Address, TransactionReceipt};
+    use rand::prelude::ThreadRng;
+    use std::{
+        marker::PhantomData,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+    use threshold_bls::{
+        curve::bn254::{G2Curve, Scalar, G2},
+        poly::{Idx, Poly},
+        schemes::bn254::G2Scheme,
+        sig::Share,
+    };
+    use tokio::{sync::RwLock, task::JoinHandle};
+
+    static RNG_FN: fn() -> ThreadRng = rand::thread_rng;
+
+    const WAIT_FOR_PHASE_INTERVAL_MILLIS: u64 = 20;
+
+    const GROUP_SIZE: usize = 3;
+
+    const THRESHOLD: usize = 2;
+
+    /// The state of a coordinator whose phase is driven by the test.
+    #[derive(Default)]
+    struct CoordinatorState {
+        phase: i8,
+        participants: Vec<Address>,
+        dkg_keys: Vec<Vec<u8>>,
+        shares: Vec<Vec<u8>>,
+        responses: Vec<Vec<u8>>,
+        justifications: Vec<Vec<u8>>,
+        /// The phases every participant has published in, once per publication.
+        publications: Vec<Vec<i8>>,
+    }
+
+    /// The view of the coordinator of one participant, whose transactions it signs.
+    #[derive(Clone)]
+    struct MockCoordinator {
+        sender_index: usize,
+        state: Arc<Mutex<CoordinatorState>>,
+    }
+
+    #[async_trait]
+    impl CoordinatorTransactions for MockCoordinator {
+        async fn publish(&self, value: Vec<u8>) -> ContractClientResult<TransactionReceipt> {
+            let mut state = self.state.lock().unwrap();
+            let phase = state.phase;
+            match phase {
+                1 => state.shares[self.sender_index] = value,
+                2 => state.responses[self.sender_index] = value,
+                3 => state.justifications[self.sender_index] = value,
+                phase => panic!("publishing in phase {}", phase),
+            }
+            state.publications[self.sender_index].push(phase);
+            Ok(TransactionReceipt::default())
+        }
+    }
+
+    #[async_trait]
+    impl CoordinatorViews for MockCoordinator {
+        async fn get_shares(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+            Ok(self.state.lock().unwrap().shares.clone())
+        }
+
+        async fn get_responses(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+            Ok(self.state.lock().unwrap().responses.clone())
+        }
+
+        async fn get_justifications(&self) -> ContractClientResult<Vec<Vec<u8>>> {
+            Ok(self.state.lock().unwrap().justifications.clone())
+        }
+
+        async fn get_participants(&self) -> ContractClientResult<Vec<Address>> {
+            Ok(self.state.lock().unwrap().participants.clone())
+        }
+
+        async fn get_dkg_keys(&self) -> ContractClientResult<(usize, Vec<Vec<u8>>)> {
+            Ok((THRESHOLD, self.state.lock().unwrap().dkg_keys.clone()))
+        }
+
+        async fn in_phase(&self) -> ContractClientResult<i8> {
+            Ok(self.state.lock().unwrap().phase)
+        }
+    }
+
+    #[async_trait]
+    impl BoardPublisher<G2Curve> for MockCoordinator {
+        type Error = ContractClientError;
+
+        async fn publish_shares(
+            &mut self,
+            shares: BundledShares<G2Curve>,
+        ) -> Result<(), Self::Error> {
+            self.publish(bincode::serialize(&shares).unwrap()).await?;
+            Ok(())
+        }
+
+        async fn publish_responses(
+            &mut self,
+            responses: BundledResponses,
+        ) -> Result<(), Self::Error> {
+            self.publish(bincode::serialize(&responses).unwrap())
+                .await?;
+            Ok(())
+        }
+
+        async fn publish_justifications(
+            &mut self,
+            justifications: BundledJustification<G2Curve>,
+        ) -> Result<(), Self::Error> {
+            self.publish(bincode::serialize(&justifications).unwrap())
+                .await?;
+            Ok(())
+        }
+    }
+
+    type GroupCache = Arc<RwLock<Box<dyn GroupInfoHandler<G2Curve>>>>;
+
+    /// The group cache of a member which has started the DKG of group 0 epoch 1.
+    async fn build_group_cache(participants: &[Address], self_index: usize) -> GroupCache {
+        let mut group_cache = InMemoryGroupInfoCache::<G2Curve>::new(participants[self_index]);
+        group_cache
+            .save_task_info(
+                self_index,
+                DKGTask {
+                    group_index: 0,
+                    epoch: 1,
+                    size: participants.len(),
+                    threshold: THRESHOLD,
+                    members: participants.to_vec(),
+                    assignment_block_height: 100,
+                    coordinator_address: Address::zero(),
+                },
+            )
+            .await
+            .unwrap();
+        group_cache
+            .update_dkg_status(0, 1, DKGStatus::InPhase)
+            .await
+            .unwrap();
+
+        Arc::new(RwLock::new(Box::new(group_cache)))
+    }
+
+    fn build_dkg_core(
+        coordinator: &Arc<Mutex<CoordinatorState>>,
+        sender_index: usize,
+        group_cache: &GroupCache,
+    ) -> AllPhasesDKGCore<MockCoordinator, G2Curve> {
+        AllPhasesDKGCore::new(
+            MockCoordinator {
+                sender_index,
+                state: coordinator.clone(),
+            },
+            WAIT_FOR_PHASE_INTERVAL_MILLIS,
+            group_cache.clone(),
+            0,
+            1,
+        )
+    }
+
+    async fn wait_until(
+        coordinator: &Arc<Mutex<CoordinatorState>>,
+        condition: impl Fn(&CoordinatorState) -> bool,
+    ) {
+        tokio::time::timeout(Duration::from_secs(30), async {
+            while !condition(&coordinator.lock().unwrap()) {
+                tokio::time::sleep(Duration::from_millis(WAIT_FOR_PHASE_INTERVAL_MILLIS)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    /// Waits until the member has saved the checkpoint of the given name.
+    async fn wait_for_checkpoint(group_cache: &GroupCache, name: &str) {
+        tokio::time::timeout(Duration::from_secs(30), async {
+            loop {
+                if let Ok(Some((_, checkpoint))) =
+                    load_in_progress_dkg::<G2Curve>(&**group_cache.read().await)
+                {
+                    if checkpoint.name() == name {
+                        break;
+                    }
+                }
+                tokio::time::sleep(Duration::from_millis(WAIT_FOR_PHASE_INTERVAL_MILLIS)).await;
+            }
+        })
+        .await
+        .unwrap();
+    }
+
+    fn build_coordinator(
+        participants: &[Address],
+        dkg_keys: Vec<Vec<u8>>,
+    ) -> Arc<Mutex<CoordinatorState>> {
+        Arc::new(Mutex::new(CoordinatorState {
+            phase: 0,
+            participants: participants.to_vec(),
+            dkg_keys,
+            shares: vec![vec![]; participants.len()],
+            responses: vec![vec![]; participants.len()],
+            justifications: vec![vec![]; participants.len()],
+            publications: vec![vec![]; participants.len()],
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_resume_dkg_from_waiting_response_checkpoint() {
+        let keypairs = (0..GROUP_SIZE)
+            .map(|_| generate_keypair::<G2Scheme>())
+            .collect::<Vec<_>>();
+        let participants = (0..GROUP_SIZE)
+            .map(|i| Address::from_low_u64_be(i as u64 + 1))
+            .collect::<Vec<_>>();
+
+        let coordinator = build_coordinator(
+            &participants,
+            keypairs
+                .iter()
+                .map(|(_, public)| bincode::serialize(public).unwrap())
+                .collect(),
+        );
+
+        let mut group_caches = vec![];
+        let mut handles: Vec<JoinHandle<DKGOutput<G2Curve>>> = vec![];
+        for (i, (dkg_private_key, _)) in keypairs.iter().enumerate() {
+            let group_cache = build_group_cache(&participants, i).await;
+            let mut dkg_core = build_dkg_core(&coordinator, i, &group_cache);
+            let dkg_private_key = *dkg_private_key;
+
+            handles.push(tokio::spawn(async move {
+                DKGCore::<_, ThreadRng, G2Curve>::run_dkg(
+                    &mut dkg_core,
+                    dkg_private_key,
+                    String::new(),
+                    RNG_FN,
+                )
+                .await
+                .unwrap()
+            }));
+            group_caches.push(group_cache);
+        }
+
+        coordinator.lock().unwrap().phase = 1;
+        wait_until(&coordinator, |state| {
+            state.shares.iter().all(|value| !value.is_empty())
+        })
+        .await;
+
+        coordinator.lock().unwrap().phase = 2;
+        wait_for_checkpoint(&group_caches[0], "WaitingResponse").await;
+
+        // the first member stops right after its responses are checkpointed
+        let stopped = handles.remove(0);
+        stopped.abort();
+        assert!(stopped.await.unwrap_err().is_cancelled());
+
+        // and picks up the DKG from the group cache once it is back
+        let (task, checkpoint) = load_in_progress_dkg(&**group_caches[0].read().await)
+            .unwrap()
+            .unwrap();
+        assert_eq!(participants, task.members);
+        assert_eq!("WaitingResponse", checkpoint.name());
+
+        let mut dkg_core = build_dkg_core(&coordinator, 0, &group_caches[0]);
+        let resumed = tokio::spawn(async move {
+            DKGCore::<fn() -> ThreadRng, ThreadRng, G2Curve>::resume_dkg(&mut dkg_core, checkpoint)
+                .await
+                .unwrap()
+        });
+
+        coordinator.lock().unwrap().phase = 3;
+
+        let resumed_output = resumed.await.unwrap();
+        for handle in handles {
+            let output = handle.await.unwrap();
+            assert_eq!(resumed_output.public, output.public);
+            assert_eq!(GROUP_SIZE, output.qual.nodes.len());
+        }
+        assert!(resumed_output.disqualified_node_indices.is_empty());
+
+        // the shares are dealt once, the restart doesn't deal new ones
+        assert_eq!(
+            1,
+            coordinator.lock().unwrap().publications[0]
+                .iter()
+                .filter(|phase| **phase == 1)
+                .count()
+        );
+    }
+    #[tokio::test]
+    async fn test_resume_resharing_from_waiting_response_checkpoint() {
+        let keypairs = (0..GROUP_SIZE)
+            .map(|_| generate_keypair::<G2Scheme>())
+            .collect::<Vec<_>>();
+        let participants = (0..GROUP_SIZE)
+            .map(|i| Address::from_low_u64_be(i as u64 + 1))
+            .collect::<Vec<_>>();
+
+        // the group key every member holds a share of before the resharing
+        let private = Poly::<Scalar>::new_from(THRESHOLD - 1, &mut rand::thread_rng());
+        let public = private.commit::<G2>();
+        let previous_group = ArpaGroup {
+            index: 0,
+            epoch: 0,
+            size: GROUP_SIZE,
+            threshold: THRESHOLD,
+            state: true,
+            public_key: Some(*public.public_key()),
+            members: participants
+                .iter()
+                .enumerate()
+                .map(|(index, id_address)| {
+                    let member = Member {
+                        index,
+                        dkg_index: Some(index),
+                        id_address: *id_address,
+                        rpc_endpoint: None,
+                        partial_public_key: Some(public.eval(index as Idx).value),
+                    };
+                    (*id_address, member)
+                })
+                .collect(),
+            committers: vec![],
+            c: PhantomData,
+        };
+
+        let coordinator = build_coordinator(
+            &participants,
+            keypairs
+                .iter()
+                .map(|(_, public)| bincode::serialize(public).unwrap())
+                .collect(),
+        );
+
+        let mut group_caches = vec![];
+        let mut handles: Vec<JoinHandle<DKGOutput<G2Curve>>> = vec![];
+        for (i, (dkg_private_key, _)) in keypairs.iter().enumerate() {
+            let group_cache = build_group_cache(&participants, i).await;
+            let mut dkg_core = build_dkg_core(&coordinator, i, &group_cache);
+            let dkg_private_key = *dkg_private_key;
+            let role = ReshareRole::Dealer(PreviousGroupShare {
+                share: Share {
+                    index: i as Idx,
+                    private: private.eval(i as Idx).value,
+                },
+                group: previous_group.clone(),
+            });
+
+            handles.push(tokio::spawn(async move {
+                DKGCore::<_, ThreadRng, G2Curve>::run_resharing(
+                    &mut dkg_core,
+                    dkg_private_key,
+                    String::new(),
+                    role,
+                    RNG_FN,
+                )
+                .await
+                .unwrap()
+            }));
+            group_caches.push(group_cache);
+        }
+
+        coordinator.lock().unwrap().phase = 1;
+        wait_until(&coordinator, |state| {
+            state.shares.iter().all(|value| !value.is_empty())
+        })
+        .await;
+        // the dealers checkpoint their dealt shares
+        wait_for_checkpoint(&group_caches[0], "ResharingWaitingShare").await;
+
+        coordinator.lock().unwrap().phase = 2;
+        wait_for_checkpoint(&group_caches[0], "ResharingWaitingResponse").await;
+
+        // the first member stops right after its responses are checkpointed
+        let stopped = handles.remove(0);
+        stopped.abort();
+        assert!(stopped.await.unwrap_err().is_cancelled());
+
+        // and picks up the resharing from the group cache once it is back
+        let (_, checkpoint) = load_in_progress_dkg(&**group_caches[0].read().await)
+            .unwrap()
+            .unwrap();
+
+        let mut dkg_core = build_dkg_core(&coordinator, 0, &group_caches[0]);
+        let resumed = tokio::spawn(async move {
+            DKGCore::<fn() -> ThreadRng, ThreadRng, G2Curve>::resume_dkg(&mut dkg_core, checkpoint)
+                .await
+                .unwrap()
+        });
+
+        coordinator.lock().unwrap().phase = 3;
+
+        let resumed_output = resumed.await.unwrap();
+        // the refreshed shares keep the group key
+        assert_eq!(public.public_key(), resumed_output.public.public_key());
+        for handle in handles {
+            let output = handle.await.unwrap();
+            assert_eq!(resumed_output.public, output.public);
+        }
+        assert!(resumed_output.disqualified_node_indices.is_empty());
+
+        // the share is dealt once, the restart doesn't deal it again
+        assert_eq!(
+            1,
+            coordinator.lock().unwrap().publications[0]
+                .iter()
+                .filter(|phase| **phase == 1)
+                .count()
+        );
+    }
+}
@@ -1,9 +1,13 @@
 pub mod block;
+pub mod group_relay_confirmation_signature_aggregation;
+pub mod group_relay_signature_aggregation;
 pub mod in_grouping;
 pub mod post_grouping;
 pub mod post_success_grouping;
 pub mod pre_grouping;
 pub mod randomness_signature_aggregation;
+pub mod ready_to_handle_group_relay_confirmation_task;
+pub mod ready_to_handle_group_relay_task;
 pub mod ready_to_handle_randomness_task;
 pub mod schedule_node_activation;
 
@@ -30,6 +30,7 @@ pub struct PostGroupingSubscriber<PC: Curve> {
     group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
     eq: Arc<RwLock<EventQueue>>,
     ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    is_group_relay_by_signature_enabled: bool,
     c: PhantomData<PC>,
 }
 
@@ -40,6 +41,7 @@ impl<PC: Curve> PostGroupingSubscriber<PC> {
         group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
         eq: Arc<RwLock<EventQueue>>,
         ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+        is_group_relay_by_signature_enabled: bool,
     ) -> Self {
         PostGroupingSubscriber {
             chain_identity,
@@ -47,6 +49,7 @@ impl<PC: Curve> PostGroupingSubscriber<PC> {
             group_cache,
             eq,
             ts,
+            is_group_relay_by_signature_enabled,
             c: PhantomData,
         }
     }
@@ -61,6 +64,7 @@ pub struct GeneralDKGPostProcessHandler<PC: Curve> {
     chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
     supported_relayed_chains: Vec<usize>,
     group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+    is_group_relay_by_signature_enabled: bool,
     c: PhantomData<PC>,
 }
 
@@ -112,7 +116,29 @@ impl<PC: Curve + Sync + Send + 'static> DKGPostProcessHandler for GeneralDKGPost
                     );
                 }
 
-                if self.group_cache.read().await.get_group()?.state {
+                if self.group_cache.read().await.get_group()?.state
+                    && self.is_group_relay_by_signature_enabled
+                {
+                    // the nodes relay the group to all the relayed chains by threshold signatures
+                    if let Ok(receipt) = controller_relayer_client
+                        .request_group_relay(group_index)
+                        .await
+                    {
+                        info!(
+                            "{}",
+                            build_group_related_transaction_receipt_payload(
+                                LogType::DKGPostProcessGroupRelayFinished,
+                                "DKG post process group relay requested.",
+                                chain_id,
+                                self.group_cache.read().await.get_group()?,
+                                None,
+                                receipt.transaction_hash,
+                                receipt.gas_used.unwrap_or(U256::zero()),
+                                receipt.effective_gas_price.unwrap_or(U256::zero())
+                            )
+                        );
+                    }
+                } else if self.group_cache.read().await.get_group()?.state {
                     for relayed_chain_id in self.supported_relayed_chains.iter() {
                         if let Ok(receipt) = controller_relayer_client
                             .relay_group(*relayed_chain_id, group_index)
@@ -157,12 +183,14 @@ impl<PC: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber
         let chain_identity = self.chain_identity.clone();
         let supported_relayed_chains = self.supported_relayed_chains.clone();
         let group_cache = self.group_cache.clone();
+        let is_group_relay_by_signature_enabled = self.is_group_relay_by_signature_enabled;
 
         self.ts.write().await.add_task(ComponentTaskType::Subscriber(self.chain_identity.read().await.get_chain_id(), SubscriberType::PostGrouping),async move {
                 let handler = GeneralDKGPostProcessHandler {
                     chain_identity,
                     supported_relayed_chains,
                     group_cache,
+                    is_group_relay_by_signature_enabled,
                     c: PhantomData,
                 };
 
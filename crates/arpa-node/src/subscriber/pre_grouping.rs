@@ -1,5 +1,6 @@
 use super::{DebuggableEvent, DebuggableSubscriber, Subscriber};
 use crate::{
+    algorithm::dkg::PreviousGroupShare,
     error::NodeResult,
     event::{new_dkg_task::NewDKGTask, run_dkg::RunDKG, types::Topic},
     queue::{event_queue::EventQueue, EventPublisher, EventSubscriber},
@@ -35,9 +36,29 @@ impl<PC: Curve> PreGroupingSubscriber<PC> {
     }
 }
 
+impl<C: Curve> PreGroupingSubscriber<C> {
+    async fn get_previous_group_share(
+        &self,
+        task_group_index: usize,
+    ) -> Option<PreviousGroupShare<C>> {
+        let group_cache = self.group_cache.read().await;
+
+        if group_cache.get_index().ok()? != task_group_index || !group_cache.get_state().ok()? {
+            return None;
+        }
+
+        Some(PreviousGroupShare {
+            share: group_cache.get_secret_share().ok()?.clone(),
+            group: group_cache.get_group().ok()?.clone(),
+        })
+    }
+}
+
 #[async_trait]
-impl<C: Curve + std::fmt::Debug + Sync + Send> EventPublisher<RunDKG> for PreGroupingSubscriber<C> {
-    async fn publish(&self, event: RunDKG) {
+impl<C: Curve + std::fmt::Debug + Sync + Send + 'static> EventPublisher<RunDKG<C>>
+    for PreGroupingSubscriber<C>
+{
+    async fn publish(&self, event: RunDKG<C>) {
         self.eq.read().await.publish(event).await;
     }
 }
@@ -66,6 +87,10 @@ impl<C: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber for PreGroup
         let task_epoch = dkg_task.epoch;
 
         if cache_index != task_group_index || cache_epoch != task_epoch {
+            // keep the ready state of the group before it gets overwritten by the new task,
+            // so that the share can be dealt if the group is reshared
+            let previous_group_share = self.get_previous_group_share(task_group_index).await;
+
             self.group_cache
                 .write()
                 .await
@@ -80,7 +105,8 @@ impl<C: Curve + std::fmt::Debug + Sync + Send + 'static> Subscriber for PreGroup
                 .await?;
 
             if res {
-                self.publish(RunDKG { dkg_task }).await;
+                self.publish(RunDKG::new(dkg_task, self_index, previous_group_share))
+                    .await;
 
                 info!(
                     "{}",
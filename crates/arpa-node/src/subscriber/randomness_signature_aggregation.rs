@@ -12,22 +12,34 @@ use arpa_contract_client::{
     error::ContractClientError,
 };
 use arpa_core::{
-    log::{build_task_related_payload, build_task_related_transaction_receipt_payload, LogType},
+    log::{
+        build_task_related_payload, build_task_related_transaction_receipt_payload,
+        trace::set_task_parent, LogType,
+    },
+    metrics::RANDOMNESS_TASKS,
     BLSTaskType, ComponentTaskType, PartialSignature, RandomnessTask, SubscriberType, TaskType,
     DEFAULT_MAX_RANDOMNESS_FULFILLMENT_ATTEMPTS,
 };
 use arpa_dal::{cache::RandomnessResultCache, BLSResultCacheState};
-use arpa_dal::{BlockInfoHandler, SignatureResultCacheHandler};
+use arpa_dal::{BlockInfoHandler, GroupInfoHandler, SignatureResultCacheHandler};
 use async_trait::async_trait;
 use ethers::types::{Address, U256};
-use log::{debug, error, info};
+use log::{debug, error, info, warn};
 use serde_json::json;
 use std::{collections::BTreeMap, marker::PhantomData, sync::Arc};
 use threshold_bls::{
     group::Curve,
+    poly::Poly,
     sig::{SignatureScheme, ThresholdScheme},
 };
 use tokio::sync::RwLock;
+use tracing::{
+    field::{debug as debug_value, Empty},
+    info_span, Instrument, Span,
+};
+
+/// The public polynomial recovered for the group of the index and the epoch.
+type GroupPublicPoly<C> = (usize, usize, Poly<<C as Curve>::Point>);
 
 #[derive(Debug)]
 pub struct RandomnessSignatureAggregationSubscriber<
@@ -37,11 +49,13 @@ pub struct RandomnessSignatureAggregationSubscriber<
     chain_id: usize,
     id_address: Address,
     chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
     block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
     randomness_signature_cache:
         Arc<RwLock<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>>>,
     eq: Arc<RwLock<EventQueue>>,
     ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    public_poly: RwLock<Option<GroupPublicPoly<PC>>>,
     c: PhantomData<PC>,
     s: PhantomData<S>,
 }
@@ -49,10 +63,12 @@ pub struct RandomnessSignatureAggregationSubscriber<
 impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>>
     RandomnessSignatureAggregationSubscriber<PC, S>
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         chain_id: usize,
         id_address: Address,
         chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+        group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
         block_cache: Arc<RwLock<Box<dyn BlockInfoHandler>>>,
         randomness_signature_cache: Arc<
             RwLock<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>>,
@@ -64,14 +80,43 @@ impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private
             chain_id,
             id_address,
             chain_identity,
+            group_cache,
             block_cache,
             randomness_signature_cache,
             eq,
             ts,
+            public_poly: RwLock::new(None),
             c: PhantomData,
             s: PhantomData,
         }
     }
+
+    /// Returns the public polynomial of the group at the index, which is recovered once per
+    /// epoch, or `None` if the node is not in the group.
+    async fn get_public_poly(&self, group_index: usize) -> NodeResult<Option<Poly<PC::Point>>> {
+        let group_cache = self.group_cache.read().await;
+
+        // partials can only be checked against the group the node is currently in
+        if !matches!(group_cache.get_index(), Ok(index) if index == group_index) {
+            return Ok(None);
+        }
+
+        let epoch = group_cache.get_epoch()?;
+
+        if let Some((cached_index, cached_epoch, public_poly)) = &*self.public_poly.read().await {
+            if *cached_index == group_index && *cached_epoch == epoch {
+                return Ok(Some(public_poly.clone()));
+            }
+        }
+
+        let public_poly = group_cache.get_group()?.public_poly();
+
+        if let Some(public_poly) = &public_poly {
+            *self.public_poly.write().await = Some((group_index, epoch, public_poly.clone()));
+        }
+
+        Ok(public_poly)
+    }
 }
 
 #[async_trait]
@@ -126,6 +171,10 @@ impl<PC: Curve> FulfillRandomnessHandler for GeneralFulfillRandomnessHandler<PC>
                     .update_commit_result(&randomness_task_request_id, BLSResultCacheState::Expired)
                     .await?;
 
+                RANDOMNESS_TASKS
+                    .with_label_values(&[&chain_id.to_string(), "expired"])
+                    .inc();
+
                 info!("mark randomness task as expired. task request id: {}, assignment_block_height:{:?}",
                     format!("0x{}", hex::encode(randomness_task_request_id)), randomness_task.assignment_block_height);
 
@@ -172,6 +221,8 @@ impl<PC: Curve> FulfillRandomnessHandler for GeneralFulfillRandomnessHandler<PC>
                 .await
             {
                 Ok(receipt) => {
+                    Span::current().record("tx_hash", debug_value(receipt.transaction_hash));
+
                     self.randomness_signature_cache
                         .write()
                         .await
@@ -181,6 +232,10 @@ impl<PC: Curve> FulfillRandomnessHandler for GeneralFulfillRandomnessHandler<PC>
                         )
                         .await?;
 
+                    RANDOMNESS_TASKS
+                        .with_label_values(&[&chain_id.to_string(), "committed"])
+                        .inc();
+
                     info!(
                         "{}",
                         build_task_related_transaction_receipt_payload(
@@ -208,6 +263,9 @@ impl<PC: Curve> FulfillRandomnessHandler for GeneralFulfillRandomnessHandler<PC>
 
                     match e {
                         ContractClientError::TransactionFailed(receipt) => {
+                            Span::current()
+                                .record("tx_hash", debug_value(receipt.transaction_hash));
+
                             error!(
                                 "{}",
                                 build_task_related_transaction_receipt_payload(
@@ -290,9 +348,9 @@ where
             let RandomnessResultCache {
                 group_index,
                 randomness_task,
-                message: _,
+                message,
                 threshold,
-                partial_signatures,
+                mut partial_signatures,
                 committed_times,
             } = ready_signature_cache.clone();
 
@@ -314,8 +372,63 @@ where
                 .map(|partial| partial.signed_partial_signature.clone())
                 .collect::<Vec<Vec<u8>>>();
 
-            match SimpleBLSCore::<PC, S>::aggregate(threshold, &partials) {
-                Ok(signature) => {
+            let aggregation = match self.get_public_poly(group_index).await? {
+                Some(public_poly) => SimpleBLSCore::<PC, S>::aggregate_verified(
+                    threshold,
+                    &public_poly,
+                    &message,
+                    &partials,
+                ),
+                None => {
+                    warn!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::AggregatedSignatureUnverified,
+                            "Partial signatures aggregated without verification as the node is not in the group.",
+                            self.chain_id,
+                            &randomness_task.request_id,
+                            TaskType::BLS(BLSTaskType::Randomness),
+                            json!(randomness_task),
+                            None
+                        )
+                    );
+
+                    SimpleBLSCore::<PC, S>::aggregate(threshold, &partials)
+                        .map(|signature| (signature, vec![]))
+                }
+            };
+
+            match aggregation {
+                Ok((signature, discarded_positions)) => {
+                    if !discarded_positions.is_empty() {
+                        // the partials are in the order of the members
+                        let misbehaving_members = partial_signatures
+                            .keys()
+                            .enumerate()
+                            .filter(|(position, _)| discarded_positions.contains(position))
+                            .map(|(_, member)| *member)
+                            .collect::<Vec<_>>();
+
+                        partial_signatures
+                            .retain(|member, _| !misbehaving_members.contains(member));
+
+                        error!(
+                            "{}",
+                            build_task_related_payload(
+                                LogType::AggregatedSignatureDiscardedPartials,
+                                &format!(
+                                    "Discarded invalid or malformed partial signatures from members: {:?}",
+                                    misbehaving_members
+                                ),
+                                self.chain_id,
+                                &randomness_task.request_id,
+                                TaskType::BLS(BLSTaskType::Randomness),
+                                json!(randomness_task),
+                                None
+                            )
+                        );
+                    }
+
                     info!(
                         "{}",
                         build_task_related_payload(
@@ -337,6 +450,15 @@ where
 
                     let randomness_signature_cache = self.randomness_signature_cache.clone();
 
+                    let span = info_span!(
+                        "fulfill_randomness",
+                        chain_id = self.chain_id,
+                        group_index,
+                        request_id = %format!("0x{}", hex::encode(&randomness_task.request_id)),
+                        tx_hash = Empty
+                    );
+                    set_task_parent(&span, &randomness_task.request_id);
+
                     self.ts.write().await.add_task(
                         ComponentTaskType::Subscriber(
                             self.chain_identity.read().await.get_chain_id(),
@@ -362,7 +484,8 @@ where
                             {
                                 error!("{:?}", e);
                             }
-                        },
+                        }
+                        .instrument(span),
                     )?;
                 }
                 Err(e) => {
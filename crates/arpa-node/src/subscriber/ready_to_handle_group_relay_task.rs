@@ -0,0 +1,469 @@
+use crate::{
+    algorithm::bls::{BLSCore, SimpleBLSCore},
+    committer::{
+        client::GeneralCommitterClient, CommitterClient, CommitterClientHandler,
+        CommitterCredentials, CommitterService,
+    },
+    context::ChainIdentityHandlerType,
+    error::NodeResult,
+    event::{ready_to_handle_group_relay_task::ReadyToHandleGroupRelayTask, types::Topic},
+    queue::{event_queue::EventQueue, EventSubscriber},
+    scheduler::{dynamic::SimpleDynamicTaskScheduler, TaskScheduler},
+};
+use arpa_contract_client::controller::ControllerViews;
+use arpa_core::{
+    log::{build_task_related_payload, LogType},
+    BLSTaskType, ComponentTaskType, ExponentialBackoffRetryDescriptor, GroupRelayTask,
+    SubscriberType, TaskType,
+};
+use arpa_dal::cache::GroupRelayResultCache;
+use arpa_dal::{BLSTasksHandler, GroupInfoHandler, SignatureResultCacheHandler};
+use async_trait::async_trait;
+use ethers::types::Address;
+use log::{debug, error, info};
+use serde_json::json;
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+use threshold_bls::{
+    group::Curve,
+    sig::{SignatureScheme, ThresholdScheme},
+};
+use tokio::sync::RwLock;
+
+use super::{DebuggableEvent, DebuggableSubscriber, Subscriber};
+
+#[derive(Debug)]
+pub struct ReadyToHandleGroupRelayTaskSubscriber<
+    PC: Curve,
+    S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>,
+> {
+    pub chain_id: usize,
+    id_address: Address,
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    relayed_chain_identities: HashMap<usize, Arc<RwLock<ChainIdentityHandlerType<PC>>>>,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+    group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>>,
+    group_relay_signature_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>>,
+    eq: Arc<RwLock<EventQueue>>,
+    ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    c: PhantomData<PC>,
+    s: PhantomData<S>,
+    commit_partial_signature_retry_descriptor: Arc<RwLock<ExponentialBackoffRetryDescriptor>>,
+    committer_credentials: CommitterCredentials,
+}
+
+impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>>
+    ReadyToHandleGroupRelayTaskSubscriber<PC, S>
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: usize,
+        id_address: Address,
+        chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+        relayed_chain_identities: HashMap<usize, Arc<RwLock<ChainIdentityHandlerType<PC>>>>,
+        group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+        group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>>,
+        group_relay_signature_cache: Arc<
+            RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>,
+        >,
+        eq: Arc<RwLock<EventQueue>>,
+        ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+        commit_partial_signature_retry_descriptor: Arc<RwLock<ExponentialBackoffRetryDescriptor>>,
+        committer_credentials: CommitterCredentials,
+    ) -> Self {
+        ReadyToHandleGroupRelayTaskSubscriber {
+            chain_id,
+            id_address,
+            chain_identity,
+            relayed_chain_identities,
+            group_cache,
+            group_relay_tasks_cache,
+            group_relay_signature_cache,
+            eq,
+            ts,
+            c: PhantomData,
+            s: PhantomData,
+            commit_partial_signature_retry_descriptor,
+            committer_credentials,
+        }
+    }
+}
+
+#[async_trait]
+pub trait GroupRelayHandler {
+    async fn handle(self) -> NodeResult<()>;
+
+    async fn send_partial_signature(
+        &self,
+        task: &GroupRelayTask,
+        message: Vec<u8>,
+        partial_signature: Vec<u8>,
+    ) -> NodeResult<()>;
+}
+
+pub struct GeneralGroupRelayHandler<
+    PC: Curve,
+    S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>,
+> {
+    chain_id: usize,
+    id_address: Address,
+    tasks: Vec<GroupRelayTask>,
+    chain_identity: Arc<RwLock<ChainIdentityHandlerType<PC>>>,
+    relayed_chain_identities: HashMap<usize, Arc<RwLock<ChainIdentityHandlerType<PC>>>>,
+    group_cache: Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>>,
+    group_relay_tasks_cache: Arc<RwLock<Box<dyn BLSTasksHandler<GroupRelayTask>>>>,
+    group_relay_signature_cache:
+        Arc<RwLock<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>>>,
+    ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
+    c: PhantomData<PC>,
+    s: PhantomData<S>,
+    commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+    committer_credentials: CommitterCredentials,
+}
+
+impl<
+        PC: Curve + Sync + Send,
+        S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar> + Sync + Send,
+    > CommitterClientHandler<GeneralCommitterClient, PC> for GeneralGroupRelayHandler<PC, S>
+{
+    async fn get_id_address(&self) -> Address {
+        self.id_address
+    }
+
+    fn get_group_cache(&self) -> Arc<RwLock<Box<dyn GroupInfoHandler<PC>>>> {
+        self.group_cache.clone()
+    }
+
+    fn get_commit_partial_signature_retry_descriptor(&self) -> ExponentialBackoffRetryDescriptor {
+        self.commit_partial_signature_retry_descriptor
+    }
+
+    fn get_committer_credentials(&self) -> CommitterCredentials {
+        self.committer_credentials.clone()
+    }
+}
+
+#[async_trait]
+impl<
+        PC: Curve + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Sync
+            + Send
+            + 'static,
+    > GroupRelayHandler for GeneralGroupRelayHandler<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    async fn handle(self) -> NodeResult<()> {
+        let client = self.chain_identity.read().await.build_controller_client();
+
+        for task in self.tasks.iter() {
+            let controller_oracle_address = match self
+                .relayed_chain_identities
+                .get(&task.relayed_chain_id)
+            {
+                Some(chain_identity) => chain_identity
+                    .read()
+                    .await
+                    .build_controller_oracle_client()
+                    .get_controller_oracle_address(),
+                None => {
+                    error!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::PartialSignatureFailed,
+                            &format!("Relayed chain {} is not configured.", task.relayed_chain_id),
+                            self.chain_id,
+                            &task.request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            json!(task),
+                            None
+                        )
+                    );
+
+                    continue;
+                }
+            };
+
+            // the relayed group is signed exactly as the controller abi-encodes it, so that the
+            // controller oracle can decode and verify it without further input. It is read at the
+            // block of the task, where the controller was at the global epoch of the task
+            let encoded_group = ControllerViews::<PC>::get_encoded_group(
+                &client,
+                task.relayed_group_index,
+                task.assignment_block_height as u64,
+            )
+            .await?;
+
+            let message = task.build_message(controller_oracle_address, encoded_group);
+
+            match SimpleBLSCore::<PC, S>::partial_sign(
+                self.group_cache.read().await.get_secret_share()?,
+                &message,
+            ) {
+                Ok(signed_partial_signature) => {
+                    info!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::PartialSignatureFinished,
+                            "Partial signature generated.",
+                            self.chain_id,
+                            &task.request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            json!(task),
+                            None
+                        )
+                    );
+
+                    self.send_partial_signature(task, message, signed_partial_signature)
+                        .await?;
+                }
+                Err(e) => {
+                    error!(
+                        "{}",
+                        build_task_related_payload(
+                            LogType::PartialSignatureFailed,
+                            &format!("Partial signature generation failed with error: {:?}", e),
+                            self.chain_id,
+                            &task.request_id,
+                            TaskType::BLS(BLSTaskType::GroupRelay),
+                            json!(task),
+                            None
+                        )
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn send_partial_signature(
+        &self,
+        task: &GroupRelayTask,
+        message: Vec<u8>,
+        partial_signature: Vec<u8>,
+    ) -> NodeResult<()> {
+        let threshold = self.group_cache.read().await.get_threshold()?;
+
+        let current_group_index = self.group_cache.read().await.get_index()?;
+
+        let current_member_index = self.group_cache.read().await.get_self_index()?;
+
+        if self
+            .group_cache
+            .read()
+            .await
+            .is_committer(self.id_address)?
+        {
+            let contained_res = self
+                .group_relay_signature_cache
+                .read()
+                .await
+                .contains(&task.request_id)
+                .await?;
+            if !contained_res {
+                let task = self
+                    .group_relay_tasks_cache
+                    .read()
+                    .await
+                    .get(&task.request_id)
+                    .await?;
+
+                self.group_relay_signature_cache
+                    .write()
+                    .await
+                    .add(current_group_index, task, message.clone(), threshold)
+                    .await?;
+            }
+
+            self.group_relay_signature_cache
+                .write()
+                .await
+                .add_partial_signature(
+                    task.request_id.clone(),
+                    self.id_address,
+                    current_member_index,
+                    partial_signature.clone(),
+                )
+                .await?;
+        }
+
+        let committers = self.prepare_committer_clients().await?;
+
+        for committer in committers.into_iter() {
+            let chain_id = self.chain_id;
+            let request_id = task.request_id.clone();
+            let message = message.clone();
+            let partial_signature = partial_signature.clone();
+            let task_json = json!(task);
+
+            self.ts.write().await.add_task(
+                ComponentTaskType::Subscriber(chain_id, SubscriberType::SendingPartialSignature),
+                async move {
+                    let committer_id = committer.get_committer_id_address();
+
+                    match committer
+                        .commit_partial_signature(
+                            chain_id,
+                            BLSTaskType::GroupRelay,
+                            request_id.clone(),
+                            message,
+                            partial_signature,
+                        )
+                        .await
+                    {
+                        Ok(true) => {
+                            info!(
+                                "{}",
+                                build_task_related_payload(
+                                    LogType::PartialSignatureSent,
+                                    "Partial signature sent and accepted.",
+                                    chain_id,
+                                    &request_id,
+                                    TaskType::BLS(BLSTaskType::GroupRelay),
+                                    task_json,
+                                    Some(committer_id)
+                                )
+                            );
+                        }
+                        Ok(false) => {
+                            info!(
+                                "{}",
+                                build_task_related_payload(
+                                    LogType::PartialSignatureSendingRejected,
+                                    "Partial signature sent and rejected.",
+                                    chain_id,
+                                    &request_id,
+                                    TaskType::BLS(BLSTaskType::GroupRelay),
+                                    task_json,
+                                    Some(committer_id)
+                                )
+                            );
+                        }
+                        Err(e) => {
+                            error!(
+                                "{}",
+                                build_task_related_payload(
+                                    LogType::PartialSignatureSendingFailed,
+                                    &format!(
+                                        "Partial signature sending failed with error: {:?}",
+                                        e
+                                    ),
+                                    chain_id,
+                                    &request_id,
+                                    TaskType::BLS(BLSTaskType::GroupRelay),
+                                    task_json,
+                                    Some(committer_id)
+                                )
+                            );
+                        }
+                    }
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<
+        PC: Curve + std::fmt::Debug + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Sync
+            + Send
+            + 'static,
+    > Subscriber for ReadyToHandleGroupRelayTaskSubscriber<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+    async fn notify(&self, topic: Topic, payload: &(dyn DebuggableEvent)) -> NodeResult<()> {
+        debug!("{:?}", topic);
+
+        let ReadyToHandleGroupRelayTask { tasks } = payload
+            .as_any()
+            .downcast_ref::<ReadyToHandleGroupRelayTask>()
+            .unwrap()
+            .clone();
+
+        let chain_id = self.chain_id;
+
+        let id_address = self.id_address;
+
+        let chain_identity_for_handler = self.chain_identity.clone();
+
+        let relayed_chain_identities = self.relayed_chain_identities.clone();
+
+        let group_cache_for_handler = self.group_cache.clone();
+
+        let group_relay_tasks_cache_for_handler = self.group_relay_tasks_cache.clone();
+
+        let group_relay_signature_cache_for_handler = self.group_relay_signature_cache.clone();
+
+        let task_scheduler_for_handler = self.ts.clone();
+
+        let commit_partial_signature_retry_descriptor =
+            *self.commit_partial_signature_retry_descriptor.read().await;
+
+        let committer_credentials = self.committer_credentials.clone();
+
+        self.ts.write().await.add_task(
+            ComponentTaskType::Subscriber(chain_id, SubscriberType::ReadyToHandleGroupRelayTask),
+            async move {
+                let handler = GeneralGroupRelayHandler {
+                    chain_id,
+                    id_address,
+                    tasks,
+                    chain_identity: chain_identity_for_handler,
+                    relayed_chain_identities,
+                    group_cache: group_cache_for_handler,
+                    group_relay_tasks_cache: group_relay_tasks_cache_for_handler,
+                    group_relay_signature_cache: group_relay_signature_cache_for_handler,
+                    ts: task_scheduler_for_handler,
+                    c: PhantomData::<PC>,
+                    s: PhantomData::<S>,
+                    commit_partial_signature_retry_descriptor,
+                    committer_credentials,
+                };
+
+                if let Err(e) = handler.handle().await {
+                    error!("{:?}", e);
+                }
+            },
+        )?;
+
+        Ok(())
+    }
+
+    async fn subscribe(self) {
+        let eq = self.eq.clone();
+
+        let subscriber = Box::new(self);
+
+        eq.write()
+            .await
+            .subscribe(Topic::ReadyToHandleGroupRelayTask, subscriber);
+    }
+}
+
+impl<
+        PC: Curve + std::fmt::Debug + Sync + Send + 'static,
+        S: SignatureScheme
+            + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>
+            + Clone
+            + Sync
+            + Send
+            + 'static,
+    > DebuggableSubscriber for ReadyToHandleGroupRelayTaskSubscriber<PC, S>
+where
+    <S as ThresholdScheme>::Error: Sync + Send,
+    <S as SignatureScheme>::Error: Sync + Send,
+{
+}
@@ -1,7 +1,8 @@
 use crate::{
     algorithm::bls::{BLSCore, SimpleBLSCore},
     committer::{
-        client::GeneralCommitterClient, CommitterClient, CommitterClientHandler, CommitterService,
+        client::GeneralCommitterClient, CommitterClient, CommitterClientHandler,
+        CommitterCredentials, CommitterService,
     },
     error::NodeResult,
     event::{ready_to_handle_randomness_task::ReadyToHandleRandomnessTask, types::Topic},
@@ -9,14 +10,15 @@ use crate::{
     scheduler::{dynamic::SimpleDynamicTaskScheduler, TaskScheduler},
 };
 use arpa_core::{
-    log::{build_task_related_payload, LogType},
-    u256_to_vec, BLSTaskType, ComponentTaskType, ExponentialBackoffRetryDescriptor, RandomnessTask,
+    log::{build_task_related_payload, trace::set_task_parent, LogType},
+    metrics::RANDOMNESS_TASKS,
+    BLSTaskType, ComponentTaskType, ExponentialBackoffRetryDescriptor, RandomnessTask,
     SubscriberType, TaskType,
 };
 use arpa_dal::cache::RandomnessResultCache;
 use arpa_dal::{BLSTasksHandler, GroupInfoHandler, SignatureResultCacheHandler};
 use async_trait::async_trait;
-use ethers::types::{Address, U256};
+use ethers::types::Address;
 use log::{debug, error, info};
 use serde_json::json;
 use std::{marker::PhantomData, sync::Arc};
@@ -25,6 +27,7 @@ use threshold_bls::{
     sig::{SignatureScheme, ThresholdScheme},
 };
 use tokio::sync::RwLock;
+use tracing::{info_span, Instrument};
 
 use super::{DebuggableEvent, DebuggableSubscriber, Subscriber};
 
@@ -43,7 +46,8 @@ pub struct ReadyToHandleRandomnessTaskSubscriber<
     ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
     c: PhantomData<PC>,
     s: PhantomData<S>,
-    commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+    commit_partial_signature_retry_descriptor: Arc<RwLock<ExponentialBackoffRetryDescriptor>>,
+    committer_credentials: CommitterCredentials,
 }
 
 impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private = PC::Scalar>>
@@ -60,7 +64,8 @@ impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private
         >,
         eq: Arc<RwLock<EventQueue>>,
         ts: Arc<RwLock<SimpleDynamicTaskScheduler>>,
-        commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+        commit_partial_signature_retry_descriptor: Arc<RwLock<ExponentialBackoffRetryDescriptor>>,
+        committer_credentials: CommitterCredentials,
     ) -> Self {
         ReadyToHandleRandomnessTaskSubscriber {
             chain_id,
@@ -73,6 +78,7 @@ impl<PC: Curve, S: SignatureScheme + ThresholdScheme<Public = PC::Point, Private
             c: PhantomData,
             s: PhantomData,
             commit_partial_signature_retry_descriptor,
+            committer_credentials,
         }
     }
 }
@@ -104,6 +110,7 @@ pub struct GeneralRandomnessHandler<
     c: PhantomData<PC>,
     s: PhantomData<S>,
     commit_partial_signature_retry_descriptor: ExponentialBackoffRetryDescriptor,
+    committer_credentials: CommitterCredentials,
 }
 
 impl<
@@ -122,6 +129,10 @@ impl<
     fn get_commit_partial_signature_retry_descriptor(&self) -> ExponentialBackoffRetryDescriptor {
         self.commit_partial_signature_retry_descriptor
     }
+
+    fn get_committer_credentials(&self) -> CommitterCredentials {
+        self.committer_credentials.clone()
+    }
 }
 
 #[async_trait]
@@ -139,49 +150,69 @@ where
     <S as SignatureScheme>::Error: Sync + Send,
 {
     async fn handle(self) -> NodeResult<()> {
-        for task in self.tasks.iter() {
-            let actual_seed = [
-                &u256_to_vec(&task.seed)[..],
-                &u256_to_vec(&U256::from(task.assignment_block_height))[..],
-            ]
-            .concat();
-
-            match SimpleBLSCore::<PC, S>::partial_sign(
-                self.group_cache.read().await.get_secret_share()?,
-                &actual_seed,
-            ) {
-                Ok(signed_partial_signature) => {
-                    info!(
-                        "{}",
-                        build_task_related_payload(
-                            LogType::PartialSignatureFinished,
-                            "Partial signature generated.",
-                            self.chain_id,
-                            &task.request_id,
-                            TaskType::BLS(BLSTaskType::Randomness),
-                            json!(task),
-                            None
-                        )
-                    );
+        let group_index = self.group_cache.read().await.get_index()?;
 
-                    self.send_partial_signature(task, actual_seed, signed_partial_signature)
-                        .await?;
-                }
-                Err(e) => {
-                    error!(
-                        "{}",
-                        build_task_related_payload(
-                            LogType::PartialSignatureFailed,
-                            &format!("Partial signature generation failed with error: {:?}", e),
-                            self.chain_id,
-                            &task.request_id,
-                            TaskType::BLS(BLSTaskType::Randomness),
-                            json!(task),
-                            None
-                        )
-                    );
+        let group_epoch = self.group_cache.read().await.get_epoch()?;
+
+        for task in self.tasks.iter() {
+            let span = info_span!(
+                "randomness_task",
+                chain_id = self.chain_id,
+                group_index,
+                group_epoch,
+                request_id = %format!("0x{}", hex::encode(&task.request_id))
+            );
+            set_task_parent(&span, &task.request_id);
+
+            async {
+                let actual_seed =
+                    RandomnessTask::build_message(&task.seed, task.assignment_block_height);
+
+                match SimpleBLSCore::<PC, S>::partial_sign(
+                    self.group_cache.read().await.get_secret_share()?,
+                    &actual_seed,
+                ) {
+                    Ok(signed_partial_signature) => {
+                        info!(
+                            "{}",
+                            build_task_related_payload(
+                                LogType::PartialSignatureFinished,
+                                "Partial signature generated.",
+                                self.chain_id,
+                                &task.request_id,
+                                TaskType::BLS(BLSTaskType::Randomness),
+                                json!(task),
+                                None
+                            )
+                        );
+
+                        RANDOMNESS_TASKS
+                            .with_label_values(&[&self.chain_id.to_string(), "signed"])
+                            .inc();
+
+                        self.send_partial_signature(task, actual_seed, signed_partial_signature)
+                            .await?;
+                    }
+                    Err(e) => {
+                        error!(
+                            "{}",
+                            build_task_related_payload(
+                                LogType::PartialSignatureFailed,
+                                &format!("Partial signature generation failed with error: {:?}", e),
+                                self.chain_id,
+                                &task.request_id,
+                                TaskType::BLS(BLSTaskType::Randomness),
+                                json!(task),
+                                None
+                            )
+                        );
+                    }
                 }
+
+                NodeResult::Ok(())
             }
+            .instrument(span)
+            .await?;
         }
 
         Ok(())
@@ -352,7 +383,9 @@ where
         let task_scheduler_for_handler = self.ts.clone();
 
         let commit_partial_signature_retry_descriptor =
-            self.commit_partial_signature_retry_descriptor;
+            *self.commit_partial_signature_retry_descriptor.read().await;
+
+        let committer_credentials = self.committer_credentials.clone();
 
         self.ts.write().await.add_task(
             ComponentTaskType::Subscriber(chain_id, SubscriberType::ReadyToHandleRandomnessTask),
@@ -368,6 +401,7 @@ where
                     c: PhantomData::<PC>,
                     s: PhantomData::<S>,
                     commit_partial_signature_retry_descriptor,
+                    committer_credentials,
                 };
 
                 if let Err(e) = handler.handle().await {
@@ -1,4 +1,4 @@
-use arpa_core::{HttpWalletSigner, WsWalletSigner};
+use arpa_core::{HttpWalletSigner, WalletSigner};
 use ethers::{
     prelude::{ContractError, ProviderError, WalletError},
     types::TransactionReceipt,
@@ -17,7 +17,7 @@ pub enum ContractClientError {
     #[error(transparent)]
     ChainProviderError(#[from] ProviderError),
     #[error(transparent)]
-    WsContractError(#[from] ContractError<WsWalletSigner>),
+    WalletContractError(#[from] ContractError<WalletSigner>),
     #[error(transparent)]
     HttpContractError(#[from] ContractError<HttpWalletSigner>),
     #[error(transparent)]
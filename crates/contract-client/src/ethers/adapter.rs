@@ -1,20 +1,24 @@
 use crate::{
-    adapter::{AdapterClientBuilder, AdapterLogs, AdapterTransactions, AdapterViews},
+    adapter::{
+        AdapterClientBuilder, AdapterLogs, AdapterTransactions, AdapterViews, RandomnessTaskLog,
+    },
     contract_stub::adapter::{
         Adapter, PartialSignature as ContractPartialSignature, RandomnessRequestFilter,
         RequestDetail,
     },
     error::{ContractClientError, ContractClientResult},
+    ethers::provider::{poll_logs_with_meta, query_logs_with_meta},
     ServiceClient, TransactionCaller, ViewCaller,
 };
 use arpa_core::{
     pad_to_bytes32, ChainIdentity, ExponentialBackoffRetryDescriptor, GeneralMainChainIdentity,
     GeneralRelayedChainIdentity, PartialSignature, RandomnessRequestType, RandomnessTask,
-    WsWalletSigner, DEFAULT_MINIMUM_THRESHOLD, FULFILL_RANDOMNESS_GAS_EXCEPT_CALLBACK,
-    RANDOMNESS_REWARD_GAS, VERIFICATION_GAS_OVER_MINIMUM_THRESHOLD,
+    WalletSigner, DEFAULT_MINIMUM_THRESHOLD, FULFILL_RANDOMNESS_GAS_EXCEPT_CALLBACK,
+    RANDOMNESS_REWARD_GAS, RANDOMNESS_TASK_BACKFILL_BLOCK_RANGE,
+    VERIFICATION_GAS_OVER_MINIMUM_THRESHOLD,
 };
 use async_trait::async_trait;
-use ethers::{prelude::*, utils::hex};
+use ethers::{abi::RawLog, prelude::*, utils::hex};
 use log::info;
 use std::{collections::BTreeMap, future::Future, sync::Arc};
 use threshold_bls::poly::Eval;
@@ -24,7 +28,7 @@ pub struct AdapterClient {
     chain_id: usize,
     main_id_address: Address,
     adapter_address: Address,
-    client: Arc<WsWalletSigner>,
+    client: Arc<WalletSigner>,
     contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
     contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
 }
@@ -34,7 +38,7 @@ impl AdapterClient {
         chain_id: usize,
         main_id_address: Address,
         adapter_address: Address,
-        client: Arc<WsWalletSigner>,
+        client: Arc<WalletSigner>,
         contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
         contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
     ) -> Self {
@@ -79,7 +83,7 @@ impl AdapterClientBuilder for GeneralRelayedChainIdentity {
     }
 }
 
-type AdapterContract = Adapter<WsWalletSigner>;
+type AdapterContract = Adapter<WalletSigner>;
 
 #[async_trait]
 impl ServiceClient<AdapterContract> for AdapterClient {
@@ -206,56 +210,191 @@ impl AdapterViews for AdapterClient {
 #[async_trait]
 impl AdapterLogs for AdapterClient {
     async fn subscribe_randomness_task<
-        C: FnMut(RandomnessTask) -> F + Send,
+        C: FnMut(RandomnessTaskLog) -> F + Send,
         F: Future<Output = ContractClientResult<()>> + Send,
+        P: FnMut(usize) -> G + Send,
+        G: Future<Output = ContractClientResult<()>> + Send,
     >(
         &self,
+        from_block: Option<usize>,
         mut cb: C,
+        mut checkpoint: P,
     ) -> ContractClientResult<()> {
         let contract = Adapter::new(self.adapter_address, self.client.clone());
 
-        let events = contract
-            .event::<RandomnessRequestFilter>()
-            .from_block(BlockNumber::Latest);
-
-        let mut stream = events.subscribe().await?.with_meta();
-
-        while let Some(Ok(evt)) = stream.next().await {
-            let (
-                RandomnessRequestFilter {
-                    request_id,
-                    sub_id,
-                    group_index,
-                    request_type,
-                    params,
-                    sender,
-                    seed,
-                    request_confirmations,
-                    callback_gas_limit,
-                    callback_max_gas_price,
-                    estimated_payment: _,
-                },
-                meta,
-            ) = evt;
-
-            info!( "Received randomness task: chain_id: {}, group_index: {}, request_id: {}, sender: {:?}, sub_id: {}, seed: {}, request_confirmations: {}, callback_gas_limit: {}, callback_max_gas_price: {}, block_number: {}",
-                self.chain_id, group_index, format!("0x{}", hex::encode(request_id)), sender, sub_id, seed, request_confirmations, callback_gas_limit, callback_max_gas_price, meta.block_number);
-
-            let task = RandomnessTask {
-                request_id: request_id.to_vec(),
-                subscription_id: sub_id,
-                group_index,
-                request_type: RandomnessRequestType::from(request_type),
-                params: params.to_vec(),
-                requester: sender,
-                seed,
-                request_confirmations,
-                callback_gas_limit,
-                callback_max_gas_price,
-                assignment_block_height: meta.block_number.as_usize(),
-            };
-            cb(task).await?;
+        let provider = self.client.provider();
+
+        let filter = contract.event::<RandomnessRequestFilter>().filter;
+
+        let mut handle_log = |evt: RandomnessRequestFilter, meta: LogMeta, removed: bool| {
+            cb(RandomnessTaskLog {
+                block_hash: meta.block_hash,
+                task: self.parse_randomness_task(evt, meta),
+                removed,
+            })
+        };
+
+        if !provider.as_ref().supports_subscription() {
+            let latest_block = provider.get_block_number().await?.as_usize();
+
+            if let Some(from_block) = from_block {
+                self.backfill_randomness_tasks(
+                    &filter,
+                    from_block,
+                    latest_block,
+                    &mut handle_log,
+                    &mut checkpoint,
+                )
+                .await?;
+            }
+
+            return poll_logs_with_meta(
+                provider,
+                &filter,
+                latest_block + 1,
+                handle_log,
+                checkpoint,
+            )
+            .await;
+        }
+
+        // subscribe before replaying history so that no request emitted in between is missed,
+        // the overlap is expected to be deduplicated by the callback
+        let mut stream = provider
+            .subscribe_logs(&filter.clone().from_block(BlockNumber::Latest))
+            .await?;
+        // the logs of a block are sent along with its header, so a new block tells
+        // the requests of the blocks before it have been handled
+        let mut blocks = provider.subscribe_blocks().await?;
+
+        if let Some(from_block) = from_block {
+            let latest_block = provider.get_block_number().await?.as_usize();
+
+            self.backfill_randomness_tasks(
+                &filter,
+                from_block,
+                latest_block,
+                &mut handle_log,
+                &mut checkpoint,
+            )
+            .await?;
+        }
+
+        loop {
+            tokio::select! {
+                log = stream.next() => {
+                    let Some(log) = log else { break };
+                    // a log is sent again with the removed flag when its block is orphaned
+                    // by a reorg
+                    let removed = log.removed.unwrap_or(false);
+                    let meta = LogMeta::from(&log);
+                    let evt =
+                        <RandomnessRequestFilter as EthLogDecode>::decode_log(&RawLog::from(log))
+                            .map_err(anyhow::Error::from)?;
+                    handle_log(evt, meta, removed).await?;
+                }
+                block = blocks.next() => {
+                    let Some(block_number) = block.and_then(|block| block.number) else { break };
+                    checkpoint(block_number.as_usize().saturating_sub(1)).await?;
+                }
+            }
         }
         Err(ContractClientError::FetchingRandomnessTaskError)
     }
+
+    async fn is_randomness_task_requested(
+        &self,
+        request_id: &[u8],
+        block_height: usize,
+        block_hash: Option<H256>,
+    ) -> ContractClientResult<bool> {
+        let contract = Adapter::new(self.adapter_address, self.client.clone());
+
+        let filter = contract
+            .event::<RandomnessRequestFilter>()
+            .filter
+            .topic1(H256::from(pad_to_bytes32(request_id).unwrap()));
+
+        let filter = match block_hash {
+            Some(block_hash) => filter.at_block_hash(block_hash),
+            None => filter.from_block(block_height).to_block(block_height),
+        };
+
+        let logs = self.client.provider().get_logs(&filter).await?;
+
+        Ok(!logs.is_empty())
+    }
+}
+
+impl AdapterClient {
+    /// Replays the randomness requests from `from_block` to `to_block`, in ranges of at most
+    /// `RANDOMNESS_TASK_BACKFILL_BLOCK_RANGE` blocks.
+    async fn backfill_randomness_tasks<
+        C: FnMut(RandomnessRequestFilter, LogMeta, bool) -> F + Send,
+        F: Future<Output = ContractClientResult<()>> + Send,
+        P: FnMut(usize) -> G + Send,
+        G: Future<Output = ContractClientResult<()>> + Send,
+    >(
+        &self,
+        filter: &Filter,
+        from_block: usize,
+        to_block: usize,
+        handle_log: &mut C,
+        checkpoint: &mut P,
+    ) -> ContractClientResult<()> {
+        let mut start = from_block;
+        while start <= to_block {
+            let end = to_block.min(start + RANDOMNESS_TASK_BACKFILL_BLOCK_RANGE - 1);
+
+            info!(
+                "Backfilling randomness tasks: chain_id: {}, from_block: {}, to_block: {}",
+                self.chain_id, start, end
+            );
+
+            let logs = query_logs_with_meta(self.client.provider(), filter, start, end).await?;
+
+            for (evt, meta) in logs {
+                handle_log(evt, meta, false).await?;
+            }
+
+            checkpoint(end).await?;
+
+            start = end + 1;
+        }
+
+        Ok(())
+    }
+
+    fn parse_randomness_task(&self, evt: RandomnessRequestFilter, meta: LogMeta) -> RandomnessTask {
+        let RandomnessRequestFilter {
+            request_id,
+            sub_id,
+            group_index,
+            request_type,
+            params,
+            sender,
+            seed,
+            request_confirmations,
+            callback_gas_limit,
+            callback_max_gas_price,
+            estimated_payment: _,
+        } = evt;
+
+        info!( "Received randomness task: chain_id: {}, group_index: {}, request_id: {}, sender: {:?}, sub_id: {}, seed: {}, request_confirmations: {}, callback_gas_limit: {}, callback_max_gas_price: {}, block_number: {}",
+            self.chain_id, group_index, format!("0x{}", hex::encode(request_id)), sender, sub_id, seed, request_confirmations, callback_gas_limit, callback_max_gas_price, meta.block_number);
+
+        RandomnessTask {
+            request_id: request_id.to_vec(),
+            subscription_id: sub_id,
+            group_index,
+            request_type: RandomnessRequestType::from(request_type),
+            params: params.to_vec(),
+            requester: sender,
+            seed,
+            request_confirmations,
+            callback_gas_limit,
+            callback_max_gas_price,
+            assignment_block_height: meta.block_number.as_usize(),
+        }
+    }
 }
@@ -6,16 +6,20 @@ use crate::{
         ControllerClientBuilder, ControllerLogs, ControllerTransactions, ControllerViews,
     },
     error::{ContractClientError, ContractClientResult},
+    ethers::provider::{poll_logs_with_meta, skip_checkpoint, skip_removed_logs},
     ServiceClient,
 };
 use crate::{TransactionCaller, ViewCaller};
 use arpa_core::{
     u256_to_vec, ChainIdentity, DKGTask, ExponentialBackoffRetryDescriptor,
     GeneralMainChainIdentity, GeneralRelayedChainIdentity, Group, MainChainIdentity, Member,
-    WsWalletSigner,
+    WalletSigner,
 };
 use async_trait::async_trait;
-use ethers::prelude::*;
+use ethers::{
+    abi::{encode, Tokenizable},
+    prelude::*,
+};
 use log::info;
 use std::collections::BTreeMap;
 use std::marker::PhantomData;
@@ -25,7 +29,7 @@ use threshold_bls::group::Curve;
 pub struct ControllerClient {
     chain_id: usize,
     controller_address: Address,
-    client: Arc<WsWalletSigner>,
+    client: Arc<WalletSigner>,
     contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
     contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
 }
@@ -70,7 +74,7 @@ impl<C: Curve> ControllerClientBuilder<C> for GeneralRelayedChainIdentity {
     }
 }
 
-type ControllerContract = Controller<WsWalletSigner>;
+type ControllerContract = Controller<WalletSigner>;
 
 #[async_trait]
 impl ServiceClient<ControllerContract> for ControllerClient {
@@ -157,6 +161,26 @@ impl<C: Curve> ControllerViews<C> for ControllerClient {
         .map(parse_contract_group)
     }
 
+    async fn get_group_at(
+        &self,
+        group_index: usize,
+        block_number: u64,
+    ) -> ContractClientResult<Group<C>> {
+        let controller_contract =
+            ServiceClient::<ControllerContract>::prepare_service_client(self).await?;
+
+        ControllerClient::call_contract_view(
+            self.chain_id,
+            "get_group",
+            controller_contract
+                .get_group(group_index.into())
+                .block(block_number),
+            self.contract_view_retry_descriptor,
+        )
+        .await
+        .map(parse_contract_group)
+    }
+
     async fn get_coordinator(&self, group_index: usize) -> ContractClientResult<Address> {
         let controller_contract =
             ServiceClient::<ControllerContract>::prepare_service_client(self).await?;
@@ -184,6 +208,32 @@ impl<C: Curve> ControllerViews<C> for ControllerClient {
 
         Ok(config.0)
     }
+
+    async fn get_encoded_group(
+        &self,
+        group_index: usize,
+        block_number: u64,
+    ) -> ContractClientResult<Vec<u8>> {
+        let controller_contract =
+            ServiceClient::<ControllerContract>::prepare_service_client(self).await?;
+
+        ControllerClient::call_contract_view(
+            self.chain_id,
+            "get_group",
+            controller_contract
+                .get_group(group_index.into())
+                .block(block_number),
+            self.contract_view_retry_descriptor,
+        )
+        .await
+        .map(encode_group)
+    }
+}
+
+/// Encodes the group as `abi.encode(group)` does in Solidity. Unlike `AbiEncode::encode`, which
+/// flattens the struct into its fields, the dynamic tuple keeps its leading offset.
+fn encode_group(group: ContractGroup) -> Vec<u8> {
+    encode(&[group.into_token()])
 }
 
 #[async_trait]
@@ -197,26 +247,19 @@ impl ControllerLogs for ControllerClient {
     ) -> ContractClientResult<()> {
         let contract = Controller::new(self.controller_address, self.client.clone());
 
-        let events = contract
-            .event::<DkgTaskFilter>()
-            .from_block(BlockNumber::Latest);
+        let provider = self.client.provider();
 
-        let mut stream = events.subscribe().await?.with_meta();
-
-        while let Some(Ok(evt)) = stream.next().await {
-            let (
-                DkgTaskFilter {
-                    global_epoch: _,
-                    group_index,
-                    group_epoch,
-                    size,
-                    threshold,
-                    members,
-                    assignment_block_height: _,
-                    coordinator_address,
-                },
-                meta,
-            ) = evt;
+        let mut handle_log = |evt: DkgTaskFilter, meta: LogMeta| {
+            let DkgTaskFilter {
+                global_epoch: _,
+                group_index,
+                group_epoch,
+                size,
+                threshold,
+                members,
+                assignment_block_height: _,
+                coordinator_address,
+            } = evt;
 
             info!(
                 "Received DKG task: group_index: {}, epoch: {}, size: {}, threshold: {}, members: {:?}, coordinator_address: {}, block_number: {}",
@@ -232,7 +275,31 @@ impl ControllerLogs for ControllerClient {
                 assignment_block_height: meta.block_number.as_usize(),
                 coordinator_address,
             };
-            cb(task).await?;
+            cb(task)
+        };
+
+        if !provider.as_ref().supports_subscription() {
+            let filter = contract.event::<DkgTaskFilter>().filter;
+            let from_block = provider.get_block_number().await?.as_usize() + 1;
+
+            return poll_logs_with_meta(
+                provider,
+                &filter,
+                from_block,
+                skip_removed_logs(handle_log),
+                skip_checkpoint,
+            )
+            .await;
+        }
+
+        let events = contract
+            .event::<DkgTaskFilter>()
+            .from_block(BlockNumber::Latest);
+
+        let mut stream = events.subscribe().await?.with_meta();
+
+        while let Some(Ok((evt, meta))) = stream.next().await {
+            handle_log(evt, meta).await?;
         }
         Err(ContractClientError::FetchingDkgTaskError)
     }
@@ -308,3 +375,61 @@ fn parse_contract_group<C: Curve>(cg: ContractGroup) -> Group<C> {
         c: PhantomData,
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::encode_group;
+    use crate::contract_stub::controller::{Group as ContractGroup, Member};
+    use ethers::types::{Address, U256};
+
+    /// `abi.encode(group)` of [`build_group_fixture`], shared with the contract tests.
+    pub(crate) const ENCODED_GROUP_FIXTURE: &[&str] = &[
+        "0000000000000000000000000000000000000000000000000000000000000020",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000180",
+        "0000000000000000000000000000000000000000000000000000000000000240",
+        "0000000000000000000000000000000000000000000000000000000000000280",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000005",
+        "0000000000000000000000000000000000000000000000000000000000000006",
+        "0000000000000000000000000000000000000000000000000000000000000007",
+        "0000000000000000000000000000000000000000000000000000000000000008",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+        "0000000000000000000000000000000000000000000000000000000000000003",
+        "0000000000000000000000000000000000000000000000000000000000000004",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+    ];
+
+    pub(crate) fn build_group_fixture() -> ContractGroup {
+        ContractGroup {
+            index: U256::from(1),
+            epoch: U256::from(3),
+            size: U256::from(1),
+            threshold: U256::from(1),
+            members: vec![Member {
+                node_id_address: Address::from_low_u64_be(1),
+                partial_public_key: [1.into(), 2.into(), 3.into(), 4.into()],
+            }],
+            committers: vec![Address::from_low_u64_be(1)],
+            commit_cache_list: vec![],
+            is_strictly_majority_consensus_reached: true,
+            public_key: [5.into(), 6.into(), 7.into(), 8.into()],
+        }
+    }
+
+    #[test]
+    fn test_encode_group_as_solidity_abi_encode() {
+        assert_eq!(
+            ENCODED_GROUP_FIXTURE.concat(),
+            ethers::utils::hex::encode(encode_group(build_group_fixture()))
+        );
+    }
+}
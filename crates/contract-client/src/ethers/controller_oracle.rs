@@ -1,26 +1,36 @@
 use crate::controller_oracle::ControllerOracleTransactions;
 use crate::{
-    contract_stub::controller_oracle::{ControllerOracle, Group as ContractGroup},
-    controller_oracle::{ControllerOracleClientBuilder, ControllerOracleViews},
-    error::ContractClientResult,
+    contract_stub::controller_oracle::{
+        ControllerOracle, Group as ContractGroup, GroupRelayConfirmationTaskFilter,
+    },
+    controller_oracle::{
+        ControllerOracleClientBuilder, ControllerOracleLogs, ControllerOracleViews,
+    },
+    error::{ContractClientError, ContractClientResult},
+    ethers::provider::{poll_logs_with_meta, skip_checkpoint, skip_removed_logs},
     ServiceClient,
 };
 use crate::{TransactionCaller, ViewCaller};
 use arpa_core::{
     u256_to_vec, ChainIdentity, ExponentialBackoffRetryDescriptor, GeneralMainChainIdentity,
-    GeneralRelayedChainIdentity, Group, Member, RelayedChainIdentity, WsWalletSigner,
+    GeneralRelayedChainIdentity, Group, GroupRelayConfirmationTask, Member, RelayedChainIdentity,
+    WalletSigner,
 };
 use async_trait::async_trait;
-use ethers::prelude::*;
+use ethers::{
+    abi::{decode, AbiType, Tokenizable},
+    prelude::*,
+};
+use log::info;
 use std::collections::BTreeMap;
 use std::marker::PhantomData;
-use std::sync::Arc;
+use std::{future::Future, sync::Arc};
 use threshold_bls::group::Curve;
 
 pub struct ControllerOracleClient {
     chain_id: usize,
     controller_oracle_address: Address,
-    client: Arc<WsWalletSigner>,
+    client: Arc<WalletSigner>,
     contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
     contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
 }
@@ -41,6 +51,10 @@ impl ControllerOracleClient {
             contract_view_retry_descriptor,
         }
     }
+
+    pub fn get_controller_oracle_address(&self) -> Address {
+        self.controller_oracle_address
+    }
 }
 
 impl<C: Curve> ControllerOracleClientBuilder<C> for GeneralMainChainIdentity {
@@ -65,7 +79,7 @@ impl<C: Curve> ControllerOracleClientBuilder<C> for GeneralRelayedChainIdentity
     }
 }
 
-type ControllerOracleContract = ControllerOracle<WsWalletSigner>;
+type ControllerOracleContract = ControllerOracle<WalletSigner>;
 
 #[async_trait]
 impl ServiceClient<ControllerOracleContract> for ControllerOracleClient {
@@ -101,6 +115,62 @@ impl ControllerOracleTransactions for ControllerOracleClient {
         )
         .await
     }
+
+    async fn relay_group(
+        &self,
+        relayer_group_index: usize,
+        controller_global_epoch: usize,
+        encoded_group: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> ContractClientResult<TransactionReceipt> {
+        let controller_oracle_contract =
+            ServiceClient::<ControllerOracleContract>::prepare_service_client(self).await?;
+
+        let group = decode_group(&encoded_group).map_err(anyhow::Error::from)?;
+
+        let sig = U256::from(signature.as_slice());
+
+        let call = controller_oracle_contract.relay_group(
+            relayer_group_index.into(),
+            controller_global_epoch.into(),
+            group,
+            sig,
+        );
+
+        ControllerOracleClient::call_contract_transaction(
+            self.chain_id,
+            "relay_group",
+            controller_oracle_contract.client_ref(),
+            call,
+            self.contract_transaction_retry_descriptor,
+            false,
+        )
+        .await
+    }
+
+    async fn confirm_group_relay(
+        &self,
+        group_relay_cache_index: usize,
+        signature: Vec<u8>,
+    ) -> ContractClientResult<TransactionReceipt> {
+        let controller_oracle_contract =
+            ServiceClient::<ControllerOracleContract>::prepare_service_client(self).await?;
+
+        let sig = U256::from(signature.as_slice());
+
+        let call =
+            controller_oracle_contract.confirm_group_relay(group_relay_cache_index.into(), sig);
+
+        ControllerOracleClient::call_contract_transaction(
+            self.chain_id,
+            "confirm_group_relay",
+            controller_oracle_contract.client_ref(),
+            call,
+            self.contract_transaction_retry_descriptor,
+            false,
+        )
+        .await
+    }
 }
 
 #[async_trait]
@@ -118,6 +188,115 @@ impl<C: Curve> ControllerOracleViews<C> for ControllerOracleClient {
         .await
         .map(parse_contract_group)
     }
+
+    async fn get_group_at(
+        &self,
+        group_index: usize,
+        block_number: u64,
+    ) -> ContractClientResult<Group<C>> {
+        let controller_oracle_contract =
+            ServiceClient::<ControllerOracleContract>::prepare_service_client(self).await?;
+
+        ControllerOracleClient::call_contract_view(
+            self.chain_id,
+            "get_group",
+            controller_oracle_contract
+                .get_group(group_index.into())
+                .block(block_number),
+            self.contract_view_retry_descriptor,
+        )
+        .await
+        .map(parse_contract_group)
+    }
+
+    async fn is_group_relay_requested(
+        &self,
+        group_index: usize,
+        group_epoch: usize,
+    ) -> ContractClientResult<bool> {
+        let controller_oracle_contract =
+            ServiceClient::<ControllerOracleContract>::prepare_service_client(self).await?;
+
+        ControllerOracleClient::call_contract_view(
+            self.chain_id,
+            "is_group_relay_requested",
+            controller_oracle_contract
+                .is_group_relay_requested(group_index.into(), group_epoch.into()),
+            self.contract_view_retry_descriptor,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl ControllerOracleLogs for ControllerOracleClient {
+    async fn subscribe_group_relay_confirmation_task<
+        C: FnMut(GroupRelayConfirmationTask) -> F + Send,
+        F: Future<Output = ContractClientResult<()>> + Send,
+    >(
+        &self,
+        mut cb: C,
+    ) -> ContractClientResult<()> {
+        let contract = ControllerOracle::new(self.controller_oracle_address, self.client.clone());
+
+        let provider = self.client.provider();
+
+        let mut handle_log = |evt: GroupRelayConfirmationTaskFilter, meta: LogMeta| {
+            let GroupRelayConfirmationTaskFilter {
+                group_relay_cache_index,
+                relayed_group_index,
+                relayed_group_epoch,
+                relayer_group_index,
+            } = evt;
+
+            info!(
+                "Received group relay confirmation task: chain_id: {}, group_relay_cache_index: {}, relayed_group_index: {}, relayed_group_epoch: {}, relayer_group_index: {}, block_number: {}",
+                self.chain_id, group_relay_cache_index, relayed_group_index, relayed_group_epoch, relayer_group_index, meta.block_number
+            );
+
+            cb(GroupRelayConfirmationTask::new(
+                group_relay_cache_index.as_usize(),
+                relayed_group_index.as_usize(),
+                relayed_group_epoch.as_usize(),
+                relayer_group_index.as_usize(),
+                meta.block_number.as_usize(),
+            ))
+        };
+
+        if !provider.as_ref().supports_subscription() {
+            let filter = contract.event::<GroupRelayConfirmationTaskFilter>().filter;
+            let from_block = provider.get_block_number().await?.as_usize() + 1;
+
+            return poll_logs_with_meta(
+                provider,
+                &filter,
+                from_block,
+                skip_removed_logs(handle_log),
+                skip_checkpoint,
+            )
+            .await;
+        }
+
+        let events = contract
+            .event::<GroupRelayConfirmationTaskFilter>()
+            .from_block(BlockNumber::Latest);
+
+        let mut stream = events.subscribe().await?.with_meta();
+
+        while let Some(Ok((evt, meta))) = stream.next().await {
+            handle_log(evt, meta).await?;
+        }
+        Err(ContractClientError::FetchingGroupRelayConfirmationTaskError)
+    }
+}
+
+/// Decodes the group encoded as `abi.encode(group)` in Solidity.
+fn decode_group(encoded_group: &[u8]) -> Result<ContractGroup, AbiError> {
+    let token = decode(&[ContractGroup::param_type()], encoded_group)?
+        .pop()
+        .ok_or(AbiError::DecodingError(ethers::abi::Error::InvalidData))?;
+
+    Ok(ContractGroup::from_token(token)?)
 }
 
 fn parse_contract_group<C: Curve>(cg: ContractGroup) -> Group<C> {
@@ -190,3 +369,30 @@ fn parse_contract_group<C: Curve>(cg: ContractGroup) -> Group<C> {
         c: PhantomData,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::decode_group;
+    use crate::ethers::controller::tests::{build_group_fixture, ENCODED_GROUP_FIXTURE};
+
+    #[test]
+    fn test_decode_group_from_solidity_abi_encode() {
+        let encoded_group = ethers::utils::hex::decode(ENCODED_GROUP_FIXTURE.concat()).unwrap();
+
+        let group = decode_group(&encoded_group).unwrap();
+        let expected = build_group_fixture();
+
+        assert_eq!(expected.index, group.index);
+        assert_eq!(expected.epoch, group.epoch);
+        assert_eq!(expected.members.len(), group.members.len());
+        assert_eq!(
+            expected.members[0].partial_public_key,
+            group.members[0].partial_public_key
+        );
+        assert_eq!(expected.committers, group.committers);
+        assert!(group.is_strictly_majority_consensus_reached);
+        assert_eq!(expected.public_key, group.public_key);
+
+        assert!(decode_group(&encoded_group[32..]).is_err());
+    }
+}
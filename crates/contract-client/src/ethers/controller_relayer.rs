@@ -1,21 +1,26 @@
 use crate::{
-    contract_stub::controller_relayer::ControllerRelayer,
-    controller_relayer::{ControllerRelayerClientBuilder, ControllerRelayerTransactions},
-    error::ContractClientResult,
+    contract_stub::controller_relayer::{ControllerRelayer, GroupRelayTaskFilter},
+    controller_relayer::{
+        ControllerRelayerClientBuilder, ControllerRelayerLogs, ControllerRelayerTransactions,
+        GroupRelayTaskLog,
+    },
+    error::{ContractClientError, ContractClientResult},
+    ethers::provider::{poll_logs_with_meta, skip_checkpoint, skip_removed_logs},
     ServiceClient, TransactionCaller,
 };
 use arpa_core::{
     ChainIdentity, ExponentialBackoffRetryDescriptor, GeneralMainChainIdentity,
-    GeneralRelayedChainIdentity, MainChainIdentity, WsWalletSigner,
+    GeneralRelayedChainIdentity, MainChainIdentity, WalletSigner,
 };
 use async_trait::async_trait;
 use ethers::prelude::*;
-use std::sync::Arc;
+use log::info;
+use std::{future::Future, sync::Arc};
 
 pub struct ControllerRelayerClient {
     chain_id: usize,
     controller_relayer_address: Address,
-    client: Arc<WsWalletSigner>,
+    client: Arc<WalletSigner>,
     contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
 }
 
@@ -56,7 +61,7 @@ impl ControllerRelayerClientBuilder for GeneralRelayedChainIdentity {
     }
 }
 
-type ControllerRelayerContract = ControllerRelayer<WsWalletSigner>;
+type ControllerRelayerContract = ControllerRelayer<WalletSigner>;
 
 #[async_trait]
 impl ServiceClient<ControllerRelayerContract> for ControllerRelayerClient {
@@ -93,4 +98,84 @@ impl ControllerRelayerTransactions for ControllerRelayerClient {
         )
         .await
     }
+
+    async fn request_group_relay(
+        &self,
+        group_index: usize,
+    ) -> ContractClientResult<TransactionReceipt> {
+        let controller_relayer_contract =
+            ServiceClient::<ControllerRelayerContract>::prepare_service_client(self).await?;
+
+        let call = controller_relayer_contract.request_group_relay(group_index.into());
+
+        ControllerRelayerClient::call_contract_transaction(
+            self.chain_id,
+            "request_group_relay",
+            controller_relayer_contract.client_ref(),
+            call,
+            self.contract_transaction_retry_descriptor,
+            false,
+        )
+        .await
+    }
+}
+
+#[async_trait]
+impl ControllerRelayerLogs for ControllerRelayerClient {
+    async fn subscribe_group_relay_task<
+        C: FnMut(GroupRelayTaskLog) -> F + Send,
+        F: Future<Output = ContractClientResult<()>> + Send,
+    >(
+        &self,
+        mut cb: C,
+    ) -> ContractClientResult<()> {
+        let contract = ControllerRelayer::new(self.controller_relayer_address, self.client.clone());
+
+        let provider = self.client.provider();
+
+        let mut handle_log = |evt: GroupRelayTaskFilter, meta: LogMeta| {
+            let GroupRelayTaskFilter {
+                controller_global_epoch,
+                relayed_group_index,
+                relayed_group_epoch,
+            } = evt;
+
+            info!(
+                "Received group relay task: controller_global_epoch: {}, relayed_group_index: {}, relayed_group_epoch: {}, block_number: {}",
+                controller_global_epoch, relayed_group_index, relayed_group_epoch, meta.block_number
+            );
+
+            cb(GroupRelayTaskLog {
+                controller_global_epoch: controller_global_epoch.as_usize(),
+                relayed_group_index: relayed_group_index.as_usize(),
+                relayed_group_epoch: relayed_group_epoch.as_usize(),
+                assignment_block_height: meta.block_number.as_usize(),
+            })
+        };
+
+        if !provider.as_ref().supports_subscription() {
+            let filter = contract.event::<GroupRelayTaskFilter>().filter;
+            let from_block = provider.get_block_number().await?.as_usize() + 1;
+
+            return poll_logs_with_meta(
+                provider,
+                &filter,
+                from_block,
+                skip_removed_logs(handle_log),
+                skip_checkpoint,
+            )
+            .await;
+        }
+
+        let events = contract
+            .event::<GroupRelayTaskFilter>()
+            .from_block(BlockNumber::Latest);
+
+        let mut stream = events.subscribe().await?.with_meta();
+
+        while let Some(Ok((evt, meta))) = stream.next().await {
+            handle_log(evt, meta).await?;
+        }
+        Err(ContractClientError::FetchingGroupRelayTaskError)
+    }
 }
@@ -9,7 +9,7 @@ use crate::{
 use ::core::panic;
 use arpa_core::{
     ChainIdentity, ExponentialBackoffRetryDescriptor, GeneralMainChainIdentity,
-    GeneralRelayedChainIdentity, WsWalletSigner,
+    GeneralRelayedChainIdentity, WalletSigner,
 };
 use async_trait::async_trait;
 use dkg_core::{
@@ -24,7 +24,7 @@ use threshold_bls::group::Curve;
 pub struct CoordinatorClient {
     chain_id: usize,
     coordinator_address: Address,
-    client: Arc<WsWalletSigner>,
+    client: Arc<WalletSigner>,
     contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
     contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
 }
@@ -69,7 +69,7 @@ impl<C: Curve + 'static> CoordinatorClientBuilder<C> for GeneralRelayedChainIden
     }
 }
 
-type CoordinatorContract = Coordinator<WsWalletSigner>;
+type CoordinatorContract = Coordinator<WalletSigner>;
 
 #[async_trait]
 impl ServiceClient<CoordinatorContract> for CoordinatorClient {
@@ -226,14 +226,16 @@ impl<C: Curve + 'static> BoardPublisher<C> for CoordinatorClient {
 
 #[cfg(test)]
 pub mod coordinator_tests {
-    use super::{CoordinatorClient, WsWalletSigner};
+    use super::{CoordinatorClient, WalletSigner};
     use crate::contract_stub::coordinator::Coordinator;
     use crate::coordinator::CoordinatorTransactions;
     use crate::error::ContractClientError;
     use arpa_core::build_client;
     use arpa_core::eip1559_gas_price_estimator;
+    use arpa_core::ChainTransport;
     use arpa_core::Config;
     use arpa_core::GeneralMainChainIdentity;
+    use arpa_core::ProviderEndpoints;
     use ethers::abi::Tokenize;
     use ethers::prelude::ContractError::Revert;
     use ethers::prelude::*;
@@ -261,16 +263,16 @@ pub mod coordinator_tests {
         Anvil::new().chain_id(1u64).mnemonic(PHRASE).spawn()
     }
 
-    async fn deploy_contract(anvil: &AnvilInstance) -> Coordinator<WsWalletSigner> {
+    async fn deploy_contract(anvil: &AnvilInstance) -> Coordinator<WalletSigner> {
         // 2. instantiate our wallet
         let wallet: LocalWallet = anvil.keys()[0].clone().into();
 
         // 3. connect to the network
         let provider = Arc::new(
-            Provider::<Ws>::connect(anvil.ws_endpoint())
-                .await
-                .unwrap()
-                .interval(Duration::from_millis(3000)),
+            Provider::new(ChainTransport::from(
+                Ws::connect(anvil.ws_endpoint()).await.unwrap(),
+            ))
+            .interval(Duration::from_millis(3000)),
         );
 
         // 4. instantiate the client with the wallet
@@ -342,17 +344,17 @@ pub mod coordinator_tests {
         call.send().await.unwrap();
 
         let provider = Arc::new(
-            Provider::<Ws>::connect(anvil.ws_endpoint())
-                .await
-                .unwrap()
-                .interval(Duration::from_millis(3000)),
+            Provider::new(ChainTransport::from(
+                Ws::connect(anvil.ws_endpoint()).await.unwrap(),
+            ))
+            .interval(Duration::from_millis(3000)),
         );
 
         let main_chain_identity = GeneralMainChainIdentity::new(
             anvil.chain_id() as usize,
             wallet,
             provider,
-            anvil.ws_endpoint(),
+            ProviderEndpoints::new(vec![anvil.ws_endpoint()], 0),
             Address::random(),
             Address::random(),
             Address::random(),
@@ -378,7 +380,7 @@ pub mod coordinator_tests {
 
         let res = client.publish(mock_value.clone()).await;
         assert!(res.is_err());
-        if let ContractClientError::WsContractError(Revert(bytes)) = res.unwrap_err() {
+        if let ContractClientError::WalletContractError(Revert(bytes)) = res.unwrap_err() {
             let error_msg = String::decode_with_selector(&bytes).unwrap();
             assert_eq!("share existed", error_msg);
         } else {
@@ -10,7 +10,7 @@ use crate::{
 use crate::{TransactionCaller, ViewCaller};
 use arpa_core::{
     ChainIdentity, ExponentialBackoffRetryDescriptor, GeneralMainChainIdentity,
-    GeneralRelayedChainIdentity, Node, WsWalletSigner,
+    GeneralRelayedChainIdentity, Node, WalletSigner,
 };
 use async_trait::async_trait;
 use ethers::{core::rand::Rng, prelude::*};
@@ -20,7 +20,7 @@ pub struct NodeRegistryClient {
     chain_id: usize,
     id_address: Address,
     node_registry_address: Address,
-    client: Arc<WsWalletSigner>,
+    client: Arc<WalletSigner>,
     contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
     contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
 }
@@ -106,7 +106,7 @@ impl NodeRegistryClientBuilder for GeneralRelayedChainIdentity {
     }
 }
 
-type NodeRegistryContract = NodeRegistry<WsWalletSigner>;
+type NodeRegistryContract = NodeRegistry<WalletSigner>;
 
 #[async_trait]
 impl ServiceClient<NodeRegistryContract> for NodeRegistryClient {
@@ -1,28 +1,247 @@
 use crate::{
     error::{ContractClientError, ContractClientResult},
-    provider::BlockFetcher,
+    provider::{BlockFetcher, BlockHeader},
+};
+use arpa_core::{
+    ChainTransport, BLOCK_HASH_HISTORY_LENGTH, LOG_POLLING_REORG_WINDOW, LOG_QUERY_BLOCK_RANGE,
 };
 use async_trait::async_trait;
-use ethers::prelude::*;
+use ethers::{abi::RawLog, prelude::*};
+use futures::future::{ready, Either, Ready};
 use std::future::Future;
 
 #[async_trait]
-impl BlockFetcher for Provider<Ws> {
-    async fn subscribe_new_block_height<
-        C: FnMut(usize) -> F + Send,
+impl BlockFetcher for Provider<ChainTransport> {
+    async fn subscribe_new_block<
+        C: FnMut(BlockHeader) -> F + Send,
         F: Future<Output = ContractClientResult<()>> + Send,
     >(
         &self,
         mut cb: C,
     ) -> ContractClientResult<()> {
+        if !self.as_ref().supports_subscription() {
+            // fall back to polling eth_blockNumber by the interval of the provider, every block
+            // since the last one is fetched so that a reorg is told by the parent hashes
+            let mut last_block_height: Option<usize> = None;
+            loop {
+                let block_height = self.get_block_number().await?.as_usize();
+
+                let from_block_height = match last_block_height {
+                    Some(last_block_height) => (last_block_height + 1)
+                        .max(block_height.saturating_sub(BLOCK_HASH_HISTORY_LENGTH - 1)),
+                    None => block_height,
+                };
+
+                for height in from_block_height..=block_height {
+                    let block = self
+                        .get_block(height as u64)
+                        .await?
+                        .ok_or(ContractClientError::FetchingBlockError)?;
+                    cb(block_header(&block)?).await?;
+                    last_block_height = Some(height);
+                }
+
+                tokio::time::sleep(self.get_interval()).await;
+            }
+        }
+
         let mut stream = self.subscribe_blocks().await?;
         while let Some(block) = stream.next().await {
-            cb(block
-                .number
-                .ok_or(ContractClientError::FetchingBlockError)?
-                .as_usize())
-            .await?;
+            cb(block_header(&block)?).await?;
         }
         Err(ContractClientError::FetchingBlockError)
     }
 }
+
+fn block_header(block: &Block<TxHash>) -> ContractClientResult<BlockHeader> {
+    Ok(BlockHeader {
+        block_height: block
+            .number
+            .ok_or(ContractClientError::FetchingBlockError)?
+            .as_usize(),
+        block_hash: block.hash.ok_or(ContractClientError::FetchingBlockError)?,
+        parent_hash: block.parent_hash,
+    })
+}
+
+/// Queries the logs matching the filter from `from_block` to `to_block` with eth_getLogs.
+pub(crate) async fn query_logs_with_meta<D: EthLogDecode>(
+    provider: &Provider<ChainTransport>,
+    filter: &Filter,
+    from_block: usize,
+    to_block: usize,
+) -> ContractClientResult<Vec<(D, LogMeta)>> {
+    provider
+        .get_logs(&filter.clone().from_block(from_block).to_block(to_block))
+        .await?
+        .into_iter()
+        .map(|log| {
+            let meta = LogMeta::from(&log);
+            let evt = D::decode_log(&RawLog::from(log)).map_err(anyhow::Error::from)?;
+            Ok((evt, meta))
+        })
+        .collect()
+}
+
+type LogWithMeta<D> = (D, LogMeta);
+
+fn is_same_log(a: &LogMeta, b: &LogMeta) -> bool {
+    a.block_hash == b.block_hash
+        && a.transaction_hash == b.transaction_hash
+        && a.log_index == b.log_index
+}
+
+/// Compares the logs of the same blocks queried again with the ones handled before,
+/// returns the logs which are removed and the ones which are new, e.g. the requests
+/// included again in the blocks replacing the orphaned ones.
+fn diff_logs<D: Clone>(
+    handled_logs: &[LogWithMeta<D>],
+    logs: &[LogWithMeta<D>],
+) -> (Vec<LogWithMeta<D>>, Vec<LogWithMeta<D>>) {
+    let removed_logs = handled_logs
+        .iter()
+        .filter(|(_, meta)| !logs.iter().any(|(_, m)| is_same_log(m, meta)))
+        .cloned()
+        .collect();
+
+    let new_logs = logs
+        .iter()
+        .filter(|(_, meta)| !handled_logs.iter().any(|(_, m)| is_same_log(m, meta)))
+        .cloned()
+        .collect();
+
+    (removed_logs, new_logs)
+}
+
+/// Polls the logs matching the filter from `from_block` on by the interval of the provider,
+/// for the transports which don't support subscriptions. `cb` is called with true as the
+/// last argument for the logs removed by a reorg, which are found by querying the latest
+/// `LOG_POLLING_REORG_WINDOW` blocks again. `checkpoint` is called with the last block of
+/// each queried range once its logs have been handled.
+pub(crate) async fn poll_logs_with_meta<
+    D: EthLogDecode + Clone,
+    C: FnMut(D, LogMeta, bool) -> F + Send,
+    F: Future<Output = ContractClientResult<()>> + Send,
+    P: FnMut(usize) -> G + Send,
+    G: Future<Output = ContractClientResult<()>> + Send,
+>(
+    provider: &Provider<ChainTransport>,
+    filter: &Filter,
+    from_block: usize,
+    mut cb: C,
+    mut checkpoint: P,
+) -> ContractClientResult<()> {
+    let mut next_block = from_block;
+    // the logs handled in the latest blocks, to be compared with the ones queried again
+    let mut recent_logs: Vec<(D, LogMeta)> = vec![];
+
+    loop {
+        let latest_block = provider.get_block_number().await?.as_usize();
+
+        if latest_block >= next_block {
+            let recheck_from_block = next_block
+                .saturating_sub(LOG_POLLING_REORG_WINDOW)
+                .max(from_block);
+
+            if recheck_from_block < next_block {
+                let logs: Vec<(D, LogMeta)> =
+                    query_logs_with_meta(provider, filter, recheck_from_block, next_block - 1)
+                        .await?;
+
+                let (removed_logs, new_logs) = diff_logs(&recent_logs, &logs);
+
+                for (evt, meta) in removed_logs {
+                    cb(evt, meta, true).await?;
+                }
+
+                for (evt, meta) in new_logs {
+                    cb(evt, meta, false).await?;
+                }
+
+                recent_logs = logs;
+            }
+
+            let mut start = next_block;
+            while start <= latest_block {
+                let end = latest_block.min(start + LOG_QUERY_BLOCK_RANGE - 1);
+
+                let logs: Vec<(D, LogMeta)> =
+                    query_logs_with_meta(provider, filter, start, end).await?;
+
+                for (evt, meta) in logs {
+                    cb(evt.clone(), meta.clone(), false).await?;
+                    recent_logs.push((evt, meta));
+                }
+
+                checkpoint(end).await?;
+
+                start = end + 1;
+            }
+
+            next_block = latest_block + 1;
+
+            recent_logs.retain(|(_, meta)| {
+                meta.block_number.as_usize() >= next_block.saturating_sub(LOG_POLLING_REORG_WINDOW)
+            });
+        }
+
+        tokio::time::sleep(provider.get_interval()).await;
+    }
+}
+
+/// Wraps a log callback which doesn't handle the logs removed by reorgs, so that they are skipped.
+pub(crate) fn skip_removed_logs<D, C: FnMut(D, LogMeta) -> F, F>(
+    mut cb: C,
+) -> impl FnMut(D, LogMeta, bool) -> Either<F, Ready<ContractClientResult<()>>> {
+    move |evt, meta, removed| {
+        if removed {
+            Either::Right(ready(Ok(())))
+        } else {
+            Either::Left(cb(evt, meta))
+        }
+    }
+}
+
+/// A checkpoint callback for the logs whose progress is not saved.
+pub(crate) async fn skip_checkpoint(_block_height: usize) -> ContractClientResult<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+pub mod provider_tests {
+    use super::diff_logs;
+    use ethers::{
+        contract::LogMeta,
+        types::{Address, H256, U256, U64},
+    };
+
+    fn build_log(block_number: u64, block_hash: u64, log_index: u64) -> (u64, LogMeta) {
+        (
+            log_index,
+            LogMeta {
+                address: Address::zero(),
+                block_number: U64::from(block_number),
+                block_hash: H256::from_low_u64_be(block_hash),
+                transaction_hash: H256::from_low_u64_be(log_index),
+                transaction_index: U64::zero(),
+                log_index: U256::from(log_index),
+            },
+        )
+    }
+
+    #[test]
+    fn test_diff_logs() {
+        let handled_logs = vec![build_log(10, 10, 1), build_log(11, 11, 2)];
+
+        // nothing changed
+        let (removed_logs, new_logs) = diff_logs(&handled_logs, &handled_logs.clone());
+        assert!(removed_logs.is_empty());
+        assert!(new_logs.is_empty());
+
+        // block 11 is replaced, the request of it is included again in block 12
+        let logs = vec![build_log(10, 10, 1), build_log(12, 112, 2)];
+        let (removed_logs, new_logs) = diff_logs(&handled_logs, &logs);
+        assert_eq!(vec![handled_logs[1].clone()], removed_logs);
+        assert_eq!(vec![logs[1].clone()], new_logs);
+    }
+}
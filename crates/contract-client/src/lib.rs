@@ -5,8 +5,8 @@ use ::ethers::prelude::ContractError;
 use ::ethers::providers::{Middleware, ProviderError};
 use ::ethers::types::{BlockNumber, TransactionReceipt, U64};
 use arpa_core::{
-    eip1559_gas_price_estimator, fallback_eip1559_gas_price_estimator, jitter, supports_eip1559,
-    ExponentialBackoffRetryDescriptor,
+    eip1559_gas_price_estimator, fallback_eip1559_gas_price_estimator, jitter,
+    metrics::record_transaction_cost, supports_eip1559, ExponentialBackoffRetryDescriptor,
 };
 use async_trait::async_trait;
 use error::ContractClientResult;
@@ -116,6 +116,14 @@ pub trait TransactionCaller {
                     })?
                     .ok_or(ContractClientError::NoTransactionReceipt)?;
 
+                // reverted transactions are paid for as well
+                record_transaction_cost(
+                    chain_id,
+                    info,
+                    receipt.gas_used.unwrap_or_default(),
+                    receipt.effective_gas_price.unwrap_or_default(),
+                );
+
                 if receipt.status == Some(U64::from(0)) {
                     error!(
                         "Transaction failed({}) with chain_id({}), receipt: {:?}",
@@ -296,9 +304,24 @@ pub mod controller {
     pub trait ControllerViews<C: Curve> {
         async fn get_group(&self, group_index: usize) -> ContractClientResult<Group<C>>;
 
+        /// Gets the group as it was at the given block.
+        async fn get_group_at(
+            &self,
+            group_index: usize,
+            block_number: u64,
+        ) -> ContractClientResult<Group<C>>;
+
         async fn get_coordinator(&self, group_index: usize) -> ContractClientResult<Address>;
 
         async fn get_node_registry_address(&self) -> ContractClientResult<Address>;
+
+        /// Gets the abi-encoded group as it was at the given block, which is the message signed
+        /// to relay the group.
+        async fn get_encoded_group(
+            &self,
+            group_index: usize,
+            block_number: u64,
+        ) -> ContractClientResult<Vec<u8>>;
     }
 
     #[async_trait]
@@ -325,9 +348,10 @@ pub mod controller {
 
 pub mod controller_oracle {
     use crate::error::ContractClientResult;
-    use arpa_core::Group;
+    use arpa_core::{Group, GroupRelayConfirmationTask};
     use async_trait::async_trait;
     use ethers::types::{Address, TransactionReceipt};
+    use std::future::Future;
     use threshold_bls::group::Curve;
 
     #[async_trait]
@@ -336,15 +360,59 @@ pub mod controller_oracle {
             &self,
             recipient: Address,
         ) -> ContractClientResult<TransactionReceipt>;
+
+        /// Relays the abi-encoded group signed by the relayer group as it was at the global epoch
+        /// of the controller, which is cached until the relayed group confirms it.
+        async fn relay_group(
+            &self,
+            relayer_group_index: usize,
+            controller_global_epoch: usize,
+            encoded_group: Vec<u8>,
+            signature: Vec<u8>,
+        ) -> ContractClientResult<TransactionReceipt>;
+
+        async fn confirm_group_relay(
+            &self,
+            group_relay_cache_index: usize,
+            signature: Vec<u8>,
+        ) -> ContractClientResult<TransactionReceipt>;
     }
 
     #[async_trait]
     pub trait ControllerOracleViews<C: Curve> {
         async fn get_group(&self, group_index: usize) -> ContractClientResult<Group<C>>;
+
+        /// Gets the group as it was at the given block.
+        async fn get_group_at(
+            &self,
+            group_index: usize,
+            block_number: u64,
+        ) -> ContractClientResult<Group<C>>;
+
+        async fn is_group_relay_requested(
+            &self,
+            group_index: usize,
+            group_epoch: usize,
+        ) -> ContractClientResult<bool>;
+    }
+
+    #[async_trait]
+    pub trait ControllerOracleLogs {
+        async fn subscribe_group_relay_confirmation_task<
+            C: FnMut(GroupRelayConfirmationTask) -> F + Send,
+            F: Future<Output = ContractClientResult<()>> + Send,
+        >(
+            &self,
+            cb: C,
+        ) -> ContractClientResult<()>;
     }
 
     pub trait ControllerOracleClientBuilder<C: Curve> {
-        type ControllerOracleService: ControllerOracleViews<C> + Send + Sync;
+        type ControllerOracleService: ControllerOracleTransactions
+            + ControllerOracleViews<C>
+            + ControllerOracleLogs
+            + Send
+            + Sync;
 
         fn build_controller_oracle_client(&self) -> Self::ControllerOracleService;
     }
@@ -354,6 +422,7 @@ pub mod controller_relayer {
     use crate::error::ContractClientResult;
     use async_trait::async_trait;
     use ethers::types::TransactionReceipt;
+    use std::future::Future;
 
     #[async_trait]
     pub trait ControllerRelayerTransactions {
@@ -362,10 +431,39 @@ pub mod controller_relayer {
             chain_id: usize,
             group_index: usize,
         ) -> ContractClientResult<TransactionReceipt>;
+
+        /// Requests the nodes to relay the group to all the relayed chains by threshold signatures.
+        async fn request_group_relay(
+            &self,
+            group_index: usize,
+        ) -> ContractClientResult<TransactionReceipt>;
+    }
+
+    /// A `GroupRelayTask` log, which is fanned out to one task per relayed chain by the node.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct GroupRelayTaskLog {
+        pub controller_global_epoch: usize,
+        pub relayed_group_index: usize,
+        pub relayed_group_epoch: usize,
+        pub assignment_block_height: usize,
+    }
+
+    #[async_trait]
+    pub trait ControllerRelayerLogs {
+        async fn subscribe_group_relay_task<
+            C: FnMut(GroupRelayTaskLog) -> F + Send,
+            F: Future<Output = ContractClientResult<()>> + Send,
+        >(
+            &self,
+            cb: C,
+        ) -> ContractClientResult<()>;
     }
 
     pub trait ControllerRelayerClientBuilder {
-        type ControllerRelayerService: ControllerRelayerTransactions + Send + Sync;
+        type ControllerRelayerService: ControllerRelayerTransactions
+            + ControllerRelayerLogs
+            + Send
+            + Sync;
 
         fn build_controller_relayer_client(&self) -> Self::ControllerRelayerService;
     }
@@ -435,7 +533,7 @@ pub mod adapter {
     use arpa_core::{PartialSignature, RandomnessTask};
     use async_trait::async_trait;
     use ethers::core::types::Address;
-    use ethers::types::{TransactionReceipt, U256};
+    use ethers::types::{TransactionReceipt, H256, U256};
     use std::collections::BTreeMap;
     use std::future::Future;
 
@@ -459,15 +557,42 @@ pub mod adapter {
         async fn is_task_pending(&self, request_id: &[u8]) -> ContractClientResult<bool>;
     }
 
+    /// A randomness request log of the adapter.
+    #[derive(Debug, Clone)]
+    pub struct RandomnessTaskLog {
+        pub task: RandomnessTask,
+        /// The hash of the block the request was emitted in.
+        pub block_hash: H256,
+        /// Whether the log was removed because its block was orphaned by a reorg.
+        pub removed: bool,
+    }
+
     #[async_trait]
     pub trait AdapterLogs {
+        /// Replays the randomness requests from `from_block` to the latest block
+        /// before following the new ones, if `from_block` is given. `checkpoint` is called
+        /// with the height of a block once the requests up to it have been handled.
         async fn subscribe_randomness_task<
-            C: FnMut(RandomnessTask) -> F + Send,
+            C: FnMut(RandomnessTaskLog) -> F + Send,
             F: Future<Output = ContractClientResult<()>> + Send,
+            P: FnMut(usize) -> G + Send,
+            G: Future<Output = ContractClientResult<()>> + Send,
         >(
             &self,
+            from_block: Option<usize>,
             cb: C,
+            checkpoint: P,
         ) -> ContractClientResult<()>;
+
+        /// Whether the request is logged in the block at the given height. The block is
+        /// looked up by its hash if given, since the height may point to another block
+        /// after a reorg.
+        async fn is_randomness_task_requested(
+            &self,
+            request_id: &[u8],
+            block_height: usize,
+            block_hash: Option<H256>,
+        ) -> ContractClientResult<bool>;
     }
 
     pub trait AdapterClientBuilder {
@@ -482,13 +607,22 @@ pub mod provider {
     use std::future::Future;
 
     use async_trait::async_trait;
+    use ethers::types::H256;
 
     use crate::error::ContractClientResult;
 
+    /// The header fields of a new block which are needed to follow the chain and its reorgs.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct BlockHeader {
+        pub block_height: usize,
+        pub block_hash: H256,
+        pub parent_hash: H256,
+    }
+
     #[async_trait]
     pub trait BlockFetcher {
-        async fn subscribe_new_block_height<
-            C: FnMut(usize) -> F + Send,
+        async fn subscribe_new_block<
+            C: FnMut(BlockHeader) -> F + Send,
             F: Future<Output = ContractClientResult<()>> + Send,
         >(
             &self,
@@ -4,3 +4,4 @@ mod utils;
 pub use types::*;
 pub use utils::*;
 pub mod log;
+pub mod metrics;
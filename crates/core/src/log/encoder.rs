@@ -17,15 +17,18 @@
 //!     "target": "foo::bar",
 //!     "thread": "main",
 //!     "thread_id": 123,
-//!     "mdc": {
-//!         "request_id": "123e4567-e89b-12d3-a456-426655440000"
-//!     }
+//!     "span": {
+//!         "chain_id": "1",
+//!         "request_id": "0x8b1d..."
+//!     },
+//!     "trace_id": "8b1d...",
 //!     "node_info": {
 //!         
 //!     }
 //! }
 //! ```
 
+use super::trace::{current_span_fields, current_trace_id, SpanFields};
 use chrono::{
     format::{DelayedFormat, Fixed, Item},
     DateTime, Local,
@@ -34,7 +37,7 @@ use lazy_static::lazy_static;
 use log::{Level, Record};
 use log4rs::encode::{Encode, Write};
 use parking_lot::RwLock;
-use serde::ser::{self, Serialize, SerializeMap};
+use serde::ser::{self, Serialize};
 use std::{
     fmt::{self, Debug},
     option, thread,
@@ -102,7 +105,8 @@ impl JsonEncoder {
             thread_id: thread_id::get(),
             node_id: &self.node_id,
             l1_chain_id: self.l1_chain_id,
-            mdc: Mdc,
+            span: current_span_fields(),
+            trace_id: current_trace_id(),
             node_info: &node_info,
             group_info: &group_info,
             version: &self.version,
@@ -137,7 +141,9 @@ struct Message<'a> {
     thread_id: usize,
     node_id: &'a str,
     l1_chain_id: usize,
-    mdc: Mdc,
+    span: SpanFields,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace_id: Option<String>,
     node_info: &'a str,
     group_info: &'a str,
     version: &'a str,
@@ -155,32 +161,12 @@ where
     }
 }
 
-struct Mdc;
-
-impl ser::Serialize for Mdc {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: ser::Serializer,
-    {
-        let mut map = serializer.serialize_map(None)?;
-
-        let mut err = Ok(());
-        log_mdc::iter(|k, v| {
-            if let Ok(()) = err {
-                err = map.serialize_key(k).and_then(|()| map.serialize_value(v));
-            }
-        });
-        err?;
-
-        map.end()
-    }
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::log::{build_general_payload, LogType};
+    use crate::log::{build_general_payload, trace::SpanFieldsLayer, LogType};
     use log4rs::encode::writer::simple::SimpleWriter;
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
 
     #[test]
     fn default() {
@@ -197,31 +183,34 @@ mod test {
         let node_id = "test";
         let l1_chain_id = 1;
         let version = "0.1.0";
-        log_mdc::insert("foo", "bar");
 
         let encoder = JsonEncoder::new(node_id.to_string(), l1_chain_id, version.to_string());
 
         let mut buf = vec![];
-        encoder
-            .encode_inner(
-                &mut SimpleWriter(&mut buf),
-                time,
-                &Record::builder()
-                    .level(level)
-                    .target(target)
-                    .module_path(Some(module_path))
-                    .file(Some(file))
-                    .line(Some(line))
-                    .args(format_args!("{}", message))
-                    .build(),
-            )
-            .unwrap();
+        tracing::subscriber::with_default(Registry::default().with(SpanFieldsLayer), || {
+            let _span = tracing::info_span!("test", foo = "bar").entered();
+
+            encoder
+                .encode_inner(
+                    &mut SimpleWriter(&mut buf),
+                    time,
+                    &Record::builder()
+                        .level(level)
+                        .target(target)
+                        .module_path(Some(module_path))
+                        .file(Some(file))
+                        .line(Some(line))
+                        .args(format_args!("{}", message))
+                        .build(),
+                )
+                .unwrap();
+        });
 
         let expected = format!(
             "{{\"time\":\"{}\",\"message\":\"{}\",\"module_path\":\"{}\",\
              \"file\":\"{}\",\"line\":{},\"level\":\"{}\",\"target\":\"{}\",\
              \"thread\":\"{}\",\"thread_id\":{},\"node_id\":\"{}\",\"l1_chain_id\":{},\
-             \"mdc\":{{\"foo\":\"bar\"}},\
+             \"span\":{{\"foo\":\"bar\"}},\
              \"node_info\":\"\",\"group_info\":\"\",\"version\":\"{}\"}}",
             time.to_rfc3339(),
             message,
@@ -258,31 +247,34 @@ mod test {
         let node_id = "test";
         let l1_chain_id = 1;
         let version = "0.1.0";
-        log_mdc::insert("foo", "bar");
 
         let encoder = JsonEncoder::new(node_id.to_string(), l1_chain_id, version.to_string());
 
         let mut buf = vec![];
-        encoder
-            .encode_inner(
-                &mut SimpleWriter(&mut buf),
-                time,
-                &Record::builder()
-                    .level(level)
-                    .target(target)
-                    .module_path(Some(module_path))
-                    .file(Some(file))
-                    .line(Some(line))
-                    .args(format_args!("{}", message))
-                    .build(),
-            )
-            .unwrap();
+        tracing::subscriber::with_default(Registry::default().with(SpanFieldsLayer), || {
+            let _span = tracing::info_span!("test", foo = "bar").entered();
+
+            encoder
+                .encode_inner(
+                    &mut SimpleWriter(&mut buf),
+                    time,
+                    &Record::builder()
+                        .level(level)
+                        .target(target)
+                        .module_path(Some(module_path))
+                        .file(Some(file))
+                        .line(Some(line))
+                        .args(format_args!("{}", message))
+                        .build(),
+                )
+                .unwrap();
+        });
 
         let expected = format!(
             "{{\"time\":\"{}\",\"message\":{},\"module_path\":\"{}\",\
              \"file\":\"{}\",\"line\":{},\"level\":\"{}\",\"target\":\"{}\",\
              \"thread\":\"{}\",\"thread_id\":{},\"node_id\":\"{}\",\"l1_chain_id\":{},\
-             \"mdc\":{{\"foo\":\"bar\"}},\
+             \"span\":{{\"foo\":\"bar\"}},\
              \"node_info\":\"\",\"group_info\":\"\",\"version\":\"{}\"}}",
             time.to_rfc3339(),
             message,
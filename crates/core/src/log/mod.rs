@@ -3,15 +3,26 @@ use crate::ser_u256_in_dec_string;
 use crate::Group;
 use crate::TaskType;
 use ethers_core::types::{Address, H256, U256};
+use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::fmt::Display;
 use threshold_bls::group::Curve;
 use threshold_bls::serialize::point_to_hex;
+use tokio::sync::broadcast;
 
 pub mod encoder;
+pub mod trace;
 
-#[derive(Serialize, Deserialize, Debug)]
+/// The receipts which are not received by a lagging subscriber in time are dropped.
+const TRANSACTION_RECEIPT_FEED_CAPACITY: usize = 256;
+
+lazy_static! {
+    static ref TRANSACTION_RECEIPT_FEED: broadcast::Sender<TransactionReceiptEvent> =
+        broadcast::channel(TRANSACTION_RECEIPT_FEED_CAPACITY).0;
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogType {
     DKGKeyGenerated,
     NodeRegistered,
@@ -19,6 +30,8 @@ pub enum LogType {
     NodeActivated,
     NodeActivationFailed,
     TaskReceived,
+    TaskRescheduled,
+    TaskWithdrawn,
     DKGGroupingStarted,
     DKGGroupingFinished,
     DKGGroupingAborted,
@@ -37,9 +50,13 @@ pub enum LogType {
     PartialSignatureSendingFailed,
     AggregatedSignatureFinished,
     AggregatedSignatureFailed,
+    AggregatedSignatureDiscardedPartials,
+    AggregatedSignatureUnverified,
     FulfillmentFinished,
     FulfillmentFailed,
     ListenerInterrupted,
+    ConfigReloaded,
+    ConfigReloadFailed,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -122,6 +139,50 @@ pub struct TransactionReceiptLog {
     pub effective_gas_price: U256,
 }
 
+/// A transaction receipt logged by the node, along with the group or the task it is sent for.
+#[derive(Debug, Clone)]
+pub struct TransactionReceiptEvent {
+    pub log_type: LogType,
+    pub message: String,
+    pub chain_id: Option<usize>,
+    pub group_index: Option<usize>,
+    pub group_epoch: Option<usize>,
+    pub request_id: Option<Vec<u8>>,
+    pub transaction_hash: H256,
+    pub gas_used: U256,
+    pub effective_gas_price: U256,
+}
+
+/// Subscribes to the transaction receipts of the node. Every payload built with a receipt is
+/// published to the subscribers, so they receive the same receipts as the logs.
+pub fn subscribe_transaction_receipts() -> broadcast::Receiver<TransactionReceiptEvent> {
+    TRANSACTION_RECEIPT_FEED.subscribe()
+}
+
+fn publish_transaction_receipt(payload: &Payload) {
+    if TRANSACTION_RECEIPT_FEED.receiver_count() == 0 {
+        return;
+    }
+
+    if let Some(receipt) = &payload.transaction_receipt_log {
+        // there is nobody to notify if all the subscribers have just gone
+        let _ = TRANSACTION_RECEIPT_FEED.send(TransactionReceiptEvent {
+            log_type: payload.log_type,
+            message: payload.message.to_string(),
+            chain_id: payload.chain_id,
+            group_index: payload.group_log.as_ref().map(|group| group.index),
+            group_epoch: payload.group_log.as_ref().map(|group| group.epoch),
+            request_id: payload
+                .task_log
+                .as_ref()
+                .map(|task| task.request_id.to_vec()),
+            transaction_hash: receipt.transaction_hash,
+            gas_used: receipt.gas_used,
+            effective_gas_price: receipt.effective_gas_price,
+        });
+    }
+}
+
 pub fn build_general_payload(log_type: LogType, message: &str, chain_id: Option<usize>) -> Payload {
     Payload {
         log_type,
@@ -181,7 +242,7 @@ pub fn build_transaction_receipt_payload(
     gas_used: U256,
     effective_gas_price: U256,
 ) -> Payload {
-    Payload {
+    let payload = Payload {
         log_type,
         message,
         chain_id: Some(chain_id),
@@ -192,7 +253,11 @@ pub fn build_transaction_receipt_payload(
             gas_used,
             effective_gas_price,
         }),
-    }
+    };
+
+    publish_transaction_receipt(&payload);
+
+    payload
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -209,7 +274,7 @@ pub fn build_group_related_transaction_receipt_payload<'a, C: Curve>(
     let mut group_log: GroupLog = group.into();
     group_log.relayed_chain_id = relayed_chain_id;
 
-    Payload {
+    let payload = Payload {
         log_type,
         message,
         chain_id: Some(chain_id),
@@ -220,7 +285,11 @@ pub fn build_group_related_transaction_receipt_payload<'a, C: Curve>(
             gas_used,
             effective_gas_price,
         }),
-    }
+    };
+
+    publish_transaction_receipt(&payload);
+
+    payload
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -235,7 +304,7 @@ pub fn build_task_related_transaction_receipt_payload<'a>(
     gas_used: U256,
     effective_gas_price: U256,
 ) -> Payload<'a> {
-    Payload {
+    let payload = Payload {
         log_type,
         message,
         chain_id: Some(chain_id),
@@ -251,7 +320,11 @@ pub fn build_task_related_transaction_receipt_payload<'a>(
             gas_used,
             effective_gas_price,
         }),
-    }
+    };
+
+    publish_transaction_receipt(&payload);
+
+    payload
 }
 
 #[cfg(test)]
@@ -291,4 +364,44 @@ mod test {
         );
         println!("{}", payload);
     }
+
+    #[test]
+    fn test_publish_transaction_receipts() {
+        let mut receipts = subscribe_transaction_receipts();
+
+        let request_id = vec![7; 32];
+
+        build_task_related_payload(
+            LogType::FulfillmentFailed,
+            "Fulfillment failed without a receipt",
+            1,
+            &request_id,
+            TaskType::BLS(BLSTaskType::Randomness),
+            json!({}),
+            None,
+        );
+
+        build_task_related_transaction_receipt_payload(
+            LogType::FulfillmentFinished,
+            "Fulfillment with the receipt",
+            1,
+            &request_id,
+            TaskType::BLS(BLSTaskType::Randomness),
+            json!({}),
+            H256::repeat_byte(1),
+            U256::from(21000),
+            U256::one(),
+        );
+
+        // other tests may build receipts at the same time
+        let receipt = std::iter::from_fn(|| receipts.try_recv().ok())
+            .find(|receipt| receipt.request_id.as_ref() == Some(&request_id))
+            .unwrap();
+
+        assert_eq!(LogType::FulfillmentFinished, receipt.log_type);
+        assert_eq!("Fulfillment with the receipt", receipt.message);
+        assert_eq!(Some(1), receipt.chain_id);
+        assert_eq!(H256::repeat_byte(1), receipt.transaction_hash);
+        assert_eq!(U256::from(21000), receipt.gas_used);
+    }
 }
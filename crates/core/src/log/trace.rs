@@ -0,0 +1,268 @@
+//! Tracing spans of the node.
+//!
+//! Unlike the thread-local MDC, the fields of a span follow the task which is instrumented by it
+//! when the task hops between the threads of the runtime, so they are written with every log
+//! inside the span by the [`JsonEncoder`](super::encoder::JsonEncoder). The spans can also be
+//! exported to an OpenTelemetry collector over OTLP.
+//!
+//! The spans of a BLS task are parented to a trace context derived from the request id of the
+//! task, so the spans of the same task on all the members of the group end up in one trace.
+
+use crate::TracingDescriptor;
+use opentelemetry::{
+    global,
+    trace::{SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState},
+    Context, KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::{propagation::TraceContextPropagator, runtime, trace, Resource};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::{collections::BTreeMap, fmt};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Level, Span, Subscriber,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{
+    filter::Targets,
+    layer::{Context as LayerContext, SubscriberExt},
+    registry::LookupSpan,
+    Layer, Registry,
+};
+
+/// The crates whose spans are recorded.
+const TRACED_TARGETS: [&str; 8] = [
+    "node_client",
+    "arpa_node",
+    "arpa_core",
+    "arpa_contract_client",
+    "arpa_dal",
+    "arpa_sea_orm_db",
+    "arpa_sqlite_db",
+    "arpa_postgres_db",
+];
+
+/// The fields recorded by a span and all its parents.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanFields(BTreeMap<&'static str, String>);
+
+impl SpanFields {
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0.get(name).map(|value| value.as_str())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Visit for SpanFields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(field.name(), format!("{:?}", value));
+    }
+}
+
+impl Serialize for SpanFields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (k, v) in self.0.iter() {
+            map.serialize_entry(k, v)?;
+        }
+        map.end()
+    }
+}
+
+/// A layer which keeps the [`SpanFields`] in the extensions of every span.
+pub struct SpanFieldsLayer;
+
+impl<S> Layer<S> for SpanFieldsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: LayerContext<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            let mut fields = span
+                .parent()
+                .and_then(|parent| parent.extensions().get::<SpanFields>().cloned())
+                .unwrap_or_default();
+            attrs.record(&mut fields);
+            span.extensions_mut().insert(fields);
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: LayerContext<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(fields) = span.extensions_mut().get_mut::<SpanFields>() {
+                values.record(fields);
+            }
+        }
+    }
+}
+
+/// Returns the fields of the current span, which are empty outside of any span or if the
+/// [`SpanFieldsLayer`] is not installed.
+pub fn current_span_fields() -> SpanFields {
+    tracing::dispatcher::get_default(|dispatch| {
+        let registry = match dispatch.downcast_ref::<Registry>() {
+            Some(registry) => registry,
+            None => return SpanFields::default(),
+        };
+
+        dispatch
+            .current_span()
+            .id()
+            .and_then(|id| registry.span(id))
+            .and_then(|span| span.extensions().get::<SpanFields>().cloned())
+            .unwrap_or_default()
+    })
+}
+
+/// Returns the trace id of the current span if the spans are exported.
+pub fn current_trace_id() -> Option<String> {
+    let context = Span::current().context();
+    let span_context = context.span().span_context().clone();
+
+    span_context
+        .is_valid()
+        .then(|| span_context.trace_id().to_string())
+}
+
+/// Returns a remote trace context whose trace id and span id are derived from the request id of
+/// a BLS task, which every member of the group derives alike.
+pub fn task_trace_context(request_id: &[u8]) -> Context {
+    let mut bytes = [0u8; 24];
+    let len = request_id.len().min(bytes.len());
+    bytes[..len].copy_from_slice(&request_id[..len]);
+
+    let mut trace_id = [0u8; 16];
+    trace_id.copy_from_slice(&bytes[..16]);
+    let mut span_id = [0u8; 8];
+    span_id.copy_from_slice(&bytes[16..]);
+
+    Context::new().with_remote_span_context(SpanContext::new(
+        TraceId::from_bytes(trace_id),
+        SpanId::from_bytes(span_id),
+        TraceFlags::SAMPLED,
+        true,
+        TraceState::default(),
+    ))
+}
+
+/// Sets the parent of the span of a BLS task to the [`task_trace_context`] of the task, and links
+/// it to the current span, e.g. the listener which the task is found by.
+pub fn set_task_parent(span: &Span, request_id: &[u8]) {
+    span.set_parent(task_trace_context(request_id));
+    span.follows_from(Span::current());
+}
+
+/// Installs the global subscriber of the spans of the node, which also exports them over
+/// OTLP/gRPC if an endpoint is configured. This should be called in the Tokio runtime.
+pub fn init_tracing(descriptor: &TracingDescriptor, node_id: &str) -> anyhow::Result<()> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    let targets = TRACED_TARGETS
+        .iter()
+        .fold(Targets::new(), |targets, target| {
+            targets.with_target(*target, Level::DEBUG)
+        });
+
+    let otlp_layer = match descriptor.get_otlp_endpoint() {
+        Some(endpoint) => {
+            let tracer = opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(endpoint),
+                )
+                .with_trace_config(trace::config().with_resource(Resource::new(vec![
+                    KeyValue::new("service.name", descriptor.get_service_name().to_string()),
+                    KeyValue::new("service.instance.id", node_id.to_string()),
+                ])))
+                .install_batch(runtime::Tokio)?;
+
+            Some(tracing_opentelemetry::layer().with_tracer(tracer))
+        }
+        None => None,
+    };
+
+    let subscriber = Registry::default()
+        .with(SpanFieldsLayer)
+        .with(otlp_layer)
+        .with(targets);
+
+    tracing::subscriber::set_global_default(subscriber)?;
+
+    Ok(())
+}
+
+/// Flushes the exported spans before the node exits.
+pub fn shutdown_tracing() {
+    global::shutdown_tracer_provider();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{current_span_fields, current_trace_id, set_task_parent, SpanFieldsLayer};
+    use opentelemetry::trace::TracerProvider;
+    use tracing::info_span;
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    #[test]
+    fn test_span_fields_are_inherited() {
+        let subscriber = Registry::default().with(SpanFieldsLayer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            assert!(current_span_fields().is_empty());
+
+            let _task = info_span!("task", chain_id = 1, request_id = "0x01").entered();
+            let handler = info_span!(
+                "handler",
+                request_id = "0x02",
+                tx_hash = tracing::field::Empty
+            );
+            let _handler = handler.enter();
+            handler.record("tx_hash", "0x03");
+
+            let fields = current_span_fields();
+            assert_eq!(Some("1"), fields.get("chain_id"));
+            assert_eq!(Some("0x02"), fields.get("request_id"));
+            assert_eq!(Some("0x03"), fields.get("tx_hash"));
+        });
+    }
+
+    #[test]
+    fn test_task_spans_share_the_trace_of_the_request() {
+        let provider = opentelemetry_sdk::trace::TracerProvider::builder().build();
+        let subscriber = Registry::default()
+            .with(SpanFieldsLayer)
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+
+        let request_id = [7u8; 32];
+
+        tracing::subscriber::with_default(subscriber, || {
+            assert_eq!(None, current_trace_id());
+
+            let trace_ids = (0..2)
+                .map(|_| {
+                    let span = info_span!("task");
+                    set_task_parent(&span, &request_id);
+                    let _span = span.entered();
+                    let _child = info_span!("child").entered();
+                    current_trace_id()
+                })
+                .collect::<Vec<_>>();
+
+            assert_eq!(Some("07".repeat(16)), trace_ids[0]);
+            assert_eq!(trace_ids[0], trace_ids[1]);
+        });
+    }
+}
@@ -0,0 +1,149 @@
+//! Prometheus metrics of a node.
+//!
+//! All metrics are registered in the default registry under the `arpa_node_` prefix
+//! and exposed in the text exposition format by [`encode_metrics`].
+
+use ethers_core::types::U256;
+use lazy_static::lazy_static;
+use prometheus::{
+    register_histogram_vec, register_int_counter_vec, register_int_gauge_vec, Encoder,
+    HistogramVec, IntCounterVec, IntGaugeVec, TextEncoder,
+};
+
+lazy_static! {
+    pub static ref BLOCK_HEIGHT: IntGaugeVec = register_int_gauge_vec!(
+        "arpa_node_block_height",
+        "Latest block height the node has processed.",
+        &["chain_id"]
+    )
+    .unwrap();
+    pub static ref BLOCK_HEIGHT_LAG: IntGaugeVec = register_int_gauge_vec!(
+        "arpa_node_block_height_lag",
+        "Blocks between the provider head and the latest block height the node has processed.",
+        &["chain_id"]
+    )
+    .unwrap();
+    pub static ref LISTENER_ITERATIONS: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_listener_iterations_total",
+        "Iterations of every listener loop.",
+        &["chain_id", "listener"]
+    )
+    .unwrap();
+    pub static ref LISTENER_ERRORS: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_listener_errors_total",
+        "Iterations of every listener loop that ended with an error.",
+        &["chain_id", "listener"]
+    )
+    .unwrap();
+    pub static ref RANDOMNESS_TASKS: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_randomness_tasks_total",
+        "Randomness tasks by the stage they reached: received, signed, committed, expired or withdrawn.",
+        &["chain_id", "stage"]
+    )
+    .unwrap();
+    pub static ref PARTIAL_SIGNATURES: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_partial_signatures_total",
+        "Partial signatures sent to committers by the result: accepted, rejected or failed.",
+        &["chain_id", "task_type", "committer", "result"]
+    )
+    .unwrap();
+    pub static ref COMMITTER_REJECTED_REQUESTS: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_committer_rejected_requests_total",
+        "Partial signatures the committer service rejected by the reason.",
+        &["reason"]
+    )
+    .unwrap();
+    pub static ref DATA_PRUNED_ROWS: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_data_pruned_rows_total",
+        "Rows of the task and result tables deleted by the retention policy.",
+        &["chain_id"]
+    )
+    .unwrap();
+    pub static ref DKG_PHASE_DURATION: HistogramVec = register_histogram_vec!(
+        "arpa_node_dkg_phase_duration_seconds",
+        "Time the node spent in every DKG phase.",
+        &["phase"],
+        vec![5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1200.0]
+    )
+    .unwrap();
+    pub static ref DKG_OUTCOMES: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_dkg_outcomes_total",
+        "DKG tasks by the outcome: finished, aborted or failed.",
+        &["outcome"]
+    )
+    .unwrap();
+    pub static ref TRANSACTION_GAS_USED: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_transaction_gas_used_total",
+        "Gas used by the transactions the node sent.",
+        &["chain_id", "method"]
+    )
+    .unwrap();
+    pub static ref TRANSACTION_FEE: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_transaction_fee_gwei_total",
+        "Fees in gwei paid for the transactions the node sent.",
+        &["chain_id", "method"]
+    )
+    .unwrap();
+    pub static ref PROVIDER_RECONNECTS: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_provider_reconnects_total",
+        "Times the provider of a chain was reset after a connection failure.",
+        &["chain_id"]
+    )
+    .unwrap();
+    pub static ref CHAIN_REORGS: IntCounterVec = register_int_counter_vec!(
+        "arpa_node_chain_reorgs_total",
+        "Times a new block replaced a block the node had already processed.",
+        &["chain_id"]
+    )
+    .unwrap();
+}
+
+/// Adds the gas used and the fee paid by a transaction receipt to the transaction metrics.
+pub fn record_transaction_cost(chain_id: usize, method: &str, gas_used: U256, gas_price: U256) {
+    let chain_id = chain_id.to_string();
+    TRANSACTION_GAS_USED
+        .with_label_values(&[&chain_id, method])
+        .inc_by(gas_used.low_u64());
+    TRANSACTION_FEE
+        .with_label_values(&[&chain_id, method])
+        .inc_by((gas_used.saturating_mul(gas_price) / U256::exp10(9)).low_u64());
+}
+
+/// Encodes all registered metrics in the Prometheus text exposition format.
+pub fn encode_metrics() -> String {
+    let mut buffer = vec![];
+    TextEncoder::new()
+        .encode(&prometheus::gather(), &mut buffer)
+        .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_metrics, record_transaction_cost, RANDOMNESS_TASKS};
+    use ethers_core::types::U256;
+
+    #[test]
+    fn test_encode_metrics() {
+        RANDOMNESS_TASKS
+            .with_label_values(&["31337", "received"])
+            .inc();
+        record_transaction_cost(
+            31337,
+            "fulfill_randomness",
+            U256::from(100_000),
+            U256::from(2_000_000_000u64),
+        );
+
+        let metrics = encode_metrics();
+
+        assert!(metrics
+            .contains("arpa_node_randomness_tasks_total{chain_id=\"31337\",stage=\"received\"} 1"));
+        assert!(metrics.contains(
+            "arpa_node_transaction_gas_used_total{chain_id=\"31337\",method=\"fulfill_randomness\"} 100000"
+        ));
+        assert!(metrics.contains(
+            "arpa_node_transaction_fee_gwei_total{chain_id=\"31337\",method=\"fulfill_randomness\"} 200000"
+        ));
+    }
+}
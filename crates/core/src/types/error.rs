@@ -52,4 +52,12 @@ pub enum ConfigError {
     InvalidChainId(usize),
     #[error("lack of ARPA contract address")]
     LackOfARPAContractAddress,
+    #[error("failed to load the configuration file: {0}")]
+    LoadingError(String),
+    #[error("provider endpoint must be a websocket or http endpoint")]
+    InvalidProviderEndpoint,
+    #[error("provider view quorum {0} must not exceed the number of provider endpoints")]
+    InvalidProviderViewQuorum(usize),
+    #[error("{0:?} can't be changed without restarting the node")]
+    UnreloadableChanges(Vec<String>),
 }
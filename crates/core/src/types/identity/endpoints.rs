@@ -0,0 +1,149 @@
+pub const MAX_PROVIDER_HEALTH_SCORE: i32 = 10;
+pub const MIN_PROVIDER_HEALTH_SCORE: i32 = -10;
+
+/// The provider endpoints of a chain, the first one is the configured `provider_endpoint`
+/// and the rest are the fallbacks in the configured order.
+///
+/// Every endpoint keeps a health score, which is raised when (re)connecting to it succeeds
+/// and lowered when it fails, so that failover prefers the endpoints that answered recently.
+#[derive(Debug, Clone)]
+pub struct ProviderEndpoints {
+    endpoints: Vec<String>,
+    health_scores: Vec<i32>,
+    current: usize,
+    view_quorum: usize,
+}
+
+impl ProviderEndpoints {
+    pub fn new(endpoints: Vec<String>, view_quorum: usize) -> Self {
+        assert!(
+            !endpoints.is_empty(),
+            "At least one provider endpoint is required"
+        );
+
+        let health_scores = vec![0; endpoints.len()];
+
+        ProviderEndpoints {
+            endpoints,
+            health_scores,
+            current: 0,
+            view_quorum,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.endpoints.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.endpoints.is_empty()
+    }
+
+    pub fn current(&self) -> &str {
+        &self.endpoints[self.current]
+    }
+
+    pub fn current_index(&self) -> usize {
+        self.current
+    }
+
+    pub fn get(&self, index: usize) -> &str {
+        &self.endpoints[index]
+    }
+
+    pub fn get_health_score(&self, index: usize) -> i32 {
+        self.health_scores[index]
+    }
+
+    /// The number of providers which have to return the same result for a view call,
+    /// quorum views are disabled when it is 0 or 1.
+    pub fn view_quorum(&self) -> usize {
+        self.view_quorum
+    }
+
+    pub fn is_view_quorum_enabled(&self) -> bool {
+        self.view_quorum > 1
+    }
+
+    /// The endpoints other than the current one, which are asked as well for quorum views.
+    pub fn quorum_peers(&self) -> Vec<&str> {
+        self.endpoints
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != self.current)
+            .map(|(_, e)| e.as_str())
+            .collect()
+    }
+
+    pub fn record_success(&mut self, index: usize) {
+        self.health_scores[index] = (self.health_scores[index] + 1).min(MAX_PROVIDER_HEALTH_SCORE);
+    }
+
+    pub fn record_failure(&mut self, index: usize) {
+        self.health_scores[index] = (self.health_scores[index] - 1).max(MIN_PROVIDER_HEALTH_SCORE);
+    }
+
+    /// The indices of the endpoints to try on failover, the healthiest first.
+    /// Among endpoints with the same score the current one comes last,
+    /// the others keep the configured order.
+    pub fn failover_candidates(&self) -> Vec<usize> {
+        let mut candidates = (0..self.endpoints.len()).collect::<Vec<_>>();
+
+        candidates.sort_by_key(|i| (-self.health_scores[*i], *i == self.current, *i));
+
+        candidates
+    }
+
+    pub fn switch_to(&mut self, index: usize) {
+        assert!(index < self.endpoints.len());
+        self.current = index;
+    }
+}
+
+#[cfg(test)]
+pub mod endpoints_tests {
+    use crate::{ProviderEndpoints, MIN_PROVIDER_HEALTH_SCORE};
+
+    fn build_endpoints() -> ProviderEndpoints {
+        ProviderEndpoints::new(
+            vec![
+                "wss://primary".to_string(),
+                "https://fallback-1".to_string(),
+                "wss://fallback-2".to_string(),
+            ],
+            2,
+        )
+    }
+
+    #[test]
+    fn test_failover_candidates() {
+        let mut endpoints = build_endpoints();
+        assert_eq!(endpoints.current(), "wss://primary");
+        assert_eq!(endpoints.failover_candidates(), vec![1, 2, 0]);
+
+        endpoints.record_failure(0);
+        endpoints.record_failure(1);
+        assert_eq!(endpoints.failover_candidates(), vec![2, 1, 0]);
+
+        endpoints.switch_to(2);
+        endpoints.record_success(2);
+        assert_eq!(endpoints.current(), "wss://fallback-2");
+        assert_eq!(
+            endpoints.quorum_peers(),
+            vec!["wss://primary", "https://fallback-1"]
+        );
+        assert_eq!(endpoints.failover_candidates(), vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn test_health_score_is_bounded() {
+        let mut endpoints = build_endpoints();
+        for _ in 0..100 {
+            endpoints.record_failure(1);
+        }
+        assert_eq!(endpoints.get_health_score(1), MIN_PROVIDER_HEALTH_SCORE);
+
+        endpoints.record_success(1);
+        assert_eq!(endpoints.get_health_score(1), MIN_PROVIDER_HEALTH_SCORE + 1);
+    }
+}
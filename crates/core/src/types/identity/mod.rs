@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use ethers_core::types::{Address, BlockNumber, U256};
-use ethers_providers::{Provider, ProviderError, Ws};
+use ethers_providers::{Provider, ProviderError};
 use std::sync::Arc;
 
+mod endpoints;
+pub use endpoints::*;
 mod gas_middleware;
 pub use gas_middleware::*;
+mod transport;
+pub use transport::*;
 mod types;
 pub use types::*;
 
@@ -18,12 +22,19 @@ pub trait ChainIdentity {
 
     fn get_adapter_address(&self) -> Address;
 
-    fn get_client(&self) -> Arc<WsWalletSigner>;
+    fn get_client(&self) -> Arc<WalletSigner>;
 
     fn get_contract_transaction_retry_descriptor(&self) -> ExponentialBackoffRetryDescriptor;
 
     fn get_contract_view_retry_descriptor(&self) -> ExponentialBackoffRetryDescriptor;
 
+    /// Replaces the retry descriptors of the contract calls, which take effect on the next call.
+    fn set_retry_descriptors(
+        &mut self,
+        contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
+        contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
+    );
+
     async fn get_current_gas_price(&self) -> Result<U256, ProviderError>;
 
     async fn get_block_timestamp(
@@ -44,7 +55,7 @@ pub trait RelayedChainIdentity: ChainIdentity {
 
 #[async_trait]
 pub trait ChainProviderManager {
-    fn get_provider(&self) -> &Provider<Ws>;
+    fn get_provider(&self) -> &Provider<ChainTransport>;
 
     fn get_provider_endpoint(&self) -> &str;
 
@@ -0,0 +1,437 @@
+use crate::ProviderEndpoints;
+use async_trait::async_trait;
+use ethers_core::types::{U256, U64};
+#[cfg(test)]
+use ethers_providers::MockProvider;
+use ethers_providers::{Http, JsonRpcClient, ProviderError, PubsubClient, Ws};
+use log::warn;
+use serde::{de::DeserializeOwned, Serialize};
+use std::{fmt::Debug, str::FromStr};
+
+/// The method of the view calls which are checked against the quorum peers.
+const QUORUM_VIEW_METHOD: &str = "eth_call";
+
+/// The transport of a chain provider, which is decided by the scheme of the provider endpoint.
+///
+/// Subscriptions are only supported over WebSocket, the listeners should fall back to polling
+/// when `supports_subscription` returns false.
+///
+/// When quorum views are enabled, `eth_call` requests are sent to the quorum peers as well and
+/// a result is only accepted once `view_quorum` providers returned the same one.
+/// Calls at the latest block are pinned to the block number of the current provider,
+/// so that the providers answer at the same height.
+#[derive(Debug, Clone)]
+pub struct ChainTransport {
+    transport: EndpointTransport,
+    quorum_peers: Vec<EndpointTransport>,
+    view_quorum: usize,
+}
+
+#[derive(Debug, Clone)]
+enum EndpointTransport {
+    Ws(Ws),
+    Http(Http),
+    #[cfg(test)]
+    Mock(MockProvider),
+}
+
+impl EndpointTransport {
+    async fn connect(endpoint: &str, reconnects: usize) -> Result<Self, ProviderError> {
+        if is_http_endpoint(endpoint) {
+            let http = Http::from_str(endpoint)
+                .map_err(|e| ProviderError::CustomError(format!("{}: {}", endpoint, e)))?;
+            Ok(EndpointTransport::Http(http))
+        } else {
+            Ok(EndpointTransport::Ws(
+                Ws::connect_with_reconnects(endpoint, reconnects).await?,
+            ))
+        }
+    }
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, ProviderError>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        match self {
+            EndpointTransport::Ws(ws) => Ok(ws.request(method, params).await?),
+            EndpointTransport::Http(http) => Ok(http.request(method, params).await?),
+            #[cfg(test)]
+            EndpointTransport::Mock(mock) => Ok(mock.request(method, params).await?),
+        }
+    }
+}
+
+impl ChainTransport {
+    fn new(transport: EndpointTransport) -> Self {
+        ChainTransport {
+            transport,
+            quorum_peers: vec![],
+            view_quorum: 0,
+        }
+    }
+
+    pub async fn connect(endpoint: &str, reconnects: usize) -> Result<Self, ProviderError> {
+        Ok(ChainTransport::new(
+            EndpointTransport::connect(endpoint, reconnects).await?,
+        ))
+    }
+
+    /// Connects to the current endpoint, and to the other endpoints as quorum peers
+    /// if quorum views are enabled. Quorum peers which fail to connect are skipped.
+    pub async fn connect_endpoints(
+        endpoints: &ProviderEndpoints,
+        reconnects: usize,
+    ) -> Result<Self, ProviderError> {
+        let mut chain_transport = ChainTransport::connect(endpoints.current(), reconnects).await?;
+
+        if endpoints.is_view_quorum_enabled() {
+            for endpoint in endpoints.quorum_peers() {
+                match EndpointTransport::connect(endpoint, reconnects).await {
+                    Ok(transport) => chain_transport.quorum_peers.push(transport),
+                    Err(e) => warn!("Failed to connect to a quorum peer provider: {:?}", e),
+                }
+            }
+            chain_transport.view_quorum = endpoints.view_quorum();
+
+            if chain_transport.quorum_peers.len() + 1 < chain_transport.view_quorum {
+                warn!(
+                    "Connected to {} of {} providers, fewer than the provider view quorum {}, view calls will fail until enough providers are reachable",
+                    chain_transport.quorum_peers.len() + 1,
+                    endpoints.len(),
+                    chain_transport.view_quorum
+                );
+            }
+        }
+
+        Ok(chain_transport)
+    }
+
+    pub fn supports_subscription(&self) -> bool {
+        matches!(self.transport, EndpointTransport::Ws(_))
+    }
+
+    async fn quorum_request<T, R>(&self, method: &str, params: T) -> Result<R, ProviderError>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        let mut params = serde_json::to_value(params)?;
+
+        // the providers can be a few blocks apart, so the latest block is pinned to a number
+        if let Some(block) = params.as_array_mut().and_then(|params| params.get_mut(1)) {
+            if block.as_str() == Some("latest") {
+                let block_number = self
+                    .transport
+                    .request::<_, U64>("eth_blockNumber", ())
+                    .await?;
+                *block = serde_json::to_value(block_number)?;
+            }
+        }
+
+        let mut results: Vec<(serde_json::Value, usize)> = vec![];
+        let mut first_error = None;
+
+        for transport in std::iter::once(&self.transport).chain(self.quorum_peers.iter()) {
+            match transport
+                .request::<_, serde_json::Value>(method, &params)
+                .await
+            {
+                Ok(value) => match results.iter_mut().find(|(v, _)| *v == value) {
+                    Some((_, count)) => *count += 1,
+                    None => results.push((value, 1)),
+                },
+                Err(e) => {
+                    warn!(
+                        "Provider failed to answer {} for the view quorum: {:?}",
+                        method, e
+                    );
+                    first_error.get_or_insert(e);
+                }
+            }
+
+            if let Some((value, _)) = results.iter().find(|(_, c)| *c >= self.view_quorum) {
+                return Ok(serde_json::from_value(value.clone())?);
+            }
+        }
+
+        // keep the error (e.g. revert data) of the providers if none of them returned a result
+        match first_error {
+            Some(e) if results.is_empty() => Err(e),
+            _ => Err(ProviderError::CustomError(format!(
+                "{} of {} providers didn't agree on the result of {}",
+                self.view_quorum,
+                self.quorum_peers.len() + 1,
+                method
+            ))),
+        }
+    }
+}
+
+pub fn is_http_endpoint(endpoint: &str) -> bool {
+    endpoint.starts_with("http://") || endpoint.starts_with("https://")
+}
+
+impl From<Ws> for ChainTransport {
+    fn from(ws: Ws) -> Self {
+        ChainTransport::new(EndpointTransport::Ws(ws))
+    }
+}
+
+impl From<Http> for ChainTransport {
+    fn from(http: Http) -> Self {
+        ChainTransport::new(EndpointTransport::Http(http))
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for ChainTransport {
+    type Error = ProviderError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned + Send,
+    {
+        if method == QUORUM_VIEW_METHOD && self.view_quorum > 1 {
+            return self.quorum_request(method, params).await;
+        }
+
+        self.transport.request(method, params).await
+    }
+}
+
+impl PubsubClient for ChainTransport {
+    type NotificationStream = <Ws as PubsubClient>::NotificationStream;
+
+    fn subscribe<T: Into<U256>>(&self, id: T) -> Result<Self::NotificationStream, Self::Error> {
+        match &self.transport {
+            EndpointTransport::Ws(ws) => Ok(ws.subscribe(id)?),
+            _ => Err(ProviderError::UnsupportedRPC),
+        }
+    }
+
+    fn unsubscribe<T: Into<U256>>(&self, id: T) -> Result<(), Self::Error> {
+        match &self.transport {
+            EndpointTransport::Ws(ws) => Ok(ws.unsubscribe(id)?),
+            _ => Err(ProviderError::UnsupportedRPC),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod transport_tests {
+    use super::EndpointTransport;
+    use crate::{failover_provider, is_http_endpoint, ChainTransport, ProviderEndpoints};
+    use ethers_core::types::U64;
+    use ethers_providers::{JsonRpcClient, JsonRpcError, MockProvider, MockResponse};
+    use serde_json::{json, Value};
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const BLOCK_NUMBER: u64 = 16;
+
+    fn call_params() -> Value {
+        json!([{"to": "0x0000000000000000000000000000000000000001", "data": "0x"}, "latest"])
+    }
+
+    fn pinned_call_params() -> Value {
+        json!([{"to": "0x0000000000000000000000000000000000000001", "data": "0x"}, U64::from(BLOCK_NUMBER)])
+    }
+
+    /// Builds a transport of the mocked providers, each of which returns the given result of the call,
+    /// the first one returns the block number to pin the call to before it.
+    fn build_quorum_transport(
+        results: Vec<Option<&str>>,
+        view_quorum: usize,
+    ) -> (ChainTransport, Vec<MockProvider>) {
+        let mocks = results
+            .into_iter()
+            .map(|result| {
+                let mock = MockProvider::new();
+                match result {
+                    Some(result) => mock.push::<Value, _>(Value::from(result)).unwrap(),
+                    None => mock.push_response(MockResponse::Error(JsonRpcError {
+                        code: -32000,
+                        message: "header not found".to_string(),
+                        data: None,
+                    })),
+                }
+                mock
+            })
+            .collect::<Vec<_>>();
+
+        mocks[0].push(U64::from(BLOCK_NUMBER)).unwrap();
+
+        let transport = ChainTransport {
+            transport: EndpointTransport::Mock(mocks[0].clone()),
+            quorum_peers: mocks[1..]
+                .iter()
+                .map(|mock| EndpointTransport::Mock(mock.clone()))
+                .collect(),
+            view_quorum,
+        };
+
+        (transport, mocks)
+    }
+
+    #[tokio::test]
+    async fn test_quorum_request_with_agreement() {
+        let (transport, mocks) =
+            build_quorum_transport(vec![Some("0x01"), Some("0x01"), Some("0x02")], 2);
+
+        let result: String = transport.request("eth_call", call_params()).await.unwrap();
+        assert_eq!(result, "0x01");
+
+        // both providers are asked at the block number of the current provider
+        mocks[0].assert_request("eth_blockNumber", ()).unwrap();
+        mocks[0]
+            .assert_request("eth_call", pinned_call_params())
+            .unwrap();
+        mocks[1]
+            .assert_request("eth_call", pinned_call_params())
+            .unwrap();
+        // the quorum is reached without the last provider
+        assert!(mocks[2]
+            .assert_request("eth_call", pinned_call_params())
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_quorum_request_with_failed_provider() {
+        let (transport, _) = build_quorum_transport(vec![Some("0x01"), None, Some("0x01")], 2);
+
+        let result: String = transport.request("eth_call", call_params()).await.unwrap();
+        assert_eq!(result, "0x01");
+    }
+
+    #[tokio::test]
+    async fn test_quorum_request_with_disagreement() {
+        let (transport, _) =
+            build_quorum_transport(vec![Some("0x01"), Some("0x02"), Some("0x03")], 2);
+
+        let err = transport
+            .request::<_, String>("eth_call", call_params())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("didn't agree"));
+    }
+
+    #[tokio::test]
+    async fn test_quorum_request_with_all_providers_failed() {
+        let (transport, _) = build_quorum_transport(vec![None, None, None], 2);
+
+        let err = transport
+            .request::<_, String>("eth_call", call_params())
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("header not found"));
+    }
+
+    #[tokio::test]
+    async fn test_quorum_request_keeps_the_given_block() {
+        let mock = MockProvider::new();
+        mock.push::<Value, _>(Value::from("0x01")).unwrap();
+        let peer = MockProvider::new();
+        peer.push::<Value, _>(Value::from("0x01")).unwrap();
+
+        let transport = ChainTransport {
+            transport: EndpointTransport::Mock(mock.clone()),
+            quorum_peers: vec![EndpointTransport::Mock(peer.clone())],
+            view_quorum: 2,
+        };
+
+        let result: String = transport
+            .request("eth_call", pinned_call_params())
+            .await
+            .unwrap();
+        assert_eq!(result, "0x01");
+
+        mock.assert_request("eth_call", pinned_call_params())
+            .unwrap();
+        peer.assert_request("eth_call", pinned_call_params())
+            .unwrap();
+    }
+
+    /// Serves `eth_blockNumber` over http, closing the connection after every response.
+    async fn serve_block_number() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = vec![];
+                let mut buf = [0u8; 1024];
+                // the request body is a single json object
+                while !request.ends_with(b"}") {
+                    let n = stream.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let body = &request[request.find("\r\n\r\n").unwrap() + 4..];
+                let id = serde_json::from_str::<Value>(body).unwrap()["id"].clone();
+
+                let body = json!({"jsonrpc": "2.0", "id": id, "result": U64::from(BLOCK_NUMBER)})
+                    .to_string();
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        endpoint
+    }
+
+    /// An endpoint which refuses connections.
+    async fn closed_endpoint() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_failover_provider() {
+        let mut endpoints = ProviderEndpoints::new(
+            vec![
+                closed_endpoint().await,
+                closed_endpoint().await,
+                serve_block_number().await,
+            ],
+            0,
+        );
+
+        failover_provider(&mut endpoints, Duration::from_millis(10))
+            .await
+            .unwrap();
+
+        // the unreachable fallback is skipped for the healthy one
+        assert_eq!(endpoints.current_index(), 2);
+        assert_eq!(endpoints.get_health_score(0), -1);
+        assert_eq!(endpoints.get_health_score(1), -1);
+        assert_eq!(endpoints.get_health_score(2), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failover_provider_with_all_endpoints_unreachable() {
+        let mut endpoints =
+            ProviderEndpoints::new(vec![closed_endpoint().await, closed_endpoint().await], 0);
+
+        assert!(failover_provider(&mut endpoints, Duration::from_millis(10))
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn test_is_http_endpoint() {
+        assert!(is_http_endpoint("http://127.0.0.1:8545"));
+        assert!(is_http_endpoint("https://mainnet.base.org"));
+        assert!(!is_http_endpoint("ws://127.0.0.1:8546"));
+        assert!(!is_http_endpoint("wss://mainnet.base.org"));
+    }
+}
@@ -1,27 +1,30 @@
 use crate::{
-    eip1559_gas_price_estimator, supports_eip1559, ChainProviderManager,
-    ExponentialBackoffRetryDescriptor, GasMiddleware, RelayedChainIdentity,
-    DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES, GAS_RAISE_PERCENTAGE,
+    eip1559_gas_price_estimator, metrics::PROVIDER_RECONNECTS, supports_eip1559,
+    ChainProviderManager, ChainTransport, ExponentialBackoffRetryDescriptor, GasMiddleware,
+    ProviderEndpoints, RelayedChainIdentity, DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+    GAS_RAISE_PERCENTAGE,
 };
 
 use super::{ChainIdentity, MainChainIdentity};
 use async_trait::async_trait;
 use ethers_core::types::{Address, BlockNumber, U256};
 use ethers_middleware::{MiddlewareBuilder, NonceManagerMiddleware, SignerMiddleware};
-use ethers_providers::{Http, Middleware, Provider, ProviderError, Ws};
+use ethers_providers::{Http, Middleware, Provider, ProviderError};
 use ethers_signers::{LocalWallet, Signer};
-use std::sync::Arc;
+use log::{info, warn};
+use std::{sync::Arc, time::Duration};
 
-pub type WsWalletSigner =
-    NonceManagerMiddleware<SignerMiddleware<GasMiddleware<Arc<Provider<Ws>>>, LocalWallet>>;
+pub type WalletSigner = NonceManagerMiddleware<
+    SignerMiddleware<GasMiddleware<Arc<Provider<ChainTransport>>>, LocalWallet>,
+>;
 pub type HttpWalletSigner =
     SignerMiddleware<NonceManagerMiddleware<Arc<Provider<Http>>>, LocalWallet>;
 
 pub fn build_client(
     wallet: LocalWallet,
     chain_id: usize,
-    provider: Arc<Provider<Ws>>,
-) -> Arc<WsWalletSigner> {
+    provider: Arc<Provider<ChainTransport>>,
+) -> Arc<WalletSigner> {
     let address = wallet.address();
 
     let wallet = wallet.with_chain_id(chain_id as u32);
@@ -36,12 +39,58 @@ pub fn build_client(
     Arc::new(client_with_nonce_manager)
 }
 
+/// Penalizes the current endpoint and connects to the healthiest endpoint which answers,
+/// the current endpoint is only retried if none of the others is reachable.
+pub async fn failover_provider(
+    provider_endpoints: &mut ProviderEndpoints,
+    interval: Duration,
+) -> Result<Arc<Provider<ChainTransport>>, ProviderError> {
+    provider_endpoints.record_failure(provider_endpoints.current_index());
+
+    let mut last_error = None;
+
+    for index in provider_endpoints.failover_candidates() {
+        provider_endpoints.switch_to(index);
+
+        let res = async {
+            let provider = Provider::new(
+                ChainTransport::connect_endpoints(
+                    provider_endpoints,
+                    DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+                )
+                .await?,
+            )
+            .interval(interval);
+            provider.get_block_number().await?;
+            Ok::<_, ProviderError>(provider)
+        }
+        .await;
+
+        match res {
+            Ok(provider) => {
+                provider_endpoints.record_success(index);
+                if provider_endpoints.len() > 1 {
+                    info!("Switched to provider endpoint #{}", index);
+                }
+                return Ok(Arc::new(provider));
+            }
+            Err(e) => {
+                warn!("Failed to connect to provider endpoint #{}: {:?}", index, e);
+                provider_endpoints.record_failure(index);
+                last_error = Some(e);
+            }
+        }
+    }
+
+    Err(last_error.unwrap())
+}
+
 #[derive(Debug, Clone)]
 pub struct GeneralMainChainIdentity {
     chain_id: usize,
     address: Address,
-    client: Arc<WsWalletSigner>,
-    provider_endpoint: String,
+    client: Arc<WalletSigner>,
+    provider_endpoints: ProviderEndpoints,
     controller_address: Address,
     controller_relayer_address: Address,
     adapter_address: Address,
@@ -54,8 +103,8 @@ impl GeneralMainChainIdentity {
     pub fn new(
         chain_id: usize,
         wallet: LocalWallet,
-        provider: Arc<Provider<Ws>>,
-        provider_endpoint: String,
+        provider: Arc<Provider<ChainTransport>>,
+        provider_endpoints: ProviderEndpoints,
         controller_address: Address,
         controller_relayer_address: Address,
         adapter_address: Address,
@@ -70,7 +119,7 @@ impl GeneralMainChainIdentity {
             chain_id,
             address,
             client,
-            provider_endpoint,
+            provider_endpoints,
             controller_address,
             controller_relayer_address,
             adapter_address,
@@ -94,7 +143,7 @@ impl ChainIdentity for GeneralMainChainIdentity {
         self.adapter_address
     }
 
-    fn get_client(&self) -> Arc<WsWalletSigner> {
+    fn get_client(&self) -> Arc<WalletSigner> {
         self.client.clone()
     }
 
@@ -106,6 +155,15 @@ impl ChainIdentity for GeneralMainChainIdentity {
         self.contract_view_retry_descriptor
     }
 
+    fn set_retry_descriptors(
+        &mut self,
+        contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
+        contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
+    ) {
+        self.contract_transaction_retry_descriptor = contract_transaction_retry_descriptor;
+        self.contract_view_retry_descriptor = contract_view_retry_descriptor;
+    }
+
     async fn get_current_gas_price(&self) -> Result<U256, ProviderError> {
         if !supports_eip1559(self.chain_id) {
             return self.client.provider().get_gas_price().await;
@@ -144,23 +202,17 @@ impl MainChainIdentity for GeneralMainChainIdentity {
 
 #[async_trait]
 impl ChainProviderManager for GeneralMainChainIdentity {
-    fn get_provider(&self) -> &Provider<Ws> {
+    fn get_provider(&self) -> &Provider<ChainTransport> {
         self.client.provider()
     }
 
     fn get_provider_endpoint(&self) -> &str {
-        &self.provider_endpoint
+        self.provider_endpoints.current()
     }
 
     async fn reset_provider(&mut self) -> Result<(), ProviderError> {
-        let provider = Arc::new(
-            Provider::<Ws>::connect_with_reconnects(
-                &self.provider_endpoint,
-                DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
-            )
-            .await?
-            .interval(self.get_provider().get_interval()),
-        );
+        let interval = self.get_provider().get_interval();
+        let provider = failover_provider(&mut self.provider_endpoints, interval).await?;
 
         self.client = build_client(
             self.client.inner().signer().clone(),
@@ -168,6 +220,10 @@ impl ChainProviderManager for GeneralMainChainIdentity {
             provider,
         );
 
+        PROVIDER_RECONNECTS
+            .with_label_values(&[&self.chain_id.to_string()])
+            .inc();
+
         Ok(())
     }
 }
@@ -176,8 +232,8 @@ impl ChainProviderManager for GeneralMainChainIdentity {
 pub struct GeneralRelayedChainIdentity {
     chain_id: usize,
     address: Address,
-    client: Arc<WsWalletSigner>,
-    provider_endpoint: String,
+    client: Arc<WalletSigner>,
+    provider_endpoints: ProviderEndpoints,
     controller_oracle_address: Address,
     adapter_address: Address,
     contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
@@ -189,8 +245,8 @@ impl GeneralRelayedChainIdentity {
     pub fn new(
         chain_id: usize,
         wallet: LocalWallet,
-        provider: Arc<Provider<Ws>>,
-        provider_endpoint: String,
+        provider: Arc<Provider<ChainTransport>>,
+        provider_endpoints: ProviderEndpoints,
         controller_oracle_address: Address,
         adapter_address: Address,
         contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
@@ -204,7 +260,7 @@ impl GeneralRelayedChainIdentity {
             chain_id,
             address,
             client,
-            provider_endpoint,
+            provider_endpoints,
             controller_oracle_address,
             adapter_address,
             contract_transaction_retry_descriptor,
@@ -227,7 +283,7 @@ impl ChainIdentity for GeneralRelayedChainIdentity {
         self.adapter_address
     }
 
-    fn get_client(&self) -> Arc<WsWalletSigner> {
+    fn get_client(&self) -> Arc<WalletSigner> {
         self.client.clone()
     }
 
@@ -239,6 +295,15 @@ impl ChainIdentity for GeneralRelayedChainIdentity {
         self.contract_view_retry_descriptor
     }
 
+    fn set_retry_descriptors(
+        &mut self,
+        contract_transaction_retry_descriptor: ExponentialBackoffRetryDescriptor,
+        contract_view_retry_descriptor: ExponentialBackoffRetryDescriptor,
+    ) {
+        self.contract_transaction_retry_descriptor = contract_transaction_retry_descriptor;
+        self.contract_view_retry_descriptor = contract_view_retry_descriptor;
+    }
+
     async fn get_current_gas_price(&self) -> Result<U256, ProviderError> {
         if !supports_eip1559(self.chain_id) {
             return self.client.provider().get_gas_price().await;
@@ -272,23 +337,17 @@ impl RelayedChainIdentity for GeneralRelayedChainIdentity {
 
 #[async_trait]
 impl ChainProviderManager for GeneralRelayedChainIdentity {
-    fn get_provider(&self) -> &Provider<Ws> {
+    fn get_provider(&self) -> &Provider<ChainTransport> {
         self.client.provider()
     }
 
     fn get_provider_endpoint(&self) -> &str {
-        &self.provider_endpoint
+        self.provider_endpoints.current()
     }
 
     async fn reset_provider(&mut self) -> Result<(), ProviderError> {
-        let provider = Arc::new(
-            Provider::<Ws>::connect_with_reconnects(
-                &self.provider_endpoint,
-                DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
-            )
-            .await?
-            .interval(self.get_provider().get_interval()),
-        );
+        let interval = self.get_provider().get_interval();
+        let provider = failover_provider(&mut self.provider_endpoints, interval).await?;
 
         self.client = build_client(
             self.client.inner().signer().clone(),
@@ -296,6 +355,10 @@ impl ChainProviderManager for GeneralRelayedChainIdentity {
             provider,
         );
 
+        PROVIDER_RECONNECTS
+            .with_label_values(&[&self.chain_id.to_string()])
+            .inc();
+
         Ok(())
     }
 }
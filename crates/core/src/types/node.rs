@@ -1,15 +1,23 @@
 use crate::ser_bytes_in_hex_string;
 use crate::ser_u256_in_dec_string;
+use crate::u256_to_vec;
 use ethers_core::{
+    abi::{decode, encode, ParamType, Token},
     types::{Address, U256},
-    utils::hex,
+    utils::{hex, keccak256},
 };
 use serde::{Deserialize, Serialize};
 use std::{collections::BTreeMap, marker::PhantomData};
-use threshold_bls::{group::Curve, serialize::point_to_hex};
+use threshold_bls::{
+    group::Curve,
+    poly::{Eval, Idx, Poly},
+    serialize::point_to_hex,
+};
 
 pub trait Task {
     fn request_id(&self) -> &[u8];
+
+    fn assignment_block_height(&self) -> usize;
 }
 
 #[derive(Debug, Clone)]
@@ -22,6 +30,30 @@ impl Task for RandomnessTask {
     fn request_id(&self) -> &[u8] {
         &self.request_id
     }
+
+    fn assignment_block_height(&self) -> usize {
+        self.assignment_block_height
+    }
+}
+
+impl Task for GroupRelayTask {
+    fn request_id(&self) -> &[u8] {
+        &self.request_id
+    }
+
+    fn assignment_block_height(&self) -> usize {
+        self.assignment_block_height
+    }
+}
+
+impl Task for GroupRelayConfirmationTask {
+    fn request_id(&self) -> &[u8] {
+        &self.request_id
+    }
+
+    fn assignment_block_height(&self) -> usize {
+        self.assignment_block_height
+    }
 }
 
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
@@ -57,6 +89,18 @@ impl std::fmt::Debug for RandomnessTask {
     }
 }
 
+impl RandomnessTask {
+    /// The message signed by the group, which is the seed followed by the assignment block
+    /// height, as the adapter rebuilds it to verify the signature.
+    pub fn build_message(seed: &U256, assignment_block_height: usize) -> Vec<u8> {
+        [
+            &u256_to_vec(seed)[..],
+            &u256_to_vec(&U256::from(assignment_block_height))[..],
+        ]
+        .concat()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DKGTask {
     pub group_index: usize,
@@ -68,16 +112,82 @@ pub struct DKGTask {
     pub coordinator_address: Address,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GroupRelayTask {
+    #[serde(serialize_with = "ser_bytes_in_hex_string")]
+    pub request_id: Vec<u8>,
+    pub relayed_chain_id: usize,
     pub controller_global_epoch: usize,
     pub relayed_group_index: usize,
     pub relayed_group_epoch: usize,
     pub assignment_block_height: usize,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl GroupRelayTask {
+    pub fn new(
+        relayed_chain_id: usize,
+        controller_global_epoch: usize,
+        relayed_group_index: usize,
+        relayed_group_epoch: usize,
+        assignment_block_height: usize,
+    ) -> Self {
+        // a group is relayed at most once per epoch to every relayed chain
+        let request_id = keccak256(encode(&[
+            Token::Uint(relayed_chain_id.into()),
+            Token::Uint(relayed_group_index.into()),
+            Token::Uint(relayed_group_epoch.into()),
+        ]))
+        .to_vec();
+
+        GroupRelayTask {
+            request_id,
+            relayed_chain_id,
+            controller_global_epoch,
+            relayed_group_index,
+            relayed_group_epoch,
+            assignment_block_height,
+        }
+    }
+
+    /// The message to be signed by the relayer group, which is the abi-encoded
+    /// `(controllerGlobalEpoch, abi.encode(relayedGroup))` bound to the `ControllerOracle` of the
+    /// relayed chain.
+    pub fn build_message(
+        &self,
+        controller_oracle_address: Address,
+        encoded_group: Vec<u8>,
+    ) -> Vec<u8> {
+        build_group_relay_message(
+            self.relayed_chain_id,
+            controller_oracle_address,
+            encode(&[
+                Token::Uint(self.controller_global_epoch.into()),
+                Token::Bytes(encoded_group),
+            ]),
+        )
+    }
+
+    /// Extracts the abi-encoded relayed group from a message built by [`Self::build_message`].
+    pub fn parse_encoded_group(message: &[u8]) -> Option<Vec<u8>> {
+        let payload = decode(
+            &[ParamType::Uint(256), ParamType::Address, ParamType::Bytes],
+            message,
+        )
+        .ok()?
+        .pop()?
+        .into_bytes()?;
+
+        decode(&[ParamType::Uint(256), ParamType::Bytes], &payload)
+            .ok()?
+            .pop()?
+            .into_bytes()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GroupRelayConfirmationTask {
+    #[serde(serialize_with = "ser_bytes_in_hex_string")]
+    pub request_id: Vec<u8>,
     pub index: usize,
     pub group_relay_cache_index: usize,
     pub relayed_group_index: usize,
@@ -86,6 +196,76 @@ pub struct GroupRelayConfirmationTask {
     pub assignment_block_height: usize,
 }
 
+impl GroupRelayConfirmationTask {
+    pub fn new(
+        group_relay_cache_index: usize,
+        relayed_group_index: usize,
+        relayed_group_epoch: usize,
+        relayer_group_index: usize,
+        assignment_block_height: usize,
+    ) -> Self {
+        let request_id = keccak256(Self::build_payload(
+            group_relay_cache_index,
+            relayed_group_index,
+            relayed_group_epoch,
+        ))
+        .to_vec();
+
+        GroupRelayConfirmationTask {
+            request_id,
+            // every group relay cache on the relayed chain is confirmed by exactly one task
+            index: group_relay_cache_index,
+            group_relay_cache_index,
+            relayed_group_index,
+            relayed_group_epoch,
+            relayer_group_index,
+            assignment_block_height,
+        }
+    }
+
+    /// The message to be signed by the relayed group, which is the abi-encoded
+    /// `(groupRelayCacheIndex, relayedGroupIndex, relayedGroupEpoch)` bound to the
+    /// `ControllerOracle` of the relayed chain.
+    pub fn build_message(&self, chain_id: usize, controller_oracle_address: Address) -> Vec<u8> {
+        build_group_relay_message(
+            chain_id,
+            controller_oracle_address,
+            Self::build_payload(
+                self.group_relay_cache_index,
+                self.relayed_group_index,
+                self.relayed_group_epoch,
+            ),
+        )
+    }
+
+    fn build_payload(
+        group_relay_cache_index: usize,
+        relayed_group_index: usize,
+        relayed_group_epoch: usize,
+    ) -> Vec<u8> {
+        encode(&[
+            Token::Uint(group_relay_cache_index.into()),
+            Token::Uint(relayed_group_index.into()),
+            Token::Uint(relayed_group_epoch.into()),
+        ])
+    }
+}
+
+/// Binds a group relay payload to the chain id and the `ControllerOracle` it is sent to, as
+/// `abi.encode(block.chainid, address(this), payload)`, so that the signature can't be replayed
+/// on another chain or another deployment.
+fn build_group_relay_message(
+    chain_id: usize,
+    controller_oracle_address: Address,
+    payload: Vec<u8>,
+) -> Vec<u8> {
+    encode(&[
+        Token::Uint(chain_id.into()),
+        Token::Address(controller_oracle_address),
+        Token::Bytes(payload),
+    ])
+}
+
 #[derive(Default, Clone, Serialize, Deserialize)]
 pub struct Group<C: Curve> {
     pub index: usize,
@@ -134,6 +314,22 @@ impl<C: Curve> Group<C> {
         self.members
             .retain(|node, _| !disqualified_nodes.contains(node));
     }
+
+    /// Recovers the public polynomial of the group from the partial public keys of the members.
+    pub fn public_poly(&self) -> Option<Poly<C::Point>> {
+        let partial_public_keys = self
+            .members
+            .values()
+            .filter_map(|member| {
+                member.partial_public_key.clone().map(|value| Eval {
+                    value,
+                    index: member.index as Idx,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Poly::<C::Point>::full_recover(self.threshold, partial_public_keys).ok()
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -255,3 +451,113 @@ impl From<u8> for RandomnessRequestType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{GroupRelayConfirmationTask, GroupRelayTask};
+    use ethers_core::{
+        abi::{encode, Token},
+        types::Address,
+        utils::{hex, keccak256},
+    };
+
+    #[test]
+    fn test_group_relay_task_request_id() {
+        let task = GroupRelayTask::new(10, 5, 1, 3, 100);
+
+        assert_eq!(
+            keccak256(encode(&[
+                Token::Uint(10.into()),
+                Token::Uint(1.into()),
+                Token::Uint(3.into())
+            ]))
+            .to_vec(),
+            task.request_id
+        );
+        assert_eq!(
+            "0xd18bc12ca49859d548250a1c90dfc4d08689b8c6e9987d1b2cf5ee4c8c8f935d",
+            format!("0x{}", hex::encode(&task.request_id))
+        );
+
+        // neither the controller epoch nor the block the task is requested in changes the task
+        assert_eq!(
+            task.request_id,
+            GroupRelayTask::new(10, 6, 1, 3, 200).request_id
+        );
+        // the same group is relayed to every relayed chain by different tasks
+        assert_ne!(
+            task.request_id,
+            GroupRelayTask::new(8453, 5, 1, 3, 100).request_id
+        );
+        assert_ne!(
+            task.request_id,
+            GroupRelayTask::new(10, 5, 1, 4, 100).request_id
+        );
+    }
+
+    #[test]
+    fn test_group_relay_message() {
+        let task = GroupRelayTask::new(10, 5, 1, 3, 100);
+        let controller_oracle_address = Address::from_low_u64_be(0x90103);
+        let encoded_group = vec![0xab; 40];
+
+        let message = task.build_message(controller_oracle_address, encoded_group.clone());
+
+        assert_eq!(
+            encode(&[
+                Token::Uint(10.into()),
+                Token::Address(controller_oracle_address),
+                Token::Bytes(encode(&[
+                    Token::Uint(5.into()),
+                    Token::Bytes(encoded_group.clone())
+                ]))
+            ]),
+            message
+        );
+        assert_eq!(
+            Some(encoded_group),
+            GroupRelayTask::parse_encoded_group(&message)
+        );
+        assert_eq!(None, GroupRelayTask::parse_encoded_group(&[0xab; 40]));
+    }
+
+    #[test]
+    fn test_group_relay_confirmation_task_request_id() {
+        let task = GroupRelayConfirmationTask::new(2, 1, 3, 0, 100);
+
+        assert_eq!(
+            keccak256(encode(&[
+                Token::Uint(2.into()),
+                Token::Uint(1.into()),
+                Token::Uint(3.into())
+            ]))
+            .to_vec(),
+            task.request_id
+        );
+        assert_eq!(2, task.index);
+        assert_ne!(
+            task.request_id,
+            GroupRelayConfirmationTask::new(3, 1, 3, 0, 100).request_id
+        );
+
+        let controller_oracle_address = Address::from_low_u64_be(0x90103);
+
+        assert_eq!(
+            encode(&[
+                Token::Uint(10.into()),
+                Token::Address(controller_oracle_address),
+                Token::Bytes(encode(&[
+                    Token::Uint(2.into()),
+                    Token::Uint(1.into()),
+                    Token::Uint(3.into())
+                ]))
+            ]),
+            task.build_message(10, controller_oracle_address)
+        );
+        // the message is bound to the chain it is confirmed on
+        assert_ne!(
+            task.build_message(10, controller_oracle_address),
+            task.build_message(8453, controller_oracle_address)
+        );
+    }
+}
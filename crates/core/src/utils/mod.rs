@@ -1,9 +1,12 @@
+use crate::RandomnessTask;
 use chrono::Local;
 use ethers_core::{
+    abi::{encode, Token},
     types::{Address, I256, U256},
     utils::{hex, keccak256},
 };
 use log::info;
+use threshold_bls::sig::SignatureScheme;
 
 /// The threshold max change/difference (in %) at which we will ignore the fee history values
 /// under it.
@@ -42,6 +45,28 @@ pub fn u256_to_vec(x: &U256) -> Vec<u8> {
     x_bytes
 }
 
+/// Verifies the signature of a randomness fulfillment with the public key of the group which
+/// fulfilled it, and returns the randomness derived from the signature.
+pub fn verify_randomness_fulfillment<S: SignatureScheme>(
+    public_key: &S::Public,
+    seed: &U256,
+    assignment_block_height: usize,
+    signature: &U256,
+) -> Result<U256, S::Error> {
+    S::verify(
+        public_key,
+        &RandomnessTask::build_message(seed, assignment_block_height),
+        &u256_to_vec(signature),
+    )?;
+
+    Ok(derive_randomness(signature))
+}
+
+/// The randomness is the hash of the abi-encoded signature, as the adapter computes it.
+pub fn derive_randomness(signature: &U256) -> U256 {
+    U256::from(keccak256(encode(&[Token::Uint(*signature)])))
+}
+
 pub fn pad_to_bytes32(s: &[u8]) -> Option<[u8; 32]> {
     let s_len = s.len();
 
@@ -182,7 +207,15 @@ pub mod util_tests {
 
     use ethers_core::types::Address;
 
-    use crate::{address_to_string, format_now_date};
+    use crate::{
+        address_to_string, derive_randomness, format_now_date, verify_randomness_fulfillment,
+        RandomnessTask,
+    };
+    use ethers_core::types::U256;
+    use threshold_bls::{
+        schemes::bn254::G2Scheme,
+        sig::{Scheme, SignatureScheme},
+    };
 
     #[test]
     fn test_format_now_date() {
@@ -202,4 +235,36 @@ pub mod util_tests {
         let address = bad_address_in_str.parse::<Address>();
         assert!(address.is_err());
     }
+
+    #[test]
+    fn test_verify_randomness_fulfillment() {
+        let (private_key, public_key) = G2Scheme::keypair(&mut rand::thread_rng());
+        let seed = U256::from(12345);
+        let assignment_block_height = 100;
+
+        let signature = G2Scheme::sign(
+            &private_key,
+            &RandomnessTask::build_message(&seed, assignment_block_height),
+        )
+        .unwrap();
+        // the signature is submitted to the adapter as an uint256
+        let signature = U256::from(signature.as_slice());
+
+        let randomness = verify_randomness_fulfillment::<G2Scheme>(
+            &public_key,
+            &seed,
+            assignment_block_height,
+            &signature,
+        )
+        .unwrap();
+        assert_eq!(derive_randomness(&signature), randomness);
+
+        assert!(verify_randomness_fulfillment::<G2Scheme>(
+            &public_key,
+            &seed,
+            assignment_block_height + 1,
+            &signature,
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,42 @@
+pub use sea_orm_migration::prelude::*;
+
+mod m20261018_000001_create_node_info_table;
+mod m20261018_000002_create_group_info_table;
+mod m20261018_000003_create_block_checkpoint_table;
+mod m20261018_000004_create_randomness_task_table;
+mod m20261018_000005_create_randomness_result_table;
+mod m20261018_000006_create_group_relay_task_table;
+mod m20261018_000007_create_group_relay_result_table;
+mod m20261018_000008_create_group_relay_confirmation_task_table;
+mod m20261018_000009_create_group_relay_confirmation_result_table;
+
+pub use m20261018_000001_create_node_info_table::NodeInfo;
+pub use m20261018_000002_create_group_info_table::GroupInfo;
+pub use m20261018_000003_create_block_checkpoint_table::BlockCheckpoint;
+pub use m20261018_000004_create_randomness_task_table::RandomnessTask;
+pub use m20261018_000005_create_randomness_result_table::RandomnessResult;
+pub use m20261018_000006_create_group_relay_task_table::GroupRelayTask;
+pub use m20261018_000007_create_group_relay_result_table::GroupRelayResult;
+pub use m20261018_000008_create_group_relay_confirmation_task_table::GroupRelayConfirmationTask;
+pub use m20261018_000009_create_group_relay_confirmation_result_table::GroupRelayConfirmationResult;
+
+/// The PostgreSQL schema starts from the latest SQLite one, the tables of the chains are keyed by
+/// the chain id, and the columns are typed as the `entity` models read them.
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20261018_000001_create_node_info_table::Migration),
+            Box::new(m20261018_000002_create_group_info_table::Migration),
+            Box::new(m20261018_000003_create_block_checkpoint_table::Migration),
+            Box::new(m20261018_000004_create_randomness_task_table::Migration),
+            Box::new(m20261018_000005_create_randomness_result_table::Migration),
+            Box::new(m20261018_000006_create_group_relay_task_table::Migration),
+            Box::new(m20261018_000007_create_group_relay_result_table::Migration),
+            Box::new(m20261018_000008_create_group_relay_confirmation_task_table::Migration),
+            Box::new(m20261018_000009_create_group_relay_confirmation_result_table::Migration),
+        ]
+    }
+}
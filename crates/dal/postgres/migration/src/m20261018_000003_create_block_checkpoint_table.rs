@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(BlockCheckpoint::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(BlockCheckpoint::ChainId)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(BlockCheckpoint::LastProcessedBlockHeight)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(BlockCheckpoint::CreateAt).text().not_null())
+                    .col(ColumnDef::new(BlockCheckpoint::UpdateAt).text().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BlockCheckpoint::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum BlockCheckpoint {
+    Table,
+    ChainId,
+    LastProcessedBlockHeight,
+    CreateAt,
+    UpdateAt,
+}
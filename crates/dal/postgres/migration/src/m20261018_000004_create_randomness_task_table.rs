@@ -0,0 +1,143 @@
+use sea_orm_migration::prelude::*;
+
+/// Randomness tasks of all the chains are kept in the same table keyed by the chain id, where 0
+/// stands for the main chain.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RandomnessTask::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RandomnessTask::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RandomnessTask::ChainId).integer().not_null())
+                    .col(
+                        ColumnDef::new(RandomnessTask::RequestId)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessTask::SubscriptionId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessTask::GroupIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessTask::RequestType)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RandomnessTask::Params).binary().not_null())
+                    .col(ColumnDef::new(RandomnessTask::Requester).text().not_null())
+                    .col(ColumnDef::new(RandomnessTask::Seed).binary().not_null())
+                    .col(
+                        ColumnDef::new(RandomnessTask::RequestConfirmations)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessTask::CallbackGasLimit)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessTask::CallbackMaxGasPrice)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessTask::AssignmentBlockHeight)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(RandomnessTask::State).integer().not_null())
+                    .col(ColumnDef::new(RandomnessTask::CreateAt).text().not_null())
+                    .col(ColumnDef::new(RandomnessTask::UpdateAt).text().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessTask::Table)
+                    .name("randomness_task_chain_id_request_id")
+                    .col(RandomnessTask::ChainId)
+                    .col(RandomnessTask::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessTask::Table)
+                    .name("randomness_task_group_index")
+                    .col(RandomnessTask::GroupIndex)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessTask::Table)
+                    .name("randomness_task_assignment_block_height")
+                    .col(RandomnessTask::AssignmentBlockHeight)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessTask::Table)
+                    .name("randomness_task_state")
+                    .col(RandomnessTask::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RandomnessTask::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum RandomnessTask {
+    Table,
+    Id,
+    ChainId,
+    RequestId,
+    SubscriptionId,
+    GroupIndex,
+    RequestType,
+    Params,
+    Requester,
+    Seed,
+    RequestConfirmations,
+    CallbackGasLimit,
+    CallbackMaxGasPrice,
+    AssignmentBlockHeight,
+    State,
+    CreateAt,
+    UpdateAt,
+}
@@ -0,0 +1,118 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RandomnessResult::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RandomnessResult::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessResult::ChainId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessResult::RequestId)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessResult::GroupIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessResult::Message)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessResult::Threshold)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessResult::PartialSignatures)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(RandomnessResult::CommittedTimes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(RandomnessResult::State).integer().not_null())
+                    .col(ColumnDef::new(RandomnessResult::CreateAt).text().not_null())
+                    .col(ColumnDef::new(RandomnessResult::UpdateAt).text().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessResult::Table)
+                    .name("randomness_result_chain_id_request_id")
+                    .col(RandomnessResult::ChainId)
+                    .col(RandomnessResult::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessResult::Table)
+                    .name("randomness_result_group_index")
+                    .col(RandomnessResult::GroupIndex)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessResult::Table)
+                    .name("randomness_result_state")
+                    .col(RandomnessResult::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RandomnessResult::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum RandomnessResult {
+    Table,
+    Id,
+    ChainId,
+    RequestId,
+    GroupIndex,
+    Message,
+    Threshold,
+    PartialSignatures,
+    CommittedTimes,
+    State,
+    CreateAt,
+    UpdateAt,
+}
@@ -0,0 +1,110 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupRelayTask::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GroupRelayTask::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayTask::RequestId)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayTask::RelayedChainId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayTask::ControllerGlobalEpoch)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayTask::RelayedGroupIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayTask::RelayedGroupEpoch)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayTask::AssignmentBlockHeight)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(GroupRelayTask::State).integer().not_null())
+                    .col(ColumnDef::new(GroupRelayTask::CreateAt).text().not_null())
+                    .col(ColumnDef::new(GroupRelayTask::UpdateAt).text().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayTask::Table)
+                    .name("group_relay_task_request_id")
+                    .col(GroupRelayTask::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayTask::Table)
+                    .name("group_relay_task_relayed_group_index")
+                    .col(GroupRelayTask::RelayedGroupIndex)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayTask::Table)
+                    .name("group_relay_task_state")
+                    .col(GroupRelayTask::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GroupRelayTask::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum GroupRelayTask {
+    Table,
+    Id,
+    RequestId,
+    RelayedChainId,
+    ControllerGlobalEpoch,
+    RelayedGroupIndex,
+    RelayedGroupEpoch,
+    AssignmentBlockHeight,
+    State,
+    CreateAt,
+    UpdateAt,
+}
@@ -0,0 +1,101 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupRelayResult::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GroupRelayResult::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayResult::RequestId)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayResult::GroupIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayResult::Message)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayResult::Threshold)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayResult::PartialSignatures)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayResult::CommittedTimes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(GroupRelayResult::State).integer().not_null())
+                    .col(ColumnDef::new(GroupRelayResult::CreateAt).text().not_null())
+                    .col(ColumnDef::new(GroupRelayResult::UpdateAt).text().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayResult::Table)
+                    .name("group_relay_result_request_id")
+                    .col(GroupRelayResult::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayResult::Table)
+                    .name("group_relay_result_state")
+                    .col(GroupRelayResult::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GroupRelayResult::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum GroupRelayResult {
+    Table,
+    Id,
+    RequestId,
+    GroupIndex,
+    Message,
+    Threshold,
+    PartialSignatures,
+    CommittedTimes,
+    State,
+    CreateAt,
+    UpdateAt,
+}
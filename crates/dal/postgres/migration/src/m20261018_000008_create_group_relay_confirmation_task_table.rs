@@ -0,0 +1,133 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::ChainId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RequestId)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::GroupRelayCacheIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RelayedGroupIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RelayedGroupEpoch)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RelayerGroupIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::AssignmentBlockHeight)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::State)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::CreateAt)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::UpdateAt)
+                            .text()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .name("group_relay_confirmation_task_chain_id_request_id")
+                    .col(GroupRelayConfirmationTask::ChainId)
+                    .col(GroupRelayConfirmationTask::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .name("group_relay_confirmation_task_relayed_group_index")
+                    .col(GroupRelayConfirmationTask::RelayedGroupIndex)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .name("group_relay_confirmation_task_state")
+                    .col(GroupRelayConfirmationTask::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum GroupRelayConfirmationTask {
+    Table,
+    Id,
+    ChainId,
+    RequestId,
+    GroupRelayCacheIndex,
+    RelayedGroupIndex,
+    RelayedGroupEpoch,
+    RelayerGroupIndex,
+    AssignmentBlockHeight,
+    State,
+    CreateAt,
+    UpdateAt,
+}
@@ -0,0 +1,124 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupRelayConfirmationResult::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::ChainId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::RequestId)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::GroupIndex)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::Message)
+                            .binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::Threshold)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::PartialSignatures)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::CommittedTimes)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::State)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::CreateAt)
+                            .text()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationResult::UpdateAt)
+                            .text()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationResult::Table)
+                    .name("group_relay_confirmation_result_chain_id_request_id")
+                    .col(GroupRelayConfirmationResult::ChainId)
+                    .col(GroupRelayConfirmationResult::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationResult::Table)
+                    .name("group_relay_confirmation_result_state")
+                    .col(GroupRelayConfirmationResult::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(GroupRelayConfirmationResult::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum GroupRelayConfirmationResult {
+    Table,
+    Id,
+    ChainId,
+    RequestId,
+    GroupIndex,
+    Message,
+    Threshold,
+    PartialSignatures,
+    CommittedTimes,
+    State,
+    CreateAt,
+    UpdateAt,
+}
@@ -0,0 +1,1097 @@
+use arpa_sea_orm_db::{SeaOrmDB, SecretCipher};
+use log::LevelFilter;
+use migration::Migrator;
+use migration::MigratorTrait;
+use sea_orm::ConnectOptions;
+use std::time::Duration;
+
+/// Connects to the database at the URL and brings its schema up to date. The database is
+/// created by the operator beforehand, and keeps the state of one node.
+///
+/// Unlike the SQLite file, the server stores the rows as they are, so the columns holding secrets
+/// are encrypted with a key derived from the signing key of the node, which is required.
+pub async fn build(
+    database_url: &str,
+    signing_key: &[u8],
+) -> Result<SeaOrmDB, Box<dyn std::error::Error>> {
+    if signing_key.is_empty() {
+        return Err("The key to encrypt the secrets in the database is missing.".into());
+    }
+
+    let mut opt = ConnectOptions::new(database_url.to_owned());
+    opt.max_connections(20)
+        .min_connections(1)
+        .connect_timeout(Duration::from_secs(8))
+        .idle_timeout(Duration::from_secs(60))
+        .sqlx_logging(true)
+        .sqlx_logging_level(LevelFilter::Debug);
+
+    let connection = sea_orm::Database::connect(opt).await?;
+
+    let db = SeaOrmDB::new(connection, Some(SecretCipher::new(signing_key)));
+
+    Migrator::up(db.get_connection(), None).await?;
+
+    db.check_secret_cipher().await.map_err(|e|
+        format!("Node identity is different from the database, please check the (account)cipher key. Original error: {:?}", e.to_string()))?;
+
+    Ok(db)
+}
+
+#[cfg(test)]
+pub mod postgres_tests {
+    use arpa_core::DKGStatus;
+    use arpa_core::DKGTask;
+    use arpa_core::RandomnessRequestType;
+    use arpa_core::RandomnessTask;
+    use arpa_core::DEFAULT_RANDOMNESS_TASK_EXCLUSIVE_WINDOW;
+    use arpa_core::PLACEHOLDER_ADDRESS;
+    use arpa_core::{DataRetentionDescriptor, RetentionPolicyDescriptor};
+    use arpa_core::{GroupRelayConfirmationTask, GroupRelayTask};
+    use arpa_dal::BLSResultCacheState;
+    use arpa_dal::BLSTasksFetcher;
+    use arpa_dal::BLSTasksUpdater;
+    use arpa_dal::BlockCheckpointFetcher;
+    use arpa_dal::BlockCheckpointUpdater;
+    use arpa_dal::GroupInfoFetcher;
+    use arpa_dal::GroupInfoUpdater;
+    use arpa_dal::NodeInfoFetcher;
+    use arpa_dal::NodeInfoUpdater;
+    use arpa_dal::SignatureResultCacheFetcher;
+    use arpa_dal::SignatureResultCacheUpdater;
+    use arpa_sea_orm_db::test_helper;
+    use arpa_sea_orm_db::SeaOrmDB;
+    use ethers_core::types::Address;
+    use ethers_core::types::U256;
+    use sea_orm::{ConnectionTrait, Database, DatabaseBackend, Statement};
+    use std::env;
+    use threshold_bls::curve::bn254::G2Curve;
+    use threshold_bls::schemes::bn254::G2Scheme;
+    use threshold_bls::sig::Scheme;
+
+    /// The URL of a database on the server the tests create their own databases in,
+    /// e.g. `postgres://postgres@localhost:5432/postgres`.
+    const TEST_DATABASE_URL: &str = "ARPA_POSTGRES_TEST_URL";
+
+    const CIPHER_KEY: &str = "passphrase";
+
+    /// Creates an empty database for the test and returns its URL. The tests which need a server
+    /// are ignored by default, and are run with `cargo test -- --ignored` once it is given.
+    async fn setup() -> String {
+        let server_url = env::var(TEST_DATABASE_URL)
+            .unwrap_or_else(|_| panic!("{} is not set", TEST_DATABASE_URL));
+
+        let database_name = format!("arpa_test_{}", rand::random::<u64>());
+
+        Database::connect(&server_url)
+            .await
+            .expect("could not connect to the server")
+            .execute_unprepared(&format!("create database {}", database_name))
+            .await
+            .expect("could not create database");
+
+        let (server, _) = server_url.rsplit_once('/').unwrap();
+
+        format!("{}/{}", server, database_name)
+    }
+
+    async fn teardown(database_url: &str) {
+        let server_url = env::var(TEST_DATABASE_URL).unwrap();
+
+        let (_, database_name) = database_url.rsplit_once('/').unwrap();
+
+        Database::connect(&server_url)
+            .await
+            .expect("could not connect to the server")
+            .execute_unprepared(&format!("drop database {} with (force)", database_name))
+            .await
+            .expect("could not drop database");
+    }
+
+    pub async fn build_postgres_db(
+        database_url: &str,
+    ) -> Result<SeaOrmDB, Box<dyn std::error::Error>> {
+        crate::build(database_url, CIPHER_KEY.as_bytes()).await
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_build_db() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await;
+
+        assert!(db.is_ok());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_save_node_info() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_node_info_client::<G2Curve>();
+
+        let id_address = "0x0000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let node_rpc_endpoint = String::from("127.0.0.1");
+
+        let rng = &mut rand::thread_rng();
+
+        let (private_key, public_key) = G2Scheme::keypair(rng);
+
+        db.save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
+            .await
+            .unwrap();
+
+        assert_eq!(id_address, db.get_id_address().unwrap());
+        assert_eq!("127.0.0.1", db.get_node_rpc_endpoint().unwrap());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_save_node_rpc_endpoint() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_node_info_client::<G2Curve>();
+
+        let id_address = "0x0000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let node_rpc_endpoint = String::from("127.0.0.1");
+
+        let rng = &mut rand::thread_rng();
+
+        let (private_key, public_key) = G2Scheme::keypair(rng);
+
+        db.save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
+            .await
+            .unwrap();
+
+        let node_rpc_endpoint = String::from("192.168.0.1");
+
+        db.set_node_rpc_endpoint(node_rpc_endpoint).await.unwrap();
+
+        assert_eq!("192.168.0.1", db.get_node_rpc_endpoint().unwrap());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_save_node_dkg_key_pair() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_node_info_client::<G2Curve>();
+
+        let id_address = "0x0000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let node_rpc_endpoint = String::from("127.0.0.1");
+
+        let rng = &mut rand::thread_rng();
+
+        let (private_key, public_key) = G2Scheme::keypair(rng);
+
+        db.save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
+            .await
+            .unwrap();
+
+        let rng = &mut rand::thread_rng();
+
+        let (private_key, public_key) = G2Scheme::keypair(rng);
+
+        db.set_dkg_key_pair(private_key, public_key).await.unwrap();
+
+        assert_eq!(&private_key, db.get_dkg_private_key().unwrap());
+        assert_eq!(&public_key, db.get_dkg_public_key().unwrap());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_get_current_group_info_when_no_task() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+
+        if let Ok(res) = db.refresh_current_group_info().await {
+            assert!(!res);
+        } else {
+            panic!("should not fail");
+        }
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_save_grouping_task_info() {
+        let database_url = setup().await;
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        let member_1 = "0x0000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let member_2 = "0x0000000000000000000000000000000000000002"
+            .parse()
+            .unwrap();
+        let member_3 = "0x0000000000000000000000000000000000000003"
+            .parse()
+            .unwrap();
+        let members: Vec<Address> = [member_1, member_2, member_3].to_vec();
+
+        let task = DKGTask {
+            group_index: 1,
+            epoch: 1,
+            size: 3,
+            threshold: 3,
+            members,
+            assignment_block_height: 100,
+            coordinator_address: "0x00000000000000000000000000000000000000c1"
+                .parse()
+                .unwrap(),
+        };
+
+        db.save_task_info(1, task).await.unwrap();
+
+        assert_eq!(3, db.get_size().unwrap());
+        assert_eq!(3, db.get_threshold().unwrap());
+        assert_eq!(0, db.get_member(member_1).unwrap().index);
+        assert_eq!(1, db.get_member(member_2).unwrap().index);
+        assert_eq!(2, db.get_member(member_3).unwrap().index);
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_update_dkg_status() {
+        let database_url = setup().await;
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        let member_1 = "0x0000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let member_2 = "0x0000000000000000000000000000000000000002"
+            .parse()
+            .unwrap();
+        let member_3 = "0x0000000000000000000000000000000000000003"
+            .parse()
+            .unwrap();
+        let members: Vec<Address> = [member_1, member_2, member_3].to_vec();
+
+        let task = DKGTask {
+            group_index: 1,
+            epoch: 1,
+            size: 3,
+            threshold: 3,
+            members,
+            assignment_block_height: 100,
+            coordinator_address: "0x00000000000000000000000000000000000000c1"
+                .parse()
+                .unwrap(),
+        };
+
+        db.save_task_info(1, task).await.unwrap();
+
+        db.update_dkg_status(1, 1, DKGStatus::InPhase)
+            .await
+            .unwrap();
+
+        assert_eq!(DKGStatus::InPhase, db.get_dkg_status().unwrap());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_save_dkg_checkpoint() {
+        let database_url = setup().await;
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut group_info_client = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        let members: Vec<Address> = [
+            "0x0000000000000000000000000000000000000001"
+                .parse()
+                .unwrap(),
+            "0x0000000000000000000000000000000000000002"
+                .parse()
+                .unwrap(),
+            "0x0000000000000000000000000000000000000003"
+                .parse()
+                .unwrap(),
+        ]
+        .to_vec();
+
+        let task = DKGTask {
+            group_index: 1,
+            epoch: 1,
+            size: 3,
+            threshold: 3,
+            members,
+            assignment_block_height: 100,
+            coordinator_address: "0x00000000000000000000000000000000000000c1"
+                .parse()
+                .unwrap(),
+        };
+
+        group_info_client.save_task_info(1, task).await.unwrap();
+        group_info_client
+            .update_dkg_status(1, 1, DKGStatus::InPhase)
+            .await
+            .unwrap();
+        group_info_client
+            .save_dkg_checkpoint(1, 1, vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        assert!(group_info_client
+            .save_dkg_checkpoint(1, 2, vec![4, 5, 6])
+            .await
+            .is_err());
+
+        // a restarted node reads the checkpoint back from the db
+        let mut restarted_client = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        restarted_client.refresh_current_group_info().await.unwrap();
+
+        assert_eq!(
+            Some(&[1u8, 2, 3][..]),
+            restarted_client.get_dkg_checkpoint().unwrap()
+        );
+
+        restarted_client
+            .save_failed_output(1, 1, vec![])
+            .await
+            .unwrap();
+
+        let mut restarted_client = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        restarted_client.refresh_current_group_info().await.unwrap();
+
+        assert_eq!(None, restarted_client.get_dkg_checkpoint().unwrap());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    async fn test_refuse_to_build_db_without_cipher_key() {
+        assert!(crate::build("postgres://localhost:5432/arpa", &[])
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_encrypt_secret_columns() {
+        let database_url = setup().await;
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let (private_key, public_key) = G2Scheme::keypair(&mut rand::thread_rng());
+        db.get_node_info_client::<G2Curve>()
+            .save_node_info(
+                PLACEHOLDER_ADDRESS,
+                String::from("127.0.0.1"),
+                private_key,
+                public_key,
+            )
+            .await
+            .unwrap();
+
+        let mut group_info_client = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        let members: Vec<Address> = (1..=3).map(Address::from_low_u64_be).collect();
+        let task = DKGTask {
+            group_index: 1,
+            epoch: 1,
+            size: 3,
+            threshold: 3,
+            members,
+            assignment_block_height: 100,
+            coordinator_address: Address::from_low_u64_be(0xc1),
+        };
+        group_info_client.save_task_info(0, task).await.unwrap();
+        group_info_client
+            .save_dkg_checkpoint(1, 1, vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        let select_column = |sql: &str| {
+            let sql = sql.to_owned();
+            let connection = db.get_connection().clone();
+            async move {
+                connection
+                    .query_one(Statement::from_string(DatabaseBackend::Postgres, sql))
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .try_get::<Vec<u8>>("", "secret")
+                    .unwrap()
+            }
+        };
+
+        let dkg_checkpoint = select_column("select dkg_checkpoint as secret from group_info").await;
+        assert_ne!(vec![1u8, 2, 3], dkg_checkpoint);
+        assert_eq!(
+            Some(&[1u8, 2, 3][..]),
+            group_info_client.get_dkg_checkpoint().unwrap()
+        );
+
+        let (mut board, phase0s) =
+            test_helper::setup::<G2Curve, G2Scheme, _>(3, 3, &mut rand::thread_rng());
+        let output = test_helper::run_dkg::<G2Curve>(&mut board, phase0s)
+            .await
+            .remove(0);
+        group_info_client
+            .save_successful_output(1, 1, output.clone())
+            .await
+            .unwrap();
+
+        let share = select_column("select share as secret from group_info").await;
+        assert_ne!(bincode::serialize(&output.share).unwrap(), share);
+
+        let dkg_private_key =
+            select_column("select dkg_private_key as secret from node_info").await;
+        assert_ne!(bincode::serialize(&private_key).unwrap(), dkg_private_key);
+
+        // the secrets can only be read back with the same signing key
+        assert!(crate::build(&database_url, b"another passphrase")
+            .await
+            .is_err());
+
+        let restarted_db = build_postgres_db(&database_url).await.unwrap();
+        let mut node_info_client = restarted_db.get_node_info_client::<G2Curve>();
+        node_info_client.refresh_current_node_info().await.unwrap();
+        assert_eq!(
+            &private_key,
+            node_info_client.get_dkg_private_key().unwrap()
+        );
+        let mut group_info_client =
+            restarted_db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        group_info_client
+            .refresh_current_group_info()
+            .await
+            .unwrap();
+        assert_eq!(&output.share, group_info_client.get_secret_share().unwrap());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_save_output() {
+        let database_url = setup().await;
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        let member_1 = "0x0000000000000000000000000000000000000001"
+            .parse()
+            .unwrap();
+        let member_2 = "0x0000000000000000000000000000000000000002"
+            .parse()
+            .unwrap();
+        let member_3 = "0x0000000000000000000000000000000000000003"
+            .parse()
+            .unwrap();
+        let members: Vec<Address> = [member_1, member_2, member_3].to_vec();
+
+        let task = DKGTask {
+            group_index: 1,
+            epoch: 1,
+            size: 3,
+            threshold: 3,
+            members,
+            assignment_block_height: 100,
+            coordinator_address: "0x00000000000000000000000000000000000000c1"
+                .parse()
+                .unwrap(),
+        };
+
+        db.save_task_info(0, task).await.unwrap();
+
+        let (t, n) = (3, 3);
+
+        let rng = &mut rand::thread_rng();
+
+        let (mut board, phase0s) = test_helper::setup::<G2Curve, G2Scheme, _>(n, t, rng);
+
+        let mut outputs = test_helper::run_dkg::<G2Curve>(&mut board, phase0s).await;
+
+        let output = outputs.remove(0);
+
+        db.save_successful_output(1, 1, output.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(3, db.get_size().unwrap());
+        assert_eq!(output.share, db.get_secret_share().unwrap().to_owned());
+        assert_eq!(output.public.public_key(), db.get_public_key().unwrap());
+        assert_eq!(
+            Some(output.public.eval(0).value),
+            db.get_member(member_1).unwrap().partial_public_key
+        );
+        assert_eq!(
+            Some(output.public.eval(1).value),
+            db.get_member(member_2).unwrap().partial_public_key
+        );
+        assert_eq!(
+            Some(output.public.eval(2).value),
+            db.get_member(member_3).unwrap().partial_public_key
+        );
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_add_and_get_randomness_task_with_assigned_group() {
+        let database_url = setup().await;
+
+        let randomness_task_exclusive_window = 10;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_bls_tasks_client::<RandomnessTask>(0);
+
+        let request_id = vec![1];
+
+        let seed = U256::from_big_endian(&String::from("test task").into_bytes());
+
+        let task = RandomnessTask {
+            request_id: request_id.clone(),
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed,
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        db.add(task.clone()).await.unwrap();
+
+        assert!(db.contains(&request_id).await.unwrap());
+        assert_eq!(task, db.get(&request_id).await.unwrap());
+        assert!(!db.is_handled(&request_id).await.unwrap());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(100, 1, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(100, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(1, available_tasks.len());
+        assert_eq!(request_id, available_tasks[0].request_id);
+        assert_eq!(seed, available_tasks[0].seed);
+        assert_eq!(2, available_tasks[0].group_index);
+        assert_eq!(100, available_tasks[0].assignment_block_height);
+
+        assert!(db.contains(&request_id).await.unwrap());
+        assert_eq!(task, db.get(&request_id).await.unwrap());
+        assert!(db.is_handled(&request_id).await.unwrap());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(100, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_add_and_get_randomness_task_over_exclusive_window() {
+        let database_url = setup().await;
+
+        let randomness_task_exclusive_window = 10;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_bls_tasks_client::<RandomnessTask>(0);
+
+        let request_id = vec![1];
+
+        let seed = U256::from_big_endian(&String::from("test task").into_bytes());
+
+        let task = RandomnessTask {
+            request_id: request_id.clone(),
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed,
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        db.add(task.clone()).await.unwrap();
+
+        assert!(db.contains(&request_id).await.unwrap());
+        assert_eq!(task, db.get(&request_id).await.unwrap());
+        assert!(!db.is_handled(&request_id).await.unwrap());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(
+                100 + DEFAULT_RANDOMNESS_TASK_EXCLUSIVE_WINDOW,
+                1,
+                randomness_task_exclusive_window,
+            )
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(
+                100 + DEFAULT_RANDOMNESS_TASK_EXCLUSIVE_WINDOW + 1,
+                1,
+                randomness_task_exclusive_window,
+            )
+            .await
+            .unwrap();
+        assert_eq!(1, available_tasks.len());
+        assert_eq!(request_id, available_tasks[0].request_id);
+        assert_eq!(seed, available_tasks[0].seed);
+        assert_eq!(2, available_tasks[0].group_index);
+        assert_eq!(100, available_tasks[0].assignment_block_height);
+
+        assert!(db.contains(&request_id).await.unwrap());
+        assert_eq!(task, db.get(&request_id).await.unwrap());
+        assert!(db.is_handled(&request_id).await.unwrap());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(
+                100 + DEFAULT_RANDOMNESS_TASK_EXCLUSIVE_WINDOW + 1,
+                1,
+                randomness_task_exclusive_window,
+            )
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_hold_randomness_task_until_confirmed_and_remove() {
+        let database_url = setup().await;
+
+        let randomness_task_exclusive_window = 10;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut db = db.get_bls_tasks_client::<RandomnessTask>(0);
+
+        let request_id = vec![1];
+
+        let task = RandomnessTask {
+            request_id: request_id.clone(),
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from_big_endian(&String::from("test task").into_bytes()),
+            request_confirmations: 3,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        db.add(task.clone()).await.unwrap();
+
+        let available_tasks = db
+            .check_and_get_available_tasks(102, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+        assert!(!db.is_handled(&request_id).await.unwrap());
+        assert_eq!(vec![task.clone()], db.get_unhandled().await.unwrap());
+
+        db.remove(&request_id).await.unwrap();
+        assert!(!db.contains(&request_id).await.unwrap());
+
+        db.add(task.clone()).await.unwrap();
+
+        let available_tasks = db
+            .check_and_get_available_tasks(103, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(1, available_tasks.len());
+        assert_eq!(task, available_tasks[0]);
+        assert!(db.get_unhandled().await.unwrap().is_empty());
+
+        // a released task is handed out again
+        db.release(&request_id).await.unwrap();
+        assert!(!db.is_handled(&request_id).await.unwrap());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(104, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(vec![task.clone()], available_tasks);
+
+        // handed out or not, the tasks are looked up by their assignment block
+        assert_eq!(vec![task], db.get_assigned_from(100).await.unwrap());
+        assert!(db.get_assigned_from(101).await.unwrap().is_empty());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_save_block_checkpoint() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut main_checkpoint = db.get_block_checkpoint_client(0);
+
+        let mut relayed_checkpoint = db.get_block_checkpoint_client(10);
+
+        assert_eq!(
+            None,
+            main_checkpoint
+                .get_last_processed_block_height()
+                .await
+                .unwrap()
+        );
+
+        main_checkpoint
+            .set_last_processed_block_height(100)
+            .await
+            .unwrap();
+
+        relayed_checkpoint
+            .set_last_processed_block_height(5)
+            .await
+            .unwrap();
+
+        main_checkpoint
+            .set_last_processed_block_height(200)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(200),
+            main_checkpoint
+                .get_last_processed_block_height()
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Some(5),
+            relayed_checkpoint
+                .get_last_processed_block_height()
+                .await
+                .unwrap()
+        );
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_add_and_get_group_relay_tasks() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let mut relay_tasks = db.get_group_relay_tasks_client();
+
+        let relay_task = GroupRelayTask::new(10, 5, 1, 3, 100);
+
+        relay_tasks.add(relay_task.clone()).await.unwrap();
+
+        assert_eq!(
+            relay_task,
+            relay_tasks.get(&relay_task.request_id).await.unwrap()
+        );
+
+        // the relayed group doesn't sign the relay of itself
+        let available_tasks = relay_tasks
+            .check_and_get_available_tasks(100, 1, 0)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = relay_tasks
+            .check_and_get_available_tasks(100, 2, 0)
+            .await
+            .unwrap();
+        assert_eq!(vec![relay_task.clone()], available_tasks);
+        assert!(relay_tasks
+            .is_handled(&relay_task.request_id)
+            .await
+            .unwrap());
+
+        let mut main_confirmation_tasks = db.get_group_relay_confirmation_tasks_client(0);
+
+        let mut relayed_confirmation_tasks = db.get_group_relay_confirmation_tasks_client(10);
+
+        let confirmation_task = GroupRelayConfirmationTask::new(0, 1, 3, 2, 50);
+
+        relayed_confirmation_tasks
+            .add(confirmation_task.clone())
+            .await
+            .unwrap();
+
+        assert!(!main_confirmation_tasks
+            .contains(&confirmation_task.request_id)
+            .await
+            .unwrap());
+
+        // only the relayed group confirms the relay of itself
+        let available_tasks = relayed_confirmation_tasks
+            .check_and_get_available_tasks(50, 2, 0)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = main_confirmation_tasks
+            .check_and_get_available_tasks(50, 1, 0)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = relayed_confirmation_tasks
+            .check_and_get_available_tasks(50, 1, 0)
+            .await
+            .unwrap();
+        assert_eq!(vec![confirmation_task], available_tasks);
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_count_pending_randomness_results() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let request_id = vec![1];
+
+        let task = RandomnessTask {
+            request_id: request_id.clone(),
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from(1),
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        db.get_bls_tasks_client::<RandomnessTask>(0)
+            .add(task.clone())
+            .await
+            .unwrap();
+
+        let mut results = db.get_randomness_result_client(0).await.unwrap();
+
+        assert_eq!(0, results.count_pending().await.unwrap());
+
+        results.add(2, task, vec![1], 3).await.unwrap();
+
+        assert_eq!(1, results.count_pending().await.unwrap());
+
+        results
+            .update_commit_result(&request_id, BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+
+        assert_eq!(0, results.count_pending().await.unwrap());
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_prune_randomness_tasks_and_results() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let build_task = |request_id: u8, assignment_block_height: usize| RandomnessTask {
+            request_id: vec![request_id],
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from(request_id),
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height,
+        };
+
+        let mut tasks = db.get_bls_tasks_client::<RandomnessTask>(0);
+        let mut results = db.get_randomness_result_client(0).await.unwrap();
+
+        // committed long ago
+        tasks.add(build_task(1, 100)).await.unwrap();
+        results
+            .add(2, build_task(1, 100), vec![1], 3)
+            .await
+            .unwrap();
+        results
+            .update_commit_result(&[1], BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+        // not committed yet
+        tasks.add(build_task(2, 100)).await.unwrap();
+        results
+            .add(2, build_task(2, 100), vec![2], 3)
+            .await
+            .unwrap();
+        // of another group, without a result
+        tasks.add(build_task(3, 100)).await.unwrap();
+        // committed recently
+        tasks.add(build_task(4, 950)).await.unwrap();
+        results
+            .add(2, build_task(4, 950), vec![4], 3)
+            .await
+            .unwrap();
+        results
+            .update_commit_result(&[4], BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+
+        // keeping everything forever
+        let retention = DataRetentionDescriptor {
+            pruning_interval_millis: 1000,
+            committed: RetentionPolicyDescriptor::default(),
+            committed_by_others: RetentionPolicyDescriptor::default(),
+            expired: RetentionPolicyDescriptor::default(),
+            faulty: RetentionPolicyDescriptor::default(),
+            tasks: RetentionPolicyDescriptor::default(),
+        };
+        assert_eq!(0, db.prune(0, 1000, &retention).await.unwrap());
+
+        let policy = RetentionPolicyDescriptor {
+            max_age_secs: None,
+            max_block_distance: Some(500),
+        };
+        let retention = DataRetentionDescriptor {
+            committed: policy,
+            committed_by_others: policy,
+            expired: policy,
+            faulty: policy,
+            tasks: policy,
+            ..retention
+        };
+        assert_eq!(3, db.prune(0, 1000, &retention).await.unwrap());
+
+        assert!(!tasks.contains(&[1]).await.unwrap());
+        assert!(!results.contains(&[1]).await.unwrap());
+        assert!(tasks.contains(&[2]).await.unwrap());
+        assert!(results.contains(&[2]).await.unwrap());
+        assert!(!tasks.contains(&[3]).await.unwrap());
+        assert!(tasks.contains(&[4]).await.unwrap());
+        assert!(results.contains(&[4]).await.unwrap());
+
+        let table_sizes = db.get_table_sizes().await.unwrap();
+        assert!(table_sizes.contains(&("randomness_task", 2)));
+        assert!(table_sizes.contains(&("randomness_result", 2)));
+        assert!(table_sizes.contains(&("group_relay_task", 0)));
+        assert!(db.get_database_size().await.unwrap() > 0);
+
+        teardown(&database_url).await;
+    }
+
+    #[tokio::test]
+    #[ignore = "needs a PostgreSQL server at ARPA_POSTGRES_TEST_URL"]
+    async fn test_keep_randomness_tasks_of_chains_apart() {
+        let database_url = setup().await;
+
+        let db = build_postgres_db(&database_url).await.unwrap();
+
+        let task = RandomnessTask {
+            request_id: vec![1],
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from(1),
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        // any relayed chain
+        let relayed_chain_id = 12345;
+
+        let mut main_tasks = db.get_bls_tasks_client::<RandomnessTask>(0);
+        let mut relayed_tasks = db.get_bls_tasks_client::<RandomnessTask>(relayed_chain_id);
+
+        main_tasks.add(task.clone()).await.unwrap();
+        assert!(!relayed_tasks.contains(&[1]).await.unwrap());
+
+        // the same request id on another chain
+        relayed_tasks.add(task.clone()).await.unwrap();
+
+        let mut main_results = db.get_randomness_result_client(0).await.unwrap();
+        let mut relayed_results = db
+            .get_randomness_result_client(relayed_chain_id)
+            .await
+            .unwrap();
+
+        relayed_results
+            .add(2, task.clone(), vec![1], 3)
+            .await
+            .unwrap();
+        assert!(!main_results.contains(&[1]).await.unwrap());
+
+        main_results.add(2, task, vec![1], 3).await.unwrap();
+        relayed_results
+            .update_commit_result(&[1], BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            BLSResultCacheState::NotCommitted,
+            main_results.get(&[1]).await.unwrap().state
+        );
+        assert_eq!(
+            BLSResultCacheState::Committed,
+            relayed_results.get(&[1]).await.unwrap().state
+        );
+
+        relayed_tasks.remove(&[1]).await.unwrap();
+        assert!(main_tasks.contains(&[1]).await.unwrap());
+        assert!(!relayed_tasks.contains(&[1]).await.unwrap());
+
+        teardown(&database_url).await;
+    }
+}
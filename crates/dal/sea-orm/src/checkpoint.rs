@@ -0,0 +1,127 @@
+use crate::types::DBError;
+use crate::types::SeaOrmDB;
+use arpa_core::format_now_date;
+use arpa_dal::error::DataAccessResult;
+use arpa_dal::{BlockCheckpointFetcher, BlockCheckpointUpdater};
+use async_trait::async_trait;
+use entity::block_checkpoint;
+use entity::prelude::BlockCheckpoint;
+use sea_orm::{ActiveModelTrait, DbConn, DbErr, EntityTrait, Set};
+use std::sync::Arc;
+
+impl SeaOrmDB {
+    pub fn get_block_checkpoint_client(&self, chain_id: usize) -> BlockCheckpointDBClient {
+        BlockCheckpointDBClient {
+            db_client: Arc::new(self.clone()),
+            chain_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BlockCheckpointDBClient {
+    db_client: Arc<SeaOrmDB>,
+    chain_id: usize,
+}
+
+impl BlockCheckpointDBClient {
+    pub fn get_connection(&self) -> &DbConn {
+        &self.db_client.connection
+    }
+}
+
+#[async_trait]
+impl BlockCheckpointFetcher for BlockCheckpointDBClient {
+    async fn get_last_processed_block_height(&self) -> DataAccessResult<Option<usize>> {
+        let checkpoint =
+            BlockCheckpointQuery::select_by_chain_id(self.get_connection(), self.chain_id as i32)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+
+        Ok(checkpoint.map(|c| c.last_processed_block_height as usize))
+    }
+}
+
+#[async_trait]
+impl BlockCheckpointUpdater for BlockCheckpointDBClient {
+    async fn set_last_processed_block_height(
+        &mut self,
+        block_height: usize,
+    ) -> DataAccessResult<()> {
+        let conn = self.get_connection();
+
+        let res = match BlockCheckpointQuery::select_by_chain_id(conn, self.chain_id as i32).await {
+            Ok(Some(model)) => {
+                BlockCheckpointMutation::update_last_processed_block_height(
+                    conn,
+                    model,
+                    block_height as i64,
+                )
+                .await
+            }
+            Ok(None) => {
+                BlockCheckpointMutation::create_block_checkpoint(
+                    conn,
+                    self.chain_id as i32,
+                    block_height as i64,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        res.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(())
+    }
+}
+
+pub struct BlockCheckpointQuery;
+
+impl BlockCheckpointQuery {
+    pub async fn select_by_chain_id(
+        db: &DbConn,
+        chain_id: i32,
+    ) -> Result<Option<block_checkpoint::Model>, DbErr> {
+        BlockCheckpoint::find_by_id(chain_id).one(db).await
+    }
+}
+
+pub struct BlockCheckpointMutation;
+
+impl BlockCheckpointMutation {
+    pub async fn create_block_checkpoint(
+        db: &DbConn,
+        chain_id: i32,
+        last_processed_block_height: i64,
+    ) -> Result<block_checkpoint::Model, DbErr> {
+        block_checkpoint::ActiveModel {
+            chain_id: Set(chain_id),
+            last_processed_block_height: Set(last_processed_block_height),
+            create_at: Set(format_now_date()),
+            update_at: Set(format_now_date()),
+        }
+        .insert(db)
+        .await
+    }
+
+    pub async fn update_last_processed_block_height(
+        db: &DbConn,
+        model: block_checkpoint::Model,
+        last_processed_block_height: i64,
+    ) -> Result<block_checkpoint::Model, DbErr> {
+        let mut block_checkpoint: block_checkpoint::ActiveModel = model.into();
+
+        block_checkpoint.last_processed_block_height = Set(last_processed_block_height);
+
+        block_checkpoint.update_at = Set(format_now_date());
+
+        block_checkpoint.update(db).await
+    }
+}
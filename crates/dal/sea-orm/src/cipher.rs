@@ -0,0 +1,125 @@
+use crate::types::{DBError, DBResult};
+use chacha20poly1305::{
+    aead::{Aead, NewAead, Payload},
+    ChaCha20Poly1305, Key, Nonce,
+};
+use hkdf::Hkdf;
+use rand::RngCore;
+use sha2::Sha256;
+
+/// The nonce length
+const NONCE_LEN: usize = 12;
+
+/// The key length
+const KEY_LEN: usize = 32;
+
+/// A domain separator of the key derived for the secret columns
+const DOMAIN: &[u8] = b"arpa-sea-orm-db secret columns";
+
+/// Encrypts the columns holding secrets, i.e. the DKG private key, the share and the DKG
+/// checkpoint, with a key derived from the signing key of the node.
+///
+/// Every value is sealed with a random nonce, which is stored in front of the ciphertext, and is
+/// bound to its column so that the values of two columns can't be swapped.
+#[derive(Clone)]
+pub struct SecretCipher {
+    aead: ChaCha20Poly1305,
+}
+
+impl std::fmt::Debug for SecretCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretCipher")
+            .field("aead", &"ignored")
+            .finish()
+    }
+}
+
+impl SecretCipher {
+    pub fn new(signing_key: &[u8]) -> Self {
+        let mut key = [0u8; KEY_LEN];
+        Hkdf::<Sha256>::new(None, signing_key)
+            .expand(DOMAIN, &mut key)
+            .expect("the key length is valid for the hkdf");
+
+        SecretCipher {
+            aead: ChaCha20Poly1305::new(Key::from_slice(&key)),
+        }
+    }
+
+    pub fn encrypt(&self, column: &str, plaintext: &[u8]) -> Vec<u8> {
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let ciphertext = self
+            .aead
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext,
+                    aad: column.as_bytes(),
+                },
+            )
+            .expect("aead should not fail");
+
+        [nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, column: &str, sealed: &[u8]) -> DBResult<Vec<u8>> {
+        if sealed.len() < NONCE_LEN {
+            return Err(DBError::SecretDecryptionError(column.to_owned()));
+        }
+
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+
+        self.aead
+            .decrypt(
+                Nonce::from_slice(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: column.as_bytes(),
+                },
+            )
+            .map_err(|_| DBError::SecretDecryptionError(column.to_owned()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SecretCipher;
+    use crate::types::DBError;
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        let cipher = SecretCipher::new(b"signing key");
+
+        let sealed = cipher.encrypt("share", b"secret");
+        assert_ne!(b"secret".as_slice(), &sealed[sealed.len() - 6..]);
+        assert_eq!(
+            b"secret".to_vec(),
+            cipher.decrypt("share", &sealed).unwrap()
+        );
+
+        // a fresh nonce every time
+        assert_ne!(sealed, cipher.encrypt("share", b"secret"));
+    }
+
+    #[test]
+    fn test_reject_value_of_another_key_or_column() {
+        let cipher = SecretCipher::new(b"signing key");
+
+        let sealed = cipher.encrypt("share", b"secret");
+
+        assert_eq!(
+            Err(DBError::SecretDecryptionError("share".to_owned())),
+            SecretCipher::new(b"another signing key").decrypt("share", &sealed)
+        );
+        assert_eq!(
+            Err(DBError::SecretDecryptionError("dkg_checkpoint".to_owned())),
+            cipher.decrypt("dkg_checkpoint", &sealed)
+        );
+        assert_eq!(
+            Err(DBError::SecretDecryptionError("share".to_owned())),
+            cipher.decrypt("share", &[1, 2, 3])
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use crate::types::DBError;
 use crate::types::DBResult;
-use crate::types::SqliteDB;
+use crate::types::SeaOrmDB;
 use arpa_core::Group;
 use arpa_core::Member;
 use arpa_core::{format_now_date, DKGStatus};
@@ -23,13 +23,13 @@ use threshold_bls::sig::Share;
 
 #[derive(Clone)]
 pub struct GroupInfoDBClient<C: Curve> {
-    pub(crate) db_client: Arc<SqliteDB>,
+    pub(crate) db_client: Arc<SeaOrmDB>,
     pub(crate) id_address: Address,
     pub(crate) group_info_cache_model: Option<group_info::Model>,
     pub(crate) group_info_cache: Option<InMemoryGroupInfoCache<C>>,
 }
 
-impl SqliteDB {
+impl SeaOrmDB {
     pub fn get_group_info_client<C: Curve>(&self, id_address: Address) -> GroupInfoDBClient<C> {
         GroupInfoDBClient {
             db_client: Arc::new(self.clone()),
@@ -91,16 +91,27 @@ impl<C: Curve> GroupInfoDBClient<C> {
                     c: PhantomData,
                 };
 
+                let share = group_info
+                    .share
+                    .as_ref()
+                    .map(|bytes| self.db_client.open_secret("share", bytes))
+                    .transpose()?
+                    .map(|bytes| bincode::deserialize(&bytes).unwrap());
+
+                let dkg_checkpoint = group_info
+                    .dkg_checkpoint
+                    .as_ref()
+                    .map(|bytes| self.db_client.open_secret("dkg_checkpoint", bytes))
+                    .transpose()?;
+
                 let group_info_cache = InMemoryGroupInfoCache::rebuild(
                     self.id_address,
-                    group_info
-                        .share
-                        .as_ref()
-                        .map(|bytes| bincode::deserialize(bytes).unwrap()),
+                    share,
                     group,
                     (group_info.dkg_status as usize).into(),
                     group_info.self_member_index as usize,
                     group_info.dkg_start_block_height as usize,
+                    dkg_checkpoint,
                 );
 
                 group_info_cache.refresh_context_entry();
@@ -225,6 +236,12 @@ impl<C: Curve> GroupInfoFetcher<C> for GroupInfoDBClient<C> {
         group_info_cache.get_dkg_status()
     }
 
+    fn get_dkg_checkpoint(&self) -> DataAccessResult<Option<&[u8]>> {
+        let group_info_cache = self.get_group_info_cache()?;
+
+        group_info_cache.get_dkg_checkpoint()
+    }
+
     fn is_committer(&self, id_address: Address) -> DataAccessResult<bool> {
         let group_info_cache = self.get_group_info_cache()?;
 
@@ -348,7 +365,8 @@ impl<C: Curve + Sync + Send> GroupInfoUpdater<C> for GroupInfoDBClient<C> {
             self.group_info_cache_model.to_owned().unwrap(),
             qualified_node_indices.len() as i32,
             bincode::serialize(&public_key).unwrap(),
-            bincode::serialize(&output.share).unwrap(),
+            self.db_client
+                .seal_secret("share", bincode::serialize(&output.share).unwrap()),
             serde_json::to_string(&group.members).unwrap(),
         )
         .await
@@ -407,6 +425,17 @@ impl<C: Curve + Sync + Send> GroupInfoUpdater<C> for GroupInfoDBClient<C> {
             e
         })?;
 
+        GroupMutation::update_dkg_checkpoint(
+            self.get_connection(),
+            self.group_info_cache_model.to_owned().unwrap(),
+            None,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
         self.refresh_current_group_info().await?;
 
         Ok(disqualified_nodes)
@@ -457,6 +486,44 @@ impl<C: Curve + Sync + Send> GroupInfoUpdater<C> for GroupInfoDBClient<C> {
         Ok(true)
     }
 
+    async fn save_dkg_checkpoint(
+        &mut self,
+        index: usize,
+        epoch: usize,
+        dkg_checkpoint: Vec<u8>,
+    ) -> DataAccessResult<()> {
+        let group_info_cache = self.get_group_info_cache()?;
+
+        let group = group_info_cache.get_group()?;
+
+        if group.index != index {
+            return Err(GroupError::GroupIndexObsolete(group.index).into());
+        }
+
+        if group.epoch != epoch {
+            return Err(GroupError::GroupEpochObsolete(group.epoch).into());
+        }
+
+        if group.state {
+            return Err(GroupError::GroupAlreadyReady.into());
+        }
+
+        GroupMutation::update_dkg_checkpoint(
+            self.get_connection(),
+            self.group_info_cache_model.to_owned().unwrap(),
+            Some(self.db_client.seal_secret("dkg_checkpoint", dkg_checkpoint)),
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        self.refresh_current_group_info().await?;
+
+        Ok(())
+    }
+
     async fn save_committers(
         &mut self,
         index: usize,
@@ -623,6 +690,20 @@ impl GroupMutation {
         group_info.update(db).await
     }
 
+    pub async fn update_dkg_checkpoint(
+        db: &DbConn,
+        model: group_info::Model,
+        dkg_checkpoint: Option<Vec<u8>>,
+    ) -> Result<group_info::Model, DbErr> {
+        let mut group_info: group_info::ActiveModel = model.into();
+
+        group_info.dkg_checkpoint = Set(dkg_checkpoint);
+
+        group_info.update_at = Set(format_now_date());
+
+        group_info.update(db).await
+    }
+
     pub async fn update_dkg_output(
         db: &DbConn,
         model: group_info::Model,
@@ -637,6 +718,7 @@ impl GroupMutation {
         group_info.public_key = Set(Some(public_key));
         group_info.share = Set(Some(share));
         group_info.members = Set(members);
+        group_info.dkg_checkpoint = Set(None);
 
         group_info.update_at = Set(format_now_date());
 
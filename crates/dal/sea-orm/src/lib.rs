@@ -0,0 +1,160 @@
+mod checkpoint;
+mod cipher;
+mod group;
+mod node;
+mod result;
+mod retention;
+mod task;
+pub mod test_helper;
+mod types;
+pub use crate::checkpoint::BlockCheckpointDBClient;
+pub use crate::cipher::SecretCipher;
+pub use crate::group::GroupInfoDBClient;
+pub use crate::node::NodeInfoDBClient;
+pub use crate::result::SignatureResultDBClient;
+pub use crate::result::{
+    GroupRelayConfirmationSignatureResultDBClient, GroupRelaySignatureResultDBClient,
+};
+pub use crate::task::BLSTasksDBClient;
+pub use crate::task::{GroupRelayConfirmationTasksDBClient, GroupRelayTasksDBClient};
+pub use crate::types::DBError;
+pub use crate::types::DBResult;
+pub use crate::types::SeaOrmDB;
+use arpa_core::RandomnessTask;
+use arpa_core::{GroupRelayConfirmationTask, GroupRelayTask};
+use arpa_dal::cache::RandomnessResultCache;
+use arpa_dal::cache::{GroupRelayConfirmationResultCache, GroupRelayResultCache};
+use arpa_dal::error::DataAccessResult;
+use arpa_dal::BLSTasksHandler;
+use arpa_dal::BlockCheckpointHandler;
+use arpa_dal::GroupInfoHandler;
+use arpa_dal::NodeInfoHandler;
+use arpa_dal::SignatureResultCacheHandler;
+use sea_orm::sea_query::{SelectStatement, UpdateStatement};
+use sea_orm::ConnectionTrait;
+use sea_orm::FromQueryResult;
+use threshold_bls::group::Curve;
+
+impl SeaOrmDB {
+    pub fn build_randomness_tasks_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn BLSTasksHandler<RandomnessTask>>> {
+        Ok(Box::new(
+            self.get_bls_tasks_client::<RandomnessTask>(chain_id),
+        ))
+    }
+
+    pub fn build_block_checkpoint_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn BlockCheckpointHandler>> {
+        Ok(Box::new(self.get_block_checkpoint_client(chain_id)))
+    }
+
+    pub async fn build_randomness_result_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>> {
+        Ok(Box::new(self.get_randomness_result_client(chain_id).await?))
+    }
+
+    /// Group relay tasks are only emitted by the `ControllerRelayer` on the main chain.
+    pub fn build_group_relay_tasks_cache(
+        &self,
+    ) -> DataAccessResult<Box<dyn BLSTasksHandler<GroupRelayTask>>> {
+        Ok(Box::new(self.get_group_relay_tasks_client()))
+    }
+
+    pub async fn build_group_relay_result_cache(
+        &self,
+    ) -> DataAccessResult<Box<dyn SignatureResultCacheHandler<GroupRelayResultCache>>> {
+        Ok(Box::new(self.get_group_relay_result_client().await?))
+    }
+
+    /// Group relay confirmation tasks are emitted by the `ControllerOracle` on every relayed chain,
+    /// they are kept in the same table keyed by the chain id.
+    pub fn build_group_relay_confirmation_tasks_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn BLSTasksHandler<GroupRelayConfirmationTask>>> {
+        Ok(Box::new(
+            self.get_group_relay_confirmation_tasks_client(chain_id),
+        ))
+    }
+
+    pub async fn build_group_relay_confirmation_result_cache(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<Box<dyn SignatureResultCacheHandler<GroupRelayConfirmationResultCache>>>
+    {
+        Ok(Box::new(
+            self.get_group_relay_confirmation_result_client(chain_id)
+                .await?,
+        ))
+    }
+
+    pub(crate) async fn execute_update_statement(
+        &self,
+        stmt: &UpdateStatement,
+    ) -> DataAccessResult<()> {
+        let builder = self.connection.get_database_backend();
+
+        self.connection
+            .execute(builder.build(stmt))
+            .await
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e
+            })?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn query_all_statement<S: FromQueryResult>(
+        &self,
+        stmt: &SelectStatement,
+    ) -> DataAccessResult<Vec<S>> {
+        let builder = self.connection.get_database_backend();
+
+        S::find_by_statement(builder.build(stmt))
+            .all(&self.connection)
+            .await
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e.into()
+            })
+    }
+
+    pub(crate) async fn query_one_statement<S: FromQueryResult>(
+        &self,
+        stmt: &SelectStatement,
+    ) -> DataAccessResult<Option<S>> {
+        let builder = self.connection.get_database_backend();
+
+        S::find_by_statement(builder.build(stmt))
+            .one(&self.connection)
+            .await
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e.into()
+            })
+    }
+}
+
+impl BlockCheckpointHandler for BlockCheckpointDBClient {}
+impl<PC: Curve + 'static> NodeInfoHandler<PC> for NodeInfoDBClient<PC> {}
+impl<PC: Curve + 'static> GroupInfoHandler<PC> for GroupInfoDBClient<PC> {}
+impl BLSTasksHandler<RandomnessTask> for BLSTasksDBClient<RandomnessTask> {}
+impl BLSTasksHandler<GroupRelayTask> for GroupRelayTasksDBClient {}
+impl BLSTasksHandler<GroupRelayConfirmationTask> for GroupRelayConfirmationTasksDBClient {}
+
+impl SignatureResultCacheHandler<RandomnessResultCache>
+    for SignatureResultDBClient<RandomnessResultCache>
+{
+}
+impl SignatureResultCacheHandler<GroupRelayResultCache> for GroupRelaySignatureResultDBClient {}
+impl SignatureResultCacheHandler<GroupRelayConfirmationResultCache>
+    for GroupRelayConfirmationSignatureResultDBClient
+{
+}
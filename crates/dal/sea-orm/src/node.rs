@@ -1,6 +1,6 @@
 use crate::types::DBError;
 use crate::types::DBResult;
-use crate::types::SqliteDB;
+use crate::types::SeaOrmDB;
 use arpa_core::{address_to_string, format_now_date};
 use arpa_dal::cache::InMemoryNodeInfoCache;
 use arpa_dal::error::DataAccessResult;
@@ -18,12 +18,12 @@ use threshold_bls::serialize::point_to_hex;
 
 #[derive(Clone)]
 pub struct NodeInfoDBClient<C: Curve> {
-    pub(crate) db_client: Arc<SqliteDB>,
+    pub(crate) db_client: Arc<SeaOrmDB>,
     pub(crate) node_info_cache_model: Option<node_info::Model>,
     pub(crate) node_info_cache: Option<InMemoryNodeInfoCache<C>>,
 }
 
-impl SqliteDB {
+impl SeaOrmDB {
     pub fn get_node_info_client<C: Curve>(&self) -> NodeInfoDBClient<C> {
         NodeInfoDBClient {
             db_client: Arc::new(self.clone()),
@@ -33,6 +33,18 @@ impl SqliteDB {
     }
 }
 
+impl SeaOrmDB {
+    /// Checks that the secret saved by the last run can be decrypted, i.e. that the database
+    /// belongs to the node identity.
+    pub async fn check_secret_cipher(&self) -> DBResult<()> {
+        if let Some(node_info) = NodeQuery::find_current_node_info(&self.connection).await? {
+            self.open_secret("dkg_private_key", &node_info.dkg_private_key)?;
+        }
+
+        Ok(())
+    }
+}
+
 impl<C: Curve> std::fmt::Debug for NodeInfoDBClient<C> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("NodeInfo")
@@ -67,7 +79,12 @@ impl<C: Curve> NodeInfoDBClient<C> {
                 let node_info_cache = InMemoryNodeInfoCache::rebuild(
                     node_info.id_address.parse().unwrap(),
                     node_info.node_rpc_endpoint.clone(),
-                    bincode::deserialize(&node_info.dkg_private_key).unwrap(),
+                    bincode::deserialize(
+                        &self
+                            .db_client
+                            .open_secret("dkg_private_key", &node_info.dkg_private_key)?,
+                    )
+                    .unwrap(),
                     bincode::deserialize(&node_info.dkg_public_key).unwrap(),
                 );
 
@@ -96,7 +113,10 @@ impl<C: Curve> NodeInfoDBClient<C> {
             id: 0,
             id_address: address_to_string(id_address),
             node_rpc_endpoint,
-            dkg_private_key: bincode::serialize(&dkg_private_key).unwrap(),
+            dkg_private_key: self.db_client.seal_secret(
+                "dkg_private_key",
+                bincode::serialize(&dkg_private_key).unwrap(),
+            ),
             dkg_public_key: bincode::serialize(&dkg_public_key).unwrap(),
             create_at: format_now_date(),
             update_at: format_now_date(),
@@ -170,7 +190,10 @@ impl<C: Curve + Sync + Send> NodeInfoUpdater<C> for NodeInfoDBClient<C> {
         NodeMutation::update_node_dkg_key_pair(
             self.get_connection(),
             self.node_info_cache_model.to_owned().unwrap(),
-            bincode::serialize(&dkg_private_key).unwrap(),
+            self.db_client.seal_secret(
+                "dkg_private_key",
+                bincode::serialize(&dkg_private_key).unwrap(),
+            ),
             bincode::serialize(&dkg_public_key).unwrap(),
         )
         .await
@@ -0,0 +1,887 @@
+use crate::types::DBError;
+use crate::types::SeaOrmDB;
+use crate::types::{GroupRelayConfirmationRecord, GroupRelayRecord};
+use arpa_core::format_now_date;
+use arpa_core::BLSTaskError;
+use arpa_core::{GroupRelayConfirmationTask, GroupRelayTask, Task};
+use arpa_dal::cache::BLSResultCache;
+use arpa_dal::cache::InMemorySignatureResultCache;
+use arpa_dal::cache::{GroupRelayConfirmationResultCache, GroupRelayResultCache};
+use arpa_dal::error::DataAccessResult;
+use arpa_dal::BLSResultCacheState;
+use arpa_dal::SignatureResultCacheFetcher;
+use arpa_dal::SignatureResultCacheUpdater;
+use async_trait::async_trait;
+use entity::group_relay_confirmation_result::{
+    Column as GroupRelayConfirmationResultColumn, Entity as GroupRelayConfirmationResultTable,
+};
+use entity::group_relay_confirmation_task::{
+    Column as GroupRelayConfirmationTaskColumn, Entity as GroupRelayConfirmationTaskTable,
+};
+use entity::group_relay_result::{
+    Column as GroupRelayResultColumn, Entity as GroupRelayResultTable,
+};
+use entity::group_relay_task::{Column as GroupRelayTaskColumn, Entity as GroupRelayTaskTable};
+use entity::prelude::{GroupRelayConfirmationResult, GroupRelayResult};
+use entity::{group_relay_confirmation_result, group_relay_result};
+use ethers_core::types::Address;
+use sea_orm::sea_query::{Expr, Query, SelectStatement, SimpleExpr};
+use sea_orm::TransactionTrait;
+use sea_orm::{ActiveModelTrait, DbConn, DbErr, Set};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+impl SeaOrmDB {
+    pub async fn get_group_relay_result_client(
+        &self,
+    ) -> DataAccessResult<GroupRelaySignatureResultDBClient> {
+        let txn = self.connection.begin().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        // set commit result of committing records(if any) to not committed
+        let update_stmt = Query::update()
+            .table(GroupRelayResultTable)
+            .values([
+                (
+                    GroupRelayResultColumn::State,
+                    BLSResultCacheState::NotCommitted.to_i32().into(),
+                ),
+                (GroupRelayResultColumn::UpdateAt, format_now_date().into()),
+            ])
+            .and_where(
+                Expr::col(GroupRelayResultColumn::State)
+                    .eq(BLSResultCacheState::Committing.to_i32()),
+            )
+            .to_owned();
+
+        self.execute_update_statement(&update_stmt).await?;
+
+        // load all not committed records
+        let query_stmt = build_group_relay_record_query(Some(
+            Expr::col((GroupRelayResultTable, GroupRelayResultColumn::State))
+                .eq(BLSResultCacheState::NotCommitted.to_i32()),
+        ));
+        let group_relay_results: Vec<GroupRelayRecord> =
+            self.query_all_statement(&query_stmt).await?;
+
+        txn.commit().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        let results = group_relay_results
+            .into_iter()
+            .map(|r| r.into())
+            .collect::<Vec<_>>();
+
+        Ok(GroupRelaySignatureResultDBClient {
+            db_client: Arc::new(self.clone()),
+            signature_results_cache: InMemorySignatureResultCache::<GroupRelayResultCache>::rebuild(
+                results,
+            ),
+        })
+    }
+
+    pub async fn get_group_relay_confirmation_result_client(
+        &self,
+        chain_id: usize,
+    ) -> DataAccessResult<GroupRelayConfirmationSignatureResultDBClient> {
+        let txn = self.connection.begin().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        // set commit result of committing records(if any) to not committed
+        let update_stmt = Query::update()
+            .table(GroupRelayConfirmationResultTable)
+            .values([
+                (
+                    GroupRelayConfirmationResultColumn::State,
+                    BLSResultCacheState::NotCommitted.to_i32().into(),
+                ),
+                (
+                    GroupRelayConfirmationResultColumn::UpdateAt,
+                    format_now_date().into(),
+                ),
+            ])
+            .and_where(Expr::col(GroupRelayConfirmationResultColumn::ChainId).eq(chain_id as i32))
+            .and_where(
+                Expr::col(GroupRelayConfirmationResultColumn::State)
+                    .eq(BLSResultCacheState::Committing.to_i32()),
+            )
+            .to_owned();
+
+        self.execute_update_statement(&update_stmt).await?;
+
+        // load all not committed records
+        let query_stmt = build_group_relay_confirmation_record_query(
+            chain_id,
+            Some(
+                Expr::col((
+                    GroupRelayConfirmationResultTable,
+                    GroupRelayConfirmationResultColumn::State,
+                ))
+                .eq(BLSResultCacheState::NotCommitted.to_i32()),
+            ),
+        );
+        let group_relay_confirmation_results: Vec<GroupRelayConfirmationRecord> =
+            self.query_all_statement(&query_stmt).await?;
+
+        txn.commit().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        let results = group_relay_confirmation_results
+            .into_iter()
+            .map(|r| r.into())
+            .collect::<Vec<_>>();
+
+        Ok(GroupRelayConfirmationSignatureResultDBClient {
+            db_client: Arc::new(self.clone()),
+            chain_id,
+            signature_results_cache: InMemorySignatureResultCache::<
+                GroupRelayConfirmationResultCache,
+            >::rebuild(results),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupRelaySignatureResultDBClient {
+    db_client: Arc<SeaOrmDB>,
+    signature_results_cache: InMemorySignatureResultCache<GroupRelayResultCache>,
+}
+
+impl GroupRelaySignatureResultDBClient {
+    pub fn get_connection(&self) -> &DbConn {
+        &self.db_client.connection
+    }
+}
+
+#[async_trait]
+impl SignatureResultCacheFetcher<GroupRelayResultCache> for GroupRelaySignatureResultDBClient {
+    async fn contains(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
+        let model =
+            GroupRelayResultQuery::select_by_request_id(self.get_connection(), task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+
+        Ok(model.is_some())
+    }
+
+    async fn get(
+        &self,
+        task_request_id: &[u8],
+    ) -> DataAccessResult<BLSResultCache<GroupRelayResultCache>> {
+        let query_stmt = build_group_relay_record_query(Some(
+            Expr::col((GroupRelayResultTable, GroupRelayResultColumn::RequestId))
+                .eq(task_request_id),
+        ));
+        if let Some(group_relay_record) = self
+            .db_client
+            .query_one_statement::<GroupRelayRecord>(&query_stmt)
+            .await?
+        {
+            return Ok(group_relay_record.into());
+        }
+        return Err(BLSTaskError::CommitterCacheNotExisted.into());
+    }
+
+    async fn count_pending(&self) -> DataAccessResult<usize> {
+        self.signature_results_cache.count_pending().await
+    }
+}
+
+#[async_trait]
+impl SignatureResultCacheUpdater<GroupRelayResultCache> for GroupRelaySignatureResultDBClient {
+    async fn get_ready_to_commit_signatures(
+        &mut self,
+        current_block_height: usize,
+    ) -> DataAccessResult<Vec<GroupRelayResultCache>> {
+        let ready_to_commit_signatures = self
+            .signature_results_cache
+            .get_ready_to_commit_signatures(current_block_height)
+            .await?;
+
+        if ready_to_commit_signatures.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let request_ids = ready_to_commit_signatures
+            .iter()
+            .map(|s| s.request_id())
+            .collect::<Vec<_>>();
+
+        let update_stmt = Query::update()
+            .table(GroupRelayResultTable)
+            .values([
+                (
+                    GroupRelayResultColumn::State,
+                    BLSResultCacheState::Committing.to_i32().into(),
+                ),
+                (GroupRelayResultColumn::UpdateAt, format_now_date().into()),
+            ])
+            .and_where(Expr::col(GroupRelayResultColumn::RequestId).is_in(request_ids))
+            .to_owned();
+
+        self.db_client
+            .execute_update_statement(&update_stmt)
+            .await?;
+
+        Ok(ready_to_commit_signatures)
+    }
+
+    async fn update_commit_result(
+        &mut self,
+        task_request_id: &[u8],
+        status: BLSResultCacheState,
+    ) -> DataAccessResult<()> {
+        let update_stmt = Query::update()
+            .table(GroupRelayResultTable)
+            .values([
+                (GroupRelayResultColumn::State, status.to_i32().into()),
+                (GroupRelayResultColumn::UpdateAt, format_now_date().into()),
+            ])
+            .and_where(Expr::col(GroupRelayResultColumn::RequestId).eq(task_request_id))
+            .to_owned();
+
+        self.db_client
+            .execute_update_statement(&update_stmt)
+            .await?;
+
+        self.signature_results_cache
+            .update_commit_result(task_request_id, status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add(
+        &mut self,
+        group_index: usize,
+        task: GroupRelayTask,
+        message: Vec<u8>,
+        threshold: usize,
+    ) -> DataAccessResult<bool> {
+        GroupRelayResultMutation::add(
+            self.get_connection(),
+            task.request_id.clone(),
+            group_index as i32,
+            message.clone(),
+            threshold as i32,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        self.signature_results_cache
+            .add(group_index, task, message, threshold)
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn add_partial_signature(
+        &mut self,
+        task_request_id: Vec<u8>,
+        member_address: Address,
+        member_index: usize,
+        partial_signature: Vec<u8>,
+    ) -> DataAccessResult<bool> {
+        let txn = self.get_connection().begin().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        let model =
+            GroupRelayResultQuery::select_by_request_id(self.get_connection(), &task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?
+                .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        GroupRelayResultMutation::add_partial_signature(
+            self.get_connection(),
+            model,
+            member_address,
+            partial_signature.clone(),
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        txn.commit().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        self.signature_results_cache
+            .add_partial_signature(
+                task_request_id,
+                member_address,
+                member_index,
+                partial_signature,
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn incr_committed_times(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        let update_stmt = Query::update()
+            .table(GroupRelayResultTable)
+            .values([
+                (
+                    GroupRelayResultColumn::CommittedTimes,
+                    Expr::col(GroupRelayResultColumn::CommittedTimes).add(1),
+                ),
+                (GroupRelayResultColumn::UpdateAt, format_now_date().into()),
+            ])
+            .and_where(Expr::col(GroupRelayResultColumn::RequestId).eq(task_request_id))
+            .to_owned();
+
+        self.db_client
+            .execute_update_statement(&update_stmt)
+            .await?;
+
+        self.signature_results_cache
+            .incr_committed_times(task_request_id)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupRelayConfirmationSignatureResultDBClient {
+    db_client: Arc<SeaOrmDB>,
+    chain_id: usize,
+    signature_results_cache: InMemorySignatureResultCache<GroupRelayConfirmationResultCache>,
+}
+
+impl GroupRelayConfirmationSignatureResultDBClient {
+    pub fn get_connection(&self) -> &DbConn {
+        &self.db_client.connection
+    }
+}
+
+#[async_trait]
+impl SignatureResultCacheFetcher<GroupRelayConfirmationResultCache>
+    for GroupRelayConfirmationSignatureResultDBClient
+{
+    async fn contains(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
+        let model = GroupRelayConfirmationResultQuery::select_by_request_id(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(model.is_some())
+    }
+
+    async fn get(
+        &self,
+        task_request_id: &[u8],
+    ) -> DataAccessResult<BLSResultCache<GroupRelayConfirmationResultCache>> {
+        let query_stmt = build_group_relay_confirmation_record_query(
+            self.chain_id,
+            Some(
+                Expr::col((
+                    GroupRelayConfirmationResultTable,
+                    GroupRelayConfirmationResultColumn::RequestId,
+                ))
+                .eq(task_request_id),
+            ),
+        );
+        if let Some(group_relay_confirmation_record) = self
+            .db_client
+            .query_one_statement::<GroupRelayConfirmationRecord>(&query_stmt)
+            .await?
+        {
+            return Ok(group_relay_confirmation_record.into());
+        }
+        return Err(BLSTaskError::CommitterCacheNotExisted.into());
+    }
+
+    async fn count_pending(&self) -> DataAccessResult<usize> {
+        self.signature_results_cache.count_pending().await
+    }
+}
+
+#[async_trait]
+impl SignatureResultCacheUpdater<GroupRelayConfirmationResultCache>
+    for GroupRelayConfirmationSignatureResultDBClient
+{
+    async fn get_ready_to_commit_signatures(
+        &mut self,
+        current_block_height: usize,
+    ) -> DataAccessResult<Vec<GroupRelayConfirmationResultCache>> {
+        let ready_to_commit_signatures = self
+            .signature_results_cache
+            .get_ready_to_commit_signatures(current_block_height)
+            .await?;
+
+        if ready_to_commit_signatures.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let request_ids = ready_to_commit_signatures
+            .iter()
+            .map(|s| s.request_id())
+            .collect::<Vec<_>>();
+
+        let update_stmt = Query::update()
+            .table(GroupRelayConfirmationResultTable)
+            .values([
+                (
+                    GroupRelayConfirmationResultColumn::State,
+                    BLSResultCacheState::Committing.to_i32().into(),
+                ),
+                (
+                    GroupRelayConfirmationResultColumn::UpdateAt,
+                    format_now_date().into(),
+                ),
+            ])
+            .and_where(
+                Expr::col(GroupRelayConfirmationResultColumn::ChainId).eq(self.chain_id as i32),
+            )
+            .and_where(Expr::col(GroupRelayConfirmationResultColumn::RequestId).is_in(request_ids))
+            .to_owned();
+
+        self.db_client
+            .execute_update_statement(&update_stmt)
+            .await?;
+
+        Ok(ready_to_commit_signatures)
+    }
+
+    async fn update_commit_result(
+        &mut self,
+        task_request_id: &[u8],
+        status: BLSResultCacheState,
+    ) -> DataAccessResult<()> {
+        let update_stmt = Query::update()
+            .table(GroupRelayConfirmationResultTable)
+            .values([
+                (
+                    GroupRelayConfirmationResultColumn::State,
+                    status.to_i32().into(),
+                ),
+                (
+                    GroupRelayConfirmationResultColumn::UpdateAt,
+                    format_now_date().into(),
+                ),
+            ])
+            .and_where(
+                Expr::col(GroupRelayConfirmationResultColumn::ChainId).eq(self.chain_id as i32),
+            )
+            .and_where(Expr::col(GroupRelayConfirmationResultColumn::RequestId).eq(task_request_id))
+            .to_owned();
+
+        self.db_client
+            .execute_update_statement(&update_stmt)
+            .await?;
+
+        self.signature_results_cache
+            .update_commit_result(task_request_id, status)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add(
+        &mut self,
+        group_index: usize,
+        task: GroupRelayConfirmationTask,
+        message: Vec<u8>,
+        threshold: usize,
+    ) -> DataAccessResult<bool> {
+        GroupRelayConfirmationResultMutation::add(
+            self.get_connection(),
+            self.chain_id as i32,
+            task.request_id.clone(),
+            group_index as i32,
+            message.clone(),
+            threshold as i32,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        self.signature_results_cache
+            .add(group_index, task, message, threshold)
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn add_partial_signature(
+        &mut self,
+        task_request_id: Vec<u8>,
+        member_address: Address,
+        member_index: usize,
+        partial_signature: Vec<u8>,
+    ) -> DataAccessResult<bool> {
+        let txn = self.get_connection().begin().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        let model = GroupRelayConfirmationResultQuery::select_by_request_id(
+            self.get_connection(),
+            self.chain_id as i32,
+            &task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?
+        .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        GroupRelayConfirmationResultMutation::add_partial_signature(
+            self.get_connection(),
+            model,
+            member_address,
+            partial_signature.clone(),
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        txn.commit().await.map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        self.signature_results_cache
+            .add_partial_signature(
+                task_request_id,
+                member_address,
+                member_index,
+                partial_signature,
+            )
+            .await?;
+
+        Ok(true)
+    }
+
+    async fn incr_committed_times(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        let update_stmt = Query::update()
+            .table(GroupRelayConfirmationResultTable)
+            .values([
+                (
+                    GroupRelayConfirmationResultColumn::CommittedTimes,
+                    Expr::col(GroupRelayConfirmationResultColumn::CommittedTimes).add(1),
+                ),
+                (
+                    GroupRelayConfirmationResultColumn::UpdateAt,
+                    format_now_date().into(),
+                ),
+            ])
+            .and_where(
+                Expr::col(GroupRelayConfirmationResultColumn::ChainId).eq(self.chain_id as i32),
+            )
+            .and_where(Expr::col(GroupRelayConfirmationResultColumn::RequestId).eq(task_request_id))
+            .to_owned();
+
+        self.db_client
+            .execute_update_statement(&update_stmt)
+            .await?;
+
+        self.signature_results_cache
+            .incr_committed_times(task_request_id)
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub struct GroupRelayResultQuery;
+
+impl GroupRelayResultQuery {
+    pub async fn select_by_request_id(
+        db: &DbConn,
+        request_id: &[u8],
+    ) -> Result<Option<group_relay_result::Model>, DbErr> {
+        GroupRelayResult::find()
+            .filter(group_relay_result::Column::RequestId.eq(request_id))
+            .one(db)
+            .await
+    }
+}
+
+pub struct GroupRelayResultMutation;
+
+impl GroupRelayResultMutation {
+    pub async fn add(
+        db: &DbConn,
+        request_id: Vec<u8>,
+        group_index: i32,
+        message: Vec<u8>,
+        threshold: i32,
+    ) -> Result<group_relay_result::ActiveModel, DbErr> {
+        group_relay_result::ActiveModel {
+            request_id: Set(request_id),
+            group_index: Set(group_index),
+            message: Set(message),
+            threshold: Set(threshold),
+            partial_signatures: Set(
+                serde_json::to_string(&BTreeMap::<Address, Vec<u8>>::new()).unwrap()
+            ),
+            committed_times: Set(0),
+            create_at: Set(format_now_date()),
+            update_at: Set(format_now_date()),
+            state: Set(BLSResultCacheState::NotCommitted.to_i32()),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn add_partial_signature(
+        db: &DbConn,
+        model: group_relay_result::Model,
+        member_address: Address,
+        partial_signature: Vec<u8>,
+    ) -> Result<group_relay_result::Model, DbErr> {
+        let mut partial_signatures: BTreeMap<Address, Vec<u8>> =
+            serde_json::from_str(&model.partial_signatures).unwrap();
+
+        partial_signatures.insert(member_address, partial_signature);
+
+        let mut group_relay_result: group_relay_result::ActiveModel = model.into();
+
+        group_relay_result.partial_signatures =
+            Set(serde_json::to_string(&partial_signatures).unwrap());
+
+        group_relay_result.update_at = Set(format_now_date());
+
+        group_relay_result.update(db).await
+    }
+}
+
+pub struct GroupRelayConfirmationResultQuery;
+
+impl GroupRelayConfirmationResultQuery {
+    pub async fn select_by_request_id(
+        db: &DbConn,
+        chain_id: i32,
+        request_id: &[u8],
+    ) -> Result<Option<group_relay_confirmation_result::Model>, DbErr> {
+        GroupRelayConfirmationResult::find()
+            .filter(group_relay_confirmation_result::Column::ChainId.eq(chain_id))
+            .filter(group_relay_confirmation_result::Column::RequestId.eq(request_id))
+            .one(db)
+            .await
+    }
+}
+
+pub struct GroupRelayConfirmationResultMutation;
+
+impl GroupRelayConfirmationResultMutation {
+    pub async fn add(
+        db: &DbConn,
+        chain_id: i32,
+        request_id: Vec<u8>,
+        group_index: i32,
+        message: Vec<u8>,
+        threshold: i32,
+    ) -> Result<group_relay_confirmation_result::ActiveModel, DbErr> {
+        group_relay_confirmation_result::ActiveModel {
+            chain_id: Set(chain_id),
+            request_id: Set(request_id),
+            group_index: Set(group_index),
+            message: Set(message),
+            threshold: Set(threshold),
+            partial_signatures: Set(
+                serde_json::to_string(&BTreeMap::<Address, Vec<u8>>::new()).unwrap()
+            ),
+            committed_times: Set(0),
+            create_at: Set(format_now_date()),
+            update_at: Set(format_now_date()),
+            state: Set(BLSResultCacheState::NotCommitted.to_i32()),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn add_partial_signature(
+        db: &DbConn,
+        model: group_relay_confirmation_result::Model,
+        member_address: Address,
+        partial_signature: Vec<u8>,
+    ) -> Result<group_relay_confirmation_result::Model, DbErr> {
+        let mut partial_signatures: BTreeMap<Address, Vec<u8>> =
+            serde_json::from_str(&model.partial_signatures).unwrap();
+
+        partial_signatures.insert(member_address, partial_signature);
+
+        let mut group_relay_confirmation_result: group_relay_confirmation_result::ActiveModel =
+            model.into();
+
+        group_relay_confirmation_result.partial_signatures =
+            Set(serde_json::to_string(&partial_signatures).unwrap());
+
+        group_relay_confirmation_result.update_at = Set(format_now_date());
+
+        group_relay_confirmation_result.update(db).await
+    }
+}
+
+pub(crate) fn build_group_relay_record_query(and_where: Option<SimpleExpr>) -> SelectStatement {
+    Query::select()
+        .column((GroupRelayResultTable, GroupRelayResultColumn::RequestId))
+        .column((GroupRelayResultTable, GroupRelayResultColumn::GroupIndex))
+        .column((GroupRelayResultTable, GroupRelayResultColumn::Message))
+        .column((GroupRelayResultTable, GroupRelayResultColumn::Threshold))
+        .column((
+            GroupRelayResultTable,
+            GroupRelayResultColumn::PartialSignatures,
+        ))
+        .column((
+            GroupRelayResultTable,
+            GroupRelayResultColumn::CommittedTimes,
+        ))
+        .column((GroupRelayResultTable, GroupRelayResultColumn::State))
+        .column((GroupRelayTaskTable, GroupRelayTaskColumn::RelayedChainId))
+        .column((
+            GroupRelayTaskTable,
+            GroupRelayTaskColumn::ControllerGlobalEpoch,
+        ))
+        .column((GroupRelayTaskTable, GroupRelayTaskColumn::RelayedGroupIndex))
+        .column((GroupRelayTaskTable, GroupRelayTaskColumn::RelayedGroupEpoch))
+        .column((
+            GroupRelayTaskTable,
+            GroupRelayTaskColumn::AssignmentBlockHeight,
+        ))
+        .from(GroupRelayResultTable)
+        .inner_join(
+            GroupRelayTaskTable,
+            Expr::col((GroupRelayResultTable, GroupRelayResultColumn::RequestId))
+                .equals((GroupRelayTaskTable, GroupRelayTaskColumn::RequestId)),
+        )
+        .conditions(
+            and_where.is_some(),
+            |x| {
+                x.and_where(and_where.unwrap());
+            },
+            |_x| {},
+        )
+        .to_owned()
+}
+
+pub(crate) fn build_group_relay_confirmation_record_query(
+    chain_id: usize,
+    and_where: Option<SimpleExpr>,
+) -> SelectStatement {
+    Query::select()
+        .column((
+            GroupRelayConfirmationResultTable,
+            GroupRelayConfirmationResultColumn::RequestId,
+        ))
+        .column((
+            GroupRelayConfirmationResultTable,
+            GroupRelayConfirmationResultColumn::GroupIndex,
+        ))
+        .column((
+            GroupRelayConfirmationResultTable,
+            GroupRelayConfirmationResultColumn::Message,
+        ))
+        .column((
+            GroupRelayConfirmationResultTable,
+            GroupRelayConfirmationResultColumn::Threshold,
+        ))
+        .column((
+            GroupRelayConfirmationResultTable,
+            GroupRelayConfirmationResultColumn::PartialSignatures,
+        ))
+        .column((
+            GroupRelayConfirmationResultTable,
+            GroupRelayConfirmationResultColumn::CommittedTimes,
+        ))
+        .column((
+            GroupRelayConfirmationResultTable,
+            GroupRelayConfirmationResultColumn::State,
+        ))
+        .column((
+            GroupRelayConfirmationTaskTable,
+            GroupRelayConfirmationTaskColumn::GroupRelayCacheIndex,
+        ))
+        .column((
+            GroupRelayConfirmationTaskTable,
+            GroupRelayConfirmationTaskColumn::RelayedGroupIndex,
+        ))
+        .column((
+            GroupRelayConfirmationTaskTable,
+            GroupRelayConfirmationTaskColumn::RelayedGroupEpoch,
+        ))
+        .column((
+            GroupRelayConfirmationTaskTable,
+            GroupRelayConfirmationTaskColumn::RelayerGroupIndex,
+        ))
+        .column((
+            GroupRelayConfirmationTaskTable,
+            GroupRelayConfirmationTaskColumn::AssignmentBlockHeight,
+        ))
+        .from(GroupRelayConfirmationResultTable)
+        .inner_join(
+            GroupRelayConfirmationTaskTable,
+            Expr::col((
+                GroupRelayConfirmationResultTable,
+                GroupRelayConfirmationResultColumn::ChainId,
+            ))
+            .equals((
+                GroupRelayConfirmationTaskTable,
+                GroupRelayConfirmationTaskColumn::ChainId,
+            ))
+            .and(
+                Expr::col((
+                    GroupRelayConfirmationResultTable,
+                    GroupRelayConfirmationResultColumn::RequestId,
+                ))
+                .equals((
+                    GroupRelayConfirmationTaskTable,
+                    GroupRelayConfirmationTaskColumn::RequestId,
+                )),
+            ),
+        )
+        .and_where(
+            Expr::col((
+                GroupRelayConfirmationResultTable,
+                GroupRelayConfirmationResultColumn::ChainId,
+            ))
+            .eq(chain_id as i32),
+        )
+        .conditions(
+            and_where.is_some(),
+            |x| {
+                x.and_where(and_where.unwrap());
+            },
+            |_x| {},
+        )
+        .to_owned()
+}
@@ -1,6 +1,6 @@
 use crate::types::DBError;
 use crate::types::RandomnessRecord;
-use crate::types::SqliteDB;
+use crate::types::SeaOrmDB;
 use arpa_core::format_now_date;
 use arpa_core::BLSTaskError;
 use arpa_core::{RandomnessTask, Task};
@@ -15,21 +15,24 @@ use arpa_dal::SignatureResultCacheUpdater;
 use async_trait::async_trait;
 use entity::prelude::RandomnessResult;
 use entity::randomness_result;
+use entity::randomness_result::{
+    Column as RandomnessResultColumn, Entity as RandomnessResultTable,
+};
+use entity::randomness_task::{Column as RandomnessTaskColumn, Entity as RandomnessTaskTable};
 use ethers_core::types::Address;
-use migration::Expr;
-use migration::Query;
-use migration::SelectStatement;
-use migration::SimpleExpr;
-use migration::{RandomnessResult as RandomnessResultTable, RandomnessTask as RandomnessTaskTable};
+use sea_orm::sea_query::{Expr, Query, SelectStatement, SimpleExpr};
 use sea_orm::TransactionTrait;
 use sea_orm::{ActiveModelTrait, DbConn, DbErr, Set};
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
-impl SqliteDB {
+impl SeaOrmDB {
+    /// Randomness results of all the chains are kept in the same table keyed by the chain id,
+    /// where 0 stands for the main chain.
     pub async fn get_randomness_result_client(
         &self,
+        chain_id: usize,
     ) -> DataAccessResult<SignatureResultDBClient<RandomnessResultCache>> {
         let txn = self.connection.begin().await.map_err(|e| {
             let e: DBError = e.into();
@@ -38,16 +41,17 @@ impl SqliteDB {
 
         // set commit result of committing records(if any) to not committed
         let update_stmt = Query::update()
-            .table(RandomnessResultTable::Table)
+            .table(RandomnessResultTable)
             .values([
                 (
-                    RandomnessResultTable::State,
+                    RandomnessResultColumn::State,
                     BLSResultCacheState::NotCommitted.to_i32().into(),
                 ),
-                (RandomnessResultTable::UpdateAt, format_now_date().into()),
+                (RandomnessResultColumn::UpdateAt, format_now_date().into()),
             ])
+            .and_where(Expr::col(RandomnessResultColumn::ChainId).eq(chain_id as i32))
             .and_where(
-                Expr::col(RandomnessResultTable::State)
+                Expr::col(RandomnessResultColumn::State)
                     .eq(BLSResultCacheState::Committing.to_i32()),
             )
             .to_owned();
@@ -55,10 +59,13 @@ impl SqliteDB {
         self.execute_update_statement(&update_stmt).await?;
 
         // load all not committed records
-        let query_stmt = build_randomness_record_query(Some(
-            Expr::col((RandomnessResultTable::Table, RandomnessResultTable::State))
-                .eq(BLSResultCacheState::NotCommitted.to_i32()),
-        ));
+        let query_stmt = build_randomness_record_query(
+            chain_id,
+            Some(
+                Expr::col((RandomnessResultTable, RandomnessResultColumn::State))
+                    .eq(BLSResultCacheState::NotCommitted.to_i32()),
+            ),
+        );
         let randomness_results: Vec<RandomnessRecord> =
             self.query_all_statement(&query_stmt).await?;
 
@@ -74,6 +81,7 @@ impl SqliteDB {
 
         Ok(SignatureResultDBClient {
             db_client: Arc::new(self.clone()),
+            chain_id,
             signature_results_cache: InMemorySignatureResultCache::<RandomnessResultCache>::rebuild(
                 results,
             ),
@@ -83,7 +91,8 @@ impl SqliteDB {
 
 #[derive(Debug, Clone)]
 pub struct SignatureResultDBClient<C: ResultCache> {
-    db_client: Arc<SqliteDB>,
+    db_client: Arc<SeaOrmDB>,
+    chain_id: usize,
     signature_results_cache: InMemorySignatureResultCache<C>,
 }
 
@@ -98,13 +107,16 @@ impl SignatureResultCacheFetcher<RandomnessResultCache>
     for SignatureResultDBClient<RandomnessResultCache>
 {
     async fn contains(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
-        let model =
-            RandomnessResultQuery::select_by_request_id(self.get_connection(), task_request_id)
-                .await
-                .map_err(|e| {
-                    let e: DBError = e.into();
-                    e
-                })?;
+        let model = RandomnessResultQuery::select_by_request_id(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
 
         Ok(model.is_some())
     }
@@ -113,13 +125,13 @@ impl SignatureResultCacheFetcher<RandomnessResultCache>
         &self,
         task_request_id: &[u8],
     ) -> DataAccessResult<BLSResultCache<RandomnessResultCache>> {
-        let query_stmt = build_randomness_record_query(Some(
-            Expr::col((
-                RandomnessResultTable::Table,
-                RandomnessResultTable::RequestId,
-            ))
-            .eq(task_request_id),
-        ));
+        let query_stmt = build_randomness_record_query(
+            self.chain_id,
+            Some(
+                Expr::col((RandomnessResultTable, RandomnessResultColumn::RequestId))
+                    .eq(task_request_id),
+            ),
+        );
         if let Some(randomness_record) = self
             .db_client
             .query_one_statement::<RandomnessRecord>(&query_stmt)
@@ -129,6 +141,10 @@ impl SignatureResultCacheFetcher<RandomnessResultCache>
         }
         return Err(BLSTaskError::CommitterCacheNotExisted.into());
     }
+
+    async fn count_pending(&self) -> DataAccessResult<usize> {
+        self.signature_results_cache.count_pending().await
+    }
 }
 
 #[async_trait]
@@ -154,15 +170,16 @@ impl SignatureResultCacheUpdater<RandomnessResultCache>
             .collect::<Vec<_>>();
 
         let update_stmt = Query::update()
-            .table(RandomnessResultTable::Table)
+            .table(RandomnessResultTable)
             .values([
                 (
-                    RandomnessResultTable::State,
+                    RandomnessResultColumn::State,
                     BLSResultCacheState::Committing.to_i32().into(),
                 ),
-                (RandomnessResultTable::UpdateAt, format_now_date().into()),
+                (RandomnessResultColumn::UpdateAt, format_now_date().into()),
             ])
-            .and_where(Expr::col(RandomnessResultTable::RequestId).is_in(request_ids))
+            .and_where(Expr::col(RandomnessResultColumn::ChainId).eq(self.chain_id as i32))
+            .and_where(Expr::col(RandomnessResultColumn::RequestId).is_in(request_ids))
             .to_owned();
 
         self.db_client
@@ -178,12 +195,13 @@ impl SignatureResultCacheUpdater<RandomnessResultCache>
         status: BLSResultCacheState,
     ) -> DataAccessResult<()> {
         let update_stmt = Query::update()
-            .table(RandomnessResultTable::Table)
+            .table(RandomnessResultTable)
             .values([
-                (RandomnessResultTable::State, status.to_i32().into()),
-                (RandomnessResultTable::UpdateAt, format_now_date().into()),
+                (RandomnessResultColumn::State, status.to_i32().into()),
+                (RandomnessResultColumn::UpdateAt, format_now_date().into()),
             ])
-            .and_where(Expr::col(RandomnessResultTable::RequestId).eq(task_request_id))
+            .and_where(Expr::col(RandomnessResultColumn::ChainId).eq(self.chain_id as i32))
+            .and_where(Expr::col(RandomnessResultColumn::RequestId).eq(task_request_id))
             .to_owned();
 
         self.db_client
@@ -206,6 +224,7 @@ impl SignatureResultCacheUpdater<RandomnessResultCache>
     ) -> DataAccessResult<bool> {
         RandomnessResultMutation::add(
             self.get_connection(),
+            self.chain_id as i32,
             task.request_id.clone(),
             group_index as i32,
             message.clone(),
@@ -236,14 +255,17 @@ impl SignatureResultCacheUpdater<RandomnessResultCache>
             e
         })?;
 
-        let model =
-            RandomnessResultQuery::select_by_request_id(self.get_connection(), &task_request_id)
-                .await
-                .map_err(|e| {
-                    let e: DBError = e.into();
-                    e
-                })?
-                .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+        let model = RandomnessResultQuery::select_by_request_id(
+            self.get_connection(),
+            self.chain_id as i32,
+            &task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?
+        .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
 
         RandomnessResultMutation::add_partial_signature(
             self.get_connection(),
@@ -276,15 +298,16 @@ impl SignatureResultCacheUpdater<RandomnessResultCache>
 
     async fn incr_committed_times(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
         let update_stmt = Query::update()
-            .table(RandomnessResultTable::Table)
+            .table(RandomnessResultTable)
             .values([
                 (
-                    RandomnessResultTable::CommittedTimes,
-                    Expr::col(RandomnessResultTable::CommittedTimes).add(1),
+                    RandomnessResultColumn::CommittedTimes,
+                    Expr::col(RandomnessResultColumn::CommittedTimes).add(1),
                 ),
-                (RandomnessResultTable::UpdateAt, format_now_date().into()),
+                (RandomnessResultColumn::UpdateAt, format_now_date().into()),
             ])
-            .and_where(Expr::col(RandomnessResultTable::RequestId).eq(task_request_id))
+            .and_where(Expr::col(RandomnessResultColumn::ChainId).eq(self.chain_id as i32))
+            .and_where(Expr::col(RandomnessResultColumn::RequestId).eq(task_request_id))
             .to_owned();
 
         self.db_client
@@ -304,9 +327,11 @@ pub struct RandomnessResultQuery;
 impl RandomnessResultQuery {
     pub async fn select_by_request_id(
         db: &DbConn,
+        chain_id: i32,
         request_id: &[u8],
     ) -> Result<Option<randomness_result::Model>, DbErr> {
         RandomnessResult::find()
+            .filter(randomness_result::Column::ChainId.eq(chain_id))
             .filter(randomness_result::Column::RequestId.eq(request_id))
             .one(db)
             .await
@@ -318,12 +343,14 @@ pub struct RandomnessResultMutation;
 impl RandomnessResultMutation {
     pub async fn add(
         db: &DbConn,
+        chain_id: i32,
         request_id: Vec<u8>,
         group_index: i32,
         message: Vec<u8>,
         threshold: i32,
     ) -> Result<randomness_result::ActiveModel, DbErr> {
         randomness_result::ActiveModel {
+            chain_id: Set(chain_id),
             request_id: Set(request_id),
             group_index: Set(group_index),
             message: Set(message),
@@ -363,62 +390,54 @@ impl RandomnessResultMutation {
     }
 }
 
-pub(crate) fn build_randomness_record_query(and_where: Option<SimpleExpr>) -> SelectStatement {
+pub(crate) fn build_randomness_record_query(
+    chain_id: usize,
+    and_where: Option<SimpleExpr>,
+) -> SelectStatement {
     Query::select()
+        .column((RandomnessResultTable, RandomnessResultColumn::RequestId))
+        .column((RandomnessResultTable, RandomnessResultColumn::GroupIndex))
+        .column((RandomnessResultTable, RandomnessResultColumn::Message))
+        .column((RandomnessResultTable, RandomnessResultColumn::Threshold))
         .column((
-            RandomnessResultTable::Table,
-            RandomnessResultTable::RequestId,
-        ))
-        .column((
-            RandomnessResultTable::Table,
-            RandomnessResultTable::GroupIndex,
-        ))
-        .column((RandomnessResultTable::Table, RandomnessResultTable::Message))
-        .column((
-            RandomnessResultTable::Table,
-            RandomnessResultTable::Threshold,
-        ))
-        .column((
-            RandomnessResultTable::Table,
-            RandomnessResultTable::PartialSignatures,
-        ))
-        .column((
-            RandomnessResultTable::Table,
-            RandomnessResultTable::CommittedTimes,
+            RandomnessResultTable,
+            RandomnessResultColumn::PartialSignatures,
         ))
-        .column((RandomnessResultTable::Table, RandomnessResultTable::State))
         .column((
-            RandomnessTaskTable::Table,
-            RandomnessTaskTable::SubscriptionId,
+            RandomnessResultTable,
+            RandomnessResultColumn::CommittedTimes,
         ))
-        .column((RandomnessTaskTable::Table, RandomnessTaskTable::RequestType))
-        .column((RandomnessTaskTable::Table, RandomnessTaskTable::Params))
-        .column((RandomnessTaskTable::Table, RandomnessTaskTable::Requester))
-        .column((RandomnessTaskTable::Table, RandomnessTaskTable::Seed))
+        .column((RandomnessResultTable, RandomnessResultColumn::State))
+        .column((RandomnessTaskTable, RandomnessTaskColumn::SubscriptionId))
+        .column((RandomnessTaskTable, RandomnessTaskColumn::RequestType))
+        .column((RandomnessTaskTable, RandomnessTaskColumn::Params))
+        .column((RandomnessTaskTable, RandomnessTaskColumn::Requester))
+        .column((RandomnessTaskTable, RandomnessTaskColumn::Seed))
         .column((
-            RandomnessTaskTable::Table,
-            RandomnessTaskTable::RequestConfirmations,
+            RandomnessTaskTable,
+            RandomnessTaskColumn::RequestConfirmations,
         ))
+        .column((RandomnessTaskTable, RandomnessTaskColumn::CallbackGasLimit))
         .column((
-            RandomnessTaskTable::Table,
-            RandomnessTaskTable::CallbackGasLimit,
+            RandomnessTaskTable,
+            RandomnessTaskColumn::CallbackMaxGasPrice,
         ))
         .column((
-            RandomnessTaskTable::Table,
-            RandomnessTaskTable::CallbackMaxGasPrice,
+            RandomnessTaskTable,
+            RandomnessTaskColumn::AssignmentBlockHeight,
         ))
-        .column((
-            RandomnessTaskTable::Table,
-            RandomnessTaskTable::AssignmentBlockHeight,
-        ))
-        .from(RandomnessResultTable::Table)
+        .from(RandomnessResultTable)
         .inner_join(
-            RandomnessTaskTable::Table,
-            Expr::col((
-                RandomnessResultTable::Table,
-                RandomnessResultTable::RequestId,
-            ))
-            .equals((RandomnessTaskTable::Table, RandomnessTaskTable::RequestId)),
+            RandomnessTaskTable,
+            Expr::col((RandomnessResultTable, RandomnessResultColumn::ChainId))
+                .equals((RandomnessTaskTable, RandomnessTaskColumn::ChainId))
+                .and(
+                    Expr::col((RandomnessResultTable, RandomnessResultColumn::RequestId))
+                        .equals((RandomnessTaskTable, RandomnessTaskColumn::RequestId)),
+                ),
+        )
+        .and_where(
+            Expr::col((RandomnessResultTable, RandomnessResultColumn::ChainId)).eq(chain_id as i32),
         )
         .conditions(
             and_where.is_some(),
@@ -0,0 +1,7 @@
+mod group_relay;
+mod main;
+
+pub use group_relay::{
+    GroupRelayConfirmationSignatureResultDBClient, GroupRelaySignatureResultDBClient,
+};
+pub use main::SignatureResultDBClient;
@@ -0,0 +1,261 @@
+use crate::types::DBError;
+use crate::types::SeaOrmDB;
+use arpa_core::{DataRetentionDescriptor, RetentionPolicyDescriptor};
+use arpa_dal::error::DataAccessResult;
+use arpa_dal::BLSResultCacheState;
+use chrono::{Duration, Local};
+use sea_orm::{ConnectionTrait, DatabaseBackend, Statement, Value};
+
+/// All the task and result tables, in the order they are reported.
+const TASK_AND_RESULT_TABLES: [&str; 6] = [
+    "randomness_task",
+    "randomness_result",
+    "group_relay_task",
+    "group_relay_result",
+    "group_relay_confirmation_task",
+    "group_relay_confirmation_result",
+];
+
+/// A task table and the result table of its tasks. The tables shared by the chains are keyed by
+/// the chain id.
+#[derive(Debug, Clone, Copy)]
+struct RetentionTables {
+    task_table: &'static str,
+    result_table: &'static str,
+    chain_id: Option<i32>,
+}
+
+impl SeaOrmDB {
+    /// Deletes the results beyond the retention of their state, and then the tasks beyond the
+    /// retention of tasks which have no result left. Chain id 0 stands for the main chain, as
+    /// the caches are built. Returns the number of deleted rows.
+    pub async fn prune(
+        &self,
+        chain_id: usize,
+        current_block_height: usize,
+        retention: &DataRetentionDescriptor,
+    ) -> DataAccessResult<u64> {
+        let mut pruned_rows = 0;
+
+        for tables in retention_tables(chain_id) {
+            for (state, policy) in [
+                (BLSResultCacheState::Committed, retention.committed),
+                (
+                    BLSResultCacheState::CommittedByOthers,
+                    retention.committed_by_others,
+                ),
+                (BLSResultCacheState::Expired, retention.expired),
+                (BLSResultCacheState::FAULTY, retention.faulty),
+            ] {
+                pruned_rows += self
+                    .prune_results(tables, state, &policy, current_block_height)
+                    .await?;
+            }
+
+            pruned_rows += self
+                .prune_tasks(tables, &retention.tasks, current_block_height)
+                .await?;
+        }
+
+        Ok(pruned_rows)
+    }
+
+    /// Counts the rows of every task and result table.
+    pub async fn get_table_sizes(&self) -> DataAccessResult<Vec<(&'static str, u64)>> {
+        let mut table_sizes = Vec::with_capacity(TASK_AND_RESULT_TABLES.len());
+
+        for table in TASK_AND_RESULT_TABLES {
+            let rows = self
+                .connection
+                .query_one(Statement::from_string(
+                    self.connection.get_database_backend(),
+                    format!("select count(*) as row_count from {}", table),
+                ))
+                .await
+                .and_then(|res| res.unwrap().try_get::<i64>("", "row_count"))
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+
+            table_sizes.push((table, rows as u64));
+        }
+
+        Ok(table_sizes)
+    }
+
+    /// Counts the bytes the database takes, on the server or in the file.
+    pub async fn get_database_size(&self) -> DataAccessResult<u64> {
+        let backend = self.connection.get_database_backend();
+
+        let sql = match backend {
+            DatabaseBackend::Postgres => {
+                "select pg_database_size(current_database()) as database_size"
+            }
+            _ => "select page_count * page_size as database_size from pragma_page_count(), pragma_page_size()",
+        };
+
+        let size = self
+            .connection
+            .query_one(Statement::from_string(backend, sql.to_owned()))
+            .await
+            .and_then(|res| res.unwrap().try_get::<i64>("", "database_size"))
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e
+            })?;
+
+        Ok(size as u64)
+    }
+
+    async fn prune_results(
+        &self,
+        tables: RetentionTables,
+        state: BLSResultCacheState,
+        policy: &RetentionPolicyDescriptor,
+        current_block_height: usize,
+    ) -> DataAccessResult<u64> {
+        let mut values: Vec<Value> = vec![state.to_i32().into()];
+
+        let mut sql = format!("delete from {} where state = $1", tables.result_table);
+
+        if let Some(chain_id) = tables.chain_id {
+            values.push(chain_id.into());
+            sql += &format!(" and chain_id = ${}", values.len());
+        }
+
+        let mut conditions = vec![];
+
+        if let Some(before) = before_update_at(policy) {
+            values.push(before.into());
+            conditions.push(format!("update_at < ${}", values.len()));
+        }
+
+        if let Some(before) = before_assignment_block_height(policy, current_block_height) {
+            values.push(before.into());
+            let mut condition = format!(
+                "request_id in (select request_id from {} where assignment_block_height < ${}",
+                tables.task_table,
+                values.len()
+            );
+            if let Some(chain_id) = tables.chain_id {
+                values.push(chain_id.into());
+                condition += &format!(" and chain_id = ${}", values.len());
+            }
+            condition += ")";
+            conditions.push(condition);
+        }
+
+        if conditions.is_empty() {
+            return Ok(0);
+        }
+
+        sql += &format!(" and ({})", conditions.join(" or "));
+
+        self.execute_delete(sql, values).await
+    }
+
+    async fn prune_tasks(
+        &self,
+        tables: RetentionTables,
+        policy: &RetentionPolicyDescriptor,
+        current_block_height: usize,
+    ) -> DataAccessResult<u64> {
+        let mut values: Vec<Value> = vec![];
+
+        let mut conditions = vec![];
+
+        if let Some(before) = before_update_at(policy) {
+            values.push(before.into());
+            conditions.push(format!("update_at < ${}", values.len()));
+        }
+
+        if let Some(before) = before_assignment_block_height(policy, current_block_height) {
+            values.push(before.into());
+            conditions.push(format!("assignment_block_height < ${}", values.len()));
+        }
+
+        if conditions.is_empty() {
+            return Ok(0);
+        }
+
+        // the results are always queried along with their tasks
+        let mut sql = format!(
+            "delete from {} where ({}) and request_id not in (select request_id from {}",
+            tables.task_table,
+            conditions.join(" or "),
+            tables.result_table
+        );
+
+        if let Some(chain_id) = tables.chain_id {
+            values.push(chain_id.into());
+            sql = format!(
+                "{} where chain_id = ${n}) and chain_id = ${n}",
+                sql,
+                n = values.len()
+            );
+        } else {
+            sql += ")";
+        }
+
+        self.execute_delete(sql, values).await
+    }
+
+    async fn execute_delete(&self, sql: String, values: Vec<Value>) -> DataAccessResult<u64> {
+        self.connection
+            .execute(Statement::from_sql_and_values(
+                self.connection.get_database_backend(),
+                &sql,
+                values,
+            ))
+            .await
+            .map(|res| res.rows_affected())
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e.into()
+            })
+    }
+}
+
+fn retention_tables(chain_id: usize) -> Vec<RetentionTables> {
+    let randomness_tables = RetentionTables {
+        task_table: "randomness_task",
+        result_table: "randomness_result",
+        chain_id: Some(chain_id as i32),
+    };
+
+    // group relay tasks are only emitted on the main chain, and confirmed on every relayed chain
+    let group_relay_tables = if chain_id == 0 {
+        RetentionTables {
+            task_table: "group_relay_task",
+            result_table: "group_relay_result",
+            chain_id: None,
+        }
+    } else {
+        RetentionTables {
+            task_table: "group_relay_confirmation_task",
+            result_table: "group_relay_confirmation_result",
+            chain_id: Some(chain_id as i32),
+        }
+    };
+
+    vec![randomness_tables, group_relay_tables]
+}
+
+fn before_update_at(policy: &RetentionPolicyDescriptor) -> Option<String> {
+    policy.max_age_secs.map(|max_age_secs| {
+        (Local::now() - Duration::seconds(max_age_secs as i64))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string()
+    })
+}
+
+fn before_assignment_block_height(
+    policy: &RetentionPolicyDescriptor,
+    current_block_height: usize,
+) -> Option<i32> {
+    policy
+        .max_block_distance
+        .filter(|max_block_distance| (current_block_height as u64) > *max_block_distance)
+        .map(|max_block_distance| (current_block_height as u64 - max_block_distance) as i32)
+}
@@ -0,0 +1,565 @@
+use super::claim_tasks_statement;
+use crate::types::DBError;
+use crate::types::SeaOrmDB;
+use arpa_core::format_now_date;
+use arpa_core::BLSTaskError;
+use arpa_core::{GroupRelayConfirmationTask, GroupRelayTask};
+use arpa_dal::error::DataAccessResult;
+use arpa_dal::{BLSTasksFetcher, BLSTasksUpdater};
+use async_trait::async_trait;
+use entity::prelude::GroupRelayConfirmationTask as GroupRelayConfirmationTaskEntity;
+use entity::prelude::GroupRelayTask as GroupRelayTaskEntity;
+use entity::{group_relay_confirmation_task, group_relay_task};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, DbConn, DbErr, FromQueryResult, Set};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use std::sync::Arc;
+
+impl SeaOrmDB {
+    pub fn get_group_relay_tasks_client(&self) -> GroupRelayTasksDBClient {
+        GroupRelayTasksDBClient {
+            db_client: Arc::new(self.clone()),
+        }
+    }
+
+    pub fn get_group_relay_confirmation_tasks_client(
+        &self,
+        chain_id: usize,
+    ) -> GroupRelayConfirmationTasksDBClient {
+        GroupRelayConfirmationTasksDBClient {
+            db_client: Arc::new(self.clone()),
+            chain_id,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupRelayTasksDBClient {
+    db_client: Arc<SeaOrmDB>,
+}
+
+impl GroupRelayTasksDBClient {
+    pub fn get_connection(&self) -> &DbConn {
+        &self.db_client.connection
+    }
+}
+
+#[async_trait]
+impl BLSTasksFetcher<GroupRelayTask> for GroupRelayTasksDBClient {
+    async fn contains(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
+        let task =
+            GroupRelayTaskQuery::select_by_request_id(self.get_connection(), task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+        Ok(task.is_some())
+    }
+
+    async fn get(&self, task_request_id: &[u8]) -> DataAccessResult<GroupRelayTask> {
+        let task =
+            GroupRelayTaskQuery::select_by_request_id(self.get_connection(), task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+
+        task.map(model_to_group_relay_task)
+            .ok_or_else(|| BLSTaskError::TaskNotFound.into())
+    }
+
+    async fn is_handled(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
+        let task =
+            GroupRelayTaskQuery::select_by_request_id(self.get_connection(), task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+
+        Ok(task.is_some() && task.unwrap().state == 1)
+    }
+
+    async fn get_unhandled(&self) -> DataAccessResult<Vec<GroupRelayTask>> {
+        let tasks = GroupRelayTaskQuery::select_unhandled(self.get_connection())
+            .await
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e
+            })?;
+
+        Ok(tasks.into_iter().map(model_to_group_relay_task).collect())
+    }
+
+    async fn get_assigned_from(
+        &self,
+        block_height: usize,
+    ) -> DataAccessResult<Vec<GroupRelayTask>> {
+        let tasks =
+            GroupRelayTaskQuery::select_assigned_from(self.get_connection(), block_height as i32)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+
+        Ok(tasks.into_iter().map(model_to_group_relay_task).collect())
+    }
+}
+
+#[async_trait]
+impl BLSTasksUpdater<GroupRelayTask> for GroupRelayTasksDBClient {
+    async fn add(&mut self, task: GroupRelayTask) -> DataAccessResult<()> {
+        GroupRelayTaskMutation::add_task(self.get_connection(), task)
+            .await
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e
+            })?;
+
+        Ok(())
+    }
+
+    async fn remove(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        GroupRelayTaskMutation::remove_task(self.get_connection(), task_request_id)
+            .await
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e
+            })?;
+
+        Ok(())
+    }
+
+    async fn release(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        GroupRelayTaskMutation::release_task(self.get_connection(), task_request_id)
+            .await
+            .map_err(|e| {
+                let e: DBError = e.into();
+                e
+            })?;
+
+        Ok(())
+    }
+
+    async fn check_and_get_available_tasks(
+        &mut self,
+        _current_block_height: usize,
+        current_group_index: usize,
+        _randomness_task_exclusive_window: usize,
+    ) -> DataAccessResult<Vec<GroupRelayTask>> {
+        GroupRelayTaskMutation::fetch_available_tasks(
+            self.get_connection(),
+            current_group_index as i32,
+        )
+        .await
+        .map(|models| {
+            models
+                .into_iter()
+                .map(model_to_group_relay_task)
+                .collect::<Vec<_>>()
+        })
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e.into()
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GroupRelayConfirmationTasksDBClient {
+    db_client: Arc<SeaOrmDB>,
+    chain_id: usize,
+}
+
+impl GroupRelayConfirmationTasksDBClient {
+    pub fn get_connection(&self) -> &DbConn {
+        &self.db_client.connection
+    }
+}
+
+#[async_trait]
+impl BLSTasksFetcher<GroupRelayConfirmationTask> for GroupRelayConfirmationTasksDBClient {
+    async fn contains(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
+        let task = GroupRelayConfirmationTaskQuery::select_by_request_id(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+        Ok(task.is_some())
+    }
+
+    async fn get(&self, task_request_id: &[u8]) -> DataAccessResult<GroupRelayConfirmationTask> {
+        let task = GroupRelayConfirmationTaskQuery::select_by_request_id(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        task.map(model_to_group_relay_confirmation_task)
+            .ok_or_else(|| BLSTaskError::TaskNotFound.into())
+    }
+
+    async fn is_handled(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
+        let task = GroupRelayConfirmationTaskQuery::select_by_request_id(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(task.is_some() && task.unwrap().state == 1)
+    }
+
+    async fn get_unhandled(&self) -> DataAccessResult<Vec<GroupRelayConfirmationTask>> {
+        let tasks = GroupRelayConfirmationTaskQuery::select_unhandled(
+            self.get_connection(),
+            self.chain_id as i32,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(tasks
+            .into_iter()
+            .map(model_to_group_relay_confirmation_task)
+            .collect())
+    }
+
+    async fn get_assigned_from(
+        &self,
+        block_height: usize,
+    ) -> DataAccessResult<Vec<GroupRelayConfirmationTask>> {
+        let tasks = GroupRelayConfirmationTaskQuery::select_assigned_from(
+            self.get_connection(),
+            self.chain_id as i32,
+            block_height as i32,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(tasks
+            .into_iter()
+            .map(model_to_group_relay_confirmation_task)
+            .collect())
+    }
+}
+
+#[async_trait]
+impl BLSTasksUpdater<GroupRelayConfirmationTask> for GroupRelayConfirmationTasksDBClient {
+    async fn add(&mut self, task: GroupRelayConfirmationTask) -> DataAccessResult<()> {
+        GroupRelayConfirmationTaskMutation::add_task(
+            self.get_connection(),
+            self.chain_id as i32,
+            task,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(())
+    }
+
+    async fn remove(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        GroupRelayConfirmationTaskMutation::remove_task(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(())
+    }
+
+    async fn release(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        GroupRelayConfirmationTaskMutation::release_task(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(())
+    }
+
+    async fn check_and_get_available_tasks(
+        &mut self,
+        _current_block_height: usize,
+        current_group_index: usize,
+        _randomness_task_exclusive_window: usize,
+    ) -> DataAccessResult<Vec<GroupRelayConfirmationTask>> {
+        GroupRelayConfirmationTaskMutation::fetch_available_tasks(
+            self.get_connection(),
+            self.chain_id as i32,
+            current_group_index as i32,
+        )
+        .await
+        .map(|models| {
+            models
+                .into_iter()
+                .map(model_to_group_relay_confirmation_task)
+                .collect::<Vec<_>>()
+        })
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e.into()
+        })
+    }
+}
+
+fn model_to_group_relay_task(model: group_relay_task::Model) -> GroupRelayTask {
+    GroupRelayTask {
+        request_id: model.request_id,
+        relayed_chain_id: model.relayed_chain_id as usize,
+        controller_global_epoch: model.controller_global_epoch as usize,
+        relayed_group_index: model.relayed_group_index as usize,
+        relayed_group_epoch: model.relayed_group_epoch as usize,
+        assignment_block_height: model.assignment_block_height as usize,
+    }
+}
+
+fn model_to_group_relay_confirmation_task(
+    model: group_relay_confirmation_task::Model,
+) -> GroupRelayConfirmationTask {
+    GroupRelayConfirmationTask {
+        request_id: model.request_id,
+        index: model.group_relay_cache_index as usize,
+        group_relay_cache_index: model.group_relay_cache_index as usize,
+        relayed_group_index: model.relayed_group_index as usize,
+        relayed_group_epoch: model.relayed_group_epoch as usize,
+        relayer_group_index: model.relayer_group_index as usize,
+        assignment_block_height: model.assignment_block_height as usize,
+    }
+}
+
+pub struct GroupRelayTaskQuery;
+
+impl GroupRelayTaskQuery {
+    pub async fn select_by_request_id(
+        db: &DbConn,
+        request_id: &[u8],
+    ) -> Result<Option<group_relay_task::Model>, DbErr> {
+        GroupRelayTaskEntity::find()
+            .filter(group_relay_task::Column::RequestId.eq(request_id))
+            .one(db)
+            .await
+    }
+
+    pub async fn select_unhandled(db: &DbConn) -> Result<Vec<group_relay_task::Model>, DbErr> {
+        GroupRelayTaskEntity::find()
+            .filter(group_relay_task::Column::State.eq(0))
+            .all(db)
+            .await
+    }
+
+    pub async fn select_assigned_from(
+        db: &DbConn,
+        block_height: i32,
+    ) -> Result<Vec<group_relay_task::Model>, DbErr> {
+        GroupRelayTaskEntity::find()
+            .filter(group_relay_task::Column::AssignmentBlockHeight.gte(block_height))
+            .all(db)
+            .await
+    }
+}
+
+pub struct GroupRelayTaskMutation;
+
+impl GroupRelayTaskMutation {
+    pub async fn add_task(
+        db: &DbConn,
+        task: GroupRelayTask,
+    ) -> Result<group_relay_task::ActiveModel, DbErr> {
+        group_relay_task::ActiveModel {
+            request_id: Set(task.request_id),
+            relayed_chain_id: Set(task.relayed_chain_id as i32),
+            controller_global_epoch: Set(task.controller_global_epoch as i32),
+            relayed_group_index: Set(task.relayed_group_index as i32),
+            relayed_group_epoch: Set(task.relayed_group_epoch as i32),
+            assignment_block_height: Set(task.assignment_block_height as i32),
+            create_at: Set(format_now_date()),
+            update_at: Set(format_now_date()),
+            state: Set(0),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn fetch_available_tasks(
+        db: &DbConn,
+        group_index: i32,
+    ) -> Result<Vec<group_relay_task::Model>, DbErr> {
+        group_relay_task::Model::find_by_statement(claim_tasks_statement(
+            db.get_database_backend(),
+            "group_relay_task",
+            "state = 0 and relayed_group_index != $2",
+            vec![format_now_date().into(), group_index.into()],
+        ))
+        .all(db)
+        .await
+    }
+
+    pub async fn remove_task(db: &DbConn, request_id: &[u8]) -> Result<(), DbErr> {
+        GroupRelayTaskEntity::delete_many()
+            .filter(group_relay_task::Column::RequestId.eq(request_id))
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn release_task(db: &DbConn, request_id: &[u8]) -> Result<(), DbErr> {
+        GroupRelayTaskEntity::update_many()
+            .col_expr(group_relay_task::Column::State, Expr::value(0))
+            .col_expr(
+                group_relay_task::Column::UpdateAt,
+                Expr::value(format_now_date()),
+            )
+            .filter(group_relay_task::Column::RequestId.eq(request_id))
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub struct GroupRelayConfirmationTaskQuery;
+
+impl GroupRelayConfirmationTaskQuery {
+    pub async fn select_by_request_id(
+        db: &DbConn,
+        chain_id: i32,
+        request_id: &[u8],
+    ) -> Result<Option<group_relay_confirmation_task::Model>, DbErr> {
+        GroupRelayConfirmationTaskEntity::find()
+            .filter(group_relay_confirmation_task::Column::ChainId.eq(chain_id))
+            .filter(group_relay_confirmation_task::Column::RequestId.eq(request_id))
+            .one(db)
+            .await
+    }
+
+    pub async fn select_unhandled(
+        db: &DbConn,
+        chain_id: i32,
+    ) -> Result<Vec<group_relay_confirmation_task::Model>, DbErr> {
+        GroupRelayConfirmationTaskEntity::find()
+            .filter(group_relay_confirmation_task::Column::ChainId.eq(chain_id))
+            .filter(group_relay_confirmation_task::Column::State.eq(0))
+            .all(db)
+            .await
+    }
+
+    pub async fn select_assigned_from(
+        db: &DbConn,
+        chain_id: i32,
+        block_height: i32,
+    ) -> Result<Vec<group_relay_confirmation_task::Model>, DbErr> {
+        GroupRelayConfirmationTaskEntity::find()
+            .filter(group_relay_confirmation_task::Column::ChainId.eq(chain_id))
+            .filter(group_relay_confirmation_task::Column::AssignmentBlockHeight.gte(block_height))
+            .all(db)
+            .await
+    }
+}
+
+pub struct GroupRelayConfirmationTaskMutation;
+
+impl GroupRelayConfirmationTaskMutation {
+    pub async fn add_task(
+        db: &DbConn,
+        chain_id: i32,
+        task: GroupRelayConfirmationTask,
+    ) -> Result<group_relay_confirmation_task::ActiveModel, DbErr> {
+        group_relay_confirmation_task::ActiveModel {
+            chain_id: Set(chain_id),
+            request_id: Set(task.request_id),
+            group_relay_cache_index: Set(task.group_relay_cache_index as i32),
+            relayed_group_index: Set(task.relayed_group_index as i32),
+            relayed_group_epoch: Set(task.relayed_group_epoch as i32),
+            relayer_group_index: Set(task.relayer_group_index as i32),
+            assignment_block_height: Set(task.assignment_block_height as i32),
+            create_at: Set(format_now_date()),
+            update_at: Set(format_now_date()),
+            state: Set(0),
+            ..Default::default()
+        }
+        .save(db)
+        .await
+    }
+
+    pub async fn fetch_available_tasks(
+        db: &DbConn,
+        chain_id: i32,
+        group_index: i32,
+    ) -> Result<Vec<group_relay_confirmation_task::Model>, DbErr> {
+        group_relay_confirmation_task::Model::find_by_statement(claim_tasks_statement(
+            db.get_database_backend(),
+            "group_relay_confirmation_task",
+            "state = 0 and chain_id = $2 and relayed_group_index = $3",
+            vec![
+                format_now_date().into(),
+                chain_id.into(),
+                group_index.into(),
+            ],
+        ))
+        .all(db)
+        .await
+    }
+
+    pub async fn remove_task(db: &DbConn, chain_id: i32, request_id: &[u8]) -> Result<(), DbErr> {
+        GroupRelayConfirmationTaskEntity::delete_many()
+            .filter(group_relay_confirmation_task::Column::ChainId.eq(chain_id))
+            .filter(group_relay_confirmation_task::Column::RequestId.eq(request_id))
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn release_task(db: &DbConn, chain_id: i32, request_id: &[u8]) -> Result<(), DbErr> {
+        GroupRelayConfirmationTaskEntity::update_many()
+            .col_expr(group_relay_confirmation_task::Column::State, Expr::value(0))
+            .col_expr(
+                group_relay_confirmation_task::Column::UpdateAt,
+                Expr::value(format_now_date()),
+            )
+            .filter(group_relay_confirmation_task::Column::ChainId.eq(chain_id))
+            .filter(group_relay_confirmation_task::Column::RequestId.eq(request_id))
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
+use super::claim_tasks_statement;
 use crate::types::model_to_randomness_task;
 use crate::types::DBError;
-use crate::types::SqliteDB;
+use crate::types::SeaOrmDB;
 use arpa_core::format_now_date;
 use arpa_core::u256_to_vec;
 use arpa_core::RandomnessRequestType;
@@ -13,14 +14,18 @@ use entity::prelude::RandomnessTask as RandomnessTaskEntity;
 use entity::randomness_task;
 use ethers_core::types::Address;
 use ethers_core::types::U256;
-use sea_orm::{ActiveModelTrait, DbBackend, DbConn, DbErr, FromQueryResult, Set, Statement};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ConnectionTrait, DbConn, DbErr, FromQueryResult, Set};
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use std::{marker::PhantomData, sync::Arc};
 
-impl SqliteDB {
-    pub fn get_bls_tasks_client<T: Task>(&self) -> BLSTasksDBClient<T> {
+impl SeaOrmDB {
+    /// Randomness tasks of all the chains are kept in the same table keyed by the chain id,
+    /// where 0 stands for the main chain.
+    pub fn get_bls_tasks_client<T: Task>(&self, chain_id: usize) -> BLSTasksDBClient<T> {
         BLSTasksDBClient {
             db_client: Arc::new(self.clone()),
+            chain_id,
             bls_tasks: PhantomData,
         }
     }
@@ -28,7 +33,8 @@ impl SqliteDB {
 
 #[derive(Debug, Clone)]
 pub struct BLSTasksDBClient<T: Task> {
-    db_client: Arc<SqliteDB>,
+    db_client: Arc<SeaOrmDB>,
+    chain_id: usize,
     bls_tasks: PhantomData<T>,
 }
 
@@ -42,23 +48,25 @@ impl BLSTasksDBClient<RandomnessTask> {
 impl BLSTasksFetcher<RandomnessTask> for BLSTasksDBClient<RandomnessTask> {
     async fn contains(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
         let conn = &self.db_client.connection;
-        let task = RandomnessTaskQuery::select_by_request_id(conn, task_request_id)
-            .await
-            .map_err(|e| {
-                let e: DBError = e.into();
-                e
-            })?;
+        let task =
+            RandomnessTaskQuery::select_by_request_id(conn, self.chain_id as i32, task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
         Ok(task.is_some())
     }
 
     async fn get(&self, task_request_id: &[u8]) -> DataAccessResult<RandomnessTask> {
         let conn = &self.db_client.connection;
-        let task = RandomnessTaskQuery::select_by_request_id(conn, task_request_id)
-            .await
-            .map_err(|e| {
-                let e: DBError = e.into();
-                e
-            })?;
+        let task =
+            RandomnessTaskQuery::select_by_request_id(conn, self.chain_id as i32, task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
 
         task.map(model_to_randomness_task).ok_or_else(|| {
             RandomnessTaskError::NoRandomnessTask(format!("{:?}", task_request_id)).into()
@@ -67,14 +75,46 @@ impl BLSTasksFetcher<RandomnessTask> for BLSTasksDBClient<RandomnessTask> {
 
     async fn is_handled(&self, task_request_id: &[u8]) -> DataAccessResult<bool> {
         let conn = &self.db_client.connection;
-        let task = RandomnessTaskQuery::select_by_request_id(conn, task_request_id)
+        let task =
+            RandomnessTaskQuery::select_by_request_id(conn, self.chain_id as i32, task_request_id)
+                .await
+                .map_err(|e| {
+                    let e: DBError = e.into();
+                    e
+                })?;
+
+        Ok(task.is_some() && task.unwrap().state == 1)
+    }
+
+    async fn get_unhandled(&self) -> DataAccessResult<Vec<RandomnessTask>> {
+        let conn = &self.db_client.connection;
+        let tasks = RandomnessTaskQuery::select_unhandled(conn, self.chain_id as i32)
             .await
             .map_err(|e| {
                 let e: DBError = e.into();
                 e
             })?;
 
-        Ok(task.is_some() && task.unwrap().state == 1)
+        Ok(tasks.into_iter().map(model_to_randomness_task).collect())
+    }
+
+    async fn get_assigned_from(
+        &self,
+        block_height: usize,
+    ) -> DataAccessResult<Vec<RandomnessTask>> {
+        let conn = &self.db_client.connection;
+        let tasks = RandomnessTaskQuery::select_assigned_from(
+            conn,
+            self.chain_id as i32,
+            block_height as i32,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(tasks.into_iter().map(model_to_randomness_task).collect())
     }
 }
 
@@ -85,6 +125,7 @@ impl BLSTasksUpdater<RandomnessTask> for BLSTasksDBClient<RandomnessTask> {
 
         RandomnessTaskMutation::add_task(
             self.get_connection(),
+            self.chain_id as i32,
             task.request_id,
             task.subscription_id as i32,
             task.group_index as i32,
@@ -106,6 +147,36 @@ impl BLSTasksUpdater<RandomnessTask> for BLSTasksDBClient<RandomnessTask> {
         Ok(())
     }
 
+    async fn remove(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        RandomnessTaskMutation::remove_task(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(())
+    }
+
+    async fn release(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        RandomnessTaskMutation::release_task(
+            self.get_connection(),
+            self.chain_id as i32,
+            task_request_id,
+        )
+        .await
+        .map_err(|e| {
+            let e: DBError = e.into();
+            e
+        })?;
+
+        Ok(())
+    }
+
     async fn check_and_get_available_tasks(
         &mut self,
         current_block_height: usize,
@@ -120,8 +191,10 @@ impl BLSTasksUpdater<RandomnessTask> for BLSTasksDBClient<RandomnessTask> {
             };
         RandomnessTaskMutation::fetch_available_tasks(
             self.get_connection(),
+            self.chain_id as i32,
             current_group_index as i32,
             before_assignment_block_height as i32,
+            current_block_height as i32,
         )
         .await
         .map(|models| {
@@ -154,13 +227,38 @@ pub struct RandomnessTaskQuery;
 impl RandomnessTaskQuery {
     pub async fn select_by_request_id(
         db: &DbConn,
+        chain_id: i32,
         request_id: &[u8],
     ) -> Result<Option<randomness_task::Model>, DbErr> {
         RandomnessTaskEntity::find()
+            .filter(randomness_task::Column::ChainId.eq(chain_id))
             .filter(randomness_task::Column::RequestId.eq(request_id))
             .one(db)
             .await
     }
+
+    pub async fn select_unhandled(
+        db: &DbConn,
+        chain_id: i32,
+    ) -> Result<Vec<randomness_task::Model>, DbErr> {
+        RandomnessTaskEntity::find()
+            .filter(randomness_task::Column::ChainId.eq(chain_id))
+            .filter(randomness_task::Column::State.eq(0))
+            .all(db)
+            .await
+    }
+
+    pub async fn select_assigned_from(
+        db: &DbConn,
+        chain_id: i32,
+        block_height: i32,
+    ) -> Result<Vec<randomness_task::Model>, DbErr> {
+        RandomnessTaskEntity::find()
+            .filter(randomness_task::Column::ChainId.eq(chain_id))
+            .filter(randomness_task::Column::AssignmentBlockHeight.gte(block_height))
+            .all(db)
+            .await
+    }
 }
 
 pub struct RandomnessTaskMutation;
@@ -169,6 +267,7 @@ impl RandomnessTaskMutation {
     #[allow(clippy::too_many_arguments)]
     pub async fn add_task(
         db: &DbConn,
+        chain_id: i32,
         request_id: Vec<u8>,
         subscription_id: i32,
         group_index: i32,
@@ -182,6 +281,7 @@ impl RandomnessTaskMutation {
         assignment_block_height: i32,
     ) -> Result<randomness_task::ActiveModel, DbErr> {
         randomness_task::ActiveModel {
+            chain_id: Set(chain_id),
             request_id: Set(request_id),
             subscription_id: Set(subscription_id),
             group_index: Set(group_index),
@@ -204,15 +304,43 @@ impl RandomnessTaskMutation {
 
     pub async fn fetch_available_tasks(
         db: &DbConn,
+        chain_id: i32,
         group_index: i32,
         assignment_block_height: i32,
+        current_block_height: i32,
     ) -> Result<Vec<randomness_task::Model>, DbErr> {
-        randomness_task::Model::find_by_statement(Statement::from_sql_and_values(
-                DbBackend::Sqlite,
-                r#"update randomness_task set state = 1, update_at = $1 where state = 0 and (group_index = $2 or assignment_block_height < $3) 
-                returning *"#,
-                vec![format_now_date().into(), group_index.into(), assignment_block_height.into()],
-            ))
-            .all(db).await
+        randomness_task::Model::find_by_statement(claim_tasks_statement(
+            db.get_database_backend(),
+            "randomness_task",
+            "chain_id = $2 and state = 0 and (group_index = $3 or assignment_block_height < $4) and assignment_block_height + request_confirmations <= $5",
+            vec![format_now_date().into(), chain_id.into(), group_index.into(), assignment_block_height.into(), current_block_height.into()],
+        ))
+        .all(db)
+        .await
+    }
+
+    pub async fn remove_task(db: &DbConn, chain_id: i32, request_id: &[u8]) -> Result<(), DbErr> {
+        RandomnessTaskEntity::delete_many()
+            .filter(randomness_task::Column::ChainId.eq(chain_id))
+            .filter(randomness_task::Column::RequestId.eq(request_id))
+            .exec(db)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn release_task(db: &DbConn, chain_id: i32, request_id: &[u8]) -> Result<(), DbErr> {
+        RandomnessTaskEntity::update_many()
+            .col_expr(randomness_task::Column::State, Expr::value(0))
+            .col_expr(
+                randomness_task::Column::UpdateAt,
+                Expr::value(format_now_date()),
+            )
+            .filter(randomness_task::Column::ChainId.eq(chain_id))
+            .filter(randomness_task::Column::RequestId.eq(request_id))
+            .exec(db)
+            .await?;
+
+        Ok(())
     }
 }
@@ -0,0 +1,28 @@
+mod group_relay;
+mod main;
+
+pub use group_relay::{GroupRelayConfirmationTasksDBClient, GroupRelayTasksDBClient};
+pub use main::BLSTasksDBClient;
+
+use sea_orm::{DbBackend, Statement, Value};
+
+/// Builds the statement which marks the unhandled tasks of the table matching the condition as
+/// handled and returns them. The first value is the update time.
+///
+/// On PostgreSQL the rows claimed by a concurrent fetch are skipped instead of waited for, so that
+/// a task is only handed out once. SQLite serializes the writes by itself.
+pub(crate) fn claim_tasks_statement(
+    backend: DbBackend,
+    table: &str,
+    condition: &str,
+    values: Vec<Value>,
+) -> Statement {
+    let sql = match backend {
+        DbBackend::Postgres => format!(
+            "update {table} set state = 1, update_at = $1 where id in (select id from {table} where {condition} for update skip locked) returning *"
+        ),
+        _ => format!("update {table} set state = 1, update_at = $1 where {condition} returning *"),
+    };
+
+    Statement::from_sql_and_values(backend, sql, values)
+}
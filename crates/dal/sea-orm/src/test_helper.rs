@@ -100,10 +100,7 @@ where
             Phase2Result::GoToPhase3(_) => unreachable!("should not get here"),
         })
         .collect::<Vec<_>>();
-    assert!(is_all_same(outputs.iter().map(|output| {
-        // println!("{:?}", output.public);
-        &output.public
-    })));
+    assert!(is_all_same(outputs.iter().map(|output| &output.public)));
 
     outputs
 }
@@ -125,10 +122,7 @@ where
     let nodes = keypairs
         .iter()
         .enumerate()
-        .map(|(i, (_, public))| {
-            // println!("{}", i);
-            Node::<C>::new(i as Idx, public.clone())
-        })
+        .map(|(i, (_, public))| Node::<C>::new(i as Idx, public.clone()))
         .collect::<Vec<_>>();
 
     // This is setup phase during which publickeys and indexes must be exchanged
@@ -0,0 +1,239 @@
+use crate::cipher::SecretCipher;
+use arpa_core::PartialSignature;
+use arpa_core::RandomnessRequestType;
+use arpa_core::RandomnessTask;
+use arpa_core::{GroupRelayConfirmationTask, GroupRelayTask};
+use arpa_dal::cache::BLSResultCache;
+use arpa_dal::cache::RandomnessResultCache;
+use arpa_dal::cache::{GroupRelayConfirmationResultCache, GroupRelayResultCache};
+use arpa_dal::error::DataAccessError;
+use arpa_dal::BLSResultCacheState;
+use entity::randomness_task;
+use ethers_core::types::Address;
+use ethers_core::types::U256;
+use sea_orm::FromQueryResult;
+use sea_orm::{DatabaseConnection, DbErr};
+use std::collections::BTreeMap;
+use thiserror::Error;
+
+pub type DBResult<A> = Result<A, DBError>;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum DBError {
+    #[error(transparent)]
+    DbError(#[from] DbErr),
+    #[error("could not decrypt the {0} column, please check the (account)cipher key")]
+    SecretDecryptionError(String),
+}
+
+impl From<DBError> for DataAccessError {
+    fn from(e: DBError) -> Self {
+        DataAccessError::DBError(anyhow::Error::from(e))
+    }
+}
+
+/// The node database behind any connection sea-orm supports. The connection setup and the
+/// migrations are up to the crate of each database.
+#[derive(Default, Debug, Clone)]
+pub struct SeaOrmDB {
+    pub(crate) connection: DatabaseConnection,
+    pub(crate) secret_cipher: Option<SecretCipher>,
+}
+
+impl SeaOrmDB {
+    /// Without a cipher the secret columns are stored as they are, which is only meant for
+    /// databases that are encrypted as a whole, like the SQLCipher file.
+    pub fn new(connection: DatabaseConnection, secret_cipher: Option<SecretCipher>) -> Self {
+        SeaOrmDB {
+            connection,
+            secret_cipher,
+        }
+    }
+
+    pub fn get_connection(&self) -> &DatabaseConnection {
+        &self.connection
+    }
+
+    pub(crate) fn seal_secret(&self, column: &str, plaintext: Vec<u8>) -> Vec<u8> {
+        match &self.secret_cipher {
+            Some(cipher) => cipher.encrypt(column, &plaintext),
+            None => plaintext,
+        }
+    }
+
+    pub(crate) fn open_secret(&self, column: &str, sealed: &[u8]) -> DBResult<Vec<u8>> {
+        match &self.secret_cipher {
+            Some(cipher) => cipher.decrypt(column, sealed),
+            None => Ok(sealed.to_vec()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub(crate) struct RandomnessRecord {
+    // result
+    pub request_id: Vec<u8>,
+    pub group_index: i32,
+    pub message: Vec<u8>,
+    pub threshold: i32,
+    pub partial_signatures: String,
+    pub committed_times: i32,
+    pub state: i32,
+    // task
+    pub subscription_id: i32,
+    pub request_type: i32,
+    pub params: Vec<u8>,
+    pub requester: String,
+    pub seed: Vec<u8>,
+    pub request_confirmations: i32,
+    pub callback_gas_limit: i32,
+    pub callback_max_gas_price: Vec<u8>,
+    pub assignment_block_height: i32,
+}
+
+impl From<RandomnessRecord> for BLSResultCache<RandomnessResultCache> {
+    fn from(randomness_record: RandomnessRecord) -> Self {
+        let task = RandomnessTask {
+            request_id: randomness_record.request_id.clone(),
+            subscription_id: randomness_record.subscription_id as u64,
+            group_index: randomness_record.group_index as u32,
+            request_type: RandomnessRequestType::from(randomness_record.request_type as u8),
+            params: randomness_record.params,
+            requester: randomness_record.requester.parse::<Address>().unwrap(),
+            seed: U256::from_big_endian(&randomness_record.seed),
+            request_confirmations: randomness_record.request_confirmations as u16,
+            callback_gas_limit: randomness_record.callback_gas_limit as u32,
+            callback_max_gas_price: U256::from_big_endian(
+                &randomness_record.callback_max_gas_price,
+            ),
+            assignment_block_height: randomness_record.assignment_block_height as usize,
+        };
+
+        let partial_signatures: BTreeMap<Address, PartialSignature> =
+            serde_json::from_str(&randomness_record.partial_signatures).unwrap_or(BTreeMap::new());
+
+        BLSResultCache {
+            result_cache: RandomnessResultCache {
+                group_index: randomness_record.group_index as usize,
+                message: randomness_record.message,
+                randomness_task: task,
+                partial_signatures,
+                threshold: randomness_record.threshold as usize,
+                committed_times: randomness_record.committed_times as usize,
+            },
+            state: BLSResultCacheState::from(randomness_record.state),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub(crate) struct GroupRelayRecord {
+    // result
+    pub request_id: Vec<u8>,
+    pub group_index: i32,
+    pub message: Vec<u8>,
+    pub threshold: i32,
+    pub partial_signatures: String,
+    pub committed_times: i32,
+    pub state: i32,
+    // task
+    pub relayed_chain_id: i32,
+    pub controller_global_epoch: i32,
+    pub relayed_group_index: i32,
+    pub relayed_group_epoch: i32,
+    pub assignment_block_height: i32,
+}
+
+impl From<GroupRelayRecord> for BLSResultCache<GroupRelayResultCache> {
+    fn from(group_relay_record: GroupRelayRecord) -> Self {
+        let task = GroupRelayTask {
+            request_id: group_relay_record.request_id,
+            relayed_chain_id: group_relay_record.relayed_chain_id as usize,
+            controller_global_epoch: group_relay_record.controller_global_epoch as usize,
+            relayed_group_index: group_relay_record.relayed_group_index as usize,
+            relayed_group_epoch: group_relay_record.relayed_group_epoch as usize,
+            assignment_block_height: group_relay_record.assignment_block_height as usize,
+        };
+
+        let partial_signatures: BTreeMap<Address, PartialSignature> =
+            serde_json::from_str(&group_relay_record.partial_signatures).unwrap_or_default();
+
+        BLSResultCache {
+            result_cache: GroupRelayResultCache {
+                group_index: group_relay_record.group_index as usize,
+                group_relay_task: task,
+                message: group_relay_record.message,
+                threshold: group_relay_record.threshold as usize,
+                partial_signatures,
+                committed_times: group_relay_record.committed_times as usize,
+            },
+            state: BLSResultCacheState::from(group_relay_record.state),
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromQueryResult)]
+pub(crate) struct GroupRelayConfirmationRecord {
+    // result
+    pub request_id: Vec<u8>,
+    pub group_index: i32,
+    pub message: Vec<u8>,
+    pub threshold: i32,
+    pub partial_signatures: String,
+    pub committed_times: i32,
+    pub state: i32,
+    // task
+    pub group_relay_cache_index: i32,
+    pub relayed_group_index: i32,
+    pub relayed_group_epoch: i32,
+    pub relayer_group_index: i32,
+    pub assignment_block_height: i32,
+}
+
+impl From<GroupRelayConfirmationRecord> for BLSResultCache<GroupRelayConfirmationResultCache> {
+    fn from(group_relay_confirmation_record: GroupRelayConfirmationRecord) -> Self {
+        let task = GroupRelayConfirmationTask {
+            request_id: group_relay_confirmation_record.request_id,
+            index: group_relay_confirmation_record.group_relay_cache_index as usize,
+            group_relay_cache_index: group_relay_confirmation_record.group_relay_cache_index
+                as usize,
+            relayed_group_index: group_relay_confirmation_record.relayed_group_index as usize,
+            relayed_group_epoch: group_relay_confirmation_record.relayed_group_epoch as usize,
+            relayer_group_index: group_relay_confirmation_record.relayer_group_index as usize,
+            assignment_block_height: group_relay_confirmation_record.assignment_block_height
+                as usize,
+        };
+
+        let partial_signatures: BTreeMap<Address, PartialSignature> =
+            serde_json::from_str(&group_relay_confirmation_record.partial_signatures)
+                .unwrap_or_default();
+
+        BLSResultCache {
+            result_cache: GroupRelayConfirmationResultCache {
+                group_index: group_relay_confirmation_record.group_index as usize,
+                group_relay_confirmation_task: task,
+                message: group_relay_confirmation_record.message,
+                threshold: group_relay_confirmation_record.threshold as usize,
+                partial_signatures,
+                committed_times: group_relay_confirmation_record.committed_times as usize,
+            },
+            state: BLSResultCacheState::from(group_relay_confirmation_record.state),
+        }
+    }
+}
+
+pub(crate) fn model_to_randomness_task(model: randomness_task::Model) -> RandomnessTask {
+    RandomnessTask {
+        request_id: model.request_id,
+        subscription_id: model.subscription_id as u64,
+        group_index: model.group_index as u32,
+        request_type: RandomnessRequestType::from(model.request_type as u8),
+        params: model.params,
+        requester: model.requester.parse::<Address>().unwrap(),
+        seed: U256::from_big_endian(&model.seed),
+        request_confirmations: model.request_confirmations as u16,
+        callback_gas_limit: model.callback_gas_limit as u32,
+        callback_max_gas_price: U256::from_big_endian(&model.callback_max_gas_price),
+        assignment_block_height: model.assignment_block_height as usize,
+    }
+}
@@ -3,19 +3,11 @@
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
-#[sea_orm(table_name = "taiko_randomness_result")]
+#[sea_orm(table_name = "block_checkpoint")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
-    pub id: i32,
-    #[sea_orm(column_type = "Binary(BlobSize::Blob(None))")]
-    pub request_id: Vec<u8>,
-    pub group_index: i32,
-    #[sea_orm(column_type = "Binary(BlobSize::Blob(None))")]
-    pub message: Vec<u8>,
-    pub threshold: i32,
-    pub partial_signatures: String,
-    pub committed_times: i32,
-    pub state: i32,
+    pub chain_id: i32,
+    pub last_processed_block_height: i64,
     pub create_at: String,
     pub update_at: String,
 }
@@ -23,6 +23,8 @@ pub struct Model {
     pub dkg_start_block_height: i32,
     pub create_at: String,
     pub update_at: String,
+    #[sea_orm(column_type = "Binary(BlobSize::Blob(None))", nullable)]
+    pub dkg_checkpoint: Option<Vec<u8>>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -3,10 +3,11 @@
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
-#[sea_orm(table_name = "base_randomness_result")]
+#[sea_orm(table_name = "group_relay_confirmation_result")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: i32,
+    pub chain_id: i32,
     #[sea_orm(column_type = "Binary(BlobSize::Blob(None))")]
     pub request_id: Vec<u8>,
     pub group_index: i32,
@@ -3,7 +3,7 @@
 use sea_orm::entity::prelude::*;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq)]
-#[sea_orm(table_name = "op_randomness_result")]
+#[sea_orm(table_name = "group_relay_result")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: i32,
@@ -14,10 +14,10 @@ pub struct Model {
     pub message: Vec<u8>,
     pub threshold: i32,
     pub partial_signatures: String,
+    pub committed_times: i32,
     pub state: i32,
     pub create_at: String,
     pub update_at: String,
-    pub committed_times: i32,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -2,17 +2,12 @@
 
 pub mod prelude;
 
-pub mod base_randomness_result;
-pub mod base_randomness_task;
+pub mod block_checkpoint;
 pub mod group_info;
-pub mod loot_randomness_result;
-pub mod loot_randomness_task;
+pub mod group_relay_confirmation_result;
+pub mod group_relay_confirmation_task;
+pub mod group_relay_result;
+pub mod group_relay_task;
 pub mod node_info;
-pub mod op_randomness_result;
-pub mod op_randomness_task;
 pub mod randomness_result;
 pub mod randomness_task;
-pub mod redstone_randomness_result;
-pub mod redstone_randomness_task;
-pub mod taiko_randomness_result;
-pub mod taiko_randomness_task;
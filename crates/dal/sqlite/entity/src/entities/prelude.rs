@@ -1,16 +1,11 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.11.3
 
-pub use super::base_randomness_result::Entity as BaseRandomnessResult;
-pub use super::base_randomness_task::Entity as BaseRandomnessTask;
+pub use super::block_checkpoint::Entity as BlockCheckpoint;
 pub use super::group_info::Entity as GroupInfo;
-pub use super::loot_randomness_result::Entity as LootRandomnessResult;
-pub use super::loot_randomness_task::Entity as LootRandomnessTask;
+pub use super::group_relay_confirmation_result::Entity as GroupRelayConfirmationResult;
+pub use super::group_relay_confirmation_task::Entity as GroupRelayConfirmationTask;
+pub use super::group_relay_result::Entity as GroupRelayResult;
+pub use super::group_relay_task::Entity as GroupRelayTask;
 pub use super::node_info::Entity as NodeInfo;
-pub use super::op_randomness_result::Entity as OpRandomnessResult;
-pub use super::op_randomness_task::Entity as OpRandomnessTask;
 pub use super::randomness_result::Entity as RandomnessResult;
 pub use super::randomness_task::Entity as RandomnessTask;
-pub use super::redstone_randomness_result::Entity as RedstoneRandomnessResult;
-pub use super::redstone_randomness_task::Entity as RedstoneRandomnessTask;
-pub use super::taiko_randomness_result::Entity as TaikoRandomnessResult;
-pub use super::taiko_randomness_task::Entity as TaikoRandomnessTask;
@@ -28,6 +28,17 @@ mod m20240318_000025_create_taiko_randomness_task_table;
 mod m20240318_000026_create_taiko_randomness_task_index;
 mod m20240318_000027_create_taiko_randomness_result_table;
 mod m20240318_000028_create_taiko_randomness_result_index;
+mod m20240520_000029_create_block_checkpoint_table;
+mod m20240601_000030_create_group_relay_task_table;
+mod m20240601_000031_create_group_relay_task_index;
+mod m20240601_000032_create_group_relay_result_table;
+mod m20240601_000033_create_group_relay_result_index;
+mod m20240601_000034_create_group_relay_confirmation_task_table;
+mod m20240601_000035_create_group_relay_confirmation_task_index;
+mod m20240601_000036_create_group_relay_confirmation_result_table;
+mod m20240601_000037_create_group_relay_confirmation_result_index;
+mod m20240701_000038_update_group_info_table;
+mod m20261018_000039_fold_chain_randomness_tables;
 
 pub use m20220920_000001_create_node_info_table::NodeInfo;
 pub use m20220920_000002_create_group_info_table::GroupInfo;
@@ -45,6 +56,14 @@ pub use m20240312_000021_create_loot_randomness_task_table::LootRandomnessTask;
 pub use m20240312_000023_create_loot_randomness_result_table::LootRandomnessResult;
 pub use m20240318_000025_create_taiko_randomness_task_table::TaikoRandomnessTask;
 pub use m20240318_000027_create_taiko_randomness_result_table::TaikoRandomnessResult;
+pub use m20240520_000029_create_block_checkpoint_table::BlockCheckpoint;
+pub use m20240601_000030_create_group_relay_task_table::GroupRelayTask;
+pub use m20240601_000032_create_group_relay_result_table::GroupRelayResult;
+pub use m20240601_000034_create_group_relay_confirmation_task_table::GroupRelayConfirmationTask;
+pub use m20240601_000036_create_group_relay_confirmation_result_table::GroupRelayConfirmationResult;
+pub use m20261018_000039_fold_chain_randomness_tables::{
+    fold_legacy_randomness_tables, RandomnessNewColumn,
+};
 pub struct Migrator;
 
 #[async_trait::async_trait]
@@ -79,6 +98,17 @@ impl MigratorTrait for Migrator {
             Box::new(m20240318_000026_create_taiko_randomness_task_index::Migration),
             Box::new(m20240318_000027_create_taiko_randomness_result_table::Migration),
             Box::new(m20240318_000028_create_taiko_randomness_result_index::Migration),
+            Box::new(m20240520_000029_create_block_checkpoint_table::Migration),
+            Box::new(m20240601_000030_create_group_relay_task_table::Migration),
+            Box::new(m20240601_000031_create_group_relay_task_index::Migration),
+            Box::new(m20240601_000032_create_group_relay_result_table::Migration),
+            Box::new(m20240601_000033_create_group_relay_result_index::Migration),
+            Box::new(m20240601_000034_create_group_relay_confirmation_task_table::Migration),
+            Box::new(m20240601_000035_create_group_relay_confirmation_task_index::Migration),
+            Box::new(m20240601_000036_create_group_relay_confirmation_result_table::Migration),
+            Box::new(m20240601_000037_create_group_relay_confirmation_result_index::Migration),
+            Box::new(m20240701_000038_update_group_info_table::Migration),
+            Box::new(m20261018_000039_fold_chain_randomness_tables::Migration),
         ]
     }
 }
@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240601_000030_create_group_relay_task_table::GroupRelayTask;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayTask::Table)
+                    .name("group_relay_task_request_id")
+                    .col(GroupRelayTask::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayTask::Table)
+                    .name("group_relay_task_relayed_group_index")
+                    .col(GroupRelayTask::RelayedGroupIndex)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayTask::Table)
+                    .name("group_relay_task_state")
+                    .col(GroupRelayTask::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("group_relay_task_request_id").to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("group_relay_task_relayed_group_index").to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("group_relay_task_state").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240601_000032_create_group_relay_result_table::GroupRelayResult;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayResult::Table)
+                    .name("group_relay_result_request_id")
+                    .col(GroupRelayResult::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayResult::Table)
+                    .name("group_relay_result_state")
+                    .col(GroupRelayResult::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("group_relay_result_request_id").to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("group_relay_result_state").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,96 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::ChainId)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RequestId)
+                            .blob(BlobSize::Medium)
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::GroupRelayCacheIndex)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RelayedGroupIndex)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RelayedGroupEpoch)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::RelayerGroupIndex)
+                            .unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::AssignmentBlockHeight)
+                            .big_unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::State)
+                            .tiny_unsigned()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::CreateAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(GroupRelayConfirmationTask::UpdateAt)
+                            .date_time()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(GroupRelayConfirmationTask::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum GroupRelayConfirmationTask {
+    Table,
+    Id,
+    ChainId,
+    RequestId,
+    GroupRelayCacheIndex,
+    RelayedGroupIndex,
+    RelayedGroupEpoch,
+    RelayerGroupIndex,
+    AssignmentBlockHeight,
+    State,
+    CreateAt,
+    UpdateAt,
+}
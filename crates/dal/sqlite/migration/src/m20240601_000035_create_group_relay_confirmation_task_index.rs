@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240601_000034_create_group_relay_confirmation_task_table::GroupRelayConfirmationTask;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .name("group_relay_confirmation_task_chain_id_request_id")
+                    .col(GroupRelayConfirmationTask::ChainId)
+                    .col(GroupRelayConfirmationTask::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .name("group_relay_confirmation_task_relayed_group_index")
+                    .col(GroupRelayConfirmationTask::RelayedGroupIndex)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationTask::Table)
+                    .name("group_relay_confirmation_task_state")
+                    .col(GroupRelayConfirmationTask::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("group_relay_confirmation_task_chain_id_request_id").to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("group_relay_confirmation_task_relayed_group_index").to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("group_relay_confirmation_task_state").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20240601_000036_create_group_relay_confirmation_result_table::GroupRelayConfirmationResult;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationResult::Table)
+                    .name("group_relay_confirmation_result_chain_id_request_id")
+                    .col(GroupRelayConfirmationResult::ChainId)
+                    .col(GroupRelayConfirmationResult::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(GroupRelayConfirmationResult::Table)
+                    .name("group_relay_confirmation_result_state")
+                    .col(GroupRelayConfirmationResult::State)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(Index::drop().name("group_relay_confirmation_result_chain_id_request_id").to_owned())
+            .await?;
+
+        manager
+            .drop_index(Index::drop().name("group_relay_confirmation_result_state").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,402 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{
+    ConnectionTrait, DatabaseConnection, Statement, TransactionTrait,
+};
+
+use crate::m20220920_000003_create_randomness_task_table::RandomnessTask;
+use crate::m20230612_000005_create_randomness_result_table::RandomnessResult;
+use crate::{
+    m20230815_000007_create_op_randomness_task_table,
+    m20230815_000008_create_op_randomness_task_index,
+    m20230815_000009_create_op_randomness_result_table,
+    m20230815_000010_create_op_randomness_result_index,
+    m20230911_000012_update_op_randomness_result_table,
+    m20231115_000013_create_base_randomness_task_table,
+    m20231115_000014_create_base_randomness_task_index,
+    m20231115_000015_create_base_randomness_result_table,
+    m20231115_000016_create_base_randomness_result_index,
+    m20240129_000017_create_redstone_randomness_task_table,
+    m20240129_000018_create_redstone_randomness_task_index,
+    m20240129_000019_create_redstone_randomness_result_table,
+    m20240129_000020_create_redstone_randomness_result_index,
+    m20240312_000021_create_loot_randomness_task_table,
+    m20240312_000022_create_loot_randomness_task_index,
+    m20240312_000023_create_loot_randomness_result_table,
+    m20240312_000024_create_loot_randomness_result_index,
+    m20240318_000025_create_taiko_randomness_task_table,
+    m20240318_000026_create_taiko_randomness_task_index,
+    m20240318_000027_create_taiko_randomness_result_table,
+    m20240318_000028_create_taiko_randomness_result_index,
+};
+
+const TASK_COLUMNS: &str = "request_id, subscription_id, group_index, request_type, params, \
+    requester, seed, request_confirmations, callback_gas_limit, callback_max_gas_price, \
+    assignment_block_height, state, create_at, update_at";
+
+const RESULT_COLUMNS: &str = "request_id, group_index, message, threshold, partial_signatures, \
+    committed_times, state, create_at, update_at";
+
+/// The tables that used to keep the randomness tasks and results of a family of L2 chains, and
+/// the chain ids the releases before the chain-keyed schema routed to them. They are only used to
+/// tell which of the relayed chains a table was kept for, and where to put its rows back on down.
+struct LegacyTables {
+    prefix: &'static str,
+    chain_ids: &'static [i32],
+}
+
+const LEGACY_TABLES: [LegacyTables; 5] = [
+    LegacyTables {
+        prefix: "op",
+        chain_ids: &[10, 420, 11155420, 901],
+    },
+    LegacyTables {
+        prefix: "base",
+        chain_ids: &[8453, 84531, 84532],
+    },
+    LegacyTables {
+        prefix: "redstone",
+        chain_ids: &[690, 17001, 17069],
+    },
+    LegacyTables {
+        prefix: "loot",
+        chain_ids: &[5151706, 9088912],
+    },
+    LegacyTables {
+        prefix: "taiko",
+        chain_ids: &[167000, 167009],
+    },
+];
+
+/// Keeps the randomness tasks and results of all the chains in `randomness_task` and
+/// `randomness_result` keyed by the chain id, where 0 stands for the main chain.
+///
+/// The rows left in the tables of every L2 chain are folded into them afterwards by
+/// [`fold_legacy_randomness_tables`], which needs the relayed chains of the config.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RandomnessTask::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(RandomnessNewColumn::ChainId)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RandomnessResult::Table)
+                    .add_column_if_not_exists(
+                        ColumnDef::new(RandomnessNewColumn::ChainId)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // the request ids are only unique in a chain
+        manager
+            .drop_index(Index::drop().name("request_id").to_owned())
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("randomness_result_request_id")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessTask::Table)
+                    .name("randomness_task_chain_id_request_id")
+                    .col(RandomnessNewColumn::ChainId)
+                    .col(RandomnessTask::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessResult::Table)
+                    .name("randomness_result_chain_id_request_id")
+                    .col(RandomnessNewColumn::ChainId)
+                    .col(RandomnessResult::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // the tables not folded yet are still there
+        if !manager.has_table("op_randomness_task").await? {
+            m20230815_000007_create_op_randomness_task_table::Migration
+                .up(manager)
+                .await?;
+            m20230815_000008_create_op_randomness_task_index::Migration
+                .up(manager)
+                .await?;
+            m20230815_000009_create_op_randomness_result_table::Migration
+                .up(manager)
+                .await?;
+            m20230815_000010_create_op_randomness_result_index::Migration
+                .up(manager)
+                .await?;
+            m20230911_000012_update_op_randomness_result_table::Migration
+                .up(manager)
+                .await?;
+        }
+
+        if !manager.has_table("base_randomness_task").await? {
+            m20231115_000013_create_base_randomness_task_table::Migration
+                .up(manager)
+                .await?;
+            m20231115_000014_create_base_randomness_task_index::Migration
+                .up(manager)
+                .await?;
+            m20231115_000015_create_base_randomness_result_table::Migration
+                .up(manager)
+                .await?;
+            m20231115_000016_create_base_randomness_result_index::Migration
+                .up(manager)
+                .await?;
+        }
+
+        if !manager.has_table("redstone_randomness_task").await? {
+            m20240129_000017_create_redstone_randomness_task_table::Migration
+                .up(manager)
+                .await?;
+            m20240129_000018_create_redstone_randomness_task_index::Migration
+                .up(manager)
+                .await?;
+            m20240129_000019_create_redstone_randomness_result_table::Migration
+                .up(manager)
+                .await?;
+            m20240129_000020_create_redstone_randomness_result_index::Migration
+                .up(manager)
+                .await?;
+        }
+
+        if !manager.has_table("loot_randomness_task").await? {
+            m20240312_000021_create_loot_randomness_task_table::Migration
+                .up(manager)
+                .await?;
+            m20240312_000022_create_loot_randomness_task_index::Migration
+                .up(manager)
+                .await?;
+            m20240312_000023_create_loot_randomness_result_table::Migration
+                .up(manager)
+                .await?;
+            m20240312_000024_create_loot_randomness_result_index::Migration
+                .up(manager)
+                .await?;
+        }
+
+        if !manager.has_table("taiko_randomness_task").await? {
+            m20240318_000025_create_taiko_randomness_task_table::Migration
+                .up(manager)
+                .await?;
+            m20240318_000026_create_taiko_randomness_task_index::Migration
+                .up(manager)
+                .await?;
+            m20240318_000027_create_taiko_randomness_result_table::Migration
+                .up(manager)
+                .await?;
+            m20240318_000028_create_taiko_randomness_result_index::Migration
+                .up(manager)
+                .await?;
+        }
+
+        let conn = manager.get_connection();
+
+        for legacy_tables in LEGACY_TABLES {
+            let chain_ids = legacy_tables
+                .chain_ids
+                .iter()
+                .map(|chain_id| chain_id.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            conn.execute_unprepared(&format!(
+                "insert into {prefix}_randomness_task ({columns}) select {columns} from randomness_task where chain_id in ({chain_ids})",
+                prefix = legacy_tables.prefix,
+                columns = TASK_COLUMNS,
+            ))
+            .await?;
+
+            conn.execute_unprepared(&format!(
+                "insert into {prefix}_randomness_result ({columns}) select {columns} from randomness_result where chain_id in ({chain_ids})",
+                prefix = legacy_tables.prefix,
+                columns = RESULT_COLUMNS,
+            ))
+            .await?;
+        }
+
+        // the tasks and results of other relayed chains had no table to go
+        conn.execute_unprepared("delete from randomness_task where chain_id != 0")
+            .await?;
+
+        conn.execute_unprepared("delete from randomness_result where chain_id != 0")
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("randomness_task_chain_id_request_id")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("randomness_result_chain_id_request_id")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessTask::Table)
+                    .name("request_id")
+                    .col(RandomnessTask::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .table(RandomnessResult::Table)
+                    .name("randomness_result_request_id")
+                    .col(RandomnessResult::RequestId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RandomnessTask::Table)
+                    .drop_column(RandomnessNewColumn::ChainId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RandomnessResult::Table)
+                    .drop_column(RandomnessNewColumn::ChainId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+/// Moves the rows left in the tables of every family of L2 chains into `randomness_task` and
+/// `randomness_result`, and drops the tables.
+///
+/// The tables never recorded the chain id of their rows, so it is taken from the relayed chains
+/// of the config. A table holding rows which can't be attributed to exactly one relayed chain is
+/// an error rather than a guess, and is left as it is.
+pub async fn fold_legacy_randomness_tables(
+    db: &DatabaseConnection,
+    relayed_chain_ids: &[i32],
+) -> Result<(), DbErr> {
+    for legacy_tables in LEGACY_TABLES {
+        let task_table = format!("{}_randomness_task", legacy_tables.prefix);
+        let result_table = format!("{}_randomness_result", legacy_tables.prefix);
+
+        if !SchemaManager::new(db).has_table(&task_table).await? {
+            continue;
+        }
+
+        let txn = db.begin().await?;
+
+        let row_count = txn
+            .query_one(Statement::from_string(
+                db.get_database_backend(),
+                format!(
+                    "select (select count(*) from {task_table}) + (select count(*) from {result_table}) as row_count"
+                ),
+            ))
+            .await?
+            .ok_or_else(|| DbErr::RecordNotFound(task_table.clone()))?
+            .try_get::<i64>("", "row_count")?;
+
+        if row_count > 0 {
+            let chain_ids = relayed_chain_ids
+                .iter()
+                .filter(|chain_id| legacy_tables.chain_ids.contains(chain_id))
+                .collect::<Vec<_>>();
+
+            let chain_id = match chain_ids.as_slice() {
+                [chain_id] => **chain_id,
+                [] => {
+                    return Err(DbErr::Migration(format!(
+                        "{task_table} and {result_table} hold {row_count} rows of none of the relayed chains, \
+                        please add back the chain they were kept for, or drop the tables if they are no longer needed"
+                    )))
+                }
+                _ => {
+                    return Err(DbErr::Migration(format!(
+                        "{task_table} and {result_table} hold {row_count} rows of one of the relayed chains {chain_ids:?}, \
+                        please relay only the chain they were kept for until they are folded"
+                    )))
+                }
+            };
+
+            txn.execute_unprepared(&format!(
+                "insert into randomness_task (chain_id, {columns}) select {chain_id}, {columns} from {task_table}",
+                columns = TASK_COLUMNS,
+            ))
+            .await?;
+
+            txn.execute_unprepared(&format!(
+                "insert into randomness_result (chain_id, {columns}) select {chain_id}, {columns} from {result_table}",
+                columns = RESULT_COLUMNS,
+            ))
+            .await?;
+        }
+
+        let manager = SchemaManager::new(&txn);
+
+        manager
+            .drop_table(Table::drop().table(Alias::new(task_table)).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(Alias::new(result_table)).to_owned())
+            .await?;
+
+        txn.commit().await?;
+    }
+
+    Ok(())
+}
+
+#[derive(Iden)]
+pub enum RandomnessNewColumn {
+    ChainId,
+}
@@ -1,265 +1,99 @@
-mod group;
-mod node;
-mod result;
-mod task;
-mod test_helper;
-mod types;
-pub use crate::group::GroupInfoDBClient;
-pub use crate::node::NodeInfoDBClient;
-pub use crate::result::OPSignatureResultDBClient;
-pub use crate::result::SignatureResultDBClient;
-pub use crate::task::BLSTasksDBClient;
-pub use crate::task::OPBLSTasksDBClient;
-pub use crate::types::DBError;
-pub use crate::types::DBResult;
-pub use crate::types::SqliteDB;
-use arpa_core::RandomnessTask;
-use arpa_core::BASE_GOERLI_TESTNET_CHAIN_ID;
-use arpa_core::BASE_MAINNET_CHAIN_ID;
-use arpa_core::BASE_SEPOLIA_TESTNET_CHAIN_ID;
-use arpa_core::LOOT_MAINNET_CHAIN_ID;
-use arpa_core::LOOT_TESTNET_CHAIN_ID;
-use arpa_core::OP_DEVNET_CHAIN_ID;
-use arpa_core::OP_GOERLI_TESTNET_CHAIN_ID;
-use arpa_core::OP_MAINNET_CHAIN_ID;
-use arpa_core::OP_SEPOLIA_TESTNET_CHAIN_ID;
-use arpa_core::REDSTONE_GARNET_TESTNET_CHAIN_ID;
-use arpa_core::REDSTONE_HOLESKY_TESTNET_CHAIN_ID;
-use arpa_core::REDSTONE_MAINNET_CHAIN_ID;
-use arpa_core::TAIKO_HEKLA_TESTNET_CHAIN_ID;
-use arpa_core::TAIKO_MAINNET_CHAIN_ID;
-use arpa_dal::cache::RandomnessResultCache;
-use arpa_dal::error::DataAccessError;
-use arpa_dal::error::DataAccessResult;
-use arpa_dal::BLSTasksHandler;
-use arpa_dal::GroupInfoHandler;
-use arpa_dal::NodeInfoHandler;
-use arpa_dal::SignatureResultCacheHandler;
+use arpa_sea_orm_db::{DBResult, SeaOrmDB};
 use ethers_core::utils::hex;
 use log::LevelFilter;
+use migration::fold_legacy_randomness_tables;
 use migration::Migrator;
 use migration::MigratorTrait;
-use migration::SelectStatement;
-use migration::UpdateStatement;
-use result::BaseSignatureResultDBClient;
-use result::LootSignatureResultDBClient;
-use result::RedstoneSignatureResultDBClient;
-use result::TaikoSignatureResultDBClient;
 use sea_orm::ConnectOptions;
 use sea_orm::ConnectionTrait;
 use sea_orm::DatabaseBackend;
-use sea_orm::FromQueryResult;
 use sea_orm::QueryResult;
 use sea_orm::Statement;
 use std::time::Duration;
-use task::BaseBLSTasksDBClient;
-use task::LootBLSTasksDBClient;
-use task::RedstoneBLSTasksDBClient;
-use task::TaikoBLSTasksDBClient;
-use threshold_bls::group::Curve;
-
-impl SqliteDB {
-    pub async fn build(
-        db_path: &str,
-        signing_key: &[u8],
-    ) -> Result<Self, Box<dyn std::error::Error>> {
-        let mut opt = ConnectOptions::new(format!("sqlite://{}?mode=rwc", db_path));
-        opt.max_connections(100)
-            .min_connections(5)
-            .connect_timeout(Duration::from_secs(8))
-            .idle_timeout(Duration::from_secs(8))
-            .max_lifetime(Duration::from_secs(8))
-            .sqlx_logging(true)
-            .sqlx_logging_level(LevelFilter::Debug)
-            .sqlcipher_key(format!("\"x'{}'\"", hex::encode(signing_key)));
-
-        let connection = sea_orm::Database::connect(opt).await?;
-
-        let db = SqliteDB { connection };
-
-        db.integrity_check().await.map_err(|e|
-            format!("Node identity is different from the database, please check the (account)cipher key. Original error: {:?}", e.to_string()))?;
-
-        Migrator::up(&db.connection, None).await?;
-
-        Ok(db)
-    }
-
-    pub async fn integrity_check(&self) -> DBResult<String> {
-        let query_res: Option<QueryResult> = self
-            .connection
-            .query_one(Statement::from_string(
-                DatabaseBackend::Sqlite,
-                "PRAGMA integrity_check;".to_owned(),
-            ))
-            .await?;
-
-        query_res
-            .unwrap()
-            .try_get::<String>("", "integrity_check")
-            .map_err(|e| e.into())
-    }
-
-    pub fn build_randomness_tasks_cache(
-        &self,
-        chain_id: usize,
-    ) -> DataAccessResult<Box<dyn BLSTasksHandler<RandomnessTask>>> {
-        match chain_id {
-            0 => Ok(Box::new(self.get_bls_tasks_client::<RandomnessTask>())),
-            OP_MAINNET_CHAIN_ID
-            | OP_GOERLI_TESTNET_CHAIN_ID
-            | OP_SEPOLIA_TESTNET_CHAIN_ID
-            | OP_DEVNET_CHAIN_ID => Ok(Box::new(self.get_op_bls_tasks_client::<RandomnessTask>())),
-            BASE_MAINNET_CHAIN_ID
-            | BASE_GOERLI_TESTNET_CHAIN_ID
-            | BASE_SEPOLIA_TESTNET_CHAIN_ID => {
-                Ok(Box::new(self.get_base_bls_tasks_client::<RandomnessTask>()))
-            }
-            REDSTONE_HOLESKY_TESTNET_CHAIN_ID
-            | REDSTONE_MAINNET_CHAIN_ID
-            | REDSTONE_GARNET_TESTNET_CHAIN_ID => Ok(Box::new(
-                self.get_redstone_bls_tasks_client::<RandomnessTask>(),
-            )),
-            LOOT_MAINNET_CHAIN_ID | LOOT_TESTNET_CHAIN_ID => {
-                Ok(Box::new(self.get_loot_bls_tasks_client::<RandomnessTask>()))
-            }
-            TAIKO_HEKLA_TESTNET_CHAIN_ID | TAIKO_MAINNET_CHAIN_ID => Ok(Box::new(
-                self.get_taiko_bls_tasks_client::<RandomnessTask>(),
-            )),
-            _ => Err(DataAccessError::InvalidChainId(chain_id)),
-        }
-    }
 
-    pub async fn build_randomness_result_cache(
-        &self,
-        chain_id: usize,
-    ) -> DataAccessResult<Box<dyn SignatureResultCacheHandler<RandomnessResultCache>>> {
-        match chain_id {
-            0 => Ok(Box::new(self.get_randomness_result_client().await?)),
-            OP_MAINNET_CHAIN_ID
-            | OP_GOERLI_TESTNET_CHAIN_ID
-            | OP_SEPOLIA_TESTNET_CHAIN_ID
-            | OP_DEVNET_CHAIN_ID => Ok(Box::new(self.get_op_randomness_result_client().await?)),
-            BASE_MAINNET_CHAIN_ID
-            | BASE_GOERLI_TESTNET_CHAIN_ID
-            | BASE_SEPOLIA_TESTNET_CHAIN_ID => {
-                Ok(Box::new(self.get_base_randomness_result_client().await?))
-            }
-            REDSTONE_HOLESKY_TESTNET_CHAIN_ID
-            | REDSTONE_MAINNET_CHAIN_ID
-            | REDSTONE_GARNET_TESTNET_CHAIN_ID => Ok(Box::new(
-                self.get_redstone_randomness_result_client().await?,
-            )),
-            LOOT_MAINNET_CHAIN_ID | LOOT_TESTNET_CHAIN_ID => {
-                Ok(Box::new(self.get_loot_randomness_result_client().await?))
-            }
-            TAIKO_HEKLA_TESTNET_CHAIN_ID | TAIKO_MAINNET_CHAIN_ID => {
-                Ok(Box::new(self.get_taiko_randomness_result_client().await?))
-            }
-            _ => Err(DataAccessError::InvalidChainId(chain_id)),
-        }
-    }
-
-    pub(crate) async fn execute_update_statement(
-        &self,
-        stmt: &UpdateStatement,
-    ) -> DataAccessResult<()> {
-        let builder = self.connection.get_database_backend();
-
-        self.connection
-            .execute(builder.build(stmt))
-            .await
-            .map_err(|e| {
-                let e: DBError = e.into();
-                e
-            })?;
-
-        Ok(())
-    }
-
-    pub(crate) async fn query_all_statement<S: FromQueryResult>(
-        &self,
-        stmt: &SelectStatement,
-    ) -> DataAccessResult<Vec<S>> {
-        let builder = self.connection.get_database_backend();
-
-        S::find_by_statement(builder.build(stmt))
-            .all(&self.connection)
-            .await
-            .map_err(|e| {
-                let e: DBError = e.into();
-                e.into()
-            })
-    }
-
-    pub(crate) async fn query_one_statement<S: FromQueryResult>(
-        &self,
-        stmt: &SelectStatement,
-    ) -> DataAccessResult<Option<S>> {
-        let builder = self.connection.get_database_backend();
-
-        S::find_by_statement(builder.build(stmt))
-            .one(&self.connection)
-            .await
-            .map_err(|e| {
-                let e: DBError = e.into();
-                e.into()
-            })
-    }
+/// Opens the SQLite file of the node, creating it if absent, and brings its schema up to date.
+/// The whole file is encrypted by SQLCipher with the signing key of the node, so the secret
+/// columns are stored as they are.
+///
+/// The randomness tasks and results left in the tables of the L2 chains by an older release are
+/// moved to the relayed chains they were kept for.
+pub async fn build(
+    db_path: &str,
+    signing_key: &[u8],
+    relayed_chain_ids: &[usize],
+) -> Result<SeaOrmDB, Box<dyn std::error::Error>> {
+    let mut opt = ConnectOptions::new(format!("sqlite://{}?mode=rwc", db_path));
+    opt.max_connections(100)
+        .min_connections(5)
+        .connect_timeout(Duration::from_secs(8))
+        .idle_timeout(Duration::from_secs(8))
+        .max_lifetime(Duration::from_secs(8))
+        .sqlx_logging(true)
+        .sqlx_logging_level(LevelFilter::Debug)
+        .sqlcipher_key(format!("\"x'{}'\"", hex::encode(signing_key)));
+
+    let connection = sea_orm::Database::connect(opt).await?;
+
+    let db = SeaOrmDB::new(connection, None);
+
+    integrity_check(&db).await.map_err(|e|
+        format!("Node identity is different from the database, please check the (account)cipher key. Original error: {:?}", e.to_string()))?;
+
+    Migrator::up(db.get_connection(), None).await?;
+
+    let relayed_chain_ids = relayed_chain_ids
+        .iter()
+        .map(|chain_id| *chain_id as i32)
+        .collect::<Vec<_>>();
+
+    fold_legacy_randomness_tables(db.get_connection(), &relayed_chain_ids).await?;
+
+    Ok(db)
 }
 
-impl<PC: Curve + 'static> NodeInfoHandler<PC> for NodeInfoDBClient<PC> {}
-impl<PC: Curve + 'static> GroupInfoHandler<PC> for GroupInfoDBClient<PC> {}
-impl BLSTasksHandler<RandomnessTask> for BLSTasksDBClient<RandomnessTask> {}
-impl BLSTasksHandler<RandomnessTask> for OPBLSTasksDBClient<RandomnessTask> {}
-impl BLSTasksHandler<RandomnessTask> for BaseBLSTasksDBClient<RandomnessTask> {}
-impl BLSTasksHandler<RandomnessTask> for RedstoneBLSTasksDBClient<RandomnessTask> {}
-impl BLSTasksHandler<RandomnessTask> for LootBLSTasksDBClient<RandomnessTask> {}
-impl BLSTasksHandler<RandomnessTask> for TaikoBLSTasksDBClient<RandomnessTask> {}
-
-impl SignatureResultCacheHandler<RandomnessResultCache>
-    for SignatureResultDBClient<RandomnessResultCache>
-{
-}
-impl SignatureResultCacheHandler<RandomnessResultCache>
-    for OPSignatureResultDBClient<RandomnessResultCache>
-{
-}
-impl SignatureResultCacheHandler<RandomnessResultCache>
-    for BaseSignatureResultDBClient<RandomnessResultCache>
-{
-}
-impl SignatureResultCacheHandler<RandomnessResultCache>
-    for RedstoneSignatureResultDBClient<RandomnessResultCache>
-{
-}
-impl SignatureResultCacheHandler<RandomnessResultCache>
-    for LootSignatureResultDBClient<RandomnessResultCache>
-{
-}
-impl SignatureResultCacheHandler<RandomnessResultCache>
-    for TaikoSignatureResultDBClient<RandomnessResultCache>
-{
+pub async fn integrity_check(db: &SeaOrmDB) -> DBResult<String> {
+    let query_res: Option<QueryResult> = db
+        .get_connection()
+        .query_one(Statement::from_string(
+            DatabaseBackend::Sqlite,
+            "PRAGMA integrity_check;".to_owned(),
+        ))
+        .await?;
+
+    query_res
+        .unwrap()
+        .try_get::<String>("", "integrity_check")
+        .map_err(|e| e.into())
 }
 
 #[cfg(test)]
 pub mod sqlite_tests {
-    use crate::test_helper;
-    use crate::SqliteDB;
     use arpa_core::DKGStatus;
     use arpa_core::DKGTask;
     use arpa_core::RandomnessRequestType;
     use arpa_core::RandomnessTask;
     use arpa_core::DEFAULT_RANDOMNESS_TASK_EXCLUSIVE_WINDOW;
     use arpa_core::PLACEHOLDER_ADDRESS;
+    use arpa_core::{DataRetentionDescriptor, RetentionPolicyDescriptor};
+    use arpa_core::{GroupRelayConfirmationTask, GroupRelayTask};
+    use arpa_dal::BLSResultCacheState;
     use arpa_dal::BLSTasksFetcher;
     use arpa_dal::BLSTasksUpdater;
+    use arpa_dal::BlockCheckpointFetcher;
+    use arpa_dal::BlockCheckpointUpdater;
     use arpa_dal::GroupInfoFetcher;
     use arpa_dal::GroupInfoUpdater;
     use arpa_dal::NodeInfoFetcher;
     use arpa_dal::NodeInfoUpdater;
+    use arpa_dal::SignatureResultCacheFetcher;
+    use arpa_dal::SignatureResultCacheUpdater;
+    use arpa_sea_orm_db::test_helper;
+    use arpa_sea_orm_db::SeaOrmDB;
     use ethers_core::types::Address;
     use ethers_core::types::U256;
+    use migration::Migrator;
+    use migration::MigratorTrait;
+    use sea_orm::{ConnectionTrait, DatabaseBackend, Statement};
     use std::{fs, path::PathBuf};
     use threshold_bls::curve::bn254::G2Curve;
     use threshold_bls::schemes::bn254::G2Scheme;
@@ -279,8 +113,8 @@ pub mod sqlite_tests {
         fs::remove_file(DB_PATH).expect("could not remove file");
     }
 
-    pub async fn build_sqlite_db() -> Result<SqliteDB, Box<dyn std::error::Error>> {
-        SqliteDB::build(DB_PATH, CIPHER_KEY.as_bytes()).await
+    pub async fn build_sqlite_db() -> Result<SeaOrmDB, Box<dyn std::error::Error>> {
+        crate::build(DB_PATH, CIPHER_KEY.as_bytes(), &[]).await
     }
 
     #[tokio::test]
@@ -300,7 +134,7 @@ pub mod sqlite_tests {
 
         let db = build_sqlite_db().await.unwrap();
 
-        let res = db.integrity_check().await.unwrap();
+        let res = crate::integrity_check(&db).await.unwrap();
 
         assert_eq!("ok".to_owned(), res);
 
@@ -324,12 +158,9 @@ pub mod sqlite_tests {
 
         let (private_key, public_key) = G2Scheme::keypair(rng);
 
-        if let Err(e) = db
-            .save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
+        db.save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
             .await
-        {
-            println!("{:?}", e);
-        }
+            .unwrap();
 
         assert_eq!(id_address, db.get_id_address().unwrap());
         assert_eq!("127.0.0.1", db.get_node_rpc_endpoint().unwrap());
@@ -354,18 +185,13 @@ pub mod sqlite_tests {
 
         let (private_key, public_key) = G2Scheme::keypair(rng);
 
-        if let Err(e) = db
-            .save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
+        db.save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
             .await
-        {
-            println!("{:?}", e);
-        }
+            .unwrap();
 
         let node_rpc_endpoint = String::from("192.168.0.1");
 
-        if let Err(e) = db.set_node_rpc_endpoint(node_rpc_endpoint).await {
-            println!("{:?}", e);
-        }
+        db.set_node_rpc_endpoint(node_rpc_endpoint).await.unwrap();
 
         assert_eq!("192.168.0.1", db.get_node_rpc_endpoint().unwrap());
 
@@ -389,20 +215,15 @@ pub mod sqlite_tests {
 
         let (private_key, public_key) = G2Scheme::keypair(rng);
 
-        if let Err(e) = db
-            .save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
+        db.save_node_info(id_address, node_rpc_endpoint, private_key, public_key)
             .await
-        {
-            println!("{:?}", e);
-        }
+            .unwrap();
 
         let rng = &mut rand::thread_rng();
 
         let (private_key, public_key) = G2Scheme::keypair(rng);
 
-        if let Err(e) = db.set_dkg_key_pair(private_key, public_key).await {
-            println!("{:?}", e);
-        }
+        db.set_dkg_key_pair(private_key, public_key).await.unwrap();
 
         assert_eq!(&private_key, db.get_dkg_private_key().unwrap());
         assert_eq!(&public_key, db.get_dkg_public_key().unwrap());
@@ -419,7 +240,7 @@ pub mod sqlite_tests {
         let mut db = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
 
         if let Ok(res) = db.refresh_current_group_info().await {
-            assert_eq!(res, false);
+            assert!(!res);
         } else {
             panic!("should not fail");
         }
@@ -456,18 +277,13 @@ pub mod sqlite_tests {
                 .unwrap(),
         };
 
-        if let Err(e) = db.save_task_info(1, task).await {
-            println!("{:?}", e);
-        }
-
-        let res = db.group_info_cache.unwrap();
-        println!("{:?}", res);
+        db.save_task_info(1, task).await.unwrap();
 
-        assert_eq!(3, res.get_size().unwrap());
-        assert_eq!(3, res.get_threshold().unwrap());
-        assert_eq!(0, res.get_member(member_1).unwrap().index);
-        assert_eq!(1, res.get_member(member_2).unwrap().index);
-        assert_eq!(2, res.get_member(member_3).unwrap().index);
+        assert_eq!(3, db.get_size().unwrap());
+        assert_eq!(3, db.get_threshold().unwrap());
+        assert_eq!(0, db.get_member(member_1).unwrap().index);
+        assert_eq!(1, db.get_member(member_2).unwrap().index);
+        assert_eq!(2, db.get_member(member_3).unwrap().index);
 
         teardown();
     }
@@ -501,18 +317,81 @@ pub mod sqlite_tests {
                 .unwrap(),
         };
 
-        if let Err(e) = db.save_task_info(1, task).await {
-            println!("{:?}", e);
-        }
+        db.save_task_info(1, task).await.unwrap();
 
-        if let Err(e) = db.update_dkg_status(1, 1, DKGStatus::InPhase).await {
-            println!("{:?}", e);
-        }
+        db.update_dkg_status(1, 1, DKGStatus::InPhase)
+            .await
+            .unwrap();
+
+        assert_eq!(DKGStatus::InPhase, db.get_dkg_status().unwrap());
+
+        teardown();
+    }
+
+    #[tokio::test]
+    async fn test_save_dkg_checkpoint() {
+        setup();
+        let db = build_sqlite_db().await.unwrap();
+
+        let mut group_info_client = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        let members: Vec<Address> = [
+            "0x0000000000000000000000000000000000000001"
+                .parse()
+                .unwrap(),
+            "0x0000000000000000000000000000000000000002"
+                .parse()
+                .unwrap(),
+            "0x0000000000000000000000000000000000000003"
+                .parse()
+                .unwrap(),
+        ]
+        .to_vec();
+
+        let task = DKGTask {
+            group_index: 1,
+            epoch: 1,
+            size: 3,
+            threshold: 3,
+            members,
+            assignment_block_height: 100,
+            coordinator_address: "0x00000000000000000000000000000000000000c1"
+                .parse()
+                .unwrap(),
+        };
+
+        group_info_client.save_task_info(1, task).await.unwrap();
+        group_info_client
+            .update_dkg_status(1, 1, DKGStatus::InPhase)
+            .await
+            .unwrap();
+        group_info_client
+            .save_dkg_checkpoint(1, 1, vec![1, 2, 3])
+            .await
+            .unwrap();
+
+        assert!(group_info_client
+            .save_dkg_checkpoint(1, 2, vec![4, 5, 6])
+            .await
+            .is_err());
+
+        // a restarted node reads the checkpoint back from the db
+        let mut restarted_client = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        restarted_client.refresh_current_group_info().await.unwrap();
+
+        assert_eq!(
+            Some(&[1u8, 2, 3][..]),
+            restarted_client.get_dkg_checkpoint().unwrap()
+        );
+
+        restarted_client
+            .save_failed_output(1, 1, vec![])
+            .await
+            .unwrap();
 
-        let res = db.group_info_cache.unwrap();
-        println!("{:?}", res);
+        let mut restarted_client = db.get_group_info_client::<G2Curve>(PLACEHOLDER_ADDRESS);
+        restarted_client.refresh_current_group_info().await.unwrap();
 
-        assert_eq!(DKGStatus::InPhase, res.get_dkg_status().unwrap());
+        assert_eq!(None, restarted_client.get_dkg_checkpoint().unwrap());
 
         teardown();
     }
@@ -546,14 +425,10 @@ pub mod sqlite_tests {
                 .unwrap(),
         };
 
-        if let Err(e) = db.save_task_info(0, task).await {
-            println!("{:?}", e);
-        }
+        db.save_task_info(0, task).await.unwrap();
 
         let (t, n) = (3, 3);
 
-        println!("nodes setup... t: {} n: {}", t, n);
-
         let rng = &mut rand::thread_rng();
 
         let (mut board, phase0s) = test_helper::setup::<G2Curve, G2Scheme, _>(n, t, rng);
@@ -562,27 +437,24 @@ pub mod sqlite_tests {
 
         let output = outputs.remove(0);
 
-        if let Err(e) = db.save_successful_output(1, 1, output.clone()).await {
-            println!("{:?}", e);
-        }
-
-        let res = db.group_info_cache.unwrap();
-        println!("{:?}", res);
+        db.save_successful_output(1, 1, output.clone())
+            .await
+            .unwrap();
 
-        assert_eq!(3, res.get_size().unwrap());
-        assert_eq!(output.share, res.get_secret_share().unwrap().to_owned());
-        assert_eq!(output.public.public_key(), res.get_public_key().unwrap());
+        assert_eq!(3, db.get_size().unwrap());
+        assert_eq!(output.share, db.get_secret_share().unwrap().to_owned());
+        assert_eq!(output.public.public_key(), db.get_public_key().unwrap());
         assert_eq!(
             Some(output.public.eval(0).value),
-            res.get_member(member_1).unwrap().partial_public_key
+            db.get_member(member_1).unwrap().partial_public_key
         );
         assert_eq!(
             Some(output.public.eval(1).value),
-            res.get_member(member_2).unwrap().partial_public_key
+            db.get_member(member_2).unwrap().partial_public_key
         );
         assert_eq!(
             Some(output.public.eval(2).value),
-            res.get_member(member_3).unwrap().partial_public_key
+            db.get_member(member_3).unwrap().partial_public_key
         );
 
         teardown();
@@ -596,7 +468,7 @@ pub mod sqlite_tests {
 
         let db = build_sqlite_db().await.unwrap();
 
-        let mut db = db.get_bls_tasks_client::<RandomnessTask>();
+        let mut db = db.get_bls_tasks_client::<RandomnessTask>(0);
 
         let request_id = vec![1];
 
@@ -616,13 +488,11 @@ pub mod sqlite_tests {
             assignment_block_height: 100,
         };
 
-        if let Err(e) = db.add(task.clone()).await {
-            println!("{:?}", e);
-        }
+        db.add(task.clone()).await.unwrap();
 
-        assert_eq!(true, db.contains(&request_id).await.unwrap());
+        assert!(db.contains(&request_id).await.unwrap());
         assert_eq!(task, db.get(&request_id).await.unwrap());
-        assert_eq!(false, db.is_handled(&request_id).await.unwrap());
+        assert!(!db.is_handled(&request_id).await.unwrap());
 
         let available_tasks = db
             .check_and_get_available_tasks(100, 1, randomness_task_exclusive_window)
@@ -640,9 +510,9 @@ pub mod sqlite_tests {
         assert_eq!(2, available_tasks[0].group_index);
         assert_eq!(100, available_tasks[0].assignment_block_height);
 
-        assert_eq!(true, db.contains(&request_id).await.unwrap());
+        assert!(db.contains(&request_id).await.unwrap());
         assert_eq!(task, db.get(&request_id).await.unwrap());
-        assert_eq!(true, db.is_handled(&request_id).await.unwrap());
+        assert!(db.is_handled(&request_id).await.unwrap());
 
         let available_tasks = db
             .check_and_get_available_tasks(100, 2, randomness_task_exclusive_window)
@@ -661,7 +531,7 @@ pub mod sqlite_tests {
 
         let db = build_sqlite_db().await.unwrap();
 
-        let mut db = db.get_bls_tasks_client::<RandomnessTask>();
+        let mut db = db.get_bls_tasks_client::<RandomnessTask>(0);
 
         let request_id = vec![1];
 
@@ -681,13 +551,11 @@ pub mod sqlite_tests {
             assignment_block_height: 100,
         };
 
-        if let Err(e) = db.add(task.clone()).await {
-            println!("{:?}", e);
-        }
+        db.add(task.clone()).await.unwrap();
 
-        assert_eq!(true, db.contains(&request_id).await.unwrap());
+        assert!(db.contains(&request_id).await.unwrap());
         assert_eq!(task, db.get(&request_id).await.unwrap());
-        assert_eq!(false, db.is_handled(&request_id).await.unwrap());
+        assert!(!db.is_handled(&request_id).await.unwrap());
 
         let available_tasks = db
             .check_and_get_available_tasks(
@@ -713,9 +581,9 @@ pub mod sqlite_tests {
         assert_eq!(2, available_tasks[0].group_index);
         assert_eq!(100, available_tasks[0].assignment_block_height);
 
-        assert_eq!(true, db.contains(&request_id).await.unwrap());
+        assert!(db.contains(&request_id).await.unwrap());
         assert_eq!(task, db.get(&request_id).await.unwrap());
-        assert_eq!(true, db.is_handled(&request_id).await.unwrap());
+        assert!(db.is_handled(&request_id).await.unwrap());
 
         let available_tasks = db
             .check_and_get_available_tasks(
@@ -729,4 +597,460 @@ pub mod sqlite_tests {
 
         teardown();
     }
+
+    #[tokio::test]
+    async fn test_hold_randomness_task_until_confirmed_and_remove() {
+        setup();
+
+        let randomness_task_exclusive_window = 10;
+
+        let db = build_sqlite_db().await.unwrap();
+
+        let mut db = db.get_bls_tasks_client::<RandomnessTask>(0);
+
+        let request_id = vec![1];
+
+        let task = RandomnessTask {
+            request_id: request_id.clone(),
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from_big_endian(&String::from("test task").into_bytes()),
+            request_confirmations: 3,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        db.add(task.clone()).await.unwrap();
+
+        let available_tasks = db
+            .check_and_get_available_tasks(102, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+        assert!(!db.is_handled(&request_id).await.unwrap());
+        assert_eq!(vec![task.clone()], db.get_unhandled().await.unwrap());
+
+        db.remove(&request_id).await.unwrap();
+        assert!(!db.contains(&request_id).await.unwrap());
+
+        db.add(task.clone()).await.unwrap();
+
+        let available_tasks = db
+            .check_and_get_available_tasks(103, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(1, available_tasks.len());
+        assert_eq!(task, available_tasks[0]);
+        assert!(db.get_unhandled().await.unwrap().is_empty());
+
+        // a released task is handed out again
+        db.release(&request_id).await.unwrap();
+        assert!(!db.is_handled(&request_id).await.unwrap());
+
+        let available_tasks = db
+            .check_and_get_available_tasks(104, 2, randomness_task_exclusive_window)
+            .await
+            .unwrap();
+        assert_eq!(vec![task.clone()], available_tasks);
+
+        // handed out or not, the tasks are looked up by their assignment block
+        assert_eq!(vec![task], db.get_assigned_from(100).await.unwrap());
+        assert!(db.get_assigned_from(101).await.unwrap().is_empty());
+
+        teardown();
+    }
+
+    #[tokio::test]
+    async fn test_save_block_checkpoint() {
+        setup();
+
+        let db = build_sqlite_db().await.unwrap();
+
+        let mut main_checkpoint = db.get_block_checkpoint_client(0);
+
+        let mut relayed_checkpoint = db.get_block_checkpoint_client(10);
+
+        assert_eq!(
+            None,
+            main_checkpoint
+                .get_last_processed_block_height()
+                .await
+                .unwrap()
+        );
+
+        main_checkpoint
+            .set_last_processed_block_height(100)
+            .await
+            .unwrap();
+
+        relayed_checkpoint
+            .set_last_processed_block_height(5)
+            .await
+            .unwrap();
+
+        main_checkpoint
+            .set_last_processed_block_height(200)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(200),
+            main_checkpoint
+                .get_last_processed_block_height()
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            Some(5),
+            relayed_checkpoint
+                .get_last_processed_block_height()
+                .await
+                .unwrap()
+        );
+
+        teardown();
+    }
+
+    #[tokio::test]
+    async fn test_add_and_get_group_relay_tasks() {
+        setup();
+
+        let db = build_sqlite_db().await.unwrap();
+
+        let mut relay_tasks = db.get_group_relay_tasks_client();
+
+        let relay_task = GroupRelayTask::new(10, 5, 1, 3, 100);
+
+        relay_tasks.add(relay_task.clone()).await.unwrap();
+
+        assert_eq!(
+            relay_task,
+            relay_tasks.get(&relay_task.request_id).await.unwrap()
+        );
+
+        // the relayed group doesn't sign the relay of itself
+        let available_tasks = relay_tasks
+            .check_and_get_available_tasks(100, 1, 0)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = relay_tasks
+            .check_and_get_available_tasks(100, 2, 0)
+            .await
+            .unwrap();
+        assert_eq!(vec![relay_task.clone()], available_tasks);
+        assert!(relay_tasks
+            .is_handled(&relay_task.request_id)
+            .await
+            .unwrap());
+
+        let mut main_confirmation_tasks = db.get_group_relay_confirmation_tasks_client(0);
+
+        let mut relayed_confirmation_tasks = db.get_group_relay_confirmation_tasks_client(10);
+
+        let confirmation_task = GroupRelayConfirmationTask::new(0, 1, 3, 2, 50);
+
+        relayed_confirmation_tasks
+            .add(confirmation_task.clone())
+            .await
+            .unwrap();
+
+        assert!(!main_confirmation_tasks
+            .contains(&confirmation_task.request_id)
+            .await
+            .unwrap());
+
+        // only the relayed group confirms the relay of itself
+        let available_tasks = relayed_confirmation_tasks
+            .check_and_get_available_tasks(50, 2, 0)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = main_confirmation_tasks
+            .check_and_get_available_tasks(50, 1, 0)
+            .await
+            .unwrap();
+        assert_eq!(0, available_tasks.len());
+
+        let available_tasks = relayed_confirmation_tasks
+            .check_and_get_available_tasks(50, 1, 0)
+            .await
+            .unwrap();
+        assert_eq!(vec![confirmation_task], available_tasks);
+
+        teardown();
+    }
+
+    #[tokio::test]
+    async fn test_count_pending_randomness_results() {
+        setup();
+
+        let db = build_sqlite_db().await.unwrap();
+
+        let request_id = vec![1];
+
+        let task = RandomnessTask {
+            request_id: request_id.clone(),
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from(1),
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        db.get_bls_tasks_client::<RandomnessTask>(0)
+            .add(task.clone())
+            .await
+            .unwrap();
+
+        let mut results = db.get_randomness_result_client(0).await.unwrap();
+
+        assert_eq!(0, results.count_pending().await.unwrap());
+
+        results.add(2, task, vec![1], 3).await.unwrap();
+
+        assert_eq!(1, results.count_pending().await.unwrap());
+
+        results
+            .update_commit_result(&request_id, BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+
+        assert_eq!(0, results.count_pending().await.unwrap());
+
+        teardown();
+    }
+
+    #[tokio::test]
+    async fn test_prune_randomness_tasks_and_results() {
+        setup();
+
+        let db = build_sqlite_db().await.unwrap();
+
+        let build_task = |request_id: u8, assignment_block_height: usize| RandomnessTask {
+            request_id: vec![request_id],
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from(request_id),
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height,
+        };
+
+        let mut tasks = db.get_bls_tasks_client::<RandomnessTask>(0);
+        let mut results = db.get_randomness_result_client(0).await.unwrap();
+
+        // committed long ago
+        tasks.add(build_task(1, 100)).await.unwrap();
+        results
+            .add(2, build_task(1, 100), vec![1], 3)
+            .await
+            .unwrap();
+        results
+            .update_commit_result(&[1], BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+        // not committed yet
+        tasks.add(build_task(2, 100)).await.unwrap();
+        results
+            .add(2, build_task(2, 100), vec![2], 3)
+            .await
+            .unwrap();
+        // of another group, without a result
+        tasks.add(build_task(3, 100)).await.unwrap();
+        // committed recently
+        tasks.add(build_task(4, 950)).await.unwrap();
+        results
+            .add(2, build_task(4, 950), vec![4], 3)
+            .await
+            .unwrap();
+        results
+            .update_commit_result(&[4], BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+
+        // keeping everything forever
+        let retention = DataRetentionDescriptor {
+            pruning_interval_millis: 1000,
+            committed: RetentionPolicyDescriptor::default(),
+            committed_by_others: RetentionPolicyDescriptor::default(),
+            expired: RetentionPolicyDescriptor::default(),
+            faulty: RetentionPolicyDescriptor::default(),
+            tasks: RetentionPolicyDescriptor::default(),
+        };
+        assert_eq!(0, db.prune(0, 1000, &retention).await.unwrap());
+
+        let policy = RetentionPolicyDescriptor {
+            max_age_secs: None,
+            max_block_distance: Some(500),
+        };
+        let retention = DataRetentionDescriptor {
+            committed: policy,
+            committed_by_others: policy,
+            expired: policy,
+            faulty: policy,
+            tasks: policy,
+            ..retention
+        };
+        assert_eq!(3, db.prune(0, 1000, &retention).await.unwrap());
+
+        assert!(!tasks.contains(&[1]).await.unwrap());
+        assert!(!results.contains(&[1]).await.unwrap());
+        assert!(tasks.contains(&[2]).await.unwrap());
+        assert!(results.contains(&[2]).await.unwrap());
+        assert!(!tasks.contains(&[3]).await.unwrap());
+        assert!(tasks.contains(&[4]).await.unwrap());
+        assert!(results.contains(&[4]).await.unwrap());
+
+        let table_sizes = db.get_table_sizes().await.unwrap();
+        assert!(table_sizes.contains(&("randomness_task", 2)));
+        assert!(table_sizes.contains(&("randomness_result", 2)));
+        assert!(table_sizes.contains(&("group_relay_task", 0)));
+
+        teardown();
+    }
+
+    #[tokio::test]
+    async fn test_keep_randomness_tasks_of_chains_apart() {
+        setup();
+
+        let db = build_sqlite_db().await.unwrap();
+
+        let task = RandomnessTask {
+            request_id: vec![1],
+            subscription_id: 0,
+            group_index: 2,
+            request_type: RandomnessRequestType::Randomness,
+            params: vec![],
+            requester: PLACEHOLDER_ADDRESS,
+            seed: U256::from(1),
+            request_confirmations: 0,
+            callback_gas_limit: 0,
+            callback_max_gas_price: 0.into(),
+            assignment_block_height: 100,
+        };
+
+        // any relayed chain, without a table of its own
+        let relayed_chain_id = 12345;
+
+        let mut main_tasks = db.get_bls_tasks_client::<RandomnessTask>(0);
+        let mut relayed_tasks = db.get_bls_tasks_client::<RandomnessTask>(relayed_chain_id);
+
+        main_tasks.add(task.clone()).await.unwrap();
+        assert!(!relayed_tasks.contains(&[1]).await.unwrap());
+
+        // the same request id on another chain
+        relayed_tasks.add(task.clone()).await.unwrap();
+
+        let mut main_results = db.get_randomness_result_client(0).await.unwrap();
+        let mut relayed_results = db
+            .get_randomness_result_client(relayed_chain_id)
+            .await
+            .unwrap();
+
+        relayed_results
+            .add(2, task.clone(), vec![1], 3)
+            .await
+            .unwrap();
+        assert!(!main_results.contains(&[1]).await.unwrap());
+
+        main_results.add(2, task, vec![1], 3).await.unwrap();
+        relayed_results
+            .update_commit_result(&[1], BLSResultCacheState::Committed)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            BLSResultCacheState::NotCommitted,
+            main_results.get(&[1]).await.unwrap().state
+        );
+        assert_eq!(
+            BLSResultCacheState::Committed,
+            relayed_results.get(&[1]).await.unwrap().state
+        );
+
+        relayed_tasks.remove(&[1]).await.unwrap();
+        assert!(main_tasks.contains(&[1]).await.unwrap());
+        assert!(!relayed_tasks.contains(&[1]).await.unwrap());
+
+        teardown();
+    }
+
+    #[tokio::test]
+    async fn test_fold_legacy_chain_randomness_tables() {
+        setup();
+
+        let db = build_sqlite_db().await.unwrap();
+
+        // back to the tables of every L2 chain
+        Migrator::down(db.get_connection(), Some(1)).await.unwrap();
+
+        for sql in [
+            "insert into op_randomness_task (request_id, subscription_id, group_index, request_type, params, requester, seed, request_confirmations, callback_gas_limit, callback_max_gas_price, assignment_block_height, state, create_at, update_at) \
+                values (X'01', 0, 2, 0, X'', '0x0000000000000000000000000000000000000001', X'01', 0, 0, X'00', 100, 1, '2024-01-01 00:00:00', '2024-01-01 00:00:00')",
+            "insert into op_randomness_result (request_id, group_index, message, threshold, partial_signatures, committed_times, state, create_at, update_at) \
+                values (X'01', 2, X'01', 3, '{}', 1, 2, '2024-01-01 00:00:00', '2024-01-01 00:00:00')",
+        ] {
+            db.get_connection().execute_unprepared(sql).await.unwrap();
+        }
+
+        // the rows can't be told apart without the chain they were kept for
+        assert!(crate::build(DB_PATH, CIPHER_KEY.as_bytes(), &[8453])
+            .await
+            .is_err());
+        assert!(
+            crate::build(DB_PATH, CIPHER_KEY.as_bytes(), &[10, 11155420])
+                .await
+                .is_err()
+        );
+
+        let db = crate::build(DB_PATH, CIPHER_KEY.as_bytes(), &[8453, 11155420])
+            .await
+            .unwrap();
+
+        // the op sepolia testnet the node relays
+        let tasks = db.get_bls_tasks_client::<RandomnessTask>(11155420);
+        assert!(tasks.is_handled(&[1]).await.unwrap());
+        assert!(!db
+            .get_bls_tasks_client::<RandomnessTask>(0)
+            .contains(&[1])
+            .await
+            .unwrap());
+
+        let results = db.get_randomness_result_client(11155420).await.unwrap();
+        let result = results.get(&[1]).await.unwrap();
+        assert_eq!(BLSResultCacheState::Committed, result.state);
+        assert_eq!(1, result.result_cache.committed_times);
+
+        let legacy_tables = db
+            .get_connection()
+            .query_one(Statement::from_string(
+                DatabaseBackend::Sqlite,
+                "select count(*) as row_count from sqlite_master where type = 'table' and name like 'op_%'"
+                    .to_owned(),
+            ))
+            .await
+            .unwrap()
+            .unwrap()
+            .try_get::<i64>("", "row_count")
+            .unwrap();
+        assert_eq!(0, legacy_tables);
+
+        teardown();
+    }
 }
@@ -1,13 +0,0 @@
-mod base;
-mod loot;
-mod main;
-mod op;
-mod redstone;
-mod taiko;
-
-pub use base::BaseSignatureResultDBClient;
-pub use loot::LootSignatureResultDBClient;
-pub use main::SignatureResultDBClient;
-pub use op::OPSignatureResultDBClient;
-pub use redstone::RedstoneSignatureResultDBClient;
-pub use taiko::TaikoSignatureResultDBClient;
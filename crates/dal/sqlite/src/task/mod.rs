@@ -1,13 +0,0 @@
-mod base;
-mod loot;
-mod main;
-mod op;
-mod redstone;
-mod taiko;
-
-pub use base::BaseBLSTasksDBClient;
-pub use loot::LootBLSTasksDBClient;
-pub use main::BLSTasksDBClient;
-pub use op::OPBLSTasksDBClient;
-pub use redstone::RedstoneBLSTasksDBClient;
-pub use taiko::TaikoBLSTasksDBClient;
@@ -1,24 +1,25 @@
 use crate::error::{DataAccessResult, GroupError, NodeInfoError};
 use crate::{
-    BLSResultCacheState, BLSTasksHandler, BlockInfoHandler, ContextInfoUpdater, GroupInfoHandler,
-    NodeInfoHandler, SignatureResultCacheHandler,
+    BLSResultCacheState, BLSTasksHandler, BlockCheckpointHandler, BlockInfoHandler,
+    ContextInfoUpdater, GroupInfoHandler, NodeInfoHandler, SignatureResultCacheHandler,
 };
 
 use super::{
-    BLSTasksFetcher, BLSTasksUpdater, BlockInfoFetcher, BlockInfoUpdater, GroupInfoFetcher,
-    GroupInfoUpdater, NodeInfoFetcher, NodeInfoUpdater, ResultCache, SignatureResultCacheFetcher,
-    SignatureResultCacheUpdater,
+    BLSTasksFetcher, BLSTasksUpdater, BlockCheckpointFetcher, BlockCheckpointUpdater,
+    BlockInfoFetcher, BlockInfoUpdater, GroupInfoFetcher, GroupInfoUpdater, NodeInfoFetcher,
+    NodeInfoUpdater, ResultCache, SignatureResultCacheFetcher, SignatureResultCacheUpdater,
 };
 use arpa_core::log::encoder;
 use arpa_core::{
-    BLSTask, BLSTaskError, DKGStatus, DKGTask, Group, Member, PartialSignature, RandomnessTask,
-    Task,
+    BLSTask, BLSTaskError, DKGStatus, DKGTask, Group, GroupRelayConfirmationTask, GroupRelayTask,
+    Member, PartialSignature, RandomnessTask, Task, BLOCK_HASH_HISTORY_LENGTH,
 };
 use async_trait::async_trait;
 use dkg_core::primitives::DKGOutput;
-use ethers_core::types::Address;
+use ethers_core::types::{Address, H256};
 use log::info;
 use std::collections::{BTreeMap, HashMap};
+use std::time::Instant;
 use threshold_bls::group::{Curve, Element};
 use threshold_bls::serialize::point_to_hex;
 use threshold_bls::sig::Share;
@@ -28,6 +29,8 @@ pub struct InMemoryBlockInfoCache {
     chain_id: usize,
     block_height: usize,
     block_time: usize,
+    block_updated_at: Option<Instant>,
+    block_hashes: BTreeMap<usize, H256>,
 }
 
 impl InMemoryBlockInfoCache {
@@ -36,6 +39,8 @@ impl InMemoryBlockInfoCache {
             chain_id,
             block_height: 0,
             block_time,
+            block_updated_at: None,
+            block_hashes: BTreeMap::new(),
         }
     }
 }
@@ -54,11 +59,61 @@ impl BlockInfoFetcher for InMemoryBlockInfoCache {
     fn get_block_time(&self) -> usize {
         self.block_time
     }
+
+    fn get_block_updated_at(&self) -> Option<Instant> {
+        self.block_updated_at
+    }
+
+    fn get_block_hash(&self, block_height: usize) -> Option<H256> {
+        self.block_hashes.get(&block_height).copied()
+    }
 }
 
 impl BlockInfoUpdater for InMemoryBlockInfoCache {
     fn set_block_height(&mut self, block_height: usize) {
         self.block_height = block_height;
+        self.block_updated_at = Some(Instant::now());
+    }
+
+    fn set_block_hash(&mut self, block_height: usize, block_hash: H256) {
+        self.block_hashes.split_off(&(block_height + 1));
+        self.block_hashes.insert(block_height, block_hash);
+        self.block_hashes = self
+            .block_hashes
+            .split_off(&block_height.saturating_sub(BLOCK_HASH_HISTORY_LENGTH - 1));
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryBlockCheckpointCache {
+    last_processed_block_height: Option<usize>,
+}
+
+impl InMemoryBlockCheckpointCache {
+    pub fn new() -> Self {
+        InMemoryBlockCheckpointCache {
+            last_processed_block_height: None,
+        }
+    }
+}
+
+impl BlockCheckpointHandler for InMemoryBlockCheckpointCache {}
+
+#[async_trait]
+impl BlockCheckpointFetcher for InMemoryBlockCheckpointCache {
+    async fn get_last_processed_block_height(&self) -> DataAccessResult<Option<usize>> {
+        Ok(self.last_processed_block_height)
+    }
+}
+
+#[async_trait]
+impl BlockCheckpointUpdater for InMemoryBlockCheckpointCache {
+    async fn set_last_processed_block_height(
+        &mut self,
+        block_height: usize,
+    ) -> DataAccessResult<()> {
+        self.last_processed_block_height = Some(block_height);
+        Ok(())
     }
 }
 
@@ -168,6 +223,7 @@ pub struct InMemoryGroupInfoCache<C: Curve> {
     pub(crate) self_id_address: Address,
     pub(crate) self_index: usize,
     pub(crate) dkg_start_block_height: usize,
+    pub(crate) dkg_checkpoint: Option<Vec<u8>>,
 }
 
 impl<C: Curve> std::fmt::Debug for InMemoryGroupInfoCache<C> {
@@ -178,6 +234,7 @@ impl<C: Curve> std::fmt::Debug for InMemoryGroupInfoCache<C> {
             .field("dkg_status", &self.dkg_status)
             .field("self_index", &self.self_index)
             .field("dkg_start_block_height", &self.dkg_start_block_height)
+            .field("dkg_checkpoint", &"ignored")
             .finish()
     }
 }
@@ -193,6 +250,7 @@ impl<C: Curve> InMemoryGroupInfoCache<C> {
             self_id_address: id_address,
             self_index: 0,
             dkg_start_block_height: 0,
+            dkg_checkpoint: None,
         }
     }
 
@@ -203,6 +261,7 @@ impl<C: Curve> InMemoryGroupInfoCache<C> {
         dkg_status: DKGStatus,
         self_index: usize,
         dkg_start_block_height: usize,
+        dkg_checkpoint: Option<Vec<u8>>,
     ) -> Self {
         InMemoryGroupInfoCache {
             share,
@@ -211,6 +270,7 @@ impl<C: Curve> InMemoryGroupInfoCache<C> {
             self_id_address: id_address,
             self_index,
             dkg_start_block_height,
+            dkg_checkpoint,
         }
     }
 
@@ -263,6 +323,31 @@ impl<C: Curve> GroupInfoUpdater<C> for InMemoryGroupInfoCache<C> {
         Ok(true)
     }
 
+    async fn save_dkg_checkpoint(
+        &mut self,
+        index: usize,
+        epoch: usize,
+        dkg_checkpoint: Vec<u8>,
+    ) -> DataAccessResult<()> {
+        self.only_has_group_task()?;
+
+        if self.group.index != index {
+            return Err(GroupError::GroupIndexObsolete(self.group.index).into());
+        }
+
+        if self.group.epoch != epoch {
+            return Err(GroupError::GroupEpochObsolete(self.group.epoch).into());
+        }
+
+        if self.group.state {
+            return Err(GroupError::GroupAlreadyReady.into());
+        }
+
+        self.dkg_checkpoint = Some(dkg_checkpoint);
+
+        Ok(())
+    }
+
     async fn save_task_info(&mut self, self_index: usize, task: DKGTask) -> DataAccessResult<()> {
         self.self_index = self_index;
 
@@ -284,6 +369,8 @@ impl<C: Curve> GroupInfoUpdater<C> for InMemoryGroupInfoCache<C> {
 
         self.dkg_start_block_height = task.assignment_block_height;
 
+        self.dkg_checkpoint = None;
+
         task.members
             .iter()
             .enumerate()
@@ -351,6 +438,7 @@ impl<C: Curve> GroupInfoUpdater<C> for InMemoryGroupInfoCache<C> {
             .members
             .retain(|node, _| !disqualified_nodes.contains(node));
         self.group.public_key = Some(public_key.clone());
+        self.dkg_checkpoint = None;
 
         for (_, member) in self.group.members.iter_mut() {
             if let Some(node) = output
@@ -412,6 +500,8 @@ impl<C: Curve> GroupInfoUpdater<C> for InMemoryGroupInfoCache<C> {
 
         self.group.size = self.group.members.len();
 
+        self.dkg_checkpoint = None;
+
         self.refresh_context_entry();
 
         Ok(disqualified_nodes)
@@ -593,6 +683,12 @@ impl<C: Curve> GroupInfoFetcher<C> for InMemoryGroupInfoCache<C> {
         Ok(self.dkg_status)
     }
 
+    fn get_dkg_checkpoint(&self) -> DataAccessResult<Option<&[u8]>> {
+        self.only_has_group_task()?;
+
+        Ok(self.dkg_checkpoint.as_deref())
+    }
+
     fn is_committer(&self, id_address: Address) -> DataAccessResult<bool> {
         self.only_has_group_task()?;
 
@@ -633,6 +729,24 @@ impl<T: Task + Sync + Clone> BLSTasksFetcher<T> for InMemoryBLSTasksQueue<T> {
             .map(|task| &task.state)
             .unwrap_or(&false))
     }
+
+    async fn get_unhandled(&self) -> DataAccessResult<Vec<T>> {
+        Ok(self
+            .bls_tasks
+            .values()
+            .filter(|task| !task.state)
+            .map(|task| task.task.clone())
+            .collect())
+    }
+
+    async fn get_assigned_from(&self, block_height: usize) -> DataAccessResult<Vec<T>> {
+        Ok(self
+            .bls_tasks
+            .values()
+            .filter(|task| task.task.assignment_block_height() >= block_height)
+            .map(|task| task.task.clone())
+            .collect())
+    }
 }
 
 #[async_trait]
@@ -644,6 +758,20 @@ impl BLSTasksUpdater<RandomnessTask> for InMemoryBLSTasksQueue<RandomnessTask> {
         Ok(())
     }
 
+    async fn remove(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        self.bls_tasks.remove(task_request_id);
+
+        Ok(())
+    }
+
+    async fn release(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        if let Some(task) = self.bls_tasks.get_mut(task_request_id) {
+            task.state = false;
+        }
+
+        Ok(())
+    }
+
     async fn check_and_get_available_tasks(
         &mut self,
         current_block_height: usize,
@@ -654,6 +782,11 @@ impl BLSTasksUpdater<RandomnessTask> for InMemoryBLSTasksQueue<RandomnessTask> {
             .bls_tasks
             .iter_mut()
             .filter(|(_, task)| !task.state)
+            // hold the task until the requested confirmations are reached
+            .filter(|(_, task)| {
+                current_block_height
+                    >= task.task.assignment_block_height + task.task.request_confirmations as usize
+            })
             .filter(|(_, task)| {
                 task.task.group_index == current_group_index as u32
                     || current_block_height
@@ -669,6 +802,98 @@ impl BLSTasksUpdater<RandomnessTask> for InMemoryBLSTasksQueue<RandomnessTask> {
     }
 }
 
+#[async_trait]
+impl BLSTasksUpdater<GroupRelayTask> for InMemoryBLSTasksQueue<GroupRelayTask> {
+    async fn add(&mut self, task: GroupRelayTask) -> DataAccessResult<()> {
+        self.bls_tasks
+            .insert(task.request_id().to_vec(), BLSTask { task, state: false });
+
+        Ok(())
+    }
+
+    async fn remove(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        self.bls_tasks.remove(task_request_id);
+
+        Ok(())
+    }
+
+    async fn release(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        if let Some(task) = self.bls_tasks.get_mut(task_request_id) {
+            task.state = false;
+        }
+
+        Ok(())
+    }
+
+    async fn check_and_get_available_tasks(
+        &mut self,
+        _current_block_height: usize,
+        current_group_index: usize,
+        _randomness_task_exclusive_window: usize,
+    ) -> DataAccessResult<Vec<GroupRelayTask>> {
+        // a group can't vouch for itself, any other group is qualified to relay it
+        let available_tasks = self
+            .bls_tasks
+            .iter_mut()
+            .filter(|(_, task)| !task.state)
+            .filter(|(_, task)| task.task.relayed_group_index != current_group_index)
+            .map(|(_, task)| {
+                task.state = true;
+                task.task.clone()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(available_tasks)
+    }
+}
+
+#[async_trait]
+impl BLSTasksUpdater<GroupRelayConfirmationTask>
+    for InMemoryBLSTasksQueue<GroupRelayConfirmationTask>
+{
+    async fn add(&mut self, task: GroupRelayConfirmationTask) -> DataAccessResult<()> {
+        self.bls_tasks
+            .insert(task.request_id().to_vec(), BLSTask { task, state: false });
+
+        Ok(())
+    }
+
+    async fn remove(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        self.bls_tasks.remove(task_request_id);
+
+        Ok(())
+    }
+
+    async fn release(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        if let Some(task) = self.bls_tasks.get_mut(task_request_id) {
+            task.state = false;
+        }
+
+        Ok(())
+    }
+
+    async fn check_and_get_available_tasks(
+        &mut self,
+        _current_block_height: usize,
+        current_group_index: usize,
+        _randomness_task_exclusive_window: usize,
+    ) -> DataAccessResult<Vec<GroupRelayConfirmationTask>> {
+        // only the relayed group itself can confirm the relay
+        let available_tasks = self
+            .bls_tasks
+            .iter_mut()
+            .filter(|(_, task)| !task.state)
+            .filter(|(_, task)| task.task.relayed_group_index == current_group_index)
+            .map(|(_, task)| {
+                task.state = true;
+                task.task.clone()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(available_tasks)
+    }
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct InMemorySignatureResultCache<C: ResultCache> {
     signature_result_caches: BTreeMap<Vec<u8>, BLSResultCache<C>>,
@@ -698,6 +923,10 @@ impl Task for RandomnessResultCache {
     fn request_id(&self) -> &[u8] {
         &self.randomness_task.request_id
     }
+
+    fn assignment_block_height(&self) -> usize {
+        self.randomness_task.assignment_block_height
+    }
 }
 
 impl ResultCache for RandomnessResultCache {
@@ -705,6 +934,36 @@ impl ResultCache for RandomnessResultCache {
     type M = Vec<u8>;
 }
 
+impl Task for GroupRelayResultCache {
+    fn request_id(&self) -> &[u8] {
+        &self.group_relay_task.request_id
+    }
+
+    fn assignment_block_height(&self) -> usize {
+        self.group_relay_task.assignment_block_height
+    }
+}
+
+impl ResultCache for GroupRelayResultCache {
+    type Task = GroupRelayTask;
+    type M = Vec<u8>;
+}
+
+impl Task for GroupRelayConfirmationResultCache {
+    fn request_id(&self) -> &[u8] {
+        &self.group_relay_confirmation_task.request_id
+    }
+
+    fn assignment_block_height(&self) -> usize {
+        self.group_relay_confirmation_task.assignment_block_height
+    }
+}
+
+impl ResultCache for GroupRelayConfirmationResultCache {
+    type Task = GroupRelayConfirmationTask;
+    type M = Vec<u8>;
+}
+
 #[derive(Debug, Clone)]
 pub struct BLSResultCache<C: ResultCache> {
     pub result_cache: C,
@@ -721,6 +980,26 @@ pub struct RandomnessResultCache {
     pub committed_times: usize,
 }
 
+#[derive(Clone, Debug)]
+pub struct GroupRelayResultCache {
+    pub group_index: usize,
+    pub group_relay_task: GroupRelayTask,
+    pub message: Vec<u8>,
+    pub threshold: usize,
+    pub partial_signatures: BTreeMap<Address, PartialSignature>,
+    pub committed_times: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct GroupRelayConfirmationResultCache {
+    pub group_index: usize,
+    pub group_relay_confirmation_task: GroupRelayConfirmationTask,
+    pub message: Vec<u8>,
+    pub threshold: usize,
+    pub partial_signatures: BTreeMap<Address, PartialSignature>,
+    pub committed_times: usize,
+}
+
 #[async_trait]
 impl<C: ResultCache + Send + Sync> SignatureResultCacheFetcher<C>
     for InMemorySignatureResultCache<C>
@@ -735,6 +1014,17 @@ impl<C: ResultCache + Send + Sync> SignatureResultCacheFetcher<C>
             .cloned()
             .ok_or_else(|| BLSTaskError::CommitterCacheNotExisted.into())
     }
+
+    async fn count_pending(&self) -> DataAccessResult<usize> {
+        Ok(self
+            .signature_result_caches
+            .values()
+            .filter(|v| {
+                v.state == BLSResultCacheState::NotCommitted
+                    || v.state == BLSResultCacheState::Committing
+            })
+            .count())
+    }
 }
 
 #[async_trait]
@@ -858,10 +1148,263 @@ impl SignatureResultCacheUpdater<RandomnessResultCache>
     }
 }
 
+#[async_trait]
+impl SignatureResultCacheUpdater<GroupRelayResultCache>
+    for InMemorySignatureResultCache<GroupRelayResultCache>
+{
+    async fn add(
+        &mut self,
+        group_index: usize,
+        task: GroupRelayTask,
+        message: Vec<u8>,
+        threshold: usize,
+    ) -> DataAccessResult<bool> {
+        let signature_result_cache = GroupRelayResultCache {
+            group_index,
+            group_relay_task: task,
+            message,
+            threshold,
+            partial_signatures: BTreeMap::new(),
+            committed_times: 0,
+        };
+
+        if self
+            .signature_result_caches
+            .contains_key(&signature_result_cache.group_relay_task.request_id)
+        {
+            return Ok(false);
+        }
+
+        self.signature_result_caches.insert(
+            signature_result_cache.group_relay_task.request_id.clone(),
+            BLSResultCache {
+                result_cache: signature_result_cache,
+                state: BLSResultCacheState::NotCommitted,
+            },
+        );
+
+        Ok(true)
+    }
+
+    async fn add_partial_signature(
+        &mut self,
+        task_request_id: Vec<u8>,
+        member_address: Address,
+        member_index: usize,
+        signed_partial_signature: Vec<u8>,
+    ) -> DataAccessResult<bool> {
+        let signature_result_cache = self
+            .signature_result_caches
+            .get_mut(&task_request_id)
+            .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        if signature_result_cache
+            .result_cache
+            .partial_signatures
+            .contains_key(&member_address)
+        {
+            return Ok(false);
+        }
+
+        let partial_signature = PartialSignature {
+            index: member_index,
+            signed_partial_signature,
+        };
+
+        signature_result_cache
+            .result_cache
+            .partial_signatures
+            .insert(member_address, partial_signature);
+
+        Ok(true)
+    }
+
+    async fn get_ready_to_commit_signatures(
+        &mut self,
+        _current_block_height: usize,
+    ) -> DataAccessResult<Vec<GroupRelayResultCache>> {
+        let ready_to_commit_signatures = self
+            .signature_result_caches
+            .values_mut()
+            .filter(|v| {
+                v.state == BLSResultCacheState::NotCommitted
+                    && v.result_cache.partial_signatures.len() >= v.result_cache.threshold
+            })
+            .map(|v| {
+                v.state = BLSResultCacheState::Committing;
+                v.result_cache.clone()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(ready_to_commit_signatures)
+    }
+
+    async fn update_commit_result(
+        &mut self,
+        task_request_id: &[u8],
+        status: BLSResultCacheState,
+    ) -> DataAccessResult<()> {
+        let signature_result_cache = self
+            .signature_result_caches
+            .get_mut(task_request_id)
+            .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        signature_result_cache.state = status;
+
+        Ok(())
+    }
+
+    async fn incr_committed_times(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        let signature_result_cache = self
+            .signature_result_caches
+            .get_mut(task_request_id)
+            .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        signature_result_cache.result_cache.committed_times += 1;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SignatureResultCacheUpdater<GroupRelayConfirmationResultCache>
+    for InMemorySignatureResultCache<GroupRelayConfirmationResultCache>
+{
+    async fn add(
+        &mut self,
+        group_index: usize,
+        task: GroupRelayConfirmationTask,
+        message: Vec<u8>,
+        threshold: usize,
+    ) -> DataAccessResult<bool> {
+        let signature_result_cache = GroupRelayConfirmationResultCache {
+            group_index,
+            group_relay_confirmation_task: task,
+            message,
+            threshold,
+            partial_signatures: BTreeMap::new(),
+            committed_times: 0,
+        };
+
+        if self.signature_result_caches.contains_key(
+            &signature_result_cache
+                .group_relay_confirmation_task
+                .request_id,
+        ) {
+            return Ok(false);
+        }
+
+        self.signature_result_caches.insert(
+            signature_result_cache
+                .group_relay_confirmation_task
+                .request_id
+                .clone(),
+            BLSResultCache {
+                result_cache: signature_result_cache,
+                state: BLSResultCacheState::NotCommitted,
+            },
+        );
+
+        Ok(true)
+    }
+
+    async fn add_partial_signature(
+        &mut self,
+        task_request_id: Vec<u8>,
+        member_address: Address,
+        member_index: usize,
+        signed_partial_signature: Vec<u8>,
+    ) -> DataAccessResult<bool> {
+        let signature_result_cache = self
+            .signature_result_caches
+            .get_mut(&task_request_id)
+            .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        if signature_result_cache
+            .result_cache
+            .partial_signatures
+            .contains_key(&member_address)
+        {
+            return Ok(false);
+        }
+
+        let partial_signature = PartialSignature {
+            index: member_index,
+            signed_partial_signature,
+        };
+
+        signature_result_cache
+            .result_cache
+            .partial_signatures
+            .insert(member_address, partial_signature);
+
+        Ok(true)
+    }
+
+    async fn get_ready_to_commit_signatures(
+        &mut self,
+        _current_block_height: usize,
+    ) -> DataAccessResult<Vec<GroupRelayConfirmationResultCache>> {
+        let ready_to_commit_signatures = self
+            .signature_result_caches
+            .values_mut()
+            .filter(|v| {
+                v.state == BLSResultCacheState::NotCommitted
+                    && v.result_cache.partial_signatures.len() >= v.result_cache.threshold
+            })
+            .map(|v| {
+                v.state = BLSResultCacheState::Committing;
+                v.result_cache.clone()
+            })
+            .collect::<Vec<_>>();
+
+        Ok(ready_to_commit_signatures)
+    }
+
+    async fn update_commit_result(
+        &mut self,
+        task_request_id: &[u8],
+        status: BLSResultCacheState,
+    ) -> DataAccessResult<()> {
+        let signature_result_cache = self
+            .signature_result_caches
+            .get_mut(task_request_id)
+            .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        signature_result_cache.state = status;
+
+        Ok(())
+    }
+
+    async fn incr_committed_times(&mut self, task_request_id: &[u8]) -> DataAccessResult<()> {
+        let signature_result_cache = self
+            .signature_result_caches
+            .get_mut(task_request_id)
+            .ok_or(BLSTaskError::CommitterCacheNotExisted)?;
+
+        signature_result_cache.result_cache.committed_times += 1;
+
+        Ok(())
+    }
+}
+
 impl<PC: Curve + 'static> NodeInfoHandler<PC> for InMemoryNodeInfoCache<PC> {}
 impl<PC: Curve + 'static> GroupInfoHandler<PC> for InMemoryGroupInfoCache<PC> {}
 impl BLSTasksHandler<RandomnessTask> for InMemoryBLSTasksQueue<RandomnessTask> {}
+impl BLSTasksHandler<GroupRelayTask> for InMemoryBLSTasksQueue<GroupRelayTask> {}
+impl BLSTasksHandler<GroupRelayConfirmationTask>
+    for InMemoryBLSTasksQueue<GroupRelayConfirmationTask>
+{
+}
 impl SignatureResultCacheHandler<RandomnessResultCache>
     for InMemorySignatureResultCache<RandomnessResultCache>
 {
 }
+impl SignatureResultCacheHandler<GroupRelayResultCache>
+    for InMemorySignatureResultCache<GroupRelayResultCache>
+{
+}
+impl SignatureResultCacheHandler<GroupRelayConfirmationResultCache>
+    for InMemorySignatureResultCache<GroupRelayConfirmationResultCache>
+{
+}
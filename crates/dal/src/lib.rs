@@ -6,9 +6,10 @@ use async_trait::async_trait;
 use cache::BLSResultCache;
 pub use dkg_core::primitives::DKGOutput;
 use error::DataAccessResult;
-use ethers_core::types::Address;
+use ethers_core::types::{Address, H256};
 use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::time::Instant;
 use threshold_bls::{group::Curve, sig::Share};
 
 pub trait BlockInfoHandler:
@@ -16,6 +17,24 @@ pub trait BlockInfoHandler:
 {
 }
 
+#[async_trait]
+pub trait BlockCheckpointHandler:
+    BlockCheckpointFetcher + BlockCheckpointUpdater + std::fmt::Debug + Sync + Send
+{
+    /// Moves the checkpoint forward to the given block, an earlier block leaves it untouched.
+    async fn advance_last_processed_block_height(
+        &mut self,
+        block_height: usize,
+    ) -> DataAccessResult<()> {
+        match self.get_last_processed_block_height().await? {
+            Some(last_processed_block_height) if last_processed_block_height >= block_height => {
+                Ok(())
+            }
+            _ => self.set_last_processed_block_height(block_height).await,
+        }
+    }
+}
+
 pub trait NodeInfoHandler<PC: Curve>:
     NodeInfoFetcher<PC> + NodeInfoUpdater<PC> + ContextInfoUpdater + std::fmt::Debug + Sync + Send
 {
@@ -40,10 +59,34 @@ pub trait BlockInfoFetcher {
     fn get_block_height(&self) -> usize;
 
     fn get_block_time(&self) -> usize;
+
+    /// The moment the latest block height was set, none if no block has been received yet.
+    fn get_block_updated_at(&self) -> Option<Instant>;
+
+    /// The hash of the block followed at the height, none if the height is not among
+    /// the latest `BLOCK_HASH_HISTORY_LENGTH` blocks.
+    fn get_block_hash(&self, block_height: usize) -> Option<H256>;
 }
 
 pub trait BlockInfoUpdater {
     fn set_block_height(&mut self, block_height: usize);
+
+    /// Records the hash of the block followed at the height, the hashes recorded above the
+    /// height belong to an orphaned branch and are dropped.
+    fn set_block_hash(&mut self, block_height: usize, block_hash: H256);
+}
+
+#[async_trait]
+pub trait BlockCheckpointFetcher {
+    async fn get_last_processed_block_height(&self) -> DataAccessResult<Option<usize>>;
+}
+
+#[async_trait]
+pub trait BlockCheckpointUpdater {
+    async fn set_last_processed_block_height(
+        &mut self,
+        block_height: usize,
+    ) -> DataAccessResult<()>;
 }
 
 pub trait ContextInfoUpdater: std::fmt::Debug {
@@ -96,6 +139,15 @@ pub trait GroupInfoUpdater<C: Curve> {
         dkg_status: DKGStatus,
     ) -> DataAccessResult<bool>;
 
+    /// Persists the state the node keeps between the phases of the running DKG,
+    /// it is cleared once the output of the DKG is saved.
+    async fn save_dkg_checkpoint(
+        &mut self,
+        index: usize,
+        epoch: usize,
+        dkg_checkpoint: Vec<u8>,
+    ) -> DataAccessResult<()>;
+
     async fn save_committers(
         &mut self,
         index: usize,
@@ -142,6 +194,8 @@ pub trait GroupInfoFetcher<C: Curve>: std::fmt::Debug {
 
     fn get_dkg_status(&self) -> DataAccessResult<DKGStatus>;
 
+    fn get_dkg_checkpoint(&self) -> DataAccessResult<Option<&[u8]>>;
+
     fn is_committer(&self, id_address: Address) -> DataAccessResult<bool>;
 }
 
@@ -152,12 +206,26 @@ pub trait BLSTasksFetcher<T: Task> {
     async fn get(&self, task_request_id: &[u8]) -> DataAccessResult<T>;
 
     async fn is_handled(&self, task_request_id: &[u8]) -> DataAccessResult<bool>;
+
+    /// Returns the tasks which are not handled yet, e.g. the ones waiting for their confirmations.
+    async fn get_unhandled(&self) -> DataAccessResult<Vec<T>>;
+
+    /// Returns the tasks assigned at or after the block whether handled or not,
+    /// e.g. the ones whose request blocks may have been replaced by a reorg.
+    async fn get_assigned_from(&self, block_height: usize) -> DataAccessResult<Vec<T>>;
 }
 
 #[async_trait]
 pub trait BLSTasksUpdater<T: Task> {
     async fn add(&mut self, task: T) -> DataAccessResult<()>;
 
+    /// Withdraws a task whatever its state, e.g. when its request is orphaned by a reorg.
+    async fn remove(&mut self, task_request_id: &[u8]) -> DataAccessResult<()>;
+
+    /// Puts a task handed out by `check_and_get_available_tasks` back, so that the next check
+    /// hands it out again, e.g. when it couldn't be confirmed on chain yet.
+    async fn release(&mut self, task_request_id: &[u8]) -> DataAccessResult<()>;
+
     async fn check_and_get_available_tasks(
         &mut self,
         current_block_height: usize,
@@ -171,6 +239,9 @@ pub trait SignatureResultCacheFetcher<T: ResultCache> {
     async fn contains(&self, task_request_id: &[u8]) -> DataAccessResult<bool>;
 
     async fn get(&self, task_request_id: &[u8]) -> DataAccessResult<BLSResultCache<T>>;
+
+    /// Counts the results which are not committed yet, including the ones being committed.
+    async fn count_pending(&self) -> DataAccessResult<usize>;
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -40,6 +40,9 @@ struct ReshareInfo<C: Curve> {
     new_index: Option<Idx>,
     // new group that is receiving the refreshed shares
     new_group: Group<C>,
+    // rpc endpoint of this node which is shared along with the dealt shares
+    #[serde(default)]
+    rpc_endpoint: String,
 }
 
 impl<C: Curve> ReshareInfo<C> {
@@ -102,6 +105,7 @@ impl<C: Curve> RDKG<C> {
             public: Some(public),
             new_index: new_idx,
             new_group,
+            rpc_endpoint: String::new(),
         };
         Ok(RDKG { info })
     }
@@ -125,15 +129,38 @@ impl<C: Curve> RDKG<C> {
             public: None,
             new_index: new_idx,
             new_group,
+            rpc_endpoint: String::new(),
         };
         Ok(RDKG { info })
     }
+
+    /// Sets the rpc endpoint of this node, which dealers encrypt together with
+    /// their shares so that share holders learn how to reach them.
+    pub fn with_rpc_endpoint(mut self, rpc_endpoint: String) -> Self {
+        self.info.rpc_endpoint = rpc_endpoint;
+        self
+    }
 }
 
 impl<C: Curve> Phase0<C> for RDKG<C> {
     type Next = RDKGWaitingShare<C>;
 
-    fn set_rpc_endpoint(&mut self) {}
+    fn set_rpc_endpoint(&mut self) {
+        let my_idx = match self.info.new_index {
+            Some(idx) => idx,
+            None => return,
+        };
+
+        if let Some(my_node) = self
+            .info
+            .new_group
+            .nodes
+            .iter_mut()
+            .find(|node| node.id() == my_idx)
+        {
+            my_node.set_rpc_endpoint(self.info.rpc_endpoint.clone());
+        }
+    }
 
     fn encrypt_shares<R, F>(
         self,
@@ -153,7 +180,7 @@ impl<C: Curve> Phase0<C> for RDKG<C> {
             info.prev_index.unwrap(),
             &secret,
             &public,
-            "",
+            &info.rpc_endpoint,
             &info.new_group,
             rng(),
         )?;
@@ -236,12 +263,31 @@ impl<C: Curve> Phase1<C> for RDKGWaitingShare<C> {
             });
 
         let mut info = self.info;
+        // dealers are indexed in the previous group, so their endpoints are
+        // attached to the new group node that holds the same key, if any
+        shares.iter().for_each(|(&dealer_idx, (_, rpc_endpoint))| {
+            let dealer_key = info
+                .prev_group
+                .nodes
+                .iter()
+                .find(|node| node.id() == dealer_idx)
+                .map(|node| node.key().clone());
+            if let Some(node) = dealer_key.and_then(|key| {
+                info.new_group
+                    .nodes
+                    .iter_mut()
+                    .find(|node| node.key() == &key)
+            }) {
+                node.set_rpc_endpoint(rpc_endpoint.to_string());
+            }
+        });
+
         if info.is_dealer() {
             let public = info.public.take().unwrap();
             let secret = info.secret.take().unwrap();
             // we register our own share and publics into the mix
             let didx = info.prev_index.unwrap();
-            shares.insert(didx, (secret.eval(didx).value, "".to_string()));
+            shares.insert(didx, (secret.eval(didx).value, info.rpc_endpoint.clone()));
             publics.insert(didx, public.clone());
             // we treat our own share as valid!
             statuses.set(didx, my_idx, Status::Success);
@@ -713,6 +759,38 @@ mod tests {
         assert_eq!(public.public_key(), reshared.public_key());
     }
 
+    #[test]
+    fn test_resharing_rpc_endpoints() {
+        // SCENARIO: share holders learn the rpc endpoints of the dealers
+        let n = 5;
+        let thr = default_threshold(n);
+        let n2 = 8;
+        let thr2 = 5;
+        let (dkgs, _) = setup_reshare::<BCurve>(n, thr, n2, thr2);
+        let dkgs = dkgs
+            .into_iter()
+            .enumerate()
+            .map(|(i, dkg)| dkg.with_rpc_endpoint(format!("node-{}", i)))
+            .collect::<Vec<_>>();
+        let (outputs, _) = full_dkg(thr2, dkgs);
+        for output in outputs.iter() {
+            for node in output
+                .qual
+                .nodes
+                .iter()
+                .filter(|node| node.id() != output.share.index)
+            {
+                // only the previous members deal, so only their endpoints are known
+                let expected = if (node.id() as usize) < n {
+                    Some(format!("node-{}", node.id()))
+                } else {
+                    None
+                };
+                assert_eq!(node.get_rpc_endpoint().cloned(), expected);
+            }
+        }
+    }
+
     #[test]
     fn test_resharing_added_members_invalid() {
         // SCENARIO: reshare with new members but give invalid shares
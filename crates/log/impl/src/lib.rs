@@ -116,12 +116,40 @@ pub fn log_function(attr: TokenStream, input: TokenStream) -> TokenStream {
         }
     };
 
-    quote! {
-        #fn_sig {
-            log_mdc::insert("fn_name", stringify!(#fn_ident));
-            #args_text
-            #stmts
-            #post_code
+    let span = quote! {
+        tracing::debug_span!(stringify!(#fn_ident), fn_name = stringify!(#fn_ident))
+    };
+
+    // The span is entered by sync functions, and instruments the futures of async functions since
+    // they can be polled on different threads.
+    if fn_async_trait {
+        quote! {
+            #fn_sig {
+                let __span = #span;
+                #args_text
+                #stmts
+                #post_code
+            }
+        }
+    } else if fn_sig.asyncness.is_some() {
+        quote! {
+            #fn_sig {
+                let __span = #span;
+                #args_text
+                tracing::Instrument::instrument(async move {
+                    #stmts
+                    #post_code
+                }, __span).await
+            }
+        }
+    } else {
+        quote! {
+            #fn_sig {
+                let __span = #span.entered();
+                #args_text
+                #stmts
+                #post_code
+            }
         }
     }
     .into()
@@ -184,7 +212,6 @@ impl FunctionLogVisitor {
                 fn_return: #return_text,
             };
             debug!(target: stringify!(#fn_ident), "{:?}", __log);
-            log_mdc::remove("fn_name");
         }
     }
 
@@ -298,14 +325,22 @@ impl Fold for FunctionLogVisitor {
     fn fold_stmt(&mut self, s: Stmt) -> Stmt {
         match s {
             Stmt::Expr(e) => match e {
-                // ignore log on Box::pin in async_trait attribute macro
+                // ignore log on Box::pin in async_trait attribute macro, but instrument the pinned
+                // future with the span of the function
                 Expr::Call(c) => match *c.func.clone() {
                     Expr::Path(p) => {
                         let first = p.path.segments.first();
                         let last = p.path.segments.last();
                         match (self.async_trait, first, last) {
                             (true, Some(f), Some(l)) if f.ident == "Box" && l.ident == "pin" => {
-                                fold::fold_stmt(self, Stmt::Expr(Expr::Call(c)))
+                                let mut call = fold::fold_expr_call(self, c);
+                                if let Some(future) = call.args.pop() {
+                                    let future = future.into_value();
+                                    call.args.push(parse_quote!(
+                                        tracing::Instrument::instrument(#future, __span)
+                                    ));
+                                }
+                                Stmt::Expr(Expr::Call(call))
                             }
                             _ => self.insert_log_and_fold_expr_stmt(Expr::Call(c)),
                         }
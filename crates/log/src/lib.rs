@@ -1,8 +1,8 @@
 //! As an attribute macro, log_function will
 //! 1. Automatically log the name, input and return value of current function at debug level
 //!  before it returns by trying to recognize return stmt and inserting a `debug!` stmt.
-//! 2. Set the name of current function as a key in `mdc` at the beginning of the function and
-//!  remove it before the function returns.
+//! 2. Run the function in a `tracing` span named after it with the name as the `fn_name` field,
+//!  which instruments the future of an async function so that it follows the task across threads.
 //!
 //! Note:
 //! 1. Input and return type need to implement `Debug`.
@@ -21,14 +21,14 @@
 //!     ```
 //!     Then the log should be: {"message":"LogModel { fn_name: \"show_subset_of_input_and_return_value\",
 //!     fn_args: [\"foo: ignored\", \"bar: ignored\", \"baz: 3\"], fn_return: \"6\" }","level":"DEBUG",
-//!     "target":"show_subset_of_input_and_return_value","mdc":{"fn_name":"show_subset_of_input_and_return_value"}}
+//!     "target":"show_subset_of_input_and_return_value","span":"show_subset_of_input_and_return_value"}
 //!     with test logger.
 //!
 //! Note: Logging result can be different with different logger implementation.
 
 pub use arpa_log_impl::*;
 pub use log::debug;
-pub use log_mdc;
+pub use tracing;
 
 #[derive(Debug)]
 pub struct LogModel<'a> {
@@ -2,7 +2,7 @@ use core::fmt;
 use log::{Level, Log, Metadata, Record, SetLoggerError};
 use once_cell::sync::OnceCell;
 use parking_lot::Mutex;
-use serde::{ser::Serialize, ser::SerializeMap, Serializer};
+use serde::Serializer;
 
 pub static SL: OnceCell<SimpleLogger> = OnceCell::new();
 
@@ -29,7 +29,9 @@ impl Log for SimpleLogger {
                 message: record.args().to_string(),
                 level: record.level(),
                 target: record.target().to_string(),
-                mdc: Mdc,
+                span: tracing::Span::current()
+                    .metadata()
+                    .map(|metadata| metadata.name()),
             };
 
             let log = serde_json::to_string(&message).unwrap();
@@ -50,6 +52,7 @@ pub fn init(log_level: Level) -> Result<(), SetLoggerError> {
     };
     SL.set(logger).unwrap();
     log::set_logger(SL.get().unwrap())?;
+    tracing::subscriber::set_global_default(tracing_subscriber::registry()).unwrap();
     Ok(())
 }
 
@@ -59,7 +62,7 @@ struct Message {
     #[serde(serialize_with = "ser_display")]
     level: Level,
     target: String,
-    mdc: Mdc,
+    span: Option<&'static str>,
 }
 
 fn ser_display<T, S>(v: &T, s: S) -> Result<S::Ok, S::Error>
@@ -69,24 +72,3 @@ where
 {
     s.collect_str(v)
 }
-
-struct Mdc;
-
-impl Serialize for Mdc {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        let mut map = serializer.serialize_map(None)?;
-
-        let mut err = Ok(());
-        log_mdc::iter(|k, v| {
-            if let Ok(()) = err {
-                err = map.serialize_key(k).and_then(|()| map.serialize_value(v));
-            }
-        });
-        err?;
-
-        map.end()
-    }
-}
@@ -198,7 +198,7 @@ mod tests {
             fn_return,
         };
         let expected = format!(
-            "{{\"message\":{:?},\"level\":\"DEBUG\",\"target\":\"{}\",\"mdc\":{{\"fn_name\":\"{}\"}}}}",
+            "{{\"message\":{:?},\"level\":\"DEBUG\",\"target\":\"{}\",\"span\":\"{}\"}}",
             format!("{:?}",log), fn_name, fn_name
         );
         expected
@@ -1,4 +1,5 @@
 use crate::group::{self, Element, PairingCurve as PC, Point, Scalar as Sc};
+use crate::hash::bls12381::{G1_HASHER, G1_SUITE_ID, G2_HASHER, G2_SUITE_ID};
 use crate::hash::hasher::Keccak256Hasher;
 use crate::hash::try_and_increment::TryAndIncrement;
 use crate::hash::HashToCurve;
@@ -35,7 +36,7 @@ pub enum BLS12Error {
 pub struct Scalar(
     #[serde(deserialize_with = "deserialize_field")]
     #[serde(serialize_with = "serialize_field")]
-    <bls12_381::Bls12_381 as PairingEngine>::Fr,
+    pub(crate) <bls12_381::Bls12_381 as PairingEngine>::Fr,
 );
 
 type ZG1 = <bls12_381::Bls12_381 as PairingEngine>::G1Projective;
@@ -44,7 +45,7 @@ type ZG1 = <bls12_381::Bls12_381 as PairingEngine>::G1Projective;
 pub struct G1(
     #[serde(deserialize_with = "deserialize_group")]
     #[serde(serialize_with = "serialize_group")]
-    ZG1,
+    pub(crate) ZG1,
 );
 
 type ZG2 = <bls12_381::Bls12_381 as PairingEngine>::G2Projective;
@@ -53,7 +54,7 @@ type ZG2 = <bls12_381::Bls12_381 as PairingEngine>::G2Projective;
 pub struct G2(
     #[serde(deserialize_with = "deserialize_group")]
     #[serde(serialize_with = "serialize_group")]
-    ZG2,
+    pub(crate) ZG2,
 );
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
@@ -149,6 +150,18 @@ impl Point for G1 {
 
         Ok(())
     }
+
+    fn hash_to_curve(&mut self, dst: &[u8], data: &[u8]) -> Result<(), BLS12Error> {
+        let hash = G1_HASHER.hash(dst, data)?;
+
+        *self = Self(hash);
+
+        Ok(())
+    }
+
+    fn hash_to_curve_suite_id() -> &'static str {
+        G1_SUITE_ID
+    }
 }
 
 impl fmt::Display for G1 {
@@ -195,6 +208,18 @@ impl Point for G2 {
 
         Ok(())
     }
+
+    fn hash_to_curve(&mut self, dst: &[u8], data: &[u8]) -> Result<(), BLS12Error> {
+        let hash = G2_HASHER.hash(dst, data)?;
+
+        *self = Self(hash);
+
+        Ok(())
+    }
+
+    fn hash_to_curve_suite_id() -> &'static str {
+        G2_SUITE_ID
+    }
 }
 
 impl fmt::Display for G2 {
@@ -253,6 +278,14 @@ impl PC for PairingCurve {
     fn pair(a: &Self::G1, b: &Self::G2) -> Self::GT {
         GT(<bls12_381::Bls12_381 as PairingEngine>::pairing(a.0, b.0))
     }
+
+    fn multi_pair(pairs: &[(Self::G1, Self::G2)]) -> Self::GT {
+        let prepared = pairs
+            .iter()
+            .map(|(a, b)| (a.0.into_affine().into(), b.0.into_affine().into()))
+            .collect::<Vec<_>>();
+        GT(<bls12_381::Bls12_381 as PairingEngine>::product_of_pairings(&prepared))
+    }
 }
 
 // Serde implementations (ideally, these should be upstreamed to Zexe)
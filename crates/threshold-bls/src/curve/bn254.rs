@@ -1,4 +1,5 @@
 use crate::group::{self, Element, PairingCurve as PC, Point, Scalar as Sc};
+use crate::hash::bn254::{G1_HASHER, G1_SUITE_ID, G2_HASHER, G2_SUITE_ID};
 use crate::hash::hasher::Keccak256Hasher;
 use crate::hash::try_and_increment::TryAndIncrement;
 use crate::hash::HashToCurve;
@@ -243,6 +244,21 @@ impl Point for G1 {
 
         Ok(())
     }
+
+    fn hash_to_curve(&mut self, dst: &[u8], data: &[u8]) -> Result<(), BNError> {
+        let hash = G1_HASHER
+            .as_ref()
+            .ok_or(BLSError::HashToCurveError)?
+            .hash(dst, data)?;
+
+        *self = Self(hash);
+
+        Ok(())
+    }
+
+    fn hash_to_curve_suite_id() -> &'static str {
+        G1_SUITE_ID
+    }
 }
 
 impl fmt::Display for G1 {
@@ -289,6 +305,21 @@ impl Point for G2 {
 
         Ok(())
     }
+
+    fn hash_to_curve(&mut self, dst: &[u8], data: &[u8]) -> Result<(), BNError> {
+        let hash = G2_HASHER
+            .as_ref()
+            .ok_or(BLSError::HashToCurveError)?
+            .hash(dst, data)?;
+
+        *self = Self(hash);
+
+        Ok(())
+    }
+
+    fn hash_to_curve_suite_id() -> &'static str {
+        G2_SUITE_ID
+    }
 }
 
 impl fmt::Display for G2 {
@@ -347,6 +378,16 @@ impl PC for PairingCurve {
     fn pair(a: &Self::G1, b: &Self::G2) -> Self::GT {
         GT(<bn254::Bn254 as PairingEngine>::pairing(a.0, b.0))
     }
+
+    fn multi_pair(pairs: &[(Self::G1, Self::G2)]) -> Self::GT {
+        let prepared = pairs
+            .iter()
+            .map(|(a, b)| (a.0.into_affine().into(), b.0.into_affine().into()))
+            .collect::<Vec<_>>();
+        GT(<bn254::Bn254 as PairingEngine>::product_of_pairings(
+            &prepared,
+        ))
+    }
 }
 
 // Serde implementations (ideally, these should be upstreamed to Zexe)
@@ -58,6 +58,10 @@ pub enum BLSError {
 
     #[error("not a valid group element")]
     NotValidPoint,
+
+    /// The version of the encoding of a group element is not supported
+    #[error("unsupported encoding version: {0}")]
+    UnsupportedEncodingVersion(u8),
 }
 
 pub trait CurveType {
@@ -52,6 +52,13 @@ pub trait Point: Element {
 
     /// Maps the provided data to a group element
     fn map(&mut self, data: &[u8]) -> Result<(), <Self as Point>::Error>;
+
+    /// Maps the provided data to a group element with the RFC 9380 hash-to-curve suite of
+    /// the group, under the domain separation tag `dst`
+    fn hash_to_curve(&mut self, dst: &[u8], data: &[u8]) -> Result<(), <Self as Point>::Error>;
+
+    /// The ID of the RFC 9380 hash-to-curve suite of the group
+    fn hash_to_curve_suite_id() -> &'static str;
 }
 
 /// A group holds functionalities to create scalar and points related; it is
@@ -86,6 +93,15 @@ pub trait PairingCurve: Clone + Debug {
 
     /// Perfors a pairing operation between the 2 group elements
     fn pair(a: &Self::G1, b: &Self::G2) -> Self::GT;
+
+    /// Computes the product of the pairings of all pairs. Implementations should share a
+    /// single Miller loop and final exponentiation between the pairs.
+    fn multi_pair(pairs: &[(Self::G1, Self::G2)]) -> Self::GT {
+        pairs.iter().fold(Self::GT::one(), |mut acc, (a, b)| {
+            acc.add(&Self::pair(a, b));
+            acc
+        })
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize)]
@@ -0,0 +1,249 @@
+//! RFC 9380 suites BLS12381G1_XMD:SHA-256_SSWU_RO_ and BLS12381G2_XMD:SHA-256_SSWU_RO_,
+//! see RFC 9380 section 8.8.
+use super::map_to_curve::{HashToCurveParameters, MapToCurveHasher};
+use super::sswu::{SSWUMap, SWUParameters};
+use ark_bls12_381::{g1, g2, Fq, Fq2};
+use ark_ec::{models::short_weierstrass_jacobian::GroupProjective, ProjectiveCurve};
+use ark_ff::field_new;
+use lazy_static::lazy_static;
+
+/// Suite ID of the hash to G1.
+pub const G1_SUITE_ID: &str = "BLS12381G1_XMD:SHA-256_SSWU_RO_";
+
+/// Suite ID of the hash to G2.
+pub const G2_SUITE_ID: &str = "BLS12381G2_XMD:SHA-256_SSWU_RO_";
+
+lazy_static! {
+    /// Hasher to G1 with the constants of the map computed once.
+    pub(crate) static ref G1_HASHER: MapToCurveHasher<g1::Parameters, SSWUMap<g1::Parameters>> =
+        MapToCurveHasher::new(SSWUMap::new());
+
+    /// Hasher to G2 with the constants of the map computed once.
+    pub(crate) static ref G2_HASHER: MapToCurveHasher<g2::Parameters, SSWUMap<g2::Parameters>> =
+        MapToCurveHasher::new(SSWUMap::new());
+}
+
+/// h_eff of G1 = 1 - z, which clears the cofactor faster than the cofactor itself.
+const G1_H_EFF: &[u64] = &[0xd201000000010001];
+
+/// h_eff of G2 = 3 * (z^2 - 1) * h2, the scalar equivalent of the endomorphism-based
+/// cofactor clearing of Budroni and Pintore.
+#[rustfmt::skip]
+const G2_H_EFF: &[u64] = &[
+    0xe8020005aaa95551, 0x59894c0adebbf6b4, 0xe954cbc06689f6a3, 0x2ec0ec69d7477c1a,
+    0x6d82bf015d1212b0, 0x329c2f178731db95, 0x9986ff031508ffe1, 0x88e2a8e9145ad768,
+    0x584c6a0ea91b3528, 0x0bc69f08f2ee75b3,
+];
+
+impl HashToCurveParameters for g1::Parameters {
+    fn clear_cofactor(p: GroupProjective<Self>) -> GroupProjective<Self> {
+        ProjectiveCurve::mul(p, G1_H_EFF)
+    }
+}
+
+impl SWUParameters for g1::Parameters {
+    #[rustfmt::skip]
+    const ISOGENOUS_COEFF_A: Fq = field_new!(Fq, "12190336318893619529228877361869031420615612348429846051986726275283378313155663745811710833465465981901188123677");
+    #[rustfmt::skip]
+    const ISOGENOUS_COEFF_B: Fq = field_new!(Fq, "2906670324641927570491258158026293881577086121416628140204402091718288198173574630967936031029026176254968826637280");
+    const Z: Fq = field_new!(Fq, "11");
+
+    const ISOGENY_X_NUM: &'static [Fq] = &G1_ISO_X_NUM;
+    const ISOGENY_X_DEN: &'static [Fq] = &G1_ISO_X_DEN;
+    const ISOGENY_Y_NUM: &'static [Fq] = &G1_ISO_Y_NUM;
+    const ISOGENY_Y_DEN: &'static [Fq] = &G1_ISO_Y_DEN;
+}
+
+impl HashToCurveParameters for g2::Parameters {
+    fn clear_cofactor(p: GroupProjective<Self>) -> GroupProjective<Self> {
+        ProjectiveCurve::mul(p, G2_H_EFF)
+    }
+}
+
+impl SWUParameters for g2::Parameters {
+    const ISOGENOUS_COEFF_A: Fq2 = field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "240"));
+    const ISOGENOUS_COEFF_B: Fq2 = field_new!(Fq2, field_new!(Fq, "1012"), field_new!(Fq, "1012"));
+    /// Z = -(2 + I)
+    const Z: Fq2 = field_new!(Fq2, field_new!(Fq, "-2"), field_new!(Fq, "-1"));
+
+    const ISOGENY_X_NUM: &'static [Fq2] = &G2_ISO_X_NUM;
+    const ISOGENY_X_DEN: &'static [Fq2] = &G2_ISO_X_DEN;
+    const ISOGENY_Y_NUM: &'static [Fq2] = &G2_ISO_Y_NUM;
+    const ISOGENY_Y_DEN: &'static [Fq2] = &G2_ISO_Y_DEN;
+}
+
+// Coefficients of the 11-isogeny from E' to G1 and of the 3-isogeny from E' to G2 of
+// RFC 9380 appendix E.2 and E.3, from the constant term up.
+
+#[rustfmt::skip]
+const G1_ISO_X_NUM: [Fq; 12] = [
+    field_new!(Fq, "2712959285290305970661081772124144179193819192423276218370281158706191519995889425075952244140278856085036081760695"),
+    field_new!(Fq, "3564859427549639835253027846704205725951033235539816243131874237388832081954622352624080767121604606753339903542203"),
+    field_new!(Fq, "2051387046688339481714726479723076305756384619135044672831882917686431912682625619320120082313093891743187631791280"),
+    field_new!(Fq, "3612713941521031012780325893181011392520079402153354595775735142359240110423346445050803899623018402874731133626465"),
+    field_new!(Fq, "2247053637822768981792833880270996398470828564809439728372634811976089874056583714987807553397615562273407692740057"),
+    field_new!(Fq, "3415427104483187489859740871640064348492611444552862448295571438270821994900526625562705192993481400731539293415811"),
+    field_new!(Fq, "2067521456483432583860405634125513059912765526223015704616050604591207046392807563217109432457129564962571408764292"),
+    field_new!(Fq, "3650721292069012982822225637849018828271936405382082649291891245623305084633066170122780668657208923883092359301262"),
+    field_new!(Fq, "1239271775787030039269460763652455868148971086016832054354147730155061349388626624328773377658494412538595239256855"),
+    field_new!(Fq, "3479374185711034293956731583912244564891370843071137483962415222733470401948838363051960066766720884717833231600798"),
+    field_new!(Fq, "2492756312273161536685660027440158956721981129429869601638362407515627529461742974364729223659746272460004902959995"),
+    field_new!(Fq, "1058488477413994682556770863004536636444795456512795473806825292198091015005841418695586811009326456605062948114985"),
+];
+
+#[rustfmt::skip]
+const G1_ISO_X_DEN: [Fq; 11] = [
+    field_new!(Fq, "1353092447850172218905095041059784486169131709710991428415161466575141675351394082965234118340787683181925558786844"),
+    field_new!(Fq, "2822220997908397120956501031591772354860004534930174057793539372552395729721474912921980407622851861692773516917759"),
+    field_new!(Fq, "1717937747208385987946072944131378949849282930538642983149296304709633281382731764122371874602115081850953846504985"),
+    field_new!(Fq, "501624051089734157816582944025690868317536915684467868346388760435016044027032505306995281054569109955275640941784"),
+    field_new!(Fq, "3025903087998593826923738290305187197829899948335370692927241015584233559365859980023579293766193297662657497834014"),
+    field_new!(Fq, "2224140216975189437834161136818943039444741035168992629437640302964164227138031844090123490881551522278632040105125"),
+    field_new!(Fq, "1146414465848284837484508420047674663876992808692209238763293935905506532411661921697047880549716175045414621825594"),
+    field_new!(Fq, "3179090966864399634396993677377903383656908036827452986467581478509513058347781039562481806409014718357094150199902"),
+    field_new!(Fq, "1549317016540628014674302140786462938410429359529923207442151939696344988707002602944342203885692366490121021806145"),
+    field_new!(Fq, "1442797143427491432630626390066422021593505165588630398337491100088557278058060064930663878153124164818522816175370"),
+    field_new!(Fq, "1"),
+];
+
+#[rustfmt::skip]
+const G1_ISO_Y_NUM: [Fq; 16] = [
+    field_new!(Fq, "1393399195776646641963150658816615410692049723305861307490980409834842911816308830479576739332720113414154429643571"),
+    field_new!(Fq, "2968610969752762946134106091152102846225411740689724909058016729455736597929366401532929068084731548131227395540630"),
+    field_new!(Fq, "122933100683284845219599644396874530871261396084070222155796123161881094323788483360414289333111221370374027338230"),
+    field_new!(Fq, "303251954782077855462083823228569901064301365507057490567314302006681283228886645653148231378803311079384246777035"),
+    field_new!(Fq, "1353972356724735644398279028378555627591260676383150667237975415318226973994509601413730187583692624416197017403099"),
+    field_new!(Fq, "3443977503653895028417260979421240655844034880950251104724609885224259484262346958661845148165419691583810082940400"),
+    field_new!(Fq, "718493410301850496156792713845282235942975872282052335612908458061560958159410402177452633054233549648465863759602"),
+    field_new!(Fq, "1466864076415884313141727877156167508644960317046160398342634861648153052436926062434809922037623519108138661903145"),
+    field_new!(Fq, "1536886493137106337339531461344158973554574987550750910027365237255347020572858445054025958480906372033954157667719"),
+    field_new!(Fq, "2171468288973248519912068884667133903101171670397991979582205855298465414047741472281361964966463442016062407908400"),
+    field_new!(Fq, "3915937073730221072189646057898966011292434045388986394373682715266664498392389619761133407846638689998746172899634"),
+    field_new!(Fq, "3802409194827407598156407709510350851173404795262202653149767739163117554648574333789388883640862266596657730112910"),
+    field_new!(Fq, "1707589313757812493102695021134258021969283151093981498394095062397393499601961942449581422761005023512037430861560"),
+    field_new!(Fq, "349697005987545415860583335313370109325490073856352967581197273584891698473628451945217286148025358795756956811571"),
+    field_new!(Fq, "885704436476567581377743161796735879083481447641210566405057346859953524538988296201011389016649354976986251207243"),
+    field_new!(Fq, "3370924952219000111210625390420697640496067348723987858345031683392215988129398381698161406651860675722373763741188"),
+];
+
+#[rustfmt::skip]
+const G1_ISO_Y_DEN: [Fq; 16] = [
+    field_new!(Fq, "3396434800020507717552209507749485772788165484415495716688989613875369612529138640646200921379825018840894888371137"),
+    field_new!(Fq, "3907278185868397906991868466757978732688957419873771881240086730384895060595583602347317992689443299391009456758845"),
+    field_new!(Fq, "854914566454823955479427412036002165304466268547334760894270240966182605542146252771872707010378658178126128834546"),
+    field_new!(Fq, "3496628876382137961119423566187258795236027183112131017519536056628828830323846696121917502443333849318934945158166"),
+    field_new!(Fq, "1828256966233331991927609917644344011503610008134915752990581590799656305331275863706710232159635159092657073225757"),
+    field_new!(Fq, "1362317127649143894542621413133849052553333099883364300946623208643344298804722863920546222860227051989127113848748"),
+    field_new!(Fq, "3443845896188810583748698342858554856823966611538932245284665132724280883115455093457486044009395063504744802318172"),
+    field_new!(Fq, "3484671274283470572728732863557945897902920439975203610275006103818288159899345245633896492713412187296754791689945"),
+    field_new!(Fq, "3755735109429418587065437067067640634211015783636675372165599470771975919172394156249639331555277748466603540045130"),
+    field_new!(Fq, "3459661102222301807083870307127272890283709299202626530836335779816726101522661683404130556379097384249447658110805"),
+    field_new!(Fq, "742483168411032072323733249644347333168432665415341249073150659015707795549260947228694495111018381111866512337576"),
+    field_new!(Fq, "1662231279858095762833829698537304807741442669992646287950513237989158777254081548205552083108208170765474149568658"),
+    field_new!(Fq, "1668238650112823419388205992952852912407572045257706138925379268508860023191233729074751042562151098884528280913356"),
+    field_new!(Fq, "369162719928976119195087327055926326601627748362769544198813069133429557026740823593067700396825489145575282378487"),
+    field_new!(Fq, "2164195715141237148945939585099633032390257748382945597506236650132835917087090097395995817229686247227784224263055"),
+    field_new!(Fq, "1"),
+];
+
+#[rustfmt::skip]
+const G2_ISO_X_NUM: [Fq2; 4] = [
+    field_new!(Fq2, field_new!(Fq, "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542"), field_new!(Fq, "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235542")),
+    field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706522")),
+    field_new!(Fq2, field_new!(Fq, "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706526"), field_new!(Fq, "1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853261")),
+    field_new!(Fq2, field_new!(Fq, "3557697382419259905260257622876359250272784728834673675850718343221361467102966990615722337003569479144794908942033"), field_new!(Fq, "0")),
+];
+
+#[rustfmt::skip]
+const G2_ISO_X_DEN: [Fq2; 3] = [
+    field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559715")),
+    field_new!(Fq2, field_new!(Fq, "12"), field_new!(Fq, "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559775")),
+    field_new!(Fq2, field_new!(Fq, "1"), field_new!(Fq, "0")),
+];
+
+#[rustfmt::skip]
+const G2_ISO_Y_NUM: [Fq2; 4] = [
+    field_new!(Fq2, field_new!(Fq, "3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558"), field_new!(Fq, "3261222600550988246488569487636662646083386001431784202863158481286248011511053074731078808919938689216061999863558")),
+    field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "889424345604814976315064405719089812568196182208668418962679585805340366775741747653930584250892369786198727235518")),
+    field_new!(Fq2, field_new!(Fq, "2668273036814444928945193217157269437704588546626005256888038757416021100327225242961791752752677109358596181706524"), field_new!(Fq, "1334136518407222464472596608578634718852294273313002628444019378708010550163612621480895876376338554679298090853263")),
+    field_new!(Fq2, field_new!(Fq, "2816510427748580758331037284777117739799287910327449993381818688383577828123182200904113516794492504322962636245776"), field_new!(Fq, "0")),
+];
+
+#[rustfmt::skip]
+const G2_ISO_Y_DEN: [Fq2; 4] = [
+    field_new!(Fq2, field_new!(Fq, "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559355"), field_new!(Fq, "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559355")),
+    field_new!(Fq2, field_new!(Fq, "0"), field_new!(Fq, "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559571")),
+    field_new!(Fq2, field_new!(Fq, "18"), field_new!(Fq, "4002409555221667393417789825735904156556882819939007885332058136124031650490837864442687629129015664037894272559769")),
+    field_new!(Fq2, field_new!(Fq, "1"), field_new!(Fq, "0")),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{G1_SUITE_ID, G2_SUITE_ID};
+    use crate::hash::{map_to_curve::MapToCurveHasher, sswu::SSWUMap, HashToCurve};
+    use ark_bls12_381::{g1, g2, Fq, Fq2};
+    use ark_ec::ProjectiveCurve;
+    use ark_ff::PrimeField;
+    use ethers_core::utils::hex;
+
+    fn fq(hex_str: &str) -> Fq {
+        Fq::from_be_bytes_mod_order(&hex::decode(hex_str).unwrap())
+    }
+
+    fn messages() -> [Vec<u8>; 5] {
+        [
+            b"".to_vec(),
+            b"abc".to_vec(),
+            b"abcdef0123456789".to_vec(),
+            [&b"q128_"[..], &[b'q'; 128]].concat(),
+            [&b"a512_"[..], &[b'a'; 512]].concat(),
+        ]
+    }
+
+    // test vectors from RFC 9380 appendix J.9.1, as (P.x, P.y) of each message
+    #[rustfmt::skip]
+    const G1_VECTORS: [(&str, &str); 5] = [
+        ("052926add2207b76ca4fa57a8734416c8dc95e24501772c814278700eed6d1e4e8cf62d9c09db0fac349612b759e79a1", "08ba738453bfed09cb546dbb0783dbb3a5f1f566ed67bb6be0e8c67e2e81a4cc68ee29813bb7994998f3eae0c9c6a265"),
+        ("03567bc5ef9c690c2ab2ecdf6a96ef1c139cc0b2f284dca0a9a7943388a49a3aee664ba5379a7655d3c68900be2f6903", "0b9c15f3fe6e5cf4211f346271d7b01c8f3b28be689c8429c85b67af215533311f0b8dfaaa154fa6b88176c229f2885d"),
+        ("11e0b079dea29a68f0383ee94fed1b940995272407e3bb916bbf268c263ddd57a6a27200a784cbc248e84f357ce82d98", "03a87ae2caf14e8ee52e51fa2ed8eefe80f02457004ba4d486d6aa1f517c0889501dc7413753f9599b099ebcbbd2d709"),
+        ("15f68eaa693b95ccb85215dc65fa81038d69629f70aeee0d0f677cf22285e7bf58d7cb86eefe8f2e9bc3f8cb84fac488", "1807a1d50c29f430b8cafc4f8638dfeeadf51211e1602a5f184443076715f91bb90a48ba1e370edce6ae1062f5e6dd38"),
+        ("082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe", "05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8"),
+    ];
+
+    // test vectors from RFC 9380 appendix J.10.1, as (P.x_0, P.x_1, P.y_0, P.y_1) of each
+    // message
+    #[rustfmt::skip]
+    const G2_VECTORS: [(&str, &str, &str, &str); 5] = [
+        ("0141ebfbdca40eb85b87142e130ab689c673cf60f1a3e98d69335266f30d9b8d4ac44c1038e9dcdd5393faf5c41fb78a", "05cb8437535e20ecffaef7752baddf98034139c38452458baeefab379ba13dff5bf5dd71b72418717047f5b0f37da03d", "0503921d7f6a12805e72940b963c0cf3471c7b2a524950ca195d11062ee75ec076daf2d4bc358c4b190c0c98064fdd92", "12424ac32561493f3fe3c260708a12b7c620e7be00099a974e259ddc7d1f6395c3c811cdd19f1e8dbf3e9ecfdcbab8d6"),
+        ("02c2d18e033b960562aae3cab37a27ce00d80ccd5ba4b7fe0e7a210245129dbec7780ccc7954725f4168aff2787776e6", "139cddbccdc5e91b9623efd38c49f81a6f83f175e80b06fc374de9eb4b41dfe4ca3a230ed250fbe3a2acf73a41177fd8", "1787327b68159716a37440985269cf584bcb1e621d3a7202be6ea05c4cfe244aeb197642555a0645fb87bf7466b2ba48", "00aa65dae3c8d732d10ecd2c50f8a1baf3001578f71c694e03866e9f3d49ac1e1ce70dd94a733534f106d4cec0eddd16"),
+        ("121982811d2491fde9ba7ed31ef9ca474f0e1501297f68c298e9f4c0028add35aea8bb83d53c08cfc007c1e005723cd0", "190d119345b94fbd15497bcba94ecf7db2cbfd1e1fe7da034d26cbba169fb3968288b3fafb265f9ebd380512a71c3f2c", "05571a0f8d3c08d094576981f4a3b8eda0a8e771fcdcc8ecceaf1356a6acf17574518acb506e435b639353c2e14827c8", "0bb5e7572275c567462d91807de765611490205a941a5a6af3b1691bfe596c31225d3aabdf15faff860cb4ef17c7c3be"),
+        ("19a84dd7248a1066f737cc34502ee5555bd3c19f2ecdb3c7d9e24dc65d4e25e50d83f0f77105e955d78f4762d33c17da", "0934aba516a52d8ae479939a91998299c76d39cc0c035cd18813bec433f587e2d7a4fef038260eef0cef4d02aae3eb91", "14f81cd421617428bc3b9fe25afbb751d934a00493524bc4e065635b0555084dd54679df1536101b2c979c0152d09192", "09bcccfa036b4847c9950780733633f13619994394c23ff0b32fa6b795844f4a0673e20282d07bc69641cee04f5e5662"),
+        ("01a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f62534", "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d01569", "0b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e", "03a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab52"),
+    ];
+
+    #[test]
+    fn test_hash_to_g1() {
+        let dst = format!("QUUX-V01-CS02-with-{}", G1_SUITE_ID);
+        let hasher = MapToCurveHasher::<g1::Parameters, _>::new(SSWUMap::new());
+
+        for (msg, (x, y)) in messages().iter().zip(G1_VECTORS) {
+            let p = hasher.hash(dst.as_bytes(), msg).unwrap().into_affine();
+
+            assert_eq!(p.x, fq(x));
+            assert_eq!(p.y, fq(y));
+        }
+    }
+
+    #[test]
+    fn test_hash_to_g2() {
+        let dst = format!("QUUX-V01-CS02-with-{}", G2_SUITE_ID);
+        let hasher = MapToCurveHasher::<g2::Parameters, _>::new(SSWUMap::new());
+
+        for (msg, (x_0, x_1, y_0, y_1)) in messages().iter().zip(G2_VECTORS) {
+            let p = hasher.hash(dst.as_bytes(), msg).unwrap().into_affine();
+
+            assert_eq!(p.x, Fq2::new(fq(x_0), fq(x_1)));
+            assert_eq!(p.y, Fq2::new(fq(y_0), fq(y_1)));
+        }
+    }
+}
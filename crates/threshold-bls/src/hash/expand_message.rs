@@ -0,0 +1,123 @@
+use crate::curve::BLSError;
+use sha2::{Digest, Sha256};
+
+/// Input block size of SHA-256 in bytes.
+const SHA256_BLOCK_SIZE: usize = 64;
+
+/// Output size of SHA-256 in bytes.
+const SHA256_OUTPUT_SIZE: usize = 32;
+
+/// Prefix of the DST hashed down when it is longer than 255 bytes, see RFC 9380 section 5.3.3.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// expand_message_xmd of RFC 9380 section 5.3.1 instantiated with SHA-256, it expands
+/// the message to `len_in_bytes` uniformly random bytes under the domain separation tag.
+pub fn expand_message_xmd(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Result<Vec<u8>, BLSError> {
+    let ell = (len_in_bytes + SHA256_OUTPUT_SIZE - 1) / SHA256_OUTPUT_SIZE;
+    if ell > 255 || len_in_bytes > u16::MAX as usize {
+        return Err(BLSError::HashToCurveError);
+    }
+
+    let dst_prime = dst_prime(dst);
+
+    let mut hasher = Sha256::new();
+    hasher.input([0u8; SHA256_BLOCK_SIZE]);
+    hasher.input(msg);
+    hasher.input((len_in_bytes as u16).to_be_bytes());
+    hasher.input([0u8]);
+    hasher.input(&dst_prime);
+    let b_0 = hasher.result();
+
+    let mut hasher = Sha256::new();
+    hasher.input(b_0);
+    hasher.input([1u8]);
+    hasher.input(&dst_prime);
+    let mut b_i = hasher.result();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * SHA256_OUTPUT_SIZE);
+    uniform_bytes.extend_from_slice(&b_i);
+
+    for i in 2..=ell {
+        let mut hasher = Sha256::new();
+        hasher.input(
+            b_0.iter()
+                .zip(b_i.iter())
+                .map(|(a, b)| a ^ b)
+                .collect::<Vec<_>>(),
+        );
+        hasher.input([i as u8]);
+        hasher.input(&dst_prime);
+        b_i = hasher.result();
+
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+
+    uniform_bytes.truncate(len_in_bytes);
+
+    Ok(uniform_bytes)
+}
+
+/// DST || I2OSP(len(DST), 1), with a DST longer than 255 bytes hashed down first.
+fn dst_prime(dst: &[u8]) -> Vec<u8> {
+    let mut dst_prime = if dst.len() > 255 {
+        let mut hasher = Sha256::new();
+        hasher.input(OVERSIZE_DST_PREFIX);
+        hasher.input(dst);
+        hasher.result().to_vec()
+    } else {
+        dst.to_vec()
+    };
+
+    dst_prime.push(dst_prime.len() as u8);
+
+    dst_prime
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand_message_xmd;
+    use ethers_core::utils::hex;
+
+    // test vectors from RFC 9380 appendix K.1
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+
+    #[test]
+    fn test_expand_message_xmd() {
+        let cases: [(&[u8], usize, &str); 4] = [
+            (
+                b"",
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                0x20,
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ];
+
+        for (msg, len_in_bytes, expected) in cases {
+            let uniform_bytes = expand_message_xmd(msg, DST, len_in_bytes).unwrap();
+            assert_eq!(hex::encode(uniform_bytes), expected);
+        }
+    }
+
+    #[test]
+    fn test_expand_message_xmd_rejects_long_output() {
+        assert!(expand_message_xmd(b"abc", DST, 255 * 32 + 1).is_err());
+    }
+}
@@ -12,8 +12,9 @@ pub struct Keccak256Hasher;
 impl Hasher for Keccak256Hasher {
     type Error = BLSError;
 
-    fn hash(&self, _domain: &[u8], message: &[u8]) -> Result<Vec<u8>, Self::Error> {
-        Ok(ethers_core::utils::keccak256(message).into())
+    /// Hashes the message prefixed by the domain, so an empty domain hashes the message alone
+    fn hash(&self, domain: &[u8], message: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        Ok(ethers_core::utils::keccak256([domain, message].concat()).into())
     }
 }
 
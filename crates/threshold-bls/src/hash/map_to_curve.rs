@@ -0,0 +1,295 @@
+use super::{expand_message::expand_message_xmd, HashToCurve};
+use crate::curve::BLSError;
+use ark_ec::models::{
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    SWModelParameters,
+};
+use ark_ec::AffineCurve;
+use ark_ff::{Field, PrimeField};
+use num_bigint::BigUint;
+use std::marker::PhantomData;
+use subtle::{Choice, ConstantTimeEq};
+
+/// Target security level in bits of the hash_to_field outputs, see RFC 9380 section 5.
+const SECURITY_LEVEL: usize = 128;
+
+/// Curve parameters needed by the RFC 9380 hash-to-curve on top of the curve equation.
+pub trait HashToCurveParameters: SWModelParameters + Sized {
+    /// Maps a point of the curve into the prime-order subgroup. The default multiplies by
+    /// the cofactor, curves whose suite defines a smaller h_eff override it.
+    fn clear_cofactor(p: GroupProjective<Self>) -> GroupProjective<Self> {
+        GroupAffine::from(p).scale_by_cofactor()
+    }
+}
+
+/// A deterministic map from a field element to a point on the curve, as defined by
+/// RFC 9380 section 6.
+pub trait MapToCurve<P: HashToCurveParameters> {
+    fn map_to_curve(&self, u: P::BaseField) -> Result<GroupAffine<P>, BLSError>;
+}
+
+/// hash_to_field of RFC 9380 section 5.2 with expand_message_xmd and SHA-256.
+pub fn hash_to_field<F: Field>(msg: &[u8], dst: &[u8], count: usize) -> Result<Vec<F>, BLSError> {
+    let m = F::extension_degree() as usize;
+    let l = (F::BasePrimeField::size_in_bits() + SECURITY_LEVEL + 7) / 8;
+
+    let uniform_bytes = expand_message_xmd(msg, dst, count * m * l)?;
+
+    uniform_bytes
+        .chunks(m * l)
+        .map(|element_bytes| {
+            let elems = element_bytes
+                .chunks(l)
+                .map(F::BasePrimeField::from_be_bytes_mod_order)
+                .collect::<Vec<_>>();
+            F::from_base_prime_field_elems(&elems).ok_or(BLSError::HashToCurveError)
+        })
+        .collect()
+}
+
+/// sgn0 of RFC 9380 section 4.1, the parity of the first non-zero coordinate of the element
+/// over its base prime field. It doesn't branch on the element.
+pub fn sgn0<F: Field>(f: &F) -> Result<bool, BLSError> {
+    let mut bytes = vec![];
+    f.serialize(&mut bytes)?;
+
+    // coordinates are serialized in order as canonical little-endian integers
+    let coordinate_size = bytes.len() / F::extension_degree() as usize;
+    let zero_coordinate = vec![0u8; coordinate_size];
+    let mut sign = Choice::from(0);
+    let mut zero = Choice::from(1);
+    for coordinate in bytes.chunks(coordinate_size) {
+        let sign_i = Choice::from(coordinate[0] & 1);
+        let zero_i = coordinate.ct_eq(&zero_coordinate);
+        sign |= zero & sign_i;
+        zero &= zero_i;
+    }
+
+    Ok(sign.into())
+}
+
+/// CMOV of RFC 9380 section 4, which selects b if c is set and a otherwise without branching
+/// on c.
+pub(crate) fn cmov<F: Field>(a: &F, b: &F, c: Choice) -> F {
+    *a + (*b - *a) * F::from(u64::from(c.unwrap_u8()))
+}
+
+/// Compares the canonical encodings of two elements in constant time.
+pub(crate) fn ct_eq<F: Field>(a: &F, b: &F) -> Result<Choice, BLSError> {
+    let mut a_bytes = vec![];
+    a.serialize(&mut a_bytes)?;
+    let mut b_bytes = vec![];
+    b.serialize(&mut b_bytes)?;
+
+    Ok(a_bytes.ct_eq(&b_bytes))
+}
+
+/// inv0 of RFC 9380 section 4, where the inverse of zero is zero. The inverse is the product of
+/// the conjugates of the element divided by its norm, the norm being inverted in the base prime
+/// field by Fermat's little theorem, so that only exponents that are public are used.
+pub(crate) fn inv0<F: Field>(x: &F) -> F {
+    let mut conjugates = F::one();
+    for power in 1..F::extension_degree() as usize {
+        let mut conjugate = *x;
+        conjugate.frobenius_map(power);
+        conjugates *= &conjugate;
+    }
+
+    let norm = conjugates * x;
+    let p_minus_2 = characteristic::<F>() - 2u32;
+
+    conjugates * norm.pow(p_minus_2.to_u64_digits())
+}
+
+/// The characteristic p of the field.
+fn characteristic<F: Field>() -> BigUint {
+    let bytes = F::characteristic()
+        .iter()
+        .flat_map(|limb| limb.to_le_bytes())
+        .collect::<Vec<_>>();
+
+    BigUint::from_bytes_le(&bytes)
+}
+
+/// sqrt_ratio of RFC 9380 appendix F.2.1 for any field, with the constants precomputed from
+/// the non-square Z. Only public exponents and CMOV are used, so neither the branches nor
+/// the number of operations depend on the inputs.
+#[derive(Clone)]
+pub(crate) struct SqrtRatio<F: Field> {
+    c1: usize,
+    c3: Vec<u64>,
+    c4: Vec<u64>,
+    c5: Vec<u64>,
+    c6: F,
+    c7: F,
+    square_exp: Vec<u64>,
+}
+
+impl<F: Field> SqrtRatio<F> {
+    pub(crate) fn new(z: F) -> Self {
+        // q = p^m
+        let q = characteristic::<F>().pow(F::extension_degree() as u32);
+        let q_minus_1 = &q - 1u32;
+
+        // c1, the largest integer such that 2^c1 divides q - 1
+        let c1 = q_minus_1.trailing_zeros().unwrap_or_default() as usize;
+        // c2 = (q - 1) / (2^c1)
+        let c2 = &q_minus_1 >> c1;
+        // c3 = (c2 - 1) / 2
+        let c3 = (&c2 - 1u32) >> 1usize;
+        // c4 = 2^c1 - 1
+        let c4 = (BigUint::from(1u32) << c1) - 1u32;
+        // c5 = 2^(c1 - 1)
+        let c5 = BigUint::from(1u32) << (c1 - 1);
+        // c6 = Z^c2
+        let c6 = z.pow(c2.to_u64_digits());
+        // c7 = Z^((c2 + 1) / 2)
+        let c7 = z.pow(((&c2 + 1u32) >> 1usize).to_u64_digits());
+
+        SqrtRatio {
+            c1,
+            c3: c3.to_u64_digits(),
+            c4: c4.to_u64_digits(),
+            c5: c5.to_u64_digits(),
+            c6,
+            c7,
+            square_exp: (q_minus_1 >> 1usize).to_u64_digits(),
+        }
+    }
+
+    /// Returns whether u / v is square, along with sqrt(u / v) if it is and sqrt(Z * u / v)
+    /// otherwise.
+    pub(crate) fn sqrt_ratio(&self, u: &F, v: &F) -> Result<(Choice, F), BLSError> {
+        let mut tv1 = self.c6;
+        let mut tv2 = v.pow(&self.c4);
+        let mut tv3 = tv2.square();
+        tv3 *= v;
+        let mut tv5 = *u * tv3;
+        tv5 = tv5.pow(&self.c3);
+        tv5 *= &tv2;
+        tv2 = tv5 * v;
+        tv3 = tv5 * u;
+        let mut tv4 = tv3 * tv2;
+        tv5 = tv4.pow(&self.c5);
+        let is_qr = ct_eq(&tv5, &F::one())?;
+        tv2 = tv3 * self.c7;
+        tv5 = tv4 * tv1;
+        tv3 = cmov(&tv2, &tv3, is_qr);
+        tv4 = cmov(&tv5, &tv4, is_qr);
+        for k in (2..=self.c1).rev() {
+            tv5 = tv4;
+            for _ in 0..k - 2 {
+                tv5.square_in_place();
+            }
+            let e1 = ct_eq(&tv5, &F::one())?;
+            tv2 = tv3 * tv1;
+            tv1.square_in_place();
+            tv5 = tv4 * tv1;
+            tv3 = cmov(&tv2, &tv3, e1);
+            tv4 = cmov(&tv5, &tv4, e1);
+        }
+
+        Ok((is_qr, tv3))
+    }
+
+    /// is_square of RFC 9380 section 4, where zero is a square.
+    pub(crate) fn is_square(&self, x: &F) -> Result<Choice, BLSError> {
+        let tv = x.pow(&self.square_exp);
+
+        Ok(ct_eq(&tv, &F::zero())? | ct_eq(&tv, &F::one())?)
+    }
+
+    /// A square root of the square x.
+    pub(crate) fn sqrt(&self, x: &F) -> Result<F, BLSError> {
+        Ok(self.sqrt_ratio(x, &F::one())?.1)
+    }
+}
+
+/// The right-hand side x^3 + A * x + B of the curve equation.
+pub(crate) fn curve_rhs<P: SWModelParameters>(x: &P::BaseField) -> P::BaseField {
+    x.square() * x + P::mul_by_a(x) + P::COEFF_B
+}
+
+/// hash_to_curve of RFC 9380 section 3, the random oracle encoding which hashes the message
+/// to two field elements, maps both to the curve and clears the cofactor of their sum.
+/// The domain passed to `hash` is the DST of the suite.
+#[derive(Clone)]
+pub struct MapToCurveHasher<P, M> {
+    map: M,
+    curve_params: PhantomData<P>,
+}
+
+impl<P, M> MapToCurveHasher<P, M>
+where
+    P: HashToCurveParameters,
+    M: MapToCurve<P>,
+{
+    pub fn new(map: M) -> Self {
+        MapToCurveHasher {
+            map,
+            curve_params: PhantomData,
+        }
+    }
+}
+
+impl<P, M> HashToCurve for MapToCurveHasher<P, M>
+where
+    P: HashToCurveParameters,
+    M: MapToCurve<P>,
+{
+    type Output = GroupProjective<P>;
+
+    fn hash(&self, domain: &[u8], message: &[u8]) -> Result<Self::Output, BLSError> {
+        let u = hash_to_field::<P::BaseField>(message, domain, 2)?;
+
+        let q0 = self.map.map_to_curve(u[0])?;
+        let q1 = self.map.map_to_curve(u[1])?;
+
+        Ok(P::clear_cofactor(
+            q0.into_projective() + q1.into_projective(),
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "bls12_381"))]
+mod tests {
+    use super::{inv0, SqrtRatio};
+    use crate::hash::sswu::SWUParameters;
+    use ark_bls12_381::{g2, Fq2};
+    use ark_ff::{Field, One, UniformRand, Zero};
+
+    #[test]
+    fn test_inv0() {
+        let rng = &mut rand::thread_rng();
+
+        assert_eq!(inv0(&Fq2::zero()), Fq2::zero());
+        for _ in 0..10 {
+            let x = Fq2::rand(rng);
+            assert_eq!(inv0(&x) * x, Fq2::one());
+        }
+    }
+
+    #[test]
+    fn test_sqrt_ratio() {
+        let rng = &mut rand::thread_rng();
+        let z = <g2::Parameters as SWUParameters>::Z;
+        let sqrt_ratio = SqrtRatio::new(z);
+
+        for _ in 0..10 {
+            let u = Fq2::rand(rng);
+            let v = Fq2::rand(rng);
+            let (is_square, y) = sqrt_ratio.sqrt_ratio(&u, &v).unwrap();
+
+            let ratio = u / v;
+            if bool::from(is_square) {
+                assert_eq!(y.square(), ratio);
+            } else {
+                assert_eq!(y.square(), z * ratio);
+            }
+            assert_eq!(
+                bool::from(sqrt_ratio.is_square(&ratio).unwrap()),
+                bool::from(is_square)
+            );
+        }
+    }
+}
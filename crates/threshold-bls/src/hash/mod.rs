@@ -1,7 +1,19 @@
+pub mod expand_message;
 pub mod hasher;
+pub mod map_to_curve;
+pub mod sswu;
+pub mod svdw;
 pub mod try_and_increment;
 use crate::curve::BLSError;
 
+/// RFC 9380 hash-to-curve suites of BLS12-381
+#[cfg(feature = "bls12_381")]
+pub mod bls12381;
+
+/// RFC 9380 hash-to-curve suites of BN254
+#[cfg(feature = "bn254")]
+pub mod bn254;
+
 /// Trait for hashing arbitrary data to a group element on an elliptic curve
 pub trait HashToCurve {
     /// The type of the curve being used.
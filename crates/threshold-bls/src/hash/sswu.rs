@@ -0,0 +1,110 @@
+use super::map_to_curve::{cmov, ct_eq, inv0, sgn0, HashToCurveParameters, MapToCurve, SqrtRatio};
+use crate::curve::BLSError;
+use ark_ec::models::short_weierstrass_jacobian::GroupAffine;
+use ark_ff::{Field, One, Zero};
+use subtle::Choice;
+
+/// Parameters of the simplified SWU map for a curve with A * B = 0, which maps to an
+/// isogenous curve E': y^2 = x^3 + A' * x + B' first, see RFC 9380 section 6.6.3.
+pub trait SWUParameters: HashToCurveParameters {
+    /// A' of the isogenous curve, not zero.
+    const ISOGENOUS_COEFF_A: Self::BaseField;
+    /// B' of the isogenous curve, not zero.
+    const ISOGENOUS_COEFF_B: Self::BaseField;
+    /// The non-square Z of the map.
+    const Z: Self::BaseField;
+
+    /// Coefficients of the rational maps of the isogeny from E' to the curve, from the
+    /// constant term up.
+    const ISOGENY_X_NUM: &'static [Self::BaseField];
+    const ISOGENY_X_DEN: &'static [Self::BaseField];
+    const ISOGENY_Y_NUM: &'static [Self::BaseField];
+    const ISOGENY_Y_DEN: &'static [Self::BaseField];
+}
+
+/// The simplified Shallue-van de Woestijne-Ulas map of RFC 9380 section 6.6.2 to E',
+/// followed by the isogeny to the curve. Both are evaluated in constant time, following the
+/// straight-line procedure of RFC 9380 appendix F.2.
+#[derive(Clone)]
+pub struct SSWUMap<P: SWUParameters> {
+    sqrt_ratio: SqrtRatio<P::BaseField>,
+}
+
+impl<P: SWUParameters> SSWUMap<P> {
+    pub fn new() -> Self {
+        SSWUMap {
+            sqrt_ratio: SqrtRatio::new(P::Z),
+        }
+    }
+}
+
+impl<P: SWUParameters> Default for SSWUMap<P> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<P: SWUParameters> MapToCurve<P> for SSWUMap<P> {
+    fn map_to_curve(&self, u: P::BaseField) -> Result<GroupAffine<P>, BLSError> {
+        let a = P::ISOGENOUS_COEFF_A;
+        let b = P::ISOGENOUS_COEFF_B;
+
+        let mut tv1 = u.square();
+        tv1 *= P::Z;
+        let mut tv2 = tv1.square();
+        tv2 += tv1;
+        let mut tv3 = tv2 + P::BaseField::one();
+        tv3 *= b;
+        let tv2_is_zero = ct_eq(&tv2, &P::BaseField::zero())?;
+        let mut tv4 = cmov(&-tv2, &P::Z, tv2_is_zero);
+        tv4 *= a;
+        tv2 = tv3.square();
+        let mut tv6 = tv4.square();
+        let mut tv5 = tv6 * a;
+        tv2 += tv5;
+        tv2 *= tv3;
+        tv6 *= tv4;
+        tv5 = tv6 * b;
+        tv2 += tv5;
+        let mut x = tv1 * tv3;
+        let (is_gx1_square, y1) = self.sqrt_ratio.sqrt_ratio(&tv2, &tv6)?;
+        let mut y = tv1 * u;
+        y *= y1;
+        x = cmov(&x, &tv3, is_gx1_square);
+        y = cmov(&y, &y1, is_gx1_square);
+        let e1 = Choice::from(u8::from(sgn0(&u)? == sgn0(&y)?));
+        y = cmov(&-y, &y, e1);
+        // tv4 is not zero as neither A' nor Z is
+        x *= inv0(&tv4);
+
+        isogeny_map::<P>(x, y)
+    }
+}
+
+/// Maps the point of E' to the curve, the kernel of the isogeny is mapped to the identity.
+fn isogeny_map<P: SWUParameters>(
+    x: P::BaseField,
+    y: P::BaseField,
+) -> Result<GroupAffine<P>, BLSError> {
+    let x_den = evaluate(P::ISOGENY_X_DEN, &x);
+    let y_den = evaluate(P::ISOGENY_Y_DEN, &x);
+    let zero = P::BaseField::zero();
+    let is_identity = ct_eq(&x_den, &zero)? | ct_eq(&y_den, &zero)?;
+
+    let x_mapped = evaluate(P::ISOGENY_X_NUM, &x) * inv0(&x_den);
+    let y_mapped = y * evaluate(P::ISOGENY_Y_NUM, &x) * inv0(&y_den);
+
+    Ok(GroupAffine::new(
+        cmov(&x_mapped, &zero, is_identity),
+        cmov(&y_mapped, &P::BaseField::one(), is_identity),
+        is_identity.into(),
+    ))
+}
+
+/// Evaluates the polynomial with the coefficients from the constant term up at x.
+fn evaluate<F: Field>(coeffs: &[F], x: &F) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::zero(), |acc, coeff| acc * x + coeff)
+}
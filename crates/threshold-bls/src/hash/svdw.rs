@@ -0,0 +1,106 @@
+use super::map_to_curve::{
+    cmov, curve_rhs, inv0, sgn0, HashToCurveParameters, MapToCurve, SqrtRatio,
+};
+use crate::curve::BLSError;
+use ark_ec::models::short_weierstrass_jacobian::GroupAffine;
+use ark_ff::{Field, One, SquareRootField, Zero};
+use subtle::Choice;
+
+/// Parameters of the Shallue-van de Woestijne map, which works for any Weierstrass curve,
+/// see RFC 9380 section 6.6.1.
+pub trait SVDWParameters: HashToCurveParameters {
+    /// The Z of the map, found by the find_z_svdw procedure of RFC 9380 appendix H.1.
+    const Z: Self::BaseField;
+}
+
+/// The Shallue-van de Woestijne map of RFC 9380 section 6.6.1, evaluated in constant time
+/// following the straight-line procedure of RFC 9380 appendix F.1.
+#[derive(Clone)]
+pub struct SVDWMap<P: SVDWParameters> {
+    c1: P::BaseField,
+    c2: P::BaseField,
+    c3: P::BaseField,
+    c4: P::BaseField,
+    sqrt_ratio: SqrtRatio<P::BaseField>,
+}
+
+impl<P: SVDWParameters> SVDWMap<P> {
+    /// Precomputes the constants of the map from Z.
+    pub fn new() -> Result<Self, BLSError> {
+        let z = P::Z;
+        let g_z = curve_rhs::<P>(&z);
+        let three_z2_four_a = z.square() * P::BaseField::from(3u64) + P::mul_by_a(&4u64.into());
+
+        // c1 = g(Z)
+        let c1 = g_z;
+        // c2 = -Z / 2
+        let c2 = -z / P::BaseField::from(2u64);
+        // c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A)) with sgn0(c3) = 0
+        let mut c3 = (-g_z * three_z2_four_a)
+            .sqrt()
+            .ok_or(BLSError::HashToCurveError)?;
+        if sgn0(&c3)? {
+            c3 = -c3;
+        }
+        // c4 = -4 * g(Z) / (3 * Z^2 + 4 * A)
+        let c4 = -g_z
+            * P::BaseField::from(4u64)
+            * three_z2_four_a
+                .inverse()
+                .ok_or(BLSError::HashToCurveError)?;
+
+        Ok(SVDWMap {
+            c1,
+            c2,
+            c3,
+            c4,
+            sqrt_ratio: SqrtRatio::new(non_square::<P::BaseField>()?),
+        })
+    }
+}
+
+/// The first non-square of the form k + I, or k over a prime field, for k = 1, 2, ... Z of the
+/// SVDW map may be a square, while sqrt_ratio needs a non-square.
+fn non_square<F: SquareRootField>() -> Result<F, BLSError> {
+    let mut elems = vec![F::BasePrimeField::zero(); F::extension_degree() as usize];
+    if let Some(i) = elems.get_mut(1) {
+        *i = F::BasePrimeField::one();
+    }
+
+    for k in 1..=u64::from(u8::MAX) {
+        elems[0] = k.into();
+        let candidate = F::from_base_prime_field_elems(&elems).ok_or(BLSError::HashToCurveError)?;
+        if !candidate.is_zero() && !candidate.legendre().is_qr() {
+            return Ok(candidate);
+        }
+    }
+
+    Err(BLSError::HashToCurveError)
+}
+
+impl<P: SVDWParameters> MapToCurve<P> for SVDWMap<P> {
+    fn map_to_curve(&self, u: P::BaseField) -> Result<GroupAffine<P>, BLSError> {
+        let one = P::BaseField::one();
+
+        let mut tv1 = u.square() * self.c1;
+        let tv2 = one + tv1;
+        tv1 = one - tv1;
+        let tv3 = inv0(&(tv1 * tv2));
+        let tv4 = u * tv1 * tv3 * self.c3;
+
+        let x1 = self.c2 - tv4;
+        let e1 = self.sqrt_ratio.is_square(&curve_rhs::<P>(&x1))?;
+        let x2 = self.c2 + tv4;
+        let e2 = self.sqrt_ratio.is_square(&curve_rhs::<P>(&x2))? & !e1;
+        let x3 = (tv2.square() * tv3).square() * self.c4 + P::Z;
+
+        // the first candidate on the curve, x3 always is if neither x1 nor x2 is
+        let mut x = cmov(&x3, &x1, e1);
+        x = cmov(&x, &x2, e2);
+
+        let y = self.sqrt_ratio.sqrt(&curve_rhs::<P>(&x))?;
+        let e3 = Choice::from(u8::from(sgn0(&u)? == sgn0(&y)?));
+
+        Ok(GroupAffine::new(x, cmov(&-y, &y, e3), false))
+    }
+}
@@ -190,7 +190,7 @@ pub mod serialize;
 
 /// Pre-instantiated signature schemes for each curve
 pub mod schemes {
-    use crate::sig::{G1Scheme, G2Scheme};
+    use crate::sig::{G1Scheme, G2Scheme, RFC9380Hash};
 
     #[cfg(feature = "bls12_381")]
     /// BLS12-381 Schemes
@@ -202,6 +202,10 @@ pub mod schemes {
         pub type G1Scheme = super::G1Scheme<PairingCurve>;
         /// Public Keys on G2, Signatures on G1
         pub type G2Scheme = super::G2Scheme<PairingCurve>;
+        /// Public Keys on G1, Signatures on G2 hashed with the RFC 9380 suite
+        pub type G1SchemeRFC9380 = super::G1Scheme<PairingCurve, super::RFC9380Hash>;
+        /// Public Keys on G2, Signatures on G1 hashed with the RFC 9380 suite
+        pub type G2SchemeRFC9380 = super::G2Scheme<PairingCurve, super::RFC9380Hash>;
     }
 
     #[cfg(feature = "bn254")]
@@ -214,6 +218,10 @@ pub mod schemes {
         pub type G1Scheme = super::G1Scheme<PairingCurve>;
         /// Public Keys on G2, Signatures on G1
         pub type G2Scheme = super::G2Scheme<PairingCurve>;
+        /// Public Keys on G1, Signatures on G2 hashed with the RFC 9380 suite
+        pub type G1SchemeRFC9380 = super::G1Scheme<PairingCurve, super::RFC9380Hash>;
+        /// Public Keys on G2, Signatures on G1 hashed with the RFC 9380 suite
+        pub type G2SchemeRFC9380 = super::G2Scheme<PairingCurve, super::RFC9380Hash>;
     }
 }
 
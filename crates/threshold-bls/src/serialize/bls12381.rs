@@ -0,0 +1,151 @@
+//! The contract form of the BLS12-381 types is the encoding of the EIP-2537 precompiles:
+//!
+//! - a base field element is a 64-byte big-endian integer, i.e. 16 zero bytes followed by
+//!   the 48-byte value, which must be reduced modulo p
+//! - an Fp2 element c0 + c1 * u is the encoding of c0 followed by the encoding of c1
+//! - a G1 point is x followed by y (128 bytes), a G2 point is x followed by y (256 bytes),
+//!   the point at infinity is all zeros
+//! - a scalar is a 32-byte big-endian integer
+//!
+//! Points are checked to be on the curve and in the prime-order subgroup when deserialized.
+use super::{affine_from_be_words, affine_to_be_words, ContractSerialize};
+use crate::curve::{bls12381, BLSError};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+
+/// Length in bytes of a base field element in EIP-2537.
+pub const EIP2537_FIELD_ELEMENT_LEN: usize = 64;
+
+/// Length in bytes of a scalar in EIP-2537.
+pub const EIP2537_SCALAR_LEN: usize = 32;
+
+impl ContractSerialize for bls12381::G1 {
+    fn serialize_to_contract_form(&self) -> Result<Vec<u8>, BLSError> {
+        affine_to_be_words(&self.0.into_affine(), EIP2537_FIELD_ELEMENT_LEN)
+    }
+
+    fn deserialize_from_contract_form(bytes: &[u8]) -> Result<Self, BLSError> {
+        let affine = affine_from_be_words::<ark_bls12_381::g1::Parameters>(
+            bytes,
+            EIP2537_FIELD_ELEMENT_LEN,
+        )?;
+
+        Ok(bls12381::G1(affine.into_projective()))
+    }
+}
+
+impl ContractSerialize for bls12381::G2 {
+    fn serialize_to_contract_form(&self) -> Result<Vec<u8>, BLSError> {
+        affine_to_be_words(&self.0.into_affine(), EIP2537_FIELD_ELEMENT_LEN)
+    }
+
+    fn deserialize_from_contract_form(bytes: &[u8]) -> Result<Self, BLSError> {
+        let affine = affine_from_be_words::<ark_bls12_381::g2::Parameters>(
+            bytes,
+            EIP2537_FIELD_ELEMENT_LEN,
+        )?;
+
+        Ok(bls12381::G2(affine.into_projective()))
+    }
+}
+
+impl ContractSerialize for bls12381::Scalar {
+    fn serialize_to_contract_form(&self) -> Result<Vec<u8>, BLSError> {
+        let mut bytes = vec![];
+        self.0.serialize(&mut bytes)?;
+
+        bytes.reverse();
+
+        Ok(bytes)
+    }
+
+    /// The precompiles accept any 32-byte integer as a scalar, so it is reduced modulo r.
+    fn deserialize_from_contract_form(bytes: &[u8]) -> Result<Self, BLSError> {
+        if bytes.len() != EIP2537_SCALAR_LEN {
+            return Err(BLSError::ContractSerializationError);
+        }
+
+        Ok(bls12381::Scalar(
+            ark_bls12_381::Fr::from_be_bytes_mod_order(bytes),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EIP2537_FIELD_ELEMENT_LEN;
+    use crate::curve::bls12381::{Scalar, G1, G2};
+    use crate::curve::BLSError;
+    use crate::group::Element;
+    use crate::serialize::ContractSerialize;
+    use ethers_core::utils::hex;
+    use rand::thread_rng;
+
+    // the generator of G1 of the EIP-2537 specification
+    const G1_GENERATOR: &str = "0000000000000000000000000000000017f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb0000000000000000000000000000000008b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+
+    #[test]
+    fn test_serialize_bls12381_g1_generator() {
+        let bytes = G1::one().serialize_to_contract_form().unwrap();
+        assert_eq!(hex::encode(&bytes), G1_GENERATOR);
+
+        let g1 = G1::deserialize_from_contract_form(&bytes).unwrap();
+        assert_eq!(g1, G1::one());
+    }
+
+    #[test]
+    fn test_serialize_bls12381_elements() {
+        for _ in 0..10 {
+            let g1 = G1::rand(&mut thread_rng());
+            let bytes = g1.serialize_to_contract_form().unwrap();
+            assert_eq!(bytes.len(), 2 * EIP2537_FIELD_ELEMENT_LEN);
+            assert_eq!(G1::deserialize_from_contract_form(&bytes).unwrap(), g1);
+
+            let g2 = G2::rand(&mut thread_rng());
+            let bytes = g2.serialize_to_contract_form().unwrap();
+            assert_eq!(bytes.len(), 4 * EIP2537_FIELD_ELEMENT_LEN);
+            assert_eq!(G2::deserialize_from_contract_form(&bytes).unwrap(), g2);
+
+            let scalar = Scalar::rand(&mut thread_rng());
+            let bytes = scalar.serialize_to_contract_form().unwrap();
+            assert_eq!(
+                Scalar::deserialize_from_contract_form(&bytes).unwrap(),
+                scalar
+            );
+        }
+    }
+
+    #[test]
+    fn test_serialize_bls12381_infinity() {
+        let bytes = G2::new().serialize_to_contract_form().unwrap();
+        assert!(bytes.iter().all(|b| *b == 0));
+        assert_eq!(
+            G2::deserialize_from_contract_form(&bytes).unwrap(),
+            G2::new()
+        );
+    }
+
+    #[test]
+    fn test_deserialize_bls12381_invalid_elements() {
+        let mut bytes = G1::one().serialize_to_contract_form().unwrap();
+
+        // wrong length
+        assert!(G1::deserialize_from_contract_form(&bytes[1..]).is_err());
+
+        // non-zero padding
+        bytes[0] = 1;
+        assert!(matches!(
+            G1::deserialize_from_contract_form(&bytes),
+            Err(BLSError::ContractSerializationError)
+        ));
+
+        // not on the curve
+        bytes[0] = 0;
+        bytes[127] ^= 1;
+        assert!(matches!(
+            G1::deserialize_from_contract_form(&bytes),
+            Err(BLSError::NotValidPoint)
+        ));
+    }
+}
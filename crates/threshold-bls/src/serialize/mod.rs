@@ -1,11 +1,21 @@
 use crate::curve::{bn254, BLSError};
 use crate::group::{Point, Scalar};
+use ark_ec::models::{short_weierstrass_jacobian::GroupAffine, SWModelParameters};
 use ark_ec::{AffineCurve, ModelParameters, ProjectiveCurve};
-use ark_ff::Field;
 use ark_ff::PrimeField;
+use ark_ff::{Field, Zero};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use ethers_core::utils::hex;
 
+/// EIP-2537 encoding of the BLS12-381 types for the precompiles
+#[cfg(feature = "bls12_381")]
+pub mod bls12381;
+
+/// Versioned point encoding shared by the curves
+pub mod versioned;
+
+pub use versioned::VersionedSerialize;
+
 pub trait ContractSerialize: Sized {
     /// Serialize the group element into a byte vector.
     fn serialize_to_contract_form(&self) -> Result<Vec<u8>, BLSError>;
@@ -95,6 +105,100 @@ impl ContractSerialize for bn254::G2 {
     }
 }
 
+/// Encodes the coordinates of the field element over its base prime field as big-endian
+/// integers, c0 first, each left-padded with zeros to `word_len` bytes.
+pub(crate) fn field_to_be_words<F: Field>(f: &F, word_len: usize) -> Result<Vec<u8>, BLSError> {
+    let mut bytes = vec![];
+    f.serialize(&mut bytes)?;
+
+    // coordinates are serialized in order as canonical little-endian integers
+    let coordinate_len = bytes.len() / F::extension_degree() as usize;
+
+    Ok(bytes
+        .chunks(coordinate_len)
+        .flat_map(|coordinate| {
+            let mut word = vec![0u8; word_len - coordinate_len];
+            word.extend(coordinate.iter().rev());
+            word
+        })
+        .collect())
+}
+
+/// Decodes a field element encoded by `field_to_be_words`, rejecting non-zero padding and
+/// coordinates which are not reduced modulo the base prime field.
+pub(crate) fn field_from_be_words<F: Field>(bytes: &[u8], word_len: usize) -> Result<F, BLSError> {
+    let coordinate_len = F::BasePrimeField::zero().serialized_size();
+    if bytes.len() != word_len * F::extension_degree() as usize {
+        return Err(BLSError::ContractSerializationError);
+    }
+
+    let coordinates = bytes
+        .chunks(word_len)
+        .map(|word| {
+            let (padding, coordinate) = word.split_at(word_len - coordinate_len);
+            if padding.iter().any(|b| *b != 0) {
+                return Err(BLSError::ContractSerializationError);
+            }
+
+            let mut coordinate = coordinate.to_vec();
+            coordinate.reverse();
+
+            F::BasePrimeField::deserialize(&mut &coordinate[..])
+                .map_err(|_| BLSError::ContractSerializationError)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    F::from_base_prime_field_elems(&coordinates).ok_or(BLSError::ContractSerializationError)
+}
+
+/// Encodes the point as x followed by y with `field_to_be_words`, the point at infinity
+/// is encoded as all zeros.
+pub(crate) fn affine_to_be_words<P: SWModelParameters>(
+    p: &GroupAffine<P>,
+    word_len: usize,
+) -> Result<Vec<u8>, BLSError> {
+    if p.infinity {
+        return Ok(vec![
+            0u8;
+            2 * word_len * P::BaseField::extension_degree() as usize
+        ]);
+    }
+
+    Ok([
+        field_to_be_words(&p.x, word_len)?,
+        field_to_be_words(&p.y, word_len)?,
+    ]
+    .concat())
+}
+
+/// Decodes a point encoded by `affine_to_be_words`, which must be on the curve and in the
+/// prime-order subgroup.
+pub(crate) fn affine_from_be_words<P: SWModelParameters>(
+    bytes: &[u8],
+    word_len: usize,
+) -> Result<GroupAffine<P>, BLSError> {
+    if bytes.len() != 2 * word_len * P::BaseField::extension_degree() as usize {
+        return Err(BLSError::ContractSerializationError);
+    }
+
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(GroupAffine::zero());
+    }
+
+    let (x, y) = bytes.split_at(bytes.len() / 2);
+    let p = GroupAffine::new(
+        field_from_be_words(x, word_len)?,
+        field_from_be_words(y, word_len)?,
+        false,
+    );
+
+    if !p.is_on_curve() || !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(BLSError::NotValidPoint);
+    }
+
+    Ok(p)
+}
+
 pub fn scalar_to_hex<S: Scalar>(s: &S) -> String {
     let bytes = bincode::serialize(s).unwrap();
     format!("0x{}", hex::encode(bytes))
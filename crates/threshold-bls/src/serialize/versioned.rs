@@ -0,0 +1,310 @@
+//! A self-describing point encoding for exchanging keys and signatures with other
+//! implementations. Version 1 is laid out as:
+//!
+//! | offset | length | content                                         |
+//! |--------|--------|-------------------------------------------------|
+//! | 0      | 1      | version, `0x01`                                 |
+//! | 1      | 1      | curve, `0x01` BN254 or `0x02` BLS12-381         |
+//! | 2      | 1      | group, `0x01` G1 or `0x02` G2                   |
+//! | 3      | 1      | format, `0x00` uncompressed or `0x01` compressed |
+//! | 4      | -      | payload                                         |
+//!
+//! Base field elements are big-endian integers reduced modulo p, an Fp2 element
+//! c0 + c1 * u is the encoding of c0 followed by the encoding of c1.
+//!
+//! The uncompressed payload is x followed by y, or all zeros for the point at infinity.
+//! Field elements take 32 bytes on BN254 and 64 bytes on BLS12-381, where they are padded
+//! with 16 zero bytes, so the payload is the input of the EIP-196/197 (BN254 G1) and
+//! EIP-2537 (BLS12-381) precompiles.
+//!
+//! The compressed payload is x alone with field elements of 32 bytes on BN254 and 48 bytes
+//! on BLS12-381. The two most significant bits of its first byte are flags: `0x80` marks
+//! the point at infinity, whose payload is zero otherwise, and `0x40` marks that y is the
+//! lexicographically larger of y and -y as integers (comparing c1 first for Fp2).
+//!
+//! Decoded points are checked to be on the curve and in the prime-order subgroup.
+use super::{affine_from_be_words, affine_to_be_words, field_from_be_words, field_to_be_words};
+use crate::curve::BLSError;
+use ark_ec::models::{
+    short_weierstrass_jacobian::{GroupAffine, GroupProjective},
+    SWModelParameters,
+};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, Zero};
+use ark_serialize::CanonicalSerialize;
+
+/// The current version of the encoding.
+pub const VERSION: u8 = 1;
+
+/// Length in bytes of the header preceding the payload.
+pub const HEADER_LEN: usize = 4;
+
+pub const CURVE_BN254: u8 = 1;
+pub const CURVE_BLS12_381: u8 = 2;
+
+pub const GROUP_G1: u8 = 1;
+pub const GROUP_G2: u8 = 2;
+
+pub const FORMAT_UNCOMPRESSED: u8 = 0;
+pub const FORMAT_COMPRESSED: u8 = 1;
+
+/// Flag of the compressed payload for the point at infinity.
+const INFINITY_FLAG: u8 = 0x80;
+
+/// Flag of the compressed payload for the larger y.
+const GREATEST_Y_FLAG: u8 = 0x40;
+
+pub trait VersionedSerialize: Sized {
+    /// Serialize the group element into the current version of the encoding.
+    fn serialize_to_versioned_form(&self, compressed: bool) -> Result<Vec<u8>, BLSError>;
+    /// Deserialize the group element from any supported version of the encoding.
+    fn deserialize_from_versioned_form(bytes: &[u8]) -> Result<Self, BLSError>;
+}
+
+/// Describes where a point type sits in the encoding.
+struct Encoding {
+    curve: u8,
+    group: u8,
+    /// Length in bytes of a base prime field element in the uncompressed payload.
+    uncompressed_word_len: usize,
+}
+
+fn serialize<P: SWModelParameters>(
+    p: &GroupProjective<P>,
+    encoding: &Encoding,
+    compressed: bool,
+) -> Result<Vec<u8>, BLSError> {
+    let p = p.into_affine();
+
+    let (format, payload) = if compressed {
+        (FORMAT_COMPRESSED, compress(&p)?)
+    } else {
+        (
+            FORMAT_UNCOMPRESSED,
+            affine_to_be_words(&p, encoding.uncompressed_word_len)?,
+        )
+    };
+
+    Ok([
+        &[VERSION, encoding.curve, encoding.group, format][..],
+        &payload,
+    ]
+    .concat())
+}
+
+fn deserialize<P: SWModelParameters>(
+    bytes: &[u8],
+    encoding: &Encoding,
+) -> Result<GroupProjective<P>, BLSError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(BLSError::ContractSerializationError);
+    }
+    let (header, payload) = bytes.split_at(HEADER_LEN);
+
+    if header[0] != VERSION {
+        return Err(BLSError::UnsupportedEncodingVersion(header[0]));
+    }
+    if header[1] != encoding.curve || header[2] != encoding.group {
+        return Err(BLSError::ContractSerializationError);
+    }
+
+    let p = match header[3] {
+        FORMAT_UNCOMPRESSED => affine_from_be_words(payload, encoding.uncompressed_word_len)?,
+        FORMAT_COMPRESSED => decompress(payload)?,
+        _ => return Err(BLSError::ContractSerializationError),
+    };
+
+    Ok(p.into_projective())
+}
+
+/// Length in bytes of a base prime field element without padding.
+fn coordinate_len<P: SWModelParameters>() -> usize {
+    <P::BaseField as Field>::BasePrimeField::zero().serialized_size()
+}
+
+fn compress<P: SWModelParameters>(p: &GroupAffine<P>) -> Result<Vec<u8>, BLSError> {
+    let coordinate_len = coordinate_len::<P>();
+
+    if p.infinity {
+        let mut bytes = vec![0u8; coordinate_len * P::BaseField::extension_degree() as usize];
+        bytes[0] |= INFINITY_FLAG;
+        return Ok(bytes);
+    }
+
+    let mut bytes = field_to_be_words(&p.x, coordinate_len)?;
+    if p.y > -p.y {
+        bytes[0] |= GREATEST_Y_FLAG;
+    }
+
+    Ok(bytes)
+}
+
+fn decompress<P: SWModelParameters>(bytes: &[u8]) -> Result<GroupAffine<P>, BLSError> {
+    if bytes.is_empty() {
+        return Err(BLSError::ContractSerializationError);
+    }
+
+    let flags = bytes[0] & (INFINITY_FLAG | GREATEST_Y_FLAG);
+    let mut x_bytes = bytes.to_vec();
+    x_bytes[0] &= !flags;
+
+    if flags & INFINITY_FLAG != 0 {
+        if flags & GREATEST_Y_FLAG != 0 || x_bytes.iter().any(|b| *b != 0) {
+            return Err(BLSError::ContractSerializationError);
+        }
+        return Ok(GroupAffine::zero());
+    }
+
+    let x = field_from_be_words(&x_bytes, coordinate_len::<P>())?;
+    let p = GroupAffine::<P>::get_point_from_x(x, flags & GREATEST_Y_FLAG != 0)
+        .ok_or(BLSError::NotValidPoint)?;
+
+    if !p.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(BLSError::NotValidPoint);
+    }
+
+    Ok(p)
+}
+
+macro_rules! impl_versioned_serialize {
+    ($point:ty, $params:ty, $curve:expr, $group:expr, $word_len:expr) => {
+        impl VersionedSerialize for $point {
+            fn serialize_to_versioned_form(&self, compressed: bool) -> Result<Vec<u8>, BLSError> {
+                serialize(
+                    &self.0,
+                    &Encoding {
+                        curve: $curve,
+                        group: $group,
+                        uncompressed_word_len: $word_len,
+                    },
+                    compressed,
+                )
+            }
+
+            fn deserialize_from_versioned_form(bytes: &[u8]) -> Result<Self, BLSError> {
+                deserialize::<$params>(
+                    bytes,
+                    &Encoding {
+                        curve: $curve,
+                        group: $group,
+                        uncompressed_word_len: $word_len,
+                    },
+                )
+                .map(Self)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "bn254")]
+mod bn254 {
+    use super::*;
+    use crate::curve::bn254::{G1, G2};
+    use ark_bn254::{g1, g2};
+
+    impl_versioned_serialize!(G1, g1::Parameters, CURVE_BN254, GROUP_G1, 32);
+    impl_versioned_serialize!(G2, g2::Parameters, CURVE_BN254, GROUP_G2, 32);
+}
+
+#[cfg(feature = "bls12_381")]
+mod bls12381 {
+    use super::*;
+    use crate::curve::bls12381::{G1, G2};
+    use crate::serialize::bls12381::EIP2537_FIELD_ELEMENT_LEN;
+    use ark_bls12_381::{g1, g2};
+
+    impl_versioned_serialize!(
+        G1,
+        g1::Parameters,
+        CURVE_BLS12_381,
+        GROUP_G1,
+        EIP2537_FIELD_ELEMENT_LEN
+    );
+    impl_versioned_serialize!(
+        G2,
+        g2::Parameters,
+        CURVE_BLS12_381,
+        GROUP_G2,
+        EIP2537_FIELD_ELEMENT_LEN
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::{bls12381, bn254};
+    use crate::group::Element;
+    use crate::serialize::ContractSerialize;
+    use rand::thread_rng;
+    use std::fmt::Debug;
+
+    fn roundtrip<P: VersionedSerialize + Element + Debug>(compressed_len: usize) {
+        for p in (0..10)
+            .map(|_| P::rand(&mut thread_rng()))
+            .chain([P::new(), P::one()])
+        {
+            let bytes = p.serialize_to_versioned_form(true).unwrap();
+            assert_eq!(bytes.len(), HEADER_LEN + compressed_len);
+            assert_eq!(P::deserialize_from_versioned_form(&bytes).unwrap(), p);
+
+            let bytes = p.serialize_to_versioned_form(false).unwrap();
+            assert_eq!(P::deserialize_from_versioned_form(&bytes).unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn test_versioned_form_roundtrip() {
+        roundtrip::<bn254::G1>(32);
+        roundtrip::<bn254::G2>(64);
+        roundtrip::<bls12381::G1>(48);
+        roundtrip::<bls12381::G2>(96);
+    }
+
+    #[test]
+    fn test_versioned_form_payload_is_contract_form() {
+        let p = bls12381::G2::rand(&mut thread_rng());
+        let bytes = p.serialize_to_versioned_form(false).unwrap();
+
+        assert_eq!(
+            bytes[..HEADER_LEN],
+            [VERSION, CURVE_BLS12_381, GROUP_G2, FORMAT_UNCOMPRESSED]
+        );
+        assert_eq!(
+            bytes[HEADER_LEN..],
+            p.serialize_to_contract_form().unwrap()[..]
+        );
+    }
+
+    #[test]
+    fn test_versioned_form_rejects_mismatched_header() {
+        let bytes = bn254::G1::one().serialize_to_versioned_form(true).unwrap();
+
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = VERSION + 1;
+        assert!(matches!(
+            bn254::G1::deserialize_from_versioned_form(&unknown_version),
+            Err(BLSError::UnsupportedEncodingVersion(_))
+        ));
+
+        // a point of another group of the same size
+        assert!(bls12381::G1::deserialize_from_versioned_form(&bytes).is_err());
+
+        let mut unknown_format = bytes;
+        unknown_format[3] = 2;
+        assert!(bn254::G1::deserialize_from_versioned_form(&unknown_format).is_err());
+    }
+
+    #[test]
+    fn test_versioned_form_rejects_point_outside_subgroup() {
+        // x = 1 does not give a point of G2
+        let mut bytes = vec![VERSION, CURVE_BN254, GROUP_G2, FORMAT_COMPRESSED];
+        let mut x = vec![0u8; 64];
+        x[31] = 1;
+        bytes.extend(x);
+
+        assert!(matches!(
+            bn254::G2::deserialize_from_versioned_form(&bytes),
+            Err(BLSError::NotValidPoint)
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::group::{Element, Point, Scalar};
+use crate::group::{Element, Scalar};
 use crate::sig::bls::{common::BLSScheme, BLSError};
 use crate::sig::{BlindScheme, Scheme};
 use rand_core::RngCore;
@@ -55,11 +55,9 @@ where
     fn blind_msg<R: RngCore>(msg: &[u8], rng: &mut R) -> (Self::Token, Vec<u8>) {
         let r = I::Private::rand(rng);
 
-        let mut h = I::Signature::new();
-
         // r * H(m)
         // XXX result from zexe API but it shouldn't
-        h.map(msg).expect("could not map to the group");
+        let mut h = I::hash_msg(msg).expect("could not map to the group");
         h.mul(&r);
 
         let serialized = bincode::serialize(&h).expect("serialization should not fail");
@@ -1,5 +1,6 @@
-use crate::group::{Element, PairingCurve, Point};
+use crate::group::{Element, PairingCurve, Point, Scalar};
 use crate::sig::{Scheme, SignatureScheme};
+use rand_core::RngCore;
 use std::{fmt::Debug, marker::PhantomData};
 use thiserror::Error;
 
@@ -14,6 +15,11 @@ pub enum BLSError {
     #[error("could not hash to curve")]
     HashingError,
 
+    /// UnevenBatch is raised when a batch has not the same number of public keys,
+    /// messages and signatures.
+    #[error("there must be the same number of public keys, messages and signatures")]
+    UnevenBatch,
+
     #[error("could not deserialize: {0}")]
     DeserializationError(#[from] bincode::Error),
 }
@@ -35,9 +41,7 @@ pub mod common {
             should_hash: bool,
         ) -> Result<Vec<u8>, BLSError> {
             let mut h = if should_hash {
-                let mut h = Self::Signature::new();
-                h.map(msg).map_err(|_| BLSError::HashingError)?;
-                h
+                Self::hash_msg(msg)?
             } else {
                 bincode::deserialize_from(msg)?
             };
@@ -57,9 +61,7 @@ pub mod common {
             let sig: Self::Signature = bincode::deserialize_from(sig_bytes)?;
 
             let h = if should_hash {
-                let mut h = Self::Signature::new();
-                h.map(msg).map_err(|_| BLSError::HashingError)?;
-                h
+                Self::hash_msg(msg)?
             } else {
                 bincode::deserialize_from(msg)?
             };
@@ -92,9 +94,7 @@ pub mod common {
             }
 
             let h = if should_hash {
-                let mut h = Self::Signature::new();
-                h.map(msg).map_err(|_| BLSError::HashingError)?;
-                h
+                Self::hash_msg(msg)?
             } else {
                 bincode::deserialize_from(msg)?
             };
@@ -107,8 +107,59 @@ pub mod common {
             Ok(())
         }
 
+        /// Verifies all (public, msg, sig) triples at once. Every signature and hashed
+        /// message is weighted by a random scalar, so that an invalid signature cannot be
+        /// cancelled out by another one in the batch.
+        fn internal_batch_verify<R: RngCore>(
+            publics: &[Self::Public],
+            msgs: &[&[u8]],
+            sigs: &[&[u8]],
+            should_hash: bool,
+            rng: &mut R,
+        ) -> Result<(), BLSError> {
+            if publics.len() != msgs.len() || publics.len() != sigs.len() {
+                return Err(BLSError::UnevenBatch);
+            }
+
+            let mut sig_aggregation = Self::Signature::zero();
+            let mut weighted_pairs = Vec::with_capacity(publics.len());
+            for ((public, msg), sig) in publics.iter().zip(msgs).zip(sigs) {
+                let mut sig: Self::Signature = bincode::deserialize_from(*sig)?;
+
+                let mut h = if should_hash {
+                    Self::hash_msg(msg)?
+                } else {
+                    bincode::deserialize_from(*msg)?
+                };
+
+                let r = Self::Private::rand(rng);
+                sig.mul(&r);
+                h.mul(&r);
+
+                sig_aggregation.add(&sig);
+                weighted_pairs.push((public.clone(), h));
+            }
+
+            let success = Self::batch_final_exp(&weighted_pairs, &sig_aggregation);
+            if !success {
+                return Err(BLSError::InvalidSig);
+            }
+
+            Ok(())
+        }
+
+        /// Hashes the message to the signature group
+        fn hash_msg(msg: &[u8]) -> Result<Self::Signature, BLSError>;
+
         /// Performs the final exponentiation for the BLS sig scheme
         fn final_exp(p: &Self::Public, sig: &Self::Signature, hm: &Self::Signature) -> bool;
+
+        /// Performs a single final exponentiation checking that the aggregated signature
+        /// matches all the (public, hashed message) pairs
+        fn batch_final_exp(
+            pairs: &[(Self::Public, Self::Signature)],
+            sig: &Self::Signature,
+        ) -> bool;
     }
 
     impl<T> SignatureScheme for T
@@ -142,29 +193,79 @@ pub mod common {
                 true,
             )
         }
+
+        fn batch_verify<R: RngCore>(
+            publics: &[Self::Public],
+            msgs: &[&[u8]],
+            sig_bytes: &[&[u8]],
+            rng: &mut R,
+        ) -> Result<(), Self::Error> {
+            T::internal_batch_verify(publics, msgs, sig_bytes, true, rng)
+        }
+    }
+}
+
+/// HashToGroup is the method a scheme hashes the messages to its signature group with.
+pub trait HashToGroup: Clone + Debug + Send + Sync {
+    fn hash_to_group<P: Point>(msg: &[u8]) -> Result<P, BLSError>;
+}
+
+/// Try-and-increment over keccak256, which is what the ARPA contracts verify.
+#[derive(Clone, Debug)]
+pub struct TryAndIncrementHash;
+
+impl HashToGroup for TryAndIncrementHash {
+    fn hash_to_group<P: Point>(msg: &[u8]) -> Result<P, BLSError> {
+        let mut h = P::new();
+        h.map(msg).map_err(|_| BLSError::HashingError)?;
+        Ok(h)
+    }
+}
+
+/// The RFC 9380 hash-to-curve suite of the signature group, under the DST of the basic
+/// scheme of the IETF BLS signature draft, e.g. `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_`,
+/// so the signatures interoperate with other BLS implementations.
+#[derive(Clone, Debug)]
+pub struct RFC9380Hash;
+
+impl HashToGroup for RFC9380Hash {
+    fn hash_to_group<P: Point>(msg: &[u8]) -> Result<P, BLSError> {
+        let dst = format!("BLS_SIG_{}NUL_", P::hash_to_curve_suite_id());
+
+        let mut h = P::new();
+        h.hash_to_curve(dst.as_bytes(), msg)
+            .map_err(|_| BLSError::HashingError)?;
+        Ok(h)
     }
 }
 
 /// G1Scheme implements the BLS signature scheme with G1 as private / public
-/// keys and G2 as signature elements over the given pairing curve.
+/// keys and G2 as signature elements over the given pairing curve. The messages
+/// are hashed to G2 with `H`.
 #[derive(Clone, Debug)]
-pub struct G1Scheme<C: PairingCurve> {
-    m: PhantomData<C>,
+pub struct G1Scheme<C: PairingCurve, H: HashToGroup = TryAndIncrementHash> {
+    m: PhantomData<(C, H)>,
 }
 
-impl<C> Scheme for G1Scheme<C>
+impl<C, H> Scheme for G1Scheme<C, H>
 where
     C: PairingCurve,
+    H: HashToGroup,
 {
     type Private = C::Scalar;
     type Public = C::G1;
     type Signature = C::G2;
 }
 
-impl<C> common::BLSScheme for G1Scheme<C>
+impl<C, H> common::BLSScheme for G1Scheme<C, H>
 where
     C: PairingCurve,
+    H: HashToGroup,
 {
+    fn hash_msg(msg: &[u8]) -> Result<Self::Signature, BLSError> {
+        H::hash_to_group(msg)
+    }
+
     fn final_exp(p: &Self::Public, sig: &Self::Signature, hm: &Self::Signature) -> bool {
         // e(g1,sig) == e(pub, H(m))
         // e(g1,H(m))^x == e(g1,H(m))^x
@@ -172,28 +273,45 @@ where
         let right = C::pair(p, hm);
         left == right
     }
+
+    fn batch_final_exp(pairs: &[(Self::Public, Self::Signature)], sig: &Self::Signature) -> bool {
+        // e(-g1,sig) * prod(e(pub_i, H(m_i))) == 1
+        let mut neg_g1 = C::G1::one();
+        neg_g1.mul(&minus_one::<C>());
+
+        let mut pairs = pairs.to_vec();
+        pairs.push((neg_g1, sig.clone()));
+        C::multi_pair(&pairs) == C::GT::one()
+    }
 }
 
 /// G2Scheme implements the BLS signature scheme with G2 as private / public
-/// keys and G1 as signature elements over the given pairing curve.
+/// keys and G1 as signature elements over the given pairing curve. The messages
+/// are hashed to G1 with `H`.
 #[derive(Clone, Debug)]
-pub struct G2Scheme<C: PairingCurve> {
-    m: PhantomData<C>,
+pub struct G2Scheme<C: PairingCurve, H: HashToGroup = TryAndIncrementHash> {
+    m: PhantomData<(C, H)>,
 }
 
-impl<C> Scheme for G2Scheme<C>
+impl<C, H> Scheme for G2Scheme<C, H>
 where
     C: PairingCurve,
+    H: HashToGroup,
 {
     type Private = C::Scalar;
     type Public = C::G2;
     type Signature = C::G1;
 }
 
-impl<C> common::BLSScheme for G2Scheme<C>
+impl<C, H> common::BLSScheme for G2Scheme<C, H>
 where
     C: PairingCurve,
+    H: HashToGroup,
 {
+    fn hash_msg(msg: &[u8]) -> Result<Self::Signature, BLSError> {
+        H::hash_to_group(msg)
+    }
+
     fn final_exp(p: &Self::Public, sig: &Self::Signature, hm: &Self::Signature) -> bool {
         // e(sig,g2) == e(H(m),pub)
         // e(H(m),g2)^x == e(H(m),g2)^x
@@ -201,6 +319,25 @@ where
         let right = C::pair(hm, p);
         left == right
     }
+
+    fn batch_final_exp(pairs: &[(Self::Public, Self::Signature)], sig: &Self::Signature) -> bool {
+        // e(-sig,g2) * prod(e(H(m_i), pub_i)) == 1
+        let mut neg_sig = sig.clone();
+        neg_sig.mul(&minus_one::<C>());
+
+        let mut pairs = pairs
+            .iter()
+            .map(|(p, hm)| (hm.clone(), p.clone()))
+            .collect::<Vec<_>>();
+        pairs.push((neg_sig, Self::Public::one()));
+        C::multi_pair(&pairs) == C::GT::one()
+    }
+}
+
+fn minus_one<C: PairingCurve>() -> C::Scalar {
+    let mut minus_one = C::Scalar::one();
+    minus_one.negate();
+    minus_one
 }
 
 #[cfg(feature = "bn254")]
@@ -233,4 +370,86 @@ mod tests {
         let sig = G1Scheme::<PCurve>::sign(&private, &msg).unwrap();
         G1Scheme::<PCurve>::verify(&public, &msg, &sig).expect("that should not happen");
     }
+
+    #[test]
+    fn nbls_rfc9380() {
+        let (private, public) = keypair::<G1Curve>();
+        let msg = vec![1, 9, 6, 9];
+        let sig = G1Scheme::<PCurve, RFC9380Hash>::sign(&private, &msg).unwrap();
+        G1Scheme::<PCurve, RFC9380Hash>::verify(&public, &msg, &sig)
+            .expect("that should not happen");
+        // the legacy hash maps the message to a different point
+        assert!(G1Scheme::<PCurve>::verify(&public, &msg, &sig).is_err());
+
+        let (private, public) = keypair::<G2Curve>();
+        let sig = G2Scheme::<PCurve, RFC9380Hash>::sign(&private, &msg).unwrap();
+        G2Scheme::<PCurve, RFC9380Hash>::verify(&public, &msg, &sig)
+            .expect("that should not happen");
+        assert!(G2Scheme::<PCurve>::verify(&public, &msg, &sig).is_err());
+    }
+
+    type Batch<S> = (Vec<<S as Scheme>::Public>, Vec<Vec<u8>>, Vec<Vec<u8>>);
+
+    fn batch<S: SignatureScheme>() -> Batch<S> {
+        (0..5u8)
+            .map(|i| {
+                let (private, public) = S::keypair(&mut thread_rng());
+                let msg = vec![1, 9, 6, 9, i];
+                let sig = S::sign(&private, &msg).unwrap();
+                (public, msg, sig)
+            })
+            .fold((vec![], vec![], vec![]), |mut acc, (public, msg, sig)| {
+                acc.0.push(public);
+                acc.1.push(msg);
+                acc.2.push(sig);
+                acc
+            })
+    }
+
+    fn test_batch_verify<S: SignatureScheme>() {
+        let (publics, msgs, mut sigs) = batch::<S>();
+        let msgs = msgs.iter().map(|msg| msg.as_slice()).collect::<Vec<_>>();
+
+        S::batch_verify(
+            &publics,
+            &msgs,
+            &sigs.iter().map(|sig| sig.as_slice()).collect::<Vec<_>>(),
+            &mut thread_rng(),
+        )
+        .expect("that should not happen");
+
+        // swapping two valid signatures invalidates the batch
+        sigs.swap(0, 1);
+        assert!(S::batch_verify(
+            &publics,
+            &msgs,
+            &sigs.iter().map(|sig| sig.as_slice()).collect::<Vec<_>>(),
+            &mut thread_rng(),
+        )
+        .is_err());
+
+        assert!(S::batch_verify(
+            &publics,
+            &msgs[1..],
+            &sigs.iter().map(|sig| sig.as_slice()).collect::<Vec<_>>(),
+            &mut thread_rng(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn nbls_g2_batch_verify() {
+        test_batch_verify::<G2Scheme<PCurve>>();
+    }
+
+    #[test]
+    fn nbls_g1_batch_verify() {
+        test_batch_verify::<G1Scheme<PCurve>>();
+    }
+
+    #[test]
+    fn nbls_rfc9380_batch_verify() {
+        test_batch_verify::<G1Scheme<PCurve, RFC9380Hash>>();
+        test_batch_verify::<G2Scheme<PCurve, RFC9380Hash>>();
+    }
 }
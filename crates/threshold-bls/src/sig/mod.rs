@@ -2,7 +2,7 @@ mod blind;
 pub use blind::{BlindError, Token};
 
 mod bls;
-pub use bls::{BLSError, G1Scheme, G2Scheme};
+pub use bls::{BLSError, G1Scheme, G2Scheme, HashToGroup, RFC9380Hash, TryAndIncrementHash};
 
 mod tblind;
 pub use tblind::BlindThresholdError;
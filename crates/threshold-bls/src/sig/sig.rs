@@ -69,6 +69,16 @@ pub trait SignatureScheme: Scheme {
         msg: &[u8],
         sig_bytes: &[&[u8]],
     ) -> Result<(), Self::Error>;
+
+    /// Verifies the signatures on the messages by the public keys at the same index with
+    /// a single multi-pairing. The check fails as a whole if any signature is invalid, so
+    /// the caller has to fall back to `verify` to find out which one.
+    fn batch_verify<R: RngCore>(
+        publics: &[Self::Public],
+        msgs: &[&[u8]],
+        sig_bytes: &[&[u8]],
+        rng: &mut R,
+    ) -> Result<(), Self::Error>;
 }
 
 /// BlindScheme is a signature scheme where the message can be blinded before
@@ -160,10 +170,32 @@ pub trait ThresholdScheme: Scheme {
         partial: &[u8],
     ) -> Result<(), Self::Error>;
 
+    /// Verifies partial signatures on the messages at the same index against the public
+    /// polynomial in one batch. Like `SignatureScheme::batch_verify`, a failure does not
+    /// tell which partial is invalid.
+    fn partial_verify_batch<R: RngCore>(
+        public: &Poly<Self::Public>,
+        msgs: &[&[u8]],
+        partials: &[&[u8]],
+        rng: &mut R,
+    ) -> Result<(), Self::Error>;
+
     /// Aggregates all partials signature together. Note that this method does
     /// not verify if the partial signatures are correct or not; it only
     /// aggregates them.
     fn aggregate(threshold: usize, partials: &[Partial]) -> Result<Vec<u8>, Self::Error>;
+
+    /// Verifies the partial signatures on the message against the public polynomial,
+    /// discards the invalid ones and aggregates the rest. Returns the signature along
+    /// with the positions in `partials` of the discarded partials, including the ones
+    /// which cannot be deserialized.
+    fn aggregate_verified<R: RngCore>(
+        threshold: usize,
+        public: &Poly<Self::Public>,
+        msg: &[u8],
+        partials: &[Partial],
+        rng: &mut R,
+    ) -> Result<(Vec<u8>, Vec<usize>), Self::Error>;
 }
 
 /// BlindThreshold is ThresholdScheme that allows to verify a partially blinded
@@ -2,6 +2,7 @@
 //! [`SignatureScheme`](../trait.SignatureScheme.html)
 use crate::poly::{Eval, Idx, Poly, PolyError};
 use crate::sig::{Partial, SignatureScheme, ThresholdScheme};
+use rand_core::RngCore;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -63,6 +64,29 @@ impl<I: SignatureScheme> ThresholdScheme for I {
         Self::verify(&public_i.value, msg, &partial.value).map_err(ThresholdError::SignatureError)
     }
 
+    fn partial_verify_batch<R: RngCore>(
+        public: &Poly<Self::Public>,
+        msgs: &[&[u8]],
+        partials: &[&[u8]],
+        rng: &mut R,
+    ) -> Result<(), <Self as ThresholdScheme>::Error> {
+        let partials = partials
+            .iter()
+            .map(|partial| bincode::deserialize(partial))
+            .collect::<Result<Vec<Eval<Vec<u8>>>, _>>()?;
+
+        let publics = partials
+            .iter()
+            .map(|partial| public.eval(partial.index).value)
+            .collect::<Vec<_>>();
+        let sigs = partials
+            .iter()
+            .map(|partial| partial.value.as_slice())
+            .collect::<Vec<_>>();
+
+        Self::batch_verify(&publics, msgs, &sigs, rng).map_err(ThresholdError::SignatureError)
+    }
+
     fn aggregate(
         threshold: usize,
         partials: &[Partial],
@@ -90,6 +114,55 @@ impl<I: SignatureScheme> ThresholdScheme for I {
             .map_err(ThresholdError::PolyError)?;
         Ok(bincode::serialize(&recovered_sig).expect("could not serialize"))
     }
+
+    fn aggregate_verified<R: RngCore>(
+        threshold: usize,
+        public: &Poly<Self::Public>,
+        msg: &[u8],
+        partials: &[Partial],
+        rng: &mut R,
+    ) -> Result<(Vec<u8>, Vec<usize>), <Self as ThresholdScheme>::Error> {
+        let (partials, malformed_partials): (Vec<_>, Vec<_>) = partials
+            .iter()
+            .enumerate()
+            .partition(|(_, partial)| bincode::deserialize::<Eval<Vec<u8>>>(partial).is_ok());
+
+        let all_valid = Self::partial_verify_batch(
+            public,
+            &vec![msg; partials.len()],
+            &partials
+                .iter()
+                .map(|(_, partial)| partial.as_slice())
+                .collect::<Vec<_>>(),
+            rng,
+        )
+        .is_ok();
+
+        // only look for the invalid partials one by one if the batch failed
+        let (valid_partials, invalid_partials): (Vec<_>, Vec<_>) = if all_valid {
+            (partials, vec![])
+        } else {
+            partials
+                .into_iter()
+                .partition(|(_, partial)| Self::partial_verify(public, msg, partial).is_ok())
+        };
+
+        let valid_partials = valid_partials
+            .into_iter()
+            .map(|(_, partial)| partial.clone())
+            .collect::<Vec<_>>();
+
+        let sig = Self::aggregate(threshold, &valid_partials)?;
+
+        let mut discarded_positions = malformed_partials
+            .into_iter()
+            .chain(invalid_partials)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        discarded_positions.sort_unstable();
+
+        Ok((sig, discarded_positions))
+    }
 }
 
 #[cfg(feature = "bls12_381")]
@@ -140,11 +213,86 @@ mod tests {
                 .iter()
                 .any(|p| T::partial_verify(&public, &msg, &p).is_err())
         );
+        T::partial_verify_batch(
+            &public,
+            &vec![msg.as_slice(); partials.len()],
+            &partials.iter().map(|p| p.as_slice()).collect::<Vec<_>>(),
+            &mut rand::thread_rng(),
+        )
+        .unwrap();
+
+        let mut forged_partials = partials.clone();
+        forged_partials[0] = T::partial_sign(&shares[0], &[2, 0, 2, 3]).unwrap();
+        assert!(T::partial_verify_batch(
+            &public,
+            &vec![msg.as_slice(); forged_partials.len()],
+            &forged_partials
+                .iter()
+                .map(|p| p.as_slice())
+                .collect::<Vec<_>>(),
+            &mut rand::thread_rng(),
+        )
+        .is_err());
+
         let final_sig = T::aggregate(threshold, &partials).unwrap();
 
         T::verify(public.public_key(), &msg, &final_sig).unwrap();
     }
 
+    fn test_aggregate_verified<T: ThresholdScheme + SignatureScheme>(creator: ShareCreator<T>) {
+        let threshold = 3;
+        let (shares, public) = creator(5, threshold);
+        let msg = vec![1, 9, 6, 9];
+
+        let mut partials: Vec<_> = shares
+            .iter()
+            .map(|s| T::partial_sign(s, &msg).unwrap())
+            .collect();
+
+        let (final_sig, discarded_positions) =
+            T::aggregate_verified(threshold, &public, &msg, &partials, &mut rand::thread_rng())
+                .unwrap();
+        T::verify(public.public_key(), &msg, &final_sig).unwrap();
+        assert!(discarded_positions.is_empty());
+
+        // two partials signed on another message are discarded and reported
+        partials[0] = T::partial_sign(&shares[0], &[2, 0, 2, 3]).unwrap();
+        partials[3] = T::partial_sign(&shares[3], &[2, 0, 2, 3]).unwrap();
+
+        let (final_sig, discarded_positions) =
+            T::aggregate_verified(threshold, &public, &msg, &partials, &mut rand::thread_rng())
+                .unwrap();
+        T::verify(public.public_key(), &msg, &final_sig).unwrap();
+        assert_eq!(vec![0, 3], discarded_positions);
+
+        // a partial which cannot be deserialized is reported as well
+        let mut malformed_partials = partials.clone();
+        malformed_partials.push(vec![1, 2, 3]);
+
+        let (final_sig, discarded_positions) = T::aggregate_verified(
+            threshold,
+            &public,
+            &msg,
+            &malformed_partials,
+            &mut rand::thread_rng(),
+        )
+        .unwrap();
+        T::verify(public.public_key(), &msg, &final_sig).unwrap();
+        assert_eq!(vec![0, 3, 5], discarded_positions);
+
+        // not enough valid partials are left
+        partials[1] = T::partial_sign(&shares[1], &[2, 0, 2, 3]).unwrap();
+
+        assert!(T::aggregate_verified(
+            threshold,
+            &public,
+            &msg,
+            &partials,
+            &mut rand::thread_rng()
+        )
+        .is_err());
+    }
+
     #[test]
     fn threshold_g1() {
         type S = G1Scheme<PCurve>;
@@ -156,4 +304,16 @@ mod tests {
         type S = G2Scheme<PCurve>;
         test_threshold_scheme::<S>(shares::<S>);
     }
+
+    #[test]
+    fn aggregate_verified_g1() {
+        type S = G1Scheme<PCurve>;
+        test_aggregate_verified::<S>(shares::<S>);
+    }
+
+    #[test]
+    fn aggregate_verified_g2() {
+        type S = G2Scheme<PCurve>;
+        test_aggregate_verified::<S>(shares::<S>);
+    }
 }
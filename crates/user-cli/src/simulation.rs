@@ -0,0 +1,274 @@
+use ethers::abi::token::{LenientTokenizer, Tokenizer};
+use ethers::abi::AbiParser;
+use ethers::providers::{JsonRpcClient, Middleware, Provider, ProviderError, RawCall};
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::{spoof, Address, Bytes, TransactionRequest};
+use thiserror::Error;
+
+/// Runtime code which makes the calls packed in its calldata one after another, reverts with the
+/// revert data of the first failed call, or returns the return data of the last call. Each call
+/// is packed as the 20-byte target address, the 32-byte length of the calldata and the calldata.
+pub const FORWARDER_CODE: &str = "0x60005b8036116012573d6000803e3d6000f35b8060140135808260340160003760008082600080863560601c5af1603a573d6000803e3d6000fd5b01603401600256";
+
+#[derive(Debug, Error)]
+pub enum SimulationError {
+    #[error("the sender {0:?} is a contract, whose code can't be replaced to make the calls")]
+    ContractSender(Address),
+    #[error(transparent)]
+    ProviderError(#[from] ProviderError),
+}
+
+/// A call made by the forwarder on behalf of the account it replaces the code of.
+#[derive(Debug, Clone)]
+pub struct ForwardedCall {
+    pub to: Address,
+    pub data: Bytes,
+}
+
+impl ForwardedCall {
+    pub fn new(to: Address, data: Bytes) -> Self {
+        ForwardedCall { to, data }
+    }
+
+    /// Makes the calls on behalf of an impersonated account.
+    pub fn batch(account: Address, calls: &[ForwardedCall]) -> Self {
+        ForwardedCall {
+            to: account,
+            data: encode_forwarded_calls(calls),
+        }
+    }
+}
+
+pub fn encode_forwarded_calls(calls: &[ForwardedCall]) -> Bytes {
+    let mut data = vec![];
+    for call in calls {
+        data.extend_from_slice(call.to.as_bytes());
+        let mut len = [0u8; 32];
+        len[24..].copy_from_slice(&(call.data.len() as u64).to_be_bytes());
+        data.extend_from_slice(&len);
+        data.extend_from_slice(&call.data);
+    }
+    data.into()
+}
+
+/// Encodes a call by its function signature, e.g. `request(uint256,string)`, and its params split
+/// by space.
+pub fn encode_function_call(signature: &str, params: &str) -> anyhow::Result<Bytes> {
+    let function = AbiParser::default().parse_function(signature)?;
+    let params = params.split_whitespace().collect::<Vec<_>>();
+
+    if params.len() != function.inputs.len() {
+        anyhow::bail!(
+            "{} expects {} params, but {} given",
+            signature,
+            function.inputs.len(),
+            params.len()
+        );
+    }
+
+    let tokens = function
+        .inputs
+        .iter()
+        .zip(params)
+        .map(|(input, param)| LenientTokenizer::tokenize(&input.kind, param))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(function.encode_input(&tokens)?.into())
+}
+
+/// Simulates transactions on the latest state of a chain with a single `eth_call`, as a fork of
+/// the chain would, by overriding the code of the accounts involved. The calls of an impersonated
+/// account are made by the forwarder put in place of its code, so the account is assumed not to
+/// rely on its own code.
+#[derive(Debug, Clone, Default)]
+pub struct ForkSimulation {
+    state: spoof::State,
+}
+
+impl ForkSimulation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows [`ForwardedCall::batch`] on behalf of the account.
+    pub fn impersonate(&mut self, account: Address) -> &mut Self {
+        self.state
+            .account(account)
+            .code(FORWARDER_CODE.parse().unwrap());
+        self
+    }
+
+    pub fn set_code(&mut self, account: Address, code: Bytes) -> &mut Self {
+        self.state.account(account).code(code);
+        self
+    }
+
+    /// Makes the calls on behalf of the sender and returns the return data of the last one, or
+    /// the revert data of the first failed one in the error. The sender must be an externally
+    /// owned account, as its code is replaced by the forwarder.
+    pub async fn call<P: JsonRpcClient>(
+        &self,
+        provider: &Provider<P>,
+        sender: Address,
+        calls: &[ForwardedCall],
+    ) -> Result<Bytes, SimulationError> {
+        if !provider.get_code(sender, None).await?.is_empty() {
+            return Err(SimulationError::ContractSender(sender));
+        }
+
+        let mut state = self.state.clone();
+        state.account(sender).code(FORWARDER_CODE.parse().unwrap());
+
+        let tx: TypedTransaction = TransactionRequest::new()
+            .from(sender)
+            .to(sender)
+            .data(encode_forwarded_calls(calls))
+            .into();
+
+        Ok(provider.call_raw(&tx).state(&state).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        encode_forwarded_calls, encode_function_call, ForkSimulation, ForwardedCall,
+        SimulationError,
+    };
+    use ethers::{
+        providers::{Http, Provider, RpcError},
+        types::{Address, Bytes, U256},
+        utils::Anvil,
+    };
+
+    /// Stores the caller when called with calldata, and returns the stored caller otherwise.
+    const CALLER_STORE_CODE: &str = "0x36600f5760005460005260206000f35b3360005500";
+
+    /// Returns the gas left to it.
+    const GAS_LEFT_CODE: &str = "0x5a60005260206000f3";
+
+    /// Reverts with 0xdeadbeef.
+    const REVERT_CODE: &str = "0x63deadbeef60e01b60005260046000fd";
+
+    #[test]
+    fn test_encode_forwarded_calls() {
+        let consumer: Address = "0x00000000000000000000000000000000000000cc"
+            .parse()
+            .unwrap();
+        let owner: Address = "0x00000000000000000000000000000000000000aa"
+            .parse()
+            .unwrap();
+
+        let calls = [
+            ForwardedCall::batch(owner, &[ForwardedCall::new(consumer, vec![1, 2].into())]),
+            ForwardedCall::new(consumer, Bytes::default()),
+        ];
+
+        let data = encode_forwarded_calls(&calls);
+
+        assert_eq!(20 + 32 + (20 + 32 + 2) + 20 + 32, data.len());
+        assert_eq!(owner.as_bytes(), &data[..20]);
+        assert_eq!(54, data[51]);
+        assert_eq!(consumer.as_bytes(), &data[52..72]);
+        assert_eq!(&[1, 2], &data[104..106]);
+        assert_eq!(consumer.as_bytes(), &data[106..126]);
+        assert!(data[126..].iter().all(|b| *b == 0));
+    }
+
+    #[test]
+    fn test_encode_function_call() {
+        let data = encode_function_call(
+            "transfer(address,uint256)",
+            "0x00000000000000000000000000000000000000aa 100",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "a9059cbb00000000000000000000000000000000000000000000000000000000000000aa0000000000000000000000000000000000000000000000000000000000000064",
+            hex::encode(data)
+        );
+
+        assert!(encode_function_call("transfer(address,uint256)", "100").is_err());
+    }
+    #[tokio::test]
+    async fn test_fork_simulation_call() {
+        let anvil = Anvil::new().spawn();
+        let provider = Provider::<Http>::try_from(anvil.endpoint()).unwrap();
+        let sender = anvil.addresses()[0];
+
+        let caller_store = Address::from_low_u64_be(0x1000);
+        let gas_left = Address::from_low_u64_be(0x1001);
+        let reverter = Address::from_low_u64_be(0x1002);
+
+        let mut simulation = ForkSimulation::new();
+        simulation
+            .set_code(caller_store, CALLER_STORE_CODE.parse().unwrap())
+            .set_code(gas_left, GAS_LEFT_CODE.parse().unwrap())
+            .set_code(reverter, REVERT_CODE.parse().unwrap());
+
+        // the calls are made by the sender one after another on the same state
+        let stored_caller = simulation
+            .call(
+                &provider,
+                sender,
+                &[
+                    ForwardedCall::new(caller_store, vec![1].into()),
+                    ForwardedCall::new(caller_store, Bytes::default()),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(sender.as_bytes(), &stored_caller[12..]);
+
+        // the callback is given the gas of the simulated transaction
+        let gas = simulation
+            .call(
+                &provider,
+                sender,
+                &[ForwardedCall::new(gas_left, Bytes::default())],
+            )
+            .await
+            .unwrap();
+        let gas = U256::from_big_endian(&gas);
+        assert!(gas > U256::zero() && gas < U256::from(30_000_000));
+
+        // the revert data of the failed call is kept, and the calls after it are not made
+        let err = simulation
+            .call(
+                &provider,
+                sender,
+                &[
+                    ForwardedCall::new(reverter, Bytes::default()),
+                    ForwardedCall::new(gas_left, Bytes::default()),
+                ],
+            )
+            .await
+            .unwrap_err();
+        match err {
+            SimulationError::ProviderError(e) => assert_eq!(
+                Some(Bytes::from(vec![0xde, 0xad, 0xbe, 0xef])),
+                e.as_error_response().and_then(|e| e.as_revert_data())
+            ),
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        // a contract can't be the sender, as its code would be replaced by the forwarder
+        provider
+            .request::<_, ()>(
+                "anvil_setCode",
+                (sender, GAS_LEFT_CODE.parse::<Bytes>().unwrap()),
+            )
+            .await
+            .unwrap();
+        assert!(matches!(
+            simulation
+                .call(
+                    &provider,
+                    sender,
+                    &[ForwardedCall::new(gas_left, Bytes::default())],
+                )
+                .await,
+            Err(SimulationError::ContractSender(contract)) if contract == sender
+        ));
+    }
+}
@@ -1,28 +1,41 @@
-use arpa_contract_client::contract_stub::adapter::Adapter as AdapterContract;
+use arpa_contract_client::contract_stub::adapter::{
+    Adapter as AdapterContract, FulfillRandomnessCall,
+};
+use arpa_contract_client::contract_stub::general_randcast_consumer_base::GeneralRandcastConsumerBase as ConsumerContract;
 use arpa_contract_client::contract_stub::ierc20::IERC20 as ArpaContract;
 use arpa_contract_client::contract_stub::staking::Staking as StakingContract;
+use arpa_contract_client::controller::{ControllerClientBuilder, ControllerViews};
+use arpa_contract_client::controller_oracle::{
+    ControllerOracleClientBuilder, ControllerOracleViews,
+};
 use arpa_contract_client::ethers::adapter::AdapterClient;
 use arpa_contract_client::{TransactionCaller, ViewCaller};
 use arpa_core::RandomnessRequestType;
 use arpa_core::{address_to_string, pad_to_bytes32};
 use arpa_core::{u256_to_vec, HttpWalletSigner};
+use arpa_core::{
+    verify_randomness_fulfillment, ChainTransport, GeneralMainChainIdentity,
+    GeneralRelayedChainIdentity, ProviderEndpoints, DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+};
 use arpa_user_cli::config::{Config, ConfigError};
-use ethers::abi::AbiEncode;
+use arpa_user_cli::simulation::{encode_function_call, ForkSimulation, ForwardedCall};
+use ethers::abi::{AbiDecode, AbiEncode};
 use ethers::prelude::{NonceManagerMiddleware, SignerMiddleware};
 use ethers::providers::{Http, Middleware, Provider};
-use ethers::signers::Signer;
+use ethers::signers::{LocalWallet, Signer};
 use ethers::types::{Address, BlockId, BlockNumber, Topic, H256, U256, U64};
-use ethers::utils::Anvil;
 use reedline_repl_rs::clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
 use reedline_repl_rs::Repl;
 use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
+use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::Arc;
 use structopt::StructOpt;
+use threshold_bls::group::Curve;
+use threshold_bls::schemes::bn254::{G2Curve, G2Scheme};
 
 pub const SIMPLE_ADAPTER_CODE: &str = "0x6080604052348015600f57600080fd5b506004361060325760003560e01c806376a911bc146037578063a39402d7146066575b600080fd5b60486042366004607e565b50600090565b60405167ffffffffffffffff90911681526020015b60405180910390f35b6071604236600460b9565b604051908152602001605d565b600060208284031215608f57600080fd5b813573ffffffffffffffffffffffffffffffffffffffff8116811460b257600080fd5b9392505050565b60006020828403121560ca57600080fd5b813567ffffffffffffffff81111560e057600080fd5b820160e0818503121560b257600080fdfea264697066735822122060db0656f5a3a02d609b3fb8d9ae455165807d775077e751b503136af39395c464736f6c63430008120033";
 pub const RANDOMNESS_REWARD_GAS: u32 = 9000;
@@ -64,11 +77,19 @@ impl Context {
     fn build_signer(config: &Config, chain_id: u32) -> anyhow::Result<Arc<HttpWalletSigner>> {
         let wallet = config.account(chain_id)?.with_chain_id(chain_id);
 
-        let nonce_manager =
-            NonceManagerMiddleware::new(Self::build_provider(config, chain_id)?, wallet.address());
+        Ok(Self::build_wallet_signer(
+            wallet,
+            Self::build_provider(config, chain_id)?,
+        ))
+    }
+
+    fn build_wallet_signer(
+        wallet: LocalWallet,
+        provider: Arc<Provider<Http>>,
+    ) -> Arc<HttpWalletSigner> {
+        let nonce_manager = NonceManagerMiddleware::new(provider, wallet.address());
 
-        let signer = Arc::new(SignerMiddleware::new(nonce_manager, wallet));
-        Ok(signer)
+        Arc::new(SignerMiddleware::new(nonce_manager, wallet))
     }
 
     fn build_provider(config: &Config, chain_id: u32) -> anyhow::Result<Arc<Provider<Http>>> {
@@ -184,6 +205,11 @@ pub struct ArpaClient;
 impl ViewCaller for ArpaClient {}
 impl TransactionCaller for ArpaClient {}
 
+pub struct ConsumerClient;
+
+impl ViewCaller for ConsumerClient {}
+impl TransactionCaller for ConsumerClient {}
+
 async fn send(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<String>> {
     match args.subcommand() {
         Some(("approve-arpa-to-staking", sub_matches)) => {
@@ -564,7 +590,7 @@ async fn send(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<
             let consumer_owner_private_key = sub_matches
                 .get_one::<String>("consumer-owner-private-key")
                 .unwrap();
-            let callback_gas_limit = sub_matches.get_one::<String>("callback-gas-limit").unwrap();
+            let callback_gas_limit = sub_matches.get_one::<u32>("callback-gas-limit").unwrap();
             let callback_max_gas_fee = sub_matches
                 .get_one::<String>("callback-max-gas-fee")
                 .unwrap();
@@ -575,24 +601,31 @@ async fn send(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<
                 consumer_owner_private_key.to_owned()
             };
 
-            let set_callback_gas_config_args = vec![
-                "send",
-                consumer,
-                "setCallbackGasConfig(uint32,uint256)",
-                callback_gas_limit,
-                callback_max_gas_fee,
-                "--private-key",
-                &consumer_owner_private_key,
-            ];
-            let cast_res = call_cast(
-                &context.config.provider_endpoint(*chain_id)?,
-                &set_callback_gas_config_args,
+            let consumer_contract = ConsumerContract::new(
+                consumer.parse::<Address>()?,
+                Context::build_wallet_signer(
+                    LocalWallet::from_str(&consumer_owner_private_key)?.with_chain_id(*chain_id),
+                    context.provider(*chain_id)?,
+                ),
             );
-            let trx_hash = &cast_res[cast_res.find("transactionHash").unwrap()
-                + "transactionHash         ".len()
-                ..cast_res.find("\ntransactionIndex").unwrap()];
+
+            let trx_hash = ConsumerClient::call_contract_transaction(
+                *chain_id as usize,
+                "set_callback_gas_config",
+                consumer_contract.client_ref(),
+                consumer_contract.set_callback_gas_config(
+                    *callback_gas_limit,
+                    U256::from_dec_str(callback_max_gas_fee)?,
+                ),
+                context
+                    .config
+                    .contract_transaction_retry_descriptor(*chain_id)?,
+                true,
+            )
+            .await?;
+
             Ok(Some(format!(
-                "Set callback gas config successfully, transaction hash: {}",
+                "Set callback gas config successfully, transaction hash: {:?}",
                 trx_hash
             )))
         }
@@ -602,9 +635,8 @@ async fn send(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<
             let consumer_owner_private_key = sub_matches
                 .get_one::<String>("consumer-owner-private-key")
                 .unwrap();
-            let request_confirmations = sub_matches
-                .get_one::<String>("request-confirmations")
-                .unwrap();
+            let request_confirmations =
+                sub_matches.get_one::<u16>("request-confirmations").unwrap();
 
             let consumer_owner_private_key = if consumer_owner_private_key.starts_with('$') {
                 env::var(consumer_owner_private_key.trim_start_matches('$'))?
@@ -612,23 +644,28 @@ async fn send(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<
                 consumer_owner_private_key.to_owned()
             };
 
-            let set_callback_gas_config_args = vec![
-                "send",
-                consumer,
-                "setRequestConfirmations(uint16)",
-                request_confirmations,
-                "--private-key",
-                &consumer_owner_private_key,
-            ];
-            let cast_res = call_cast(
-                &context.config.provider_endpoint(*chain_id)?,
-                &set_callback_gas_config_args,
+            let consumer_contract = ConsumerContract::new(
+                consumer.parse::<Address>()?,
+                Context::build_wallet_signer(
+                    LocalWallet::from_str(&consumer_owner_private_key)?.with_chain_id(*chain_id),
+                    context.provider(*chain_id)?,
+                ),
             );
-            let trx_hash = &cast_res[cast_res.find("transactionHash").unwrap()
-                + "transactionHash         ".len()
-                ..cast_res.find("\ntransactionIndex").unwrap()];
+
+            let trx_hash = ConsumerClient::call_contract_transaction(
+                *chain_id as usize,
+                "set_request_confirmations",
+                consumer_contract.client_ref(),
+                consumer_contract.set_request_confirmations(*request_confirmations),
+                context
+                    .config
+                    .contract_transaction_retry_descriptor(*chain_id)?,
+                true,
+            )
+            .await?;
+
             Ok(Some(format!(
-                "Set request confirmations successfully, transaction hash: {}",
+                "Set request confirmations successfully, transaction hash: {:?}",
                 trx_hash
             )))
         }
@@ -739,69 +776,61 @@ async fn call(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<
     }
 }
 
-fn call_cast(rpc_url: &str, args: &[&str]) -> String {
-    let mut cmd = std::process::Command::new("cast");
-    cmd.stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::inherit());
-
-    cmd.args(args);
-    cmd.arg("--rpc-url");
-    cmd.arg(rpc_url);
-    let mut child = cmd.spawn().expect("couldnt start cast");
-
-    let stdout = child
-        .stdout
-        .take()
-        .expect("Unable to get stdout for cast child process");
-
-    let mut reader = BufReader::new(stdout);
-    let mut line = String::new();
-    reader
-        .read_to_string(&mut line)
-        .expect("Failed to read line from cast process");
-    line.trim_end_matches('\n').to_string()
-}
-
 async fn randcast(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<String>> {
     match args.subcommand() {
         Some(("nonces", sub_matches)) => {
             let chain_id = sub_matches.get_one::<u32>("chain-id").unwrap();
             let consumer = sub_matches.get_one::<String>("consumer").unwrap();
-            let sub_id = sub_matches.get_one::<String>("sub-id").unwrap();
+            let sub_id = sub_matches.get_one::<u64>("sub-id").unwrap();
+
+            let consumer_contract =
+                ConsumerContract::new(consumer.parse::<Address>()?, context.signer(*chain_id)?);
 
-            let nonce_args = vec!["call", consumer, "getNonce(uint64)(uint256)", sub_id];
-            let nonce_res = call_cast(&context.config.provider_endpoint(*chain_id)?, &nonce_args);
+            let nonce = ConsumerClient::call_contract_view(
+                *chain_id as usize,
+                "get_nonce",
+                consumer_contract.get_nonce(*sub_id),
+                context.config.contract_view_retry_descriptor(*chain_id)?,
+            )
+            .await?;
 
-            Ok(Some(format!("consumer_nonce: {}", nonce_res)))
+            Ok(Some(format!("consumer_nonce: {}", nonce)))
         }
         Some(("callback-gas-limit", sub_matches)) => {
             let chain_id = sub_matches.get_one::<u32>("chain-id").unwrap();
             let consumer = sub_matches.get_one::<String>("consumer").unwrap();
 
-            let callback_gas_limit_args = vec!["call", consumer, "callbackGasLimit()(uint32)"];
-            let callback_gas_limit_res = call_cast(
-                &context.config.provider_endpoint(*chain_id)?,
-                &callback_gas_limit_args,
-            );
+            let consumer_contract =
+                ConsumerContract::new(consumer.parse::<Address>()?, context.signer(*chain_id)?);
 
-            Ok(Some(format!(
-                "callback_gas_limit: {}",
-                callback_gas_limit_res
-            )))
+            let callback_gas_limit = ConsumerClient::call_contract_view(
+                *chain_id as usize,
+                "callback_gas_limit",
+                consumer_contract.callback_gas_limit(),
+                context.config.contract_view_retry_descriptor(*chain_id)?,
+            )
+            .await?;
+
+            Ok(Some(format!("callback_gas_limit: {}", callback_gas_limit)))
         }
         Some(("callback-max-gas-fee", sub_matches)) => {
             let chain_id = sub_matches.get_one::<u32>("chain-id").unwrap();
             let consumer = sub_matches.get_one::<String>("consumer").unwrap();
 
-            let callback_max_gas_fee_args = vec!["call", consumer, "callbackMaxGasFee()(uint256)"];
-            let callback_max_gas_fee_res = call_cast(
-                &context.config.provider_endpoint(*chain_id)?,
-                &callback_max_gas_fee_args,
-            );
+            let consumer_contract =
+                ConsumerContract::new(consumer.parse::<Address>()?, context.signer(*chain_id)?);
+
+            let callback_max_gas_fee = ConsumerClient::call_contract_view(
+                *chain_id as usize,
+                "callback_max_gas_fee",
+                consumer_contract.callback_max_gas_fee(),
+                context.config.contract_view_retry_descriptor(*chain_id)?,
+            )
+            .await?;
 
             Ok(Some(format!(
                 "callback_max_gas_fee: {}",
-                callback_max_gas_fee_res
+                callback_max_gas_fee
             )))
         }
         Some(("estimate-callback-gas", sub_matches)) => {
@@ -812,90 +841,88 @@ async fn randcast(args: ArgMatches, context: &mut Context) -> anyhow::Result<Opt
             let request_params = sub_matches.get_one::<String>("request-params").unwrap();
             let request_contract = sub_matches.get_one::<String>("request-contract");
 
-            let existed_callback_gas_limit_args =
-                vec!["call", consumer, "callbackGasLimit()(uint32)"];
-            let existed_callback_gas_limit = call_cast(
-                &context.config.provider_endpoint(*chain_id)?,
-                &existed_callback_gas_limit_args,
-            );
+            let consumer = consumer.parse::<Address>()?;
+            let request_sender = request_sender.parse::<Address>()?;
+            let request_contract = match request_contract {
+                Some(contract) => contract.parse::<Address>()?,
+                None => consumer,
+            };
+
+            let consumer_contract = ConsumerContract::new(consumer, context.signer(*chain_id)?);
+
+            let existed_callback_gas_limit = ConsumerClient::call_contract_view(
+                *chain_id as usize,
+                "callback_gas_limit",
+                consumer_contract.callback_gas_limit(),
+                context.config.contract_view_retry_descriptor(*chain_id)?,
+            )
+            .await?;
 
-            let anvil = Anvil::new()
-                .chain_id(*chain_id as u64)
-                .fork(context.config.provider_endpoint(*chain_id)?)
-                .port(8544u16)
-                .spawn();
+            let owner = ConsumerClient::call_contract_view(
+                *chain_id as usize,
+                "owner",
+                consumer_contract.owner(),
+                context.config.contract_view_retry_descriptor(*chain_id)?,
+            )
+            .await?;
 
-            let get_owner_args = vec!["call", consumer, "owner()(address)"];
-            let owner = call_cast(&anvil.endpoint(), &get_owner_args);
+            let mut owner_calls = vec![];
 
-            if existed_callback_gas_limit != "0" {
+            if existed_callback_gas_limit != 0 {
                 println!(
                     "callbackGasLimit is already set: {}",
                     existed_callback_gas_limit
                 );
 
-                let impersonate_account_args = vec!["rpc", "anvil_impersonateAccount", &owner];
-                call_cast(&anvil.endpoint(), &impersonate_account_args);
-
-                let reset_callback_gas_args = vec![
-                    "send",
+                owner_calls.push(ForwardedCall::new(
                     consumer,
-                    "setCallbackGasConfig(uint32,uint256)",
-                    "0",
-                    "0",
-                    "--from",
-                    &owner,
-                    "--unlocked",
-                ];
-                call_cast(&anvil.endpoint(), &reset_callback_gas_args);
+                    consumer_contract
+                        .set_callback_gas_config(0, U256::zero())
+                        .calldata()
+                        .unwrap(),
+                ));
             }
 
-            let impersonate_account_args = vec!["rpc", "anvil_impersonateAccount", request_sender];
-            call_cast(&anvil.endpoint(), &impersonate_account_args);
+            owner_calls.push(ForwardedCall::new(
+                consumer,
+                consumer_contract
+                    .set_request_confirmations(3)
+                    .calldata()
+                    .unwrap(),
+            ));
 
-            // replace adapter code to make sure the request randomness success
-            let adapter_address = address_to_string(context.config.adapter_address(*chain_id)?);
-            let set_code_args = vec![
-                "rpc",
-                "anvil_setCode",
-                &adapter_address,
-                SIMPLE_ADAPTER_CODE,
-            ];
-            call_cast(&anvil.endpoint(), &set_code_args);
+            let mut simulation = ForkSimulation::new();
 
-            let request_contract = match request_contract {
-                Some(contract) => contract,
-                None => consumer,
-            };
+            // replace adapter code to make sure the request randomness success
+            simulation.impersonate(owner).set_code(
+                context.config.adapter_address(*chain_id)?,
+                SIMPLE_ADAPTER_CODE.parse()?,
+            );
 
-            let set_request_confirmations_args = vec![
-                "send",
-                consumer,
-                "setRequestConfirmations(uint16)",
-                "3",
-                "--from",
-                &owner,
-                "--unlocked",
-            ];
-            call_cast(&anvil.endpoint(), &set_request_confirmations_args);
-
-            let request_randomness_args = vec![
-                "send",
-                request_contract,
-                request_signature,
-                request_params,
-                "--from",
-                request_sender,
-                "--unlocked",
-            ];
-            call_cast(&anvil.endpoint(), &request_randomness_args);
-
-            let callback_gas_limit_args = vec!["call", consumer, "callbackGasLimit()(uint32)"];
-            let callback_gas_limit_res = call_cast(&anvil.endpoint(), &callback_gas_limit_args);
+            let provider = context.provider(*chain_id)?;
+
+            // the consumer estimates and saves the callback gas limit when requesting randomness without one
+            let callback_gas_limit = simulation
+                .call(
+                    &provider,
+                    request_sender,
+                    &[
+                        ForwardedCall::batch(owner, &owner_calls),
+                        ForwardedCall::new(
+                            request_contract,
+                            encode_function_call(request_signature, request_params)?,
+                        ),
+                        ForwardedCall::new(
+                            consumer,
+                            consumer_contract.callback_gas_limit().calldata().unwrap(),
+                        ),
+                    ],
+                )
+                .await?;
 
             Ok(Some(format!(
                 "estimate_callback_gas_limit_res: {}",
-                callback_gas_limit_res
+                u32::decode(callback_gas_limit)?
             )))
         }
         Some(("estimate-payment-amount", sub_matches)) => {
@@ -1315,6 +1342,95 @@ async fn randcast(args: ArgMatches, context: &mut Context) -> anyhow::Result<Opt
 
             Ok(Some(format!("requests: {:#?}", results)))
         }
+        Some(("verify-fulfillment", sub_matches)) => {
+            let chain_id = sub_matches.get_one::<u32>("chain-id").unwrap();
+            let request_id = sub_matches.get_one::<String>("request-id").unwrap();
+
+            let request_id = H256::from(
+                pad_to_bytes32(&hex::decode(
+                    request_id.strip_prefix("0x").unwrap_or(request_id),
+                )?)
+                .ok_or_else(|| anyhow::anyhow!("bad format of request id"))?,
+            );
+
+            let adapter_contract = AdapterContract::new(
+                context.config.adapter_address(*chain_id)?,
+                context.signer(*chain_id)?,
+            );
+
+            let (request, request_meta) = adapter_contract
+                .randomness_request_filter()
+                .topic1(request_id)
+                .from_block(context.config.adapter_deployed_block_height(*chain_id)?)
+                .to_block(BlockNumber::Latest)
+                .query_with_meta()
+                .await?
+                .pop()
+                .ok_or_else(|| anyhow::anyhow!("Request not found"))?;
+
+            let (fulfillment, fulfillment_meta) = adapter_contract
+                .randomness_request_result_filter()
+                .topic1(request_id)
+                .from_block(request_meta.block_number)
+                .to_block(BlockNumber::Latest)
+                .query_with_meta()
+                .await?
+                .pop()
+                .ok_or_else(|| anyhow::anyhow!("Request is not fulfilled yet"))?;
+
+            // the signature is only submitted in the calldata of the fulfillment
+            let fulfillment_trx = context
+                .provider(*chain_id)?
+                .get_transaction(fulfillment_meta.transaction_hash)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Fulfillment transaction not found"))?;
+
+            let signature = FulfillRandomnessCall::decode(&fulfillment_trx.input)
+                .map_err(|_| {
+                    anyhow::anyhow!(
+                        "Fulfillment transaction {:?} is not a fulfillRandomness call to the adapter",
+                        fulfillment_meta.transaction_hash
+                    )
+                })?
+                .signature;
+
+            // the group may have been rebuilt since, so its key is read at the fulfillment
+            let public_key = get_group_public_key(
+                context,
+                *chain_id,
+                fulfillment.group_index as usize,
+                fulfillment_meta.block_number.as_u64(),
+            )
+            .await?;
+
+            let randomness = verify_randomness_fulfillment::<G2Scheme>(
+                &public_key,
+                &request.seed,
+                request_meta.block_number.as_usize(),
+                &signature,
+            )
+            .map_err(|e| {
+                anyhow::anyhow!(
+                    "Signature is invalid under the public key of group {} at block {}: {:?}",
+                    fulfillment.group_index,
+                    fulfillment_meta.block_number,
+                    e
+                )
+            })?;
+
+            if randomness != fulfillment.randommness {
+                return Err(anyhow::anyhow!(
+                    "Signature is valid, but the fulfilled randomness {:#?} is not derived from it: {:#?}",
+                    fulfillment.randommness,
+                    randomness
+                ));
+            }
+
+            Ok(Some(format!(
+                "Fulfillment verified, group_index: {:#?}, signature: {:#?}, randomness: {:#?}",
+                fulfillment.group_index, signature, randomness
+            )))
+        }
         Some(("last-assigned-group-index", sub_matches)) => {
             let chain_id = sub_matches.get_one::<u32>("chain-id").unwrap();
             let adapter_contract = AdapterContract::new(
@@ -1414,6 +1530,89 @@ async fn randcast(args: ArgMatches, context: &mut Context) -> anyhow::Result<Opt
     }
 }
 
+/// Gets the public key of a group at the given block from the controller of the chain, which is
+/// the controller oracle on a relayed chain.
+async fn get_group_public_key(
+    context: &mut Context,
+    chain_id: u32,
+    group_index: usize,
+    block_number: u64,
+) -> anyhow::Result<<G2Curve as Curve>::Point> {
+    let adapter_address = context.config.adapter_address(chain_id)?;
+    let adapter_contract = AdapterContract::new(adapter_address, context.signer(chain_id)?);
+
+    let controller_address = AdapterClient::call_contract_view(
+        chain_id as usize,
+        "get_controller",
+        adapter_contract.get_controller(),
+        context.config.contract_view_retry_descriptor(chain_id)?,
+    )
+    .await?;
+
+    let provider_endpoint = context.config.provider_endpoint(chain_id)?;
+    let provider = Arc::new(Provider::new(
+        ChainTransport::connect(
+            &provider_endpoint,
+            DEFAULT_WEBSOCKET_PROVIDER_RECONNECT_TIMES,
+        )
+        .await?,
+    ));
+    let provider_endpoints = ProviderEndpoints::new(vec![provider_endpoint], 1);
+
+    let group = if chain_id == context.config.main_chain_id() {
+        let identity = GeneralMainChainIdentity::new(
+            chain_id as usize,
+            context.config.account(chain_id)?,
+            provider,
+            provider_endpoints,
+            controller_address,
+            Address::zero(),
+            adapter_address,
+            context
+                .config
+                .contract_transaction_retry_descriptor(chain_id)?,
+            context.config.contract_view_retry_descriptor(chain_id)?,
+        );
+
+        let controller_client =
+            ControllerClientBuilder::<G2Curve>::build_controller_client(&identity);
+
+        ControllerViews::<G2Curve>::get_group_at(&controller_client, group_index, block_number)
+            .await?
+    } else {
+        let identity = GeneralRelayedChainIdentity::new(
+            chain_id as usize,
+            context.config.account(chain_id)?,
+            provider,
+            provider_endpoints,
+            controller_address,
+            adapter_address,
+            context
+                .config
+                .contract_transaction_retry_descriptor(chain_id)?,
+            context.config.contract_view_retry_descriptor(chain_id)?,
+        );
+
+        let controller_oracle_client =
+            ControllerOracleClientBuilder::<G2Curve>::build_controller_oracle_client(&identity);
+
+        ControllerOracleViews::<G2Curve>::get_group_at(
+            &controller_oracle_client,
+            group_index,
+            block_number,
+        )
+        .await?
+    };
+
+    group.public_key.ok_or_else(|| {
+        anyhow::anyhow!(
+            "Group {} has no public key at block {}",
+            group_index,
+            block_number
+        )
+    })
+}
+
 async fn stake(args: ArgMatches, context: &mut Context) -> anyhow::Result<Option<String>> {
     match args.subcommand() {
         Some(("stake", _sub_matches)) => {
@@ -1912,6 +2111,10 @@ async fn main() -> anyhow::Result<()> {
                         .arg(Arg::new("success").long("success").required(false).action(ArgAction::SetTrue).help("only success requests"))
                         .arg(Arg::new("failed").long("failed").required(false).action(ArgAction::SetTrue).help("only failed requests, which means the callback function in consumer contract reverts due to business logic or gas limit"))
                 ).subcommand(
+                    Command::new("verify-fulfillment").visible_alias("vf")
+                        .about("Verify the signature of a fulfilled request with the public key of the group which fulfilled it at the fulfillment block, and the randomness derived from the signature")
+                        .arg(Arg::new("chain-id").required(true).value_parser(value_parser!(u32)).help("chain id in decimal format"))
+                        .arg(Arg::new("request-id").required(true).help("request id in hex format"))                ).subcommand(
                     Command::new("estimate-callback-gas").visible_alias("ecg")
                         .about("Estimate callback gas for any consumer contract extends GeneralRandcastConsumerBase under the current circumstances. \
                                 This can be used before the first request to estimate how much eth is needed for subscription funding, \
@@ -1920,7 +2123,7 @@ async fn main() -> anyhow::Result<()> {
                                 An error will be returned if callback in the consumer contract reverts.")
                         .arg(Arg::new("chain-id").required(true).value_parser(value_parser!(u32)).help("chain id in decimal format"))
                         .arg(Arg::new("consumer").required(true).help("address of your customized consumer contract in hex format"))
-                        .arg(Arg::new("request-sender").required(true).help("sender address(depending on your business logic, don't have to be the owner of the consumer contract) to request randomness(don't have to be the function in consumer contract) in hex format"))
+                        .arg(Arg::new("request-sender").required(true).help("sender address(depending on your business logic, don't have to be the owner of the consumer contract, but must not be a contract) to request randomness(don't have to be the function in consumer contract) in hex format"))
                         .arg(Arg::new("request-signature").required(true).help("function signature of request randomness with a pair of quotation marks"))
                         .arg(Arg::new("request-params").required(true).help("request params split by space"))
                         .arg(Arg::new("request-contract").required(false).help("request contract address in hex format, if not set, will use the consumer contract address"))
@@ -1944,7 +2147,7 @@ async fn main() -> anyhow::Result<()> {
                         .about("Get nonce(counting from 1, as there was no request) for a specific subscription id and consumer address")
                         .arg(Arg::new("chain-id").required(true).value_parser(value_parser!(u32)).help("chain id in decimal format"))
                         .arg(Arg::new("consumer").required(true).help("consumer address in hex format"))
-                        .arg(Arg::new("sub-id").required(true).help("subscription id in decimal"))
+                        .arg(Arg::new("sub-id").required(true).value_parser(value_parser!(u64)).help("subscription id in decimal"))
                 ).subcommand(
                     Command::new("last-randomness").visible_alias("lr")
                         .about("Get last randomness")
@@ -2106,7 +2309,7 @@ async fn main() -> anyhow::Result<()> {
                         .arg(Arg::new("chain-id").required(true).value_parser(value_parser!(u32)).help("chain id in decimal format"))
                         .arg(Arg::new("consumer").required(true).help("consumer contract address in hex format"))
                         .arg(Arg::new("consumer-owner-private-key").required(true).help("consumer contract owner private key in plain hex format, or a env var starts with $"))
-                        .arg(Arg::new("callback-gas-limit").required(true).help("callback gas limit"))
+                        .arg(Arg::new("callback-gas-limit").required(true).value_parser(value_parser!(u32)).help("callback gas limit"))
                         .arg(Arg::new("callback-max-gas-fee").required(true).help("callback max gas fee"))
                 ).subcommand(
                     Command::new("set-request-confirmations").visible_alias("src")
@@ -2114,7 +2317,7 @@ async fn main() -> anyhow::Result<()> {
                         .arg(Arg::new("chain-id").required(true).value_parser(value_parser!(u32)).help("chain id in decimal format"))
                         .arg(Arg::new("consumer").required(true).help("consumer contract address in hex format"))
                         .arg(Arg::new("consumer-owner-private-key").required(true).help("consumer contract owner private key in plain hex format, or a env var starts with $"))
-                        .arg(Arg::new("request-confirmations").required(true).help("the number of blocks required between the randomness request and the randomness fulfillment"))
+                        .arg(Arg::new("request-confirmations").required(true).value_parser(value_parser!(u16)).help("the number of blocks required between the randomness request and the randomness fulfillment"))
                 )
                 .about("*** Be careful this will change on-chain state and cost gas as well as block time***\nSend trxs to on-chain contracts"),
             |args, context| Box::pin(send(args, context)),